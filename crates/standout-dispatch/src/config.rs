@@ -0,0 +1,177 @@
+//! Reading configuration into typed settings.
+//!
+//! Configuration layered from environment variables and `--set` overrides
+//! arrives as strings: the loader can't know whether `MYAPP_PORT=8080` is a
+//! number or `MYAPP_TOKEN=1234` a string. [`from_config`] settles that from
+//! the target type instead. A string is read as a number or boolean where
+//! the field expects one, and stays a string everywhere else, so a `String`
+//! field keeps `1234`, `1.0` or `true` exactly as written.
+
+use serde::de::value::{MapDeserializer, SeqDeserializer};
+use serde::de::{DeserializeOwned, Deserializer, IntoDeserializer, Visitor};
+use serde_json::Value;
+
+/// Deserializes `T` from `value`, reading strings as numbers or booleans
+/// where `T` expects them.
+pub(crate) fn from_config<T: DeserializeOwned>(value: &Value) -> Result<T, serde_json::Error> {
+    T::deserialize(Lenient(value))
+}
+
+/// A configuration value that converts strings on request.
+struct Lenient<'a>(&'a Value);
+
+impl<'de> IntoDeserializer<'de, serde_json::Error> for Lenient<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+/// Deserializes a string-valued number or boolean with `$visit`, or the
+/// value as-is when it isn't one.
+macro_rules! lenient_scalar {
+    ($($method:ident => $parse:ty, $visit:ident;)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                if let Value::String(s) = self.0 {
+                    if let Ok(parsed) = s.trim().parse::<$parse>() {
+                        return visitor.$visit(parsed);
+                    }
+                }
+                self.deserialize_any(visitor)
+            }
+        )*
+    };
+}
+
+impl<'de> Deserializer<'de> for Lenient<'de> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Array(items) => {
+                visitor.visit_seq(SeqDeserializer::new(items.iter().map(Lenient)))
+            }
+            Value::Object(map) => visitor.visit_map(MapDeserializer::new(
+                map.iter()
+                    .map(|(key, value)| (key.as_str(), Lenient(value))),
+            )),
+            other => other.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.0.deserialize_enum(name, variants, visitor)
+    }
+
+    lenient_scalar! {
+        deserialize_bool => bool, visit_bool;
+        deserialize_i8 => i64, visit_i64;
+        deserialize_i16 => i64, visit_i64;
+        deserialize_i32 => i64, visit_i64;
+        deserialize_i64 => i64, visit_i64;
+        deserialize_i128 => i128, visit_i128;
+        deserialize_u8 => u64, visit_u64;
+        deserialize_u16 => u64, visit_u64;
+        deserialize_u32 => u64, visit_u64;
+        deserialize_u64 => u64, visit_u64;
+        deserialize_u128 => u128, visit_u128;
+        deserialize_f32 => f64, visit_f64;
+        deserialize_f64 => f64, visit_f64;
+    }
+
+    serde::forward_to_deserialize_any! {
+        char str string bytes byte_buf unit unit_struct seq tuple tuple_struct
+        map struct identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Settings {
+        port: u16,
+        ratio: f64,
+        verbose: bool,
+        token: String,
+        version: String,
+        id: String,
+        limit: Option<u32>,
+        sync: Sync,
+        tags: Vec<u8>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Sync {
+        enabled: bool,
+    }
+
+    #[test]
+    fn test_strings_read_as_the_field_type() {
+        let settings: Settings = from_config(&json!({
+            "port": "8080",
+            "ratio": "0.5",
+            "verbose": "true",
+            "token": "1234",
+            "version": "1.0",
+            "id": "123456789012345678901234567890",
+            "limit": "20",
+            "sync": { "enabled": "false" },
+            "tags": ["1", 2],
+        }))
+        .unwrap();
+
+        assert_eq!(
+            settings,
+            Settings {
+                port: 8080,
+                ratio: 0.5,
+                verbose: true,
+                token: "1234".to_string(),
+                version: "1.0".to_string(),
+                id: "123456789012345678901234567890".to_string(),
+                limit: Some(20),
+                sync: Sync { enabled: false },
+                tags: vec![1, 2],
+            }
+        );
+    }
+
+    #[test]
+    fn test_unconvertible_strings_fail() {
+        #[derive(Debug, Deserialize)]
+        #[allow(dead_code)]
+        struct Port {
+            port: u16,
+        }
+
+        assert!(from_config::<Port>(&json!({ "port": "http" })).is_err());
+        assert!(from_config::<Port>(&json!({ "port": "70000" })).is_err());
+        assert!(from_config::<Port>(&json!({ "port": "1.5" })).is_err());
+    }
+}
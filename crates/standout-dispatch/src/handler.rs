@@ -61,16 +61,21 @@
 //! - [`Extensions`]: Type-safe container for injecting custom state
 //! - [`Output`]: What a handler produces (render data, silent, or binary)
 //! - [`HandlerResult`]: The result type for handlers (`Result<Output<T>, Error>`)
+//! - [`ExitError`]: A handler error that carries a process exit code
 //! - [`RunResult`]: The result of running the CLI dispatcher
 //! - [`Handler`]: Trait for command handlers (`&mut self`)
 
+use crate::progress::{Progress, ProgressReporter};
 use crate::verify::ExpectedArg;
 use clap::ArgMatches;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
 use std::fmt;
-use std::rc::Rc;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 
 /// Type-safe container for injecting custom state into handlers.
 ///
@@ -83,7 +88,9 @@ use std::rc::Rc;
 /// results in a new, empty map. This is because the underlying `Box<dyn Any>` values cannot
 /// be cloned generically.
 ///
-/// If you need to share state across threads/clones, use `Arc<T>` inside the extension.
+/// Values must be `Send + Sync`, so a context (and an app holding one as
+/// `app_state`) can be shared across threads. Wrap interior mutability in a
+/// `Mutex` or an atomic rather than a `RefCell` or `Cell`.
 ///
 /// # Example
 ///
@@ -106,7 +113,7 @@ use std::rc::Rc;
 /// ```
 #[derive(Default)]
 pub struct Extensions {
-    map: HashMap<TypeId, Box<dyn Any>>,
+    map: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
 }
 
 impl Extensions {
@@ -118,7 +125,7 @@ impl Extensions {
     /// Inserts a value into the extensions.
     ///
     /// If a value of this type already exists, it is replaced and returned.
-    pub fn insert<T: 'static>(&mut self, val: T) -> Option<T> {
+    pub fn insert<T: Send + Sync + 'static>(&mut self, val: T) -> Option<T> {
         self.map
             .insert(TypeId::of::<T>(), Box::new(val))
             .and_then(|boxed| boxed.downcast().ok().map(|b| *b))
@@ -301,60 +308,340 @@ pub struct CommandContext {
     /// like database connections, configuration, and API clients.
     ///
     /// Use `get::<T>()` or `get_required::<T>()` to retrieve values.
-    pub app_state: Rc<Extensions>,
+    pub app_state: Arc<Extensions>,
 
     /// Mutable per-request state container.
     ///
     /// Pre-dispatch hooks can insert values that handlers retrieve.
     /// Each dispatch gets a fresh Extensions instance.
     pub extensions: Extensions,
+
+    /// Cancelled when the user interrupts the command (e.g. Ctrl-C).
+    ///
+    /// Long-running handlers should poll `is_cancelled()` and return early
+    /// with whatever partial output they have, so it still gets rendered and
+    /// post-output hooks still run.
+    pub cancellation: CancellationToken,
+
+    /// How much output the user asked for with `-q` / `-v` flags.
+    ///
+    /// Always [`Verbosity::Normal`] unless the app enables verbosity flags.
+    pub verbosity: Verbosity,
 }
 
 impl CommandContext {
     /// Creates a new CommandContext with the given path and shared app state.
     ///
     /// This is more efficient than `Default::default()` when you already have app_state.
-    pub fn new(command_path: Vec<String>, app_state: Rc<Extensions>) -> Self {
+    pub fn new(command_path: Vec<String>, app_state: Arc<Extensions>) -> Self {
         Self {
             command_path,
             app_state,
             extensions: Extensions::new(),
+            cancellation: CancellationToken::new(),
+            verbosity: Verbosity::Normal,
+        }
+    }
+
+    /// Returns true if the command has been interrupted.
+    ///
+    /// Shorthand for `ctx.cancellation.is_cancelled()`.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation.is_cancelled()
+    }
+
+    /// Deserializes the app's configuration into `T`.
+    ///
+    /// The configuration is the [`ConfigValues`] in `extensions`, which the
+    /// app's config loader inserts before dispatch. Without one, `T` is
+    /// deserialized from an empty table, so fields with `#[serde(default)]`
+    /// get their defaults.
+    ///
+    /// String values, as environment variables and `--set` overrides give,
+    /// are read as numbers or booleans where `T` expects one, and stay
+    /// strings otherwise.
+    ///
+    /// ```rust
+    /// use standout_dispatch::{CommandContext, ConfigValues};
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Settings {
+    ///     #[serde(default)]
+    ///     editor: Option<String>,
+    /// }
+    ///
+    /// let mut ctx = CommandContext::default();
+    /// ctx.extensions
+    ///     .insert(ConfigValues(serde_json::json!({ "editor": "vim" })));
+    /// let settings: Settings = ctx.config().unwrap();
+    /// assert_eq!(settings.editor.as_deref(), Some("vim"));
+    /// ```
+    pub fn config<T: DeserializeOwned>(&self) -> anyhow::Result<T> {
+        let empty = serde_json::Value::Object(Default::default());
+        let values = match self.extensions.get::<ConfigValues>() {
+            Some(ConfigValues(values)) => values,
+            None => &empty,
+        };
+        crate::config::from_config(values)
+            .map_err(|e| anyhow::anyhow!("invalid configuration: {}", e))
+    }
+
+    /// Returns the app's config, data, cache and state directories.
+    ///
+    /// These are the [`AppPaths`] in `extensions`, which the app inserts
+    /// before dispatch. `None` when the context wasn't built by an app (for
+    /// example in a handler unit test).
+    pub fn paths(&self) -> Option<&AppPaths> {
+        self.extensions.get::<AppPaths>()
+    }
+
+    /// Returns a handle for reporting the progress of a long operation.
+    ///
+    /// The handle draws through the [`ProgressReporter`] in `extensions`,
+    /// which the app inserts when progress can be shown (standout does when
+    /// stderr is a terminal and `-q` wasn't given). Otherwise it is silent,
+    /// so handlers can report progress without checking.
+    ///
+    /// ```rust,ignore
+    /// let progress = ctx.progress();
+    /// progress.set_message("uploading");
+    /// progress.set_total(files.len() as u64);
+    /// for file in &files {
+    ///     upload(file)?;
+    ///     progress.inc(1);
+    /// }
+    /// ```
+    pub fn progress(&self) -> Progress {
+        match self.extensions.get::<ProgressReporter>() {
+            Some(ProgressReporter(sink)) => Progress::new(sink.clone()),
+            None => Progress::silent(),
         }
     }
 }
 
+/// The app's merged configuration, read by [`CommandContext::config`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConfigValues(pub serde_json::Value);
+
+/// Well-known per-app directories, returned by [`CommandContext::paths`].
+///
+/// The directories aren't created; call `std::fs::create_dir_all` before
+/// writing to one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AppPaths {
+    /// User configuration (config files, user themes).
+    pub config: PathBuf,
+    /// User data that should be kept (databases, downloaded resources).
+    pub data: PathBuf,
+    /// Data that can be regenerated and safely deleted.
+    pub cache: PathBuf,
+    /// State worth keeping across runs but not backing up (history, logs).
+    pub state: PathBuf,
+}
+
 impl Default for CommandContext {
     fn default() -> Self {
         Self {
             command_path: Vec::new(),
-            app_state: Rc::new(Extensions::new()),
+            app_state: Arc::new(Extensions::new()),
             extensions: Extensions::new(),
+            cancellation: CancellationToken::new(),
+            verbosity: Verbosity::Normal,
+        }
+    }
+}
+
+/// How much a command should say, from the `-q` / `-v` flags.
+///
+/// Levels are ordered, so `ctx.verbosity >= Verbosity::Verbose` checks for
+/// "`-v` or more".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Verbosity {
+    /// `-q`: only errors
+    Quiet,
+    /// No flags (the default)
+    #[default]
+    Normal,
+    /// `-v`
+    Verbose,
+    /// `-vv`
+    Debug,
+    /// `-vvv` or more
+    Trace,
+}
+
+impl Verbosity {
+    /// Maps `-q` and the number of `-v` occurrences to a level.
+    ///
+    /// `quiet` wins over any `-v`.
+    pub fn from_flags(quiet: bool, verbose: u8) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Verbosity::Quiet,
+            (false, 0) => Verbosity::Normal,
+            (false, 1) => Verbosity::Verbose,
+            (false, 2) => Verbosity::Debug,
+            (false, _) => Verbosity::Trace,
         }
     }
+
+    /// Returns true for `-q`.
+    pub fn is_quiet(self) -> bool {
+        self == Verbosity::Quiet
+    }
+
+    /// Returns true for `-v` or more.
+    pub fn is_verbose(self) -> bool {
+        self >= Verbosity::Verbose
+    }
+}
+
+/// A flag shared between a running command and whoever may cancel it.
+///
+/// Clones share the same flag: cancelling any clone cancels them all. The
+/// token is `Send + Sync`, so it can be handed to a signal handler or a worker
+/// thread while the handler keeps its own copy to poll.
+///
+/// ```rust
+/// use standout_dispatch::CancellationToken;
+///
+/// let token = CancellationToken::new();
+/// let signal_side = token.clone();
+///
+/// assert!(!token.is_cancelled());
+/// signal_side.cancel();
+/// assert!(token.is_cancelled());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Creates a token that hasn't been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks the token, and every clone of it, as cancelled.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns true once [`cancel`](Self::cancel) has been called on any clone.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+/// Stream that rendered handler output is written to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Destination {
+    /// Standard output, for the command's data (the default)
+    #[default]
+    Stdout,
+    /// Standard error, for diagnostics and progress that shouldn't end up in pipes
+    Stderr,
 }
 
 /// What a handler produces.
 ///
 /// This enum represents the different types of output a command handler can produce.
-#[derive(Debug)]
 pub enum Output<T: Serialize> {
     /// Data to render with a template or serialize to JSON/YAML/etc.
     Render(T),
+    /// Data to render like [`Output::Render`], written to the given stream.
+    ///
+    /// `Output::RenderTo(Destination::Stderr, report)` keeps a diagnostic or
+    /// progress report out of piped stdout. `Output::Render(data)` is the same
+    /// as `Output::RenderTo(Destination::Stdout, data)`.
+    RenderTo(Destination, T),
     /// Silent exit (no output produced)
     Silent,
     /// Binary output for file exports
+    ///
+    /// The bytes are never converted to text: they're written to
+    /// `--output-file-path`, streamed to stdout when it's piped, or saved to
+    /// `filename`. The filename's extension also determines the MIME type
+    /// (see [`Output::mime_type`]).
     Binary {
         /// The binary data
         data: Vec<u8>,
         /// Suggested filename for the output
         filename: String,
     },
+    /// Items rendered one at a time, as they arrive.
+    ///
+    /// Each item is rendered through the command's template (or written as
+    /// one line of JSON with `--output json`) and, when the app is run, printed
+    /// as soon as it's yielded, so `tail`-like commands show output
+    /// immediately. Iteration stops early when the command is interrupted.
+    /// Build one with [`Output::stream`].
+    Stream(Box<dyn Iterator<Item = T>>),
+}
+
+impl<T: Serialize + fmt::Debug> fmt::Debug for Output<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Output::Render(data) => f.debug_tuple("Render").field(data).finish(),
+            Output::RenderTo(destination, data) => f
+                .debug_tuple("RenderTo")
+                .field(destination)
+                .field(data)
+                .finish(),
+            Output::Silent => f.write_str("Silent"),
+            Output::Binary { data, filename } => f
+                .debug_struct("Binary")
+                .field("data", data)
+                .field("filename", filename)
+                .finish(),
+            Output::Stream(_) => f.write_str("Stream(..)"),
+        }
+    }
 }
 
 impl<T: Serialize> Output<T> {
-    /// Returns true if this is a render result.
+    /// Streams `items`, rendering each as it arrives.
+    ///
+    /// Takes any iterator, including the receiving end of a channel:
+    ///
+    /// ```rust
+    /// use standout_dispatch::Output;
+    ///
+    /// let (tx, rx) = std::sync::mpsc::channel();
+    /// std::thread::spawn(move || {
+    ///     for line in ["started", "done"] {
+    ///         tx.send(line.to_string()).unwrap();
+    ///     }
+    /// });
+    /// let output = Output::stream(rx);
+    /// assert!(output.is_stream());
+    /// ```
+    pub fn stream<I>(items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: 'static,
+    {
+        Output::Stream(Box::new(items.into_iter()))
+    }
+
+    /// Returns true if this is a render result ([`Output::Render`] or [`Output::RenderTo`]).
     pub fn is_render(&self) -> bool {
-        matches!(self, Output::Render(_))
+        matches!(self, Output::Render(_) | Output::RenderTo(_, _))
+    }
+
+    /// Returns the stream a render result is written to, or `None` if nothing is rendered.
+    pub fn destination(&self) -> Option<Destination> {
+        match self {
+            Output::Render(_) => Some(Destination::Stdout),
+            Output::RenderTo(destination, _) => Some(*destination),
+            Output::Stream(_) => Some(Destination::Stdout),
+            Output::Silent | Output::Binary { .. } => None,
+        }
+    }
+
+    /// Returns true if this is a stream of items.
+    pub fn is_stream(&self) -> bool {
+        matches!(self, Output::Stream(_))
     }
 
     /// Returns true if this is a silent result.
@@ -366,6 +653,43 @@ impl<T: Serialize> Output<T> {
     pub fn is_binary(&self) -> bool {
         matches!(self, Output::Binary { .. })
     }
+
+    /// Returns the MIME type of a binary result, guessed from its filename.
+    pub fn mime_type(&self) -> Option<&'static str> {
+        match self {
+            Output::Binary { filename, .. } => Some(mime_type_for(filename)),
+            _ => None,
+        }
+    }
+}
+
+/// Guesses the MIME type of binary output from its filename extension.
+///
+/// Unknown extensions map to `application/octet-stream`.
+pub fn mime_type_for(filename: &str) -> &'static str {
+    let extension = std::path::Path::new(filename)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "gz" => "application/gzip",
+        "tar" => "application/x-tar",
+        "wasm" => "application/wasm",
+        "json" => "application/json",
+        "csv" => "text/csv",
+        "html" | "htm" => "text/html",
+        "txt" => "text/plain",
+        _ => "application/octet-stream",
+    }
 }
 
 /// The result type for command handlers.
@@ -427,6 +751,93 @@ impl<T: Serialize> IntoHandlerResult<T> for HandlerResult<T> {
     }
 }
 
+/// A handler error that carries the process exit code to use.
+///
+/// Handlers return errors as `anyhow::Error`, so any error type works and
+/// exits with code 1. Wrapping the error in an `ExitError` picks a different
+/// code; the dispatcher finds it anywhere in the error's context chain.
+///
+/// # Example
+///
+/// ```rust
+/// use standout_dispatch::{ExitError, HandlerResult};
+///
+/// fn show(id: u32) -> HandlerResult<String> {
+///     Err(ExitError::not_found(anyhow::anyhow!("no item with id {}", id)).into())
+/// }
+///
+/// let err = show(7).unwrap_err();
+/// assert_eq!(ExitError::code_of(&err), 3);
+/// assert_eq!(err.to_string(), "no item with id 7");
+/// ```
+#[derive(Debug)]
+pub struct ExitError {
+    /// Process exit code
+    pub code: u8,
+    /// The underlying error, whose message is displayed
+    pub source: anyhow::Error,
+}
+
+impl ExitError {
+    /// Exit code for invalid arguments or input.
+    pub const USAGE: u8 = 2;
+    /// Exit code for a requested resource that doesn't exist.
+    pub const NOT_FOUND: u8 = 3;
+    /// Exit code for a command that ran out of time, as `timeout(1)` uses.
+    pub const TIMEOUT: u8 = 124;
+
+    /// Wraps an error with the given exit code.
+    pub fn new(code: u8, source: impl Into<anyhow::Error>) -> Self {
+        Self {
+            code,
+            source: source.into(),
+        }
+    }
+
+    /// Wraps an error as a usage error (exit code 2).
+    pub fn usage(source: impl Into<anyhow::Error>) -> Self {
+        Self::new(Self::USAGE, source)
+    }
+
+    /// Wraps an error as a not-found error (exit code 3).
+    pub fn not_found(source: impl Into<anyhow::Error>) -> Self {
+        Self::new(Self::NOT_FOUND, source)
+    }
+
+    /// A usage error for an argument value that couldn't be parsed.
+    ///
+    /// The message names the argument by its id: `invalid value 'x' for
+    /// 'limit': invalid digit found in string`.
+    pub fn invalid_value(arg: &str, value: &str, error: impl fmt::Display) -> Self {
+        Self::usage(anyhow::anyhow!(
+            "invalid value '{}' for '{}': {}",
+            value,
+            arg,
+            error
+        ))
+    }
+
+    /// Returns the exit code for a handler error: the code of the first
+    /// `ExitError` in its chain, or 1.
+    pub fn code_of(err: &anyhow::Error) -> u8 {
+        err.chain()
+            .find_map(|cause| cause.downcast_ref::<ExitError>())
+            .map_or(1, |exit| exit.code)
+    }
+}
+
+impl fmt::Display for ExitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.source, f)
+    }
+}
+
+impl std::error::Error for ExitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source.source()
+    }
+}
+
 /// Result of running the CLI dispatcher.
 ///
 /// After processing arguments, the dispatcher either handles a command,
@@ -439,6 +850,9 @@ impl<T: Serialize> IntoHandlerResult<T> for HandlerResult<T> {
 pub enum RunResult {
     /// A handler processed the command successfully; contains the rendered output
     Handled(String),
+    /// A handler rendered output for stderr via
+    /// `Output::RenderTo(Destination::Stderr, _)`; contains the rendered output
+    Stderr(String),
     /// A handler produced binary output (bytes, suggested filename)
     Binary(Vec<u8>, String),
     /// Silent output (handler completed but produced no output)
@@ -446,6 +860,9 @@ pub enum RunResult {
     /// A handler, hook, or output step failed; contains the formatted error message.
     /// Consumers should write this to stderr and exit non-zero.
     Error(String),
+    /// A command failed with an exit code other than 1 (message, exit code),
+    /// e.g. from an [`ExitError`] or a command-line usage error.
+    Exit(String, u8),
     /// No handler matched; contains the ArgMatches for manual handling
     NoMatch(ArgMatches),
 }
@@ -466,9 +883,19 @@ impl RunResult {
         matches!(self, RunResult::Silent)
     }
 
-    /// Returns true if the result is an error.
+    /// Creates a failed result, using [`RunResult::Error`] for exit code 1
+    /// and [`RunResult::Exit`] for any other code.
+    pub fn failure(message: impl Into<String>, exit_code: u8) -> Self {
+        if exit_code == 1 {
+            RunResult::Error(message.into())
+        } else {
+            RunResult::Exit(message.into(), exit_code)
+        }
+    }
+
+    /// Returns true if the result is an error ([`RunResult::Error`] or [`RunResult::Exit`]).
     pub fn is_error(&self) -> bool {
-        matches!(self, RunResult::Error(_))
+        matches!(self, RunResult::Error(_) | RunResult::Exit(_, _))
     }
 
     /// Returns the output if handled, or None otherwise.
@@ -479,14 +906,37 @@ impl RunResult {
         }
     }
 
+    /// Returns the output if it was rendered for stderr, or None otherwise.
+    pub fn stderr(&self) -> Option<&str> {
+        match self {
+            RunResult::Stderr(s) => Some(s),
+            _ => None,
+        }
+    }
+
     /// Returns the error message if this is an error, or None otherwise.
     pub fn error(&self) -> Option<&str> {
         match self {
-            RunResult::Error(s) => Some(s),
+            RunResult::Error(s) | RunResult::Exit(s, _) => Some(s),
             _ => None,
         }
     }
 
+    /// Returns the process exit code for this result: 0 on success, 1 for
+    /// [`RunResult::Error`], the carried code for [`RunResult::Exit`], and 2
+    /// (clap's usage-error convention) when no handler matched.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            RunResult::Handled(_)
+            | RunResult::Stderr(_)
+            | RunResult::Binary(_, _)
+            | RunResult::Silent => 0,
+            RunResult::Error(_) => 1,
+            RunResult::Exit(_, code) => *code,
+            RunResult::NoMatch(_) => 2,
+        }
+    }
+
     /// Returns the binary data and filename if binary, or None otherwise.
     pub fn binary(&self) -> Option<(&[u8], &str)> {
         match self {
@@ -495,6 +945,11 @@ impl RunResult {
         }
     }
 
+    /// Returns the MIME type of binary output, guessed from its filename.
+    pub fn mime_type(&self) -> Option<&'static str> {
+        self.binary().map(|(_, filename)| mime_type_for(filename))
+    }
+
     /// Returns the matches if unhandled, or None if handled.
     pub fn matches(&self) -> Option<&ArgMatches> {
         match self {
@@ -673,12 +1128,58 @@ mod tests {
     fn test_command_context_creation() {
         let ctx = CommandContext {
             command_path: vec!["config".into(), "get".into()],
-            app_state: Rc::new(Extensions::new()),
+            app_state: Arc::new(Extensions::new()),
             extensions: Extensions::new(),
+            cancellation: CancellationToken::new(),
+            verbosity: Verbosity::Normal,
         };
         assert_eq!(ctx.command_path, vec!["config", "get"]);
     }
 
+    #[test]
+    fn test_verbosity_from_flags() {
+        assert_eq!(Verbosity::from_flags(false, 0), Verbosity::Normal);
+        assert_eq!(Verbosity::from_flags(false, 1), Verbosity::Verbose);
+        assert_eq!(Verbosity::from_flags(false, 2), Verbosity::Debug);
+        assert_eq!(Verbosity::from_flags(false, 5), Verbosity::Trace);
+        assert_eq!(Verbosity::from_flags(true, 2), Verbosity::Quiet);
+        assert!(Verbosity::Debug.is_verbose());
+        assert!(!Verbosity::Normal.is_verbose());
+    }
+
+    #[test]
+    fn test_command_context_config() {
+        #[derive(serde::Deserialize, Debug, PartialEq)]
+        struct Settings {
+            #[serde(default)]
+            limit: u32,
+        }
+
+        let mut ctx = CommandContext::default();
+        assert_eq!(ctx.config::<Settings>().unwrap(), Settings { limit: 0 });
+
+        ctx.extensions
+            .insert(ConfigValues(serde_json::json!({ "limit": "many" })));
+        let err = ctx.config::<Settings>().unwrap_err();
+        assert!(
+            err.to_string().starts_with("invalid configuration"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn test_command_context_paths() {
+        let mut ctx = CommandContext::default();
+        assert!(ctx.paths().is_none());
+
+        let paths = AppPaths {
+            cache: PathBuf::from("/tmp/cache/app"),
+            ..Default::default()
+        };
+        ctx.extensions.insert(paths.clone());
+        assert_eq!(ctx.paths(), Some(&paths));
+    }
+
     #[test]
     fn test_command_context_default() {
         let ctx = CommandContext::default();
@@ -702,13 +1203,15 @@ mod tests {
             url: "postgres://localhost".into(),
         });
         app_state.insert(Config { debug: true });
-        let app_state = Rc::new(app_state);
+        let app_state = Arc::new(app_state);
 
         // Create context with app state
         let ctx = CommandContext {
             command_path: vec!["list".into()],
             app_state: app_state.clone(),
             extensions: Extensions::new(),
+            cancellation: CancellationToken::new(),
+            verbosity: Verbosity::Normal,
         };
 
         // Retrieve app state
@@ -718,8 +1221,8 @@ mod tests {
         let config = ctx.app_state.get::<Config>().unwrap();
         assert!(config.debug);
 
-        // App state is shared via Rc
-        assert_eq!(Rc::strong_count(&ctx.app_state), 2);
+        // App state is shared via Arc
+        assert_eq!(Arc::strong_count(&ctx.app_state), 2);
     }
 
     #[test]
@@ -731,8 +1234,10 @@ mod tests {
 
         let ctx = CommandContext {
             command_path: vec![],
-            app_state: Rc::new(app_state),
+            app_state: Arc::new(app_state),
             extensions: Extensions::new(),
+            cancellation: CancellationToken::new(),
+            verbosity: Verbosity::Normal,
         };
 
         // Success case
@@ -930,6 +1435,17 @@ mod tests {
         assert!(!output.is_binary());
     }
 
+    #[test]
+    fn test_output_render_to() {
+        let output: Output<String> = Output::RenderTo(Destination::Stderr, "50%".into());
+        assert!(output.is_render());
+        assert_eq!(output.destination(), Some(Destination::Stderr));
+
+        let output: Output<String> = Output::Render("data".into());
+        assert_eq!(output.destination(), Some(Destination::Stdout));
+        assert_eq!(Output::<String>::Silent.destination(), None);
+    }
+
     #[test]
     fn test_output_silent() {
         let output: Output<String> = Output::Silent;
@@ -959,6 +1475,16 @@ mod tests {
         assert!(result.matches().is_none());
     }
 
+    #[test]
+    fn test_run_result_stderr() {
+        let result = RunResult::Stderr("progress".into());
+        assert!(!result.is_handled());
+        assert!(!result.is_error());
+        assert_eq!(result.stderr(), Some("progress"));
+        assert_eq!(result.output(), None);
+        assert_eq!(result.exit_code(), 0);
+    }
+
     #[test]
     fn test_run_result_silent() {
         let result = RunResult::Silent;
@@ -978,6 +1504,15 @@ mod tests {
         let (data, filename) = result.binary().unwrap();
         assert_eq!(data, &bytes);
         assert_eq!(filename, "report.pdf");
+        assert_eq!(result.mime_type(), Some("application/pdf"));
+    }
+
+    #[test]
+    fn test_mime_type_for() {
+        assert_eq!(mime_type_for("chart.PNG"), "image/png");
+        assert_eq!(mime_type_for("dump.tar.gz"), "application/gzip");
+        assert_eq!(mime_type_for("data.bin"), "application/octet-stream");
+        assert_eq!(mime_type_for("noext"), "application/octet-stream");
     }
 
     #[test]
@@ -987,6 +1522,56 @@ mod tests {
         assert!(!result.is_handled());
         assert!(!result.is_binary());
         assert!(result.matches().is_some());
+        assert_eq!(result.exit_code(), 2);
+    }
+
+    #[test]
+    fn test_run_result_failure() {
+        let result = RunResult::failure("boom", 1);
+        assert!(matches!(result, RunResult::Error(_)));
+        assert_eq!(result.exit_code(), 1);
+
+        let result = RunResult::failure("missing", 3);
+        assert!(result.is_error());
+        assert_eq!(result.error(), Some("missing"));
+        assert_eq!(result.exit_code(), 3);
+    }
+
+    #[test]
+    fn test_exit_error_codes() {
+        assert_eq!(ExitError::usage(anyhow::anyhow!("x")).code, 2);
+        assert_eq!(ExitError::not_found(anyhow::anyhow!("x")).code, 3);
+        assert_eq!(ExitError::new(42, anyhow::anyhow!("x")).code, 42);
+    }
+
+    #[test]
+    fn test_exit_error_invalid_value() {
+        let err = ExitError::invalid_value("limit", "ten", "invalid digit found in string");
+        assert_eq!(err.code, ExitError::USAGE);
+        assert_eq!(
+            err.to_string(),
+            "invalid value 'ten' for 'limit': invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_exit_error_displays_source() {
+        let err = ExitError::not_found(anyhow::anyhow!("no such item"));
+        assert_eq!(err.to_string(), "no such item");
+    }
+
+    #[test]
+    fn test_exit_error_found_through_context() {
+        let err = anyhow::Error::new(ExitError::not_found(anyhow::anyhow!("no such item")))
+            .context("while loading item");
+
+        assert_eq!(ExitError::code_of(&err), 3);
+    }
+
+    #[test]
+    fn test_exit_error_absent() {
+        let err = anyhow::anyhow!("plain failure");
+        assert_eq!(ExitError::code_of(&err), 1);
     }
 
     #[test]
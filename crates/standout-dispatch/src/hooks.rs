@@ -30,7 +30,7 @@
 //!   Use for: logging, clipboard copy, output filtering.
 
 use std::fmt;
-use std::rc::Rc;
+use std::sync::Arc;
 use thiserror::Error;
 
 use crate::handler::CommandContext;
@@ -140,6 +140,8 @@ pub enum HookPhase {
     PreDispatch,
     /// Error occurred during post-dispatch phase
     PostDispatch,
+    /// Error occurred during pre-render phase
+    PreRender,
     /// Error occurred during post-output phase
     PostOutput,
 }
@@ -149,6 +151,7 @@ impl fmt::Display for HookPhase {
         match self {
             HookPhase::PreDispatch => write!(f, "pre-dispatch"),
             HookPhase::PostDispatch => write!(f, "post-dispatch"),
+            HookPhase::PreRender => write!(f, "pre-render"),
             HookPhase::PostOutput => write!(f, "post-output"),
         }
     }
@@ -186,6 +189,15 @@ impl HookError {
         }
     }
 
+    /// Creates a new hook error for the pre-render phase.
+    pub fn pre_render(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            phase: HookPhase::PreRender,
+            source: None,
+        }
+    }
+
     /// Creates a new hook error for the post-output phase.
     pub fn post_output(message: impl Into<String>) -> Self {
         Self {
@@ -209,16 +221,39 @@ impl HookError {
 ///
 /// Pre-dispatch hooks receive mutable access to [`CommandContext`], allowing them
 /// to inject state into `ctx.extensions` that handlers can retrieve.
-pub type PreDispatchFn = Rc<dyn Fn(&ArgMatches, &mut CommandContext) -> Result<(), HookError>>;
+pub type PreDispatchFn =
+    Arc<dyn Fn(&ArgMatches, &mut CommandContext) -> Result<(), HookError> + Send + Sync>;
 
 /// Type alias for post-dispatch hook functions.
-pub type PostDispatchFn = Rc<
-    dyn Fn(&ArgMatches, &CommandContext, serde_json::Value) -> Result<serde_json::Value, HookError>,
+pub type PostDispatchFn = Arc<
+    dyn Fn(&ArgMatches, &CommandContext, serde_json::Value) -> Result<serde_json::Value, HookError>
+        + Send
+        + Sync,
+>;
+
+/// What a command is about to render, as seen by pre-render hooks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderRequest {
+    /// The template to render with: a registered template name or inline
+    /// template content.
+    pub template: String,
+    /// The data the template receives.
+    pub data: serde_json::Value,
+}
+
+/// Type alias for pre-render hook functions.
+pub type PreRenderFn = Arc<
+    dyn Fn(&ArgMatches, &CommandContext, RenderRequest) -> Result<RenderRequest, HookError>
+        + Send
+        + Sync,
 >;
 
 /// Type alias for post-output hook functions.
-pub type PostOutputFn =
-    Rc<dyn Fn(&ArgMatches, &CommandContext, RenderedOutput) -> Result<RenderedOutput, HookError>>;
+pub type PostOutputFn = Arc<
+    dyn Fn(&ArgMatches, &CommandContext, RenderedOutput) -> Result<RenderedOutput, HookError>
+        + Send
+        + Sync,
+>;
 
 /// Per-command hook configuration.
 ///
@@ -227,6 +262,7 @@ pub type PostOutputFn =
 pub struct Hooks {
     pre_dispatch: Vec<PreDispatchFn>,
     post_dispatch: Vec<PostDispatchFn>,
+    pre_render: Vec<PreRenderFn>,
     post_output: Vec<PostOutputFn>,
 }
 
@@ -238,7 +274,10 @@ impl Hooks {
 
     /// Returns true if no hooks are registered.
     pub fn is_empty(&self) -> bool {
-        self.pre_dispatch.is_empty() && self.post_dispatch.is_empty() && self.post_output.is_empty()
+        self.pre_dispatch.is_empty()
+            && self.post_dispatch.is_empty()
+            && self.pre_render.is_empty()
+            && self.post_output.is_empty()
     }
 
     /// Adds a pre-dispatch hook.
@@ -263,9 +302,9 @@ impl Hooks {
     /// ```
     pub fn pre_dispatch<F>(mut self, f: F) -> Self
     where
-        F: Fn(&ArgMatches, &mut CommandContext) -> Result<(), HookError> + 'static,
+        F: Fn(&ArgMatches, &mut CommandContext) -> Result<(), HookError> + Send + Sync + 'static,
     {
-        self.pre_dispatch.push(Rc::new(f));
+        self.pre_dispatch.push(Arc::new(f));
         self
     }
 
@@ -277,9 +316,39 @@ impl Hooks {
                 &CommandContext,
                 serde_json::Value,
             ) -> Result<serde_json::Value, HookError>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.post_dispatch.push(Arc::new(f));
+        self
+    }
+
+    /// Adds a pre-render hook.
+    ///
+    /// Pre-render hooks run after post-dispatch hooks, right before
+    /// rendering. They can swap the template or reshape the data, for
+    /// example to wrap a page of results with pagination metadata.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use standout_dispatch::Hooks;
+    ///
+    /// let hooks = Hooks::new().pre_render(|_matches, _ctx, mut request| {
+    ///     request.data = serde_json::json!({ "items": request.data, "page": 1 });
+    ///     request.template = "paginated.jinja".into();
+    ///     Ok(request)
+    /// });
+    /// ```
+    pub fn pre_render<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&ArgMatches, &CommandContext, RenderRequest) -> Result<RenderRequest, HookError>
+            + Send
+            + Sync
             + 'static,
     {
-        self.post_dispatch.push(Rc::new(f));
+        self.pre_render.push(Arc::new(f));
         self
     }
 
@@ -287,12 +356,49 @@ impl Hooks {
     pub fn post_output<F>(mut self, f: F) -> Self
     where
         F: Fn(&ArgMatches, &CommandContext, RenderedOutput) -> Result<RenderedOutput, HookError>
+            + Send
+            + Sync
             + 'static,
     {
-        self.post_output.push(Rc::new(f));
+        self.post_output.push(Arc::new(f));
         self
     }
 
+    /// Returns these hooks wrapped by `outer`.
+    ///
+    /// `outer`'s pre-dispatch hooks run before these, and its post-dispatch,
+    /// pre-render and post-output hooks run after these, so `outer` sees the command
+    /// first on the way in and last on the way out. This is how app-wide
+    /// middleware is layered around per-command hooks.
+    pub fn wrapped_by(&self, outer: &Hooks) -> Hooks {
+        Hooks {
+            pre_dispatch: outer
+                .pre_dispatch
+                .iter()
+                .chain(&self.pre_dispatch)
+                .cloned()
+                .collect(),
+            post_dispatch: self
+                .post_dispatch
+                .iter()
+                .chain(&outer.post_dispatch)
+                .cloned()
+                .collect(),
+            pre_render: self
+                .pre_render
+                .iter()
+                .chain(&outer.pre_render)
+                .cloned()
+                .collect(),
+            post_output: self
+                .post_output
+                .iter()
+                .chain(&outer.post_output)
+                .cloned()
+                .collect(),
+        }
+    }
+
     /// Runs all pre-dispatch hooks.
     ///
     /// Hooks receive mutable access to the context, allowing state injection.
@@ -321,6 +427,20 @@ impl Hooks {
         Ok(current)
     }
 
+    /// Runs all pre-render hooks, chaining transformations.
+    pub fn run_pre_render(
+        &self,
+        matches: &ArgMatches,
+        ctx: &CommandContext,
+        request: RenderRequest,
+    ) -> Result<RenderRequest, HookError> {
+        let mut current = request;
+        for hook in &self.pre_render {
+            current = hook(matches, ctx, current)?;
+        }
+        Ok(current)
+    }
+
     /// Runs all post-output hooks, chaining transformations.
     pub fn run_post_output(
         &self,
@@ -341,6 +461,7 @@ impl fmt::Debug for Hooks {
         f.debug_struct("Hooks")
             .field("pre_dispatch_count", &self.pre_dispatch.len())
             .field("post_dispatch_count", &self.post_dispatch.len())
+            .field("pre_render_count", &self.pre_render.len())
             .field("post_output_count", &self.post_output.len())
             .finish()
     }
@@ -399,14 +520,13 @@ mod tests {
 
     #[test]
     fn test_pre_dispatch_success() {
-        use std::cell::Cell;
-        use std::rc::Rc;
+        use std::sync::atomic::{AtomicBool, Ordering};
 
-        let called = Rc::new(Cell::new(false));
+        let called = Arc::new(AtomicBool::new(false));
         let called_clone = called.clone();
 
         let hooks = Hooks::new().pre_dispatch(move |_, _| {
-            called_clone.set(true);
+            called_clone.store(true, Ordering::SeqCst);
             Ok(())
         });
 
@@ -415,7 +535,7 @@ mod tests {
         let result = hooks.run_pre_dispatch(&matches, &mut ctx);
 
         assert!(result.is_ok());
-        assert!(called.get());
+        assert!(called.load(Ordering::SeqCst));
     }
 
     #[test]
@@ -504,6 +624,37 @@ mod tests {
         assert_eq!(output["modified"], true);
     }
 
+    #[test]
+    fn test_pre_render_swaps_template_and_wraps_data() {
+        use serde_json::json;
+
+        let hooks = Hooks::new()
+            .pre_render(|_, _, request| {
+                Ok(RenderRequest {
+                    template: "paginated".into(),
+                    data: json!({ "items": request.data, "page": 1 }),
+                })
+            })
+            .pre_render(|_, _, mut request| {
+                request.data["pages"] = json!(3);
+                Ok(request)
+            });
+
+        let request = RenderRequest {
+            template: "list".into(),
+            data: json!([1, 2]),
+        };
+        let result = hooks
+            .run_pre_render(&test_matches(), &test_context(), request)
+            .unwrap();
+
+        assert_eq!(result.template, "paginated");
+        assert_eq!(
+            result.data,
+            json!({ "items": [1, 2], "page": 1, "pages": 3 })
+        );
+    }
+
     #[test]
     fn test_post_output_transformation() {
         let hooks = Hooks::new().post_output(|_, _, output| {
@@ -525,4 +676,50 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.unwrap().as_text(), Some("HELLO"));
     }
+
+    #[test]
+    fn test_wrapped_by_orders_outer_hooks_around_inner() {
+        use std::sync::Mutex;
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let hooks = |name: &'static str| {
+            let (pre, post, out) = (log.clone(), log.clone(), log.clone());
+            Hooks::new()
+                .pre_dispatch(move |_, _| {
+                    pre.lock().unwrap().push(format!("pre:{}", name));
+                    Ok(())
+                })
+                .post_dispatch(move |_, _, data| {
+                    post.lock().unwrap().push(format!("post:{}", name));
+                    Ok(data)
+                })
+                .post_output(move |_, _, output| {
+                    out.lock().unwrap().push(format!("output:{}", name));
+                    Ok(output)
+                })
+        };
+
+        let combined = hooks("inner").wrapped_by(&hooks("outer"));
+        let mut ctx = test_context();
+        let matches = test_matches();
+        combined.run_pre_dispatch(&matches, &mut ctx).unwrap();
+        combined
+            .run_post_dispatch(&matches, &ctx, serde_json::json!({}))
+            .unwrap();
+        combined
+            .run_post_output(&matches, &ctx, RenderedOutput::Silent)
+            .unwrap();
+
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "pre:outer",
+                "pre:inner",
+                "post:inner",
+                "post:outer",
+                "output:inner",
+                "output:outer"
+            ]
+        );
+    }
 }
@@ -59,7 +59,7 @@
 //! [`CommandContext`] provides two mechanisms for dependency injection:
 //!
 //! - **`app_state`**: Immutable, app-lifetime state (database, config, API clients).
-//!   Configured at app build time, shared across all dispatches via `Rc<Extensions>`.
+//!   Configured at app build time, shared across all dispatches via `Arc<Extensions>`.
 //!
 //! - **`extensions`**: Mutable, per-request state. Injected by pre-dispatch hooks
 //!   for request-scoped data like user sessions or request IDs.
@@ -120,9 +120,11 @@
 //! the template registry, theme, and output format from CLI args.
 
 // Core modules
+mod config;
 mod dispatch;
 mod handler;
 mod hooks;
+mod progress;
 mod render;
 pub mod verify;
 
@@ -134,15 +136,19 @@ pub use dispatch::{
 
 // Re-export handler types
 pub use handler::{
-    CommandContext, Extensions, FnHandler, Handler, HandlerResult, IntoHandlerResult, Output,
-    RunResult, SimpleFnHandler,
+    mime_type_for, AppPaths, CancellationToken, CommandContext, ConfigValues, Destination,
+    ExitError, Extensions, FnHandler, Handler, HandlerResult, IntoHandlerResult, Output, RunResult,
+    SimpleFnHandler, Verbosity,
 };
 
 // Re-export hook types
 pub use hooks::{
-    HookError, HookPhase, Hooks, PostDispatchFn, PostOutputFn, PreDispatchFn, RenderedOutput,
-    TextOutput,
+    HookError, HookPhase, Hooks, PostDispatchFn, PostOutputFn, PreDispatchFn, PreRenderFn,
+    RenderRequest, RenderedOutput, TextOutput,
 };
 
+// Re-export progress reporting
+pub use progress::{Progress, ProgressKind, ProgressReporter, ProgressSink, ProgressState};
+
 // Re-export render abstraction
 pub use render::{from_fn, RenderError, RenderFn};
@@ -0,0 +1,254 @@
+//! Progress reporting for long-running handlers.
+//!
+//! [`CommandContext::progress`](crate::CommandContext::progress) returns a
+//! [`Progress`] handle. The handle only tracks state; drawing is left to the
+//! [`ProgressSink`] the app puts in `ctx.extensions` as a
+//! [`ProgressReporter`] (standout draws a themed line on stderr when it's a
+//! terminal). Without one the handle is silent, so handlers can report
+//! progress unconditionally without breaking piped or machine output.
+//!
+//! ```rust
+//! use standout_dispatch::CommandContext;
+//!
+//! let ctx = CommandContext::default();
+//! let progress = ctx.progress();
+//! progress.set_message("uploading");
+//! progress.set_total(3);
+//! for _ in 0..3 {
+//!     progress.inc(1);
+//! }
+//! progress.finish();
+//! assert_eq!(progress.state().fraction(), Some(1.0));
+//! ```
+
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::sync::Arc;
+
+/// How a progress line is displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProgressKind {
+    /// An animated spinner, for work of unknown size (the default)
+    #[default]
+    Spinner,
+    /// A bar with a percentage, once the total is known
+    Bar,
+    /// Only the percentage
+    Percent,
+}
+
+/// Where an operation is, as passed to a [`ProgressSink`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProgressState {
+    /// How the progress is displayed.
+    pub kind: ProgressKind,
+    /// What's being done.
+    pub message: String,
+    /// Units done so far.
+    pub position: u64,
+    /// Units in total, if known.
+    pub total: Option<u64>,
+}
+
+impl ProgressState {
+    /// Returns the share of the work done, between 0 and 1, if the total is
+    /// known.
+    pub fn fraction(&self) -> Option<f64> {
+        match self.total {
+            Some(0) => Some(1.0),
+            Some(total) => Some((self.position as f64 / total as f64).min(1.0)),
+            None => None,
+        }
+    }
+}
+
+/// Draws progress for a [`Progress`] handle.
+///
+/// Sinks are `Send + Sync` so the [`ProgressReporter`] holding one can be
+/// stored in `ctx.extensions`.
+pub trait ProgressSink: Send + Sync {
+    /// Draws `state`, replacing what was drawn before.
+    fn draw(&self, state: &ProgressState);
+
+    /// Removes the progress display.
+    fn clear(&self);
+}
+
+/// The sink `ctx.progress()` draws to, stored in `ctx.extensions`.
+#[derive(Clone)]
+pub struct ProgressReporter(pub Arc<dyn ProgressSink>);
+
+impl fmt::Debug for ProgressReporter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ProgressReporter")
+    }
+}
+
+/// A handle for reporting the progress of one operation.
+///
+/// Methods take `&self`, so the handle can be used from handlers that only
+/// have a shared context. The display is cleared by [`finish`](Self::finish)
+/// or when the handle is dropped.
+pub struct Progress {
+    sink: Option<Arc<dyn ProgressSink>>,
+    state: RefCell<ProgressState>,
+    finished: Cell<bool>,
+}
+
+impl Progress {
+    /// Creates a handle drawing to `sink`.
+    pub fn new(sink: Arc<dyn ProgressSink>) -> Self {
+        Self {
+            sink: Some(sink),
+            state: RefCell::default(),
+            finished: Cell::new(false),
+        }
+    }
+
+    /// Creates a handle that draws nothing.
+    pub fn silent() -> Self {
+        Self {
+            sink: None,
+            state: RefCell::default(),
+            finished: Cell::new(false),
+        }
+    }
+
+    /// Shows only the percentage instead of a bar once the total is known.
+    pub fn percent(self) -> Self {
+        self.state.borrow_mut().kind = ProgressKind::Percent;
+        self
+    }
+
+    /// Sets what's being done.
+    pub fn set_message(&self, message: impl Into<String>) {
+        self.update(|state| state.message = message.into());
+    }
+
+    /// Sets the total, turning a spinner into a bar.
+    pub fn set_total(&self, total: u64) {
+        self.update(|state| {
+            state.total = Some(total);
+            if state.kind == ProgressKind::Spinner {
+                state.kind = ProgressKind::Bar;
+            }
+        });
+    }
+
+    /// Sets the units done so far.
+    pub fn set_position(&self, position: u64) {
+        self.update(|state| state.position = position);
+    }
+
+    /// Adds `delta` units done.
+    pub fn inc(&self, delta: u64) {
+        self.update(|state| state.position += delta);
+    }
+
+    /// Redraws without changing the state, advancing a spinner.
+    pub fn tick(&self) {
+        self.update(|_| {});
+    }
+
+    /// Removes the progress display. Later updates are ignored.
+    pub fn finish(&self) {
+        if self.finished.replace(true) {
+            return;
+        }
+        if let Some(sink) = &self.sink {
+            sink.clear();
+        }
+    }
+
+    /// Returns the current state.
+    pub fn state(&self) -> ProgressState {
+        self.state.borrow().clone()
+    }
+
+    fn update(&self, f: impl FnOnce(&mut ProgressState)) {
+        f(&mut self.state.borrow_mut());
+        if self.finished.get() {
+            return;
+        }
+        if let Some(sink) = &self.sink {
+            sink.draw(&self.state.borrow());
+        }
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+impl fmt::Debug for Progress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Progress")
+            .field("state", &self.state.borrow())
+            .field("finished", &self.finished.get())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        drawn: std::sync::Mutex<Vec<ProgressState>>,
+        cleared: std::sync::atomic::AtomicUsize,
+    }
+
+    impl ProgressSink for Recorder {
+        fn draw(&self, state: &ProgressState) {
+            self.drawn.lock().unwrap().push(state.clone());
+        }
+
+        fn clear(&self) {
+            self.cleared
+                .fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_progress_draws_updates_and_clears_once() {
+        let recorder = Arc::new(Recorder::default());
+        let progress = Progress::new(recorder.clone());
+
+        progress.set_message("copying");
+        progress.set_total(4);
+        progress.inc(1);
+        progress.finish();
+        progress.inc(1);
+        drop(progress);
+
+        let drawn = recorder.drawn.lock().unwrap();
+        assert_eq!(drawn.len(), 3);
+        assert_eq!(drawn[0].kind, ProgressKind::Spinner);
+        assert_eq!(
+            drawn[2],
+            ProgressState {
+                kind: ProgressKind::Bar,
+                message: "copying".into(),
+                position: 1,
+                total: Some(4),
+            }
+        );
+        assert_eq!(
+            recorder.cleared.load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+    }
+
+    #[test]
+    fn test_percent_kind_survives_total() {
+        let progress = Progress::silent().percent();
+        progress.set_total(10);
+        progress.set_position(15);
+
+        assert_eq!(progress.state().kind, ProgressKind::Percent);
+        assert_eq!(progress.state().fraction(), Some(1.0));
+    }
+}
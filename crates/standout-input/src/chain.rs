@@ -13,6 +13,9 @@ use crate::InputError;
 /// Validator function type.
 type ValidatorFn<T> = Box<dyn Fn(&T) -> Result<(), String> + Send + Sync>;
 
+/// Reporter for validation errors before a retry.
+type InvalidFn = Box<dyn Fn(&str) + Send + Sync>;
+
 /// Chain multiple input sources with fallback behavior.
 ///
 /// Sources are tried in the order they were added. The first source that
@@ -44,10 +47,22 @@ type ValidatorFn<T> = Box<dyn Fn(&T) -> Result<(), String> + Send + Sync>;
 /// ```
 ///
 /// Interactive sources (prompts, editor) can retry on validation failure.
+/// Limit the attempts with [`max_retries`](Self::max_retries) and control
+/// how the error is shown with [`on_invalid`](Self::on_invalid):
+///
+/// ```ignore
+/// let chain = InputChain::<String>::new()
+///     .try_source(TextPromptSource::new("Email: "))
+///     .validate(|s| s.contains('@'), "Must be a valid email")
+///     .max_retries(3)
+///     .on_invalid(|msg| eprintln!("{}", styled!(theme, "[error]{}[/error]", msg)));
+/// ```
 pub struct InputChain<T> {
     sources: Vec<(Box<dyn InputCollector<T>>, InputSourceKind)>,
     validators: Vec<(ValidatorFn<T>, String)>,
     default: Option<T>,
+    max_retries: Option<usize>,
+    on_invalid: InvalidFn,
 }
 
 impl<T: Clone + Send + Sync + 'static> InputChain<T> {
@@ -57,6 +72,8 @@ impl<T: Clone + Send + Sync + 'static> InputChain<T> {
             sources: Vec::new(),
             validators: Vec::new(),
             default: None,
+            max_retries: None,
+            on_invalid: Box::new(|msg| eprintln!("Invalid: {}", msg)),
         }
     }
 
@@ -121,6 +138,29 @@ impl<T: Clone + Send + Sync + 'static> InputChain<T> {
         self
     }
 
+    /// Limit how many times an interactive source is asked again after
+    /// invalid input.
+    ///
+    /// Once the retries are used up, the last validation error is returned.
+    /// Default is unlimited.
+    pub fn max_retries(mut self, retries: usize) -> Self {
+        self.max_retries = Some(retries);
+        self
+    }
+
+    /// Set how a validation error is shown before asking again.
+    ///
+    /// Called with the validator's message whenever an interactive source
+    /// gets invalid input and will retry. Default prints `Invalid: {msg}`
+    /// to stderr; use this to style the message with the app's theme.
+    pub fn on_invalid<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&str) + Send + Sync + 'static,
+    {
+        self.on_invalid = Box::new(f);
+        self
+    }
+
     /// Set a default value to use when no source provides input.
     ///
     /// This is equivalent to adding a [`DefaultSource`](crate::DefaultSource)
@@ -151,40 +191,29 @@ impl<T: Clone + Send + Sync + 'static> InputChain<T> {
                 continue;
             }
 
-            // This loop is intentional: interactive sources (where can_retry() is true)
-            // will re-prompt on validation failure. The `break` on None moves to the
-            // next source in the chain.
-            #[allow(clippy::while_let_loop)]
-            loop {
-                match source.collect(matches)? {
-                    Some(value) => {
-                        // Run source-level validation
-                        if let Err(msg) = source.validate(&value) {
-                            if source.can_retry() {
-                                eprintln!("Invalid: {}", msg);
-                                continue;
-                            }
-                            return Err(InputError::ValidationFailed(msg));
-                        }
-
-                        // Run chain-level validators
-                        for (validator, _) in &self.validators {
-                            if let Err(msg) = validator(&value) {
-                                if source.can_retry() {
-                                    eprintln!("Invalid: {}", msg);
-                                    continue;
-                                }
-                                return Err(InputError::ValidationFailed(msg));
-                            }
-                        }
-
-                        return Ok(ResolvedInput {
-                            value,
-                            source: *kind,
-                        });
-                    }
-                    None => break, // Try next source
+            // Interactive sources (where can_retry() is true) are asked again
+            // on validation failure; `None` moves to the next source.
+            let mut retries = 0;
+            while let Some(value) = source.collect(matches)? {
+                // Source-level validation first, then chain-level validators
+                let error = source.validate(&value).err().or_else(|| {
+                    self.validators
+                        .iter()
+                        .find_map(|(validator, _)| validator(&value).err())
+                });
+
+                let Some(msg) = error else {
+                    return Ok(ResolvedInput {
+                        value,
+                        source: *kind,
+                    });
+                };
+
+                if !source.can_retry() || self.max_retries.is_some_and(|max| retries >= max) {
+                    return Err(InputError::ValidationFailed(msg));
                 }
+                retries += 1;
+                (self.on_invalid)(&msg);
             }
         }
 
@@ -225,6 +254,7 @@ impl<T> fmt::Debug for InputChain<T> {
             )
             .field("validators", &self.validators.len())
             .field("has_default", &self.default.is_some())
+            .field("max_retries", &self.max_retries)
             .finish()
     }
 }
@@ -237,6 +267,7 @@ fn source_kind_from_name(name: &str) -> InputSourceKind {
         "stdin" => InputSourceKind::Stdin,
         "environment variable" => InputSourceKind::Env,
         "clipboard" => InputSourceKind::Clipboard,
+        "config" => InputSourceKind::Config,
         "editor" => InputSourceKind::Editor,
         "prompt" => InputSourceKind::Prompt,
         "default" => InputSourceKind::Default,
@@ -347,6 +378,47 @@ mod tests {
         assert!(matches!(result, Err(InputError::ValidationFailed(_))));
     }
 
+    #[cfg(feature = "simple-prompts")]
+    #[test]
+    fn chain_validation_retries_interactive_sources() {
+        use crate::sources::{MockTerminal, TextPromptSource};
+        use std::sync::{Arc, Mutex};
+
+        let shown = Arc::new(Mutex::new(Vec::new()));
+        let shown_in_reporter = shown.clone();
+        let chain = InputChain::<String>::new()
+            .try_source(TextPromptSource::with_terminal(
+                "Email: ",
+                MockTerminal::with_responses(["nope", "still nope", "a@b.c"]),
+            ))
+            .validate(|s| s.contains('@'), "Must contain @")
+            .on_invalid(move |msg| shown_in_reporter.lock().unwrap().push(msg.to_string()));
+
+        let result = chain.resolve(&make_matches(&["test"])).unwrap();
+        assert_eq!(result, "a@b.c");
+        assert_eq!(*shown.lock().unwrap(), ["Must contain @", "Must contain @"]);
+    }
+
+    #[cfg(feature = "simple-prompts")]
+    #[test]
+    fn chain_validation_stops_after_max_retries() {
+        use crate::sources::{MockTerminal, TextPromptSource};
+
+        let chain = InputChain::<String>::new()
+            .try_source(TextPromptSource::with_terminal(
+                "Email: ",
+                MockTerminal::with_responses(["a", "b", "c@d"]),
+            ))
+            .validate_with(|s| Err(format!("'{}' is not an email", s)))
+            .max_retries(1)
+            .on_invalid(|_| {});
+
+        let result = chain.resolve(&make_matches(&["test"]));
+        assert!(
+            matches!(result, Err(InputError::ValidationFailed(msg)) if msg == "'b' is not an email")
+        );
+    }
+
     #[test]
     fn chain_complex_fallback() {
         let matches = make_matches(&["test"]);
@@ -365,6 +437,49 @@ mod tests {
         assert_eq!(result.source, InputSourceKind::Clipboard);
     }
 
+    #[cfg(feature = "simple-prompts")]
+    #[test]
+    fn chain_config_precedence() {
+        use crate::env::MockConfig;
+        use crate::sources::{ConfigSource, MockTerminal, TextPromptSource};
+
+        // arg → env → config → prompt → default
+        let resolve = |args: &[&str], env: MockEnv, config: MockConfig, terminal: MockTerminal| {
+            InputChain::<String>::new()
+                .try_source(ArgSource::new("message"))
+                .try_source(EnvSource::with_reader("MY_MSG", env))
+                .try_source(ConfigSource::with_reader("message", config))
+                .try_source(TextPromptSource::with_terminal("Message: ", terminal))
+                .default("from default".to_string())
+                .resolve_with_source(&make_matches(args))
+                .unwrap()
+        };
+        let env = || MockEnv::new().with_var("MY_MSG", "from env");
+        let config = || MockConfig::new().with_value("message", "from config");
+        let prompt = || MockTerminal::with_response("from prompt");
+
+        let result = resolve(&["test", "-m", "from arg"], env(), config(), prompt());
+        assert_eq!(result.source, InputSourceKind::Arg);
+
+        let result = resolve(&["test"], env(), config(), prompt());
+        assert_eq!(result.source, InputSourceKind::Env);
+
+        let result = resolve(&["test"], MockEnv::new(), config(), prompt());
+        assert_eq!(result.value, "from config");
+        assert_eq!(result.source, InputSourceKind::Config);
+
+        let result = resolve(&["test"], MockEnv::new(), MockConfig::new(), prompt());
+        assert_eq!(result.source, InputSourceKind::Prompt);
+
+        let result = resolve(
+            &["test"],
+            MockEnv::new(),
+            MockConfig::new(),
+            MockTerminal::non_terminal(),
+        );
+        assert_eq!(result.source, InputSourceKind::Default);
+    }
+
     #[test]
     fn chain_has_available_source() {
         let matches = make_matches(&["test"]);
@@ -132,6 +132,8 @@ pub enum InputSourceKind {
     Env,
     /// From the system clipboard.
     Clipboard,
+    /// From the application's configuration.
+    Config,
     /// From an external editor.
     Editor,
     /// From an interactive prompt.
@@ -148,6 +150,7 @@ impl std::fmt::Display for InputSourceKind {
             Self::Stdin => write!(f, "stdin"),
             Self::Env => write!(f, "environment variable"),
             Self::Clipboard => write!(f, "clipboard"),
+            Self::Config => write!(f, "config"),
             Self::Editor => write!(f, "editor"),
             Self::Prompt => write!(f, "prompt"),
             Self::Default => write!(f, "default"),
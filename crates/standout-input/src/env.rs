@@ -15,9 +15,20 @@
 //! Tests can swap in a mock without touching handler code by calling
 //! [`set_default_stdin_reader`] / [`set_default_clipboard_reader`]. The
 //! `TestHarness` in the `standout-test` crate wires these automatically.
+//!
+//! [`ConfigSource::new`](crate::ConfigSource::new) works the same way with
+//! [`DefaultConfig`], except that there's no OS fallback: the app installs
+//! its loaded configuration with [`set_default_config_reader`].
+//!
+//! The configuration and the [`PromptMode`] can also be scoped to the
+//! current thread with [`scope_config_reader`] and [`scope_prompt_mode`].
+//! `standout` apps do this for each dispatch, so commands dispatched on
+//! several threads at once each see their own.
 
 use once_cell::sync::Lazy;
+use std::cell::{Cell, RefCell};
 use std::io::{self, IsTerminal, Read};
+use std::marker::PhantomData;
 use std::sync::{Arc, Mutex};
 
 use crate::InputError;
@@ -49,6 +60,101 @@ pub trait ClipboardReader: Send + Sync {
     fn read(&self) -> Result<Option<String>, InputError>;
 }
 
+/// Abstraction over the application's loaded configuration.
+pub trait ConfigReader: Send + Sync {
+    /// Get the value at a dot-separated path (`sync.remote`) as text.
+    ///
+    /// Returns `None` if the path isn't set or isn't a scalar.
+    fn get(&self, path: &str) -> Option<String>;
+}
+
+/// Whether the process looks like it runs in CI.
+///
+/// True when the `CI` environment variable is set to anything but an empty
+/// string, `0` or `false`, as CI services do.
+pub fn is_ci() -> bool {
+    ci_flag(std::env::var("CI").ok().as_deref())
+}
+
+fn ci_flag(value: Option<&str>) -> bool {
+    value
+        .map(|v| v.trim().to_ascii_lowercase())
+        .is_some_and(|v| !v.is_empty() && v != "0" && v != "false")
+}
+
+/// Whether interactive sources (prompts and editors) can ask the user.
+///
+/// Requires the [`PromptMode`] to be [`Ask`](PromptMode::Ask), stdin to be
+/// a terminal and the process not to run in CI (see [`is_ci`]), where a
+/// prompt would hang the job. When this is false, those sources report
+/// themselves unavailable and chains move on.
+pub fn is_interactive() -> bool {
+    prompt_mode() == PromptMode::Ask && io::stdin().is_terminal() && !is_ci()
+}
+
+/// Whether interactive sources may ask the user, as set by `--yes` or
+/// `--no-input` style flags.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PromptMode {
+    /// Ask when interactive (the default).
+    #[default]
+    Ask,
+    /// Never ask: confirmation prompts answer their default, or yes, and
+    /// other interactive sources are skipped.
+    AssumeYes,
+    /// Never ask: every interactive source is skipped, so chains fall
+    /// through to their defaults.
+    NoInput,
+}
+
+static PROMPT_MODE: Mutex<PromptMode> = Mutex::new(PromptMode::Ask);
+
+thread_local! {
+    static SCOPED_PROMPT_MODE: Cell<Option<PromptMode>> = const { Cell::new(None) };
+    static SCOPED_CONFIG: RefCell<Option<SharedConfig>> = const { RefCell::new(None) };
+}
+
+/// Sets the process-global [`PromptMode`].
+///
+/// A mode set with [`scope_prompt_mode`] takes precedence on its thread.
+pub fn set_prompt_mode(mode: PromptMode) {
+    *PROMPT_MODE.lock().unwrap() = mode;
+}
+
+/// Sets the [`PromptMode`] for the current thread until the returned guard
+/// is dropped, which restores the previous one.
+///
+/// `standout` apps with `prompt_flags()` scope the mode chosen by `--yes`
+/// and `--no-input` to each dispatch.
+pub fn scope_prompt_mode(mode: PromptMode) -> PromptModeScope {
+    PromptModeScope {
+        previous: SCOPED_PROMPT_MODE.with(|scoped| scoped.replace(Some(mode))),
+        _thread: PhantomData,
+    }
+}
+
+/// Restores the previous thread's [`PromptMode`] when dropped; see
+/// [`scope_prompt_mode`].
+#[must_use = "the prompt mode only holds while the scope is alive"]
+pub struct PromptModeScope {
+    previous: Option<PromptMode>,
+    _thread: PhantomData<*const ()>,
+}
+
+impl Drop for PromptModeScope {
+    fn drop(&mut self) {
+        SCOPED_PROMPT_MODE.with(|scoped| scoped.set(self.previous));
+    }
+}
+
+/// Returns the current [`PromptMode`]: the one scoped to this thread, if
+/// any, else the process-global one.
+pub fn prompt_mode() -> PromptMode {
+    SCOPED_PROMPT_MODE
+        .with(Cell::get)
+        .unwrap_or_else(|| *PROMPT_MODE.lock().unwrap())
+}
+
 // === Real implementations ===
 
 /// Real stdin reader using std::io.
@@ -229,6 +335,31 @@ impl ClipboardReader for MockClipboard {
     }
 }
 
+/// Mock configuration reader for testing.
+#[derive(Debug, Clone, Default)]
+pub struct MockConfig {
+    values: std::collections::HashMap<String, String>,
+}
+
+impl MockConfig {
+    /// Create an empty mock configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the value at a dot-separated path.
+    pub fn with_value(mut self, path: impl Into<String>, value: impl Into<String>) -> Self {
+        self.values.insert(path.into(), value.into());
+        self
+    }
+}
+
+impl ConfigReader for MockConfig {
+    fn get(&self, path: &str) -> Option<String> {
+        self.values.get(path).cloned()
+    }
+}
+
 // === Process-global default reader overrides ===
 //
 // `StdinSource::new()` and `ClipboardSource::new()` resolve their reader
@@ -239,9 +370,11 @@ impl ClipboardReader for MockClipboard {
 
 type SharedStdin = Arc<dyn StdinReader + Send + Sync>;
 type SharedClipboard = Arc<dyn ClipboardReader + Send + Sync>;
+type SharedConfig = Arc<dyn ConfigReader + Send + Sync>;
 
 static STDIN_OVERRIDE: Lazy<Mutex<Option<SharedStdin>>> = Lazy::new(|| Mutex::new(None));
 static CLIPBOARD_OVERRIDE: Lazy<Mutex<Option<SharedClipboard>>> = Lazy::new(|| Mutex::new(None));
+static CONFIG_OVERRIDE: Lazy<Mutex<Option<SharedConfig>>> = Lazy::new(|| Mutex::new(None));
 
 /// Installs a process-global stdin reader that [`DefaultStdin`] (and
 /// therefore [`StdinSource::new`](crate::StdinSource::new)) will delegate
@@ -271,6 +404,48 @@ pub fn reset_default_clipboard_reader() {
     *CLIPBOARD_OVERRIDE.lock().unwrap() = None;
 }
 
+/// Installs a process-global configuration that [`DefaultConfig`] (and
+/// therefore [`ConfigSource::new`](crate::ConfigSource::new)) will read
+/// until [`reset_default_config_reader`] is called.
+///
+/// `standout` apps with a config loader install the loaded configuration
+/// for the duration of each dispatch.
+pub fn set_default_config_reader(reader: SharedConfig) {
+    *CONFIG_OVERRIDE.lock().unwrap() = Some(reader);
+}
+
+/// Clears the configuration installed by [`set_default_config_reader`].
+pub fn reset_default_config_reader() {
+    *CONFIG_OVERRIDE.lock().unwrap() = None;
+}
+
+/// Makes [`DefaultConfig`] read `reader` on the current thread until the
+/// returned guard is dropped, ahead of [`set_default_config_reader`].
+///
+/// `standout` apps with a config loader scope the loaded configuration to
+/// each dispatch.
+pub fn scope_config_reader(reader: SharedConfig) -> ConfigReaderScope {
+    ConfigReaderScope {
+        previous: SCOPED_CONFIG.with(|scoped| scoped.replace(Some(reader))),
+        _thread: PhantomData,
+    }
+}
+
+/// Restores the thread's previous configuration when dropped; see
+/// [`scope_config_reader`].
+#[must_use = "the configuration is only read while the scope is alive"]
+pub struct ConfigReaderScope {
+    previous: Option<SharedConfig>,
+    _thread: PhantomData<*const ()>,
+}
+
+impl Drop for ConfigReaderScope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        SCOPED_CONFIG.with(|scoped| *scoped.borrow_mut() = previous);
+    }
+}
+
 fn current_stdin_override() -> Option<SharedStdin> {
     STDIN_OVERRIDE.lock().unwrap().clone()
 }
@@ -321,11 +496,41 @@ impl ClipboardReader for DefaultClipboard {
     }
 }
 
+/// Configuration reader used by
+/// [`ConfigSource::new`](crate::ConfigSource::new).
+///
+/// Delegates to the configuration scoped to this thread with
+/// [`scope_config_reader`], else the one installed via
+/// [`set_default_config_reader`]; without either, no value is set.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultConfig;
+
+impl ConfigReader for DefaultConfig {
+    fn get(&self, path: &str) -> Option<String> {
+        let reader = SCOPED_CONFIG
+            .with(|scoped| scoped.borrow().clone())
+            .or_else(|| CONFIG_OVERRIDE.lock().unwrap().clone())?;
+        reader.get(path)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use serial_test::serial;
 
+    #[test]
+    fn ci_flag_values() {
+        assert!(ci_flag(Some("true")));
+        assert!(ci_flag(Some("1")));
+        assert!(ci_flag(Some("woodpecker")));
+        assert!(!ci_flag(Some("false")));
+        assert!(!ci_flag(Some("FALSE")));
+        assert!(!ci_flag(Some("0")));
+        assert!(!ci_flag(Some("")));
+        assert!(!ci_flag(None));
+    }
+
     #[test]
     fn mock_stdin_terminal() {
         let stdin = MockStdin::terminal();
@@ -406,4 +611,49 @@ mod tests {
         assert_eq!(reader.read().unwrap(), Some("paste".to_string()));
         reset_default_clipboard_reader();
     }
+
+    #[test]
+    #[serial]
+    fn default_config_uses_override() {
+        reset_default_config_reader();
+        assert_eq!(DefaultConfig.get("editor"), None);
+
+        set_default_config_reader(Arc::new(MockConfig::new().with_value("editor", "vim")));
+        assert_eq!(DefaultConfig.get("editor"), Some("vim".to_string()));
+        assert_eq!(DefaultConfig.get("pager"), None);
+        reset_default_config_reader();
+    }
+
+    #[test]
+    #[serial]
+    fn scoped_config_shadows_override_on_its_thread() {
+        set_default_config_reader(Arc::new(MockConfig::new().with_value("editor", "vim")));
+        {
+            let _scope =
+                scope_config_reader(Arc::new(MockConfig::new().with_value("editor", "nano")));
+            assert_eq!(DefaultConfig.get("editor"), Some("nano".to_string()));
+            let other = std::thread::spawn(|| DefaultConfig.get("editor"));
+            assert_eq!(other.join().unwrap(), Some("vim".to_string()));
+        }
+        assert_eq!(DefaultConfig.get("editor"), Some("vim".to_string()));
+        reset_default_config_reader();
+    }
+
+    #[test]
+    #[serial]
+    fn scoped_prompt_mode_is_per_thread() {
+        set_prompt_mode(PromptMode::Ask);
+        {
+            let _scope = scope_prompt_mode(PromptMode::NoInput);
+            assert_eq!(prompt_mode(), PromptMode::NoInput);
+            {
+                let _inner = scope_prompt_mode(PromptMode::AssumeYes);
+                assert_eq!(prompt_mode(), PromptMode::AssumeYes);
+            }
+            assert_eq!(prompt_mode(), PromptMode::NoInput);
+            let other = std::thread::spawn(prompt_mode);
+            assert_eq!(other.join().unwrap(), PromptMode::Ask);
+        }
+        assert_eq!(prompt_mode(), PromptMode::Ask);
+    }
 }
@@ -48,6 +48,13 @@ pub enum InputError {
     /// Failed to parse argument value.
     #[error("Failed to parse argument '{name}': {reason}")]
     ParseError { name: String, reason: String },
+
+    /// A structured payload could not be deserialized.
+    #[error("Invalid {format} on stdin: {reason}")]
+    InvalidPayload {
+        format: &'static str,
+        reason: String,
+    },
 }
 
 impl InputError {
@@ -22,6 +22,8 @@
 //! - **`editor`** (default) - Enable [`EditorCollector`] for editor-based input
 //! - **`simple-prompts`** (default) - Enable basic terminal prompts
 //! - **`inquire`** - Enable rich TUI prompts via the inquire crate
+//! - **`json`** / **`yaml`** - Enable [`JsonStdinSource`] / [`YamlStdinSource`]
+//!   for deserializing piped payloads
 //!
 //! # Architecture
 //!
@@ -68,25 +70,41 @@ pub use responder::{
 
 // Re-export sources at crate root for convenience
 pub use sources::{
-    read_if_piped, ArgSource, ClipboardSource, DefaultSource, EnvSource, FlagSource, StdinSource,
+    read_if_piped, ArgSource, ClipboardSource, ConfigSource, DefaultSource, EnvSource, FlagSource,
+    StdinSource,
 };
 
 #[cfg(feature = "editor")]
-pub use sources::{EditorRunner, EditorSource, MockEditorResult, MockEditorRunner};
+pub use sources::{
+    EditorDocument, EditorRunner, EditorSource, FrontMatterSource, MockEditorResult,
+    MockEditorRunner,
+};
 
 #[cfg(feature = "simple-prompts")]
-pub use sources::{ConfirmPromptSource, MockTerminal, TerminalIO, TextPromptSource};
+pub use sources::{
+    ConfirmPromptSource, MockTerminal, PasswordPromptSource, TerminalIO, TextPromptSource,
+};
 
 #[cfg(feature = "inquire")]
 pub use sources::{
     InquireConfirm, InquireEditor, InquireMultiSelect, InquirePassword, InquireSelect, InquireText,
 };
 
+#[cfg(feature = "json")]
+pub use sources::JsonStdinSource;
+
+#[cfg(feature = "yaml")]
+pub use sources::YamlStdinSource;
+
 // Re-export mock types for testing
-pub use env::{MockClipboard, MockEnv, MockStdin};
+pub use env::{MockClipboard, MockConfig, MockEnv, MockStdin};
 
 // Re-export process-global default reader controls (used by test harnesses)
 pub use env::{
-    reset_default_clipboard_reader, reset_default_stdin_reader, set_default_clipboard_reader,
-    set_default_stdin_reader, DefaultClipboard, DefaultStdin,
+    reset_default_clipboard_reader, reset_default_config_reader, reset_default_stdin_reader,
+    scope_config_reader, set_default_clipboard_reader, set_default_config_reader,
+    set_default_stdin_reader, ConfigReaderScope, DefaultClipboard, DefaultConfig, DefaultStdin,
 };
+
+// Re-export the `--yes` / `--no-input` prompt mode
+pub use env::{prompt_mode, scope_prompt_mode, set_prompt_mode, PromptMode, PromptModeScope};
@@ -47,7 +47,8 @@ pub enum PromptKind {
     /// Free-form text input ([`InquireText`](crate::InquireText),
     /// [`TextPromptSource`](crate::TextPromptSource)).
     Text,
-    /// Masked password input ([`InquirePassword`](crate::InquirePassword)).
+    /// Masked password input ([`InquirePassword`](crate::InquirePassword),
+    /// [`PasswordPromptSource`](crate::PasswordPromptSource)).
     Password,
     /// Editor-based multi-line input ([`EditorSource`](crate::EditorSource),
     /// [`InquireEditor`](crate::InquireEditor)).
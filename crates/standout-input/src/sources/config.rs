@@ -0,0 +1,126 @@
+//! Configuration file input source.
+
+use std::sync::Arc;
+
+use clap::ArgMatches;
+
+use crate::collector::InputCollector;
+use crate::env::{ConfigReader, DefaultConfig};
+use crate::InputError;
+
+/// Collect input from the application's configuration.
+///
+/// Reads the value at a dot-separated path (`sync.remote`) from the
+/// configuration scoped to the thread with
+/// [`scope_config_reader`](crate::scope_config_reader), else the one
+/// installed with [`set_default_config_reader`](crate::set_default_config_reader).
+/// In a `standout` app with a config loader, that's the configuration loaded
+/// for the current command. The source is available when the path is set and
+/// non-empty.
+///
+/// Placed between the environment and interactive sources, it gives the
+/// usual precedence of argument, environment, config file, prompt, default.
+///
+/// # Example
+///
+/// ```ignore
+/// use standout_input::{InputChain, ArgSource, ConfigSource, EnvSource, TextPromptSource};
+///
+/// let chain = InputChain::<String>::new()
+///     .try_source(ArgSource::new("author"))
+///     .try_source(EnvSource::new("MYAPP_AUTHOR"))
+///     .try_source(ConfigSource::new("user.name"))
+///     .try_source(TextPromptSource::new("Author: "))
+///     .default("anonymous".to_string());
+/// ```
+///
+/// # Testing
+///
+/// ```ignore
+/// use standout_input::{ConfigSource, MockConfig};
+///
+/// let config = MockConfig::new().with_value("user.name", "ada");
+/// let source = ConfigSource::with_reader("user.name", config);
+/// ```
+#[derive(Clone)]
+pub struct ConfigSource<R: ConfigReader = DefaultConfig> {
+    path: String,
+    reader: Arc<R>,
+}
+
+impl ConfigSource<DefaultConfig> {
+    /// Create a source reading `path` from the application's configuration.
+    pub fn new(path: impl Into<String>) -> Self {
+        Self::with_reader(path, DefaultConfig)
+    }
+}
+
+impl<R: ConfigReader> ConfigSource<R> {
+    /// Create a config source with a custom reader.
+    ///
+    /// This is primarily used for testing to inject a mock configuration.
+    pub fn with_reader(path: impl Into<String>, reader: R) -> Self {
+        Self {
+            path: path.into(),
+            reader: Arc::new(reader),
+        }
+    }
+
+    /// Get the configuration path.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+impl<R: ConfigReader + 'static> InputCollector<String> for ConfigSource<R> {
+    fn name(&self) -> &'static str {
+        "config"
+    }
+
+    fn is_available(&self, _matches: &ArgMatches) -> bool {
+        self.reader
+            .get(&self.path)
+            .is_some_and(|value| !value.is_empty())
+    }
+
+    fn collect(&self, _matches: &ArgMatches) -> Result<Option<String>, InputError> {
+        match self.reader.get(&self.path) {
+            Some(value) if !value.is_empty() => Ok(Some(value)),
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::env::MockConfig;
+    use clap::Command;
+
+    fn empty_matches() -> ArgMatches {
+        Command::new("test").try_get_matches_from(["test"]).unwrap()
+    }
+
+    #[test]
+    fn config_reads_value_at_path() {
+        let config = MockConfig::new().with_value("user.name", "ada");
+        let source = ConfigSource::with_reader("user.name", config);
+
+        assert!(source.is_available(&empty_matches()));
+        assert_eq!(
+            source.collect(&empty_matches()).unwrap(),
+            Some("ada".to_string())
+        );
+    }
+
+    #[test]
+    fn config_unavailable_when_unset_or_empty() {
+        let config = MockConfig::new().with_value("user.email", "");
+        let missing = ConfigSource::with_reader("user.name", config.clone());
+        let empty = ConfigSource::with_reader("user.email", config);
+
+        assert!(!missing.is_available(&empty_matches()));
+        assert!(!empty.is_available(&empty_matches()));
+        assert_eq!(empty.collect(&empty_matches()).unwrap(), None);
+    }
+}
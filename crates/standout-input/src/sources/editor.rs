@@ -1,11 +1,18 @@
 //! Editor-based input source.
 //!
 //! Opens the user's preferred text editor for multi-line input.
+//!
+//! Like `git commit` and `kubectl edit`, the editor can be pre-filled with a
+//! template of commented instructions and fields: [`EditorSource`] strips
+//! the comments, and [`FrontMatterSource`] parses the fields into an
+//! [`EditorDocument`].
 
+use std::fmt;
 use std::fs;
 use std::io::{self, Write};
 use std::path::Path;
 use std::process::Command;
+use std::str::FromStr;
 use std::sync::Arc;
 use std::time::SystemTime;
 
@@ -130,7 +137,8 @@ fn editor_exists(editor: &str) -> bool {
 ///
 /// ```ignore
 /// let source = EditorSource::new()
-///     .initial_content("# Enter your message\n\n")
+///     .initial_content("\n# Enter your message. Lines starting with # are ignored.\n")
+///     .strip_comments("#")
 ///     .extension(".md")
 ///     .require_save(true);
 /// ```
@@ -141,6 +149,7 @@ pub struct EditorSource<R: EditorRunner = RealEditorRunner> {
     extension: String,
     require_save: bool,
     trim: bool,
+    comment_prefix: Option<String>,
 }
 
 impl EditorSource<RealEditorRunner> {
@@ -152,6 +161,7 @@ impl EditorSource<RealEditorRunner> {
             extension: ".txt".to_string(),
             require_save: false,
             trim: true,
+            comment_prefix: None,
         }
     }
 }
@@ -173,6 +183,7 @@ impl<R: EditorRunner> EditorSource<R> {
             extension: ".txt".to_string(),
             require_save: false,
             trim: true,
+            comment_prefix: None,
         }
     }
 
@@ -210,6 +221,33 @@ impl<R: EditorRunner> EditorSource<R> {
         self.trim = trim;
         self
     }
+
+    /// Remove lines starting with `prefix` from the result.
+    ///
+    /// Use this with [`initial_content`](Self::initial_content) to give
+    /// instructions that don't end up in the input, as `git commit` does
+    /// with `#`. Leading whitespace before the prefix is ignored.
+    /// Default is to keep all lines.
+    pub fn strip_comments(mut self, prefix: impl Into<String>) -> Self {
+        self.comment_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Parse the result as an [`EditorDocument`] with front matter.
+    pub fn front_matter(self) -> FrontMatterSource<R> {
+        FrontMatterSource { editor: self }
+    }
+
+    /// Remove comment lines, if a comment prefix is set.
+    fn strip(&self, content: String) -> String {
+        let Some(prefix) = &self.comment_prefix else {
+            return content;
+        };
+        content
+            .split_inclusive('\n')
+            .filter(|line| !line.trim_start().starts_with(prefix.as_str()))
+            .collect()
+    }
 }
 
 impl<R: EditorRunner + 'static> EditorSource<R> {
@@ -253,8 +291,8 @@ impl<R: EditorRunner + 'static> InputCollector<String> for EditorSource<R> {
     }
 
     fn is_available(&self, _matches: &ArgMatches) -> bool {
-        // Editor is available if we can detect one and we have a TTY
-        self.runner.detect_editor().is_some() && std::io::stdin().is_terminal()
+        // Editor is available if we can detect one and we have a TTY (not CI)
+        self.runner.detect_editor().is_some() && crate::env::is_interactive()
     }
 
     fn collect(&self, _matches: &ArgMatches) -> Result<Option<String>, InputError> {
@@ -295,6 +333,7 @@ impl<R: EditorRunner + 'static> InputCollector<String> for EditorSource<R> {
 
         // Read the result
         let content = fs::read_to_string(path).map_err(InputError::EditorFailed)?;
+        let content = self.strip(content);
 
         let result = if self.trim {
             content.trim().to_string()
@@ -315,13 +354,150 @@ impl<R: EditorRunner + 'static> InputCollector<String> for EditorSource<R> {
     }
 }
 
+/// Collect an [`EditorDocument`] via an external text editor.
+///
+/// Created with [`EditorSource::front_matter`]. The edited text (after
+/// comments are stripped) may start with a front matter block of
+/// `key: value` fields between `---` lines, followed by a free-form body:
+///
+/// ```text
+/// ---
+/// title: Fix the login page
+/// priority: 2
+/// ---
+/// The button is misaligned on mobile.
+/// ```
+///
+/// # Example
+///
+/// ```ignore
+/// use standout_input::{EditorSource, InputChain};
+///
+/// let template = "---\ntitle:\npriority: 3\n---\n\n# Describe the task above.\n";
+/// let doc = InputChain::new()
+///     .try_source(EditorSource::new().initial_content(template).strip_comments("#").front_matter())
+///     .resolve(&matches)?;
+///
+/// let title = doc.field("title").unwrap_or_default();
+/// let priority: Option<u8> = doc.get("priority")?;
+/// ```
+#[derive(Clone)]
+pub struct FrontMatterSource<R: EditorRunner = RealEditorRunner> {
+    editor: EditorSource<R>,
+}
+
+impl<R: EditorRunner + 'static> InputCollector<EditorDocument> for FrontMatterSource<R> {
+    fn name(&self) -> &'static str {
+        "editor"
+    }
+
+    fn is_available(&self, matches: &ArgMatches) -> bool {
+        self.editor.is_available(matches)
+    }
+
+    fn collect(&self, matches: &ArgMatches) -> Result<Option<EditorDocument>, InputError> {
+        match self.editor.collect(matches)? {
+            Some(content) => EditorDocument::parse(&content).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn can_retry(&self) -> bool {
+        true
+    }
+}
+
+/// Text with optional front matter, as collected by [`FrontMatterSource`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EditorDocument {
+    /// Front matter fields, in order of appearance.
+    pub fields: Vec<(String, String)>,
+    /// The text after the front matter.
+    pub body: String,
+}
+
+impl EditorDocument {
+    /// Parse text with an optional front matter block.
+    ///
+    /// Without a leading `---` line the whole text is the body. Fields are
+    /// `key: value` lines with surrounding whitespace trimmed; blank lines
+    /// in the block are skipped. A block that isn't closed, or a line
+    /// without `:`, is a [`InputError::ParseError`].
+    pub fn parse(text: &str) -> Result<Self, InputError> {
+        let mut lines = text.lines();
+        if lines.clone().next().map(str::trim_end) != Some("---") {
+            return Ok(Self {
+                fields: Vec::new(),
+                body: text.to_string(),
+            });
+        }
+        lines.next();
+
+        let mut fields = Vec::new();
+        let mut closed = false;
+        for line in lines.by_ref() {
+            let line = line.trim();
+            if line == "---" {
+                closed = true;
+                break;
+            }
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = line.split_once(':').ok_or_else(|| {
+                InputError::parse(
+                    "front matter",
+                    format!("expected `key: value`, got `{}`", line),
+                )
+            })?;
+            fields.push((key.trim().to_string(), value.trim().to_string()));
+        }
+        if !closed {
+            return Err(InputError::parse(
+                "front matter",
+                "missing closing `---` line",
+            ));
+        }
+
+        let body: Vec<&str> = lines.collect();
+        Ok(Self {
+            fields,
+            body: body.join("\n").trim().to_string(),
+        })
+    }
+
+    /// Get a field's value, as written.
+    pub fn field(&self, key: &str) -> Option<&str> {
+        self.fields
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Get a field parsed as `T`.
+    ///
+    /// Returns `Ok(None)` if the field is missing or left empty, so template
+    /// fields can be optional.
+    pub fn get<T>(&self, key: &str) -> Result<Option<T>, InputError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        match self.field(key) {
+            Some(value) if !value.is_empty() => value
+                .parse()
+                .map(Some)
+                .map_err(|e: T::Err| InputError::parse(key, e.to_string())),
+            _ => Ok(None),
+        }
+    }
+}
+
 /// Get the modification time of a file.
 fn get_mtime(path: &Path) -> io::Result<SystemTime> {
     fs::metadata(path)?.modified()
 }
 
-use std::io::IsTerminal;
-
 /// Mock editor runner for testing.
 ///
 /// Simulates editor behavior without actually launching an editor.
@@ -468,6 +644,58 @@ mod tests {
         assert_eq!(result, Some("user input".to_string()));
     }
 
+    #[test]
+    fn editor_strips_comments() {
+        let source = EditorSource::with_runner(MockEditorRunner::with_result(
+            "Fix login\n# Please enter a message\n  # indented\nDetails #1\n",
+        ))
+        .strip_comments("#");
+        let result = source.collect(&empty_matches()).unwrap();
+        assert_eq!(result, Some("Fix login\nDetails #1".to_string()));
+
+        let source = EditorSource::with_runner(MockEditorRunner::with_result("# only comments\n"))
+            .strip_comments("#");
+        assert_eq!(source.collect(&empty_matches()).unwrap(), None);
+    }
+
+    #[test]
+    fn front_matter_source_parses_fields_and_body() {
+        let source = EditorSource::with_runner(MockEditorRunner::with_result(
+            "---\ntitle: Fix login\npriority: 2\ndue:\n---\n# Describe the task\n\nIt breaks on mobile.\n",
+        ))
+        .strip_comments("#")
+        .front_matter();
+        let doc = source.collect(&empty_matches()).unwrap().unwrap();
+
+        assert_eq!(doc.field("title"), Some("Fix login"));
+        assert_eq!(doc.get::<u8>("priority").unwrap(), Some(2));
+        assert_eq!(doc.get::<u8>("due").unwrap(), None);
+        assert_eq!(doc.field("missing"), None);
+        assert_eq!(doc.body, "It breaks on mobile.");
+    }
+
+    #[test]
+    fn editor_document_without_front_matter() {
+        let doc = EditorDocument::parse("just text\n---\n").unwrap();
+        assert!(doc.fields.is_empty());
+        assert_eq!(doc.body, "just text\n---\n");
+    }
+
+    #[test]
+    fn editor_document_errors() {
+        let err = EditorDocument::parse("---\ntitle: x\n").unwrap_err();
+        assert!(err.to_string().contains("missing closing `---`"));
+
+        let err = EditorDocument::parse("---\nno colon\n---\n").unwrap_err();
+        assert!(matches!(err, InputError::ParseError { .. }));
+
+        let doc = EditorDocument::parse("---\npriority: high\n---\n").unwrap();
+        let err = doc.get::<u8>("priority").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("Failed to parse argument 'priority'"));
+    }
+
     #[test]
     fn editor_can_retry() {
         let source = EditorSource::with_runner(MockEditorRunner::with_result("test"));
@@ -483,7 +711,7 @@ mod tests {
 
     // === .prompt() shortcut ===
     //
-    // EditorSource::is_available checks std::io::stdin().is_terminal() directly,
+    // EditorSource::is_available checks crate::env::is_interactive() directly,
     // so under `cargo test` (no TTY) prompt() always short-circuits to NoInput.
     // The happy path with the mock runner is covered by the existing
     // editor_collects_content / editor_failure / editor_no_editor_error tests
@@ -4,7 +4,6 @@
 //! These provide a more polished interactive experience than simple-prompts.
 
 use std::fmt::Display;
-use std::io::IsTerminal;
 
 use clap::ArgMatches;
 use inquire::{
@@ -109,7 +108,7 @@ impl InputCollector<String> for InquireText {
     }
 
     fn is_available(&self, _matches: &ArgMatches) -> bool {
-        std::io::stdin().is_terminal()
+        crate::env::is_interactive()
     }
 
     fn collect(&self, _matches: &ArgMatches) -> Result<Option<String>, InputError> {
@@ -208,10 +207,14 @@ impl InputCollector<bool> for InquireConfirm {
     }
 
     fn is_available(&self, _matches: &ArgMatches) -> bool {
-        std::io::stdin().is_terminal()
+        crate::env::prompt_mode() == crate::env::PromptMode::AssumeYes
+            || crate::env::is_interactive()
     }
 
     fn collect(&self, _matches: &ArgMatches) -> Result<Option<bool>, InputError> {
+        if crate::env::prompt_mode() == crate::env::PromptMode::AssumeYes {
+            return Ok(Some(self.default.unwrap_or(true)));
+        }
         let mut prompt = Confirm::new(&self.message);
 
         if let Some(default) = self.default {
@@ -306,7 +309,7 @@ impl<T: Display + Clone + Send + Sync + 'static> InputCollector<T> for InquireSe
     }
 
     fn is_available(&self, _matches: &ArgMatches) -> bool {
-        std::io::stdin().is_terminal() && !self.options.is_empty()
+        crate::env::is_interactive() && !self.options.is_empty()
     }
 
     fn collect(&self, _matches: &ArgMatches) -> Result<Option<T>, InputError> {
@@ -424,7 +427,7 @@ impl<T: Display + Clone + Send + Sync + 'static> InputCollector<Vec<T>> for Inqu
     }
 
     fn is_available(&self, _matches: &ArgMatches) -> bool {
-        std::io::stdin().is_terminal() && !self.options.is_empty()
+        crate::env::is_interactive() && !self.options.is_empty()
     }
 
     fn collect(&self, _matches: &ArgMatches) -> Result<Option<Vec<T>>, InputError> {
@@ -562,7 +565,7 @@ impl InputCollector<String> for InquirePassword {
     }
 
     fn is_available(&self, _matches: &ArgMatches) -> bool {
-        std::io::stdin().is_terminal()
+        crate::env::is_interactive()
     }
 
     fn collect(&self, _matches: &ArgMatches) -> Result<Option<String>, InputError> {
@@ -676,7 +679,7 @@ impl InputCollector<String> for InquireEditor {
     }
 
     fn is_available(&self, _matches: &ArgMatches) -> bool {
-        std::io::stdin().is_terminal()
+        crate::env::is_interactive()
     }
 
     fn collect(&self, _matches: &ArgMatches) -> Result<Option<String>, InputError> {
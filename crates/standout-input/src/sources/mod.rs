@@ -6,12 +6,16 @@
 //! - [`FlagSource`] - Read from CLI flags
 //! - [`StdinSource`] - Read from piped stdin
 //! - [`EnvSource`] - Read from environment variables
+//! - [`ConfigSource`] - Read from the application's configuration
 //! - [`ClipboardSource`] - Read from system clipboard
 //! - [`DefaultSource`] - Provide a fallback value
 //! - [`EditorSource`] - Read from external text editor (requires `editor` feature)
+//! - [`JsonStdinSource`] / [`YamlStdinSource`] - Deserialize piped stdin
+//!   (requires `json` / `yaml` features)
 
 mod arg;
 mod clipboard;
+mod config;
 mod default;
 mod env;
 mod stdin;
@@ -25,19 +29,34 @@ mod prompt;
 #[cfg(feature = "inquire")]
 mod inquire_adapters;
 
+#[cfg(any(feature = "json", feature = "yaml"))]
+mod structured;
+
 pub use arg::{ArgSource, FlagSource};
 pub use clipboard::ClipboardSource;
+pub use config::ConfigSource;
 pub use default::DefaultSource;
 pub use env::EnvSource;
 pub use stdin::{read_if_piped, StdinSource};
 
 #[cfg(feature = "editor")]
-pub use editor::{EditorRunner, EditorSource, MockEditorResult, MockEditorRunner};
+pub use editor::{
+    EditorDocument, EditorRunner, EditorSource, FrontMatterSource, MockEditorResult,
+    MockEditorRunner,
+};
 
 #[cfg(feature = "simple-prompts")]
-pub use prompt::{ConfirmPromptSource, MockTerminal, TerminalIO, TextPromptSource};
+pub use prompt::{
+    ConfirmPromptSource, MockTerminal, PasswordPromptSource, TerminalIO, TextPromptSource,
+};
 
 #[cfg(feature = "inquire")]
 pub use inquire_adapters::{
     InquireConfirm, InquireEditor, InquireMultiSelect, InquirePassword, InquireSelect, InquireText,
 };
+
+#[cfg(feature = "json")]
+pub use structured::JsonStdinSource;
+
+#[cfg(feature = "yaml")]
+pub use structured::YamlStdinSource;
@@ -2,18 +2,28 @@
 //!
 //! Basic interactive prompts that work without external dependencies.
 //! For richer TUI prompts, use the `inquire` feature instead.
+//!
+//! Prompts are only shown when stdin is a terminal and the process isn't
+//! running in CI (see [`is_interactive`](crate::env::is_interactive)), so
+//! chains skip them in pipelines. With a [`timeout`](TextPromptSource::timeout)
+//! an unanswered prompt also falls through to the next source.
+
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
-use std::io::{self, BufRead, IsTerminal, Write};
-use std::sync::Arc;
+use once_cell::sync::OnceCell;
 
 use clap::ArgMatches;
 
 use crate::collector::InputCollector;
+use crate::env::{prompt_mode, PromptMode};
 use crate::InputError;
 
 /// Abstraction over terminal I/O for testability.
 pub trait TerminalIO: Send + Sync {
-    /// Check if stdin is a terminal.
+    /// Check if stdin is a terminal that prompts can be shown on.
     fn is_terminal(&self) -> bool;
 
     /// Write a prompt to stdout.
@@ -21,6 +31,26 @@ pub trait TerminalIO: Send + Sync {
 
     /// Read a line from stdin.
     fn read_line(&self) -> io::Result<String>;
+
+    /// Read a line from stdin, or `None` if nothing is entered within
+    /// `timeout`.
+    ///
+    /// The default ignores the timeout and waits for the line.
+    fn read_line_timeout(&self, timeout: Duration) -> io::Result<Option<String>> {
+        let _ = timeout;
+        self.read_line().map(Some)
+    }
+
+    /// Read a line from stdin without echoing it.
+    ///
+    /// With a `mask`, that character is shown for each one typed. Like
+    /// [`read_line`](Self::read_line), returns an empty string on EOF or
+    /// cancel. The default reads a normal line, for terminals that can't
+    /// hide input.
+    fn read_secret(&self, mask: Option<char>) -> io::Result<String> {
+        let _ = mask;
+        self.read_line()
+    }
 }
 
 /// Real terminal I/O.
@@ -29,7 +59,7 @@ pub struct RealTerminal;
 
 impl TerminalIO for RealTerminal {
     fn is_terminal(&self) -> bool {
-        std::io::stdin().is_terminal()
+        crate::env::is_interactive()
     }
 
     fn write_prompt(&self, prompt: &str) -> io::Result<()> {
@@ -38,10 +68,105 @@ impl TerminalIO for RealTerminal {
     }
 
     fn read_line(&self) -> io::Result<String> {
+        // Once a timed read has started the reader thread, it owns stdin
+        if let Some(lines) = STDIN_LINES.get() {
+            return match lines.lock().unwrap().recv() {
+                Ok(line) => line,
+                Err(_) => Ok(String::new()),
+            };
+        }
         let mut line = String::new();
         io::stdin().lock().read_line(&mut line)?;
         Ok(line)
     }
+
+    fn read_line_timeout(&self, timeout: Duration) -> io::Result<Option<String>> {
+        match stdin_lines().lock().unwrap().recv_timeout(timeout) {
+            Ok(line) => line.map(Some),
+            Err(RecvTimeoutError::Timeout) => Ok(None),
+            // The reader thread stops at EOF
+            Err(RecvTimeoutError::Disconnected) => Ok(Some(String::new())),
+        }
+    }
+
+    fn read_secret(&self, mask: Option<char>) -> io::Result<String> {
+        use console::{Key, Term};
+
+        let term = Term::stdout();
+        let Some(mask) = mask else {
+            return term.read_secure_line().map(|line| line + "\n");
+        };
+
+        let mut secret = String::new();
+        loop {
+            match term.read_key()? {
+                Key::Enter => {
+                    term.write_line("")?;
+                    return Ok(secret + "\n");
+                }
+                Key::Backspace if !secret.is_empty() => {
+                    secret.pop();
+                    term.clear_chars(1)?;
+                }
+                Key::Char(c) if !c.is_control() => {
+                    secret.push(c);
+                    term.write_str(mask.encode_utf8(&mut [0; 4]))?;
+                }
+                Key::CtrlC | Key::Escape => {
+                    term.write_line("")?;
+                    return Ok(String::new());
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+type StdinLines = Mutex<Receiver<io::Result<String>>>;
+
+/// Lines read by a background thread, so a read can stop waiting.
+static STDIN_LINES: OnceCell<StdinLines> = OnceCell::new();
+
+/// Starts the stdin reader thread on first use.
+///
+/// A read that times out leaves the thread waiting for the line, which
+/// then answers the next read instead of being lost.
+fn stdin_lines() -> &'static StdinLines {
+    STDIN_LINES.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || loop {
+            let mut line = String::new();
+            let result = io::stdin().lock().read_line(&mut line).map(|_| line);
+            let done = !matches!(result, Ok(ref line) if !line.is_empty());
+            if sender.send(result).is_err() || done {
+                break;
+            }
+        });
+        Mutex::new(receiver)
+    })
+}
+
+/// Whether a prompt can be shown: the terminal allows it and `--no-input`
+/// or `--yes` (see [`PromptMode`]) didn't rule it out.
+fn can_ask<T: TerminalIO>(terminal: &T) -> bool {
+    prompt_mode() == PromptMode::Ask && terminal.is_terminal()
+}
+
+/// Reads a prompt's answer, or `None` if `timeout` passes first.
+fn read_answer<T: TerminalIO>(
+    terminal: &T,
+    timeout: Option<Duration>,
+) -> Result<Option<String>, InputError> {
+    let line = match timeout {
+        Some(timeout) => terminal.read_line_timeout(timeout),
+        None => terminal.read_line().map(Some),
+    }
+    .map_err(|e| InputError::PromptFailed(e.to_string()))?;
+    if line.is_none() {
+        // End the unanswered prompt's line
+        let _ = terminal.write_prompt("\n");
+    }
+    Ok(line)
 }
 
 /// Simple text input prompt.
@@ -65,6 +190,7 @@ pub struct TextPromptSource<T: TerminalIO = RealTerminal> {
     terminal: Arc<T>,
     prompt: String,
     trim: bool,
+    timeout: Option<Duration>,
 }
 
 impl TextPromptSource<RealTerminal> {
@@ -74,6 +200,7 @@ impl TextPromptSource<RealTerminal> {
             terminal: Arc::new(RealTerminal),
             prompt: prompt.into(),
             trim: true,
+            timeout: None,
         }
     }
 }
@@ -85,6 +212,7 @@ impl<T: TerminalIO> TextPromptSource<T> {
             terminal: Arc::new(terminal),
             prompt: prompt.into(),
             trim: true,
+            timeout: None,
         }
     }
 
@@ -95,6 +223,15 @@ impl<T: TerminalIO> TextPromptSource<T> {
         self.trim = trim;
         self
     }
+
+    /// Stop waiting for an answer after `timeout`.
+    ///
+    /// An unanswered prompt provides no input, so the chain moves on to the
+    /// next source instead of hanging. Default is to wait forever.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
 }
 
 impl<T: TerminalIO + 'static> TextPromptSource<T> {
@@ -108,7 +245,7 @@ impl<T: TerminalIO + 'static> TextPromptSource<T> {
     ///
     /// Errors:
     /// - [`InputError::PromptCancelled`] on EOF (Ctrl+D)
-    /// - [`InputError::NoInput`] if stdin is not a TTY *or* the user
+    /// - [`InputError::NoInput`] if stdin is not a TTY (or in CI) *or* the user
     ///   submits empty input
     /// - [`InputError::PromptFailed`] on terminal I/O failure
     pub fn prompt(&self) -> Result<String, InputError> {
@@ -131,11 +268,11 @@ impl<T: TerminalIO + 'static> InputCollector<String> for TextPromptSource<T> {
     }
 
     fn is_available(&self, _matches: &ArgMatches) -> bool {
-        self.terminal.is_terminal()
+        can_ask(&*self.terminal)
     }
 
     fn collect(&self, _matches: &ArgMatches) -> Result<Option<String>, InputError> {
-        if !self.terminal.is_terminal() {
+        if !can_ask(&*self.terminal) {
             return Ok(None);
         }
 
@@ -143,10 +280,9 @@ impl<T: TerminalIO + 'static> InputCollector<String> for TextPromptSource<T> {
             .write_prompt(&self.prompt)
             .map_err(|e| InputError::PromptFailed(e.to_string()))?;
 
-        let line = self
-            .terminal
-            .read_line()
-            .map_err(|e| InputError::PromptFailed(e.to_string()))?;
+        let Some(line) = read_answer(&*self.terminal, self.timeout)? else {
+            return Ok(None);
+        };
 
         // Check for EOF (user pressed Ctrl+D)
         if line.is_empty() {
@@ -174,6 +310,151 @@ impl<T: TerminalIO + 'static> InputCollector<String> for TextPromptSource<T> {
     }
 }
 
+/// Password or secret prompt.
+///
+/// Reads input without echoing it, optionally showing a mask character per
+/// keystroke, and can ask for the value twice. Input isn't trimmed, since
+/// spaces may be part of a secret.
+///
+/// # Example
+///
+/// ```ignore
+/// use standout_input::{InputChain, EnvSource, PasswordPromptSource};
+///
+/// let chain = InputChain::<String>::new()
+///     .try_source(EnvSource::new("API_TOKEN"))
+///     .try_source(PasswordPromptSource::new("API token: ").masked());
+///
+/// let token = chain.resolve(&matches)?;
+/// ```
+#[derive(Clone)]
+pub struct PasswordPromptSource<T: TerminalIO = RealTerminal> {
+    terminal: Arc<T>,
+    prompt: String,
+    confirmation: Option<String>,
+    mask: Option<char>,
+}
+
+impl PasswordPromptSource<RealTerminal> {
+    /// Create a new password prompt. Input is hidden by default.
+    pub fn new(prompt: impl Into<String>) -> Self {
+        Self::with_terminal(prompt, RealTerminal)
+    }
+}
+
+impl<T: TerminalIO> PasswordPromptSource<T> {
+    /// Create a password prompt with a custom terminal for testing.
+    pub fn with_terminal(prompt: impl Into<String>, terminal: T) -> Self {
+        Self {
+            terminal: Arc::new(terminal),
+            prompt: prompt.into(),
+            confirmation: None,
+            mask: None,
+        }
+    }
+
+    /// Show `*` for each typed character.
+    pub fn masked(self) -> Self {
+        self.mask_char('*')
+    }
+
+    /// Show `mask` for each typed character.
+    pub fn mask_char(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Ask for the value a second time with `prompt`; the two must match.
+    pub fn with_confirmation(mut self, prompt: impl Into<String>) -> Self {
+        self.confirmation = Some(prompt.into());
+        self
+    }
+
+    fn read(&self, prompt: &str) -> Result<String, InputError> {
+        self.terminal
+            .write_prompt(prompt)
+            .map_err(|e| InputError::PromptFailed(e.to_string()))?;
+
+        let line = self
+            .terminal
+            .read_secret(self.mask)
+            .map_err(|e| InputError::PromptFailed(e.to_string()))?;
+
+        // Check for EOF (user pressed Ctrl+D)
+        if line.is_empty() {
+            return Err(InputError::PromptCancelled);
+        }
+
+        Ok(line
+            .trim_end_matches('\n')
+            .trim_end_matches('\r')
+            .to_string())
+    }
+}
+
+impl<T: TerminalIO + 'static> PasswordPromptSource<T> {
+    /// Prompt the user for the secret and return it.
+    ///
+    /// Standalone counterpart to [`InputCollector::collect`] for wizard /
+    /// REPL flows that drive standout themselves and have no `&ArgMatches`
+    /// to plumb through. Routes through any installed
+    /// [`PromptResponder`](crate::PromptResponder).
+    ///
+    /// Errors:
+    /// - [`InputError::PromptCancelled`] on EOF (Ctrl+D) or Ctrl+C
+    /// - [`InputError::NoInput`] if stdin is not a TTY (or in CI) *or* the user
+    ///   submits empty input
+    /// - [`InputError::ValidationFailed`] if the confirmation doesn't match
+    /// - [`InputError::PromptFailed`] on terminal I/O failure
+    pub fn prompt(&self) -> Result<String, InputError> {
+        if let Some(value) =
+            crate::responder::intercept_text(crate::PromptKind::Password, &self.prompt)?
+        {
+            return Ok(value);
+        }
+        let matches = crate::collector::empty_matches();
+        if !self.is_available(matches) {
+            return Err(InputError::NoInput);
+        }
+        self.collect(matches)?.ok_or(InputError::NoInput)
+    }
+}
+
+impl<T: TerminalIO + 'static> InputCollector<String> for PasswordPromptSource<T> {
+    fn name(&self) -> &'static str {
+        "prompt"
+    }
+
+    fn is_available(&self, _matches: &ArgMatches) -> bool {
+        can_ask(&*self.terminal)
+    }
+
+    fn collect(&self, _matches: &ArgMatches) -> Result<Option<String>, InputError> {
+        if !can_ask(&*self.terminal) {
+            return Ok(None);
+        }
+
+        let secret = self.read(&self.prompt)?;
+        if secret.is_empty() {
+            return Ok(None);
+        }
+
+        if let Some(confirmation) = &self.confirmation {
+            if self.read(confirmation)? != secret {
+                return Err(InputError::ValidationFailed(
+                    "Entries do not match".to_string(),
+                ));
+            }
+        }
+
+        Ok(Some(secret))
+    }
+
+    fn can_retry(&self) -> bool {
+        true
+    }
+}
+
 /// Simple yes/no confirmation prompt.
 ///
 /// Prompts the user for a yes/no response. Accepts y/yes/n/no (case-insensitive).
@@ -194,6 +475,7 @@ pub struct ConfirmPromptSource<T: TerminalIO = RealTerminal> {
     terminal: Arc<T>,
     prompt: String,
     default: Option<bool>,
+    timeout: Option<Duration>,
 }
 
 impl ConfirmPromptSource<RealTerminal> {
@@ -203,6 +485,7 @@ impl ConfirmPromptSource<RealTerminal> {
             terminal: Arc::new(RealTerminal),
             prompt: prompt.into(),
             default: None,
+            timeout: None,
         }
     }
 }
@@ -214,6 +497,7 @@ impl<T: TerminalIO> ConfirmPromptSource<T> {
             terminal: Arc::new(terminal),
             prompt: prompt.into(),
             default: None,
+            timeout: None,
         }
     }
 
@@ -227,6 +511,16 @@ impl<T: TerminalIO> ConfirmPromptSource<T> {
         self.default = Some(default);
         self
     }
+
+    /// Stop waiting for an answer after `timeout`.
+    ///
+    /// An unanswered prompt provides no input (the [`default`](Self::default)
+    /// is for an empty answer), so the chain moves on to the next source.
+    /// Default is to wait forever.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
 }
 
 impl<T: TerminalIO + 'static> ConfirmPromptSource<T> {
@@ -264,11 +558,14 @@ impl<T: TerminalIO + 'static> InputCollector<bool> for ConfirmPromptSource<T> {
     }
 
     fn is_available(&self, _matches: &ArgMatches) -> bool {
-        self.terminal.is_terminal()
+        prompt_mode() == PromptMode::AssumeYes || can_ask(&*self.terminal)
     }
 
     fn collect(&self, _matches: &ArgMatches) -> Result<Option<bool>, InputError> {
-        if !self.terminal.is_terminal() {
+        if prompt_mode() == PromptMode::AssumeYes {
+            return Ok(Some(self.default.unwrap_or(true)));
+        }
+        if !can_ask(&*self.terminal) {
             return Ok(None);
         }
 
@@ -284,10 +581,9 @@ impl<T: TerminalIO + 'static> InputCollector<bool> for ConfirmPromptSource<T> {
             .write_prompt(&full_prompt)
             .map_err(|e| InputError::PromptFailed(e.to_string()))?;
 
-        let line = self
-            .terminal
-            .read_line()
-            .map_err(|e| InputError::PromptFailed(e.to_string()))?;
+        let Some(line) = read_answer(&*self.terminal, self.timeout)? else {
+            return Ok(None);
+        };
 
         // Check for EOF
         if line.is_empty() {
@@ -319,12 +615,17 @@ impl<T: TerminalIO + 'static> InputCollector<bool> for ConfirmPromptSource<T> {
 }
 
 /// Mock terminal for testing prompts.
+///
+/// Records the prompts written to it and the mask used for secret reads,
+/// so tests can check what the user would have seen.
 #[derive(Debug)]
 pub struct MockTerminal {
     is_terminal: bool,
     responses: Vec<String>,
     /// Index of the next response to return.
     response_index: std::sync::atomic::AtomicUsize,
+    prompts: std::sync::Mutex<Vec<String>>,
+    secret_reads: std::sync::Mutex<Vec<Option<char>>>,
 }
 
 impl Clone for MockTerminal {
@@ -336,6 +637,8 @@ impl Clone for MockTerminal {
                 self.response_index
                     .load(std::sync::atomic::Ordering::SeqCst),
             ),
+            prompts: std::sync::Mutex::new(self.prompts()),
+            secret_reads: std::sync::Mutex::new(self.secret_reads()),
         }
     }
 }
@@ -347,6 +650,8 @@ impl MockTerminal {
             is_terminal: false,
             responses: vec![],
             response_index: std::sync::atomic::AtomicUsize::new(0),
+            prompts: Default::default(),
+            secret_reads: Default::default(),
         }
     }
 
@@ -356,6 +661,8 @@ impl MockTerminal {
             is_terminal: true,
             responses: vec![response.into()],
             response_index: std::sync::atomic::AtomicUsize::new(0),
+            prompts: Default::default(),
+            secret_reads: Default::default(),
         }
     }
 
@@ -367,17 +674,38 @@ impl MockTerminal {
             is_terminal: true,
             responses: responses.into_iter().map(Into::into).collect(),
             response_index: std::sync::atomic::AtomicUsize::new(0),
+            prompts: Default::default(),
+            secret_reads: Default::default(),
         }
     }
 
+    /// Create a mock terminal where the user never answers.
+    ///
+    /// Timed reads time out; untimed reads get EOF, like [`eof`](Self::eof).
+    pub fn silent() -> Self {
+        Self::eof()
+    }
+
     /// Create a mock that simulates EOF (Ctrl+D).
     pub fn eof() -> Self {
         Self {
             is_terminal: true,
             responses: vec![], // Empty vec means EOF
             response_index: std::sync::atomic::AtomicUsize::new(0),
+            prompts: Default::default(),
+            secret_reads: Default::default(),
         }
     }
+
+    /// Prompts written so far, in order.
+    pub fn prompts(&self) -> Vec<String> {
+        self.prompts.lock().unwrap().clone()
+    }
+
+    /// The mask of each secret read so far (`None` for hidden input).
+    pub fn secret_reads(&self) -> Vec<Option<char>> {
+        self.secret_reads.lock().unwrap().clone()
+    }
 }
 
 impl TerminalIO for MockTerminal {
@@ -385,8 +713,8 @@ impl TerminalIO for MockTerminal {
         self.is_terminal
     }
 
-    fn write_prompt(&self, _prompt: &str) -> io::Result<()> {
-        // Mock doesn't actually write
+    fn write_prompt(&self, prompt: &str) -> io::Result<()> {
+        self.prompts.lock().unwrap().push(prompt.to_string());
         Ok(())
     }
 
@@ -402,6 +730,17 @@ impl TerminalIO for MockTerminal {
             Ok(String::new())
         }
     }
+
+    fn read_line_timeout(&self, _timeout: Duration) -> io::Result<Option<String>> {
+        // Out of responses, a timed read times out instead of hitting EOF
+        let line = self.read_line()?;
+        Ok(Some(line).filter(|line| !line.is_empty()))
+    }
+
+    fn read_secret(&self, mask: Option<char>) -> io::Result<String> {
+        self.secret_reads.lock().unwrap().push(mask);
+        self.read_line()
+    }
 }
 
 #[cfg(test)]
@@ -479,6 +818,91 @@ mod tests {
         assert!(source.can_retry());
     }
 
+    // === PasswordPromptSource tests ===
+
+    #[test]
+    fn password_prompt_reads_hidden_and_keeps_spaces() {
+        let source = PasswordPromptSource::with_terminal(
+            "Token: ",
+            MockTerminal::with_response(" s3 cr3t "),
+        );
+        let result = source.collect(&empty_matches()).unwrap();
+        assert_eq!(result, Some(" s3 cr3t ".to_string()));
+        assert_eq!(source.terminal.secret_reads(), [None]);
+    }
+
+    #[test]
+    fn password_prompt_masked() {
+        let source =
+            PasswordPromptSource::with_terminal("Pwd: ", MockTerminal::with_response("x")).masked();
+        source.collect(&empty_matches()).unwrap();
+        assert_eq!(source.terminal.secret_reads(), [Some('*')]);
+    }
+
+    #[test]
+    fn password_prompt_confirmation() {
+        let source = PasswordPromptSource::with_terminal(
+            "Password: ",
+            MockTerminal::with_responses(["hunter2", "hunter2"]),
+        )
+        .with_confirmation("Again: ");
+        let result = source.collect(&empty_matches()).unwrap();
+        assert_eq!(result, Some("hunter2".to_string()));
+        assert_eq!(source.terminal.prompts(), ["Password: ", "Again: "]);
+
+        let source = PasswordPromptSource::with_terminal(
+            "Password: ",
+            MockTerminal::with_responses(["hunter2", "hunter3"]),
+        )
+        .with_confirmation("Again: ");
+        let result = source.collect(&empty_matches());
+        assert!(matches!(result, Err(InputError::ValidationFailed(_))));
+    }
+
+    #[test]
+    fn password_prompt_empty_unavailable_and_eof() {
+        let source = PasswordPromptSource::with_terminal("Pwd: ", MockTerminal::with_response(""))
+            .with_confirmation("Again: ");
+        assert_eq!(source.collect(&empty_matches()).unwrap(), None);
+        assert_eq!(source.terminal.prompts(), ["Pwd: "]);
+
+        let source = PasswordPromptSource::with_terminal("Pwd: ", MockTerminal::non_terminal());
+        assert!(!source.is_available(&empty_matches()));
+
+        let source = PasswordPromptSource::with_terminal("Pwd: ", MockTerminal::eof());
+        let result = source.collect(&empty_matches());
+        assert!(matches!(result, Err(InputError::PromptCancelled)));
+    }
+
+    #[test]
+    fn text_prompt_timeout_falls_through() {
+        let terminal = MockTerminal::silent();
+        let source =
+            TextPromptSource::with_terminal("Name: ", terminal).timeout(Duration::from_millis(10));
+        assert_eq!(source.collect(&empty_matches()).unwrap(), None);
+
+        let source =
+            TextPromptSource::with_terminal("Name: ", MockTerminal::with_response("Alice"))
+                .timeout(Duration::from_secs(5));
+        assert_eq!(
+            source.collect(&empty_matches()).unwrap(),
+            Some("Alice".to_string())
+        );
+    }
+
+    #[test]
+    fn timed_out_prompt_moves_chain_on() {
+        let chain = crate::InputChain::<String>::new()
+            .try_source(
+                TextPromptSource::with_terminal("Name: ", MockTerminal::silent())
+                    .timeout(Duration::from_millis(10)),
+            )
+            .default("anonymous".to_string());
+        let result = chain.resolve_with_source(&empty_matches()).unwrap();
+        assert_eq!(result.value, "anonymous");
+        assert_eq!(result.source, crate::InputSourceKind::Default);
+    }
+
     // === ConfirmPromptSource tests ===
 
     #[test]
@@ -549,6 +973,14 @@ mod tests {
         assert_eq!(result, Some(false));
     }
 
+    #[test]
+    fn confirm_prompt_timeout_ignores_default() {
+        let source = ConfirmPromptSource::with_terminal("Proceed?", MockTerminal::silent())
+            .default(true)
+            .timeout(Duration::from_millis(10));
+        assert_eq!(source.collect(&empty_matches()).unwrap(), None);
+    }
+
     #[test]
     fn confirm_prompt_empty_without_default() {
         let source =
@@ -674,6 +1106,14 @@ mod tests {
         assert_eq!(value, "Ada");
     }
 
+    #[test]
+    #[serial(prompt_responder)]
+    fn password_prompt_routes_through_responder() {
+        let _g = ResponderGuard::install(ScriptedResponder::new([PromptResponse::text("s3cret")]));
+        let source = PasswordPromptSource::with_terminal("Pwd: ", MockTerminal::non_terminal());
+        assert_eq!(source.prompt().unwrap(), "s3cret");
+    }
+
     #[test]
     #[serial(prompt_responder)]
     fn confirm_prompt_routes_through_responder() {
@@ -0,0 +1,270 @@
+//! Structured stdin sources.
+//!
+//! [`JsonStdinSource`] and [`YamlStdinSource`] read piped stdin and
+//! deserialize it into a `T`, for commands fed a payload:
+//!
+//! ```text
+//! cat payload.json | myapp create
+//! ```
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use clap::ArgMatches;
+use serde::de::DeserializeOwned;
+
+use crate::collector::InputCollector;
+use crate::env::{DefaultStdin, StdinReader};
+use crate::InputError;
+
+/// Reads piped stdin, returning `None` for a terminal or blank input.
+fn read_piped<R: StdinReader>(reader: &R) -> Result<Option<String>, InputError> {
+    if reader.is_terminal() {
+        return Ok(None);
+    }
+    let content = reader.read_to_string().map_err(InputError::StdinFailed)?;
+    if content.trim().is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(content))
+    }
+}
+
+/// Collect a JSON payload from piped stdin.
+///
+/// Like [`StdinSource`](crate::StdinSource), this only reads when stdin is
+/// piped, so the chain moves on when run interactively. Malformed input or
+/// input that doesn't fit `T` fails with [`InputError::InvalidPayload`],
+/// naming the line and column and the field that didn't match.
+///
+/// # Example
+///
+/// ```ignore
+/// use standout_input::{InputChain, JsonStdinSource, EditorSource};
+///
+/// #[derive(Clone, Deserialize)]
+/// struct NewTask { title: String, priority: u8 }
+///
+/// // cat task.json | myapp create
+/// let task = InputChain::<NewTask>::new()
+///     .try_source(JsonStdinSource::new())
+///     .resolve(&matches)?;
+/// ```
+#[cfg(feature = "json")]
+pub struct JsonStdinSource<T, R: StdinReader = DefaultStdin> {
+    reader: Arc<R>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "json")]
+impl<T> JsonStdinSource<T, DefaultStdin> {
+    /// Create a JSON stdin source.
+    pub fn new() -> Self {
+        Self::with_reader(DefaultStdin)
+    }
+}
+
+#[cfg(feature = "json")]
+impl<T> Default for JsonStdinSource<T, DefaultStdin> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "json")]
+impl<T, R: StdinReader> JsonStdinSource<T, R> {
+    /// Create a JSON stdin source with a custom reader, for testing.
+    pub fn with_reader(reader: R) -> Self {
+        Self {
+            reader: Arc::new(reader),
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "json")]
+impl<T, R> InputCollector<T> for JsonStdinSource<T, R>
+where
+    T: DeserializeOwned + Send + Sync,
+    R: StdinReader + 'static,
+{
+    fn name(&self) -> &'static str {
+        "stdin"
+    }
+
+    fn is_available(&self, _matches: &ArgMatches) -> bool {
+        !self.reader.is_terminal()
+    }
+
+    fn collect(&self, _matches: &ArgMatches) -> Result<Option<T>, InputError> {
+        let Some(content) = read_piped(&*self.reader)? else {
+            return Ok(None);
+        };
+        serde_json::from_str(&content)
+            .map(Some)
+            .map_err(|e| InputError::InvalidPayload {
+                format: "JSON",
+                reason: e.to_string(),
+            })
+    }
+}
+
+/// Collect a YAML payload from piped stdin.
+///
+/// The YAML counterpart of [`JsonStdinSource`]. Since JSON is valid YAML,
+/// this also accepts JSON payloads.
+///
+/// # Example
+///
+/// ```ignore
+/// use standout_input::{InputChain, YamlStdinSource};
+///
+/// // cat task.yaml | myapp create
+/// let task = InputChain::<NewTask>::new()
+///     .try_source(YamlStdinSource::new())
+///     .resolve(&matches)?;
+/// ```
+#[cfg(feature = "yaml")]
+pub struct YamlStdinSource<T, R: StdinReader = DefaultStdin> {
+    reader: Arc<R>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+#[cfg(feature = "yaml")]
+impl<T> YamlStdinSource<T, DefaultStdin> {
+    /// Create a YAML stdin source.
+    pub fn new() -> Self {
+        Self::with_reader(DefaultStdin)
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl<T> Default for YamlStdinSource<T, DefaultStdin> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl<T, R: StdinReader> YamlStdinSource<T, R> {
+    /// Create a YAML stdin source with a custom reader, for testing.
+    pub fn with_reader(reader: R) -> Self {
+        Self {
+            reader: Arc::new(reader),
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "yaml")]
+impl<T, R> InputCollector<T> for YamlStdinSource<T, R>
+where
+    T: DeserializeOwned + Send + Sync,
+    R: StdinReader + 'static,
+{
+    fn name(&self) -> &'static str {
+        "stdin"
+    }
+
+    fn is_available(&self, _matches: &ArgMatches) -> bool {
+        !self.reader.is_terminal()
+    }
+
+    fn collect(&self, _matches: &ArgMatches) -> Result<Option<T>, InputError> {
+        let Some(content) = read_piped(&*self.reader)? else {
+            return Ok(None);
+        };
+        serde_yaml::from_str(&content)
+            .map(Some)
+            .map_err(|e| InputError::InvalidPayload {
+                format: "YAML",
+                reason: e.to_string(),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::env::MockStdin;
+    use clap::Command;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Task {
+        title: String,
+        priority: u8,
+    }
+
+    fn empty_matches() -> ArgMatches {
+        Command::new("test").try_get_matches_from(["test"]).unwrap()
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_deserializes_piped_payload() {
+        let source = JsonStdinSource::<Task, _>::with_reader(MockStdin::piped(
+            r#"{"title": "Ship it", "priority": 2}"#,
+        ));
+        assert!(source.is_available(&empty_matches()));
+        let task = source.collect(&empty_matches()).unwrap();
+        assert_eq!(
+            task,
+            Some(Task {
+                title: "Ship it".into(),
+                priority: 2
+            })
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_skips_terminal_and_empty_input() {
+        let source = JsonStdinSource::<Task, _>::with_reader(MockStdin::terminal());
+        assert!(!source.is_available(&empty_matches()));
+        assert_eq!(source.collect(&empty_matches()).unwrap(), None);
+
+        let source = JsonStdinSource::<Task, _>::with_reader(MockStdin::piped("  \n"));
+        assert_eq!(source.collect(&empty_matches()).unwrap(), None);
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_reports_schema_errors() {
+        let source = JsonStdinSource::<Task, _>::with_reader(MockStdin::piped(
+            "{\n  \"title\": \"Ship it\",\n  \"priority\": \"high\"\n}",
+        ));
+        let err = source.collect(&empty_matches()).unwrap_err();
+        let message = err.to_string();
+        assert!(message.starts_with("Invalid JSON on stdin: invalid type"));
+        assert!(message.contains("line 3"));
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn yaml_deserializes_piped_payload() {
+        let source = YamlStdinSource::<Task, _>::with_reader(MockStdin::piped(
+            "title: Ship it\npriority: 2\n",
+        ));
+        let task = source.collect(&empty_matches()).unwrap();
+        assert_eq!(
+            task,
+            Some(Task {
+                title: "Ship it".into(),
+                priority: 2
+            })
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn yaml_reports_missing_fields() {
+        let source = YamlStdinSource::<Task, _>::with_reader(MockStdin::piped("title: Ship it\n"));
+        let err = source.collect(&empty_matches()).unwrap_err();
+        assert!(matches!(
+            err,
+            InputError::InvalidPayload { format: "YAML", .. }
+        ));
+        assert!(err.to_string().contains("missing field `priority`"));
+    }
+}
@@ -425,3 +425,83 @@ fn mock_stdin_preserves_whitespace_when_configured() {
         .try_source(StdinSource::with_reader(MockStdin::piped("  hello  \n")).trim(false));
     assert_eq!(chain.resolve(&matches).unwrap(), "  hello  \n");
 }
+
+// ============================================================================
+// Test: --yes / --no-input prompt modes
+// ============================================================================
+
+mod prompt_modes {
+    use super::*;
+    use serial_test::serial;
+    use standout_input::{
+        set_prompt_mode, ConfirmPromptSource, MockTerminal, PromptMode, TextPromptSource,
+    };
+
+    struct ModeGuard;
+
+    impl Drop for ModeGuard {
+        fn drop(&mut self) {
+            set_prompt_mode(PromptMode::Ask);
+        }
+    }
+
+    fn with_mode(mode: PromptMode) -> ModeGuard {
+        set_prompt_mode(mode);
+        ModeGuard
+    }
+
+    #[test]
+    #[serial]
+    fn assume_yes_answers_confirm_without_asking() {
+        let _guard = with_mode(PromptMode::AssumeYes);
+        let matches = create_test_command()
+            .try_get_matches_from(["test"])
+            .unwrap();
+
+        let terminal = MockTerminal::with_response("n");
+        let source = ConfirmPromptSource::with_terminal("Proceed?", terminal.clone());
+        let chain = InputChain::<bool>::new().try_source(source);
+        assert!(chain.resolve(&matches).unwrap());
+        assert!(terminal.prompts().is_empty());
+
+        let source = ConfirmPromptSource::with_terminal("Proceed?", MockTerminal::non_terminal())
+            .default(false);
+        let chain = InputChain::<bool>::new().try_source(source);
+        assert!(!chain.resolve(&matches).unwrap());
+    }
+
+    #[test]
+    #[serial]
+    fn assume_yes_skips_text_prompts() {
+        let _guard = with_mode(PromptMode::AssumeYes);
+        let matches = create_test_command()
+            .try_get_matches_from(["test"])
+            .unwrap();
+
+        let terminal = MockTerminal::with_response("typed");
+        let chain = InputChain::<String>::new()
+            .try_source(TextPromptSource::with_terminal("Name: ", terminal.clone()))
+            .default("fallback".to_string());
+        assert_eq!(chain.resolve(&matches).unwrap(), "fallback");
+        assert!(terminal.prompts().is_empty());
+    }
+
+    #[test]
+    #[serial]
+    fn no_input_skips_confirm_prompts() {
+        let _guard = with_mode(PromptMode::NoInput);
+        let matches = create_test_command()
+            .try_get_matches_from(["test"])
+            .unwrap();
+
+        let terminal = MockTerminal::with_response("y");
+        let chain = InputChain::<bool>::new()
+            .try_source(ConfirmPromptSource::with_terminal(
+                "Proceed?",
+                terminal.clone(),
+            ))
+            .default(false);
+        assert!(!chain.resolve(&matches).unwrap());
+        assert!(terminal.prompts().is_empty());
+    }
+}
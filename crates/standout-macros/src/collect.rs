@@ -0,0 +1,402 @@
+//! Implementation of the `#[derive(Collect)]` macro.
+//!
+//! Each field is resolved by an `InputChain` built from its `#[input(...)]`
+//! attribute, with sources tried in the order they're written. The macro
+//! generates an inherent `collect(&ArgMatches)` returning the struct.
+//!
+//! How a field's value is produced depends on its type:
+//!
+//! - `bool` fields use a `bool` chain (`FlagSource`, `ConfirmPromptSource`)
+//!   and are `false` when no source answers
+//! - `Option<T>` fields are `None` when no source answers
+//! - other fields use a `String` chain, converted with `FromStr` unless the
+//!   field is a `String`, and fail with `MissingArgument` when no source
+//!   answers
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Data, DeriveInput, Error, Expr, ExprLit, Fields, GenericArgument, Lit, Meta, PathArguments,
+    Result, Token, Type,
+};
+
+/// An input source, as written in `#[input(...)]`.
+enum Source {
+    /// `arg` or `arg = "name"`
+    Arg(Option<String>),
+    /// `flag` or `flag = "name"`
+    Flag(Option<String>),
+    /// `env = "VAR"`
+    Env(String),
+    /// `config = "path"`
+    Config(String),
+    /// `stdin`
+    Stdin,
+    /// `clipboard`
+    Clipboard,
+    /// `editor`
+    Editor,
+    /// `prompt` or `prompt = "Message: "`
+    Prompt(Option<String>),
+}
+
+/// Field-level attributes from `#[input(...)]`.
+#[derive(Default)]
+struct InputAttr {
+    sources: Vec<(Source, proc_macro2::Span)>,
+    default: Option<Expr>,
+}
+
+impl Parse for InputAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut attr = InputAttr::default();
+        let content: Punctuated<Meta, Token![,]> = Punctuated::parse_terminated(input)?;
+
+        for meta in content {
+            let span = meta.span();
+            let name = meta
+                .path()
+                .get_ident()
+                .map(|ident| ident.unraw().to_string())
+                .unwrap_or_default();
+            let value = match &meta {
+                Meta::Path(_) => None,
+                Meta::NameValue(nv) => Some(&nv.value),
+                Meta::List(_) => {
+                    return Err(Error::new(span, "expected `source` or `source = ...`"));
+                }
+            };
+
+            let source = match name.as_str() {
+                "default" => {
+                    let value =
+                        value.ok_or_else(|| Error::new(span, "expected `default = <expr>`"))?;
+                    attr.default = Some(value.clone());
+                    continue;
+                }
+                "arg" => Source::Arg(value.map(string_value).transpose()?),
+                "flag" => Source::Flag(value.map(string_value).transpose()?),
+                "env" => {
+                    let value =
+                        value.ok_or_else(|| Error::new(span, "expected `env = \"VAR\"`"))?;
+                    Source::Env(string_value(value)?)
+                }
+                "config" => {
+                    let value =
+                        value.ok_or_else(|| Error::new(span, "expected `config = \"path\"`"))?;
+                    Source::Config(string_value(value)?)
+                }
+                "prompt" => Source::Prompt(value.map(string_value).transpose()?),
+                "stdin" | "clipboard" | "editor" if value.is_some() => {
+                    return Err(Error::new(span, format!("`{}` takes no value", name)));
+                }
+                "stdin" => Source::Stdin,
+                "clipboard" => Source::Clipboard,
+                "editor" => Source::Editor,
+                _ => {
+                    return Err(Error::new(
+                        span,
+                        "unknown input attribute. Expected: arg, flag, env, config, stdin, \
+                         clipboard, editor, prompt or default",
+                    ))
+                }
+            };
+            attr.sources.push((source, span));
+        }
+
+        Ok(attr)
+    }
+}
+
+fn string_value(expr: &Expr) -> Result<String> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(s), ..
+        }) => Ok(s.value()),
+        _ => Err(Error::new(expr.span(), "expected a string literal")),
+    }
+}
+
+/// How a field's type is collected.
+enum FieldKind<'a> {
+    Bool,
+    String,
+    Parsed(&'a Type),
+}
+
+/// Splits `Option<T>` into `T`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
+fn field_kind(ty: &Type) -> FieldKind<'_> {
+    if let Type::Path(path) = ty {
+        if path.path.is_ident("bool") {
+            return FieldKind::Bool;
+        }
+        if path
+            .path
+            .segments
+            .last()
+            .is_some_and(|s| s.ident == "String")
+        {
+            return FieldKind::String;
+        }
+    }
+    FieldKind::Parsed(ty)
+}
+
+/// Default prompt for a field: `due_date` becomes `"Due date: "`.
+fn prompt_for(field_name: &str) -> String {
+    let text = field_name.replace('_', " ");
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => format!("{}{}: ", first.to_uppercase(), chars.as_str()),
+        None => text,
+    }
+}
+
+/// Builds the source expression for a field.
+fn source_tokens(
+    source: &Source,
+    span: proc_macro2::Span,
+    field_name: &str,
+    is_bool: bool,
+) -> Result<TokenStream> {
+    let unsupported = |what: &str| {
+        Err(Error::new(
+            span,
+            if is_bool {
+                format!("`{}` can't collect a bool; use arg, flag or prompt", what)
+            } else {
+                format!("`{}` only collects bool fields; use arg", what)
+            },
+        ))
+    };
+
+    Ok(match source {
+        Source::Arg(name) | Source::Flag(name) if is_bool => {
+            let name = name.as_deref().unwrap_or(field_name);
+            quote! { ::standout::input::FlagSource::new(#name) }
+        }
+        Source::Arg(name) => {
+            let name = name.as_deref().unwrap_or(field_name);
+            quote! { ::standout::input::ArgSource::new(#name) }
+        }
+        Source::Prompt(message) => {
+            let message = message.clone().unwrap_or_else(|| prompt_for(field_name));
+            if is_bool {
+                quote! { ::standout::input::ConfirmPromptSource::new(#message) }
+            } else {
+                quote! { ::standout::input::TextPromptSource::new(#message) }
+            }
+        }
+        Source::Flag(_) => return unsupported("flag"),
+        _ if is_bool => {
+            return unsupported(match source {
+                Source::Env(_) => "env",
+                Source::Config(_) => "config",
+                Source::Stdin => "stdin",
+                Source::Clipboard => "clipboard",
+                _ => "editor",
+            })
+        }
+        Source::Env(var) => quote! { ::standout::input::EnvSource::new(#var) },
+        Source::Config(path) => quote! { ::standout::input::ConfigSource::new(#path) },
+        Source::Stdin => quote! { ::standout::input::StdinSource::new() },
+        Source::Clipboard => quote! { ::standout::input::ClipboardSource::new() },
+        Source::Editor => quote! { ::standout::input::EditorSource::new() },
+    })
+}
+
+/// Main implementation of the Collect derive macro.
+pub fn collect_derive_impl(input: DeriveInput) -> Result<TokenStream> {
+    let struct_name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(Error::new(
+                    input.span(),
+                    "Collect can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "Collect can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut idents = Vec::new();
+    let mut resolvers = Vec::new();
+
+    for field in fields {
+        let ident = field
+            .ident
+            .as_ref()
+            .ok_or_else(|| Error::new(field.span(), "expected named field"))?;
+        let mut attr = InputAttr::default();
+        for a in &field.attrs {
+            if a.path().is_ident("input") {
+                attr = a.parse_args()?;
+            }
+        }
+        let field_name = ident.unraw().to_string();
+        if attr.sources.is_empty() {
+            attr.sources.push((Source::Arg(None), ident.span()));
+        }
+
+        let optional = option_inner(&field.ty);
+        let kind = field_kind(optional.unwrap_or(&field.ty));
+        let is_bool = matches!(kind, FieldKind::Bool);
+        let chain_ty = if is_bool {
+            quote! { bool }
+        } else {
+            quote! { ::std::string::String }
+        };
+
+        let sources = attr
+            .sources
+            .iter()
+            .map(|(source, span)| source_tokens(source, *span, &field_name, is_bool))
+            .collect::<Result<Vec<_>>>()?;
+
+        let value = match kind {
+            FieldKind::Bool | FieldKind::String => quote! { __value },
+            FieldKind::Parsed(ty) => quote! {
+                <#ty as ::std::str::FromStr>::from_str(&__value).map_err(|e| {
+                    ::standout::input::InputError::parse(#field_name, e.to_string())
+                })?
+            },
+        };
+        let (value, missing) = match (optional, attr.default) {
+            (Some(_), Some(default)) => (
+                quote! { ::std::option::Option::Some(#value) },
+                quote! { ::std::option::Option::Some(::std::convert::Into::into(#default)) },
+            ),
+            (Some(_), None) => (
+                quote! { ::std::option::Option::Some(#value) },
+                quote! { ::std::option::Option::None },
+            ),
+            (None, Some(default)) => (value, quote! { ::std::convert::Into::into(#default) }),
+            (None, None) if is_bool => (value, quote! { false }),
+            (None, None) => (
+                value,
+                quote! {
+                    return ::std::result::Result::Err(
+                        ::standout::input::InputError::MissingArgument(#field_name.to_string()),
+                    )
+                },
+            ),
+        };
+
+        resolvers.push(quote! {
+            let #ident = match ::standout::input::InputChain::<#chain_ty>::new()
+                #(.try_source(#sources))*
+                .resolve(matches)
+            {
+                ::std::result::Result::Ok(__value) => #value,
+                ::std::result::Result::Err(::standout::input::InputError::NoInput) => #missing,
+                ::std::result::Result::Err(e) => return ::std::result::Result::Err(e),
+            };
+        });
+        idents.push(ident);
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let doc = format!(
+        "Collect a `{}` from its fields' input sources.",
+        struct_name.unraw()
+    );
+
+    Ok(quote! {
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            #[doc = #doc]
+            ///
+            /// Returns the first error from a source, or `MissingArgument`
+            /// for a required field no source provided.
+            pub fn collect(
+                matches: &::clap::ArgMatches,
+            ) -> ::std::result::Result<Self, ::standout::input::InputError> {
+                #(#resolvers)*
+                ::std::result::Result::Ok(Self { #(#idents),* })
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt_for() {
+        assert_eq!(prompt_for("title"), "Title: ");
+        assert_eq!(prompt_for("due_date"), "Due date: ");
+    }
+
+    #[test]
+    fn test_sources_in_order() {
+        let input: DeriveInput = syn::parse_quote! {
+            struct NewTask {
+                #[input(arg, env = "TASK_TITLE", config = "task.title", prompt)]
+                title: String,
+                #[input(flag = "urgent")]
+                urgent: bool,
+                priority: Option<u8>,
+            }
+        };
+        let output = collect_derive_impl(input).unwrap().to_string();
+
+        let arg = output.find("ArgSource :: new (\"title\")").unwrap();
+        let env = output.find("EnvSource :: new (\"TASK_TITLE\")").unwrap();
+        let config = output.find("ConfigSource :: new (\"task.title\")").unwrap();
+        let prompt = output
+            .find("TextPromptSource :: new (\"Title: \")")
+            .unwrap();
+        assert!(arg < env && env < config && config < prompt);
+        assert!(output.contains("FlagSource :: new (\"urgent\")"));
+        assert!(output.contains("ArgSource :: new (\"priority\")"));
+        assert!(output.contains("< u8 as :: std :: str :: FromStr > :: from_str"));
+    }
+
+    #[test]
+    fn test_rejects_unsupported_sources() {
+        let bool_env: DeriveInput = syn::parse_quote! {
+            struct Opts {
+                #[input(env = "FORCE")]
+                force: bool,
+            }
+        };
+        let err = collect_derive_impl(bool_env).err().unwrap();
+        assert!(err.to_string().contains("`env` can't collect a bool"));
+
+        let unknown: DeriveInput = syn::parse_quote! {
+            struct Opts {
+                #[input(file)]
+                body: String,
+            }
+        };
+        assert!(collect_derive_impl(unknown).is_err());
+    }
+}
@@ -25,6 +25,7 @@
 //! | Attribute | Required | Description |
 //! |-----------|----------|-------------|
 //! | `handlers = path` | Yes | Module containing handler functions |
+//! | `template_dir = "dir"` | No | Directory of the variants' templates: `{dir}/{snake_case}` |
 //!
 //! # Variant Attributes
 //!
@@ -34,6 +35,9 @@
 //! |-----------|-------------|---------|
 //! | `handler = path` | Handler function path | `{handlers}::{snake_case}` |
 //! | `template = "path"` | Template file path | `{snake_case}.j2` |
+//! | `output = "mode"` | Output mode when `--output` isn't given (`json`, `yaml`, ...) | `auto` |
+//! | `alias = "name"` | Command alias, shown in help (repeatable) | None |
+//! | `hidden` | Hide from help, completions and docs | false |
 //! | `pre_dispatch = fn` | Pre-dispatch hook | None |
 //! | `post_dispatch = fn` | Post-dispatch hook | None |
 //! | `post_output = fn` | Post-output hook | None |
@@ -58,10 +62,12 @@ use syn::{
     Data, DeriveInput, Error, Expr, Fields, Meta, Path, Result, Token,
 };
 
-/// Container-level attributes: `#[dispatch(handlers = path)]`
+/// Container-level attributes: `#[dispatch(handlers = path, template_dir = "...")]`
 #[derive(Default)]
 struct ContainerAttrs {
     handlers: Option<Path>,
+    /// Directory that variant templates are looked up in
+    template_dir: Option<String>,
 }
 
 /// Variant-level attributes: `#[dispatch(handler = path, template = "...", ...)]`
@@ -69,6 +75,10 @@ struct ContainerAttrs {
 struct VariantAttrs {
     handler: Option<Path>,
     template: Option<String>,
+    /// Default output mode, as the `OutputMode` variant name
+    output: Option<syn::Ident>,
+    aliases: Vec<String>,
+    hidden: bool,
     pre_dispatch: Option<Path>,
     post_dispatch: Option<Path>,
     post_output: Option<Path>,
@@ -109,10 +119,20 @@ impl Parse for ContainerAttrs {
                         return Err(Error::new(nv.value.span(), "expected path"));
                     }
                 }
+                Meta::NameValue(nv) if nv.path.is_ident("template_dir") => {
+                    let Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit_str),
+                        ..
+                    }) = &nv.value
+                    else {
+                        return Err(Error::new(nv.value.span(), "expected string literal"));
+                    };
+                    attrs.template_dir = Some(lit_str.value().trim_end_matches('/').to_string());
+                }
                 _ => {
                     return Err(Error::new(
                         meta.span(),
-                        "unknown attribute, expected `handlers = path`",
+                        "unknown attribute, expected `handlers = path` or `template_dir = \"...\"`",
                     ));
                 }
             }
@@ -148,6 +168,35 @@ impl Parse for VariantAttrs {
                         return Err(Error::new(nv.value.span(), "expected string literal"));
                     }
                 }
+                Meta::NameValue(nv) if nv.path.is_ident("output") => {
+                    let Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit_str),
+                        ..
+                    }) = &nv.value
+                    else {
+                        return Err(Error::new(nv.value.span(), "expected string literal"));
+                    };
+                    let variant = output_mode_variant(&lit_str.value()).ok_or_else(|| {
+                        Error::new(
+                            lit_str.span(),
+                            "unknown output mode, expected one of: auto, term, text, term-debug, json, yaml, xml, toml, csv",
+                        )
+                    })?;
+                    attrs.output = Some(format_ident!("{}", variant));
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("alias") => {
+                    let Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit_str),
+                        ..
+                    }) = &nv.value
+                    else {
+                        return Err(Error::new(nv.value.span(), "expected string literal"));
+                    };
+                    attrs.aliases.push(lit_str.value());
+                }
+                Meta::Path(p) if p.is_ident("hidden") => {
+                    attrs.hidden = true;
+                }
                 Meta::NameValue(nv) if nv.path.is_ident("pre_dispatch") => {
                     if let Expr::Path(expr_path) = &nv.value {
                         attrs.pre_dispatch = Some(expr_path.path.clone());
@@ -226,7 +275,7 @@ impl Parse for VariantAttrs {
                 _ => {
                     return Err(Error::new(
                         meta.span(),
-                        "unknown attribute, expected one of: handler, template, pre_dispatch, post_dispatch, post_output, nested, skip, default, list_view, item_type, pipe_to, pipe_through, pipe_to_clipboard, simple, pure",
+                        "unknown attribute, expected one of: handler, template, output, alias, hidden, pre_dispatch, post_dispatch, post_output, nested, skip, default, list_view, item_type, pipe_to, pipe_through, pipe_to_clipboard, simple, pure",
                     ));
                 }
             }
@@ -236,6 +285,22 @@ impl Parse for VariantAttrs {
     }
 }
 
+/// Maps an `--output` value to its `OutputMode` variant name
+fn output_mode_variant(mode: &str) -> Option<&'static str> {
+    match mode {
+        "auto" => Some("Auto"),
+        "term" => Some("Term"),
+        "text" => Some("Text"),
+        "term-debug" => Some("TermDebug"),
+        "json" => Some("Json"),
+        "yaml" => Some("Yaml"),
+        "xml" => Some("Xml"),
+        "toml" => Some("Toml"),
+        "csv" => Some("Csv"),
+        _ => None,
+    }
+}
+
 /// Converts PascalCase to snake_case
 fn to_snake_case(s: &str) -> String {
     let mut result = String::new();
@@ -334,6 +399,13 @@ pub fn dispatch_derive_impl(input: DeriveInput) -> Result<TokenStream> {
         //    This fixes the bug where Command(String) was treated as nested.
         let is_nested = attrs.nested;
 
+        if is_nested && (!attrs.aliases.is_empty() || attrs.hidden) {
+            return Err(Error::new(
+                variant.span(),
+                "`alias` and `hidden` apply to commands, not #[dispatch(nested)] groups",
+            ));
+        }
+
         if is_nested && nested_type_candidate.is_none() {
             return Err(Error::new(
                 variant.span(),
@@ -402,8 +474,14 @@ pub fn dispatch_derive_impl(input: DeriveInput) -> Result<TokenStream> {
                 if v.attrs.list_view && v_template.is_none() {
                     v_template = Some("standout/list-view".to_string());
                 }
+                if let (None, Some(dir)) = (&v_template, &container_attrs.template_dir) {
+                    v_template = Some(format!("{}/{}", dir, v.snake_name));
+                }
 
                 let has_config = v_template.is_some()
+                    || v.attrs.output.is_some()
+                    || !v.attrs.aliases.is_empty()
+                    || v.attrs.hidden
                     || v.attrs.pre_dispatch.is_some()
                     || v.attrs.post_dispatch.is_some()
                     || v.attrs.post_output.is_some()
@@ -428,7 +506,7 @@ pub fn dispatch_derive_impl(input: DeriveInput) -> Result<TokenStream> {
                                     result.map(|output| {
                                         match output {
                                             ::standout::cli::handler::Output::Render(mut lv) => {
-                                                 lv.tabular_spec = Some(<#item_type_path as ::standout::tabular::Tabular>::tabular_spec());
+                                                 lv.set_tabular_spec(<#item_type_path as ::standout::tabular::Tabular>::tabular_spec());
                                                  ::standout::cli::handler::Output::Render(lv)
                                             }
                                             o => o
@@ -443,7 +521,7 @@ pub fn dispatch_derive_impl(input: DeriveInput) -> Result<TokenStream> {
                                     result.map(|output| {
                                         match output {
                                             ::standout::cli::handler::Output::Render(mut lv) => {
-                                                 lv.tabular_spec = Some(<#item_type_path as ::standout::tabular::Tabular>::tabular_spec());
+                                                 lv.set_tabular_spec(<#item_type_path as ::standout::tabular::Tabular>::tabular_spec());
                                                  ::standout::cli::handler::Output::Render(lv)
                                             }
                                             o => o
@@ -470,6 +548,15 @@ pub fn dispatch_derive_impl(input: DeriveInput) -> Result<TokenStream> {
                     let template_call = v_template.as_ref().map(|t| {
                         quote! { __cfg = __cfg.template(#t); }
                     });
+                    let output_call = v.attrs.output.as_ref().map(|variant| {
+                        quote! { __cfg = __cfg.output(::standout::OutputMode::#variant); }
+                    });
+                    let alias_calls = v.attrs.aliases.iter().map(|alias| {
+                        quote! { __cfg = __cfg.alias(#alias); }
+                    });
+                    let hidden_call = v.attrs.hidden.then(|| {
+                        quote! { __cfg = __cfg.hidden(); }
+                    });
                     let pre_dispatch_call = v.attrs.pre_dispatch.as_ref().map(|p| {
                         quote! { __cfg = __cfg.pre_dispatch(#p); }
                     });
@@ -494,6 +581,9 @@ pub fn dispatch_derive_impl(input: DeriveInput) -> Result<TokenStream> {
                     quote! {
                         let __builder = __builder.command_with(#cmd_name, #handler_expr, |mut __cfg| {
                             #template_call
+                            #output_call
+                            #(#alias_calls)*
+                            #hidden_call
                             #pre_dispatch_call
                             #post_dispatch_call
                             #post_output_call
@@ -550,6 +640,13 @@ mod tests {
         assert_eq!(to_snake_case("getHTTPResponse"), "get_h_t_t_p_response");
     }
 
+    #[test]
+    fn test_output_mode_variant() {
+        assert_eq!(output_mode_variant("json"), Some("Json"));
+        assert_eq!(output_mode_variant("term-debug"), Some("TermDebug"));
+        assert_eq!(output_mode_variant("JSON"), None);
+    }
+
     #[test]
     fn test_to_snake_case_simple() {
         assert_eq!(to_snake_case("Complete"), "complete");
@@ -35,6 +35,9 @@ pub const TEMPLATE_EXTENSIONS: &[&str] = &[".jinja", ".jinja2", ".j2", ".txt"];
 /// Stylesheet file extensions (must match standout::style::STYLESHEET_EXTENSIONS).
 pub const STYLESHEET_EXTENSIONS: &[&str] = &[".css", ".yaml", ".yml"];
 
+/// Help topic file extensions (must match standout::topics).
+pub const TOPIC_EXTENSIONS: &[&str] = &[".txt", ".md"];
+
 /// Generates code to create an EmbeddedTemplates source.
 ///
 /// This function:
@@ -123,11 +126,55 @@ pub fn embed_styles_impl(input: LitStr) -> TokenStream {
     }
 }
 
+/// Generates code to create an EmbeddedTopics source.
+///
+/// This function:
+/// 1. Walks the directory at compile time
+/// 2. Collects all files matching topic extensions
+/// 3. Generates an `EmbeddedSource<TopicResource>` with entries and source path
+///
+/// The returned `EmbeddedSource` can be passed to `App::builder().topics()` or
+/// converted to a `TopicRegistry` via `into()`.
+pub fn embed_topics_impl(input: LitStr) -> TokenStream {
+    let source_path = input.value();
+    let dir_path = resolve_path(&source_path);
+
+    let files = match collect_files(&dir_path, TOPIC_EXTENSIONS) {
+        Ok(files) => files,
+        Err(e) => {
+            return syn::Error::new(input.span(), e).to_compile_error();
+        }
+    };
+
+    // Store the absolute path for runtime hot-reload to work correctly
+    let absolute_path = dir_path.to_string_lossy().to_string();
+
+    // Generate array of (name_with_ext, content) tuples
+    let entries: Vec<_> = files
+        .iter()
+        .map(|(name, content)| {
+            quote! { (#name, #content) }
+        })
+        .collect();
+
+    quote! {
+        {
+            static ENTRIES: &[(&str, &str)] = &[
+                #(#entries),*
+            ];
+            ::standout::EmbeddedSource::<::standout::TopicResource>::new(
+                ENTRIES,
+                #absolute_path,
+            )
+        }
+    }
+}
+
 /// Resolves a path relative to the crate's manifest directory.
 ///
 /// CARGO_MANIFEST_DIR is set during compilation to the directory containing
 /// the Cargo.toml of the crate being compiled (not the proc-macro crate).
-fn resolve_path(path: &str) -> PathBuf {
+pub(crate) fn resolve_path(path: &str) -> PathBuf {
     let manifest_dir = std::env::var("CARGO_MANIFEST_DIR")
         .expect("CARGO_MANIFEST_DIR should be set during compilation");
     Path::new(&manifest_dir).join(path)
@@ -139,7 +186,10 @@ fn resolve_path(path: &str) -> PathBuf {
 /// is the relative path from root INCLUDING the extension (e.g., "themes/dark.css").
 ///
 /// NO extension stripping or priority logic is done here - that's the registry's job.
-fn collect_files(dir: &Path, extensions: &[&str]) -> Result<Vec<(String, String)>, String> {
+pub(crate) fn collect_files(
+    dir: &Path,
+    extensions: &[&str],
+) -> Result<Vec<(String, String)>, String> {
     if !dir.exists() {
         return Err(format!("Directory not found: {}", dir.display()));
     }
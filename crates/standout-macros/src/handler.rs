@@ -57,6 +57,8 @@
 //! | `#[arg]` | `Option<T>` | `m.get_one::<T>("name").cloned()` |
 //! | `#[arg]` | `Vec<T>` | `m.get_many::<T>("name")...` |
 //! | `#[arg(name = "x")]` | `T` | `m.get_one::<T>("x")...` |
+//! | `#[arg(default = 10)]` | `T` | `m.get_one::<T>("name").cloned().unwrap_or_else(...)` |
+//! | `#[arg(parser = f)]` | `T`, `Option<T>`, `Vec<T>` | `f(m.get_one::<String>("name")...)` |
 //! | `#[ctx]` | `&CommandContext` | Pass through from wrapper |
 //! | `#[matches]` | `&ArgMatches` | Pass through directly |
 //!
@@ -66,6 +68,19 @@
 //! |-------------|---------------------------|
 //! | `Result<T, E>` | `Result<T, E>` (dispatch auto-wraps via IntoHandlerResult) |
 //! | `Result<(), E>` | `HandlerResult<()>` with explicit `Output::Silent` |
+//! | `Result<T, E>` with a `parser` | `HandlerResult<T>` |
+//!
+//! # Defaults and Parsers
+//!
+//! `default` is used when the argument is absent, so the clap argument can
+//! be optional. A string literal is converted with `From` (`"."` for a
+//! `PathBuf`); any other expression must have the parameter's type.
+//!
+//! `parser` names a `fn(&str) -> Result<T, E>` with `E: Display`. The
+//! argument is read as a `String` and passed through it; a failure is a
+//! usage error (exit code 2) naming the argument and value. With a parser,
+//! a string literal `default` is parsed too: `#[arg(default = "30s",
+//! parser = parse_duration)]`.
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
@@ -73,7 +88,7 @@ use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
-    Error, Expr, FnArg, ItemFn, Meta, Pat, PatType, Result, Token, Type,
+    Error, Expr, FnArg, ItemFn, Lit, Meta, Pat, PatType, Path, Result, Token, Type,
 };
 
 /// Information about a parameter annotation
@@ -81,8 +96,12 @@ use syn::{
 enum ParamKind {
     /// `#[flag]` or `#[flag(name = "x")]`
     Flag { cli_name: Option<String> },
-    /// `#[arg]` or `#[arg(name = "x")]`
-    Arg { cli_name: Option<String> },
+    /// `#[arg]` or `#[arg(name = "x", default = ..., parser = ...)]`
+    Arg {
+        cli_name: Option<String>,
+        default: Option<Box<Expr>>,
+        parser: Option<Path>,
+    },
     /// `#[ctx]` - CommandContext reference
     Ctx,
     /// `#[matches]` - ArgMatches reference
@@ -103,14 +122,18 @@ struct ParamInfo {
     kind: ParamKind,
 }
 
-/// Attribute arguments for #[flag(name = "x")] or #[arg(name = "x")]
+/// Attribute arguments for #[flag(name = "x")] or
+/// #[arg(name = "x", default = ..., parser = ...)]
+#[derive(Default)]
 struct AttrArgs {
     name: Option<String>,
+    default: Option<Box<Expr>>,
+    parser: Option<Path>,
 }
 
 impl Parse for AttrArgs {
     fn parse(input: ParseStream) -> Result<Self> {
-        let mut args = AttrArgs { name: None };
+        let mut args = AttrArgs::default();
 
         if input.is_empty() {
             return Ok(args);
@@ -130,10 +153,18 @@ impl Parse for AttrArgs {
                     } else {
                         return Err(Error::new(nv.value.span(), "expected string literal"));
                     }
+                } else if nv.path.is_ident("default") {
+                    args.default = Some(Box::new(nv.value));
+                } else if nv.path.is_ident("parser") {
+                    if let Expr::Path(expr_path) = &nv.value {
+                        args.parser = Some(expr_path.path.clone());
+                    } else {
+                        return Err(Error::new(nv.value.span(), "expected a function path"));
+                    }
                 } else {
                     return Err(Error::new(
                         nv.path.span(),
-                        "unknown attribute, expected `name`",
+                        "unknown attribute, expected `name`, `default` or `parser`",
                     ));
                 }
             }
@@ -148,22 +179,37 @@ fn parse_param_kind(pat_type: &PatType) -> Result<ParamKind> {
     for attr in &pat_type.attrs {
         if attr.path().is_ident("flag") {
             let args: AttrArgs = if attr.meta.require_path_only().is_ok() {
-                AttrArgs { name: None }
+                AttrArgs::default()
             } else {
                 attr.parse_args()?
             };
+            if args.default.is_some() || args.parser.is_some() {
+                return Err(Error::new(
+                    attr.span(),
+                    "`default` and `parser` only apply to #[arg] parameters",
+                ));
+            }
             return Ok(ParamKind::Flag {
                 cli_name: args.name,
             });
         }
         if attr.path().is_ident("arg") {
             let args: AttrArgs = if attr.meta.require_path_only().is_ok() {
-                AttrArgs { name: None }
+                AttrArgs::default()
             } else {
                 attr.parse_args()?
             };
+            if args.default.is_some() && (is_option_type(&pat_type.ty) || is_vec_type(&pat_type.ty))
+            {
+                return Err(Error::new(
+                    attr.span(),
+                    "`default` needs a plain type, not Option<T> or Vec<T>",
+                ));
+            }
             return Ok(ParamKind::Arg {
                 cli_name: args.name,
+                default: args.default,
+                parser: args.parser,
             });
         }
         if attr.path().is_ident("ctx") {
@@ -259,9 +305,9 @@ fn generate_expected_arg(param: &ParamInfo) -> Option<TokenStream> {
         ParamKind::Flag { .. } => Some(quote! {
             ::standout_dispatch::verify::ExpectedArg::flag(#cli_name, #rust_name)
         }),
-        ParamKind::Arg { .. } => {
+        ParamKind::Arg { default, .. } => {
             let ty = &param.ty;
-            if is_option_type(ty) {
+            if is_option_type(ty) || default.is_some() {
                 Some(quote! {
                     ::standout_dispatch::verify::ExpectedArg::optional_arg(#cli_name, #rust_name)
                 })
@@ -291,6 +337,73 @@ fn generate_extraction(param: &ParamInfo) -> TokenStream {
                 let #rust_name: bool = __matches.get_flag(#cli_name);
             }
         }
+        ParamKind::Arg {
+            parser: Some(parser),
+            default,
+            ..
+        } => {
+            // Values are read as strings and converted by the parser
+            let parse = quote! {
+                #parser(__value).map_err(|e| {
+                    ::standout_dispatch::ExitError::invalid_value(#cli_name, __value, e)
+                })
+            };
+            if is_option_type(ty) {
+                quote! {
+                    let #rust_name: #ty = __matches
+                        .get_one::<::std::string::String>(#cli_name)
+                        .map(|__value| #parse)
+                        .transpose()?;
+                }
+            } else if is_vec_type(ty) {
+                quote! {
+                    let #rust_name: #ty = __matches
+                        .get_many::<::std::string::String>(#cli_name)
+                        .into_iter()
+                        .flatten()
+                        .map(|__value| #parse)
+                        .collect::<::std::result::Result<_, _>>()?;
+                }
+            } else {
+                let missing = match default.as_deref() {
+                    // A string default is parsed like a command-line value
+                    Some(Expr::Lit(syn::ExprLit {
+                        lit: Lit::Str(lit), ..
+                    })) => quote! {{
+                        let __value: &str = #lit;
+                        #parse?
+                    }},
+                    Some(default) => quote! { #default },
+                    None => quote! {
+                        panic!(concat!("Missing required argument '", #cli_name, "' - ensure clap definition matches handler"))
+                    },
+                };
+                quote! {
+                    let #rust_name: #ty = match __matches.get_one::<::std::string::String>(#cli_name) {
+                        ::std::option::Option::Some(__value) => #parse?,
+                        ::std::option::Option::None => #missing,
+                    };
+                }
+            }
+        }
+        ParamKind::Arg {
+            default: Some(default),
+            ..
+        } => {
+            // A string default converts into the parameter type (String, PathBuf, ...)
+            let default = match &**default {
+                Expr::Lit(syn::ExprLit {
+                    lit: Lit::Str(lit), ..
+                }) => quote! { ::std::convert::From::from(#lit) },
+                default => quote! { #default },
+            };
+            quote! {
+                let #rust_name: #ty = __matches
+                    .get_one::<#ty>(#cli_name)
+                    .cloned()
+                    .unwrap_or_else(|| #default);
+            }
+        }
         ParamKind::Arg { .. } => {
             if is_option_type(ty) {
                 // Option<T> -> get_one::<T>().cloned()
@@ -386,7 +499,7 @@ pub fn handler_impl(attr: TokenStream, item: TokenStream) -> Result<TokenStream>
 
                 // Determine CLI name
                 let cli_name = match &kind {
-                    ParamKind::Flag { cli_name } | ParamKind::Arg { cli_name } => cli_name
+                    ParamKind::Flag { cli_name } | ParamKind::Arg { cli_name, .. } => cli_name
                         .clone()
                         .unwrap_or_else(|| rust_name.replace('_', "-")),
                     _ => rust_name.clone(),
@@ -445,12 +558,46 @@ pub fn handler_impl(attr: TokenStream, item: TokenStream) -> Result<TokenStream>
     // Get return type
     let return_type = &fn_item.sig.output;
 
+    let ok_type = extract_result_ok_type(&fn_item).ok_or_else(|| {
+        Error::new(
+            fn_item.sig.output.span(),
+            "handler must return Result<T, E>",
+        )
+    })?;
+
+    // If unit result, Output is ()
+    let output_type = if is_unit_result(&fn_item) {
+        quote! { () }
+    } else if let Some(inner) = extract_output_type(&ok_type) {
+        quote! { #inner }
+    } else {
+        quote! { #ok_type }
+    };
+
+    // Parser failures are usage errors, which the function's own error type
+    // can't carry, so the wrapper returns a HandlerResult
+    let has_parser = params.iter().any(|p| {
+        matches!(
+            p.kind,
+            ParamKind::Arg {
+                parser: Some(_),
+                ..
+            }
+        )
+    });
+
     // Handle unit result specially - wrap in Output::Silent
     let call_and_return = if is_unit_result(&fn_item) {
         quote! {
             #fn_name(#(#call_args),*)?;
             Ok(::standout_dispatch::Output::Silent)
         }
+    } else if has_parser {
+        quote! {
+            ::standout_dispatch::IntoHandlerResult::<#output_type>::into_handler_result(
+                #fn_name(#(#call_args),*)
+            )
+        }
     } else {
         quote! {
             #fn_name(#(#call_args),*)
@@ -460,6 +607,8 @@ pub fn handler_impl(attr: TokenStream, item: TokenStream) -> Result<TokenStream>
     // For unit results, we need to change the return type to HandlerResult<()>
     let wrapper_return_type = if is_unit_result(&fn_item) {
         quote! { -> ::standout_dispatch::HandlerResult<()> }
+    } else if has_parser {
+        quote! { -> ::standout_dispatch::HandlerResult<#output_type> }
     } else {
         quote! { #return_type }
     };
@@ -481,21 +630,6 @@ pub fn handler_impl(attr: TokenStream, item: TokenStream) -> Result<TokenStream>
     // Generate the output
     // Generate Handler struct
     let handler_struct_name = format_ident!("{}_Handler", fn_name);
-    let ok_type = extract_result_ok_type(&fn_item).ok_or_else(|| {
-        Error::new(
-            fn_item.sig.output.span(),
-            "handler must return Result<T, E>",
-        )
-    })?;
-
-    // If unit result, Output is ()
-    let output_type = if is_unit_result(&fn_item) {
-        quote! { () }
-    } else if let Some(inner) = extract_output_type(&ok_type) {
-        quote! { #inner }
-    } else {
-        quote! { #ok_type }
-    };
 
     Ok(quote! {
         // Original function (with annotations stripped)
@@ -9,6 +9,12 @@
 //!
 //! - [`embed_templates!`] - Embed template files (`.jinja`, `.jinja2`, `.j2`, `.txt`)
 //! - [`embed_styles!`] - Embed stylesheet files (`.css`, `.yaml`, `.yml`)
+//! - [`embed_topics!`] - Embed help topic files (`.txt`, `.md`)
+//!
+//! ## Validation Macros
+//!
+//! - [`validate_assets!`] - Fail the build if templates use undefined styles
+//! - [`styled!`] - Format a one-off string with style tags checked at compile time
 //!
 //! ## Derive Macros
 //!
@@ -16,6 +22,8 @@
 //! - [`Tabular`] - Generate `TabularSpec` from struct field annotations
 //! - [`TabularRow`] - Generate optimized row extraction without JSON serialization
 //! - [`Seekable`] - Generate query-enabled accessor functions for Seeker
+//! - [`ThemeDef`] - Build a `Theme` from a struct of styles
+//! - [`Collect`] - Collect a struct's fields from input chains
 //!
 //! ## Attribute Macros
 //!
@@ -42,12 +50,16 @@
 //! [`EmbeddedSource`]: standout::EmbeddedSource
 //! [`RenderSetup`]: standout::RenderSetup
 
+mod collect;
 mod command;
 mod dispatch;
 mod embed;
 mod handler;
 mod seeker;
+mod styled;
 mod tabular;
+mod theme_def;
+mod validate;
 
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, DeriveInput, LitStr};
@@ -135,6 +147,99 @@ pub fn embed_styles(input: TokenStream) -> TokenStream {
     embed::embed_styles_impl(path_lit).into()
 }
 
+/// Embeds all help topic files from a directory at compile time.
+///
+/// This macro walks the specified directory, reads all `.txt` and `.md`
+/// files, and returns an [`EmbeddedTopics`] source that can be passed to
+/// `App::builder().topics()` or converted to a [`TopicRegistry`].
+///
+/// Files are parsed exactly as by `TopicRegistry::add_from_directory`:
+/// front matter, title line, and one level of subdirectories as categories.
+///
+/// # Hot Reload Behavior
+///
+/// - Release builds: Uses embedded content (zero file I/O)
+/// - Debug builds: Reads from disk if source path exists (hot-reload)
+///
+/// For working examples, see `standout/tests/embed_macros.rs`.
+///
+/// # Compile-Time Errors
+///
+/// The macro will fail to compile if:
+/// - The directory doesn't exist
+/// - The directory is not readable
+/// - Any file content is not valid UTF-8
+///
+/// [`EmbeddedTopics`]: standout::EmbeddedTopics
+/// [`TopicRegistry`]: standout::topics::TopicRegistry
+#[proc_macro]
+pub fn embed_topics(input: TokenStream) -> TokenStream {
+    let path_lit = parse_macro_input!(input as LitStr);
+    embed::embed_topics_impl(path_lit).into()
+}
+
+/// Checks at compile time that templates only use styles the stylesheets
+/// define.
+///
+/// Takes a templates directory and a styles directory, relative to the
+/// crate's `Cargo.toml` like [`embed_templates!`] and [`embed_styles!`]. Every
+/// `[tag]` in the templates must be a style (or alias) defined in at least
+/// one stylesheet, so a typo like `[titel]` fails the build instead of
+/// showing up as `[titel?]` at runtime.
+///
+/// ```rust,ignore
+/// standout::validate_assets!("src/templates", "src/styles");
+/// ```
+///
+/// The macro expands to an item, so use it at module level. MiniJinja
+/// blocks are ignored, so tags whose names are computed at runtime
+/// (`[{{ status }}]`) aren't checked. Styles added in code (`Theme::add`)
+/// aren't known to the macro. The build reruns when a checked file changes.
+///
+/// # Compile-Time Errors
+///
+/// The macro will fail to compile if:
+/// - A template uses a style no stylesheet defines (each is listed with its
+///   file and line)
+/// - Either directory doesn't exist or isn't readable
+/// - A stylesheet can't be parsed
+#[proc_macro]
+pub fn validate_assets(input: TokenStream) -> TokenStream {
+    let dirs = parse_macro_input!(input as validate::AssetDirs);
+    validate::validate_assets_impl(dirs).into()
+}
+
+/// Formats a string like `format!` and applies a theme's style tags to it.
+///
+/// For quick one-off messages that don't warrant a template: the text
+/// doesn't go through MiniJinja, only through the style tag pass. Styles
+/// are applied when stdout supports color and removed otherwise.
+///
+/// ```rust,ignore
+/// use standout::styled;
+///
+/// let line = styled!(theme, "[title]{}[/title]: {} items", name, count);
+/// eprintln!("{}", styled!(theme, "[warn]{path}[/warn] is empty"));
+/// ```
+///
+/// Argument values are escaped, so brackets in them print literally. Tag
+/// names are resolved against the theme at runtime; as in templates,
+/// unknown ones show with a `?` marker when styles are applied.
+///
+/// # Compile-Time Errors
+///
+/// The macro will fail to compile if:
+/// - A tag is opened and never closed (`[title]{}`)
+/// - A tag is closed without being open, or out of order
+/// - The format string or arguments are invalid for `format!`
+#[proc_macro]
+pub fn styled(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as styled::StyledInput);
+    styled::styled_impl(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
 /// Derives dispatch configuration from a clap `Subcommand` enum.
 ///
 /// This macro eliminates boilerplate command-to-handler mappings by using
@@ -154,6 +259,7 @@ pub fn embed_styles(input: TokenStream) -> TokenStream {
 /// | Attribute | Required | Description |
 /// |-----------|----------|-------------|
 /// | `handlers = path` | Yes | Module containing handler functions |
+/// | `template_dir = "dir"` | No | Directory of the variants' templates |
 ///
 /// # Variant Attributes
 ///
@@ -161,6 +267,9 @@ pub fn embed_styles(input: TokenStream) -> TokenStream {
 /// |-----------|-------------|---------|
 /// | `handler = path` | Handler function | `{handlers}::{snake_case}` |
 /// | `template = "path"` | Template file | `{snake_case}.j2` |
+/// | `output = "mode"` | Default output mode | `auto` |
+/// | `alias = "name"` | Command alias (repeatable) | None |
+/// | `hidden` | Hide from help and completions | false |
 /// | `pre_dispatch = fn` | Pre-dispatch hook | None |
 /// | `post_dispatch = fn` | Post-dispatch hook | None |
 /// | `post_output = fn` | Post-output hook | None |
@@ -199,9 +308,14 @@ pub fn dispatch_derive(input: TokenStream) -> TokenStream {
 /// | `truncate_at` | `"end"`, `"start"`, `"middle"` | Truncation position |
 /// | `style` | string | Style name for the column |
 /// | `style_from_value` | flag | Use cell value as style name |
+/// | `style_if` | `(<op> = value, style = "name")` | Conditional style; `op` is `eq`, `ne`, `gt`, `ge`, `lt`, or `le`. Repeatable, first match wins |
+/// | `style_fn` | string (path) | `fn(&str) -> Option<String>` choosing the style from the cell value |
 /// | `header` | string | Header title (default: field name) |
 /// | `null_repr` | string | Representation for null values |
-/// | `key` | string | Data extraction key (supports dot notation) |
+/// | `key` | string | Data extraction key (dot notation and indexes, e.g. `"tags[0]"`) |
+/// | `default` | string | Text used when the key path is missing or null |
+/// | `format_fn` | string (path) | `fn(&serde_json::Value) -> String` formatting the extracted value |
+/// | `only` | string | Include only in the named view (e.g. `"wide"`); see `Tabular::tabular_spec_for` |
 /// | `skip` | flag | Exclude this field from the spec |
 ///
 /// # Container Attributes
@@ -253,6 +367,7 @@ pub fn tabular_derive(input: TokenStream) -> TokenStream {
 /// | Attribute | Description |
 /// |-----------|-------------|
 /// | `skip` | Exclude this field from the row |
+/// | `format_with = path` | Format the field with `fn(&T) -> impl Display` |
 ///
 /// # Example
 ///
@@ -303,6 +418,7 @@ pub fn tabular_row_derive(input: TokenStream) -> TokenStream {
 /// | `Enum` | Enum field (supports Eq, Ne, In) - requires `SeekerEnum` impl |
 /// | `Bool` | Boolean field (supports Eq, Ne, Is) |
 /// | `skip` | Exclude this field from queries |
+/// | `flatten` | Expose a nested `Seekable` struct's fields as `field.nested` |
 /// | `rename = "..."` | Use a custom name for queries |
 ///
 /// # Generated Code
@@ -397,6 +513,34 @@ pub fn tabular_row_derive(input: TokenStream) -> TokenStream {
 ///     created_at: MyDateTime,
 /// }
 /// ```
+///
+/// # Optional and Nested Fields
+///
+/// `Option<T>` fields take the seek type of `T`. `None` is a missing value:
+/// comparisons don't match it and orderings sort it last.
+///
+/// `#[seek(flatten)]` on a field whose type also derives `Seekable` makes
+/// its fields queryable under the field's name (or its `rename`). The field
+/// can be an `Option` too:
+///
+/// ```ignore
+/// #[derive(Seekable)]
+/// struct Author {
+///     #[seek(String)]
+///     name: String,
+/// }
+///
+/// #[derive(Seekable)]
+/// struct Post {
+///     #[seek(String)]
+///     summary: Option<String>,
+///     #[seek(flatten)]
+///     author: Author,
+/// }
+///
+/// // Matches posts by "ada"
+/// let query = Query::new().and_eq("author.name", "ada").build();
+/// ```
 #[proc_macro_derive(Seekable, attributes(seek))]
 pub fn seekable_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
@@ -405,6 +549,122 @@ pub fn seekable_derive(input: TokenStream) -> TokenStream {
         .into()
 }
 
+/// Derives `ThemeDef` for a struct whose fields are `console::Style`s.
+///
+/// Each field becomes a style of the theme, named after the field with
+/// underscores turned into dashes. The field's value is the base style; the
+/// `#[style(...)]` attribute can add light and dark mode variants. A
+/// constant per field holds the style name, so code and templates refer to
+/// styles without repeating string literals, and renaming a field breaks
+/// the build instead of silently unstyling output.
+///
+/// Also implements `From<T> for Theme`.
+///
+/// # Field Attributes
+///
+/// | Attribute | Type | Description |
+/// |-----------|------|-------------|
+/// | `name` | string | Style name (default: field name, dashed) |
+/// | `light` | expression | `Style` used in light mode |
+/// | `dark` | expression | `Style` used in dark mode |
+///
+/// # Example
+///
+/// ```ignore
+/// use console::Style;
+/// use standout::{Theme, ThemeDef};
+///
+/// #[derive(ThemeDef)]
+/// struct AppTheme {
+///     #[style(light = Style::new().black(), dark = Style::new().white())]
+///     title: Style,
+///     #[style(name = "dim")]
+///     muted: Style,
+/// }
+///
+/// let theme: Theme = AppTheme {
+///     title: Style::new().bold(),
+///     muted: Style::new().dim(),
+/// }
+/// .into();
+///
+/// // "[title]Tasks[/title]"
+/// let template = format!("[{0}]Tasks[/{0}]", AppTheme::TITLE);
+/// ```
+#[proc_macro_derive(ThemeDef, attributes(style))]
+pub fn theme_def_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    theme_def::theme_def_derive_impl(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives a `collect(&ArgMatches)` constructor that fills each field from
+/// an input chain.
+///
+/// Each field's `#[input(...)]` attribute lists its sources, tried in
+/// order, as `InputChain` would. A field without the attribute is read from
+/// the argument of the same name. This replaces the per-field chain
+/// boilerplate of create-style commands.
+///
+/// # Field Attributes
+///
+/// | Attribute | Source |
+/// |-----------|--------|
+/// | `arg`, `arg = "name"` | `ArgSource` (`FlagSource` for `bool` fields) |
+/// | `flag`, `flag = "name"` | `FlagSource` (`bool` fields only) |
+/// | `env = "VAR"` | `EnvSource` |
+/// | `config = "path"` | `ConfigSource` |
+/// | `stdin` | `StdinSource` |
+/// | `clipboard` | `ClipboardSource` |
+/// | `editor` | `EditorSource` (requires the `input-editor` feature) |
+/// | `prompt`, `prompt = "Message: "` | `TextPromptSource` (`ConfirmPromptSource` for `bool` fields) |
+/// | `default = expr` | Value used when no source provides one |
+///
+/// Argument names default to the field name and prompts to the field name
+/// as a sentence (`due_date` prompts `Due date: `).
+///
+/// # Field Types
+///
+/// - `String` fields take the collected text as is
+/// - Other types are parsed with `FromStr`; a failure is an
+///   `InputError::ParseError` naming the field
+/// - `Option<T>` fields are `None` when no source provides a value
+/// - `bool` fields are `false` when no source provides a value
+///
+/// Any other field with no value and no `default` fails with
+/// `InputError::MissingArgument`.
+///
+/// # Example
+///
+/// ```ignore
+/// use standout::Collect;
+///
+/// #[derive(Collect)]
+/// struct NewTask {
+///     #[input(arg, env = "TASK_TITLE", prompt = "Title: ")]
+///     title: String,
+///     #[input(arg, config = "tasks.project", default = "inbox")]
+///     project: String,
+///     #[input(arg, stdin, editor)]
+///     body: String,
+///     #[input(arg, default = 3)]
+///     priority: u8,
+///     due: Option<String>,
+///     #[input(flag)]
+///     urgent: bool,
+/// }
+///
+/// let task = NewTask::collect(&matches)?;
+/// ```
+#[proc_macro_derive(Collect, attributes(input))]
+pub fn collect_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    collect::collect_derive_impl(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
 /// Transforms a pure function into a Standout-compatible handler.
 ///
 /// This macro generates a wrapper function that extracts CLI arguments from
@@ -421,6 +681,8 @@ pub fn seekable_derive(input: TokenStream) -> TokenStream {
 /// | `#[arg]` | `Option<T>` | Optional CLI argument |
 /// | `#[arg]` | `Vec<T>` | Multiple CLI arguments |
 /// | `#[arg(name = "x")]` | `T` | Argument with custom CLI name |
+/// | `#[arg(default = 10)]` | `T` | Optional CLI argument with a default |
+/// | `#[arg(parser = f)]` | `T`, `Option<T>`, `Vec<T>` | Converted by `fn f(&str) -> Result<T, E>` |
 /// | `#[ctx]` | `&CommandContext` | Access to command context |
 /// | `#[matches]` | `&ArgMatches` | Raw matches (escape hatch) |
 ///
@@ -431,6 +693,9 @@ pub fn seekable_derive(input: TokenStream) -> TokenStream {
 /// | `Result<T, E>` | Passed through (dispatch auto-wraps in Output::Render) |
 /// | `Result<(), E>` | Wrapped in `HandlerResult<()>` with `Output::Silent` |
 ///
+/// A value the `parser` rejects is a usage error (exit code 2), so wrappers
+/// of handlers with a parser return `HandlerResult<T>`.
+///
 /// # Generated Code
 ///
 /// For a function `fn foo(...)`, the macro generates `fn foo__handler(...)`.
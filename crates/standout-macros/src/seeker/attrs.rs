@@ -71,6 +71,8 @@ pub struct SeekAttr {
     pub seek_type: Option<SeekType>,
     /// Skip this field from seeking.
     pub skip: bool,
+    /// Expose a nested `Seekable` struct's fields as `field.nested`.
+    pub flatten: bool,
     /// Custom field name for queries (default: field name).
     pub rename: Option<String>,
     /// The span for error reporting.
@@ -82,6 +84,7 @@ impl Default for SeekAttr {
         SeekAttr {
             seek_type: None,
             skip: false,
+            flatten: false,
             rename: None,
             span: Span::call_site(),
         }
@@ -100,13 +103,15 @@ impl Parse for SeekAttr {
                 Meta::Path(p) => {
                     if p.is_ident("skip") {
                         attr.skip = true;
+                    } else if p.is_ident("flatten") {
+                        attr.flatten = true;
                     } else if let Some(ident) = p.get_ident() {
                         attr.seek_type = Some(SeekType::from_ident(ident)?);
                         attr.span = ident.span();
                     } else {
                         return Err(Error::new(
                             p.span(),
-                            "expected seek type: String, Number, Timestamp, Enum, Bool, or skip",
+                            "expected seek type: String, Number, Timestamp, Enum, Bool, skip or flatten",
                         ));
                     }
                 }
@@ -147,12 +152,19 @@ impl Parse for SeekAttr {
                 _ => {
                     return Err(Error::new(
                         meta.span(),
-                        "unknown seek attribute. Expected: String, Number, Timestamp, Enum, Bool, skip, rename = \"...\", or ty = \"...\"",
+                        "unknown seek attribute. Expected: String, Number, Timestamp, Enum, Bool, skip, flatten, rename = \"...\", or ty = \"...\"",
                     ));
                 }
             }
         }
 
+        if attr.flatten && attr.seek_type.is_some() {
+            return Err(Error::new(
+                attr.span,
+                "flatten takes the field types from the nested struct; remove the seek type",
+            ));
+        }
+
         Ok(attr)
     }
 }
@@ -235,6 +247,14 @@ mod tests {
         assert_eq!(attr.seek_type, None);
     }
 
+    #[test]
+    fn test_seek_flatten() {
+        let attr = parse_seek(r#"flatten, rename = "owner""#).unwrap();
+        assert!(attr.flatten);
+        assert_eq!(attr.rename, Some("owner".to_string()));
+        assert!(parse_seek("flatten, String").is_err());
+    }
+
     #[test]
     fn test_seek_rename() {
         let attr = parse_seek(r#"String, rename = "custom_name""#).unwrap();
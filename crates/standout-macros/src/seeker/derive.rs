@@ -2,10 +2,17 @@
 //!
 //! This macro generates an implementation of the `Seekable` trait,
 //! `SeekerSchema` trait, and field name constants for type-safe query building.
+//!
+//! `Option<T>` fields are read through: `None` is a missing value, which
+//! comparisons never match and orderings sort last. `#[seek(flatten)]` fields
+//! expose a nested `Seekable` struct's fields as `field.nested`.
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Result};
+use syn::{
+    spanned::Spanned, Data, DeriveInput, Error, Fields, GenericArgument, PathArguments, Result,
+    Type,
+};
 
 use super::attrs::{parse_seek_attrs, SeekType};
 
@@ -17,6 +24,20 @@ struct FieldInfo {
     seek_type: SeekType,
     /// The actual field identifier.
     field_ident: syn::Ident,
+    /// Whether the field is an `Option<T>`.
+    optional: bool,
+}
+
+/// Information about a `#[seek(flatten)]` field.
+struct FlattenInfo {
+    /// Prefix of the nested fields' query names, including the dot.
+    prefix: String,
+    /// The actual field identifier.
+    field_ident: syn::Ident,
+    /// The nested struct type (without `Option`).
+    nested_type: Type,
+    /// Whether the field is an `Option<T>`.
+    optional: bool,
 }
 
 /// Main implementation of the Seekable derive macro.
@@ -44,6 +65,7 @@ pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
 
     // Collect field information
     let mut field_infos: Vec<FieldInfo> = Vec::new();
+    let mut flatten_infos: Vec<FlattenInfo> = Vec::new();
 
     for field in fields.iter() {
         let field_name = field
@@ -59,6 +81,19 @@ pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
             continue;
         }
 
+        let option_inner = option_inner_type(&field.ty);
+
+        if seek_attrs.flatten {
+            let prefix = seek_attrs.rename.unwrap_or_else(|| field_name.to_string());
+            flatten_infos.push(FlattenInfo {
+                prefix: format!("{}.", prefix),
+                field_ident: field_name.clone(),
+                nested_type: option_inner.unwrap_or(&field.ty).clone(),
+                optional: option_inner.is_some(),
+            });
+            continue;
+        }
+
         // If no seek type is specified, skip this field
         let seek_type = match seek_attrs.seek_type {
             Some(t) => t,
@@ -72,6 +107,7 @@ pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
             query_name,
             seek_type,
             field_ident: field_name.clone(),
+            optional: option_inner.is_some(),
         });
     }
 
@@ -94,30 +130,45 @@ pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
         .map(|info| {
             let query_name = &info.query_name;
             let field_ident = &info.field_ident;
+            // Built from a reference to the field's value
+            let value = quote! { __value };
             let value_expr = match info.seek_type {
                 SeekType::String => {
-                    quote! { ::standout_seeker::Value::String(&self.#field_ident) }
+                    quote! { ::standout_seeker::Value::String(#value) }
                 }
                 SeekType::Number => {
-                    quote! { ::standout_seeker::Value::Number(::standout_seeker::Number::from(self.#field_ident)) }
+                    quote! { ::standout_seeker::Value::Number(::standout_seeker::Number::from(*#value)) }
                 }
                 SeekType::Timestamp => {
                     quote! {
                         ::standout_seeker::Value::Timestamp(
-                            ::standout_seeker::SeekerTimestamp::seeker_timestamp(&self.#field_ident)
+                            ::standout_seeker::SeekerTimestamp::seeker_timestamp(#value)
                         )
                     }
                 }
                 SeekType::Enum => {
                     quote! {
                         ::standout_seeker::Value::Enum(
-                            ::standout_seeker::SeekerEnum::seeker_discriminant(&self.#field_ident)
+                            ::standout_seeker::SeekerEnum::seeker_discriminant(#value)
                         )
                     }
                 }
                 SeekType::Bool => {
-                    quote! { ::standout_seeker::Value::Bool(self.#field_ident) }
+                    quote! { ::standout_seeker::Value::Bool(*#value) }
+                }
+            };
+            let value_expr = if info.optional {
+                quote! {
+                    match &self.#field_ident {
+                        ::core::option::Option::Some(__value) => #value_expr,
+                        ::core::option::Option::None => ::standout_seeker::Value::None,
+                    }
                 }
+            } else {
+                quote! {{
+                    let __value = &self.#field_ident;
+                    #value_expr
+                }}
             };
             quote! {
                 #query_name => #value_expr,
@@ -149,6 +200,85 @@ pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
         .map(|info| info.query_name.as_str())
         .collect();
 
+    // Nested fields are looked up by stripping the flattened field's prefix
+    let nested_values: Vec<TokenStream> = flatten_infos
+        .iter()
+        .map(|info| {
+            let prefix = &info.prefix;
+            let field_ident = &info.field_ident;
+            let lookup = if info.optional {
+                quote! {
+                    match &self.#field_ident {
+                        ::core::option::Option::Some(__nested) => {
+                            ::standout_seeker::Seekable::seeker_field_value(__nested, __rest)
+                        }
+                        ::core::option::Option::None => ::standout_seeker::Value::None,
+                    }
+                }
+            } else {
+                quote! { ::standout_seeker::Seekable::seeker_field_value(&self.#field_ident, __rest) }
+            };
+            quote! {
+                if let ::core::option::Option::Some(__rest) = field.strip_prefix(#prefix) {
+                    return #lookup;
+                }
+            }
+        })
+        .collect();
+
+    let nested_types: Vec<TokenStream> = flatten_infos
+        .iter()
+        .map(|info| {
+            let prefix = &info.prefix;
+            let nested_type = &info.nested_type;
+            quote! {
+                if let ::core::option::Option::Some(__rest) = field.strip_prefix(#prefix) {
+                    return <#nested_type as ::standout_seeker::SeekerSchema>::field_type(__rest);
+                }
+            }
+        })
+        .collect();
+
+    // Nested names are only known at runtime, so they're built once
+    let field_names_body = if flatten_infos.is_empty() {
+        quote! { &[#(#field_name_literals),*] }
+    } else {
+        let prefixes = flatten_infos.iter().map(|info| &info.prefix);
+        let nested_types = flatten_infos.iter().map(|info| &info.nested_type);
+        quote! {
+            static OWNED: ::std::sync::OnceLock<::std::vec::Vec<::std::string::String>> =
+                ::std::sync::OnceLock::new();
+            static NAMES: ::std::sync::OnceLock<::std::vec::Vec<&'static str>> =
+                ::std::sync::OnceLock::new();
+            let owned = OWNED.get_or_init(|| {
+                let mut names: ::std::vec::Vec<::std::string::String> =
+                    ::std::vec![#(::std::string::String::from(#field_name_literals)),*];
+                #(
+                    for __name in <#nested_types as ::standout_seeker::SeekerSchema>::field_names() {
+                        names.push(::std::format!("{}{}", #prefixes, __name));
+                    }
+                )*
+                names
+            });
+            NAMES.get_or_init(|| owned.iter().map(|name| name.as_str()).collect())
+        }
+    };
+
+    let resolve_enum_variant = (!flatten_infos.is_empty()).then(|| {
+        let prefixes = flatten_infos.iter().map(|info| &info.prefix);
+        let nested_types = flatten_infos.iter().map(|info| &info.nested_type);
+        quote! {
+            fn resolve_enum_variant(field: &str, variant: &str) -> ::core::option::Option<u32> {
+                #(
+                    if let ::core::option::Option::Some(__rest) = field.strip_prefix(#prefixes) {
+                        return <#nested_types as ::standout_seeker::SeekerSchema>::resolve_enum_variant(__rest, variant);
+                    }
+                )*
+                ::core::option::Option::None
+            }
+        }
+    });
+
     // Generate the impl blocks
     let expanded = quote! {
         impl #struct_name {
@@ -159,7 +289,10 @@ pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
             fn seeker_field_value(&self, field: &str) -> ::standout_seeker::Value<'_> {
                 match field {
                     #(#field_matches)*
-                    _ => ::standout_seeker::Value::None,
+                    _ => {
+                        #(#nested_values)*
+                        ::standout_seeker::Value::None
+                    }
                 }
             }
         }
@@ -168,19 +301,42 @@ pub fn seekable_derive_impl(input: DeriveInput) -> Result<TokenStream> {
             fn field_type(field: &str) -> ::core::option::Option<::standout_seeker::SeekType> {
                 match field {
                     #(#schema_field_type_matches)*
-                    _ => ::core::option::Option::None,
+                    _ => {
+                        #(#nested_types)*
+                        ::core::option::Option::None
+                    }
                 }
             }
 
             fn field_names() -> &'static [&'static str] {
-                &[#(#field_name_literals),*]
+                #field_names_body
             }
+
+            #resolve_enum_variant
         }
     };
 
     Ok(expanded)
 }
 
+/// Returns `T` if `ty` is `Option<T>`.
+fn option_inner_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
 /// Convert a string to SCREAMING_SNAKE_CASE.
 fn to_screaming_snake_case(s: &str) -> String {
     let mut result = String::with_capacity(s.len() + 4);
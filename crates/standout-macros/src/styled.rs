@@ -0,0 +1,173 @@
+//! Implementation of the `styled!` macro.
+//!
+//! The format string's style tags are checked at compile time: every
+//! opened tag must be closed, in order. The arguments are wrapped so their
+//! values are escaped, and the formatted text is passed to
+//! `standout::render_styled` with the theme.
+
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use standout_bbparser::{BBParser, TagTransform, UnknownTagKind};
+use syn::{
+    parse::{Parse, ParseStream},
+    Error, Expr, Ident, LitStr, Result, Token,
+};
+
+/// Parsed input: `theme, "format", args...`.
+pub struct StyledInput {
+    theme: Expr,
+    format: LitStr,
+    args: Vec<(Option<Ident>, Expr)>,
+}
+
+impl Parse for StyledInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let theme = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let format = input.parse()?;
+
+        let mut args = Vec::new();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let name = if input.peek(Ident) && input.peek2(Token![=]) && !input.peek2(Token![==]) {
+                let name = input.parse()?;
+                input.parse::<Token![=]>()?;
+                Some(name)
+            } else {
+                None
+            };
+            args.push((name, input.parse()?));
+        }
+
+        Ok(StyledInput {
+            theme,
+            format,
+            args,
+        })
+    }
+}
+
+/// Main implementation of the `styled!` macro.
+pub fn styled_impl(input: StyledInput) -> Result<TokenStream> {
+    let format = input.format.value();
+    check_tags(&format).map_err(|message| Error::new(input.format.span(), message))?;
+
+    let mut named: HashSet<String> = HashSet::new();
+    let mut args: Vec<TokenStream> = input
+        .args
+        .iter()
+        .map(|(name, expr)| match name {
+            Some(name) => {
+                named.insert(name.to_string());
+                quote! { #name = ::standout::StyledArg(&#expr) }
+            }
+            None => quote! { ::standout::StyledArg(&#expr) },
+        })
+        .collect();
+
+    // Inline `{name}` arguments are captured explicitly, so they're escaped too
+    for name in captured_names(&format) {
+        if named.insert(name.clone()) {
+            let ident = format_ident!("{}", name, span = input.format.span());
+            args.push(quote! { #ident = ::standout::StyledArg(&#ident) });
+        }
+    }
+
+    let theme = &input.theme;
+    let format = &input.format;
+    Ok(quote! {
+        ::standout::render_styled(&::std::format!(#format, #(#args),*), &#theme)
+    })
+}
+
+/// Checks that every tag in `format` is closed, in order.
+fn check_tags(format: &str) -> std::result::Result<(), String> {
+    let parser = BBParser::new(Default::default(), TagTransform::Keep);
+    let Err(errors) = parser.validate(format) else {
+        return Ok(());
+    };
+    let problems: Vec<String> = errors
+        .into_iter()
+        .filter_map(|error| match error.kind {
+            UnknownTagKind::Unbalanced => Some(format!("`[{}]` is never closed", error.tag)),
+            UnknownTagKind::UnexpectedClose => {
+                Some(format!("`[/{}]` closes a tag that isn't open", error.tag))
+            }
+            // Tag names are checked against the theme at runtime
+            UnknownTagKind::Open | UnknownTagKind::Close => None,
+        })
+        .collect();
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems.join("; "))
+    }
+}
+
+/// Returns the identifiers used as inline arguments (`{name}`, `{name:>8}`).
+fn captured_names(format: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut rest = format;
+    while let Some(start) = rest.find(['{', '}']) {
+        let after = &rest[start + 1..];
+        if rest[start..].starts_with("{{") || rest[start..].starts_with("}}") {
+            rest = &after[1..];
+            continue;
+        }
+        if rest.as_bytes()[start] == b'}' {
+            rest = after;
+            continue;
+        }
+        let end = after.find('}').unwrap_or(after.len());
+        let argument = after[..end].split(':').next().unwrap_or("").trim();
+        let is_ident = argument
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphabetic() || c == '_')
+            && argument.chars().all(|c| c.is_alphanumeric() || c == '_');
+        if is_ident && !names.iter().any(|name| name == argument) {
+            names.push(argument.to_string());
+        }
+        rest = &after[end.min(after.len())..];
+    }
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_tags() {
+        assert!(check_tags("[title]{}[/title]: {}").is_ok());
+        assert!(check_tags("[a][b]x[/b][/a] [unknown]y[/unknown]").is_ok());
+        assert_eq!(
+            check_tags("[title]{}").unwrap_err(),
+            "`[title]` is never closed"
+        );
+        assert_eq!(
+            check_tags("{}[/title]").unwrap_err(),
+            "`[/title]` closes a tag that isn't open"
+        );
+    }
+
+    #[test]
+    fn test_captured_names() {
+        assert_eq!(
+            captured_names("{name} {{literal}} {} {0} {count:>4} {name}"),
+            vec!["name", "count"]
+        );
+    }
+
+    #[test]
+    fn test_rejects_unbalanced_tags() {
+        let input: StyledInput = syn::parse_quote!(theme, "[title]{}", name);
+        let err = styled_impl(input).err().unwrap();
+        assert!(err.to_string().contains("never closed"));
+    }
+}
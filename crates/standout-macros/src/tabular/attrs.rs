@@ -40,16 +40,46 @@ pub struct ColAttr {
     pub style: Option<String>,
     /// Style from value: `style_from_value`
     pub style_from_value: bool,
+    /// Conditional styles: `style_if(eq = "error", style = "red")`
+    pub style_rules: Vec<StyleRuleAttr>,
+    /// Style callback path: `style_fn = "status_style"`
+    pub style_fn: Option<syn::Path>,
     /// Header title: `header = "Due Date"`
     pub header: Option<String>,
     /// Null representation: `null_repr = "N/A"`
     pub null_repr: Option<String>,
-    /// Key override: `key = "user.name"`
+    /// Key override: `key = "user.name"` or `key = "tags[0]"`
     pub key: Option<String>,
+    /// Default when the key path is missing: `default = "none"`
+    pub default: Option<String>,
+    /// Value formatter path: `format_fn = "fmt_size"`
+    pub format_fn: Option<syn::Path>,
+    /// Typed field formatter for `TabularRow`: `format_with = fmt::money`
+    pub format_with: Option<syn::Path>,
+    /// Restrict to a named view: `only = "wide"`
+    pub only: Option<String>,
     /// Skip this field: `skip`
     pub skip: bool,
 }
 
+/// A conditional style from `style_if(<op> = <value>, style = "...")`.
+#[derive(Debug, Clone)]
+pub struct StyleRuleAttr {
+    /// Comparison operator: one of eq, ne, gt, ge, lt, le.
+    pub op: String,
+    /// Operand: a string for eq/ne, a number for the ordering operators.
+    pub value: StyleRuleValue,
+    /// Style name applied when the condition holds.
+    pub style: String,
+}
+
+/// Operand of a [`StyleRuleAttr`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum StyleRuleValue {
+    Str(String),
+    Num(f64),
+}
+
 /// Container-level attributes from `#[tabular(...)]`.
 #[derive(Debug, Default, Clone)]
 pub struct TabularAttr {
@@ -114,6 +144,23 @@ impl Parse for ColAttr {
                     attr.style_from_value = true;
                 }
 
+                // style_if(eq = "error", style = "red")
+                Meta::List(list) if list.path.is_ident("style_if") => {
+                    attr.style_rules
+                        .push(list.parse_args_with(parse_style_rule)?);
+                }
+
+                // style_fn = "path::to::fn"
+                Meta::NameValue(nv) if nv.path.is_ident("style_fn") => {
+                    let path = parse_string_expr(&nv.value)?;
+                    attr.style_fn = Some(syn::parse_str(&path).map_err(|_| {
+                        Error::new(
+                            nv.value.span(),
+                            format!("invalid style_fn path: '{}'", path),
+                        )
+                    })?);
+                }
+
                 // header = "Due Date"
                 Meta::NameValue(nv) if nv.path.is_ident("header") => {
                     attr.header = Some(parse_string_expr(&nv.value)?);
@@ -129,6 +176,43 @@ impl Parse for ColAttr {
                     attr.key = Some(parse_string_expr(&nv.value)?);
                 }
 
+                // default = "none"
+                Meta::NameValue(nv) if nv.path.is_ident("default") => {
+                    attr.default = Some(parse_string_expr(&nv.value)?);
+                }
+
+                // format_fn = "fmt_size"
+                Meta::NameValue(nv) if nv.path.is_ident("format_fn") => {
+                    let path = parse_string_expr(&nv.value)?;
+                    attr.format_fn = Some(syn::parse_str(&path).map_err(|_| {
+                        Error::new(
+                            nv.value.span(),
+                            format!("invalid format_fn path: '{}'", path),
+                        )
+                    })?);
+                }
+
+                // format_with = path::to::fn (or "path::to::fn")
+                Meta::NameValue(nv) if nv.path.is_ident("format_with") => {
+                    attr.format_with = Some(match &nv.value {
+                        Expr::Path(expr_path) => expr_path.path.clone(),
+                        value => {
+                            let path = parse_string_expr(value)?;
+                            syn::parse_str(&path).map_err(|_| {
+                                Error::new(
+                                    value.span(),
+                                    format!("invalid format_with path: '{}'", path),
+                                )
+                            })?
+                        }
+                    });
+                }
+
+                // only = "wide"
+                Meta::NameValue(nv) if nv.path.is_ident("only") => {
+                    attr.only = Some(parse_string_expr(&nv.value)?);
+                }
+
                 // skip (flag)
                 Meta::Path(p) if p.is_ident("skip") => {
                     attr.skip = true;
@@ -138,8 +222,8 @@ impl Parse for ColAttr {
                     return Err(Error::new(
                         meta.span(),
                         "unknown col attribute: expected one of: width, min, max, align, \
-                             anchor, overflow, truncate_at, style, style_from_value, header, \
-                             null_repr, key, skip"
+                             anchor, overflow, truncate_at, style, style_from_value, style_if, \
+                             style_fn, header, null_repr, key, default, format_fn, format_with, only, skip"
                             .to_string(),
                     ));
                 }
@@ -234,6 +318,82 @@ fn parse_width_value(expr: &Expr, attr: &mut ColAttr) -> Result<()> {
     Ok(())
 }
 
+/// Parse the contents of `style_if(...)`: one comparison plus `style`.
+fn parse_style_rule(input: ParseStream) -> Result<StyleRuleAttr> {
+    let span = input.span();
+    let content: Punctuated<Meta, Token![,]> = Punctuated::parse_terminated(input)?;
+
+    let mut condition: Option<(String, StyleRuleValue)> = None;
+    let mut style = None;
+
+    for meta in content {
+        let nv = match &meta {
+            Meta::NameValue(nv) => nv,
+            _ => {
+                return Err(Error::new(
+                    meta.span(),
+                    "style_if expects `<op> = <value>, style = \"name\"`",
+                ))
+            }
+        };
+        let ident = nv
+            .path
+            .get_ident()
+            .map(|i| i.to_string())
+            .unwrap_or_default();
+
+        match ident.as_str() {
+            "style" => style = Some(parse_string_expr(&nv.value)?),
+            "eq" | "ne" | "gt" | "ge" | "lt" | "le" => {
+                if condition.is_some() {
+                    return Err(Error::new(
+                        nv.span(),
+                        "style_if accepts exactly one comparison",
+                    ));
+                }
+                let value = if ident == "eq" || ident == "ne" {
+                    StyleRuleValue::Str(parse_string_expr(&nv.value)?)
+                } else {
+                    StyleRuleValue::Num(parse_number_expr(&nv.value)?)
+                };
+                condition = Some((ident, value));
+            }
+            _ => {
+                return Err(Error::new(
+                    nv.path.span(),
+                    "unknown style_if key: expected one of: eq, ne, gt, ge, lt, le, style",
+                ))
+            }
+        }
+    }
+
+    let (op, value) = condition.ok_or_else(|| {
+        Error::new(
+            span,
+            "style_if requires a comparison: eq, ne, gt, ge, lt, or le",
+        )
+    })?;
+    let style = style.ok_or_else(|| Error::new(span, "style_if requires `style = \"name\"`"))?;
+
+    Ok(StyleRuleAttr { op, value, style })
+}
+
+/// Parse a number (integer or float, optionally negative) from an expression.
+fn parse_number_expr(expr: &Expr) -> Result<f64> {
+    match expr {
+        Expr::Lit(expr_lit) => match &expr_lit.lit {
+            Lit::Int(lit_int) => return lit_int.base10_parse::<f64>(),
+            Lit::Float(lit_float) => return lit_float.base10_parse::<f64>(),
+            _ => {}
+        },
+        Expr::Unary(unary) if matches!(unary.op, syn::UnOp::Neg(_)) => {
+            return Ok(-parse_number_expr(&unary.expr)?);
+        }
+        _ => {}
+    }
+    Err(Error::new(expr.span(), "expected number literal"))
+}
+
 /// Parse a usize from an expression.
 fn parse_usize_expr(expr: &Expr) -> Result<usize> {
     if let Expr::Lit(expr_lit) = expr {
@@ -332,6 +492,48 @@ pub fn generate_anchor_tokens(anchor: &Option<String>) -> Result<TokenStream> {
     }
 }
 
+/// Generate token stream for the `style_rules` vector.
+pub fn generate_style_rules_tokens(rules: &[StyleRuleAttr]) -> TokenStream {
+    let rules = rules.iter().map(|rule| {
+        let variant = match rule.op.as_str() {
+            "eq" => quote! { Eq },
+            "ne" => quote! { Ne },
+            "gt" => quote! { Gt },
+            "ge" => quote! { Ge },
+            "lt" => quote! { Lt },
+            _ => quote! { Le },
+        };
+        let operand = match &rule.value {
+            StyleRuleValue::Str(s) => quote! { #s.to_string() },
+            StyleRuleValue::Num(n) => quote! { #n },
+        };
+        let style = &rule.style;
+        quote! {
+            ::standout::tabular::StyleRule::new(
+                ::standout::tabular::StyleCondition::#variant(#operand),
+                #style,
+            )
+        }
+    });
+    quote! { vec![#(#rules),*] }
+}
+
+/// Generate token stream for the optional `style_fn` callback.
+pub fn generate_style_fn_tokens(path: &Option<syn::Path>) -> TokenStream {
+    match path {
+        Some(p) => quote! { Some(::standout::tabular::StyleFn::new(#p)) },
+        None => quote! { None },
+    }
+}
+
+/// Generate token stream for an optional value formatter.
+pub fn generate_format_fn_tokens(path: &Option<syn::Path>) -> TokenStream {
+    match path {
+        Some(p) => quote! { Some(::standout::tabular::FormatFn::new(#p)) },
+        None => quote! { None },
+    }
+}
+
 /// Generate token stream for Overflow enum variant.
 pub fn generate_overflow_tokens(attr: &ColAttr) -> Result<TokenStream> {
     let truncate_at = match attr.truncate_at.as_deref() {
@@ -447,6 +649,49 @@ mod tests {
         assert!(attr.style_from_value);
     }
 
+    #[test]
+    fn test_col_style_if() {
+        let attr =
+            parse_col(r#"style_if(eq = "error", style = "red"), style_if(gt = 90, style = "hot")"#)
+                .unwrap();
+        assert_eq!(attr.style_rules.len(), 2);
+        assert_eq!(attr.style_rules[0].op, "eq");
+        assert_eq!(
+            attr.style_rules[0].value,
+            StyleRuleValue::Str("error".to_string())
+        );
+        assert_eq!(attr.style_rules[0].style, "red");
+        assert_eq!(attr.style_rules[1].op, "gt");
+        assert_eq!(attr.style_rules[1].value, StyleRuleValue::Num(90.0));
+    }
+
+    #[test]
+    fn test_col_style_if_negative_float() {
+        let attr = parse_col(r#"style_if(lt = -0.5, style = "loss")"#).unwrap();
+        assert_eq!(attr.style_rules[0].value, StyleRuleValue::Num(-0.5));
+    }
+
+    #[test]
+    fn test_col_style_if_requires_style() {
+        let result = parse_col(r#"style_if(eq = "error")"#);
+        assert!(result.unwrap_err().to_string().contains("requires `style"));
+    }
+
+    #[test]
+    fn test_col_style_if_requires_single_comparison() {
+        let result = parse_col(r#"style_if(eq = "a", ne = "b", style = "x")"#);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("exactly one comparison"));
+    }
+
+    #[test]
+    fn test_col_style_fn() {
+        let attr = parse_col(r#"style_fn = "styles::status""#).unwrap();
+        assert!(attr.style_fn.is_some());
+    }
+
     #[test]
     fn test_col_header() {
         let attr = parse_col(r#"header = "Due Date""#).unwrap();
@@ -465,6 +710,35 @@ mod tests {
         assert_eq!(attr.key, Some("user.name".to_string()));
     }
 
+    #[test]
+    fn test_col_default_and_format_fn() {
+        let attr =
+            parse_col(r#"key = "tags[0]", default = "none", format_fn = "fmt::tag""#).unwrap();
+        assert_eq!(attr.key, Some("tags[0]".to_string()));
+        assert_eq!(attr.default, Some("none".to_string()));
+        assert!(attr.format_fn.is_some());
+    }
+
+    #[test]
+    fn test_col_invalid_format_fn() {
+        assert!(parse_col(r#"format_fn = "not a path""#).is_err());
+    }
+
+    #[test]
+    fn test_col_format_with() {
+        let attr = parse_col("format_with = fmt::money").unwrap();
+        assert!(attr.format_with.is_some());
+        let attr = parse_col(r#"format_with = "fmt::money""#).unwrap();
+        assert!(attr.format_with.is_some());
+        assert!(parse_col("format_with = 3").is_err());
+    }
+
+    #[test]
+    fn test_col_only() {
+        let attr = parse_col(r#"only = "wide""#).unwrap();
+        assert_eq!(attr.only, Some("wide".to_string()));
+    }
+
     #[test]
     fn test_col_skip() {
         let attr = parse_col("skip").unwrap();
@@ -33,6 +33,7 @@ pub fn tabular_row_derive_impl(input: DeriveInput) -> Result<TokenStream> {
 
     // Collect field accessors for non-skipped fields
     let mut field_conversions: Vec<TokenStream> = Vec::new();
+    let mut view_cells: Vec<TokenStream> = Vec::new();
 
     for field in fields.iter() {
         let field_name = field
@@ -49,10 +50,27 @@ pub fn tabular_row_derive_impl(input: DeriveInput) -> Result<TokenStream> {
         }
 
         // Generate the field conversion
-        // We use ToString trait which is implemented for all Display types
-        field_conversions.push(quote! {
-            self.#field_name.to_tabular_cell()
-        });
+        // We use ToString trait which is implemented for all Display types,
+        // or the field's formatter when it has one
+        let conversion = match &col_attrs.format_with {
+            Some(format_with) => quote! {
+                ::std::string::ToString::to_string(&#format_with(&self.#field_name))
+            },
+            None => quote! {
+                self.#field_name.to_tabular_cell()
+            },
+        };
+
+        // View-restricted fields only appear in their view's rows
+        match &col_attrs.only {
+            Some(only) => view_cells.push(quote! {
+                (view == Some(#only)).then(|| #conversion)
+            }),
+            None => {
+                view_cells.push(quote! { Some(#conversion) });
+                field_conversions.push(conversion);
+            }
+        }
     }
 
     // Generate the impl block
@@ -64,6 +82,14 @@ pub fn tabular_row_derive_impl(input: DeriveInput) -> Result<TokenStream> {
                     #(#field_conversions),*
                 ]
             }
+
+            fn to_row_for(&self, view: Option<&str>) -> Vec<String> {
+                use ::standout::tabular::{TabularFieldDisplay, TabularFieldOption};
+                let cells: Vec<Option<String>> = vec![
+                    #(#view_cells),*
+                ];
+                cells.into_iter().flatten().collect()
+            }
         }
     };
 
@@ -7,8 +7,9 @@ use quote::quote;
 use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Result};
 
 use super::attrs::{
-    generate_align_tokens, generate_anchor_tokens, generate_overflow_tokens, generate_width_tokens,
-    parse_col_attrs, parse_tabular_attrs,
+    generate_align_tokens, generate_anchor_tokens, generate_format_fn_tokens,
+    generate_overflow_tokens, generate_style_fn_tokens, generate_style_rules_tokens,
+    generate_width_tokens, parse_col_attrs, parse_tabular_attrs,
 };
 
 /// Main implementation of the Tabular derive macro.
@@ -76,6 +77,10 @@ pub fn tabular_derive_impl(input: DeriveInput) -> Result<TokenStream> {
         // Generate style_from_value
         let style_from_value = col_attrs.style_from_value;
 
+        // Generate conditional styles
+        let style_rules_tokens = generate_style_rules_tokens(&col_attrs.style_rules);
+        let style_fn_tokens = generate_style_fn_tokens(&col_attrs.style_fn);
+
         // Generate header tokens (use header if specified, otherwise field name)
         let header_tokens = match &col_attrs.header {
             Some(h) => quote! { Some(#h.to_string()) },
@@ -94,6 +99,19 @@ pub fn tabular_derive_impl(input: DeriveInput) -> Result<TokenStream> {
             None => quote! { Some(#field_name_str.to_string()) },
         };
 
+        // Generate extraction default and formatter tokens
+        let default_tokens = match &col_attrs.default {
+            Some(d) => quote! { Some(#d.to_string()) },
+            None => quote! { None },
+        };
+        let format_fn_tokens = generate_format_fn_tokens(&col_attrs.format_fn);
+
+        // Generate view restriction tokens
+        let only_tokens = match &col_attrs.only {
+            Some(v) => quote! { Some(#v.to_string()) },
+            None => quote! { None },
+        };
+
         // Generate the Column construction
         column_tokens.push(quote! {
             ::standout::tabular::Column {
@@ -105,8 +123,13 @@ pub fn tabular_derive_impl(input: DeriveInput) -> Result<TokenStream> {
                 null_repr: #null_repr_tokens,
                 style: #style_tokens,
                 style_from_value: #style_from_value,
+                style_rules: #style_rules_tokens,
+                style_fn: #style_fn_tokens,
                 key: #key_tokens,
+                default: #default_tokens,
+                format_fn: #format_fn_tokens,
                 header: #header_tokens,
+                only: #only_tokens,
                 sub_columns: None,
             }
         });
@@ -117,20 +140,31 @@ pub fn tabular_derive_impl(input: DeriveInput) -> Result<TokenStream> {
     let prefix = container_attrs.prefix.as_deref().unwrap_or("");
     let suffix = container_attrs.suffix.as_deref().unwrap_or("");
 
+    // Full spec with every view's columns; each method narrows it to a view
+    let spec_tokens = quote! {
+        ::standout::tabular::TabularSpec {
+            columns: vec![
+                #(#column_tokens),*
+            ],
+            decorations: ::standout::tabular::Decorations {
+                column_sep: #separator.to_string(),
+                row_prefix: #prefix.to_string(),
+                row_suffix: #suffix.to_string(),
+            },
+        }
+    };
+
     // Generate the impl block
     let expanded = quote! {
         impl ::standout::tabular::Tabular for #struct_name {
             fn tabular_spec() -> ::standout::tabular::TabularSpec {
-                ::standout::tabular::TabularSpec {
-                    columns: vec![
-                        #(#column_tokens),*
-                    ],
-                    decorations: ::standout::tabular::Decorations {
-                        column_sep: #separator.to_string(),
-                        row_prefix: #prefix.to_string(),
-                        row_suffix: #suffix.to_string(),
-                    },
-                }
+                let spec = #spec_tokens;
+                spec.for_view(None)
+            }
+
+            fn tabular_spec_for(view: &str) -> ::standout::tabular::TabularSpec {
+                let spec = #spec_tokens;
+                spec.for_view(Some(view))
             }
         }
     };
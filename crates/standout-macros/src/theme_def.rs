@@ -0,0 +1,201 @@
+//! Implementation of the `#[derive(ThemeDef)]` macro.
+//!
+//! Each field of the struct becomes a style of the theme, named after the
+//! field with underscores turned into dashes (`section_title` becomes
+//! `section-title`). The macro generates:
+//!
+//! - a `pub const` per field holding its style name (`AppTheme::SECTION_TITLE`)
+//! - an implementation of `ThemeDef`
+//! - `From<AppTheme> for Theme`
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Data, DeriveInput, Error, Expr, Fields, Lit, Meta, Result, Token,
+};
+
+/// Field-level attributes from `#[style(...)]`.
+#[derive(Default)]
+struct StyleAttr {
+    /// Style name (default: the field name, dashed).
+    name: Option<String>,
+    /// Style used in light mode.
+    light: Option<Expr>,
+    /// Style used in dark mode.
+    dark: Option<Expr>,
+}
+
+impl Parse for StyleAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut attr = StyleAttr::default();
+        let content: Punctuated<Meta, Token![,]> = Punctuated::parse_terminated(input)?;
+
+        for meta in content {
+            let Meta::NameValue(nv) = meta else {
+                return Err(Error::new(
+                    meta.span(),
+                    "expected `name = \"...\"`, `light = ...` or `dark = ...`",
+                ));
+            };
+            if nv.path.is_ident("name") {
+                match &nv.value {
+                    Expr::Lit(syn::ExprLit {
+                        lit: Lit::Str(s), ..
+                    }) => attr.name = Some(s.value()),
+                    _ => return Err(Error::new(nv.value.span(), "name must be a string literal")),
+                }
+            } else if nv.path.is_ident("light") {
+                attr.light = Some(nv.value);
+            } else if nv.path.is_ident("dark") {
+                attr.dark = Some(nv.value);
+            } else {
+                return Err(Error::new(
+                    nv.path.span(),
+                    "unknown style attribute. Expected: name, light or dark",
+                ));
+            }
+        }
+
+        Ok(attr)
+    }
+}
+
+/// Main implementation of the ThemeDef derive macro.
+pub fn theme_def_derive_impl(input: DeriveInput) -> Result<TokenStream> {
+    let struct_name = &input.ident;
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(named) => &named.named,
+            _ => {
+                return Err(Error::new(
+                    input.span(),
+                    "ThemeDef can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                input.span(),
+                "ThemeDef can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut names = Vec::new();
+    let mut constants = Vec::new();
+    let mut styles = Vec::new();
+
+    for field in fields {
+        let ident = field
+            .ident
+            .as_ref()
+            .ok_or_else(|| Error::new(field.span(), "expected named field"))?;
+        let mut attr = StyleAttr::default();
+        for a in &field.attrs {
+            if a.path().is_ident("style") {
+                attr = a.parse_args()?;
+            }
+        }
+
+        let field_name = ident.unraw().to_string();
+        let name = attr.name.unwrap_or_else(|| field_name.replace('_', "-"));
+        if names.contains(&name) {
+            return Err(Error::new(
+                ident.span(),
+                format!("duplicate style name `{}`", name),
+            ));
+        }
+
+        let const_name = format_ident!("{}", field_name.to_ascii_uppercase());
+        let doc = format!("The `{}` style name.", name);
+        constants.push(quote! {
+            #[doc = #doc]
+            pub const #const_name: &'static str = #name;
+        });
+
+        let light = option_tokens(attr.light);
+        let dark = option_tokens(attr.dark);
+        styles.push(quote! {
+            .add_adaptive(#name, ::std::clone::Clone::clone(&self.#ident), #light, #dark)
+        });
+        names.push(name);
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            #(#constants)*
+        }
+
+        impl #impl_generics ::standout::ThemeDef for #struct_name #ty_generics #where_clause {
+            const STYLE_NAMES: &'static [&'static str] = &[#(#names),*];
+
+            fn to_theme(&self) -> ::standout::Theme {
+                ::standout::Theme::new()
+                    #(#styles)*
+            }
+        }
+
+        impl #impl_generics ::std::convert::From<#struct_name #ty_generics> for ::standout::Theme
+        #where_clause
+        {
+            fn from(def: #struct_name #ty_generics) -> Self {
+                ::standout::ThemeDef::to_theme(&def)
+            }
+        }
+    })
+}
+
+fn option_tokens(expr: Option<Expr>) -> TokenStream {
+    match expr {
+        Some(expr) => quote! { ::std::option::Option::Some(#expr) },
+        None => quote! { ::std::option::Option::None },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_style_names_and_constants() {
+        let input: DeriveInput = syn::parse_quote! {
+            struct AppTheme {
+                section_title: Style,
+                #[style(name = "dim", dark = Style::new().white())]
+                muted: Style,
+            }
+        };
+        let output = theme_def_derive_impl(input).unwrap().to_string();
+
+        assert!(output.contains("pub const SECTION_TITLE : & 'static str = \"section-title\""));
+        assert!(output.contains("pub const MUTED : & 'static str = \"dim\""));
+        assert!(output.contains("& [\"section-title\" , \"dim\"]"));
+    }
+
+    #[test]
+    fn test_rejects_duplicates_and_unknown_attributes() {
+        let duplicate: DeriveInput = syn::parse_quote! {
+            struct AppTheme {
+                title: Style,
+                #[style(name = "title")]
+                heading: Style,
+            }
+        };
+        let err = theme_def_derive_impl(duplicate).err().unwrap();
+        assert!(err.to_string().contains("duplicate style name `title`"));
+
+        let unknown: DeriveInput = syn::parse_quote! {
+            struct AppTheme {
+                #[style(bold = true)]
+                title: Style,
+            }
+        };
+        assert!(theme_def_derive_impl(unknown).is_err());
+    }
+}
@@ -0,0 +1,268 @@
+//! Compile-time check of template style tags against stylesheets.
+//!
+//! `validate_assets!` reads a templates directory and a styles directory the
+//! same way `embed_templates!` and `embed_styles!` do, and fails the build
+//! when a template uses a `[tag]` no stylesheet defines. The check runs the
+//! same BBParser validation used at runtime, on template source with the
+//! MiniJinja blocks blanked out, so expressions like `{{ items[0] }}` aren't
+//! mistaken for tags and tags built at runtime (`[{{ status }}]`) are skipped.
+//!
+//! Only style names matter here, so stylesheets are read for their names
+//! (YAML keys, CSS class selectors) rather than built into themes, which
+//! keeps the renderer out of the macro's dependencies.
+
+use cssparser::{ParseError, Parser, ParserInput, Token as CssToken};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+use syn::parse::{Parse, ParseStream};
+use syn::{LitStr, Token};
+
+use standout_bbparser::{BBParser, TagTransform, UnknownTagKind};
+
+use crate::embed::{collect_files, resolve_path, STYLESHEET_EXTENSIONS, TEMPLATE_EXTENSIONS};
+
+/// The macro input: `"templates_dir", "styles_dir"`.
+pub struct AssetDirs {
+    templates: LitStr,
+    styles: LitStr,
+}
+
+impl Parse for AssetDirs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let templates = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let styles = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(Self { templates, styles })
+    }
+}
+
+/// Generates an item that fails to compile if a template uses an undefined
+/// style, and otherwise includes every checked file so the build reruns when
+/// one changes.
+pub fn validate_assets_impl(input: AssetDirs) -> TokenStream {
+    let templates_dir = resolve_path(&input.templates.value());
+    let styles_dir = resolve_path(&input.styles.value());
+
+    let problems = match check_assets(&templates_dir, &styles_dir) {
+        Ok(problems) => problems,
+        Err(e) => return syn::Error::new(input.templates.span(), e).to_compile_error(),
+    };
+    if !problems.is_empty() {
+        let message = format!(
+            "templates use styles no stylesheet defines:\n{}",
+            problems.join("\n")
+        );
+        return syn::Error::new(input.templates.span(), message).to_compile_error();
+    }
+
+    let mut tracked = Vec::new();
+    for (dir, extensions) in [
+        (&templates_dir, TEMPLATE_EXTENSIONS),
+        (&styles_dir, STYLESHEET_EXTENSIONS),
+    ] {
+        if let Ok(files) = collect_files(dir, extensions) {
+            tracked.extend(
+                files
+                    .into_iter()
+                    .map(|(name, _)| dir.join(name).to_string_lossy().into_owned()),
+            );
+        }
+    }
+
+    quote! {
+        const _: &[&str] = &[#(include_str!(#tracked)),*];
+    }
+}
+
+/// Returns one `file:line: message` entry per unknown style used.
+fn check_assets(templates_dir: &Path, styles_dir: &Path) -> Result<Vec<String>, String> {
+    let mut styles = HashMap::new();
+    for (name, content) in collect_files(styles_dir, STYLESHEET_EXTENSIONS)? {
+        let names = if name.ends_with(".css") {
+            Ok(css_style_names(&content))
+        } else {
+            yaml_style_names(&content)
+        }
+        .map_err(|e| format!("{}: {}", styles_dir.join(&name).display(), e))?;
+        styles.extend(names.into_iter().map(|name| (name, console::Style::new())));
+    }
+    let parser = BBParser::new(styles, TagTransform::Remove);
+
+    let mut problems = Vec::new();
+    for (name, content) in collect_files(templates_dir, TEMPLATE_EXTENSIONS)? {
+        let markup = blank_template_blocks(&content);
+        let Err(errors) = parser.validate(&markup) else {
+            continue;
+        };
+        let mut seen = BTreeSet::new();
+        for error in errors.errors {
+            if !matches!(error.kind, UnknownTagKind::Open | UnknownTagKind::Close)
+                || !seen.insert(error.tag.clone())
+            {
+                continue;
+            }
+            let line = content[..error.start].matches('\n').count() + 1;
+            problems.push(format!(
+                "  {}:{}: unknown style `{}`",
+                name, line, error.tag
+            ));
+        }
+    }
+    Ok(problems)
+}
+
+/// Returns the styles a YAML stylesheet defines: its top-level keys, except
+/// the `icons` section.
+fn yaml_style_names(yaml: &str) -> Result<Vec<String>, String> {
+    let root: serde_yaml::Value = serde_yaml::from_str(yaml).map_err(|e| e.to_string())?;
+    let mapping = root
+        .as_mapping()
+        .ok_or("Stylesheet must be a YAML mapping")?;
+    mapping
+        .keys()
+        .map(|key| {
+            key.as_str()
+                .map(String::from)
+                .ok_or_else(|| format!("Style name must be a string, got {:?}", key))
+        })
+        .filter(|name| !matches!(name.as_deref(), Ok("icons")))
+        .collect()
+}
+
+/// Returns the styles a CSS stylesheet defines: the class selectors of its
+/// rules, including those inside `@media` blocks.
+fn css_style_names(css: &str) -> Vec<String> {
+    let mut input = ParserInput::new(css);
+    let mut names = Vec::new();
+    collect_css_classes(&mut Parser::new(&mut input), &mut names);
+    names
+}
+
+fn collect_css_classes(input: &mut Parser, names: &mut Vec<String>) {
+    let mut in_at_rule = false;
+    while let Ok(token) = input.next() {
+        match token {
+            CssToken::AtKeyword(_) => in_at_rule = true,
+            CssToken::Delim('.') if !in_at_rule => {
+                if let Ok(name) = input.expect_ident_cloned() {
+                    names.push(name.to_string());
+                }
+            }
+            // Rule bodies hold declarations; at-rule bodies hold more rules
+            CssToken::CurlyBracketBlock => {
+                if in_at_rule {
+                    let _ = input.parse_nested_block(|block| {
+                        collect_css_classes(block, names);
+                        Ok::<_, ParseError<()>>(())
+                    });
+                }
+                in_at_rule = false;
+            }
+            CssToken::Semicolon => in_at_rule = false,
+            _ => {}
+        }
+    }
+}
+
+/// Replaces `{{ }}`, `{% %}` and `{# #}` blocks with spaces, keeping newlines
+/// so offsets and line numbers still match the source.
+fn blank_template_blocks(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find('{') {
+        let close = match rest[start..].chars().nth(1) {
+            Some('{') => "}}",
+            Some('%') => "%}",
+            Some('#') => "#}",
+            _ => {
+                out.push_str(&rest[..=start]);
+                rest = &rest[start + 1..];
+                continue;
+            }
+        };
+        out.push_str(&rest[..start]);
+        let end = rest[start + 2..]
+            .find(close)
+            .map_or(rest.len(), |i| start + 2 + i + close.len());
+        // One space per byte, so multi-byte characters keep their width
+        for c in rest[start..end].chars() {
+            match c {
+                '\n' => out.push('\n'),
+                c => out.push_str(&" ".repeat(c.len_utf8())),
+            }
+        }
+        rest = &rest[end..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn assets(template: &str) -> (TempDir, TempDir) {
+        let templates = TempDir::new().unwrap();
+        let styles = TempDir::new().unwrap();
+        fs::write(templates.path().join("list.j2"), template).unwrap();
+        fs::write(
+            styles.path().join("default.css"),
+            ".title { font-weight: bold; }",
+        )
+        .unwrap();
+        fs::write(styles.path().join("dark.yaml"), "muted:\n  dim: true\n").unwrap();
+        (templates, styles)
+    }
+
+    #[test]
+    fn test_known_styles_pass() {
+        let (templates, styles) = assets(
+            "[title]{{ name }}[/title]\n{% for i in items[1:] %}[muted]{{ i[0] }}[/muted]{% endfor %}\n[{{ status }}]x[/{{ status }}]",
+        );
+        let problems = check_assets(templates.path(), styles.path()).unwrap();
+        assert!(problems.is_empty(), "{problems:?}");
+    }
+
+    #[test]
+    fn test_unknown_styles_reported_with_lines() {
+        let (templates, styles) =
+            assets("[title]ok[/title]\n\n[titel]{{ name }}[/titel] [nope]x[/nope]");
+        let problems = check_assets(templates.path(), styles.path()).unwrap();
+        assert_eq!(
+            problems,
+            [
+                "  list.j2:3: unknown style `titel`",
+                "  list.j2:3: unknown style `nope`"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_style_names_from_stylesheets() {
+        let css = "/* .commented */ .title, .header { color: red; opacity: .5; }\n\
+                   @media (prefers-color-scheme: dark) { .muted { color: gray; } }";
+        assert_eq!(css_style_names(css), ["title", "header", "muted"]);
+
+        let yaml = "title:\n  bold: true\ndisabled: muted\nicons:\n  check: x\n";
+        let mut names = yaml_style_names(yaml).unwrap();
+        names.sort();
+        assert_eq!(names, ["disabled", "title"]);
+        assert!(yaml_style_names("- not a mapping").is_err());
+    }
+
+    #[test]
+    fn test_blank_template_blocks_keeps_offsets() {
+        let source = "a{{ x[0] }}\n{% if é %}b{# c #}{";
+        let blanked = blank_template_blocks(source);
+        assert_eq!(blanked.len(), source.len());
+        assert_eq!(blanked.lines().count(), 2);
+        assert!(blanked.starts_with("a "));
+        assert!(blanked.contains('b'));
+        assert!(!blanked.contains("x[0]"));
+    }
+}
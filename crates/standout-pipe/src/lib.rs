@@ -1,6 +1,8 @@
 pub mod pipe;
 pub mod platform;
 pub mod shell;
+pub mod tee;
 
 pub use pipe::{PipeError, PipeMode, PipeTarget, SimplePipe};
 pub use platform::clipboard;
+pub use tee::{strip_ansi, FileTarget, StdoutTarget, Tee, Transform};
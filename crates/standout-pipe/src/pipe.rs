@@ -1,10 +1,14 @@
-use crate::shell::{run_piped, ShellError};
+use crate::shell::{run_piped, run_program, ShellError};
 use std::time::Duration;
 
 #[derive(Debug, thiserror::Error)]
 pub enum PipeError {
     #[error("Shell error: {0}")]
     Shell(#[from] ShellError),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("{0} is not supported on this platform")]
+    Unsupported(&'static str),
 }
 
 /// A target that can receive piped output
@@ -51,8 +55,10 @@ pub enum PipeMode {
 /// ```
 pub struct SimplePipe {
     command: String,
+    /// Arguments when the command is run directly instead of by the shell.
+    args: Option<Vec<String>>,
     mode: PipeMode,
-    timeout: Duration,
+    timeout: Option<Duration>,
 }
 
 impl SimplePipe {
@@ -66,8 +72,36 @@ impl SimplePipe {
     pub fn new(command: impl Into<String>) -> Self {
         Self {
             command: command.into(),
+            args: None,
             mode: PipeMode::Passthrough,
-            timeout: Duration::from_secs(30),
+            timeout: Some(Duration::from_secs(30)),
+        }
+    }
+
+    /// Create a pipe that runs `program` with `args` and returns its stdout.
+    ///
+    /// The program is run directly, not through the shell, so arguments
+    /// need no escaping. The mode is [`PipeMode::Capture`] with no timeout,
+    /// since the program may wait on the user: this is how a handler hands a
+    /// rendered list to a picker and continues with the choice.
+    ///
+    /// ```ignore
+    /// let picked = SimplePipe::to_command("fzf", ["--prompt", "task> "])
+    ///     .pipe(&rendered_list)?;
+    /// ```
+    ///
+    /// A picker that's cancelled usually exits with a non-zero status, which
+    /// surfaces as [`ShellError::CommandFailed`].
+    pub fn to_command<I, S>(program: impl Into<String>, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            command: program.into(),
+            args: Some(args.into_iter().map(Into::into).collect()),
+            mode: PipeMode::Capture,
+            timeout: None,
         }
     }
 
@@ -84,14 +118,17 @@ impl SimplePipe {
     }
 
     pub fn with_timeout(mut self, timeout: Duration) -> Self {
-        self.timeout = timeout;
+        self.timeout = Some(timeout);
         self
     }
 }
 
 impl PipeTarget for SimplePipe {
     fn pipe(&self, input: &str) -> Result<String, PipeError> {
-        let cmd_output = run_piped(&self.command, input, Some(self.timeout))?;
+        let cmd_output = match &self.args {
+            Some(args) => run_program(&self.command, args, input, self.timeout)?,
+            None => run_piped(&self.command, input, self.timeout)?,
+        };
 
         match self.mode {
             PipeMode::Passthrough => Ok(input.to_string()),
@@ -143,4 +180,22 @@ mod tests {
         let output = pipe.pipe(input).unwrap();
         assert_eq!(output, "");
     }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_to_command_captures_stdout() {
+        let pipe = SimplePipe::to_command("sed", ["-n", "2p"]);
+        let output = pipe.pipe("first\nsecond; not a shell\n").unwrap();
+        assert_eq!(output, "second; not a shell\n");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_to_command_reports_failure() {
+        let pipe = SimplePipe::to_command("false", Vec::<String>::new());
+        assert!(matches!(
+            pipe.pipe(""),
+            Err(PipeError::Shell(ShellError::CommandFailed(cmd, _))) if cmd == "false"
+        ));
+    }
 }
@@ -1,5 +1,6 @@
-use std::io::Write;
+use std::io::{self, Read, Write};
 use std::process::{Command, Stdio};
+use std::thread;
 use std::time::Duration;
 use thiserror::Error;
 use wait_timeout::ChildExt;
@@ -35,7 +36,7 @@ pub fn run_piped(
     input: &str,
     timeout: Option<Duration>,
 ) -> Result<String, ShellError> {
-    let mut cmd = if cfg!(target_os = "windows") {
+    let cmd = if cfg!(target_os = "windows") {
         let mut c = Command::new("cmd");
         c.arg("/C").arg(command_str);
         c
@@ -44,44 +45,85 @@ pub fn run_piped(
         c.arg("-c").arg(command_str);
         c
     };
+    run_with_input(cmd, command_str, input, timeout)
+}
+
+/// Execute a program directly, without a shell, with the given input piped
+/// to stdin.
+///
+/// Arguments are passed as-is, so they need no quoting or escaping. Like
+/// [`run_piped`], returns the program's stdout on success. The program's
+/// stderr and controlling terminal are left alone, so interactive pickers
+/// such as `fzf` can draw their UI.
+pub fn run_program<I, S>(
+    program: &str,
+    args: I,
+    input: &str,
+    timeout: Option<Duration>,
+) -> Result<String, ShellError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<std::ffi::OsStr>,
+{
+    let mut cmd = Command::new(program);
+    cmd.args(args);
+    run_with_input(cmd, program, input, timeout)
+}
 
+/// Runs `cmd` with `input` on stdin, naming it `command_str` in errors.
+fn run_with_input(
+    mut cmd: Command,
+    command_str: &str,
+    input: &str,
+    timeout: Option<Duration>,
+) -> Result<String, ShellError> {
     cmd.stdin(Stdio::piped())
         .stdout(Stdio::piped())
         .stderr(Stdio::inherit());
 
     let mut child = cmd.spawn()?;
 
-    if let Some(mut stdin) = child.stdin.take() {
-        stdin.write_all(input.as_bytes())?;
-    }
+    // Feed stdin and drain stdout on their own threads, so a program that
+    // writes more than a pipe buffer before reading all its input can't
+    // deadlock against us
+    let writer = child.stdin.take().map(|mut stdin| {
+        let input = input.to_owned();
+        thread::spawn(move || match stdin.write_all(input.as_bytes()) {
+            // The program exited without reading everything
+            Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+            result => result,
+        })
+    });
+    let reader = child.stdout.take().map(|mut stdout| {
+        thread::spawn(move || {
+            let mut output = Vec::new();
+            stdout.read_to_end(&mut output).map(|_| output)
+        })
+    });
 
-    match timeout {
+    let status = match timeout {
         Some(duration) => match child.wait_timeout(duration)? {
-            Some(status) => {
-                if !status.success() {
-                    return Err(ShellError::CommandFailed(command_str.to_string(), status));
-                }
-            }
+            Some(status) => status,
             None => {
                 child.kill()?;
+                let _ = child.wait();
                 return Err(ShellError::Timeout(command_str.to_string(), duration));
             }
         },
-        None => {
-            let status = child.wait()?;
-            if !status.success() {
-                return Err(ShellError::CommandFailed(command_str.to_string(), status));
-            }
-        }
+        None => child.wait()?,
+    };
+    if !status.success() {
+        return Err(ShellError::CommandFailed(command_str.to_string(), status));
     }
 
-    let mut output = String::new();
-    if let Some(mut stdout) = child.stdout.take() {
-        use std::io::Read;
-        stdout.read_to_string(&mut output)?;
+    if let Some(writer) = writer {
+        writer.join().expect("stdin writer panicked")?;
     }
-
-    Ok(output)
+    let output = match reader {
+        Some(reader) => reader.join().expect("stdout reader panicked")?,
+        None => Vec::new(),
+    };
+    Ok(String::from_utf8(output)?)
 }
 
 #[cfg(test)]
@@ -107,6 +149,21 @@ mod tests {
         assert_eq!(output.trim(), "foo");
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn test_run_program_passes_args_verbatim() {
+        let output = run_program("grep", ["-e", "a b"], "a b\nab\n", None).unwrap();
+        assert_eq!(output, "a b\n");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_run_program_echoing_more_than_a_pipe_buffer() {
+        let input = "x".repeat(300 * 1024);
+        let output = run_program("cat", std::iter::empty::<&str>(), &input, None).unwrap();
+        assert_eq!(output.len(), input.len());
+    }
+
     #[test]
     fn test_timeout() {
         let cmd = if cfg!(windows) {
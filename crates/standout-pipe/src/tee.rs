@@ -0,0 +1,276 @@
+//! Fanning output out to several targets in one call.
+//!
+//! A [`Tee`] sends the same output to each of its targets, each with its own
+//! [`Transform`]: the terminal usually wants the styled text, while files and
+//! the clipboard want it with ANSI codes stripped.
+
+use crate::pipe::{PipeError, PipeTarget};
+use std::io::Write;
+use std::path::PathBuf;
+
+/// What a tee target receives.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transform {
+    /// The output as given, ANSI codes included.
+    Ansi,
+    /// The output with ANSI codes removed.
+    StripAnsi,
+}
+
+/// Sends output to several targets at once.
+///
+/// Every target runs, even if an earlier one fails; the first error is
+/// returned. As a [`PipeTarget`], a tee is passthrough: it returns its
+/// input unchanged.
+///
+/// ```ignore
+/// use standout_pipe::{Tee, Transform, SimplePipe};
+///
+/// let tee = Tee::new()
+///     .terminal()
+///     .file("report.txt")
+///     .clipboard();
+/// tee.tee(&styled, &plain)?;
+///
+/// // Any PipeTarget can be added, with the text it should receive
+/// let tee = Tee::new().target(SimplePipe::new("logger"), Transform::StripAnsi);
+/// ```
+#[derive(Default)]
+pub struct Tee {
+    targets: Vec<(Box<dyn PipeTarget>, Transform)>,
+}
+
+impl Tee {
+    /// Creates a tee with no targets.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a target receiving the output as selected by `transform`.
+    pub fn target(mut self, target: impl PipeTarget + 'static, transform: Transform) -> Self {
+        self.targets.push((Box::new(target), transform));
+        self
+    }
+
+    /// Adds stdout, receiving the styled output.
+    pub fn terminal(self) -> Self {
+        self.target(StdoutTarget, Transform::Ansi)
+    }
+
+    /// Adds a file, overwritten with the output stripped of ANSI codes.
+    pub fn file(self, path: impl Into<PathBuf>) -> Self {
+        self.target(FileTarget::new(path), Transform::StripAnsi)
+    }
+
+    /// Adds the system clipboard, receiving the output stripped of ANSI
+    /// codes.
+    ///
+    /// Fails when piping on platforms without a known clipboard command
+    /// (see [`clipboard`](crate::clipboard)).
+    pub fn clipboard(self) -> Self {
+        self.target(ClipboardTarget, Transform::StripAnsi)
+    }
+
+    /// Returns the number of targets.
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Returns `true` if the tee has no targets.
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    /// Sends `styled` or `plain` to each target, by its transform.
+    ///
+    /// Use this when the plain text is already at hand, as in standout's
+    /// rendered output; [`pipe`](PipeTarget::pipe) strips the codes itself.
+    pub fn tee(&self, styled: &str, plain: &str) -> Result<(), PipeError> {
+        let mut first_error = None;
+        for (target, transform) in &self.targets {
+            let input = match transform {
+                Transform::Ansi => styled,
+                Transform::StripAnsi => plain,
+            };
+            if let Err(e) = target.pipe(input) {
+                first_error.get_or_insert(e);
+            }
+        }
+        first_error.map_or(Ok(()), Err)
+    }
+}
+
+impl PipeTarget for Tee {
+    fn pipe(&self, input: &str) -> Result<String, PipeError> {
+        self.tee(input, &strip_ansi(input))?;
+        Ok(input.to_string())
+    }
+}
+
+/// Writes its input to stdout.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StdoutTarget;
+
+impl PipeTarget for StdoutTarget {
+    fn pipe(&self, input: &str) -> Result<String, PipeError> {
+        let mut stdout = std::io::stdout().lock();
+        stdout.write_all(input.as_bytes())?;
+        stdout.flush()?;
+        Ok(input.to_string())
+    }
+}
+
+/// Writes its input to a file, replacing it unless appending.
+#[derive(Debug, Clone)]
+pub struct FileTarget {
+    path: PathBuf,
+    append: bool,
+}
+
+impl FileTarget {
+    /// Creates a target that overwrites `path`.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            append: false,
+        }
+    }
+
+    /// Appends to the file instead of replacing it.
+    pub fn append(mut self) -> Self {
+        self.append = true;
+        self
+    }
+}
+
+impl PipeTarget for FileTarget {
+    fn pipe(&self, input: &str) -> Result<String, PipeError> {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(self.append)
+            .truncate(!self.append)
+            .open(&self.path)?;
+        file.write_all(input.as_bytes())?;
+        Ok(input.to_string())
+    }
+}
+
+/// The platform clipboard, resolved when piping.
+struct ClipboardTarget;
+
+impl PipeTarget for ClipboardTarget {
+    fn pipe(&self, input: &str) -> Result<String, PipeError> {
+        crate::clipboard()
+            .ok_or(PipeError::Unsupported("clipboard"))?
+            .pipe(input)
+    }
+}
+
+/// Removes ANSI escape sequences (CSI and OSC) from `text`.
+pub fn strip_ansi(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            // CSI: parameters, then a final byte in @..~
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('@'..='~').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            // OSC: ends with BEL or ESC \
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' && chars.peek() == Some(&'\\') {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    struct Recorder(Arc<Mutex<Vec<String>>>);
+
+    impl PipeTarget for Recorder {
+        fn pipe(&self, input: &str) -> Result<String, PipeError> {
+            self.0.lock().unwrap().push(input.to_string());
+            Ok(input.to_string())
+        }
+    }
+
+    struct Failing;
+
+    impl PipeTarget for Failing {
+        fn pipe(&self, _input: &str) -> Result<String, PipeError> {
+            Err(PipeError::Unsupported("failing"))
+        }
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("\x1b[1;31mred\x1b[0m plain"), "red plain");
+        assert_eq!(
+            strip_ansi("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x07"),
+            "link"
+        );
+        assert_eq!(strip_ansi("no codes"), "no codes");
+    }
+
+    #[test]
+    fn test_tee_applies_transform_per_target() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let tee = Tee::new()
+            .target(Recorder(seen.clone()), Transform::Ansi)
+            .target(Recorder(seen.clone()), Transform::StripAnsi);
+
+        let output = tee.pipe("\x1b[1mbold\x1b[0m").unwrap();
+
+        assert_eq!(output, "\x1b[1mbold\x1b[0m");
+        assert_eq!(*seen.lock().unwrap(), ["\x1b[1mbold\x1b[0m", "bold"]);
+    }
+
+    #[test]
+    fn test_tee_runs_every_target_and_returns_first_error() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let tee = Tee::new()
+            .target(Failing, Transform::Ansi)
+            .target(Recorder(seen.clone()), Transform::StripAnsi);
+
+        let result = tee.tee("styled", "plain");
+
+        assert!(matches!(result, Err(PipeError::Unsupported("failing"))));
+        assert_eq!(*seen.lock().unwrap(), ["plain"]);
+    }
+
+    #[test]
+    fn test_file_target_overwrites_or_appends() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.txt");
+
+        Tee::new().file(&path).tee("\x1b[1mx\x1b[0m", "x").unwrap();
+        Tee::new().file(&path).tee("y", "y").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "y");
+
+        FileTarget::new(&path).append().pipe("z").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "yz");
+    }
+}
@@ -0,0 +1,257 @@
+//! What the terminal on stdout can do.
+//!
+//! [`Capabilities::detect`] gathers everything layout decisions tend to need
+//! in one report: whether stdout is a terminal, how many colors it shows,
+//! whether it can draw Unicode and hyperlinks, its size, and whether the
+//! process runs in CI.
+//!
+//! ```rust
+//! use standout_render::Capabilities;
+//!
+//! let caps = Capabilities::detect();
+//! let bullet = if caps.unicode { "•" } else { "*" };
+//! let width = caps.width.unwrap_or(80);
+//! ```
+//!
+//! Color support comes from
+//! [`detect_color_capability`](crate::detect_color_capability), the same
+//! check [`OutputMode::Auto`](crate::OutputMode::Auto) makes, so the report
+//! always agrees with how `Auto` renders. The TTY and size fields also go
+//! through the overridable detectors in [`environment`](crate::environment);
+//! the rest is read from environment variables.
+
+use serde::Serialize;
+
+use crate::environment::{
+    detect_color_capability, detect_is_tty, detect_terminal_height, detect_terminal_width,
+};
+
+/// How many colors the terminal shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColorDepth {
+    /// No color: output is plain text.
+    None,
+    /// The 16 basic ANSI colors.
+    Ansi16,
+    /// The 256-color palette.
+    Ansi256,
+    /// 24-bit RGB.
+    TrueColor,
+}
+
+/// A report of the terminal's capabilities.
+///
+/// Serializes to a map with the field names as keys (`color` as `"none"`,
+/// `"ansi16"`, `"ansi256"` or `"truecolor"`), which is how `standout`
+/// exposes it to templates as `terminal`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Capabilities {
+    /// Whether stdout is a terminal.
+    pub tty: bool,
+    /// How many colors output shows.
+    pub color: ColorDepth,
+    /// Whether the terminal can show Unicode (from the locale on Unix).
+    pub unicode: bool,
+    /// Whether the terminal makes OSC 8 hyperlinks clickable.
+    ///
+    /// `FORCE_HYPERLINK=1` or `=0` overrides the detection.
+    pub hyperlinks: bool,
+    /// Width in columns, if known.
+    pub width: Option<usize>,
+    /// Height in rows, if known.
+    pub height: Option<usize>,
+    /// Whether the process runs in CI (`CI` set, and not `0` or `false`).
+    pub ci: bool,
+}
+
+/// Terminal programs known to support OSC 8 hyperlinks, by `TERM_PROGRAM`.
+const HYPERLINK_PROGRAMS: &[&str] = &["iTerm.app", "WezTerm", "vscode", "Hyper", "ghostty"];
+
+/// `TERM` values of terminals known to support OSC 8 hyperlinks.
+const HYPERLINK_TERMS: &[&str] = &[
+    "xterm-kitty",
+    "alacritty",
+    "foot",
+    "xterm-ghostty",
+    "wezterm",
+];
+
+impl Capabilities {
+    /// Detects the capabilities of the terminal on stdout.
+    pub fn detect() -> Self {
+        Self::from_probes(
+            detect_is_tty(),
+            detect_color_capability(),
+            detect_terminal_width(),
+            detect_terminal_height(),
+            |name| std::env::var(name).ok(),
+        )
+    }
+
+    /// Returns `true` if output shows any color.
+    pub fn supports_color(&self) -> bool {
+        self.color != ColorDepth::None
+    }
+
+    fn from_probes(
+        tty: bool,
+        color_capable: bool,
+        width: Option<usize>,
+        height: Option<usize>,
+        env: impl Fn(&str) -> Option<String>,
+    ) -> Self {
+        let set = |name: &str| env(name).filter(|v| !v.is_empty());
+        let ci = set("CI").is_some_and(|v| v != "0" && !v.eq_ignore_ascii_case("false"));
+
+        let color = if !color_capable {
+            ColorDepth::None
+        } else if set("COLORTERM").is_some_and(|v| v == "truecolor" || v == "24bit")
+            || set("WT_SESSION").is_some()
+        {
+            ColorDepth::TrueColor
+        } else if set("TERM").is_some_and(|v| v.contains("256color")) {
+            ColorDepth::Ansi256
+        } else {
+            ColorDepth::Ansi16
+        };
+
+        let unicode = if cfg!(windows) {
+            set("WT_SESSION").is_some() || set("TERM_PROGRAM").is_some() || set("TERM").is_some()
+        } else {
+            set("TERM").as_deref() != Some("linux")
+                && ["LC_ALL", "LC_CTYPE", "LANG"]
+                    .iter()
+                    .find_map(|name| set(name))
+                    .is_some_and(|locale| {
+                        let locale = locale.to_ascii_lowercase();
+                        locale.contains("utf-8") || locale.contains("utf8")
+                    })
+        };
+
+        let hyperlinks = match set("FORCE_HYPERLINK") {
+            Some(force) => force != "0",
+            None => {
+                tty && !ci
+                    && (set("WT_SESSION").is_some()
+                        || set("KONSOLE_VERSION").is_some()
+                        || set("DOMTERM").is_some()
+                        || set("TERM_PROGRAM")
+                            .is_some_and(|p| HYPERLINK_PROGRAMS.contains(&p.as_str()))
+                        || set("TERM").is_some_and(|t| HYPERLINK_TERMS.contains(&t.as_str()))
+                        || set("VTE_VERSION")
+                            .and_then(|v| v.parse::<u32>().ok())
+                            .is_some_and(|v| v >= 5000))
+            }
+        };
+
+        Self {
+            tty,
+            color,
+            unicode,
+            hyperlinks,
+            width,
+            height,
+            ci,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::{
+        set_color_capability_detector, set_terminal_height_detector, set_terminal_width_detector,
+        set_tty_detector, DetectorGuard,
+    };
+    use serial_test::serial;
+
+    fn probe(tty: bool, color: bool, vars: &[(&str, &str)]) -> Capabilities {
+        Capabilities::from_probes(tty, color, Some(80), Some(24), |name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        })
+    }
+
+    #[test]
+    fn color_depth_follows_capability_and_env() {
+        assert_eq!(
+            probe(true, false, &[("COLORTERM", "truecolor")]).color,
+            ColorDepth::None
+        );
+        assert_eq!(
+            probe(true, true, &[("COLORTERM", "24bit")]).color,
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            probe(true, true, &[("TERM", "xterm-256color")]).color,
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            probe(true, true, &[("TERM", "xterm")]).color,
+            ColorDepth::Ansi16
+        );
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn unicode_follows_locale() {
+        assert!(probe(true, true, &[("LANG", "en_US.UTF-8")]).unicode);
+        assert!(probe(true, true, &[("LC_ALL", "C.utf8"), ("LANG", "C")]).unicode);
+        assert!(!probe(true, true, &[("LC_ALL", "C"), ("LANG", "en_US.UTF-8")]).unicode);
+        assert!(!probe(true, true, &[("LANG", "en_US.UTF-8"), ("TERM", "linux")]).unicode);
+        assert!(!probe(true, true, &[]).unicode);
+    }
+
+    #[test]
+    fn hyperlinks_need_a_known_terminal() {
+        assert!(probe(true, true, &[("TERM_PROGRAM", "WezTerm")]).hyperlinks);
+        assert!(probe(true, true, &[("VTE_VERSION", "6003")]).hyperlinks);
+        assert!(!probe(true, true, &[("VTE_VERSION", "4601")]).hyperlinks);
+        assert!(!probe(false, true, &[("TERM_PROGRAM", "WezTerm")]).hyperlinks);
+        assert!(!probe(true, true, &[("TERM_PROGRAM", "WezTerm"), ("CI", "1")]).hyperlinks);
+        assert!(probe(false, false, &[("FORCE_HYPERLINK", "1")]).hyperlinks);
+        assert!(
+            !probe(
+                true,
+                true,
+                &[("TERM_PROGRAM", "WezTerm"), ("FORCE_HYPERLINK", "0")]
+            )
+            .hyperlinks
+        );
+    }
+
+    #[test]
+    fn ci_flag() {
+        assert!(probe(false, false, &[("CI", "true")]).ci);
+        assert!(!probe(false, false, &[("CI", "false")]).ci);
+        assert!(!probe(false, false, &[("CI", "0")]).ci);
+        assert!(!probe(false, false, &[("CI", "")]).ci);
+    }
+
+    #[test]
+    #[serial]
+    fn detect_uses_environment_detectors() {
+        let _guard = DetectorGuard::new();
+        set_tty_detector(|| true);
+        set_color_capability_detector(|| false);
+        set_terminal_width_detector(|| Some(100));
+        set_terminal_height_detector(|| Some(40));
+
+        let caps = Capabilities::detect();
+
+        assert!(caps.tty);
+        assert!(!caps.supports_color());
+        assert_eq!((caps.width, caps.height), (Some(100), Some(40)));
+    }
+
+    #[test]
+    fn serializes_for_templates() {
+        let caps = probe(true, true, &[("COLORTERM", "truecolor")]);
+        let value = serde_json::to_value(&caps).unwrap();
+
+        assert_eq!(value["color"], "truecolor");
+        assert_eq!(value["width"], 80);
+    }
+}
@@ -51,12 +51,12 @@
 //! };
 //! ```
 
-use super::output::OutputMode;
+use super::output::{CsvOptions, OutputMode};
 use super::theme::Theme;
 use minijinja::Value;
 use std::collections::HashMap;
 use std::fmt::Debug;
-use std::rc::Rc;
+use std::sync::Arc;
 
 /// Information available at render time for dynamic context providers.
 ///
@@ -70,6 +70,7 @@ use std::rc::Rc;
 /// - `theme`: The theme being used for rendering
 /// - `data`: The handler's output data as a JSON value
 /// - `extras`: Additional string key-value pairs for extension
+/// - `csv_options`: Delimiter, quoting and header settings for CSV output
 ///
 /// # Example
 ///
@@ -83,6 +84,7 @@ use std::rc::Rc;
 ///     theme: &Theme::new(),
 ///     data: &serde_json::json!({"count": 42}),
 ///     extras: std::collections::HashMap::new(),
+///     csv_options: Default::default(),
 /// };
 ///
 /// // Use context to configure a formatter
@@ -115,6 +117,9 @@ pub struct RenderContext<'a> {
     /// This allows passing arbitrary metadata to context providers
     /// without modifying the struct definition.
     pub extras: HashMap<String, String>,
+
+    /// Formatting options used when the output mode is CSV.
+    pub csv_options: CsvOptions,
 }
 
 impl<'a> RenderContext<'a> {
@@ -131,9 +136,16 @@ impl<'a> RenderContext<'a> {
             theme,
             data,
             extras: HashMap::new(),
+            csv_options: CsvOptions::default(),
         }
     }
 
+    /// Sets the CSV formatting options.
+    pub fn with_csv_options(mut self, options: CsvOptions) -> Self {
+        self.csv_options = options;
+        self
+    }
+
     /// Adds an extra key-value pair to the context.
     pub fn with_extra(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
         self.extras.insert(key.into(), value.into());
@@ -171,11 +183,12 @@ impl<'a> RenderContext<'a> {
 /// };
 /// ```
 ///
-/// # Single-Threaded Design
+/// # Thread Safety
 ///
-/// CLI applications are single-threaded, so context providers don't require
-/// `Send + Sync` bounds.
-pub trait ContextProvider {
+/// Providers are `Send + Sync`, so a [`ContextRegistry`] can be shared by
+/// threads rendering at the same time, as in a server producing CLI-style
+/// reports.
+pub trait ContextProvider: Send + Sync {
     /// Produce a context object for the given render context.
     ///
     /// The returned value will be made available in templates under the
@@ -186,7 +199,7 @@ pub trait ContextProvider {
 /// Blanket implementation for closures that return values convertible to minijinja::Value.
 impl<F> ContextProvider for F
 where
-    F: Fn(&RenderContext) -> Value,
+    F: Fn(&RenderContext) -> Value + Send + Sync,
 {
     fn provide(&self, ctx: &RenderContext) -> Value {
         (self)(ctx)
@@ -217,10 +230,11 @@ impl ContextProvider for StaticProvider {
 
 /// Storage for context entries, supporting both static and dynamic providers.
 ///
-/// `ContextRegistry` is cheap to clone since it stores providers as `Rc`.
+/// `ContextRegistry` is cheap to clone since it stores providers as `Arc`,
+/// and is `Send + Sync`.
 #[derive(Default, Clone)]
 pub struct ContextRegistry {
-    providers: HashMap<String, Rc<dyn ContextProvider>>,
+    providers: HashMap<String, Arc<dyn ContextProvider>>,
 }
 
 impl ContextRegistry {
@@ -234,7 +248,7 @@ impl ContextRegistry {
     /// The value will be available in templates under the given name.
     pub fn add_static(&mut self, name: impl Into<String>, value: Value) {
         self.providers
-            .insert(name.into(), Rc::new(StaticProvider::new(value)));
+            .insert(name.into(), Arc::new(StaticProvider::new(value)));
     }
 
     /// Registers a dynamic context provider.
@@ -245,7 +259,7 @@ impl ContextRegistry {
         name: impl Into<String>,
         provider: P,
     ) {
-        self.providers.insert(name.into(), Rc::new(provider));
+        self.providers.insert(name.into(), Arc::new(provider));
     }
 
     /// Returns true if the registry has no entries.
@@ -301,6 +315,28 @@ mod tests {
         assert!(ctx.extras.is_empty());
     }
 
+    #[test]
+    fn registry_resolves_from_several_threads() {
+        let mut registry = ContextRegistry::new();
+        registry.add_static("app", Value::from("demo"));
+        registry.add_provider("width", |ctx: &RenderContext| {
+            Value::from(ctx.terminal_width.unwrap_or(80))
+        });
+
+        std::thread::scope(|scope| {
+            for width in [40, 120] {
+                let registry = &registry;
+                scope.spawn(move || {
+                    let (theme, data) = test_context();
+                    let ctx = RenderContext::new(OutputMode::Text, Some(width), &theme, &data);
+                    let resolved = registry.resolve(&ctx);
+                    assert_eq!(resolved.get("width"), Some(&Value::from(width)));
+                    assert_eq!(resolved.get("app"), Some(&Value::from("demo")));
+                });
+            }
+        });
+    }
+
     #[test]
     fn render_context_with_extras() {
         let (theme, data) = test_context();
@@ -1,7 +1,7 @@
 //! Injectable environment detection.
 //!
 //! This module centralizes process-global detection of terminal properties
-//! — width, TTY status, and ANSI color capability — behind overridable
+//! — width, height, TTY status, and ANSI color capability — behind overridable
 //! function pointers so tests can force specific values without touching
 //! real environment state.
 //!
@@ -38,7 +38,29 @@
 //! Overrides are process-global, so tests that set them should be annotated
 //! with `#[serial]` (via the `serial_test` crate) and should use
 //! [`DetectorGuard`] to guarantee cleanup even when the test panics.
+//!
+//! # Piped Output
+//!
+//! By default, color is off whenever stdout isn't a terminal. That's right
+//! for `myapp | grep`, but loses color in `myapp | less -R`. Apps can opt
+//! into keeping it for known pagers with [`set_piped_color`]:
+//!
+//! ```rust
+//! use standout_render::environment::{set_piped_color, PipedColor};
+//!
+//! set_piped_color(PipedColor::pagers());
+//! # set_piped_color(PipedColor::Strip);
+//! ```
+//!
+//! # WebAssembly
+//!
+//! On wasm targets the default detectors report no terminal: no TTY, no
+//! color and no size. [`OutputMode::Auto`](crate::OutputMode::Auto) renders
+//! plain text there, and hosts that can show ANSI (say, a web terminal) opt
+//! in with `set_color_capability_detector(|| true)` or by rendering with
+//! [`OutputMode::Term`](crate::OutputMode::Term).
 
+#[cfg(not(target_family = "wasm"))]
 use console::Term;
 use once_cell::sync::Lazy;
 use std::sync::Mutex;
@@ -49,9 +71,50 @@ type ColorDetector = fn() -> bool;
 
 static WIDTH_DETECTOR: Lazy<Mutex<WidthDetector>> =
     Lazy::new(|| Mutex::new(default_width_detector));
+static HEIGHT_DETECTOR: Lazy<Mutex<WidthDetector>> =
+    Lazy::new(|| Mutex::new(default_height_detector));
 static TTY_DETECTOR: Lazy<Mutex<TtyDetector>> = Lazy::new(|| Mutex::new(default_tty_detector));
 static COLOR_DETECTOR: Lazy<Mutex<ColorDetector>> =
     Lazy::new(|| Mutex::new(default_color_detector));
+static PIPED_COLOR: Lazy<Mutex<PipedColor>> = Lazy::new(|| Mutex::new(PipedColor::Strip));
+
+/// Pagers that [`PipedColor::pagers`] keeps color for.
+pub const COLOR_PAGERS: &[&str] = &["less", "bat", "delta", "moar", "ov"];
+
+/// How the default color detector treats stdout when it's a pipe.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum PipedColor {
+    /// No color when stdout isn't a terminal (the default).
+    #[default]
+    Strip,
+    /// Keep color when the program reading the pipe is one of these and
+    /// shows ANSI codes as colors.
+    ///
+    /// `less` qualifies only with `-R` / `-r`, passed to it or set in its
+    /// `LESS` environment variable. The reader is found through `/proc`, so
+    /// this only has an effect on Linux. `NO_COLOR` still turns color off.
+    KeepFor(Vec<String>),
+}
+
+impl PipedColor {
+    /// Keeps color for the pagers in [`COLOR_PAGERS`].
+    pub fn pagers() -> Self {
+        Self::KeepFor(COLOR_PAGERS.iter().map(|p| p.to_string()).collect())
+    }
+}
+
+/// Sets how the default color detector treats piped stdout.
+///
+/// See [`PipedColor`]. Has no effect while a detector installed with
+/// [`set_color_capability_detector`] is in place.
+pub fn set_piped_color(policy: PipedColor) {
+    *PIPED_COLOR.lock().unwrap() = policy;
+}
+
+/// Returns the current [`PipedColor`] policy.
+pub fn piped_color() -> PipedColor {
+    PIPED_COLOR.lock().unwrap().clone()
+}
 
 /// Overrides the detector used to query terminal width.
 ///
@@ -62,6 +125,14 @@ pub fn set_terminal_width_detector(detector: WidthDetector) {
     *WIDTH_DETECTOR.lock().unwrap() = detector;
 }
 
+/// Overrides the detector used to query terminal height.
+///
+/// Accepts a `fn` pointer or a non-capturing closure, returning `Some(rows)`
+/// or `None` like the width detector.
+pub fn set_terminal_height_detector(detector: WidthDetector) {
+    *HEIGHT_DETECTOR.lock().unwrap() = detector;
+}
+
 /// Overrides the detector used to check whether stdout is a TTY.
 ///
 /// Accepts a `fn` pointer or a non-capturing closure.
@@ -88,6 +159,12 @@ pub fn detect_terminal_width() -> Option<usize> {
     detector()
 }
 
+/// Returns the current terminal height in rows, or `None` when unavailable.
+pub fn detect_terminal_height() -> Option<usize> {
+    let detector = *HEIGHT_DETECTOR.lock().unwrap();
+    detector()
+}
+
 /// Returns `true` when stdout is attached to a terminal.
 pub fn detect_is_tty() -> bool {
     let detector = *TTY_DETECTOR.lock().unwrap();
@@ -100,16 +177,77 @@ pub fn detect_color_capability() -> bool {
     detector()
 }
 
+#[cfg(not(target_family = "wasm"))]
 fn default_width_detector() -> Option<usize> {
     terminal_size::terminal_size().map(|(w, _)| w.0 as usize)
 }
 
+#[cfg(not(target_family = "wasm"))]
+fn default_height_detector() -> Option<usize> {
+    terminal_size::terminal_size().map(|(_, h)| h.0 as usize)
+}
+
+#[cfg(not(target_family = "wasm"))]
 fn default_tty_detector() -> bool {
     Term::stdout().is_term()
 }
 
+// On wasm, stdout goes wherever the host sends it, so output is plain text
+// unless the host installs detectors of its own.
+
+#[cfg(target_family = "wasm")]
+fn default_width_detector() -> Option<usize> {
+    None
+}
+
+#[cfg(target_family = "wasm")]
+fn default_height_detector() -> Option<usize> {
+    None
+}
+
+#[cfg(target_family = "wasm")]
+fn default_tty_detector() -> bool {
+    false
+}
+
+#[cfg(target_family = "wasm")]
+fn default_color_detector() -> bool {
+    false
+}
+
+/// Lets the Windows console interpret ANSI escape codes, returning whether
+/// it does.
+///
+/// Turns on virtual terminal processing for stdout and stderr, once per
+/// process. Legacy consoles without it (before Windows 10) return `false`,
+/// and styled output falls back to plain text there rather than printing raw
+/// escape codes. Returns `true` when stdout isn't a console, and on other
+/// platforms.
+pub fn enable_virtual_terminal() -> bool {
+    static ENABLED: once_cell::sync::OnceCell<bool> = once_cell::sync::OnceCell::new();
+    *ENABLED.get_or_init(|| {
+        #[cfg(windows)]
+        if Term::stdout().is_term() {
+            return anstyle_query::windows::enable_ansi_colors().unwrap_or(false);
+        }
+        true
+    })
+}
+
+#[cfg(not(target_family = "wasm"))]
 fn default_color_detector() -> bool {
-    Term::stdout().features().colors_supported()
+    let stdout = Term::stdout();
+    if stdout.is_term() {
+        return enable_virtual_terminal() && stdout.features().colors_supported();
+    }
+    let PipedColor::KeepFor(readers) = &*PIPED_COLOR.lock().unwrap() else {
+        return false;
+    };
+    if std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+        return false;
+    }
+    crate::pipe_reader::stdout_reader()
+        .is_some_and(|reader| readers.contains(&reader.name) && reader.renders_ansi())
 }
 
 /// Resets every environment detector in this module to its default
@@ -120,6 +258,7 @@ fn default_color_detector() -> bool {
 /// [`DetectorGuard`] instead of calling this manually.
 pub fn reset_detectors() {
     set_terminal_width_detector(default_width_detector);
+    set_terminal_height_detector(default_height_detector);
     set_tty_detector(default_tty_detector);
     set_color_capability_detector(default_color_detector);
 }
@@ -177,6 +316,14 @@ mod tests {
         assert_eq!(detect_terminal_width(), None);
     }
 
+    #[test]
+    #[serial]
+    fn height_override_is_honored() {
+        let _guard = DetectorGuard::new();
+        set_terminal_height_detector(|| Some(24));
+        assert_eq!(detect_terminal_height(), Some(24));
+    }
+
     #[test]
     #[serial]
     fn tty_override_is_honored() {
@@ -222,6 +369,23 @@ mod tests {
         let _ = detect_color_capability();
     }
 
+    #[test]
+    #[cfg(not(windows))]
+    fn virtual_terminal_is_a_no_op_off_windows() {
+        assert!(enable_virtual_terminal());
+    }
+
+    #[test]
+    #[serial]
+    fn piped_color_policy_round_trips() {
+        assert_eq!(piped_color(), PipedColor::Strip);
+        set_piped_color(PipedColor::pagers());
+        assert!(
+            matches!(piped_color(), PipedColor::KeepFor(ref p) if p.len() == COLOR_PAGERS.len())
+        );
+        set_piped_color(PipedColor::Strip);
+    }
+
     #[test]
     #[serial]
     fn guard_restores_on_drop() {
@@ -81,6 +81,12 @@ impl From<quick_xml::DeError> for RenderError {
     }
 }
 
+impl From<toml::ser::Error> for RenderError {
+    fn from(err: toml::ser::Error) -> Self {
+        RenderError::SerializationError(err.to_string())
+    }
+}
+
 impl From<csv::Error> for RenderError {
     fn from(err: csv::Error) -> Self {
         RenderError::SerializationError(err.to_string())
@@ -10,7 +10,7 @@
 //! - [`Theme`]: Named, adaptive styles that automatically respect [`ColorMode`]
 //! - [`Renderer`]: Compile and reuse templates for fast repeated rendering
 //! - [`validate_template`]: Surface typos or unknown tags before you ship templates
-//! - [`OutputMode`]: Control how content is emitted (Auto/Term/Text/TermDebug/Json/Yaml)
+//! - [`OutputMode`]: Control how content is emitted (Auto/Term/Text/TermDebug/Json/Yaml/Toml)
 //! - Style syntax: Tag-based `[name]content[/name]` markup for inline styling
 //!
 //! ## Quick Start
@@ -144,6 +144,7 @@
 //! ```
 
 // Internal modules
+pub mod capabilities;
 pub mod colorspace;
 pub mod context;
 mod embedded;
@@ -151,6 +152,8 @@ pub mod environment;
 mod error;
 pub mod file_loader;
 pub mod output;
+#[cfg(not(target_family = "wasm"))]
+mod pipe_reader;
 pub mod prelude;
 pub mod style;
 pub mod tabular;
@@ -164,38 +167,49 @@ pub use error::RenderError;
 
 // Style module exports (including former stylesheet exports)
 pub use style::{
-    parse_css, parse_stylesheet, ColorDef, StyleAttributes, StyleDefinition, StyleValidationError,
-    StyleValue, Styles, StylesheetError, StylesheetRegistry, ThemeVariants,
-    DEFAULT_MISSING_STYLE_INDICATOR, STYLESHEET_EXTENSIONS,
+    parse_css, parse_stylesheet, ColorDef, ConditionalStyle, StyleAttributes, StyleCondition,
+    StyleDefinition, StyleValidationError, StyleValue, Styles, StylesheetError, StylesheetRegistry,
+    ThemeVariants, DEFAULT_MISSING_STYLE_INDICATOR, STYLESHEET_EXTENSIONS,
 };
 
 // Theme module exports
 pub use theme::{
-    detect_color_mode, detect_icon_mode, set_icon_detector, set_theme_detector, ColorMode,
-    IconDefinition, IconMode, IconSet, Theme,
+    detect_color_mode, detect_icon_mode, reset_theme_detector, set_icon_detector,
+    set_theme_detector, ColorMode, IconDefinition, IconMode, IconSet, Theme, ThemeDef,
 };
 
 // Output module exports
-pub use output::{write_binary_output, write_output, OutputDestination, OutputMode};
+pub use output::{
+    write_binary_output, write_binary_output_with, write_output, write_output_with, CsvOptions,
+    CsvQuoting, FileWriteOptions, OutputDestination, OutputMode,
+};
 
 // Environment detection exports
 pub use environment::{
-    detect_color_capability, detect_is_tty, detect_terminal_width,
-    reset_detectors as reset_environment_detectors, set_color_capability_detector,
-    set_terminal_width_detector, set_tty_detector, DetectorGuard,
+    detect_color_capability, detect_is_tty, detect_terminal_height, detect_terminal_width,
+    enable_virtual_terminal, piped_color, reset_detectors as reset_environment_detectors,
+    set_color_capability_detector, set_piped_color, set_terminal_height_detector,
+    set_terminal_width_detector, set_tty_detector, DetectorGuard, PipedColor, COLOR_PAGERS,
 };
 
+// Terminal capability report
+pub use capabilities::{Capabilities, ColorDepth};
+
 // Render module exports
 pub use template::{
+    // Compiled-template cache
+    clear_template_cache,
     render,
     render_auto,
     render_auto_with_context,
     render_auto_with_engine,
     render_auto_with_spec,
+    render_styled,
     render_with_context,
     render_with_mode,
     render_with_output,
     render_with_vars,
+    set_template_cache_capacity,
     validate_template,
     // Template registry
     walk_template_dir,
@@ -204,9 +218,11 @@ pub use template::{
     RegistryError,
     Renderer,
     ResolvedTemplate,
+    StyledArg,
     TemplateEngine,
     TemplateFile,
     TemplateRegistry,
+    DEFAULT_TEMPLATE_CACHE_CAPACITY,
     TEMPLATE_EXTENSIONS,
 };
 
@@ -215,7 +231,8 @@ pub use standout_bbparser::{UnknownTagError, UnknownTagErrors, UnknownTagKind};
 
 // Utility exports
 pub use util::{
-    flatten_json_for_csv, rgb_to_ansi256, rgb_to_truecolor, serialize_to_xml, truncate_to_width,
+    colorize_json, flatten_json_for_csv, format_csv, rgb_to_ansi256, rgb_to_truecolor,
+    serialize_to_toml, serialize_to_xml, truncate_to_width,
 };
 
 // File loader exports
@@ -9,7 +9,7 @@
 //! |----------|-------|-----------|-------|
 //! | Templated | Auto, Term, Text | Yes | Varies |
 //! | Debug | TermDebug | Yes | Tags kept as `[name]...[/name]` |
-//! | Structured | Json, Yaml, Xml, Csv | No — serializes directly | No |
+//! | Structured | Json, Yaml, Xml, Toml, Csv | No — serializes directly | No |
 //!
 //! ## How Modes Are Selected
 //!
@@ -25,6 +25,10 @@
 //! - TTY with color support → behaves like `Term` (ANSI codes applied)
 //! - Piped output or no color support → behaves like `Text` (tags stripped)
 //!
+//! [`Capabilities::detect`](crate::Capabilities::detect) reports the same
+//! decision as its `color` field, along with the terminal's other
+//! capabilities.
+//!
 //! This detection happens at render time, not startup. Tests can override
 //! the result via
 //! [`set_color_capability_detector`](crate::set_color_capability_detector)
@@ -32,7 +36,7 @@
 //!
 //! ## Structured Modes
 //!
-//! JSON, YAML, XML, and CSV modes skip template rendering entirely.
+//! JSON, YAML, XML, TOML, and CSV modes skip template rendering entirely.
 //! Handler data is serialized directly, which means:
 //! - Template content is ignored
 //! - Style tags never apply
@@ -41,7 +45,7 @@
 //! Use [`render_auto`](crate::render_auto) to automatically dispatch between
 //! templated and structured rendering based on output mode.
 
-use crate::environment::detect_color_capability;
+use crate::environment::{detect_color_capability, enable_virtual_terminal};
 use std::io::Write;
 
 /// Destination for rendered output.
@@ -53,6 +57,8 @@ pub enum OutputDestination {
     Stdout,
     /// Write to a specific file
     File(std::path::PathBuf),
+    /// Write to standard output and, with ANSI codes stripped, to a file
+    Tee(std::path::PathBuf),
 }
 
 /// Validates that a file path is safe to write to.
@@ -70,11 +76,134 @@ fn validate_path(path: &std::path::Path) -> std::io::Result<()> {
     Ok(())
 }
 
+/// How [`write_output_with`] and [`write_binary_output_with`] write files.
+///
+/// The defaults (all `false`) overwrite the file in place and fail if its
+/// parent directory is missing, matching [`write_output`].
+///
+/// # Example
+///
+/// ```rust
+/// use standout_render::FileWriteOptions;
+///
+/// let options = FileWriteOptions::new().atomic(true).create_dirs(true);
+/// assert!(options.atomic && !options.append);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FileWriteOptions {
+    /// Append to the file instead of overwriting it (default `false`).
+    ///
+    /// Appended text always ends with a newline so consecutive writes stay
+    /// on separate lines.
+    pub append: bool,
+    /// Write to a temporary file next to the target and rename it into place,
+    /// so an interrupted write never leaves a partial file (default `false`).
+    /// Ignored when appending.
+    pub atomic: bool,
+    /// Create missing parent directories instead of failing (default `false`).
+    pub create_dirs: bool,
+}
+
+impl FileWriteOptions {
+    /// Creates options with the defaults (overwrite in place, no directory creation).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether to append instead of overwriting.
+    pub fn append(mut self, append: bool) -> Self {
+        self.append = append;
+        self
+    }
+
+    /// Sets whether to write through a temporary file and rename.
+    pub fn atomic(mut self, atomic: bool) -> Self {
+        self.atomic = atomic;
+        self
+    }
+
+    /// Sets whether missing parent directories are created.
+    pub fn create_dirs(mut self, create_dirs: bool) -> Self {
+        self.create_dirs = create_dirs;
+        self
+    }
+}
+
+/// Writes `content` to `path` according to `options`.
+fn write_file(
+    path: &std::path::Path,
+    content: &[u8],
+    options: &FileWriteOptions,
+) -> std::io::Result<()> {
+    if options.create_dirs {
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                std::fs::create_dir_all(parent)?;
+            }
+        }
+    } else {
+        validate_path(path)?;
+    }
+
+    if options.append {
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        file.write_all(content)
+    } else if options.atomic {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        // WASI has no process ids (`std::process::id` panics there)
+        let pid = if cfg!(target_os = "wasi") {
+            0
+        } else {
+            std::process::id()
+        };
+        let temp_path = path.with_file_name(format!(".{}.{}.tmp", file_name, pid));
+
+        let result = std::fs::File::create(&temp_path)
+            .and_then(|mut file| {
+                file.write_all(content)?;
+                file.sync_all()
+            })
+            .and_then(|_| std::fs::rename(&temp_path, path));
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+        result
+    } else {
+        std::fs::write(path, content)
+    }
+}
+
 /// Writes text content to the specified destination.
 ///
 /// - `Stdout`: Writes to stdout with a newline
 /// - `File`: Writes to the file (overwriting)
+/// - `Tee`: Writes to stdout as-is and the plain (ANSI-stripped) text to the file
 pub fn write_output(content: &str, dest: &OutputDestination) -> std::io::Result<()> {
+    write_output_with(content, dest, &FileWriteOptions::default())
+}
+
+/// Writes text content to the specified destination, using `options` for files.
+///
+/// See [`write_output`] for how each destination is handled.
+pub fn write_output_with(
+    content: &str,
+    dest: &OutputDestination,
+    options: &FileWriteOptions,
+) -> std::io::Result<()> {
+    let to_file = |path: &std::path::Path, text: &str| {
+        if options.append && !text.ends_with('\n') {
+            write_file(path, format!("{}\n", text).as_bytes(), options)
+        } else {
+            write_file(path, text.as_bytes(), options)
+        }
+    };
+
     match dest {
         OutputDestination::Stdout => {
             // Use println! logic (writeln to stdout)
@@ -82,9 +211,10 @@ pub fn write_output(content: &str, dest: &OutputDestination) -> std::io::Result<
             let mut handle = stdout.lock();
             writeln!(handle, "{}", content)
         }
-        OutputDestination::File(path) => {
-            validate_path(path)?;
-            std::fs::write(path, content)
+        OutputDestination::File(path) => to_file(path, content),
+        OutputDestination::Tee(path) => {
+            to_file(path, &console::strip_ansi_codes(content))?;
+            write_output_with(content, &OutputDestination::Stdout, options)
         }
     }
 }
@@ -93,16 +223,25 @@ pub fn write_output(content: &str, dest: &OutputDestination) -> std::io::Result<
 ///
 /// - `Stdout`: Writes raw bytes to stdout
 /// - `File`: Writes to the file (overwriting)
+/// - `Tee`: Writes to the file only; binary data isn't echoed to the terminal
 pub fn write_binary_output(content: &[u8], dest: &OutputDestination) -> std::io::Result<()> {
+    write_binary_output_with(content, dest, &FileWriteOptions::default())
+}
+
+/// Writes binary content to the specified destination, using `options` for files.
+pub fn write_binary_output_with(
+    content: &[u8],
+    dest: &OutputDestination,
+    options: &FileWriteOptions,
+) -> std::io::Result<()> {
     match dest {
         OutputDestination::Stdout => {
             let stdout = std::io::stdout();
             let mut handle = stdout.lock();
             handle.write_all(content)
         }
-        OutputDestination::File(path) => {
-            validate_path(path)?;
-            std::fs::write(path, content)
+        OutputDestination::File(path) | OutputDestination::Tee(path) => {
+            write_file(path, content, options)
         }
     }
 }
@@ -176,6 +315,8 @@ pub enum OutputMode {
     Yaml,
     /// Structured output: serialize data as XML (skips template rendering)
     Xml,
+    /// Structured output: serialize data as TOML (skips template rendering)
+    Toml,
     /// Structured output: serialize flattened data as CSV (skips template rendering)
     Csv,
 }
@@ -184,19 +325,21 @@ impl OutputMode {
     /// Resolves the output mode to a concrete decision about whether to use color.
     ///
     /// - `Auto` checks terminal capabilities
-    /// - `Term` always returns `true`
+    /// - `Term` returns `true`, except on legacy Windows consoles that can't
+    ///   show ANSI codes (see [`enable_virtual_terminal`])
     /// - `Text` always returns `false`
     /// - `TermDebug` returns `false` (handled specially by apply methods)
     /// - `Json` returns `false` (structured output, no ANSI codes)
     pub fn should_use_color(&self) -> bool {
         match self {
             OutputMode::Auto => detect_color_capability(),
-            OutputMode::Term => true,
+            OutputMode::Term => enable_virtual_terminal(),
             OutputMode::Text => false,
             OutputMode::TermDebug => false, // Handled specially
             OutputMode::Json => false,      // Structured output
             OutputMode::Yaml => false,      // Structured output
             OutputMode::Xml => false,       // Structured output
+            OutputMode::Toml => false,      // Structured output
             OutputMode::Csv => false,       // Structured output
         }
     }
@@ -212,11 +355,91 @@ impl OutputMode {
     pub fn is_structured(&self) -> bool {
         matches!(
             self,
-            OutputMode::Json | OutputMode::Yaml | OutputMode::Xml | OutputMode::Csv
+            OutputMode::Json
+                | OutputMode::Yaml
+                | OutputMode::Xml
+                | OutputMode::Toml
+                | OutputMode::Csv
         )
     }
 }
 
+/// How fields are quoted in CSV output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CsvQuoting {
+    /// Quote only fields containing the delimiter, quotes or line breaks
+    #[default]
+    Necessary,
+    /// Quote every field
+    Always,
+    /// Quote every field that isn't a number
+    NonNumeric,
+    /// Never quote fields (may produce ambiguous output)
+    Never,
+}
+
+/// Formatting options for [`OutputMode::Csv`].
+///
+/// Spreadsheets differ by region: many European locales expect `;` as the
+/// delimiter because `,` is the decimal separator. The defaults match RFC 4180
+/// (comma-separated, minimal quoting, header row included).
+///
+/// # Example
+///
+/// ```rust
+/// use standout_render::{CsvOptions, CsvQuoting};
+///
+/// let options = CsvOptions::new()
+///     .delimiter(b';')
+///     .quoting(CsvQuoting::Always)
+///     .headers(false);
+/// assert_eq!(options.delimiter, b';');
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// Field delimiter byte (default `b','`; use `b';'` or `b'\t'` for TSV).
+    pub delimiter: u8,
+    /// Quoting style (default [`CsvQuoting::Necessary`]).
+    pub quoting: CsvQuoting,
+    /// Whether to emit a header row (default `true`).
+    pub headers: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quoting: CsvQuoting::Necessary,
+            headers: true,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Creates options with the defaults (comma, minimal quoting, header row).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the field delimiter.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets the quoting style.
+    pub fn quoting(mut self, quoting: CsvQuoting) -> Self {
+        self.quoting = quoting;
+        self
+    }
+
+    /// Sets whether a header row is emitted.
+    pub fn headers(mut self, headers: bool) -> Self {
+        self.headers = headers;
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -260,6 +483,12 @@ mod tests {
         assert!(OutputMode::Json.is_structured());
     }
 
+    #[test]
+    fn test_output_mode_toml_is_structured() {
+        assert!(OutputMode::Toml.is_structured());
+        assert!(!OutputMode::Toml.should_use_color());
+    }
+
     #[test]
     fn test_output_mode_non_json_not_structured() {
         assert!(!OutputMode::Auto.is_structured());
@@ -298,6 +527,59 @@ mod tests {
         assert_eq!(content, "new");
     }
 
+    #[test]
+    fn test_write_output_tee_strips_ansi_in_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("output.txt");
+        let dest = OutputDestination::Tee(file_path.clone());
+
+        write_output("\x1b[1mhello\x1b[0m", &dest).unwrap();
+
+        let content = std::fs::read_to_string(file_path).unwrap();
+        assert_eq!(content, "hello");
+    }
+
+    #[test]
+    fn test_write_output_append() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("report.log");
+        let dest = OutputDestination::File(file_path.clone());
+        let options = FileWriteOptions::new().append(true);
+
+        write_output_with("first", &dest, &options).unwrap();
+        write_output_with("second\n", &dest, &options).unwrap();
+
+        let content = std::fs::read_to_string(file_path).unwrap();
+        assert_eq!(content, "first\nsecond\n");
+    }
+
+    #[test]
+    fn test_write_output_atomic_replaces_file() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("output.txt");
+        std::fs::write(&file_path, "initial").unwrap();
+
+        let dest = OutputDestination::File(file_path.clone());
+        write_output_with("new", &dest, &FileWriteOptions::new().atomic(true)).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "new");
+        // No temporary file is left behind
+        assert_eq!(std::fs::read_dir(temp_dir.path()).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_write_output_missing_parent() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("reports/2026/output.txt");
+        let dest = OutputDestination::File(file_path.clone());
+
+        assert!(write_output("hello", &dest).is_err());
+
+        let options = FileWriteOptions::new().create_dirs(true);
+        write_output_with("hello", &dest, &options).unwrap();
+        assert_eq!(std::fs::read_to_string(file_path).unwrap(), "hello");
+    }
+
     #[test]
     fn test_write_output_binary_file() {
         let temp_dir = tempfile::tempdir().unwrap();
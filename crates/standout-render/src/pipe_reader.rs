@@ -0,0 +1,186 @@
+//! Finding the program that reads stdout when stdout is a pipe.
+//!
+//! Used by the [`PipedColor`](crate::environment::PipedColor) policy to tell
+//! `myapp | less -R` apart from `myapp | grep`. The reader is found by
+//! matching the pipe against other processes' open files in `/proc`, so this
+//! only works on Linux; elsewhere no reader is ever found.
+
+use once_cell::sync::OnceCell;
+
+/// A process reading from the pipe stdout writes to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct PipeReader {
+    /// Program name, as in `/proc/<pid>/comm`.
+    pub name: String,
+    /// Command-line arguments, without the program.
+    pub args: Vec<String>,
+    /// The reader's `LESS` environment variable.
+    pub less_env: Option<String>,
+}
+
+impl PipeReader {
+    /// Whether the reader shows ANSI codes as colors.
+    ///
+    /// `less` only does with `-R` / `-r`, on its command line or in `LESS`;
+    /// other programs are assumed to, having been allowed by name.
+    pub(crate) fn renders_ansi(&self) -> bool {
+        match self.name.as_str() {
+            "less" => less_renders_ansi(&self.args, self.less_env.as_deref()),
+            _ => true,
+        }
+    }
+}
+
+/// Returns the program reading stdout, if stdout is a pipe and it's found.
+///
+/// Looked up once per process.
+pub(crate) fn stdout_reader() -> Option<&'static PipeReader> {
+    static READER: OnceCell<Option<PipeReader>> = OnceCell::new();
+    READER.get_or_init(find_stdout_reader).as_ref()
+}
+
+#[cfg(target_os = "linux")]
+fn find_stdout_reader() -> Option<PipeReader> {
+    linux::find_reader(1)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn find_stdout_reader() -> Option<PipeReader> {
+    None
+}
+
+fn less_renders_ansi(args: &[String], less_env: Option<&str>) -> bool {
+    let is_raw = |flag: &str| match flag.strip_prefix("--") {
+        Some(long) => long.eq_ignore_ascii_case("raw-control-chars"),
+        None => flag.contains(['R', 'r']),
+    };
+    // LESS holds options with or without dashes; arguments without one are
+    // file names
+    less_env.is_some_and(|env| env.split_whitespace().any(is_raw))
+        || args
+            .iter()
+            .filter(|arg| arg.starts_with('-') && !arg.starts_with("-+"))
+            .any(|arg| is_raw(arg))
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use super::PipeReader;
+    use std::fs;
+    use std::path::Path;
+
+    /// Finds another process with the read end of the pipe `fd` writes to.
+    pub(super) fn find_reader(fd: i32) -> Option<PipeReader> {
+        let pipe = fs::read_link(format!("/proc/self/fd/{}", fd)).ok()?;
+        if !pipe.to_string_lossy().starts_with("pipe:") {
+            return None;
+        }
+        let own_pid = std::process::id().to_string();
+
+        fs::read_dir("/proc").ok()?.flatten().find_map(|entry| {
+            let pid = entry.file_name().into_string().ok()?;
+            if pid == own_pid || !pid.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            let process = entry.path();
+            let reads_pipe = fs::read_dir(process.join("fd")).ok()?.flatten().any(|fd| {
+                fs::read_link(fd.path()).is_ok_and(|target| target == pipe)
+                    && is_read_only(&process, &fd.file_name().to_string_lossy())
+            });
+            reads_pipe.then(|| describe(&process))
+        })
+    }
+
+    /// Whether the process opened the descriptor for reading only.
+    fn is_read_only(process: &Path, fd: &str) -> bool {
+        let Ok(info) = fs::read_to_string(process.join("fdinfo").join(fd)) else {
+            return false;
+        };
+        info.lines()
+            .find_map(|line| line.strip_prefix("flags:"))
+            .and_then(|flags| u32::from_str_radix(flags.trim(), 8).ok())
+            .is_some_and(|flags| flags & 0o3 == 0)
+    }
+
+    fn describe(process: &Path) -> PipeReader {
+        let name = fs::read_to_string(process.join("comm"))
+            .map(|comm| comm.trim_end().to_string())
+            .unwrap_or_default();
+        let args = fs::read(process.join("cmdline"))
+            .map(|cmdline| split_nul(&cmdline).skip(1).collect())
+            .unwrap_or_default();
+        let less_env = fs::read(process.join("environ")).ok().and_then(|environ| {
+            split_nul(&environ).find_map(|var| var.strip_prefix("LESS=").map(str::to_string))
+        });
+        PipeReader {
+            name,
+            args,
+            less_env,
+        }
+    }
+
+    fn split_nul(bytes: &[u8]) -> impl Iterator<Item = String> + '_ {
+        bytes
+            .split(|&b| b == 0)
+            .filter(|part| !part.is_empty())
+            .map(|part| String::from_utf8_lossy(part).into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn less(args: &[&str], env: Option<&str>) -> PipeReader {
+        PipeReader {
+            name: "less".to_string(),
+            args: args.iter().map(|a| a.to_string()).collect(),
+            less_env: env.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn less_needs_raw_control_chars() {
+        assert!(less(&["-R"], None).renders_ansi());
+        assert!(less(&["-FRX"], None).renders_ansi());
+        assert!(less(&["--RAW-CONTROL-CHARS"], None).renders_ansi());
+        assert!(less(&[], Some("FRX")).renders_ansi());
+        assert!(less(&[], Some("-i -R")).renders_ansi());
+
+        assert!(!less(&[], None).renders_ansi());
+        assert!(!less(&["-FX", "README"], Some("-i")).renders_ansi());
+        assert!(!less(&["-+R"], None).renders_ansi());
+    }
+
+    #[test]
+    fn other_readers_are_trusted() {
+        let bat = PipeReader {
+            name: "bat".to_string(),
+            args: vec![],
+            less_env: None,
+        };
+        assert!(bat.renders_ansi());
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn finds_the_process_reading_a_pipe() {
+        use std::os::fd::AsRawFd;
+        use std::process::{Command, Stdio};
+
+        let mut child = Command::new("sleep")
+            .arg("5")
+            .stdin(Stdio::piped())
+            .spawn()
+            .unwrap();
+        let stdin = child.stdin.take().unwrap();
+
+        let reader = linux::find_reader(stdin.as_raw_fd());
+
+        child.kill().unwrap();
+        child.wait().unwrap();
+        let reader = reader.expect("sleep reads the pipe");
+        assert_eq!(reader.name, "sleep");
+        assert_eq!(reader.args, ["5"]);
+    }
+}
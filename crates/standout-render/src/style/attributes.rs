@@ -79,8 +79,8 @@ impl StyleAttributes {
                     path: None,
                 })?;
 
-            // Skip mode sections (handled by parent parser)
-            if key_str == "light" || key_str == "dark" {
+            // Skip mode and condition sections (handled by parent parser)
+            if matches!(key_str, "light" | "dark" | "when" | "else") {
                 continue;
             }
 
@@ -0,0 +1,187 @@
+//! Environment conditions on stylesheet styles.
+//!
+//! A YAML style definition may carry a `when` mapping. The style applies only
+//! where every condition holds; elsewhere it resolves to its `else`
+//! attributes, or to no styling at all. Conditions are checked against
+//! [`Capabilities::detect`] each time a theme's styles are resolved, so the
+//! same stylesheet adapts to the terminal it runs in.
+//!
+//! ```yaml
+//! # Underlined only where links are clickable
+//! link:
+//!   fg: blue
+//!   underline: true
+//!   when:
+//!     hyperlinks: true
+//!
+//! # Keep the background off narrow terminals and CI logs
+//! banner:
+//!   fg: white
+//!   bg: blue
+//!   bold: true
+//!   when:
+//!     min_width: 80
+//!     ci: false
+//!   else: "white bold"
+//! ```
+//!
+//! | Key | Holds when |
+//! |-----|------------|
+//! | `tty: bool` | stdout is (or isn't) a terminal |
+//! | `ci: bool` | the process runs (or doesn't run) in CI |
+//! | `hyperlinks: bool` | the terminal does (or doesn't) make links clickable |
+//! | `unicode: bool` | the terminal can (or can't) show Unicode |
+//! | `min_width: N` | the terminal is at least `N` columns wide |
+//! | `max_width: N` | the terminal is at most `N` columns wide |
+//!
+//! Width conditions don't hold when the width is unknown, as when output is
+//! piped.
+
+use console::Style;
+
+use super::error::StylesheetError;
+use crate::Capabilities;
+
+/// Conditions on the environment, all of which must hold.
+///
+/// Unset fields aren't checked, so the default condition always holds.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct StyleCondition {
+    /// Whether stdout must be a terminal.
+    pub tty: Option<bool>,
+    /// Whether the process must run in CI.
+    pub ci: Option<bool>,
+    /// Whether the terminal must make hyperlinks clickable.
+    pub hyperlinks: Option<bool>,
+    /// Whether the terminal must show Unicode.
+    pub unicode: Option<bool>,
+    /// Least terminal width, in columns.
+    pub min_width: Option<usize>,
+    /// Greatest terminal width, in columns.
+    pub max_width: Option<usize>,
+}
+
+impl StyleCondition {
+    /// Parses a `when` mapping.
+    pub fn parse(value: &serde_yaml::Value, style_name: &str) -> Result<Self, StylesheetError> {
+        let invalid = |message: String| StylesheetError::InvalidDefinition {
+            style: style_name.to_string(),
+            message,
+            path: None,
+        };
+        let map = value
+            .as_mapping()
+            .ok_or_else(|| invalid("'when' must be a mapping".to_string()))?;
+
+        let mut condition = StyleCondition::default();
+        for (key, value) in map {
+            let key = key
+                .as_str()
+                .ok_or_else(|| invalid(format!("Non-string key in 'when': {:?}", key)))?;
+            let flag = || {
+                value
+                    .as_bool()
+                    .ok_or_else(|| invalid(format!("'when.{}' must be true or false", key)))
+            };
+            let columns = || {
+                value
+                    .as_u64()
+                    .map(|n| n as usize)
+                    .ok_or_else(|| invalid(format!("'when.{}' must be a column count", key)))
+            };
+            match key {
+                "tty" => condition.tty = Some(flag()?),
+                "ci" => condition.ci = Some(flag()?),
+                "hyperlinks" => condition.hyperlinks = Some(flag()?),
+                "unicode" => condition.unicode = Some(flag()?),
+                "min_width" => condition.min_width = Some(columns()?),
+                "max_width" => condition.max_width = Some(columns()?),
+                _ => return Err(invalid(format!("Unknown condition 'when.{}'", key))),
+            }
+        }
+        Ok(condition)
+    }
+
+    /// Returns true if every set condition holds for `caps`.
+    pub fn holds(&self, caps: &Capabilities) -> bool {
+        let flag = |expected: Option<bool>, actual: bool| expected.is_none_or(|e| e == actual);
+        flag(self.tty, caps.tty)
+            && flag(self.ci, caps.ci)
+            && flag(self.hyperlinks, caps.hyperlinks)
+            && flag(self.unicode, caps.unicode)
+            && self
+                .min_width
+                .is_none_or(|min| caps.width.is_some_and(|w| w >= min))
+            && self
+                .max_width
+                .is_none_or(|max| caps.width.is_some_and(|w| w <= max))
+    }
+}
+
+/// The condition on a style, with the style used when it doesn't hold.
+#[derive(Debug, Clone)]
+pub struct ConditionalStyle {
+    /// When the style's own definition applies.
+    pub when: StyleCondition,
+    /// The style used otherwise (plain when the definition has no `else`).
+    pub otherwise: Style,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ColorDepth;
+
+    fn caps(width: Option<usize>, ci: bool) -> Capabilities {
+        Capabilities {
+            tty: true,
+            color: ColorDepth::Ansi256,
+            unicode: true,
+            hyperlinks: false,
+            width,
+            height: None,
+            ci,
+        }
+    }
+
+    fn parse(yaml: &str) -> Result<StyleCondition, StylesheetError> {
+        StyleCondition::parse(&serde_yaml::from_str(yaml).unwrap(), "test")
+    }
+
+    #[test]
+    fn test_parse_condition() {
+        let condition = parse("{ tty: true, ci: false, min_width: 80, max_width: 200 }").unwrap();
+        assert_eq!(
+            condition,
+            StyleCondition {
+                tty: Some(true),
+                ci: Some(false),
+                min_width: Some(80),
+                max_width: Some(200),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_condition_errors() {
+        assert!(parse("[tty]").is_err());
+        assert!(parse("{ tty: yes please }").is_err());
+        assert!(parse("{ min_width: -1 }").is_err());
+        assert!(parse("{ colour: true }").is_err());
+    }
+
+    #[test]
+    fn test_holds() {
+        let wide_local = parse("{ min_width: 80, ci: false }").unwrap();
+        assert!(wide_local.holds(&caps(Some(120), false)));
+        assert!(!wide_local.holds(&caps(Some(60), false)));
+        assert!(!wide_local.holds(&caps(Some(120), true)));
+        assert!(!wide_local.holds(&caps(None, false)));
+
+        assert!(parse("{ hyperlinks: false, max_width: 80 }")
+            .unwrap()
+            .holds(&caps(Some(80), false)));
+        assert!(StyleCondition::default().holds(&caps(None, true)));
+    }
+}
@@ -5,6 +5,7 @@
 //!
 //! - Alias: Reference to another style by name
 //! - Attributes: Direct style with optional light/dark overrides
+//! - Conditional: Attributes that apply only in some environments
 //!
 //! # YAML Formats
 //!
@@ -27,9 +28,17 @@
 //!     fg: black
 //!   dark:
 //!     fg: white
+//!
+//! # Conditional definition - applies only where `when` holds
+//! banner:
+//!   bg: blue
+//!   when:
+//!     min_width: 80
+//!   else: bold
 //! ```
 
 use super::attributes::{parse_shorthand, StyleAttributes};
+use super::condition::StyleCondition;
 use super::error::StylesheetError;
 
 /// Parsed style definition from YAML.
@@ -55,6 +64,22 @@ pub enum StyleDefinition {
         /// Dark mode overrides (merged onto base).
         dark: Option<StyleAttributes>,
     },
+
+    /// Concrete style definition that applies only where `when` holds.
+    ///
+    /// See [`StyleCondition`] for the conditions.
+    Conditional {
+        /// Base style attributes.
+        base: StyleAttributes,
+        /// Light mode overrides (merged onto base).
+        light: Option<StyleAttributes>,
+        /// Dark mode overrides (merged onto base).
+        dark: Option<StyleAttributes>,
+        /// Conditions under which the definition applies.
+        when: StyleCondition,
+        /// Attributes used where `when` doesn't hold (plain if empty).
+        otherwise: StyleAttributes,
+    },
 }
 
 impl StyleDefinition {
@@ -160,7 +185,38 @@ impl StyleDefinition {
             None
         };
 
-        Ok(StyleDefinition::Attributes { base, light, dark })
+        let key = |name: &str| serde_yaml::Value::String(name.into());
+        let Some(when) = map.get(key("when")) else {
+            if map.contains_key(key("else")) {
+                return Err(StylesheetError::InvalidDefinition {
+                    style: style_name.to_string(),
+                    message: "'else' needs a 'when'".to_string(),
+                    path: None,
+                });
+            }
+            return Ok(StyleDefinition::Attributes { base, light, dark });
+        };
+
+        let when = StyleCondition::parse(when, style_name)?;
+        let otherwise = match map.get(key("else")) {
+            None => StyleAttributes::new(),
+            Some(serde_yaml::Value::String(s)) => parse_shorthand(s, style_name)?,
+            Some(serde_yaml::Value::Mapping(m)) => StyleAttributes::parse_mapping(m, style_name)?,
+            Some(_) => {
+                return Err(StylesheetError::InvalidDefinition {
+                    style: style_name.to_string(),
+                    message: "'else' must be a string or mapping".to_string(),
+                    path: None,
+                })
+            }
+        };
+        Ok(StyleDefinition::Conditional {
+            base,
+            light,
+            dark,
+            when,
+            otherwise,
+        })
     }
 
     /// Returns true if this is an alias definition.
@@ -450,6 +506,32 @@ mod tests {
         assert!(def.alias_target().is_none());
     }
 
+    #[test]
+    fn test_parse_conditional() {
+        let yaml = "{ bg: blue, when: { min_width: 80 }, else: bold }";
+        let value: serde_yaml::Value = serde_yaml::from_str(yaml).unwrap();
+        match StyleDefinition::parse(&value, "banner").unwrap() {
+            StyleDefinition::Conditional {
+                base,
+                when,
+                otherwise,
+                ..
+            } => {
+                assert_eq!(base.bg, Some(ColorDef::Named(Color::Blue)));
+                assert_eq!(when.min_width, Some(80));
+                assert_eq!(otherwise.bold, Some(true));
+                assert!(otherwise.bg.is_none());
+            }
+            other => panic!("Expected Conditional, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parse_else_without_when() {
+        let value: serde_yaml::Value = serde_yaml::from_str("{ bg: blue, else: bold }").unwrap();
+        assert!(StyleDefinition::parse(&value, "banner").is_err());
+    }
+
     // =========================================================================
     // is_likely_alias tests
     // =========================================================================
@@ -43,8 +43,18 @@
 //!
 //! # Aliases
 //! disabled: muted
+//!
+//! # Conditional style: plain unless the terminal is 80+ columns and not CI
+//! banner:
+//!   fg: white
+//!   bg: blue
+//!   when:
+//!     min_width: 80
+//!     ci: false
 //! ```
 //!
+//! See [`StyleCondition`] for the `when` keys.
+//!
 //! ## Color Formats
 //!
 //! ```yaml
@@ -85,6 +95,7 @@ mod value;
 // Stylesheet parsing (YAML and CSS)
 mod attributes;
 mod color;
+mod condition;
 mod css_parser;
 mod definition;
 mod file_registry;
@@ -98,6 +109,7 @@ pub use value::StyleValue;
 // Stylesheet parsing exports
 pub use attributes::StyleAttributes;
 pub use color::ColorDef;
+pub use condition::{ConditionalStyle, StyleCondition};
 pub use css_parser::parse_css;
 pub use definition::StyleDefinition;
 pub(crate) use file_registry::parse_theme_content;
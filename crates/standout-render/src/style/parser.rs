@@ -23,14 +23,21 @@
 //!
 //! This means styles with no `light:` or `dark:` sections work in all modes,
 //! while adaptive styles provide mode-specific overrides.
+//!
+//! Styles with a `when:` section are built the same way, and their condition
+//! is recorded with the style to use where it doesn't hold. Conditions are
+//! checked when the styles are resolved.
 
 use std::collections::HashMap;
 
 use console::Style;
 
 use crate::colorspace::ThemePalette;
+use crate::Capabilities;
 
 use super::super::theme::ColorMode;
+use super::attributes::StyleAttributes;
+use super::condition::ConditionalStyle;
 use super::definition::StyleDefinition;
 use super::error::StylesheetError;
 use super::value::StyleValue;
@@ -68,6 +75,9 @@ pub struct ThemeVariants {
 
     /// Alias definitions: style name → target style name.
     aliases: HashMap<String, String>,
+
+    /// Conditions on styles, by style name.
+    conditions: HashMap<String, ConditionalStyle>,
 }
 
 impl ThemeVariants {
@@ -78,6 +88,7 @@ impl ThemeVariants {
             light: HashMap::new(),
             dark: HashMap::new(),
             aliases: HashMap::new(),
+            conditions: HashMap::new(),
         }
     }
 
@@ -88,7 +99,9 @@ impl ThemeVariants {
     /// - Concrete styles are `StyleValue::Concrete` with the mode-appropriate style
     ///
     /// For light/dark modes, mode-specific styles take precedence over base.
-    /// For unknown mode (None), only base styles are used.
+    /// For unknown mode (None), only base styles are used. Conditional styles
+    /// whose condition doesn't hold in the current environment resolve to
+    /// their fallback.
     pub fn resolve(&self, mode: Option<ColorMode>) -> HashMap<String, StyleValue> {
         let mut result = HashMap::new();
 
@@ -110,6 +123,18 @@ impl ThemeVariants {
             result.insert(name.clone(), StyleValue::Concrete(style.clone()));
         }
 
+        if !self.conditions.is_empty() {
+            let caps = Capabilities::detect();
+            for (name, conditional) in &self.conditions {
+                if !conditional.when.holds(&caps) {
+                    result.insert(
+                        name.clone(),
+                        StyleValue::Concrete(conditional.otherwise.clone()),
+                    );
+                }
+            }
+        }
+
         result
     }
 
@@ -133,6 +158,11 @@ impl ThemeVariants {
         &self.aliases
     }
 
+    /// Returns the conditions on styles, by style name.
+    pub fn conditions(&self) -> &HashMap<String, ConditionalStyle> {
+        &self.conditions
+    }
+
     /// Returns true if no styles are defined.
     pub fn is_empty(&self) -> bool {
         self.base.is_empty() && self.aliases.is_empty()
@@ -243,23 +273,23 @@ pub(crate) fn build_variants(
                 variants.aliases.insert(name.clone(), target.clone());
             }
             StyleDefinition::Attributes { base, light, dark } => {
-                // Build base style
-                let base_style = base.to_style(palette);
-                variants.base.insert(name.clone(), base_style);
-
-                // Build light variant if overrides exist
-                if let Some(light_attrs) = light {
-                    let merged = base.merge(light_attrs);
-                    variants
-                        .light
-                        .insert(name.clone(), merged.to_style(palette));
-                }
-
-                // Build dark variant if overrides exist
-                if let Some(dark_attrs) = dark {
-                    let merged = base.merge(dark_attrs);
-                    variants.dark.insert(name.clone(), merged.to_style(palette));
-                }
+                add_attributes(&mut variants, name, base, light, dark, palette);
+            }
+            StyleDefinition::Conditional {
+                base,
+                light,
+                dark,
+                when,
+                otherwise,
+            } => {
+                add_attributes(&mut variants, name, base, light, dark, palette);
+                variants.conditions.insert(
+                    name.clone(),
+                    ConditionalStyle {
+                        when: when.clone(),
+                        otherwise: otherwise.to_style(palette),
+                    },
+                );
             }
         }
     }
@@ -267,6 +297,37 @@ pub(crate) fn build_variants(
     Ok(variants)
 }
 
+/// Adds the base style and any light/dark variants of one definition.
+fn add_attributes(
+    variants: &mut ThemeVariants,
+    name: &str,
+    base: &StyleAttributes,
+    light: &Option<StyleAttributes>,
+    dark: &Option<StyleAttributes>,
+    palette: Option<&ThemePalette>,
+) {
+    // Build base style
+    variants
+        .base
+        .insert(name.to_string(), base.to_style(palette));
+
+    // Build light variant if overrides exist
+    if let Some(light_attrs) = light {
+        let merged = base.merge(light_attrs);
+        variants
+            .light
+            .insert(name.to_string(), merged.to_style(palette));
+    }
+
+    // Build dark variant if overrides exist
+    if let Some(dark_attrs) = dark {
+        let merged = base.merge(dark_attrs);
+        variants
+            .dark
+            .insert(name.to_string(), merged.to_style(palette));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
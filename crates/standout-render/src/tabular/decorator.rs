@@ -179,6 +179,12 @@ impl BorderChars {
 pub struct Table {
     /// The underlying formatter.
     formatter: TabularFormatter,
+    /// The spec the formatter was built from (kept for re-resolving widths).
+    spec: FlatDataSpec,
+    /// Total available width including decorations.
+    total_width: usize,
+    /// Named view for `TabularRow` extraction (see [`Table::from_type_for`]).
+    view: Option<String>,
     /// Column headers.
     headers: Option<Vec<String>>,
     /// Border style.
@@ -198,6 +204,9 @@ impl Clone for Table {
     fn clone(&self) -> Self {
         Self {
             formatter: self.formatter.clone(),
+            spec: self.spec.clone(),
+            total_width: self.total_width,
+            view: self.view.clone(),
             headers: self.headers.clone(),
             border: self.border,
             header_style: self.header_style.clone(),
@@ -212,6 +221,8 @@ impl std::fmt::Debug for Table {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Table")
             .field("formatter", &self.formatter)
+            .field("total_width", &self.total_width)
+            .field("view", &self.view)
             .field("headers", &self.headers)
             .field("border", &self.border)
             .field("header_style", &self.header_style)
@@ -228,6 +239,9 @@ impl Table {
         let formatter = TabularFormatter::new(&spec, total_width);
         Table {
             formatter,
+            spec,
+            total_width,
+            view: None,
             headers: None,
             border: BorderStyle::None,
             header_style: None,
@@ -242,6 +256,9 @@ impl Table {
         let formatter = TabularFormatter::new(spec, total_width);
         Table {
             formatter,
+            spec: spec.clone(),
+            total_width,
+            view: None,
             headers: None,
             border: BorderStyle::None,
             header_style: None,
@@ -280,6 +297,25 @@ impl Table {
         Self::new(spec, total_width)
     }
 
+    /// Create a table for a named view of a type that implements `Tabular`.
+    ///
+    /// Uses [`Tabular::tabular_spec_for`], so columns marked
+    /// `#[col(only = "wide")]` are included when `view` is `"wide"`. Rows
+    /// added with [`row_from_trait`](Table::row_from_trait) use the same view.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let view = if wide { "wide" } else { "default" };
+    /// let table = Table::from_type_for::<Task>(view, 120).header_from_columns();
+    /// ```
+    pub fn from_type_for<T: Tabular>(view: &str, total_width: usize) -> Self {
+        let spec = T::tabular_spec_for(view);
+        let mut table = Self::new(spec, total_width);
+        table.view = Some(view.to_string());
+        table
+    }
+
     /// Set the border style.
     pub fn border(mut self, border: BorderStyle) -> Self {
         self.border = border;
@@ -361,6 +397,33 @@ impl Table {
         self.formatter.num_columns()
     }
 
+    /// Get the spec this table was built from.
+    pub fn spec(&self) -> &FlatDataSpec {
+        &self.spec
+    }
+
+    /// Get the total available width including decorations.
+    pub fn total_width(&self) -> usize {
+        self.total_width
+    }
+
+    /// Re-resolve column widths from sample data.
+    ///
+    /// Bounded columns are sized to fit the sample (within their bounds),
+    /// exactly as [`FlatDataSpec::resolve_widths_from_data`] does. Used by
+    /// [`TableStream`](super::TableStream) to lock widths after its sample window.
+    pub fn resolve_from_data<S: AsRef<str>>(mut self, data: &[Vec<S>]) -> Self {
+        let resolved = self.spec.resolve_widths_from_data(self.total_width, data);
+        self.formatter =
+            TabularFormatter::from_resolved_with_width(&self.spec, resolved, self.total_width);
+        self
+    }
+
+    /// Reset the data row counter used for alternating row styles.
+    pub(crate) fn reset_row_counter(&self) {
+        self.row_counter.store(0, Ordering::Relaxed);
+    }
+
     /// Format a data row.
     pub fn row<S: AsRef<str>>(&self, values: &[S]) -> String {
         let content = self.formatter.format_row(values);
@@ -426,7 +489,8 @@ impl Table {
     /// println!("{}", table.row_from_trait(&task));
     /// ```
     pub fn row_from_trait<T: TabularRow>(&self, value: &T) -> String {
-        let content = self.formatter.row_from_trait(value);
+        let values = value.to_row_for(self.view.as_deref());
+        let content = self.formatter.format_row(&values);
         self.wrap_data_row(&content)
     }
 
@@ -465,6 +529,19 @@ impl Table {
         self.horizontal_line(LineType::Bottom)
     }
 
+    /// The separator line drawn between data rows, if enabled and visible.
+    pub(crate) fn row_separator_line(&self) -> Option<String> {
+        if !self.row_separator {
+            return None;
+        }
+        let sep = self.separator_row();
+        if sep.is_empty() {
+            None
+        } else {
+            Some(sep)
+        }
+    }
+
     /// Wrap a data row with alternating style (if set) and borders.
     fn wrap_data_row(&self, content: &str) -> String {
         let bordered = self.wrap_row(content);
@@ -558,50 +635,122 @@ impl Table {
     ///
     /// Includes top border, header (if set), separator, data rows, and bottom border.
     pub fn render<S: AsRef<str>>(&self, rows: &[Vec<S>]) -> String {
-        self.row_counter.store(0, Ordering::Relaxed);
-        let mut output = Vec::new();
+        let lines = self.row_lines(rows);
+        self.assemble(lines, None)
+    }
 
-        // Top border
+    /// Render the table as pages of at most `page_height` lines.
+    ///
+    /// Each page is a complete table: the top border, header and separator are
+    /// repeated at the start of every page and the bottom border closes each
+    /// one, so the column titles stay visible while scrolling through a pager.
+    /// Rows are never split across pages; a single row taller than a page gets
+    /// a page of its own.
+    ///
+    /// Without a header there is nothing to repeat, and this is the same as
+    /// [`render`](Self::render).
+    pub fn render_paged<S: AsRef<str>>(&self, rows: &[Vec<S>], page_height: usize) -> String {
+        let lines = self.row_lines(rows);
+        self.assemble(lines, Some(page_height))
+    }
+
+    /// Render the table, paging it only when it is taller than `max_height`.
+    ///
+    /// Returns what [`render`](Self::render) would when the table fits in
+    /// `max_height` lines and what [`render_paged`](Self::render_paged) would
+    /// with `page_height` otherwise, together with whether it was paged. Rows
+    /// are formatted once either way.
+    pub fn render_to_fit<S: AsRef<str>>(
+        &self,
+        rows: &[Vec<S>],
+        max_height: usize,
+        page_height: usize,
+    ) -> (String, bool) {
+        let lines = self.row_lines(rows);
+        let (head, has_header) = self.head_lines();
+        let separators = match self.row_separator_line() {
+            Some(_) => lines.len().saturating_sub(1),
+            None => 0,
+        };
+        let height = head.len()
+            + lines
+                .iter()
+                .map(|l| l.lines().count().max(1))
+                .sum::<usize>()
+            + separators
+            + usize::from(!self.bottom_border().is_empty());
+
+        if height <= max_height || !has_header {
+            (self.assemble(lines, None), false)
+        } else {
+            (self.assemble(lines, Some(page_height)), true)
+        }
+    }
+
+    /// Format every data row, restarting the alternating row styles.
+    fn row_lines<S: AsRef<str>>(&self, rows: &[Vec<S>]) -> Vec<String> {
+        self.reset_row_counter();
+        rows.iter().map(|row| self.row(row)).collect()
+    }
+
+    /// The top border, header and header separator, and whether there is a
+    /// header.
+    fn head_lines(&self) -> (Vec<String>, bool) {
+        let mut head = Vec::new();
         let top = self.top_border();
         if !top.is_empty() {
-            output.push(top);
+            head.push(top);
         }
-
-        // Header
         let header = self.header_row();
-        if !header.is_empty() {
-            output.push(header);
-
-            // Separator after header
+        let has_header = !header.is_empty();
+        if has_header {
+            head.push(header);
             let sep = self.separator_row();
             if !sep.is_empty() {
-                output.push(sep);
+                head.push(sep);
             }
         }
+        (head, has_header)
+    }
 
-        // Data rows (with optional separators between them)
-        let separator = if self.row_separator {
-            let sep = self.separator_row();
-            if sep.is_empty() {
-                None
-            } else {
-                Some(sep)
-            }
-        } else {
-            None
+    /// Surround formatted rows with borders, header and row separators,
+    /// splitting them into pages of `page_height` lines when given and the
+    /// table has a header to repeat.
+    fn assemble(&self, rows: Vec<String>, page_height: Option<usize>) -> String {
+        let (head, has_header) = self.head_lines();
+        let bottom = self.bottom_border();
+        let footer_height = usize::from(!bottom.is_empty());
+        // Without paging (or a header to repeat) everything is one page
+        let room = match page_height {
+            Some(height) if has_header => height.saturating_sub(head.len() + footer_height).max(1),
+            _ => usize::MAX,
         };
 
-        for (i, row) in rows.iter().enumerate() {
-            if i > 0 {
-                if let Some(ref sep) = separator {
-                    output.push(sep.clone());
+        let separator = self.row_separator_line();
+        let mut output = head.clone();
+        let mut used = 0;
+
+        for (i, line) in rows.into_iter().enumerate() {
+            let height = line.lines().count().max(1);
+            let row_sep = if i > 0 { separator.as_ref() } else { None };
+            let sep_height = usize::from(row_sep.is_some());
+
+            if used > 0 && used + sep_height + height > room {
+                // Close this page and start the next with a fresh header
+                if !bottom.is_empty() {
+                    output.push(bottom.clone());
                 }
+                output.extend(head.iter().cloned());
+                used = 0;
+            } else if let Some(sep) = row_sep {
+                output.push(sep.clone());
+                used += 1;
             }
-            output.push(self.row(row));
+
+            output.push(line);
+            used += height;
         }
 
-        // Bottom border
-        let bottom = self.bottom_border();
         if !bottom.is_empty() {
             output.push(bottom);
         }
@@ -920,6 +1069,74 @@ mod tests {
         assert!(lines[5].starts_with('└'));
     }
 
+    #[test]
+    fn table_render_paged_repeats_header() {
+        let table = Table::new(simple_spec(), 80).header(vec!["Name", "Value"]);
+        let data: Vec<Vec<String>> = (0..5)
+            .map(|i| vec![format!("r{}", i), i.to_string()])
+            .collect();
+
+        // Borderless: the header line leaves room for 2 rows per 3-line page
+        let output = table.render_paged(&data, 3);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 8);
+        assert_eq!(lines.iter().filter(|l| l.starts_with("Name")).count(), 3);
+        assert!(lines[3].starts_with("Name"));
+        assert!(lines[4].starts_with("r2"));
+        assert!(lines[7].starts_with("r4"));
+    }
+
+    #[test]
+    fn table_render_paged_closes_each_page() {
+        let table = Table::new(simple_spec(), 80)
+            .border(BorderStyle::Light)
+            .header(vec!["Name", "Value"]);
+        let data = vec![vec!["a", "1"], vec!["b", "2"], vec!["c", "3"]];
+
+        // top + header + separator + bottom leaves room for 2 rows per 6-line page
+        let output = table.render_paged(&data, 6);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.iter().filter(|l| l.starts_with('┌')).count(), 2);
+        assert_eq!(lines.iter().filter(|l| l.starts_with('└')).count(), 2);
+        assert!(lines[5].starts_with('└'));
+        assert!(lines[6].starts_with('┌'));
+    }
+
+    #[test]
+    fn table_render_paged_without_header_matches_render() {
+        let table = Table::new(simple_spec(), 80);
+        let data = vec![vec!["a", "1"], vec!["b", "2"], vec!["c", "3"]];
+
+        assert_eq!(table.render_paged(&data, 2), table.render(&data));
+    }
+
+    #[test]
+    fn table_render_paged_fits_single_page() {
+        let table = Table::new(simple_spec(), 80).header(vec!["Name", "Value"]);
+        let data = vec![vec!["a", "1"], vec!["b", "2"]];
+
+        assert_eq!(table.render_paged(&data, 50), table.render(&data));
+    }
+
+    #[test]
+    fn table_render_to_fit_pages_only_when_too_tall() {
+        let table = Table::new(simple_spec(), 80)
+            .header(vec!["Name", "Value"])
+            .border(BorderStyle::Light);
+        let data: Vec<Vec<String>> = (0..4)
+            .map(|i| vec![format!("r{}", i), i.to_string()])
+            .collect();
+        let full = table.render(&data);
+        let height = full.lines().count();
+
+        assert_eq!(table.render_to_fit(&data, height, 5), (full, false));
+        let (paged, was_paged) = table.render_to_fit(&data, height - 1, 5);
+        assert!(was_paged);
+        assert_eq!(paged, table.render_paged(&data, 5));
+    }
+
     #[test]
     fn table_render_no_border() {
         let table = Table::new(simple_spec(), 80).header(vec!["Name", "Value"]);
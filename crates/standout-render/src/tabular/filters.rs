@@ -50,18 +50,26 @@ use super::traits::Tabular;
 use super::types::{
     Align, Column, Overflow, SubColumn, SubColumns, TabularSpec, TruncateAt, Width,
 };
-use super::util::{
-    display_width, pad_center, pad_left, pad_right, truncate_end, truncate_middle, truncate_start,
-};
+use super::util::{display_width, truncate_end, truncate_middle, truncate_start};
 
 /// Register all tabular-related filters on a MiniJinja environment.
 ///
 /// # Filters Added
 ///
-/// - `col(width, ...)` - Format value to fit column width
+/// - `col(width, align=?, overflow=?, truncate=?, ellipsis=?)` - Format value to
+///   fit column width
 /// - `pad_left(width)` - Right-align with padding
 /// - `pad_right(width)` - Left-align with padding
 /// - `truncate_at(width, pos, ellipsis)` - Truncate at position
+/// - `center(width?)` - Center each line in the terminal width
+/// - `right_align(width?)` - Right-align each line in the terminal width
+/// - `hr(...)` - Horizontal rule with the value as its centered label
+///
+/// # Functions Added
+///
+/// - `hr(label?, width=?, char=?, style=?, label_style=?)` - Horizontal rule
+///   spanning the terminal width
+/// - `tabular(columns, ...)` / `table(columns, ...)` - Table formatters
 ///
 /// # Example
 ///
@@ -108,16 +116,46 @@ pub fn register_tabular_filters(env: &mut Environment<'static>) {
             };
 
             let align = kwargs.get::<Option<String>>("align")?.unwrap_or_default();
-            let truncate = kwargs
-                .get::<Option<String>>("truncate")?
-                .unwrap_or_default();
-            let ellipsis = kwargs
-                .get::<Option<String>>("ellipsis")?
-                .unwrap_or_else(|| "…".to_string());
+            let overflow = kwargs.get::<Option<Value>>("overflow")?;
+            let truncate = kwargs.get::<Option<String>>("truncate")?;
+            let ellipsis = kwargs.get::<Option<String>>("ellipsis")?;
 
             kwargs.assert_all_used()?;
 
-            Ok(format_col(&text, width, &align, &truncate, &ellipsis))
+            let overflow = match overflow {
+                Some(value) => {
+                    if let Some(name) = value.as_str() {
+                        if !OVERFLOW_NAMES.contains(&name.to_lowercase().as_str()) {
+                            return Err(minijinja::Error::new(
+                                minijinja::ErrorKind::InvalidOperation,
+                                format!(
+                                    "Invalid overflow: '{}'. Use one of {}",
+                                    name,
+                                    OVERFLOW_NAMES.join(", ")
+                                ),
+                            ));
+                        }
+                    }
+                    parse_overflow(&value)?
+                }
+                None => Overflow::default(),
+            };
+            // truncate= and ellipsis= refine truncation, the default overflow
+            let overflow = match overflow {
+                Overflow::Truncate { at, marker } => Overflow::Truncate {
+                    at: truncate.as_deref().map(parse_truncate).unwrap_or(at),
+                    marker: ellipsis.unwrap_or(marker),
+                },
+                _ if truncate.is_some() || ellipsis.is_some() => {
+                    return Err(minijinja::Error::new(
+                        minijinja::ErrorKind::InvalidOperation,
+                        "truncate and ellipsis only apply to overflow='truncate'",
+                    ));
+                }
+                other => other,
+            };
+
+            Ok(format_col(&text, width, parse_align(&align), overflow))
         },
     );
 
@@ -163,6 +201,35 @@ pub fn register_tabular_filters(env: &mut Environment<'static>) {
         }
     });
 
+    // center filter: {{ title | center }} or {{ title | center(60) }}
+    // Each line is centered in the terminal width unless a width is given.
+    env.add_filter("center", |value: Value, width: Option<usize>| -> String {
+        align_lines(&value.to_string(), width, |line, padding| {
+            let left_pad = padding / 2;
+            format!("{}{}", " ".repeat(left_pad), line)
+        })
+    });
+
+    // right_align filter: {{ status | right_align }} or {{ status | right_align(60) }}
+    // Each line is right-aligned in the terminal width unless a width is given.
+    env.add_filter(
+        "right_align",
+        |value: Value, width: Option<usize>| -> String {
+            align_lines(&value.to_string(), width, |line, padding| {
+                format!("{}{}", " ".repeat(padding), line)
+            })
+        },
+    );
+
+    // hr filter: {{ "Results" | hr }} or {{ "Results" | hr(style="muted") }}
+    // Same keyword arguments as the hr() function.
+    env.add_filter(
+        "hr",
+        |value: Value, kwargs: minijinja::value::Kwargs| -> Result<String, minijinja::Error> {
+            horizontal_rule(&value.to_string(), &kwargs)
+        },
+    );
+
     // truncate_at filter: {{ value | truncate_at(width, "middle") }}
     // BBCode tags are stripped before truncation.
     env.add_filter(
@@ -208,6 +275,16 @@ pub fn register_tabular_filters(env: &mut Environment<'static>) {
 
 /// Register global functions for creating table formatters.
 fn register_table_functions(env: &mut Environment<'static>) {
+    // hr(label=?, width=?, char=?, style=?, label_style=?) -> String
+    env.add_function(
+        "hr",
+        |label: Option<String>,
+         kwargs: minijinja::value::Kwargs|
+         -> Result<String, minijinja::Error> {
+            horizontal_rule(label.as_deref().unwrap_or_default(), &kwargs)
+        },
+    );
+
     // tabular(columns, separator=?, width=?) -> TabularFormatter
     env.add_function(
         "tabular",
@@ -398,6 +475,13 @@ fn parse_column(value: &Value) -> Result<Column, minijinja::Error> {
         }
     }
 
+    // Optional: default (used when the key path is missing)
+    if let Ok(default_val) = value.get_attr("default") {
+        if !default_val.is_none() && !default_val.is_undefined() {
+            col = col.default_value(default_val.to_string());
+        }
+    }
+
     // Optional: anchor
     if let Ok(anchor_val) = value.get_attr("anchor") {
         if !anchor_val.is_none()
@@ -425,6 +509,17 @@ fn parse_column(value: &Value) -> Result<Column, minijinja::Error> {
     Ok(col)
 }
 
+/// Overflow names accepted in string form.
+const OVERFLOW_NAMES: &[&str] = &[
+    "truncate",
+    "truncate_start",
+    "truncate_middle",
+    "truncate_end",
+    "wrap",
+    "clip",
+    "expand",
+];
+
 /// Parse an overflow specification from a template value.
 fn parse_overflow(value: &Value) -> Result<Overflow, minijinja::Error> {
     // String shorthand: "truncate", "wrap", "clip", "expand"
@@ -738,50 +833,97 @@ pub fn table_from_type<T: Tabular>(width: usize, border: BorderStyle, use_header
     Value::from_object(table)
 }
 
-/// Format a value for a column with specified width, alignment, and truncation.
+/// Format a value for a column with specified width, alignment, and overflow.
 ///
 /// BBCode-style markup tags (e.g., `[bold]...[/bold]`) are treated as zero-width:
 /// width measurement is done on the stripped text, and tags are preserved in the
-/// output when padding. When truncation is needed, tags are stripped first since
-/// the visible content exceeds the available width.
-fn format_col(text: &str, width: usize, align: &str, truncate: &str, ellipsis: &str) -> String {
-    if width == 0 {
-        return String::new();
-    }
-
-    // Strip BBCode tags to measure visible width
-    let stripped = strip_tags(text);
-    let visible_width = display_width(&stripped);
-
-    if visible_width > width {
-        // Content is genuinely too wide — truncate the stripped text
-        let truncated = match truncate {
-            "start" => truncate_start(&stripped, width, ellipsis),
-            "middle" => truncate_middle(&stripped, width, ellipsis),
-            _ => truncate_end(&stripped, width, ellipsis),
-        };
-        // Pad the truncated (tag-free) text
-        match align {
-            "right" => pad_left(&truncated, width),
-            "center" => pad_center(&truncated, width),
-            _ => pad_right(&truncated, width),
-        }
-    } else {
-        // Content fits — pad the original text, preserving BBCode tags
-        let padding = width - visible_width;
-        if padding == 0 {
-            return text.to_string();
-        }
-        match align {
-            "right" => format!("{}{}", " ".repeat(padding), text),
-            "center" => {
-                let left_pad = padding / 2;
-                let right_pad = padding - left_pad;
-                format!("{}{}{}", " ".repeat(left_pad), text, " ".repeat(right_pad))
+/// output when padding. When the content overflows, tags are stripped first since
+/// the visible content exceeds the available width. `Overflow::Wrap` yields
+/// one padded line per wrapped line, joined with newlines.
+fn format_col(text: &str, width: usize, align: Align, overflow: Overflow) -> String {
+    let column = Column::new(Width::Fixed(width))
+        .align(align)
+        .overflow(overflow);
+    TabularFormatter::with_widths(vec![column], vec![width])
+        .format_row_lines(&[text])
+        .join("\n")
+}
+
+/// Width used by `center` and `right_align` when the terminal width is unknown.
+const DEFAULT_ALIGN_WIDTH: usize = 80;
+
+/// Pads each line of `text` with `pad(line, padding)`, where `padding` is the
+/// room left in `width` (the terminal width by default).
+///
+/// Width is measured with BBCode tags and ANSI codes stripped; lines that
+/// already fill the width are left as they are.
+fn align_lines(text: &str, width: Option<usize>, pad: impl Fn(&str, usize) -> String) -> String {
+    let width = width
+        .or_else(crate::environment::detect_terminal_width)
+        .unwrap_or(DEFAULT_ALIGN_WIDTH);
+    text.split('\n')
+        .map(|line| {
+            let visible_width = display_width(&strip_tags(line));
+            if visible_width >= width {
+                line.to_string()
+            } else {
+                pad(line, width - visible_width)
             }
-            _ => format!("{}{}", text, " ".repeat(padding)),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Renders a horizontal rule across `width` (the terminal width by default),
+/// with `label` centered in it when not empty: `──── Results ────`.
+///
+/// `char` sets the rule character, `style` the style of the rule and
+/// `label_style` the style of the label. A label too wide for the rule is
+/// shown on its own.
+fn horizontal_rule(
+    label: &str,
+    kwargs: &minijinja::value::Kwargs,
+) -> Result<String, minijinja::Error> {
+    let width = kwargs
+        .get::<Option<usize>>("width")?
+        .or_else(crate::environment::detect_terminal_width)
+        .unwrap_or(DEFAULT_ALIGN_WIDTH);
+    let fill = kwargs
+        .get::<Option<String>>("char")?
+        .unwrap_or_else(|| "─".to_string());
+    let style = kwargs.get::<Option<String>>("style")?.unwrap_or_default();
+    let label_style = kwargs
+        .get::<Option<String>>("label_style")?
+        .unwrap_or_default();
+    kwargs.assert_all_used()?;
+
+    let fill_width = display_width(&fill);
+    if fill_width == 0 {
+        return Err(minijinja::Error::new(
+            minijinja::ErrorKind::InvalidOperation,
+            "hr char must have a visible width",
+        ));
+    }
+    let styled = |text: String, style: &str| {
+        if style.is_empty() || text.is_empty() {
+            text
+        } else {
+            format!("[{}]{}[/{}]", style, text, style)
         }
+    };
+    let rule = |columns: usize| styled(fill.repeat(columns / fill_width), &style);
+
+    if label.is_empty() {
+        return Ok(rule(width));
     }
+    let label_width = display_width(&strip_tags(label));
+    let label = styled(label.to_string(), &label_style);
+    if label_width + 2 >= width {
+        return Ok(label);
+    }
+    let room = width - label_width - 2;
+    let left = room / 2;
+    Ok(format!("{} {} {}", rule(left), label, rule(room - left)))
 }
 
 #[cfg(test)]
@@ -1238,6 +1380,201 @@ mod tests {
         assert_eq!(display_width(&stripped), 8);
     }
 
+    #[test]
+    fn filter_center_and_right_align_with_width() {
+        let mut env = setup_env();
+        env.add_template(
+            "test",
+            "{{ value | center(10) }}|{{ value | right_align(10) }}",
+        )
+        .unwrap();
+        let result = env
+            .get_template("test")
+            .unwrap()
+            .render(context!(value => "[bold]hi[/bold]"))
+            .unwrap();
+        assert_eq!(result, "    [bold]hi[/bold]|        [bold]hi[/bold]");
+    }
+
+    #[test]
+    fn filter_align_ignores_ansi_and_pads_each_line() {
+        let mut env = setup_env();
+        env.add_template("test", "{{ value | right_align(6) }}")
+            .unwrap();
+        let result = env
+            .get_template("test")
+            .unwrap()
+            .render(context!(value => "\x1b[1mok\x1b[0m\ntoo long"))
+            .unwrap();
+        assert_eq!(result, "    \x1b[1mok\x1b[0m\ntoo long");
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn filter_align_defaults_to_terminal_width() {
+        use crate::environment::{set_terminal_width_detector, DetectorGuard};
+        let _guard = DetectorGuard::new();
+        let mut env = setup_env();
+        env.add_template("test", "{{ value | center }}|{{ value | right_align }}")
+            .unwrap();
+        let render = |env: &Environment| {
+            env.get_template("test")
+                .unwrap()
+                .render(context!(value => "ab"))
+                .unwrap()
+        };
+
+        set_terminal_width_detector(|| Some(8));
+        assert_eq!(render(&env), "   ab|      ab");
+
+        set_terminal_width_detector(|| None);
+        assert_eq!(
+            render(&env),
+            format!("{}ab|{}ab", " ".repeat(39), " ".repeat(78))
+        );
+    }
+
+    #[test]
+    fn hr_function_and_filter() {
+        let mut env = setup_env();
+        env.add_template("plain", "{{ hr(width=6) }}").unwrap();
+        env.add_template("labeled", "{{ hr('Results', width=17) }}")
+            .unwrap();
+        env.add_template("filter", "{{ title | hr(width=12, char='=') }}")
+            .unwrap();
+        let render = |name: &str| {
+            env.get_template(name)
+                .unwrap()
+                .render(context!(title => "Log"))
+                .unwrap()
+        };
+
+        assert_eq!(render("plain"), "──────");
+        assert_eq!(render("labeled"), "──── Results ────");
+        assert_eq!(render("filter"), "=== Log ====");
+    }
+
+    #[test]
+    fn hr_styles_rule_and_label() {
+        let mut env = setup_env();
+        env.add_template(
+            "test",
+            "{{ '[b]Hi[/b]' | hr(width=8, style='muted', label_style='title') }}",
+        )
+        .unwrap();
+        let result = env.get_template("test").unwrap().render(()).unwrap();
+        assert_eq!(
+            result,
+            "[muted]──[/muted] [title][b]Hi[/b][/title] [muted]──[/muted]"
+        );
+    }
+
+    #[test]
+    fn hr_label_wider_than_rule_stands_alone() {
+        let mut env = setup_env();
+        env.add_template("test", "{{ hr('Results', width=8) }}")
+            .unwrap();
+        let result = env.get_template("test").unwrap().render(()).unwrap();
+        assert_eq!(result, "Results");
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn hr_defaults_to_terminal_width() {
+        use crate::environment::{set_terminal_width_detector, DetectorGuard};
+        let _guard = DetectorGuard::new();
+        set_terminal_width_detector(|| Some(4));
+        let mut env = setup_env();
+        env.add_template("test", "{{ hr(char='-') }}").unwrap();
+        let result = env.get_template("test").unwrap().render(()).unwrap();
+        assert_eq!(result, "----");
+    }
+
+    #[test]
+    fn filter_col_overflow_wrap() {
+        let mut env = setup_env();
+        env.add_template(
+            "test",
+            "{{ value | col(10, overflow='wrap', align='right') }}|",
+        )
+        .unwrap();
+        let result = env
+            .get_template("test")
+            .unwrap()
+            .render(context!(value => "wrap this long text"))
+            .unwrap();
+        assert_eq!(result, " wrap this\n long text|");
+    }
+
+    #[test]
+    fn filter_col_overflow_clip_and_expand() {
+        let mut env = setup_env();
+        env.add_template(
+            "test",
+            "{{ value | col(5, overflow='clip') }}|{{ value | col(5, overflow='expand') }}|",
+        )
+        .unwrap();
+        let result = env
+            .get_template("test")
+            .unwrap()
+            .render(context!(value => "abcdefg"))
+            .unwrap();
+        assert_eq!(result, "abcde|abcdefg|");
+    }
+
+    #[test]
+    fn filter_col_rejects_unknown_overflow() {
+        let mut env = setup_env();
+        env.add_template("test", "{{ value | col(10, overflow='wrpa') }}")
+            .unwrap();
+        let err = env
+            .get_template("test")
+            .unwrap()
+            .render(context!(value => "hello"))
+            .unwrap_err();
+        assert_eq!(err.kind(), minijinja::ErrorKind::InvalidOperation);
+        assert!(err.to_string().contains("wrpa"), "{err}");
+    }
+
+    #[test]
+    fn filter_col_rejects_truncate_args_without_truncation() {
+        let mut env = setup_env();
+        env.add_template(
+            "truncate",
+            "{{ value | col(4, truncate='start', overflow='wrap') }}",
+        )
+        .unwrap();
+        env.add_template(
+            "ellipsis",
+            "{{ value | col(4, ellipsis='..', overflow='clip') }}",
+        )
+        .unwrap();
+        for name in ["truncate", "ellipsis"] {
+            let err = env
+                .get_template(name)
+                .unwrap()
+                .render(context!(value => "hello world"))
+                .unwrap_err();
+            assert_eq!(err.kind(), minijinja::ErrorKind::InvalidOperation);
+        }
+    }
+
+    #[test]
+    fn filter_col_overflow_truncate_object() {
+        let mut env = setup_env();
+        env.add_template(
+            "test",
+            "{{ value | col(9, overflow={'truncate': {'at': 'middle', 'marker': '..'}}) }}",
+        )
+        .unwrap();
+        let result = env
+            .get_template("test")
+            .unwrap()
+            .render(context!(value => "abcdefghijkl"))
+            .unwrap();
+        assert_eq!(result, "abc..ijkl");
+    }
+
     #[test]
     fn filter_truncate_at_bbcode() {
         let mut env = setup_env();
@@ -1688,6 +2025,33 @@ mod tests {
         assert!(result.contains("active"));
     }
 
+    #[test]
+    fn function_table_row_from_nested_path_and_default() {
+        let mut env = setup_env();
+        env.add_template(
+            "test",
+            r#"{% set tbl = table([{"width": 10, "key": "tags[0]"}, {"width": 8, "key": "owner.name", "default": "nobody"}], separator="  ") %}{{ tbl.row_from(item) }}"#,
+        )
+        .unwrap();
+
+        #[derive(Serialize)]
+        struct TestItem {
+            tags: Vec<&'static str>,
+        }
+
+        let item = TestItem {
+            tags: vec!["urgent", "backend"],
+        };
+
+        let result = env
+            .get_template("test")
+            .unwrap()
+            .render(context!(item => item))
+            .unwrap();
+        assert!(result.contains("urgent"));
+        assert!(result.contains("nobody"));
+    }
+
     // ============================================================================
     // Sub-column Template Tests
     // ============================================================================
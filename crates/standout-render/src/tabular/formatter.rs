@@ -39,7 +39,6 @@
 
 use minijinja::value::{Enumerator, Object, Value};
 use serde::Serialize;
-use serde_json::Value as JsonValue;
 use std::sync::Arc;
 
 use super::resolve::ResolvedWidths;
@@ -613,7 +612,7 @@ impl TabularFormatter {
                 let key = col.key.as_ref().or(col.name.as_ref());
 
                 match key {
-                    Some(k) => extract_field(&json, k),
+                    Some(k) => col.extract_cell(&json, k).unwrap_or_default(),
                     None => col.null_repr.clone(),
                 }
             })
@@ -621,46 +620,6 @@ impl TabularFormatter {
     }
 }
 
-/// Extract a field value from JSON using dot notation.
-///
-/// Supports paths like "user.email" or "items.0.name".
-fn extract_field(value: &JsonValue, path: &str) -> String {
-    let mut current = value;
-
-    for part in path.split('.') {
-        match current {
-            JsonValue::Object(map) => {
-                current = match map.get(part) {
-                    Some(v) => v,
-                    None => return String::new(),
-                };
-            }
-            JsonValue::Array(arr) => {
-                // Try to parse as index
-                if let Ok(idx) = part.parse::<usize>() {
-                    current = match arr.get(idx) {
-                        Some(v) => v,
-                        None => return String::new(),
-                    };
-                } else {
-                    return String::new();
-                }
-            }
-            _ => return String::new(),
-        }
-    }
-
-    // Convert final value to string
-    match current {
-        JsonValue::String(s) => s.clone(),
-        JsonValue::Number(n) => n.to_string(),
-        JsonValue::Bool(b) => b.to_string(),
-        JsonValue::Null => String::new(),
-        // For arrays/objects, use JSON representation
-        _ => current.to_string(),
-    }
-}
-
 // ============================================================================
 // MiniJinja Object Implementation
 // ============================================================================
@@ -789,14 +748,8 @@ impl Object for TabularFormatter {
 
 /// Format a single cell value according to column spec.
 fn format_cell(value: &str, width: usize, col: &Column) -> String {
-    // If style_from_value is set, use the value as the style
-    let style_override = if col.style_from_value {
-        Some(value)
-    } else {
-        None
-    };
-    let style = style_override.or(col.style.as_deref());
-    format_value(value, width, col.align, &col.overflow, style)
+    let style = col.resolve_style(value);
+    format_value(value, width, col.align, &col.overflow, style.as_deref())
 }
 
 /// Format a value with the given width, alignment, overflow, and optional style.
@@ -1092,12 +1045,8 @@ fn format_cell_lines(value: &str, width: usize, col: &Column) -> CellOutput {
     let stripped = standout_bbparser::strip_tags(value);
     let current_width = display_width(&stripped);
 
-    // Determine style: style_from_value takes precedence
-    let style = if col.style_from_value {
-        Some(value)
-    } else {
-        col.style.as_deref()
-    };
+    let style = col.resolve_style(value);
+    let style = style.as_deref();
 
     match &col.overflow {
         Overflow::Wrap { indent } => {
@@ -1143,7 +1092,7 @@ fn format_cell_lines(value: &str, width: usize, col: &Column) -> CellOutput {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tabular::{TabularSpec, Width};
+    use crate::tabular::{StyleCondition, TabularSpec, Width};
 
     fn simple_spec() -> FlatDataSpec {
         FlatDataSpec::builder()
@@ -1890,6 +1839,31 @@ mod tests {
         assert!(row.contains("Second"));
     }
 
+    #[test]
+    fn row_from_bracket_index_and_default() {
+        #[derive(Serialize)]
+        struct Record {
+            tags: Vec<String>,
+        }
+
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::Fixed(8)).key("tags[1]"))
+            .column(
+                Column::new(Width::Fixed(8))
+                    .key("tags[9]")
+                    .default_value("none"),
+            )
+            .separator(" ")
+            .build();
+        let formatter = TabularFormatter::new(&spec, 80);
+
+        let record = Record {
+            tags: vec!["a".to_string(), "b".to_string()],
+        };
+
+        assert_eq!(formatter.row_from(&record), "b        none    ");
+    }
+
     #[test]
     fn row_from_missing_field_uses_null_repr() {
         #[derive(Serialize)]
@@ -1964,6 +1938,12 @@ mod tests {
         assert!(row.contains("true"));
     }
 
+    fn extract_field(value: &serde_json::Value, path: &str) -> String {
+        crate::tabular::types::lookup_path(value, path)
+            .map(crate::tabular::types::value_to_cell)
+            .unwrap_or_default()
+    }
+
     #[test]
     fn extract_field_simple() {
         let json = serde_json::json!({
@@ -2002,6 +1982,7 @@ mod tests {
         assert_eq!(extract_field(&json, "items.0"), "a");
         assert_eq!(extract_field(&json, "items.1"), "b");
         assert_eq!(extract_field(&json, "items.10"), ""); // Out of bounds
+        assert_eq!(extract_field(&json, "items[2]"), "c");
     }
 
     #[test]
@@ -2090,6 +2071,58 @@ mod tests {
         assert!(output.contains("[/custom]"));
     }
 
+    #[test]
+    fn format_cell_style_rules_first_match_wins() {
+        let spec = FlatDataSpec::builder()
+            .column(
+                Column::new(Width::Fixed(6))
+                    .style("plain")
+                    .style_if(StyleCondition::Eq("error".into()), "red")
+                    .style_if(StyleCondition::Eq("ok".into()), "green"),
+            )
+            .build();
+        let formatter = TabularFormatter::new(&spec, 80);
+
+        assert_eq!(formatter.format_row(&["error"]), "[red]error [/red]");
+        assert_eq!(formatter.format_row(&["ok"]), "[green]ok    [/green]");
+        // No rule matches: falls back to the static style
+        assert_eq!(formatter.format_row(&["other"]), "[plain]other [/plain]");
+    }
+
+    #[test]
+    fn format_cell_style_rules_thresholds() {
+        let spec = FlatDataSpec::builder()
+            .column(
+                Column::new(Width::Fixed(4))
+                    .right()
+                    .style_if(StyleCondition::Ge(90.0), "error")
+                    .style_if(StyleCondition::Ge(70.0), "warning"),
+            )
+            .build();
+        let formatter = TabularFormatter::new(&spec, 80);
+
+        assert_eq!(formatter.format_row(&["95"]), "[error]  95[/error]");
+        assert_eq!(formatter.format_row(&["70"]), "[warning]  70[/warning]");
+        assert_eq!(formatter.format_row(&["12"]), "  12");
+        // Non-numeric cells never match numeric conditions
+        assert_eq!(formatter.format_row(&["n/a"]), " n/a");
+    }
+
+    #[test]
+    fn format_cell_style_fn_takes_precedence() {
+        let spec = FlatDataSpec::builder()
+            .column(
+                Column::new(Width::Fixed(6))
+                    .style_if(StyleCondition::Eq("error".into()), "rule")
+                    .style_fn(|v| (v == "error").then(|| "callback".to_string())),
+            )
+            .build();
+        let formatter = TabularFormatter::new(&spec, 80);
+
+        assert!(formatter.format_row(&["error"]).starts_with("[callback]"));
+        assert!(!formatter.format_row(&["ok"]).contains('['));
+    }
+
     #[test]
     fn format_row_multiple_styled_columns() {
         let spec = FlatDataSpec::builder()
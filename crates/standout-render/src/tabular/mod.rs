@@ -94,6 +94,28 @@
 //! ]);
 //! ```
 //!
+//! ## Streaming Large Tables
+//!
+//! [`TableStream`] writes rows from an iterator as they arrive instead of
+//! buffering the whole table. Widths are resolved from the first rows (the
+//! sample window, [`DEFAULT_SAMPLE_SIZE`] by default) and then locked:
+//!
+//! ```rust
+//! use standout_render::tabular::{Col, Table, TableStream, TabularSpec};
+//!
+//! let spec = TabularSpec::builder()
+//!     .column(Col::bounded(4, 12))
+//!     .column(Col::fill())
+//!     .build();
+//!
+//! let rows = (0..100_000).map(|i| vec![format!("#{}", i), "item".to_string()]);
+//! let mut out = std::io::sink();
+//! TableStream::new(Table::new(spec, 60))
+//!     .sample_size(500)
+//!     .write_rows(rows, &mut out)
+//!     .unwrap();
+//! ```
+//!
 //! ## Utility Functions
 //!
 //! ```rust
@@ -136,6 +158,7 @@ mod decorator;
 pub mod filters;
 mod formatter;
 mod resolve;
+mod stream;
 mod traits;
 mod types;
 mod util;
@@ -144,13 +167,15 @@ mod util;
 pub use decorator::{BorderStyle, Table};
 pub use formatter::{CellOutput, CellValue, TabularFormatter};
 pub use resolve::ResolvedWidths;
+pub use stream::{TableStream, DEFAULT_SAMPLE_SIZE};
 pub use traits::{Tabular, TabularFieldDisplay, TabularFieldOption, TabularRow};
 
 // Note: Tabular and TabularRow derive macros are re-exported from the main `standout` crate
 // when the "macros" feature is enabled.
 pub use types::{
     Align, Anchor, Col, Column, ColumnBuilder, Decorations, FlatDataSpec, FlatDataSpecBuilder,
-    Overflow, SubCol, SubColumn, SubColumns, TabularSpec, TabularSpecBuilder, TruncateAt, Width,
+    FormatFn, Overflow, StyleCondition, StyleFn, StyleRule, SubCol, SubColumn, SubColumns,
+    TabularSpec, TabularSpecBuilder, TruncateAt, Width,
 };
 
 // Re-export utility functions
@@ -0,0 +1,374 @@
+//! Incremental table rendering for large row sets.
+//!
+//! [`Table::render`] needs every row up front, which is fine for a screenful of
+//! data but wasteful when a command lists hundreds of thousands of items.
+//! [`TableStream`] accepts rows from an iterator and writes each one as soon as
+//! it arrives.
+//!
+//! Column widths are resolved from a *sample window*: the first N rows are
+//! buffered, widths are computed from them (as
+//! [`FlatDataSpec::resolve_widths_from_data`] does), and from then on widths
+//! are locked. Rows after the window that are wider than the locked widths are
+//! truncated or wrapped according to each column's overflow setting.
+//!
+//! # Example
+//!
+//! ```rust
+//! use standout_render::tabular::{BorderStyle, Col, Table, TableStream, TabularSpec};
+//!
+//! let spec = TabularSpec::builder()
+//!     .column(Col::bounded(2, 10).header("ID"))
+//!     .column(Col::fill().header("Name"))
+//!     .separator("  ")
+//!     .build();
+//!
+//! let table = Table::new(spec, 40)
+//!     .header_from_columns()
+//!     .border(BorderStyle::Ascii);
+//!
+//! let rows = (0..1000).map(|i| vec![i.to_string(), format!("item-{}", i)]);
+//!
+//! let mut out = Vec::new();
+//! let written = TableStream::new(table)
+//!     .sample_size(50)
+//!     .write_rows(rows, &mut out)
+//!     .unwrap();
+//! assert_eq!(written, 1000);
+//! ```
+
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+use super::decorator::Table;
+use crate::output::OutputMode;
+use crate::style::Styles;
+use crate::template::apply_style_tags;
+use crate::theme::{detect_color_mode, Theme};
+
+/// Default number of rows buffered before column widths are locked.
+pub const DEFAULT_SAMPLE_SIZE: usize = 100;
+
+/// Writes table rows incrementally, locking widths after a sample window.
+///
+/// Wraps a configured [`Table`] (borders, headers, row styles) and streams rows
+/// into any [`io::Write`]. By default lines are written with style tags intact,
+/// exactly as [`Table::row`] produces them; call [`styled`](Self::styled) to
+/// resolve tags against a theme as each line is written.
+#[derive(Debug, Clone)]
+pub struct TableStream {
+    table: Table,
+    sample_size: usize,
+    styling: Option<(Styles, OutputMode)>,
+}
+
+impl TableStream {
+    /// Create a stream from a configured table.
+    pub fn new(table: Table) -> Self {
+        TableStream {
+            table,
+            sample_size: DEFAULT_SAMPLE_SIZE,
+            styling: None,
+        }
+    }
+
+    /// Set how many rows are buffered to resolve column widths.
+    ///
+    /// A size of 0 skips sampling entirely: widths are resolved from the spec
+    /// alone and the first row is written immediately.
+    pub fn sample_size(mut self, rows: usize) -> Self {
+        self.sample_size = rows;
+        self
+    }
+
+    /// Apply theme styles to each written line using the given output mode.
+    pub fn styled(mut self, theme: &Theme, mode: OutputMode) -> Self {
+        let styles = theme.resolve_styles(Some(detect_color_mode()));
+        self.styling = Some((styles, mode));
+        self
+    }
+
+    /// Stream rows of cell values to `out`.
+    ///
+    /// Returns the number of data rows written.
+    pub fn write_rows<I, R, S, W>(&self, rows: I, out: &mut W) -> io::Result<usize>
+    where
+        I: IntoIterator<Item = R>,
+        R: AsRef<[S]>,
+        S: AsRef<str>,
+        W: Write,
+    {
+        let mut rows = rows.into_iter();
+
+        let sample: Vec<Vec<String>> = rows
+            .by_ref()
+            .take(self.sample_size)
+            .map(|row| {
+                row.as_ref()
+                    .iter()
+                    .map(|s| s.as_ref().to_string())
+                    .collect()
+            })
+            .collect();
+
+        let table = if sample.is_empty() {
+            self.table.clone()
+        } else {
+            self.table.clone().resolve_from_data(&sample)
+        };
+        table.reset_row_counter();
+
+        let mut writer = LineWriter {
+            out,
+            styling: self.styling.as_ref(),
+            row_separator: table.row_separator_line(),
+            rows: 0,
+        };
+
+        writer.line(&table.top_border())?;
+        let header = table.header_row();
+        if !header.is_empty() {
+            writer.line(&header)?;
+            writer.line(&table.separator_row())?;
+        }
+
+        for row in &sample {
+            writer.row(&table.row(row.as_slice()))?;
+        }
+        for row in rows {
+            writer.row(&table.row(row.as_ref()))?;
+        }
+
+        writer.line(&table.bottom_border())?;
+        writer.out.flush()?;
+        Ok(writer.rows)
+    }
+
+    /// Stream serializable records to `out`, extracting cells by column key.
+    ///
+    /// Each item is converted to JSON and its cells extracted with
+    /// [`FlatDataSpec::extract_row`](super::FlatDataSpec::extract_row), so
+    /// column `key`s support the same dot notation as CSV output.
+    ///
+    /// Returns the number of data rows written.
+    pub fn write_serialized<I, T, W>(&self, items: I, out: &mut W) -> io::Result<usize>
+    where
+        I: IntoIterator<Item = T>,
+        T: Serialize,
+        W: Write,
+    {
+        let spec = self.table.spec().clone();
+        let mut failure = None;
+        let rows = items
+            .into_iter()
+            .map_while(|item| match serde_json::to_value(&item) {
+                Ok(value) => Some(spec.extract_row(&value)),
+                Err(e) => {
+                    failure = Some(e);
+                    None
+                }
+            });
+        let count = self.write_rows(rows, out)?;
+        match failure {
+            Some(e) => Err(io::Error::new(io::ErrorKind::InvalidData, e)),
+            None => Ok(count),
+        }
+    }
+}
+
+/// Writes non-empty lines, optionally resolving style tags first.
+struct LineWriter<'a, W: Write> {
+    out: &'a mut W,
+    styling: Option<&'a (Styles, OutputMode)>,
+    row_separator: Option<String>,
+    rows: usize,
+}
+
+impl<W: Write> LineWriter<'_, W> {
+    /// Write a data row, preceded by the row separator after the first row.
+    fn row(&mut self, line: &str) -> io::Result<()> {
+        if self.rows > 0 {
+            if let Some(sep) = &self.row_separator {
+                write_line(&mut *self.out, self.styling, sep)?;
+            }
+        }
+        self.rows += 1;
+        write_line(&mut *self.out, self.styling, line)
+    }
+
+    /// Write a decoration line (border, header, separator).
+    fn line(&mut self, line: &str) -> io::Result<()> {
+        write_line(&mut *self.out, self.styling, line)
+    }
+}
+
+fn write_line<W: Write>(
+    out: &mut W,
+    styling: Option<&(Styles, OutputMode)>,
+    line: &str,
+) -> io::Result<()> {
+    if line.is_empty() {
+        return Ok(());
+    }
+    match styling {
+        Some((styles, mode)) => writeln!(out, "{}", apply_style_tags(line, styles, *mode)),
+        None => writeln!(out, "{}", line),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tabular::{BorderStyle, Col, TabularSpec};
+    use console::Style;
+
+    fn spec() -> TabularSpec {
+        TabularSpec::builder()
+            .column(Col::bounded(2, 20).header("ID"))
+            .column(Col::bounded(4, 20).header("Name"))
+            .separator(" ")
+            .build()
+    }
+
+    fn lines(out: &[u8]) -> Vec<String> {
+        String::from_utf8(out.to_vec())
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn streams_all_rows() {
+        let table = Table::new(spec(), 40).header_from_columns();
+        let rows = (0..250).map(|i| vec![i.to_string(), format!("n{}", i)]);
+
+        let mut out = Vec::new();
+        let count = TableStream::new(table)
+            .sample_size(10)
+            .write_rows(rows, &mut out)
+            .unwrap();
+
+        assert_eq!(count, 250);
+        let lines = lines(&out);
+        assert_eq!(lines.len(), 251);
+        assert!(lines[0].starts_with("ID"));
+        assert!(lines[250].contains("n249"));
+    }
+
+    #[test]
+    fn widths_locked_after_sample() {
+        // Exactly wide enough for the sampled widths, so nothing expands
+        let table = Table::new(spec(), 7);
+        let rows = vec![
+            vec!["1", "ab"],
+            vec!["2", "cd"],
+            vec!["3", "a much longer name"],
+        ];
+
+        let mut out = Vec::new();
+        TableStream::new(table)
+            .sample_size(2)
+            .write_rows(rows, &mut out)
+            .unwrap();
+
+        let lines = lines(&out);
+        // Sample resolves the name column to its minimum of 4; the late row is truncated
+        assert_eq!(lines[0].trim_end(), "1  ab");
+        assert_eq!(lines[2], "3  a m…");
+    }
+
+    #[test]
+    fn sample_larger_than_input() {
+        let table = Table::new(spec(), 40).border(BorderStyle::Light);
+        let rows = vec![vec!["1", "one"]];
+
+        let mut out = Vec::new();
+        let count = TableStream::new(table).write_rows(rows, &mut out).unwrap();
+
+        assert_eq!(count, 1);
+        let lines = lines(&out);
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with('┌'));
+        assert!(lines[2].starts_with('└'));
+    }
+
+    #[test]
+    fn empty_input_writes_frame_only() {
+        let table = Table::new(spec(), 40).header_from_columns();
+        let rows: Vec<Vec<String>> = vec![];
+
+        let mut out = Vec::new();
+        let count = TableStream::new(table).write_rows(rows, &mut out).unwrap();
+
+        assert_eq!(count, 0);
+        assert_eq!(lines(&out).len(), 1);
+    }
+
+    #[test]
+    fn row_separators_between_rows() {
+        let table = Table::new(spec(), 40)
+            .border(BorderStyle::Ascii)
+            .row_separator(true);
+        let rows = vec![vec!["1", "a"], vec!["2", "b"], vec!["3", "c"]];
+
+        let mut out = Vec::new();
+        TableStream::new(table)
+            .sample_size(1)
+            .write_rows(rows, &mut out)
+            .unwrap();
+
+        let seps = lines(&out).iter().filter(|l| l.starts_with("+-")).count();
+        // top + 2 between rows + bottom
+        assert_eq!(seps, 4);
+    }
+
+    #[test]
+    fn write_serialized_extracts_keys() {
+        #[derive(Serialize)]
+        struct Item {
+            id: u32,
+            name: String,
+        }
+
+        let spec = TabularSpec::builder()
+            .column(Col::bounded(2, 10).key("id"))
+            .column(Col::bounded(4, 20).key("name"))
+            .separator(" ")
+            .build();
+        let items = (1..=3).map(|id| Item {
+            id,
+            name: format!("item{}", id),
+        });
+
+        let mut out = Vec::new();
+        let count = TableStream::new(Table::new(spec, 40))
+            .write_serialized(items, &mut out)
+            .unwrap();
+
+        assert_eq!(count, 3);
+        assert_eq!(lines(&out)[1].trim_end(), "2  item2");
+    }
+
+    #[test]
+    fn styled_resolves_tags() {
+        let table = Table::new(spec(), 40)
+            .header_from_columns()
+            .header_style("hdr");
+        let theme = Theme::new().add("hdr", Style::new().bold());
+        let rows = vec![vec!["1", "one"]];
+
+        let mut raw = Vec::new();
+        TableStream::new(table.clone())
+            .write_rows(rows.clone(), &mut raw)
+            .unwrap();
+        assert!(lines(&raw)[0].starts_with("[hdr]"));
+
+        let mut plain = Vec::new();
+        TableStream::new(table)
+            .styled(&theme, OutputMode::Text)
+            .write_rows(rows, &mut plain)
+            .unwrap();
+        assert!(lines(&plain)[0].starts_with("ID"));
+    }
+}
@@ -30,9 +30,24 @@ use super::TabularSpec;
 /// // Use the generated spec
 /// let spec = Task::tabular_spec();
 /// ```
+///
+/// # Views
+///
+/// Fields annotated with `#[col(only = "wide")]` are left out of
+/// [`tabular_spec`](Tabular::tabular_spec) and included by
+/// [`tabular_spec_for("wide")`](Tabular::tabular_spec_for), so one struct
+/// serves both a compact default table and an extended one.
 pub trait Tabular {
-    /// Returns the `TabularSpec` for this type.
+    /// Returns the `TabularSpec` for this type (default view).
     fn tabular_spec() -> TabularSpec;
+
+    /// Returns the `TabularSpec` for a named view, e.g. `"wide"`.
+    ///
+    /// The default implementation ignores the view and returns
+    /// [`tabular_spec`](Tabular::tabular_spec).
+    fn tabular_spec_for(_view: &str) -> TabularSpec {
+        Self::tabular_spec()
+    }
 }
 
 /// Trait for types that can be converted to a row of strings.
@@ -62,8 +77,17 @@ pub trait Tabular {
 /// assert_eq!(row, vec!["TSK-001", "Implement feature", "pending"]);
 /// ```
 pub trait TabularRow {
-    /// Converts this instance to a row of string values.
+    /// Converts this instance to a row of string values (default view).
     fn to_row(&self) -> Vec<String>;
+
+    /// Converts this instance to a row for a named view.
+    ///
+    /// Cells line up with the columns of
+    /// [`Tabular::tabular_spec_for`]. `None` is the default view. The default
+    /// implementation ignores the view and returns [`to_row`](TabularRow::to_row).
+    fn to_row_for(&self, _view: Option<&str>) -> Vec<String> {
+        self.to_row()
+    }
 }
 
 /// Trait for types that implement Display.
@@ -5,6 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::sync::Arc;
 
 /// Text alignment within a column.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
@@ -223,6 +224,122 @@ impl Width {
     }
 }
 
+/// Condition tested against a cell value by a [`StyleRule`].
+///
+/// String conditions compare the cell text exactly. Numeric conditions parse
+/// the (trimmed) cell text as a number and never match non-numeric cells.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StyleCondition {
+    /// Cell text equals the given string.
+    Eq(String),
+    /// Cell text differs from the given string.
+    Ne(String),
+    /// Cell number is greater than the threshold.
+    Gt(f64),
+    /// Cell number is greater than or equal to the threshold.
+    Ge(f64),
+    /// Cell number is less than the threshold.
+    Lt(f64),
+    /// Cell number is less than or equal to the threshold.
+    Le(f64),
+}
+
+impl StyleCondition {
+    /// Check whether the condition holds for a cell value.
+    pub fn matches(&self, value: &str) -> bool {
+        let number = || value.trim().parse::<f64>().ok();
+        match self {
+            StyleCondition::Eq(s) => value == s,
+            StyleCondition::Ne(s) => value != s,
+            StyleCondition::Gt(t) => number().is_some_and(|n| n > *t),
+            StyleCondition::Ge(t) => number().is_some_and(|n| n >= *t),
+            StyleCondition::Lt(t) => number().is_some_and(|n| n < *t),
+            StyleCondition::Le(t) => number().is_some_and(|n| n <= *t),
+        }
+    }
+}
+
+/// Applies a style to a cell when its value satisfies a condition.
+///
+/// Rules are evaluated in order; the first matching rule wins.
+///
+/// ```rust
+/// use standout_render::tabular::{Col, StyleCondition};
+///
+/// let col = Col::fixed(8)
+///     .style_if(StyleCondition::Eq("error".into()), "red")
+///     .style_if(StyleCondition::Eq("ok".into()), "green");
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StyleRule {
+    /// The condition to test.
+    pub when: StyleCondition,
+    /// Style name applied when the condition holds.
+    pub style: String,
+}
+
+impl StyleRule {
+    /// Create a rule applying `style` when `when` holds.
+    pub fn new(when: StyleCondition, style: impl Into<String>) -> Self {
+        StyleRule {
+            when,
+            style: style.into(),
+        }
+    }
+}
+
+type StyleCallback = Arc<dyn Fn(&str) -> Option<String> + Send + Sync>;
+
+/// Callback choosing a style name from a cell value.
+///
+/// Returning `None` falls through to the column's style rules and static style.
+#[derive(Clone)]
+pub struct StyleFn(StyleCallback);
+
+impl StyleFn {
+    /// Wrap a closure or function as a style callback.
+    pub fn new(f: impl Fn(&str) -> Option<String> + Send + Sync + 'static) -> Self {
+        StyleFn(Arc::new(f))
+    }
+
+    /// Compute the style for a cell value.
+    pub fn call(&self, value: &str) -> Option<String> {
+        (self.0)(value)
+    }
+}
+
+impl std::fmt::Debug for StyleFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("StyleFn(..)")
+    }
+}
+
+/// Callback turning an extracted JSON value into cell text.
+///
+/// Used by [`Column::format_fn`] when rows are extracted from serializable
+/// data by key (e.g. `row_from`, CSV export).
+#[derive(Clone)]
+pub struct FormatFn(Arc<dyn Fn(&Value) -> String + Send + Sync>);
+
+impl FormatFn {
+    /// Wrap a closure or function as a format callback.
+    pub fn new(f: impl Fn(&Value) -> String + Send + Sync + 'static) -> Self {
+        FormatFn(Arc::new(f))
+    }
+
+    /// Format an extracted value.
+    pub fn call(&self, value: &Value) -> String {
+        (self.0)(value)
+    }
+}
+
+impl std::fmt::Debug for FormatFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("FormatFn(..)")
+    }
+}
+
 /// Configuration for a single column in a table.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Column {
@@ -242,10 +359,31 @@ pub struct Column {
     pub style: Option<String>,
     /// When true, use the cell value as the style name.
     pub style_from_value: bool,
-    /// Optional key for data extraction (supports dot notation for nested fields).
+    /// Value-conditional styles; the first matching rule wins.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub style_rules: Vec<StyleRule>,
+    /// Callback computing a style from the cell value (not serialized).
+    #[serde(skip)]
+    pub style_fn: Option<StyleFn>,
+    /// Optional key for data extraction.
+    ///
+    /// Supports dot notation for nested fields (`author.name`) and array
+    /// indexing (`tags[0]`, `items.1.name`).
     pub key: Option<String>,
+    /// Text used when the key path is missing or null in extracted data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    /// Callback formatting the extracted value (not serialized).
+    #[serde(skip)]
+    pub format_fn: Option<FormatFn>,
     /// Optional header title (for table headers and CSV export).
     pub header: Option<String>,
+    /// Restrict this column to a named view (e.g. `"wide"`).
+    ///
+    /// Columns without a view appear in every view; columns with one only
+    /// appear when that view is selected. See [`FlatDataSpec::for_view`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub only: Option<String>,
     /// Optional sub-column layout within this column.
     ///
     /// When set, cell values for this column should be arrays of sub-values.
@@ -265,8 +403,13 @@ impl Default for Column {
             null_repr: "-".to_string(),
             style: None,
             style_from_value: false,
+            style_rules: Vec::new(),
+            style_fn: None,
             key: None,
+            default: None,
+            format_fn: None,
             header: None,
+            only: None,
             sub_columns: None,
         }
     }
@@ -392,18 +535,118 @@ impl Column {
         self
     }
 
-    /// Set the data key for this column (e.g. "author.name").
+    /// Apply `style` to cells whose value satisfies `when`.
+    ///
+    /// Can be called repeatedly; rules are checked in the order added and
+    /// take precedence over [`style`](Self::style) and
+    /// [`style_from_value`](Self::style_from_value).
+    pub fn style_if(mut self, when: StyleCondition, style: impl Into<String>) -> Self {
+        self.style_rules.push(StyleRule::new(when, style));
+        self
+    }
+
+    /// Compute the cell style from its value with a callback.
+    ///
+    /// The callback is consulted first; returning `None` falls back to style
+    /// rules, then `style_from_value`, then the static column style.
+    ///
+    /// ```rust
+    /// use standout_render::tabular::Col;
+    ///
+    /// let col = Col::fixed(6).style_fn(|v| match v {
+    ///     "error" => Some("red".into()),
+    ///     "ok" => Some("green".into()),
+    ///     _ => None,
+    /// });
+    /// ```
+    pub fn style_fn(mut self, f: impl Fn(&str) -> Option<String> + Send + Sync + 'static) -> Self {
+        self.style_fn = Some(StyleFn::new(f));
+        self
+    }
+
+    /// Resolve the style for a cell value.
+    ///
+    /// Precedence: `style_fn`, then the first matching style rule, then the
+    /// value itself when `style_from_value` is set, then the static style.
+    pub fn resolve_style(&self, value: &str) -> Option<String> {
+        if let Some(style) = self.style_fn.as_ref().and_then(|f| f.call(value)) {
+            return Some(style);
+        }
+        if let Some(rule) = self.style_rules.iter().find(|r| r.when.matches(value)) {
+            return Some(rule.style.clone());
+        }
+        if self.style_from_value {
+            return Some(value.to_string());
+        }
+        self.style.clone()
+    }
+
+    /// Set the data key for this column (e.g. "author.name" or "tags[0]").
     pub fn key(mut self, key: impl Into<String>) -> Self {
         self.key = Some(key.into());
         self
     }
 
+    /// Set the text used when the key path is missing or null.
+    pub fn default_value(mut self, default: impl Into<String>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Format extracted values with a callback instead of the built-in conversion.
+    ///
+    /// The callback receives the JSON value found at the column's key path.
+    /// Missing or null values skip the callback and use the default.
+    ///
+    /// ```rust
+    /// use standout_render::tabular::Col;
+    ///
+    /// let col = Col::fixed(8).key("size").format_fn(|v| {
+    ///     v.as_u64().map(|b| format!("{}K", b / 1024)).unwrap_or_default()
+    /// });
+    /// ```
+    pub fn format_fn(mut self, f: impl Fn(&Value) -> String + Send + Sync + 'static) -> Self {
+        self.format_fn = Some(FormatFn::new(f));
+        self
+    }
+
+    /// Extract this column's cell text from JSON data at `path`.
+    ///
+    /// Returns the formatted value, or the column default when the path is
+    /// missing or null. Returns `None` when neither is available, leaving the
+    /// fallback to the caller.
+    pub fn extract_cell(&self, data: &Value, path: &str) -> Option<String> {
+        match lookup_path(data, path) {
+            Some(Value::Null) | None => self.default.clone(),
+            Some(value) => Some(match &self.format_fn {
+                Some(f) => f.call(value),
+                None => value_to_cell(value),
+            }),
+        }
+    }
+
     /// Set the header title for this column.
     pub fn header(mut self, header: impl Into<String>) -> Self {
         self.header = Some(header.into());
         self
     }
 
+    /// Show this column only in the named view (e.g. `"wide"`).
+    pub fn only(mut self, view: impl Into<String>) -> Self {
+        self.only = Some(view.into());
+        self
+    }
+
+    /// Check whether this column is part of the given view.
+    ///
+    /// `None` is the default view, which excludes every view-restricted column.
+    pub fn in_view(&self, view: Option<&str>) -> bool {
+        match &self.only {
+            None => true,
+            Some(only) => view == Some(only.as_str()),
+        }
+    }
+
     /// Set sub-columns for per-row width distribution within this column.
     pub fn sub_columns(mut self, sub_cols: SubColumns) -> Self {
         self.sub_columns = Some(sub_cols);
@@ -422,8 +665,13 @@ pub struct ColumnBuilder {
     null_repr: Option<String>,
     style: Option<String>,
     style_from_value: bool,
+    style_rules: Vec<StyleRule>,
+    style_fn: Option<StyleFn>,
     key: Option<String>,
+    default: Option<String>,
+    format_fn: Option<FormatFn>,
     header: Option<String>,
+    only: Option<String>,
     sub_columns: Option<SubColumns>,
 }
 
@@ -546,18 +794,48 @@ impl ColumnBuilder {
         self
     }
 
+    /// Add a value-conditional style rule.
+    pub fn style_if(mut self, when: StyleCondition, style: impl Into<String>) -> Self {
+        self.style_rules.push(StyleRule::new(when, style));
+        self
+    }
+
+    /// Set a callback computing the style from the cell value.
+    pub fn style_fn(mut self, f: impl Fn(&str) -> Option<String> + Send + Sync + 'static) -> Self {
+        self.style_fn = Some(StyleFn::new(f));
+        self
+    }
+
     /// Set the data key.
     pub fn key(mut self, key: impl Into<String>) -> Self {
         self.key = Some(key.into());
         self
     }
 
+    /// Set the text used when the key path is missing or null.
+    pub fn default_value(mut self, default: impl Into<String>) -> Self {
+        self.default = Some(default.into());
+        self
+    }
+
+    /// Format extracted values with a callback.
+    pub fn format_fn(mut self, f: impl Fn(&Value) -> String + Send + Sync + 'static) -> Self {
+        self.format_fn = Some(FormatFn::new(f));
+        self
+    }
+
     /// Set the header title.
     pub fn header(mut self, header: impl Into<String>) -> Self {
         self.header = Some(header.into());
         self
     }
 
+    /// Show the column only in the named view.
+    pub fn only(mut self, view: impl Into<String>) -> Self {
+        self.only = Some(view.into());
+        self
+    }
+
     /// Set sub-columns for per-row width distribution within this column.
     pub fn sub_columns(mut self, sub_cols: SubColumns) -> Self {
         self.sub_columns = Some(sub_cols);
@@ -576,8 +854,13 @@ impl ColumnBuilder {
             null_repr: self.null_repr.unwrap_or(default.null_repr),
             style: self.style,
             style_from_value: self.style_from_value,
+            style_rules: self.style_rules,
+            style_fn: self.style_fn,
             key: self.key,
+            default: self.default,
+            format_fn: self.format_fn,
             header: self.header,
+            only: self.only,
             sub_columns: self.sub_columns,
         }
     }
@@ -921,6 +1204,47 @@ impl FlatDataSpec {
         self.columns.iter().any(|c| matches!(c.width, Width::Fill))
     }
 
+    /// Return a copy of the spec containing only the columns of a view.
+    ///
+    /// `None` selects the default view: every column without an
+    /// [`only`](Column::only) restriction. `Some("wide")` adds the columns
+    /// restricted to `"wide"` in their declared positions.
+    ///
+    /// ```rust
+    /// use standout_render::tabular::{Col, TabularSpec};
+    ///
+    /// let spec = TabularSpec::builder()
+    ///     .column(Col::fixed(8).named("id"))
+    ///     .column(Col::fixed(20).named("path").only("wide"))
+    ///     .column(Col::fill().named("title"))
+    ///     .build();
+    ///
+    /// assert_eq!(spec.for_view(None).num_columns(), 2);
+    /// assert_eq!(spec.for_view(Some("wide")).num_columns(), 3);
+    /// ```
+    pub fn for_view(&self, view: Option<&str>) -> FlatDataSpec {
+        FlatDataSpec {
+            columns: self
+                .columns
+                .iter()
+                .filter(|c| c.in_view(view))
+                .cloned()
+                .collect(),
+            decorations: self.decorations.clone(),
+        }
+    }
+
+    /// Names of the views referenced by columns, in declaration order.
+    pub fn views(&self) -> Vec<&str> {
+        let mut views: Vec<&str> = Vec::new();
+        for view in self.columns.iter().filter_map(|c| c.only.as_deref()) {
+            if !views.contains(&view) {
+                views.push(view);
+            }
+        }
+        views
+    }
+
     /// Extract a header row from the spec.
     ///
     /// Uses column `header` if present, otherwise `key`, otherwise empty string.
@@ -941,39 +1265,67 @@ impl FlatDataSpec {
     ///
     /// For each column:
     /// - If `key` is set, traverses the JSON to find the value.
-    /// - If `key` is unset/missing, uses `null_repr`.
-    /// - Handles nested objects via dot notation (e.g. "author.name").
+    /// - Handles nested objects via dot notation (e.g. "author.name") and
+    ///   array indexing (e.g. "tags[0]" or "items.0").
+    /// - Missing values use the column `default`, then `null_repr`.
     pub fn extract_row(&self, data: &Value) -> Vec<String> {
         self.columns
             .iter()
             .map(|col| {
-                if let Some(key) = &col.key {
-                    extract_value(data, key).unwrap_or(col.null_repr.clone())
-                } else {
-                    col.null_repr.clone()
-                }
+                col.key
+                    .as_deref()
+                    .and_then(|key| col.extract_cell(data, key))
+                    .unwrap_or_else(|| col.null_repr.clone())
             })
             .collect()
     }
 }
 
-/// Helper to extract a value from nested JSON using dot notation.
-fn extract_value(data: &Value, path: &str) -> Option<String> {
+/// Look up a key path in nested JSON.
+///
+/// Paths are dot-separated segments. A segment may be an object key, a
+/// numeric array index (`items.0`), or a key followed by bracketed indexes
+/// (`tags[0]`, `matrix[1][2]`). Object keys take precedence over indexes, so
+/// a map with a `"0"` key is still addressable.
+pub(crate) fn lookup_path<'a>(data: &'a Value, path: &str) -> Option<&'a Value> {
     let mut current = data;
-    for part in path.split('.') {
-        match current {
-            Value::Object(map) => {
-                current = map.get(part)?;
+    for segment in path.split('.') {
+        let (name, indexes) = match segment.find('[') {
+            Some(pos) => segment.split_at(pos),
+            None => (segment, ""),
+        };
+
+        if !name.is_empty() {
+            current = match current {
+                Value::Object(map) => map.get(name)?,
+                Value::Array(items) => items.get(name.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+
+        let mut rest = indexes;
+        while !rest.is_empty() {
+            let close = rest.find(']')?;
+            let index: usize = rest.get(1..close)?.trim().parse().ok()?;
+            current = current.as_array()?.get(index)?;
+            rest = &rest[close + 1..];
+            if !rest.is_empty() && !rest.starts_with('[') {
+                return None;
             }
-            _ => return None,
         }
     }
+    Some(current)
+}
 
-    match current {
-        Value::String(s) => Some(s.clone()),
-        Value::Null => None,
-        // For structured types, just jsonify them effectively
-        v => Some(v.to_string()),
+/// Convert an extracted JSON value to cell text.
+///
+/// Strings are used as-is; other scalars and structured values use their JSON
+/// representation.
+pub(crate) fn value_to_cell(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => String::new(),
+        v => v.to_string(),
     }
 }
 
@@ -1425,6 +1777,89 @@ mod tests {
         assert_eq!(row[3], "-"); // Default null_repr
     }
 
+    #[test]
+    fn extract_array_index_paths() {
+        let json = serde_json::json!({
+            "tags": ["urgent", "backend"],
+            "items": [{"name": "first"}, {"name": "second"}],
+            "matrix": [[1, 2], [3, 4]]
+        });
+
+        let spec = FlatDataSpec::builder()
+            .column(Column::new(Width::Fixed(10)).key("tags[0]"))
+            .column(Column::new(Width::Fixed(10)).key("items[1].name"))
+            .column(Column::new(Width::Fixed(10)).key("items.0.name"))
+            .column(Column::new(Width::Fixed(10)).key("matrix[1][0]"))
+            .column(Column::new(Width::Fixed(10)).key("tags[5]"))
+            .build();
+
+        let row = spec.extract_row(&json);
+        assert_eq!(row, vec!["urgent", "second", "first", "3", "-"]);
+    }
+
+    #[test]
+    fn extract_malformed_index_is_missing() {
+        let json = serde_json::json!({"tags": ["a"]});
+        assert!(lookup_path(&json, "tags[x]").is_none());
+        assert!(lookup_path(&json, "tags[0").is_none());
+        assert!(lookup_path(&json, "tags[0]x").is_none());
+        assert!(lookup_path(&json, "tags[0]").is_some());
+    }
+
+    #[test]
+    fn extract_default_for_missing_and_null() {
+        let json = serde_json::json!({"owner": null});
+
+        let spec = FlatDataSpec::builder()
+            .column(
+                Column::new(Width::Fixed(10))
+                    .key("owner")
+                    .default_value("nobody"),
+            )
+            .column(
+                Column::new(Width::Fixed(10))
+                    .key("labels[0]")
+                    .default_value("none"),
+            )
+            .column(Column::new(Width::Fixed(10)).key("owner"))
+            .build();
+
+        let row = spec.extract_row(&json);
+        assert_eq!(row, vec!["nobody", "none", "-"]);
+    }
+
+    #[test]
+    fn extract_with_format_fn() {
+        let json = serde_json::json!({"size": 4096, "tags": ["a", "b"]});
+
+        let spec = FlatDataSpec::builder()
+            .column(
+                Column::new(Width::Fixed(10))
+                    .key("size")
+                    .format_fn(|v| format!("{}K", v.as_u64().unwrap_or(0) / 1024)),
+            )
+            .column(Column::new(Width::Fixed(10)).key("tags").format_fn(|v| {
+                v.as_array()
+                    .map(|a| {
+                        a.iter()
+                            .filter_map(|t| t.as_str())
+                            .collect::<Vec<_>>()
+                            .join(",")
+                    })
+                    .unwrap_or_default()
+            }))
+            .column(
+                Column::new(Width::Fixed(10))
+                    .key("missing")
+                    .default_value("?")
+                    .format_fn(|_| "called".to_string()),
+            )
+            .build();
+
+        let row = spec.extract_row(&json);
+        assert_eq!(row, vec!["4K", "a,b", "?"]);
+    }
+
     #[test]
     fn extract_header_row() {
         let spec = FlatDataSpec::builder()
@@ -0,0 +1,256 @@
+//! Compiled-template cache for the render free functions.
+//!
+//! [`render`](super::render) and friends take a template source and a theme
+//! on every call, which used to mean building a MiniJinja environment,
+//! compiling the template and resolving the theme each time. Rendering a row
+//! template once per item paid that cost per row. The cache keeps the
+//! compiled template and the resolved, validated styles for recently used
+//! combinations of template source, theme and color mode, evicting the least
+//! recently used entry when full.
+
+use minijinja::Environment;
+use once_cell::sync::Lazy;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex};
+
+use super::engine::register_filters;
+use crate::error::RenderError;
+use crate::style::Styles;
+use crate::theme::{ColorMode, Theme};
+
+/// Number of entries the cache holds unless changed with
+/// [`set_template_cache_capacity`].
+pub const DEFAULT_TEMPLATE_CACHE_CAPACITY: usize = 64;
+
+/// Name the template is registered under in its entry's environment.
+const TEMPLATE_NAME: &str = "template";
+
+/// A compiled template with the styles it renders with.
+pub(crate) struct CachedTemplate {
+    source: String,
+    env: Environment<'static>,
+    styles: Styles,
+}
+
+impl CachedTemplate {
+    /// Renders the template with `ctx`.
+    pub(crate) fn render(&self, ctx: minijinja::Value) -> Result<String, RenderError> {
+        Ok(self.env.get_template(TEMPLATE_NAME)?.render(ctx)?)
+    }
+
+    /// The theme's styles, resolved for the entry's color mode.
+    pub(crate) fn styles(&self) -> &Styles {
+        &self.styles
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Key {
+    template: u64,
+    theme: u64,
+    color_mode: ColorMode,
+}
+
+struct TemplateCache {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<Key, (Arc<CachedTemplate>, u64)>,
+}
+
+impl TemplateCache {
+    fn get(&mut self, key: &Key, source: &str) -> Option<Arc<CachedTemplate>> {
+        self.tick += 1;
+        let tick = self.tick;
+        let (entry, used) = self.entries.get_mut(key)?;
+        // Keys hash the source; a collision is a miss
+        if entry.source != source {
+            return None;
+        }
+        *used = tick;
+        Some(entry.clone())
+    }
+
+    fn insert(&mut self, key: Key, entry: Arc<CachedTemplate>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if !self.entries.contains_key(&key) {
+            while self.entries.len() >= self.capacity {
+                self.evict_oldest();
+            }
+        }
+        self.tick += 1;
+        self.entries.insert(key, (entry, self.tick));
+    }
+
+    fn evict_oldest(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, (_, used))| *used)
+            .map(|(key, _)| *key);
+        if let Some(key) = oldest {
+            self.entries.remove(&key);
+        }
+    }
+}
+
+static CACHE: Lazy<Mutex<TemplateCache>> = Lazy::new(|| {
+    Mutex::new(TemplateCache {
+        capacity: DEFAULT_TEMPLATE_CACHE_CAPACITY,
+        tick: 0,
+        entries: HashMap::new(),
+    })
+});
+
+/// Sets how many compiled templates the render functions keep.
+///
+/// `0` turns the cache off. Shrinking it evicts the least recently used
+/// entries.
+pub fn set_template_cache_capacity(capacity: usize) {
+    let mut cache = CACHE.lock().unwrap();
+    cache.capacity = capacity;
+    while cache.entries.len() > capacity {
+        cache.evict_oldest();
+    }
+}
+
+/// Empties the compiled-template cache.
+pub fn clear_template_cache() {
+    CACHE.lock().unwrap().entries.clear();
+}
+
+/// Returns the compiled template for `source` with `theme`'s styles,
+/// compiling and caching it on a miss.
+///
+/// Fails if the theme has broken aliases or the template doesn't compile;
+/// failures aren't cached.
+pub(crate) fn compiled(
+    source: &str,
+    theme: &Theme,
+    color_mode: ColorMode,
+) -> Result<Arc<CachedTemplate>, RenderError> {
+    let mut hasher = DefaultHasher::new();
+    source.hash(&mut hasher);
+    let key = Key {
+        template: hasher.finish(),
+        theme: theme.fingerprint(),
+        color_mode,
+    };
+    if let Some(entry) = CACHE.lock().unwrap().get(&key, source) {
+        return Ok(entry);
+    }
+
+    theme
+        .validate()
+        .map_err(|e| RenderError::StyleError(e.to_string()))?;
+    let mut env = Environment::new();
+    register_filters(&mut env);
+    env.add_template_owned(TEMPLATE_NAME, source.to_string())?;
+    let entry = Arc::new(CachedTemplate {
+        source: source.to_string(),
+        env,
+        styles: theme.resolve_styles(Some(color_mode)),
+    });
+
+    CACHE.lock().unwrap().insert(key, entry.clone());
+    Ok(entry)
+}
+
+#[cfg(test)]
+pub(crate) fn cached_len() -> usize {
+    CACHE.lock().unwrap().entries.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::Style;
+    use serial_test::serial;
+
+    fn restore() {
+        set_template_cache_capacity(DEFAULT_TEMPLATE_CACHE_CAPACITY);
+        clear_template_cache();
+    }
+
+    #[test]
+    #[serial]
+    fn test_reuses_entry_for_same_source_and_theme() {
+        clear_template_cache();
+        let theme = Theme::new().add("ok", Style::new().green());
+
+        let first = compiled("{{ x }}", &theme, ColorMode::Dark).unwrap();
+        let again = compiled("{{ x }}", &theme.clone(), ColorMode::Dark).unwrap();
+        let rebuilt = Theme::new().add("ok", Style::new().green());
+        let equal_theme = compiled("{{ x }}", &rebuilt, ColorMode::Dark).unwrap();
+
+        assert!(Arc::ptr_eq(&first, &again));
+        assert!(Arc::ptr_eq(&first, &equal_theme));
+        assert_eq!(cached_len(), 1);
+        restore();
+    }
+
+    #[test]
+    #[serial]
+    fn test_theme_and_color_mode_are_part_of_the_key() {
+        clear_template_cache();
+        let green = Theme::new().add("ok", Style::new().green());
+        let red = Theme::new().add("ok", Style::new().red());
+
+        let a = compiled("{{ x }}", &green, ColorMode::Dark).unwrap();
+        let b = compiled("{{ x }}", &red, ColorMode::Dark).unwrap();
+        let c = compiled("{{ x }}", &green, ColorMode::Light).unwrap();
+
+        assert!(!Arc::ptr_eq(&a, &b));
+        assert!(!Arc::ptr_eq(&a, &c));
+        assert_eq!(cached_len(), 3);
+        restore();
+    }
+
+    #[test]
+    #[serial]
+    fn test_evicts_least_recently_used() {
+        clear_template_cache();
+        set_template_cache_capacity(2);
+        let theme = Theme::new();
+
+        let a = compiled("a", &theme, ColorMode::Dark).unwrap();
+        compiled("b", &theme, ColorMode::Dark).unwrap();
+        compiled("a", &theme, ColorMode::Dark).unwrap();
+        compiled("c", &theme, ColorMode::Dark).unwrap();
+
+        assert_eq!(cached_len(), 2);
+        assert!(Arc::ptr_eq(
+            &a,
+            &compiled("a", &theme, ColorMode::Dark).unwrap()
+        ));
+        restore();
+    }
+
+    #[test]
+    #[serial]
+    fn test_zero_capacity_disables_cache() {
+        clear_template_cache();
+        set_template_cache_capacity(0);
+
+        let theme = Theme::new();
+        compiled("a", &theme, ColorMode::Dark).unwrap();
+
+        assert_eq!(cached_len(), 0);
+        restore();
+    }
+
+    #[test]
+    #[serial]
+    fn test_errors_are_not_cached() {
+        clear_template_cache();
+        let broken = Theme::new().add("alias", "missing");
+
+        assert!(compiled("{{ x }}", &broken, ColorMode::Dark).is_err());
+        assert!(compiled("{{ x", &Theme::new(), ColorMode::Dark).is_err());
+        assert_eq!(cached_len(), 0);
+        restore();
+    }
+}
@@ -75,10 +75,11 @@ use serde::Serialize;
 use standout_bbparser::{BBParser, TagTransform, UnknownTagBehavior};
 use std::collections::HashMap;
 
+use super::cache;
 use super::engine::{MiniJinjaEngine, TemplateEngine};
 use crate::context::{ContextRegistry, RenderContext};
 use crate::error::RenderError;
-use crate::output::OutputMode;
+use crate::output::{CsvOptions, OutputMode};
 use crate::style::Styles;
 use crate::tabular::FlatDataSpec;
 use crate::theme::{detect_color_mode, detect_icon_mode, ColorMode, Theme};
@@ -97,9 +98,11 @@ fn output_mode_to_transform(mode: OutputMode) -> TagTransform {
         OutputMode::Text => TagTransform::Remove,
         OutputMode::TermDebug => TagTransform::Keep,
         // Structured modes shouldn't reach here (filtered out before)
-        OutputMode::Json | OutputMode::Yaml | OutputMode::Xml | OutputMode::Csv => {
-            TagTransform::Remove
-        }
+        OutputMode::Json
+        | OutputMode::Yaml
+        | OutputMode::Xml
+        | OutputMode::Toml
+        | OutputMode::Csv => TagTransform::Remove,
     }
 }
 
@@ -359,26 +362,25 @@ pub fn render_with_mode<T: Serialize>(
     output_mode: OutputMode,
     color_mode: ColorMode,
 ) -> Result<String, RenderError> {
-    // Validate style aliases before rendering
-    theme
-        .validate()
-        .map_err(|e| RenderError::StyleError(e.to_string()))?;
-
-    // Resolve styles for the specified color mode
-    let styles = theme.resolve_styles(Some(color_mode));
+    // Compiled template and validated styles, cached across calls
+    let compiled = cache::compiled(template, theme, color_mode)?;
 
     // Pass 1: Template rendering (with icons if defined)
-    let engine = MiniJinjaEngine::new();
     let data_value = serde_json::to_value(data)?;
     let icon_context = build_icon_context(theme);
     let template_output = if icon_context.is_empty() {
-        engine.render_template(template, &data_value)?
+        compiled.render(minijinja::Value::from_serialize(&data_value))?
     } else {
-        engine.render_with_context(template, &data_value, icon_context)?
+        // Data takes precedence over icons
+        let mut combined = icon_context;
+        if let serde_json::Value::Object(map) = data_value {
+            combined.extend(map);
+        }
+        compiled.render(minijinja::Value::from_serialize(&combined))?
     };
 
     // Pass 2: BBParser style tag processing
-    let final_output = apply_style_tags(&template_output, &styles, output_mode);
+    let final_output = apply_style_tags(&template_output, compiled.styles(), output_mode);
 
     Ok(final_output)
 }
@@ -518,17 +520,12 @@ pub fn render_auto<T: Serialize>(
             OutputMode::Json => Ok(serde_json::to_string_pretty(data)?),
             OutputMode::Yaml => Ok(serde_yaml::to_string(data)?),
             OutputMode::Xml => Ok(crate::util::serialize_to_xml(data)?),
+            OutputMode::Toml => Ok(crate::util::serialize_to_toml(data)?),
             OutputMode::Csv => {
                 let value = serde_json::to_value(data)?;
                 let (headers, rows) = crate::util::flatten_json_for_csv(&value);
 
-                let mut wtr = csv::Writer::from_writer(Vec::new());
-                wtr.write_record(&headers)?;
-                for row in rows {
-                    wtr.write_record(&row)?;
-                }
-                let bytes = wtr.into_inner()?;
-                Ok(String::from_utf8(bytes)?)
+                crate::util::format_csv(&headers, &rows, &CsvOptions::default())
             }
             _ => unreachable!("is_structured() returned true for non-structured mode"),
         }
@@ -562,6 +559,7 @@ pub fn render_auto_with_spec<T: Serialize>(
             OutputMode::Json => Ok(serde_json::to_string_pretty(data)?),
             OutputMode::Yaml => Ok(serde_yaml::to_string(data)?),
             OutputMode::Xml => Ok(crate::util::serialize_to_xml(data)?),
+            OutputMode::Toml => Ok(crate::util::serialize_to_toml(data)?),
             OutputMode::Csv => {
                 let value = serde_json::to_value(data)?;
 
@@ -580,13 +578,7 @@ pub fn render_auto_with_spec<T: Serialize>(
                     crate::util::flatten_json_for_csv(&value)
                 };
 
-                let mut wtr = csv::Writer::from_writer(Vec::new());
-                wtr.write_record(&headers)?;
-                for row in rows {
-                    wtr.write_record(&row)?;
-                }
-                let bytes = wtr.into_inner()?;
-                Ok(String::from_utf8(bytes)?)
+                crate::util::format_csv(&headers, &rows, &CsvOptions::default())
             }
             _ => unreachable!("is_structured() returned true for non-structured mode"),
         }
@@ -794,17 +786,12 @@ pub fn render_auto_with_context<T: Serialize>(
             OutputMode::Json => Ok(serde_json::to_string_pretty(data)?),
             OutputMode::Yaml => Ok(serde_yaml::to_string(data)?),
             OutputMode::Xml => Ok(crate::util::serialize_to_xml(data)?),
+            OutputMode::Toml => Ok(crate::util::serialize_to_toml(data)?),
             OutputMode::Csv => {
                 let value = serde_json::to_value(data)?;
                 let (headers, rows) = crate::util::flatten_json_for_csv(&value);
 
-                let mut wtr = csv::Writer::from_writer(Vec::new());
-                wtr.write_record(&headers)?;
-                for row in rows {
-                    wtr.write_record(&row)?;
-                }
-                let bytes = wtr.into_inner()?;
-                Ok(String::from_utf8(bytes)?)
+                crate::util::format_csv(&headers, &rows, &render_context.csv_options)
             }
             _ => unreachable!("is_structured() returned true for non-structured mode"),
         }
@@ -892,16 +879,11 @@ pub fn render_auto_with_engine(
             OutputMode::Json => Ok(serde_json::to_string_pretty(data)?),
             OutputMode::Yaml => Ok(serde_yaml::to_string(data)?),
             OutputMode::Xml => Ok(crate::util::serialize_to_xml(data)?),
+            OutputMode::Toml => Ok(crate::util::serialize_to_toml(data)?),
             OutputMode::Csv => {
                 let (headers, rows) = crate::util::flatten_json_for_csv(data);
 
-                let mut wtr = csv::Writer::from_writer(Vec::new());
-                wtr.write_record(&headers)?;
-                for row in rows {
-                    wtr.write_record(&row)?;
-                }
-                let bytes = wtr.into_inner()?;
-                Ok(String::from_utf8(bytes)?)
+                crate::util::format_csv(&headers, &rows, &render_context.csv_options)
             }
             _ => unreachable!("is_structured() returned true for non-structured mode"),
         }
@@ -960,16 +942,11 @@ pub fn render_auto_with_engine_split(
             OutputMode::Json => serde_json::to_string_pretty(data)?,
             OutputMode::Yaml => serde_yaml::to_string(data)?,
             OutputMode::Xml => crate::util::serialize_to_xml(data)?,
+            OutputMode::Toml => crate::util::serialize_to_toml(data)?,
             OutputMode::Csv => {
                 let (headers, rows) = crate::util::flatten_json_for_csv(data);
 
-                let mut wtr = csv::Writer::from_writer(Vec::new());
-                wtr.write_record(&headers)?;
-                for row in rows {
-                    wtr.write_record(&row)?;
-                }
-                let bytes = wtr.into_inner()?;
-                String::from_utf8(bytes)?
+                crate::util::format_csv(&headers, &rows, &render_context.csv_options)?
             }
             _ => unreachable!("is_structured() returned true for non-structured mode"),
         };
@@ -1431,7 +1408,7 @@ mod tests {
     }
 
     // ============================================================================
-    // YAML/XML/CSV Output Tests
+    // YAML/XML/TOML/CSV Output Tests
     // ============================================================================
 
     #[test]
@@ -1447,6 +1424,19 @@ mod tests {
         assert!(output.contains("count: 42"));
     }
 
+    #[test]
+    fn test_render_auto_toml_mode() {
+        use serde_json::json;
+
+        let theme = Theme::new();
+        let data = json!({"name": "test", "count": 42});
+
+        let output = render_auto("unused template", &data, &theme, OutputMode::Toml).unwrap();
+
+        assert!(output.contains("name = \"test\""));
+        assert!(output.contains("count = 42"));
+    }
+
     #[test]
     fn test_render_auto_xml_mode_named_struct() {
         let theme = Theme::new();
@@ -2294,6 +2284,38 @@ mod tests {
         assert!(output.contains("count: 42"));
     }
 
+    #[test]
+    fn test_render_auto_with_context_csv_options() {
+        use crate::context::{ContextRegistry, RenderContext};
+        use crate::output::CsvQuoting;
+        use serde_json::json;
+
+        let theme = Theme::new();
+        let data = json!([{"name": "tea", "price": 2.5}, {"name": "cake", "price": 3}]);
+
+        let registry = ContextRegistry::new();
+        let render_ctx = RenderContext::new(OutputMode::Csv, Some(80), &theme, &data)
+            .with_csv_options(
+                CsvOptions::new()
+                    .delimiter(b';')
+                    .quoting(CsvQuoting::Always)
+                    .headers(false),
+            );
+
+        let output = render_auto_with_context(
+            "unused template",
+            &data,
+            &theme,
+            OutputMode::Csv,
+            &registry,
+            &render_ctx,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(output, "\"tea\";\"2.5\"\n\"cake\";\"3\"\n");
+    }
+
     // =========================================================================
     // Icon integration tests
     // =========================================================================
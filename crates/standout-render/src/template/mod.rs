@@ -62,7 +62,7 @@
 //! | [`render_auto`] | CLI with `--output=json` support (skips template for structured modes) |
 //!
 //! The "auto" in [`render_auto`] refers to template-vs-serialization dispatch,
-//! not color detection. Structured modes (JSON, YAML, XML, CSV) serialize data
+//! not color detection. Structured modes (JSON, YAML, XML, TOML, CSV) serialize data
 //! directly, skipping the template entirely.
 //!
 //! ## Style Tags in Templates
@@ -105,13 +105,18 @@
 //! - [`crate::tabular`]: Column formatting utilities and template filters
 //! - [`crate::context`]: Context injection for templates
 
+mod cache;
 mod engine;
 pub mod filters;
 mod functions;
 pub mod registry;
 mod renderer;
 mod simple;
+mod styled;
 
+pub use cache::{
+    clear_template_cache, set_template_cache_capacity, DEFAULT_TEMPLATE_CACHE_CAPACITY,
+};
 pub use engine::{register_filters, MiniJinjaEngine, TemplateEngine};
 pub use functions::{
     apply_style_tags, render, render_auto, render_auto_with_context, render_auto_with_engine,
@@ -124,3 +129,4 @@ pub use registry::{
 };
 pub use renderer::Renderer;
 pub use simple::SimpleEngine;
+pub use styled::{render_styled, StyledArg};
@@ -29,6 +29,18 @@
 //! In release mode:
 //! - Templates can be embedded at compile time for deployment
 //! - Use [`Renderer::with_embedded`] to load pre-embedded templates
+//!
+//! # Threads
+//!
+//! `Renderer` is `Send + Sync`. Rendering takes `&mut self`, since templates
+//! are compiled and cached on first use, so threads sharing one renderer
+//! wrap it in a `Mutex`:
+//!
+//! ```rust,ignore
+//! let renderer = Arc::new(Mutex::new(renderer));
+//! let shared = Arc::clone(&renderer);
+//! std::thread::spawn(move || shared.lock().unwrap().render("report", &data));
+//! ```
 
 use std::collections::HashMap;
 use std::path::Path;
@@ -515,9 +527,11 @@ impl Renderer {
             OutputMode::Term => TagTransform::Apply,
             OutputMode::Text => TagTransform::Remove,
             OutputMode::TermDebug => TagTransform::Keep,
-            OutputMode::Json | OutputMode::Yaml | OutputMode::Xml | OutputMode::Csv => {
-                TagTransform::Remove
-            }
+            OutputMode::Json
+            | OutputMode::Yaml
+            | OutputMode::Xml
+            | OutputMode::Toml
+            | OutputMode::Csv => TagTransform::Remove,
         };
 
         let resolved_styles = self.styles.to_resolved_map();
@@ -567,6 +581,31 @@ mod tests {
     use std::io::Write;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_renderer_shared_across_threads() {
+        use std::sync::{Arc, Mutex};
+
+        let mut renderer = Renderer::with_output(Theme::new(), OutputMode::Text).unwrap();
+        renderer.add_template("greet", "Hi {{ message }}").unwrap();
+        let renderer = Arc::new(Mutex::new(renderer));
+
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let renderer = Arc::clone(&renderer);
+                std::thread::spawn(move || {
+                    let data = SimpleData {
+                        message: i.to_string(),
+                    };
+                    renderer.lock().unwrap().render("greet", &data).unwrap()
+                })
+            })
+            .collect();
+
+        for (i, handle) in handles.into_iter().enumerate() {
+            assert_eq!(handle.join().unwrap(), format!("Hi {}", i));
+        }
+    }
+
     #[derive(Serialize)]
     struct SimpleData {
         message: String,
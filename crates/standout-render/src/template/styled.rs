@@ -0,0 +1,92 @@
+//! One-off styled strings, without a template engine.
+//!
+//! The `styled!` macro (from `standout-macros`) formats its arguments like
+//! `format!` and applies the theme's style tags to the result:
+//!
+//! ```rust,ignore
+//! let line = styled!(theme, "[title]{}[/title]: {} items", name, count);
+//! ```
+//!
+//! Tag syntax is checked at compile time. Argument values are escaped, so
+//! brackets in them print literally instead of being read as tags.
+
+use std::fmt;
+
+use super::functions::apply_style_tags;
+use crate::output::OutputMode;
+use crate::theme::{detect_color_mode, Theme};
+
+/// Applies `theme`'s style tags to `text`.
+///
+/// Styles are applied when stdout supports color and removed otherwise,
+/// like [`OutputMode::Auto`]. As in templates, unknown tags are kept with a
+/// `?` marker when styles are applied.
+pub fn render_styled(text: &str, theme: &Theme) -> String {
+    let styles = theme.resolve_styles(Some(detect_color_mode()));
+    apply_style_tags(text, &styles, OutputMode::Auto)
+}
+
+/// Displays a value with `[` and `]` escaped, so style tags leave it alone.
+///
+/// Used by `styled!` for its arguments.
+#[doc(hidden)]
+pub struct StyledArg<'a, T: ?Sized>(pub &'a T);
+
+impl<T: fmt::Display + ?Sized> fmt::Display for StyledArg<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match f.precision().or(f.width()) {
+            // Apply width and precision before escaping
+            Some(_) => format_with_spec(self.0, f),
+            None => self.0.to_string(),
+        };
+        f.write_str(&text.replace('[', "\\[").replace(']', "\\]"))
+    }
+}
+
+fn format_with_spec<T: fmt::Display + ?Sized>(value: &T, f: &fmt::Formatter<'_>) -> String {
+    let text = value.to_string();
+    let text: String = match f.precision() {
+        Some(precision) => text.chars().take(precision).collect(),
+        None => text,
+    };
+    let width = f.width().unwrap_or(0);
+    let len = text.chars().count();
+    if len >= width {
+        return text;
+    }
+    let fill = f.fill().to_string().repeat(width - len);
+    match f.align() {
+        Some(fmt::Alignment::Right) => fill + &text,
+        Some(fmt::Alignment::Center) => {
+            let left = (width - len) / 2;
+            let (before, after) = fill.split_at(left * f.fill().len_utf8());
+            format!("{}{}{}", before, text, after)
+        }
+        _ => text + &fill,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::environment::{set_color_capability_detector, DetectorGuard};
+    use console::Style;
+    use serial_test::serial;
+
+    #[test]
+    fn test_styled_arg_escapes_brackets() {
+        assert_eq!(StyledArg("[x]").to_string(), "\\[x\\]");
+        assert_eq!(format!("{:>4}|", StyledArg("[")), "   \\[|");
+    }
+
+    #[test]
+    #[serial]
+    fn test_render_styled_removes_tags_without_color() {
+        let _guard = DetectorGuard::new();
+        set_color_capability_detector(|| false);
+        let theme = Theme::new().add("title", Style::new().bold());
+        let text = format!("[title]{}[/title] [other]x[/other]", StyledArg("[a]"));
+
+        assert_eq!(render_styled(&text, &theme), "[a] x");
+    }
+}
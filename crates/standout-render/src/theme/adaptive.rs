@@ -25,12 +25,13 @@
 //! set_theme_detector(|| ColorMode::Dark);
 //! ```
 
+#[cfg(not(target_family = "wasm"))]
 use dark_light::{detect as detect_os_theme, Mode as OsThemeMode};
 use once_cell::sync::Lazy;
 use std::sync::Mutex;
 
 /// The user's preferred color mode.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ColorMode {
     /// Light mode (light background, dark text).
     Light,
@@ -63,9 +64,15 @@ pub fn set_theme_detector(detector: ThemeDetector) {
     *guard = detector;
 }
 
+/// Restores the OS-backed theme detector replaced by [`set_theme_detector`].
+pub fn reset_theme_detector() {
+    set_theme_detector(os_theme_detector);
+}
+
 /// Detects the user's preferred color mode from the OS.
 ///
 /// Uses the `dark-light` crate to query the OS for the current theme preference.
+/// On wasm targets there is no OS to ask and the mode is [`ColorMode::Light`].
 /// The detector can be overridden via [`set_theme_detector`] for testing.
 ///
 /// # Returns
@@ -77,6 +84,7 @@ pub fn detect_color_mode() -> ColorMode {
     (*detector)()
 }
 
+#[cfg(not(target_family = "wasm"))]
 fn os_theme_detector() -> ColorMode {
     match detect_os_theme() {
         OsThemeMode::Dark => ColorMode::Dark,
@@ -84,6 +92,13 @@ fn os_theme_detector() -> ColorMode {
     }
 }
 
+/// There's no OS to ask on wasm; hosts that know the page's scheme can set
+/// it with [`set_theme_detector`].
+#[cfg(target_family = "wasm")]
+fn os_theme_detector() -> ColorMode {
+    ColorMode::Light
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -80,8 +80,10 @@ mod icon_def;
 mod icon_mode;
 #[allow(clippy::module_inception)]
 mod theme;
+mod theme_def;
 
-pub use adaptive::{detect_color_mode, set_theme_detector, ColorMode};
+pub use adaptive::{detect_color_mode, reset_theme_detector, set_theme_detector, ColorMode};
 pub use icon_def::{IconDefinition, IconSet};
 pub use icon_mode::{detect_icon_mode, set_icon_detector, IconMode};
 pub use theme::Theme;
+pub use theme_def::ThemeDef;
@@ -59,15 +59,20 @@
 //! Use [`resolve_styles`](Theme::resolve_styles) to get a `Styles` collection
 //! for a specific color mode. This is typically called during rendering.
 
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::hash::{Hash, Hasher};
 use std::path::{Path, PathBuf};
 
 use console::Style;
 
 use crate::colorspace::ThemePalette;
+use crate::Capabilities;
 
 use super::super::style::{
-    parse_stylesheet, StyleValidationError, StyleValue, Styles, StylesheetError, ThemeVariants,
+    parse_stylesheet, ConditionalStyle, StyleValidationError, StyleValue, Styles, StylesheetError,
+    ThemeVariants,
 };
 
 use super::adaptive::ColorMode;
@@ -127,6 +132,8 @@ pub struct Theme {
     dark: HashMap<String, Style>,
     /// Alias definitions (name → target).
     aliases: HashMap<String, String>,
+    /// Conditions on styles, checked when styles are resolved.
+    conditions: HashMap<String, ConditionalStyle>,
     /// Icon definitions (classic + optional nerdfont variants).
     icons: IconSet,
     /// Theme palette for resolving [`ColorDef::Cube`] colors.
@@ -143,6 +150,7 @@ impl Theme {
             light: HashMap::new(),
             dark: HashMap::new(),
             aliases: HashMap::new(),
+            conditions: HashMap::new(),
             icons: IconSet::new(),
             palette: None,
         }
@@ -157,6 +165,7 @@ impl Theme {
             light: HashMap::new(),
             dark: HashMap::new(),
             aliases: HashMap::new(),
+            conditions: HashMap::new(),
             icons: IconSet::new(),
             palette: None,
         }
@@ -233,6 +242,7 @@ impl Theme {
             light: variants.light().clone(),
             dark: variants.dark().clone(),
             aliases: variants.aliases().clone(),
+            conditions: variants.conditions().clone(),
             icons,
             palette: None,
         })
@@ -278,6 +288,7 @@ impl Theme {
             light: variants.light().clone(),
             dark: variants.dark().clone(),
             aliases: variants.aliases().clone(),
+            conditions: variants.conditions().clone(),
             icons,
             palette: None,
         })
@@ -312,6 +323,7 @@ impl Theme {
             light: variants.light().clone(),
             dark: variants.dark().clone(),
             aliases: variants.aliases().clone(),
+            conditions: variants.conditions().clone(),
             icons: IconSet::new(),
             palette: None,
         })
@@ -353,6 +365,7 @@ impl Theme {
             light: variants.light().clone(),
             dark: variants.dark().clone(),
             aliases: variants.aliases().clone(),
+            conditions: variants.conditions().clone(),
             icons: IconSet::new(),
             palette: None,
         })
@@ -367,6 +380,7 @@ impl Theme {
             light: variants.light().clone(),
             dark: variants.dark().clone(),
             aliases: variants.aliases().clone(),
+            conditions: variants.conditions().clone(),
             icons: IconSet::new(),
             palette: None,
         }
@@ -422,6 +436,7 @@ impl Theme {
         self.light = variants.light().clone();
         self.dark = variants.dark().clone();
         self.aliases = variants.aliases().clone();
+        self.conditions = variants.conditions().clone();
         self.icons = icons;
 
         Ok(())
@@ -454,6 +469,7 @@ impl Theme {
     ///     .add("timestamp", "disabled");
     /// ```
     pub fn add<V: Into<StyleValue>>(mut self, name: &str, value: V) -> Self {
+        self.conditions.remove(name);
         match value.into() {
             StyleValue::Concrete(style) => {
                 self.base.insert(name.to_string(), style);
@@ -492,6 +508,7 @@ impl Theme {
         light: Option<Style>,
         dark: Option<Style>,
     ) -> Self {
+        self.conditions.remove(name);
         self.base.insert(name.to_string(), base);
         if let Some(light_style) = light {
             self.light.insert(name.to_string(), light_style);
@@ -597,6 +614,16 @@ impl Theme {
             styles = styles.add(name, target.clone());
         }
 
+        // Fall back where a style's condition doesn't hold
+        if !self.conditions.is_empty() {
+            let caps = Capabilities::detect();
+            for (name, conditional) in &self.conditions {
+                if !conditional.when.holds(&caps) {
+                    styles = styles.add(name, conditional.otherwise.clone());
+                }
+            }
+        }
+
         styles
     }
 
@@ -609,6 +636,48 @@ impl Theme {
         self.resolve_styles(None).validate()
     }
 
+    /// Returns a hash of the style definitions, used to key caches.
+    ///
+    /// Equal definitions give equal fingerprints, however the themes were
+    /// built; the name, source path and icons don't count.
+    pub(crate) fn fingerprint(&self) -> u64 {
+        let mut hasher = FmtHasher(DefaultHasher::new());
+        for (section, map) in [
+            ("base", &self.base),
+            ("light", &self.light),
+            ("dark", &self.dark),
+        ] {
+            let mut names: Vec<_> = map.keys().collect();
+            names.sort();
+            for name in names {
+                let _ = write!(hasher, "{}\0{}\0{:?}\0", section, name, map[name]);
+            }
+        }
+        let mut aliases: Vec<_> = self.aliases.iter().collect();
+        aliases.sort();
+        for (name, target) in aliases {
+            let _ = write!(hasher, "alias\0{}\0{}\0", name, target);
+        }
+        // Resolved styles depend on whether conditions hold right now
+        if !self.conditions.is_empty() {
+            let caps = Capabilities::detect();
+            let mut names: Vec<_> = self.conditions.keys().collect();
+            names.sort();
+            for name in names {
+                let conditional = &self.conditions[name];
+                let _ = write!(
+                    hasher,
+                    "when\0{}\0{:?}\0{:?}\0{}\0",
+                    name,
+                    conditional.when,
+                    conditional.otherwise,
+                    conditional.when.holds(&caps)
+                );
+            }
+        }
+        hasher.0.finish()
+    }
+
     /// Returns true if no styles are defined.
     pub fn is_empty(&self) -> bool {
         self.base.is_empty() && self.aliases.is_empty()
@@ -660,6 +729,11 @@ impl Theme {
     /// // "text" is now bold (from user)
     /// ```
     pub fn merge(mut self, other: Theme) -> Self {
+        // A style redefined by `other` drops the condition it had here
+        for name in other.base.keys().chain(other.aliases.keys()) {
+            self.conditions.remove(name);
+        }
+        self.conditions.extend(other.conditions);
         self.base.extend(other.base);
         self.light.extend(other.light);
         self.dark.extend(other.dark);
@@ -838,6 +912,16 @@ fn parse_icons_from_yaml(root: &serde_yaml::Value) -> Result<IconSet, Stylesheet
     Ok(icon_set)
 }
 
+/// Feeds formatted text to a hasher, so styles hash through `Debug`.
+struct FmtHasher(DefaultHasher);
+
+impl std::fmt::Write for FmtHasher {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        s.hash(&mut self.0);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1200,6 +1284,55 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_theme_conditional_styles_follow_environment() {
+        use crate::environment::{set_terminal_width_detector, DetectorGuard};
+
+        let _guard = DetectorGuard::new();
+        let theme = Theme::from_yaml(
+            r#"
+            banner:
+              fg: white
+              bg: blue
+              when:
+                min_width: 80
+              else: bold
+            plain:
+              bg: red
+              when:
+                max_width: 40
+            "#,
+        )
+        .unwrap();
+        let banner = |theme: &Theme| theme.get_style("banner", None).unwrap();
+
+        set_terminal_width_detector(|| Some(120));
+        assert_eq!(
+            format!("{:?}", banner(&theme)),
+            format!("{:?}", Style::new().white().on_blue())
+        );
+        let wide = theme.fingerprint();
+
+        set_terminal_width_detector(|| Some(60));
+        assert_eq!(
+            format!("{:?}", banner(&theme)),
+            format!("{:?}", Style::new().bold())
+        );
+        assert_eq!(
+            format!("{:?}", theme.get_style("plain", None).unwrap()),
+            format!("{:?}", Style::new())
+        );
+        assert_ne!(theme.fingerprint(), wide);
+
+        // Redefining the style drops its condition
+        let merged = theme.merge(Theme::new().add("banner", Style::new().green()));
+        assert_eq!(
+            format!("{:?}", banner(&merged)),
+            format!("{:?}", Style::new().green())
+        );
+    }
+
     #[test]
     fn test_theme_merge() {
         let base = Theme::new()
@@ -0,0 +1,32 @@
+//! Themes defined by a struct of styles.
+
+use super::Theme;
+
+/// A struct whose fields are the styles of a [`Theme`].
+///
+/// Usually derived with `#[derive(ThemeDef)]` (from `standout-macros`),
+/// which also adds a constant per field holding its style name, so code
+/// and templates can refer to styles without repeating string literals:
+///
+/// ```rust,ignore
+/// #[derive(ThemeDef)]
+/// struct AppTheme {
+///     #[style(light = Style::new().black(), dark = Style::new().white())]
+///     title: Style,
+///     muted: Style,
+/// }
+///
+/// let theme: Theme = AppTheme {
+///     title: Style::new().bold(),
+///     muted: Style::new().dim(),
+/// }
+/// .into();
+/// assert_eq!(AppTheme::TITLE, "title");
+/// ```
+pub trait ThemeDef {
+    /// The style names, in field order.
+    const STYLE_NAMES: &'static [&'static str];
+
+    /// Builds a theme with one style per field.
+    fn to_theme(&self) -> Theme;
+}
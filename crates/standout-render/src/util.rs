@@ -1,9 +1,14 @@
 //! Utility functions for text processing and color conversion.
 
+use console::Style;
 use serde::Serialize;
 use serde_json::Value;
 use std::collections::{BTreeMap, BTreeSet};
 
+use crate::error::RenderError;
+use crate::output::{CsvOptions, CsvQuoting};
+use crate::style::Styles;
+
 /// Converts an RGB triplet to the nearest ANSI 256-color palette index.
 ///
 /// # Example
@@ -117,6 +122,136 @@ pub fn serialize_to_xml<T: Serialize + ?Sized>(data: &T) -> Result<String, quick
     }
 }
 
+/// Serializes data to TOML, handling all serializable types.
+///
+/// Structs and maps serialize directly as a TOML document. A TOML document
+/// must be a table, so anything else is wrapped under a `value` key: arrays
+/// become `value = [...]` (or `[[value]]` tables for lists of records) and
+/// primitives become `value = ...`. TOML has no null, so null fields and
+/// array elements are omitted, and a null value produces an empty document.
+pub fn serialize_to_toml<T: Serialize + ?Sized>(data: &T) -> Result<String, toml::ser::Error> {
+    // Direct serialization works for structs and maps without nulls
+    if let Ok(toml) = toml::to_string_pretty(data) {
+        return Ok(toml);
+    }
+    let value = serde_json::to_value(data).unwrap_or(serde_json::Value::Null);
+    match strip_nulls(&value) {
+        serde_json::Value::Null => Ok(String::new()),
+        table @ serde_json::Value::Object(_) => toml::to_string_pretty(&table),
+        other => {
+            let mut map = serde_json::Map::new();
+            map.insert("value".to_string(), other);
+            toml::to_string_pretty(&serde_json::Value::Object(map))
+        }
+    }
+}
+
+/// Recursively removes null object fields and array elements.
+fn strip_nulls(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => serde_json::Value::Object(
+            map.iter()
+                .filter(|(_, v)| !v.is_null())
+                .map(|(k, v)| (k.clone(), strip_nulls(v)))
+                .collect(),
+        ),
+        serde_json::Value::Array(arr) => serde_json::Value::Array(
+            arr.iter()
+                .filter(|v| !v.is_null())
+                .map(strip_nulls)
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Pretty-prints JSON with syntax highlighting, the way `jq` does on a terminal.
+///
+/// Layout matches `serde_json::to_string_pretty`. Tokens are styled with the
+/// `json_key`, `json_string`, `json_number`, `json_bool` and `json_null`
+/// styles from `styles`; any the theme doesn't define fall back to jq-like
+/// defaults (blue bold keys, green strings). Punctuation is left unstyled.
+///
+/// This always emits ANSI codes (subject to `console`'s global color
+/// setting): decide whether the destination is a color-capable terminal
+/// before calling it.
+///
+/// # Example
+///
+/// ```rust
+/// use standout_render::{colorize_json, Styles};
+/// use console::Style;
+///
+/// let styles = Styles::new().add("json_key", Style::new().yellow());
+/// let value = serde_json::json!({"name": "standout"});
+///
+/// let colored = colorize_json(&value, &styles);
+/// println!("{}", colored);
+/// ```
+pub fn colorize_json(value: &Value, styles: &Styles) -> String {
+    let resolved = styles.to_resolved_map();
+    let style = |name: &str, fallback: Style| resolved.get(name).cloned().unwrap_or(fallback);
+    let palette = JsonPalette {
+        key: style("json_key", Style::new().blue().bold()),
+        string: style("json_string", Style::new().green()),
+        number: style("json_number", Style::new()),
+        boolean: style("json_bool", Style::new()),
+        null: style("json_null", Style::new().dim()),
+    };
+
+    let mut out = String::new();
+    write_colored_json(&mut out, value, &palette, 0);
+    out
+}
+
+/// Resolved styles for each JSON token kind.
+struct JsonPalette {
+    key: Style,
+    string: Style,
+    number: Style,
+    boolean: Style,
+    null: Style,
+}
+
+fn write_colored_json(out: &mut String, value: &Value, palette: &JsonPalette, depth: usize) {
+    const INDENT: &str = "  ";
+    match value {
+        Value::Null => out.push_str(&palette.null.apply_to("null").to_string()),
+        Value::Bool(b) => out.push_str(&palette.boolean.apply_to(b).to_string()),
+        Value::Number(n) => out.push_str(&palette.number.apply_to(n).to_string()),
+        Value::String(s) => out.push_str(&palette.string.apply_to(quote_json(s)).to_string()),
+        Value::Array(items) if items.is_empty() => out.push_str("[]"),
+        Value::Object(map) if map.is_empty() => out.push_str("{}"),
+        Value::Array(items) => {
+            out.push_str("[\n");
+            for (i, item) in items.iter().enumerate() {
+                out.push_str(&INDENT.repeat(depth + 1));
+                write_colored_json(out, item, palette, depth + 1);
+                out.push_str(if i + 1 < items.len() { ",\n" } else { "\n" });
+            }
+            out.push_str(&INDENT.repeat(depth));
+            out.push(']');
+        }
+        Value::Object(map) => {
+            out.push_str("{\n");
+            for (i, (key, item)) in map.iter().enumerate() {
+                out.push_str(&INDENT.repeat(depth + 1));
+                out.push_str(&palette.key.apply_to(quote_json(key)).to_string());
+                out.push_str(": ");
+                write_colored_json(out, item, palette, depth + 1);
+                out.push_str(if i + 1 < map.len() { ",\n" } else { "\n" });
+            }
+            out.push_str(&INDENT.repeat(depth));
+            out.push('}');
+        }
+    }
+}
+
+/// Quotes and escapes a string as a JSON string literal.
+fn quote_json(s: &str) -> String {
+    Value::String(s.to_string()).to_string()
+}
+
 /// Recursively sanitizes JSON object keys to be valid XML element names.
 fn sanitize_xml_keys(value: &serde_json::Value) -> serde_json::Value {
     match value {
@@ -164,6 +299,47 @@ fn sanitize_xml_name(name: &str) -> String {
     result
 }
 
+/// Writes a header and rows as CSV text using the given options.
+///
+/// The header row is skipped when `options.headers` is false.
+///
+/// # Example
+///
+/// ```rust
+/// use standout_render::{format_csv, CsvOptions};
+///
+/// let headers = vec!["name".to_string(), "price".to_string()];
+/// let rows = vec![vec!["tea".to_string(), "2,50".to_string()]];
+///
+/// let csv = format_csv(&headers, &rows, &CsvOptions::new().delimiter(b';')).unwrap();
+/// assert_eq!(csv, "name;price\ntea;2,50\n");
+/// ```
+pub fn format_csv(
+    headers: &[String],
+    rows: &[Vec<String>],
+    options: &CsvOptions,
+) -> Result<String, RenderError> {
+    let quote_style = match options.quoting {
+        CsvQuoting::Necessary => csv::QuoteStyle::Necessary,
+        CsvQuoting::Always => csv::QuoteStyle::Always,
+        CsvQuoting::NonNumeric => csv::QuoteStyle::NonNumeric,
+        CsvQuoting::Never => csv::QuoteStyle::Never,
+    };
+    let mut wtr = csv::WriterBuilder::new()
+        .delimiter(options.delimiter)
+        .quote_style(quote_style)
+        .from_writer(Vec::new());
+
+    if options.headers {
+        wtr.write_record(headers)?;
+    }
+    for row in rows {
+        wtr.write_record(row)?;
+    }
+    let bytes = wtr.into_inner()?;
+    Ok(String::from_utf8(bytes)?)
+}
+
 /// Flattens a JSON Value into a list of records for CSV export.
 ///
 /// Returns a tuple of `(headers, rows)`, where rows are vectors of strings corresponding to headers.
@@ -409,6 +585,153 @@ mod tests {
         assert!(xml.contains("<value>"));
     }
 
+    #[test]
+    fn test_serialize_to_toml_struct() {
+        #[derive(serde::Serialize)]
+        struct User {
+            name: String,
+            age: u32,
+            email: Option<String>,
+        }
+
+        let data = User {
+            name: "Alice".into(),
+            age: 30,
+            email: None,
+        };
+        let toml = serialize_to_toml(&data).unwrap();
+        assert!(toml.contains("name = \"Alice\""));
+        assert!(toml.contains("age = 30"));
+        assert!(!toml.contains("email"));
+    }
+
+    #[test]
+    fn test_serialize_to_toml_nested_table() {
+        let data = serde_json::json!({"user": {"name": "Bob"}, "tags": ["a", "b"]});
+        let toml = serialize_to_toml(&data).unwrap();
+        assert!(toml.contains("[user]"));
+        assert!(toml.contains("name = \"Bob\""));
+        assert!(toml.contains("tags = ["));
+    }
+
+    #[test]
+    fn test_serialize_to_toml_null_fields_omitted() {
+        let data = serde_json::json!({"name": "x", "owner": null, "ids": [1, null, 2]});
+        let toml = serialize_to_toml(&data).unwrap();
+        assert!(toml.contains("name = \"x\""));
+        assert!(!toml.contains("owner"));
+        let parsed: toml::Value = toml::from_str(&toml).unwrap();
+        assert_eq!(parsed["ids"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_serialize_to_toml_array_of_records() {
+        let data = serde_json::json!([{"id": 1}, {"id": 2}]);
+        let toml = serialize_to_toml(&data).unwrap();
+        assert_eq!(toml.matches("[[value]]").count(), 2);
+        assert!(toml.contains("id = 2"));
+    }
+
+    #[test]
+    fn test_serialize_to_toml_bare_values() {
+        assert_eq!(
+            serialize_to_toml(&serde_json::json!("hello"))
+                .unwrap()
+                .trim(),
+            "value = \"hello\""
+        );
+        assert_eq!(serialize_to_toml(&42).unwrap().trim(), "value = 42");
+        assert_eq!(serialize_to_toml(&serde_json::Value::Null).unwrap(), "");
+    }
+
+    #[test]
+    fn test_colorize_json_layout_matches_pretty() {
+        let value = serde_json::json!({
+            "name": "a \"quoted\" name",
+            "count": 3,
+            "ok": true,
+            "none": null,
+            "tags": ["x", 1.5],
+            "empty": [],
+            "nested": {"inner": {}}
+        });
+        let plain = Styles::new()
+            .add("json_key", Style::new())
+            .add("json_string", Style::new())
+            .add("json_number", Style::new())
+            .add("json_bool", Style::new())
+            .add("json_null", Style::new());
+
+        assert_eq!(
+            colorize_json(&value, &plain),
+            serde_json::to_string_pretty(&value).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_colorize_json_applies_theme_styles() {
+        let value = serde_json::json!({"name": "alice", "age": 30});
+        let styles = Styles::new()
+            .add("json_key", Style::new().red().force_styling(true))
+            .add("json_string", Style::new().green().force_styling(true))
+            .add("json_number", Style::new().cyan().force_styling(true));
+
+        let colored = colorize_json(&value, &styles);
+        assert!(colored.contains("\x1b[31m\"name\"\x1b[0m"));
+        assert!(colored.contains("\x1b[32m\"alice\"\x1b[0m"));
+        assert!(colored.contains("\x1b[36m30\x1b[0m"));
+        assert_eq!(
+            console::strip_ansi_codes(&colored),
+            serde_json::to_string_pretty(&value).unwrap()
+        );
+    }
+
+    fn csv_fixture() -> (Vec<String>, Vec<Vec<String>>) {
+        let headers = vec!["name".to_string(), "price".to_string()];
+        let rows = vec![
+            vec!["tea".to_string(), "2.5".to_string()],
+            vec!["scones, plain".to_string(), "3".to_string()],
+        ];
+        (headers, rows)
+    }
+
+    #[test]
+    fn test_format_csv_defaults() {
+        let (headers, rows) = csv_fixture();
+        let csv = format_csv(&headers, &rows, &CsvOptions::default()).unwrap();
+        assert_eq!(csv, "name,price\ntea,2.5\n\"scones, plain\",3\n");
+    }
+
+    #[test]
+    fn test_format_csv_semicolon_delimiter() {
+        let (headers, rows) = csv_fixture();
+        let csv = format_csv(&headers, &rows, &CsvOptions::new().delimiter(b';')).unwrap();
+        assert_eq!(csv, "name;price\ntea;2.5\nscones, plain;3\n");
+    }
+
+    #[test]
+    fn test_format_csv_tab_without_headers() {
+        let (headers, rows) = csv_fixture();
+        let options = CsvOptions::new().delimiter(b'\t').headers(false);
+        let csv = format_csv(&headers, &rows, &options).unwrap();
+        assert_eq!(csv, "tea\t2.5\nscones, plain\t3\n");
+    }
+
+    #[test]
+    fn test_format_csv_quoting_styles() {
+        let (headers, rows) = csv_fixture();
+
+        let always = CsvOptions::new().quoting(CsvQuoting::Always).headers(false);
+        let csv = format_csv(&headers, &rows[..1], &always).unwrap();
+        assert_eq!(csv, "\"tea\",\"2.5\"\n");
+
+        let non_numeric = CsvOptions::new()
+            .quoting(CsvQuoting::NonNumeric)
+            .headers(false);
+        let csv = format_csv(&headers, &rows[..1], &non_numeric).unwrap();
+        assert_eq!(csv, "\"tea\",2.5\n");
+    }
+
     #[test]
     fn test_serialize_to_xml_numeric_keys() {
         let data = serde_json::json!({"0": "zero", "1": "one"});
@@ -160,7 +160,7 @@ fn style_for_stderr(
 /// Decides whether the warnings block should use ANSI styling.
 ///
 /// `OutputMode::Text` explicitly opts out of color. Structured modes
-/// (`Json`/`Yaml`/`Xml`/`Csv`) target stdout, not stderr, so they don't
+/// (`Json`/`Yaml`/`Xml`/`Toml`/`Csv`) target stdout, not stderr, so they don't
 /// constrain our styling choices here — stderr TTY capability is what
 /// matters. `TermDebug` emits bracket tags instead of ANSI in the main
 /// output, but the warnings banner isn't subject to that contract, so we
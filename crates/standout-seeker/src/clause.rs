@@ -3,9 +3,13 @@
 //! A [`Clause`] represents a single filter predicate: a field name,
 //! an operator, and a comparison value.
 
+use std::cmp::Ordering::{Equal, Greater, Less};
+use std::ops::RangeInclusive;
+
 use regex::Regex;
 
 use crate::op::Op;
+use crate::parse::{parse_date_only, parse_datetime};
 use crate::value::{Number, Timestamp, Value};
 
 /// A single filter predicate.
@@ -27,6 +31,7 @@ use crate::value::{Number, Timestamp, Value};
 /// };
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Clause {
     /// The field name to compare.
     pub field: String,
@@ -50,7 +55,16 @@ impl Clause {
     ///
     /// Returns `true` if the value matches the clause's predicate.
     /// Returns `false` if the value doesn't match or if the types are incompatible.
+    /// A string value compared against a number or timestamp clause is parsed
+    /// as that type first, so `"42"` matches `gt 40`.
     pub fn matches(&self, field_value: &Value<'_>) -> bool {
+        // Presence checks take a boolean: `Exists` with `false` means missing
+        if matches!(self.op, Op::Exists | Op::IsNull) {
+            let expected = !matches!(self.value, ClauseValue::Bool(false));
+            let present = !field_value.is_none();
+            return (present == (self.op == Op::Exists)) == expected;
+        }
+
         match (&self.value, field_value) {
             // String comparisons
             (ClauseValue::String(pattern), Value::String(s)) => self.match_string(s, pattern),
@@ -68,6 +82,24 @@ impl Clause {
                 self.match_timestamp(*field_ts, *clause_ts)
             }
 
+            // Set membership
+            (ClauseValue::StringSet(set), Value::String(s)) => {
+                self.op == Op::In && set.iter().any(|item| item == s)
+            }
+            (ClauseValue::NumberSet(set), Value::Number(n)) => {
+                self.op == Op::In && set.iter().any(|item| item.compare(*n) == Some(Equal))
+            }
+
+            // Inclusive ranges
+            (ClauseValue::NumberRange(lo, hi), Value::Number(n)) => {
+                self.op == Op::Between
+                    && n.compare(*lo).is_some_and(|o| o != Less)
+                    && n.compare(*hi).is_some_and(|o| o != Greater)
+            }
+            (ClauseValue::TimestampRange(lo, hi), Value::Timestamp(ts)) => {
+                self.op == Op::Between && lo <= ts && ts <= hi
+            }
+
             // Enum comparisons
             (ClauseValue::Enum(clause_disc), Value::Enum(field_disc)) => {
                 self.match_enum(*field_disc, *clause_disc)
@@ -90,6 +122,16 @@ impl Clause {
                 false
             }
 
+            // Loosely typed sources (JSON, CSV) store numbers and dates as
+            // strings; read them as the clause's type
+            (
+                ClauseValue::Number(_) | ClauseValue::NumberSet(_) | ClauseValue::NumberRange(..),
+                Value::String(s),
+            ) => coerce_number(s).is_some_and(|n| self.matches(&Value::Number(n))),
+            (ClauseValue::Timestamp(_) | ClauseValue::TimestampRange(..), Value::String(s)) => {
+                coerce_timestamp(s).is_some_and(|ts| self.matches(&Value::Timestamp(ts)))
+            }
+
             // Type mismatch - doesn't match
             _ => false,
         }
@@ -102,6 +144,9 @@ impl Clause {
             Op::StartsWith => field.starts_with(pattern),
             Op::EndsWith => field.ends_with(pattern),
             Op::Contains => field.contains(pattern),
+            Op::IEq => field.to_lowercase() == pattern.to_lowercase(),
+            Op::IContains => field.to_lowercase().contains(&pattern.to_lowercase()),
+            Op::Fuzzy => fuzzy_match(field, pattern),
             // Regex handled separately
             _ => false,
         }
@@ -148,6 +193,8 @@ impl Clause {
 /// Unlike [`Value`], which borrows from the source struct, `ClauseValue`
 /// owns its data so it can be stored in query definitions.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum ClauseValue {
     /// String value.
     String(String),
@@ -159,9 +206,18 @@ pub enum ClauseValue {
     Enum(u32),
     /// Set of enum discriminants (for `In` operator).
     EnumSet(Vec<u32>),
+    /// Set of strings (for `In` operator).
+    StringSet(Vec<String>),
+    /// Set of numbers (for `In` operator).
+    NumberSet(Vec<Number>),
+    /// Inclusive numeric range (for `Between` operator).
+    NumberRange(Number, Number),
+    /// Inclusive timestamp range (for `Between` operator).
+    TimestampRange(Timestamp, Timestamp),
     /// Boolean value.
     Bool(bool),
-    /// Compiled regular expression.
+    /// Compiled regular expression. Serialized as its pattern.
+    #[cfg_attr(feature = "serde", serde(with = "regex_pattern"))]
     Regex(Regex),
 }
 
@@ -283,6 +339,78 @@ impl From<Vec<u32>> for ClauseValue {
     }
 }
 
+impl From<Vec<String>> for ClauseValue {
+    fn from(v: Vec<String>) -> Self {
+        ClauseValue::StringSet(v)
+    }
+}
+
+impl From<Vec<&str>> for ClauseValue {
+    fn from(v: Vec<&str>) -> Self {
+        ClauseValue::StringSet(v.into_iter().map(String::from).collect())
+    }
+}
+
+impl<T: Into<Number>> From<RangeInclusive<T>> for ClauseValue {
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (lo, hi) = range.into_inner();
+        ClauseValue::NumberRange(lo.into(), hi.into())
+    }
+}
+
+impl From<RangeInclusive<Timestamp>> for ClauseValue {
+    fn from(range: RangeInclusive<Timestamp>) -> Self {
+        let (lo, hi) = range.into_inner();
+        ClauseValue::TimestampRange(lo, hi)
+    }
+}
+
+impl From<Vec<Number>> for ClauseValue {
+    fn from(v: Vec<Number>) -> Self {
+        ClauseValue::NumberSet(v)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod regex_pattern {
+    use regex::Regex;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(regex: &Regex, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(regex.as_str())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Regex, D::Error> {
+        let pattern = String::deserialize(deserializer)?;
+        Regex::new(&pattern).map_err(serde::de::Error::custom)
+    }
+}
+
+fn coerce_number(s: &str) -> Option<Number> {
+    let s = s.trim();
+    s.parse::<i64>()
+        .map(Number::I64)
+        .or_else(|_| s.parse::<u64>().map(Number::U64))
+        .or_else(|_| s.parse::<f64>().map(Number::F64))
+        .ok()
+}
+
+fn coerce_timestamp(s: &str) -> Option<Timestamp> {
+    let s = s.trim();
+    parse_date_only(s).or_else(|| parse_datetime(s))
+}
+
+/// Returns `true` if `text` contains every character of `pattern`, in
+/// order, ignoring case.
+fn fuzzy_match(text: &str, pattern: &str) -> bool {
+    let mut text = text.chars().flat_map(char::to_lowercase);
+    pattern
+        .chars()
+        .flat_map(char::to_lowercase)
+        .filter(|c| !c.is_whitespace())
+        .all(|p| text.any(|t| t == p))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -326,6 +454,76 @@ mod tests {
         assert!(!clause.matches(&Value::String("helo")));
     }
 
+    #[test]
+    fn string_case_insensitive() {
+        let clause = Clause::new("name", Op::IEq, "hello");
+        assert!(clause.matches(&Value::String("HeLLo")));
+        assert!(!clause.matches(&Value::String("hello!")));
+
+        let clause = Clause::new("name", Op::IContains, "WORLD");
+        assert!(clause.matches(&Value::String("Hello world")));
+        assert!(!clause.matches(&Value::String("word")));
+    }
+
+    #[test]
+    fn string_fuzzy() {
+        let clause = Clause::new("name", Op::Fuzzy, "fxbg");
+        assert!(clause.matches(&Value::String("Fix login bug")));
+        assert!(clause.matches(&Value::String("fxbg")));
+        assert!(!clause.matches(&Value::String("bug fix")));
+
+        let clause = Clause::new("name", Op::Fuzzy, "rel notes");
+        assert!(clause.matches(&Value::String("Release Notes")));
+    }
+
+    #[test]
+    fn strings_coerce_to_numbers_and_timestamps() {
+        assert!(Clause::new("n", Op::Gt, 10i64).matches(&Value::String("12")));
+        assert!(Clause::new("n", Op::Between, 1..=2).matches(&Value::String("1.5")));
+        assert!(!Clause::new("n", Op::Ne, 10i64).matches(&Value::String("ten")));
+
+        let clause = Clause::new("at", Op::After, Timestamp(0));
+        assert!(clause.matches(&Value::String("2024-01-15T10:30:00Z")));
+        assert!(!clause.matches(&Value::String("yesterday")));
+    }
+
+    #[test]
+    fn set_membership() {
+        let clause = Clause::new("name", Op::In, vec!["a", "b"]);
+        assert!(clause.matches(&Value::String("b")));
+        assert!(!clause.matches(&Value::String("c")));
+
+        let clause = Clause::new("count", Op::In, vec![Number::I64(1), Number::F64(2.0)]);
+        assert!(clause.matches(&Value::Number(Number::U64(2))));
+        assert!(!clause.matches(&Value::Number(Number::I64(3))));
+    }
+
+    #[test]
+    fn ranges() {
+        let clause = Clause::new("count", Op::Between, 1..=5);
+        assert!(clause.matches(&Value::Number(Number::I64(1))));
+        assert!(clause.matches(&Value::Number(Number::F64(5.0))));
+        assert!(!clause.matches(&Value::Number(Number::I64(6))));
+
+        let clause = Clause::new("at", Op::Between, Timestamp(10)..=Timestamp(20));
+        assert!(clause.matches(&Value::Timestamp(Timestamp(20))));
+        assert!(!clause.matches(&Value::Timestamp(Timestamp(9))));
+    }
+
+    #[test]
+    fn presence() {
+        let exists = Clause::new("due", Op::Exists, true);
+        assert!(exists.matches(&Value::Timestamp(Timestamp(0))));
+        assert!(!exists.matches(&Value::None));
+
+        let missing = Clause::new("due", Op::IsNull, true);
+        assert!(missing.matches(&Value::None));
+        assert!(!missing.matches(&Value::String("")));
+
+        let not_missing = Clause::new("due", Op::IsNull, false);
+        assert!(not_missing.matches(&Value::Bool(false)));
+    }
+
     #[test]
     fn string_regex() {
         let regex = Regex::new(r"^hello\d+$").unwrap();
@@ -0,0 +1,259 @@
+//! Human-readable query descriptions.
+//!
+//! [`Query::explain`] describes a query's clause groups, ordering and
+//! pagination. [`Query::explain_with`] also runs each clause on its own
+//! against a collection and records how many items it matched, which shows
+//! which clause is emptying the result:
+//!
+//! ```text
+//! AND (all must match)
+//!   priority gte 3 [12 of 40]
+//!   status eq 2 [0 of 40]
+//! ORDER BY priority desc
+//! matched 0 of 40
+//! ```
+//!
+//! With the `serde` feature, [`Explain`] serializes to JSON for tooling.
+
+use std::fmt;
+
+use crate::clause::{Clause, ClauseValue};
+use crate::op::Op;
+use crate::ordering::Collation;
+use crate::query::Query;
+use crate::value::{Number, Value};
+
+/// Description of a query, from [`Query::explain`] or
+/// [`Query::explain_with`].
+///
+/// Displays as an indented tree; see the [module docs](self).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Explain {
+    /// AND clauses: all must match.
+    pub and: Vec<ClauseExplain>,
+    /// OR clauses: at least one must match.
+    pub or: Vec<ClauseExplain>,
+    /// NOT clauses: none may match.
+    pub not: Vec<ClauseExplain>,
+    /// Orderings, as `field dir`.
+    pub order_by: Vec<String>,
+    /// Number of matching items skipped.
+    pub offset: Option<usize>,
+    /// Maximum number of items returned.
+    pub limit: Option<usize>,
+    /// Number of items the query ran against (`explain_with` only).
+    pub total: Option<usize>,
+    /// Number of items the query returned (`explain_with` only).
+    pub matched: Option<usize>,
+}
+
+/// One clause of an [`Explain`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ClauseExplain {
+    /// The clause, as `field op value`.
+    pub clause: String,
+    /// Number of items the clause matches on its own (`explain_with` only).
+    ///
+    /// For NOT clauses this counts the items the clause excludes.
+    pub matches: Option<usize>,
+}
+
+impl Query {
+    /// Describes this query without running it.
+    pub fn explain(&self) -> Explain {
+        self.describe(|_| None, None)
+    }
+
+    /// Describes this query and runs it against `items`, recording how many
+    /// items each clause matches on its own and how many the query returns.
+    pub fn explain_with<T, F>(&self, items: &[T], accessor: F) -> Explain
+    where
+        for<'a> F: Fn(&'a T, &str) -> Value<'a>,
+    {
+        let count = |clause: &Clause| {
+            Some(
+                items
+                    .iter()
+                    .filter(|item| clause.matches(&accessor(item, &clause.field)))
+                    .count(),
+            )
+        };
+        let matched = self.filter(items, &accessor).len();
+        let mut explain = self.describe(count, Some(matched));
+        explain.total = Some(items.len());
+        explain
+    }
+
+    fn describe(
+        &self,
+        count: impl Fn(&Clause) -> Option<usize>,
+        matched: Option<usize>,
+    ) -> Explain {
+        let group = |clauses: &[Clause]| {
+            clauses
+                .iter()
+                .map(|clause| ClauseExplain {
+                    clause: clause.to_string(),
+                    matches: count(clause),
+                })
+                .collect()
+        };
+        Explain {
+            and: group(self.and_clauses()),
+            or: group(self.or_clauses()),
+            not: group(self.not_clauses()),
+            order_by: self
+                .orderings()
+                .iter()
+                .map(|order| match order.collation {
+                    Collation::Binary => format!("{} {}", order.field, order.dir),
+                    Collation::Natural => format!("{} {} natural", order.field, order.dir),
+                })
+                .collect(),
+            offset: self.get_offset(),
+            limit: self.get_limit(),
+            total: None,
+            matched,
+        }
+    }
+}
+
+impl fmt::Display for Explain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let groups = [
+            ("AND (all must match)", &self.and),
+            ("OR (any must match)", &self.or),
+            ("NOT (none may match)", &self.not),
+        ];
+        let mut empty = true;
+        for (title, clauses) in groups {
+            if clauses.is_empty() {
+                continue;
+            }
+            empty = false;
+            writeln!(f, "{}", title)?;
+            for clause in clauses {
+                write!(f, "  {}", clause.clause)?;
+                match (clause.matches, self.total) {
+                    (Some(matches), Some(total)) => writeln!(f, " [{} of {}]", matches, total)?,
+                    _ => writeln!(f)?,
+                }
+            }
+        }
+        if empty {
+            writeln!(f, "all items (no clauses)")?;
+        }
+        if !self.order_by.is_empty() {
+            writeln!(f, "ORDER BY {}", self.order_by.join(", "))?;
+        }
+        if let Some(offset) = self.offset {
+            writeln!(f, "OFFSET {}", offset)?;
+        }
+        if let Some(limit) = self.limit {
+            writeln!(f, "LIMIT {}", limit)?;
+        }
+        if let (Some(matched), Some(total)) = (self.matched, self.total) {
+            writeln!(f, "matched {} of {}", matched, total)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Clause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.op, &self.value) {
+            (Op::Exists | Op::IsNull, ClauseValue::Bool(true)) => {
+                write!(f, "{} {}", self.field, self.op)
+            }
+            _ => write!(f, "{} {} {}", self.field, self.op, self.value),
+        }
+    }
+}
+
+impl fmt::Display for ClauseValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list =
+            |f: &mut fmt::Formatter<'_>, items: Vec<String>| write!(f, "({})", items.join(", "));
+        match self {
+            ClauseValue::String(s) => write!(f, "{:?}", s),
+            ClauseValue::Number(n) => write!(f, "{}", number(n)),
+            ClauseValue::Timestamp(ts) => write!(f, "{}", ts.0),
+            ClauseValue::Enum(disc) => write!(f, "{}", disc),
+            ClauseValue::EnumSet(set) => list(f, set.iter().map(u32::to_string).collect()),
+            ClauseValue::StringSet(set) => {
+                list(f, set.iter().map(|s| format!("{:?}", s)).collect())
+            }
+            ClauseValue::NumberSet(set) => list(f, set.iter().map(number).collect()),
+            ClauseValue::NumberRange(lo, hi) => write!(f, "{}..={}", number(lo), number(hi)),
+            ClauseValue::TimestampRange(lo, hi) => write!(f, "{}..={}", lo.0, hi.0),
+            ClauseValue::Bool(b) => write!(f, "{}", b),
+            ClauseValue::Regex(regex) => write!(f, "/{}/", regex.as_str()),
+        }
+    }
+}
+
+fn number(n: &Number) -> String {
+    match n {
+        Number::I64(n) => n.to_string(),
+        Number::U64(n) => n.to_string(),
+        Number::F64(n) => n.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Dir;
+
+    fn accessor<'a>(n: &'a i64, field: &str) -> Value<'a> {
+        match field {
+            "n" => Value::Number(Number::I64(*n)),
+            _ => Value::None,
+        }
+    }
+
+    #[test]
+    fn explain_describes_groups() {
+        let query = Query::new()
+            .and_gte("n", 3i64)
+            .or_contains("name", "bug")
+            .or_in("status", [1, 2])
+            .not_between("n", 5..=6)
+            .and_null("due")
+            .order_by("n", Dir::Desc)
+            .limit(5)
+            .build();
+
+        assert_eq!(
+            query.explain().to_string(),
+            "AND (all must match)\n  n gte 3\n  due isnull\n\
+             OR (any must match)\n  name contains \"bug\"\n  status in (1, 2)\n\
+             NOT (none may match)\n  n between 5..=6\n\
+             ORDER BY n desc\nLIMIT 5\n"
+        );
+        assert_eq!(
+            Query::new().explain().to_string(),
+            "all items (no clauses)\n"
+        );
+    }
+
+    #[test]
+    fn explain_with_counts_clause_matches() {
+        let items: Vec<i64> = (1..=10).collect();
+        let query = Query::new()
+            .and_gt("n", 8i64)
+            .and_lt("n", 3i64)
+            .not_eq("n", 9i64)
+            .build();
+
+        let explain = query.explain_with(&items, accessor);
+        assert_eq!(explain.and[0].matches, Some(2));
+        assert_eq!(explain.and[1].matches, Some(2));
+        assert_eq!(explain.not[0].matches, Some(1));
+        assert_eq!(explain.matched, Some(0));
+        assert!(explain.to_string().contains("  n gt 8 [2 of 10]\n"));
+        assert!(explain.to_string().ends_with("matched 0 of 10\n"));
+    }
+}
@@ -0,0 +1,571 @@
+//! Filter expression parsing.
+//!
+//! Parses a single expression string into a [`Query`], so an app can expose
+//! one `--filter` flag instead of a flag per field and operator.
+
+use crate::op::Op;
+use crate::parse::{parse_clause, parse_operator, ParseError, ParseResult};
+use crate::query::Query;
+use crate::schema::{SeekType, SeekerSchema};
+
+/// Parses a filter expression into a [`Query`].
+///
+/// Fields, operators and values are checked against `S`, with the same
+/// errors as [`parse_query`](crate::parse_query).
+///
+/// # Syntax
+///
+/// ```text
+/// priority >= 3 AND status != done AND name ~ 'bug'
+/// ```
+///
+/// A comparison is `field operator value`. Values containing spaces or
+/// symbols are quoted with `'` or `"`; a backslash escapes the next
+/// character inside quotes. A boolean field on its own means `field = true`.
+///
+/// | Operator | Meaning |
+/// |----------|---------|
+/// | `=`, `==` | [`Op::Eq`] |
+/// | `!=` | [`Op::Ne`] |
+/// | `>`, `>=`, `<`, `<=` | [`Op::Gt`], [`Op::Gte`], [`Op::Lt`], [`Op::Lte`] |
+/// | `~` | [`Op::Contains`] |
+/// | `=~` | [`Op::Regex`] |
+/// | words | any name accepted by [`parse_operator`](crate::parse_operator): `contains`, `icontains`, `fuzzy`, `before`, `in`, ... |
+///
+/// `in` and `between` take a list: `status in (pending, active)`,
+/// `priority between (1, 5)`. `exists` and `isnull` need no value:
+/// `due exists`. Timestamps accept relative times as well as dates:
+/// `created_at > 7d ago`, `updated_at < now-1h`.
+///
+/// Comparisons are joined with `AND` or `OR` and negated with `NOT`
+/// (keywords are case-insensitive). An expression uses one of the two: since
+/// parentheses aren't supported, `a OR b AND c` is rejected rather than
+/// guessing which grouping was meant. `NOT` only combines with `AND`.
+///
+/// # Example
+///
+/// ```
+/// use standout_seeker::{parse_filter, SeekerSchema, SeekType};
+///
+/// struct Task;
+/// impl SeekerSchema for Task {
+///     fn field_type(field: &str) -> Option<SeekType> {
+///         match field {
+///             "name" => Some(SeekType::String),
+///             "priority" => Some(SeekType::Number),
+///             _ => None,
+///         }
+///     }
+///     fn field_names() -> &'static [&'static str] {
+///         &["name", "priority"]
+///     }
+/// }
+///
+/// let query = parse_filter::<Task>("priority >= 3 AND name ~ 'bug'").unwrap();
+/// assert_eq!(query.and_clauses().len(), 2);
+/// ```
+pub fn parse_filter<S: SeekerSchema>(expression: &str) -> ParseResult<Query> {
+    let tokens = tokenize(expression)?;
+    let mut parser = Parser {
+        expression,
+        tokens,
+        pos: 0,
+    };
+
+    let mut comparisons = Vec::new();
+    // Whether comparisons are joined with OR, once a keyword has been seen
+    let mut joined_by_or = None;
+    loop {
+        comparisons.push(parser.comparison::<S>()?);
+        let or = match parser.next() {
+            None => break,
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case("and") => false,
+            Some(Token::Word(word)) if word.eq_ignore_ascii_case("or") => true,
+            Some(token) => return Err(parser.error(format!("expected AND or OR, found {}", token))),
+        };
+        if *joined_by_or.get_or_insert(or) != or {
+            return Err(parser
+                .error("mixing AND and OR needs parentheses, which aren't supported".to_string()));
+        }
+    }
+    let or = joined_by_or.unwrap_or(false);
+
+    let mut query = Query::new();
+    for comparison in comparisons {
+        let Comparison {
+            negated,
+            field,
+            op,
+            value,
+        } = comparison;
+        let (op, value) = parse_clause::<S>(&field, Some(op), value)?;
+        query = match (negated, or) {
+            (true, true) => {
+                return Err(parser.error(format!("NOT {} can't be combined with OR", field)))
+            }
+            (true, false) => query.not(&field, op, value),
+            (false, true) => query.or(&field, op, value),
+            (false, false) => query.and(&field, op, value),
+        };
+    }
+
+    Ok(query.build())
+}
+
+impl Query {
+    /// Parses a filter expression such as
+    /// `priority >= 3 AND status != done AND name ~ 'bug'`.
+    ///
+    /// Shorthand for [`parse_filter`].
+    pub fn parse<S: SeekerSchema>(expression: &str) -> ParseResult<Query> {
+        parse_filter::<S>(expression)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    /// Bare word: field names, keywords, unquoted values.
+    Word(String),
+    /// Quoted value.
+    Quoted(String),
+    /// Symbolic operator.
+    Symbol(&'static str),
+    LParen,
+    RParen,
+    Comma,
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Word(word) => write!(f, "'{}'", word),
+            Token::Quoted(value) => write!(f, "\"{}\"", value),
+            Token::Symbol(symbol) => write!(f, "'{}'", symbol),
+            Token::LParen => f.write_str("'('"),
+            Token::RParen => f.write_str("')'"),
+            Token::Comma => f.write_str("','"),
+        }
+    }
+}
+
+/// Symbolic operators, longest first so `>=` wins over `>`.
+const SYMBOLS: &[(&str, Op)] = &[
+    ("==", Op::Eq),
+    ("!=", Op::Ne),
+    (">=", Op::Gte),
+    ("<=", Op::Lte),
+    ("=~", Op::Regex),
+    ("=", Op::Eq),
+    (">", Op::Gt),
+    ("<", Op::Lt),
+    ("~", Op::Contains),
+];
+
+fn tokenize(expression: &str) -> ParseResult<Vec<Token>> {
+    let invalid = |reason: String| ParseError::InvalidExpression {
+        expression: expression.to_string(),
+        reason,
+    };
+
+    let mut tokens = Vec::new();
+    let mut rest = expression;
+    while let Some(c) = rest.chars().next() {
+        if c.is_whitespace() {
+            rest = &rest[c.len_utf8()..];
+        } else if c == '(' || c == ')' || c == ',' {
+            tokens.push(match c {
+                '(' => Token::LParen,
+                ')' => Token::RParen,
+                _ => Token::Comma,
+            });
+            rest = &rest[1..];
+        } else if c == '\'' || c == '"' {
+            let mut value = String::new();
+            let mut chars = rest[1..].char_indices();
+            let mut end = None;
+            while let Some((i, ch)) = chars.next() {
+                match ch {
+                    '\\' => value.extend(chars.next().map(|(_, escaped)| escaped)),
+                    ch if ch == c => {
+                        end = Some(1 + i + 1);
+                        break;
+                    }
+                    ch => value.push(ch),
+                }
+            }
+            let end = end.ok_or_else(|| invalid(format!("unterminated {} quote", c)))?;
+            tokens.push(Token::Quoted(value));
+            rest = &rest[end..];
+        } else if let Some(&(symbol, _)) = SYMBOLS.iter().find(|(s, _)| rest.starts_with(s)) {
+            tokens.push(Token::Symbol(symbol));
+            rest = &rest[symbol.len()..];
+        } else if c == '!' {
+            return Err(invalid("expected '!='".to_string()));
+        } else {
+            let end = rest
+                .find(|ch: char| ch.is_whitespace() || "()',\"=!<>~".contains(ch))
+                .unwrap_or(rest.len());
+            tokens.push(Token::Word(rest[..end].to_string()));
+            rest = &rest[end..];
+        }
+    }
+    Ok(tokens)
+}
+
+/// A parsed `[NOT] field op value`, before it's checked against the schema.
+struct Comparison {
+    negated: bool,
+    field: String,
+    op: Op,
+    value: String,
+}
+
+struct Parser<'a> {
+    expression: &'a str,
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn error(&self, reason: String) -> ParseError {
+        ParseError::InvalidExpression {
+            expression: self.expression.to_string(),
+            reason,
+        }
+    }
+
+    /// Returns `true` at the end of input or before `AND`/`OR`.
+    fn at_comparison_end(&self) -> bool {
+        match self.peek() {
+            None => true,
+            Some(Token::Word(word)) => {
+                word.eq_ignore_ascii_case("and") || word.eq_ignore_ascii_case("or")
+            }
+            Some(_) => false,
+        }
+    }
+
+    fn comparison<S: SeekerSchema>(&mut self) -> ParseResult<Comparison> {
+        let mut negated = false;
+        let field = loop {
+            match self.next() {
+                Some(Token::Word(word)) if word.eq_ignore_ascii_case("not") => {
+                    negated = !negated;
+                }
+                Some(Token::Word(word))
+                    if word.eq_ignore_ascii_case("and") || word.eq_ignore_ascii_case("or") =>
+                {
+                    return Err(self.error(format!("expected a field name, found '{}'", word)));
+                }
+                Some(Token::Word(word)) => break word,
+                Some(token) => {
+                    return Err(self.error(format!("expected a field name, found {}", token)))
+                }
+                None => return Err(self.error("expected a field name".to_string())),
+            }
+        };
+
+        // A boolean field on its own reads as `field = true`
+        if self.at_comparison_end() && S::field_type(&field) == Some(SeekType::Bool) {
+            return Ok(Comparison {
+                negated,
+                field,
+                op: Op::Eq,
+                value: "true".to_string(),
+            });
+        }
+
+        let op = match self.next() {
+            Some(Token::Symbol(symbol)) => SYMBOLS
+                .iter()
+                .find(|(s, _)| *s == symbol)
+                .map(|&(_, op)| op)
+                .unwrap(),
+            Some(Token::Word(word)) => {
+                parse_operator(&word).ok_or(ParseError::UnknownOperator { operator: word })?
+            }
+            Some(token) => {
+                return Err(self.error(format!(
+                    "expected an operator after '{}', found {}",
+                    field, token
+                )))
+            }
+            None => return Err(self.error(format!("expected an operator after '{}'", field))),
+        };
+
+        let mut value = if matches!(op, Op::Exists | Op::IsNull) && self.at_comparison_end() {
+            // `due exists` needs no value
+            String::new()
+        } else if matches!(op, Op::In | Op::Between) && self.peek() == Some(&Token::LParen) {
+            self.pos += 1;
+            self.list()?.join(",")
+        } else {
+            self.value(&field)?
+        };
+
+        // `created_at > 7d ago` reads the relative time as one value
+        if S::field_type(&field) == Some(SeekType::Timestamp)
+            && matches!(self.peek(), Some(Token::Word(word)) if word.eq_ignore_ascii_case("ago"))
+        {
+            self.pos += 1;
+            value.push_str(" ago");
+        }
+
+        Ok(Comparison {
+            negated,
+            field,
+            op,
+            value,
+        })
+    }
+
+    fn value(&mut self, field: &str) -> ParseResult<String> {
+        match self.next() {
+            Some(Token::Word(word) | Token::Quoted(word)) => Ok(word),
+            Some(token) => {
+                Err(self.error(format!("expected a value for '{}', found {}", field, token)))
+            }
+            None => Err(self.error(format!("expected a value for '{}'", field))),
+        }
+    }
+
+    /// Parses `a, b, c)` after an opening parenthesis.
+    fn list(&mut self) -> ParseResult<Vec<String>> {
+        let mut values = Vec::new();
+        loop {
+            match self.next() {
+                Some(Token::Word(word) | Token::Quoted(word)) => values.push(word),
+                Some(Token::RParen) if values.is_empty() => return Ok(values),
+                _ => return Err(self.error("expected a value in the list".to_string())),
+            }
+            match self.next() {
+                Some(Token::Comma) => continue,
+                Some(Token::RParen) => return Ok(values),
+                _ => return Err(self.error("expected ',' or ')' in the list".to_string())),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clause::ClauseValue;
+    use crate::{Number, Value};
+
+    struct Task;
+
+    impl SeekerSchema for Task {
+        fn field_type(field: &str) -> Option<SeekType> {
+            match field {
+                "name" => Some(SeekType::String),
+                "priority" => Some(SeekType::Number),
+                "status" => Some(SeekType::Enum),
+                "done" => Some(SeekType::Bool),
+                _ => None,
+            }
+        }
+
+        fn field_names() -> &'static [&'static str] {
+            &["name", "priority", "status", "done"]
+        }
+
+        fn resolve_enum_variant(_field: &str, variant: &str) -> Option<u32> {
+            ["pending", "active", "done"]
+                .iter()
+                .position(|v| *v == variant)
+                .map(|i| i as u32)
+        }
+    }
+
+    struct Item {
+        name: &'static str,
+        priority: i64,
+        status: u32,
+        done: bool,
+    }
+
+    fn accessor<'a>(item: &'a Item, field: &str) -> Value<'a> {
+        match field {
+            "name" => Value::String(item.name),
+            "priority" => Value::Number(Number::I64(item.priority)),
+            "status" => Value::Enum(item.status),
+            "done" => Value::Bool(item.done),
+            _ => Value::None,
+        }
+    }
+
+    fn items() -> Vec<Item> {
+        vec![
+            Item {
+                name: "fix bug in parser",
+                priority: 5,
+                status: 1,
+                done: false,
+            },
+            Item {
+                name: "write docs",
+                priority: 3,
+                status: 0,
+                done: false,
+            },
+            Item {
+                name: "old bug",
+                priority: 4,
+                status: 2,
+                done: true,
+            },
+        ]
+    }
+
+    fn names(expression: &str) -> Vec<&'static str> {
+        let query = Query::parse::<Task>(expression).unwrap();
+        items()
+            .iter()
+            .filter(|item| query.matches(*item, accessor))
+            .map(|item| item.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_and_comparisons() {
+        assert_eq!(
+            names("priority >= 3 AND status != done AND name ~ 'bug'"),
+            ["fix bug in parser"]
+        );
+        assert_eq!(names("priority<4"), ["write docs"]);
+        assert_eq!(names("name startswith \"write\""), ["write docs"]);
+        assert_eq!(names("name icontains 'BUG IN'"), ["fix bug in parser"]);
+        assert_eq!(names("name fuzzy wdcs"), ["write docs"]);
+        assert_eq!(
+            names("priority between (4, 5) AND name in ('old bug', x)"),
+            ["old bug"]
+        );
+        assert_eq!(names("name exists AND priority in (3)"), ["write docs"]);
+        assert!(names("name isnull").is_empty());
+    }
+
+    #[test]
+    fn test_or_not_and_bool_shorthand() {
+        assert_eq!(
+            names("status = pending or status = active"),
+            ["fix bug in parser", "write docs"]
+        );
+        assert_eq!(names("NOT done"), ["fix bug in parser", "write docs"]);
+        assert_eq!(names("done AND priority > 1"), ["old bug"]);
+        assert_eq!(
+            names("name ~ bug OR name ~ docs OR priority > 4"),
+            ["fix bug in parser", "write docs", "old bug"]
+        );
+        assert_eq!(
+            names("name ~ bug AND NOT done AND priority > 4"),
+            ["fix bug in parser"]
+        );
+    }
+
+    #[test]
+    fn test_mixed_and_or_rejected() {
+        for expression in [
+            "name ~ bug OR name ~ docs AND not done",
+            "a = 1 AND b = 2 OR c = 3",
+            "status = pending or status = active and done",
+        ] {
+            match Query::parse::<Task>(expression) {
+                Err(ParseError::InvalidExpression { reason, .. }) => {
+                    assert!(reason.contains("mixing AND and OR"), "{reason}")
+                }
+                other => panic!("{expression}: {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_in_lists_and_quotes() {
+        assert_eq!(
+            names("status in (done, pending)"),
+            ["write docs", "old bug"]
+        );
+        let query = Query::parse::<Task>(r#"name = 'it\'s (done)'"#).unwrap();
+        assert!(matches!(
+            &query.and_clauses()[0].value,
+            ClauseValue::String(s) if s == "it's (done)"
+        ));
+    }
+
+    #[test]
+    fn test_relative_timestamps() {
+        struct Event;
+        impl SeekerSchema for Event {
+            fn field_type(field: &str) -> Option<SeekType> {
+                (field == "created_at").then_some(SeekType::Timestamp)
+            }
+            fn field_names() -> &'static [&'static str] {
+                &["created_at"]
+            }
+        }
+
+        for expression in [
+            "created_at > 7d ago",
+            "created_at > 7d",
+            "created_at after now-1h",
+        ] {
+            let query = Query::parse::<Event>(expression).unwrap();
+            assert!(matches!(
+                query.and_clauses()[0].value,
+                ClauseValue::Timestamp(_)
+            ));
+        }
+        assert!(Query::parse::<Event>("created_at > 7d ago AND created_at < now").is_ok());
+    }
+
+    #[test]
+    fn test_typed_errors() {
+        assert!(matches!(
+            Query::parse::<Task>("owner = me"),
+            Err(ParseError::UnknownField { field, .. }) if field == "owner"
+        ));
+        assert!(matches!(
+            Query::parse::<Task>("name > 3"),
+            Err(ParseError::InvalidOperator { field, .. }) if field == "name"
+        ));
+        assert!(matches!(
+            Query::parse::<Task>("name equals x"),
+            Err(ParseError::UnknownOperator { operator }) if operator == "equals"
+        ));
+        assert!(matches!(
+            Query::parse::<Task>("priority = high"),
+            Err(ParseError::InvalidValue { field, .. }) if field == "priority"
+        ));
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        for expression in [
+            "",
+            "priority >=",
+            "name = 'open",
+            "priority = 3 priority = 4",
+            "a = 1 OR b = 2 AND c = 3 OR d = 4",
+            "name ~ x OR NOT done",
+            "status in (pending",
+        ] {
+            assert!(
+                matches!(
+                    Query::parse::<Task>(expression),
+                    Err(ParseError::InvalidExpression { .. })
+                ),
+                "{expression}"
+            );
+        }
+    }
+}
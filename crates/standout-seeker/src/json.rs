@@ -0,0 +1,109 @@
+//! Accessor for `serde_json::Value` collections.
+//!
+//! [`json_accessor`] reads fields from JSON values by dot path, so API
+//! responses can be queried without defining structs:
+//!
+//! ```
+//! use standout_seeker::{json_accessor, Query};
+//! use serde_json::json;
+//!
+//! let items = vec![
+//!     json!({"title": "Fix bug", "author": {"name": "ana"}, "votes": 3}),
+//!     json!({"title": "Docs", "author": {"name": "bo"}, "votes": "12"}),
+//! ];
+//!
+//! let query = Query::new()
+//!     .and_eq("author.name", "bo")
+//!     .and_gt("votes", 10i64)
+//!     .build();
+//! assert_eq!(query.filter(&items, json_accessor).len(), 1);
+//! ```
+
+use serde_json::Value as Json;
+
+use crate::value::{Number, Value};
+
+/// Reads the field at `path` from a JSON value.
+///
+/// Path segments are separated by `.`; a numeric segment indexes into an
+/// array (`tags.0`). JSON strings, numbers and booleans map to the matching
+/// [`Value`] variant. Null, missing fields, arrays and objects are
+/// [`Value::None`].
+///
+/// Numbers and dates stored as strings (`"12"`, `"2024-01-15"`) still
+/// compare against number and timestamp clauses: clauses parse string
+/// values into their own type.
+pub fn json_accessor<'a>(item: &'a Json, path: &str) -> Value<'a> {
+    let found = path
+        .split('.')
+        .try_fold(item, |value, segment| match value {
+            Json::Object(map) => map.get(segment),
+            Json::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        });
+    match found {
+        Some(Json::String(s)) => Value::String(s),
+        Some(Json::Bool(b)) => Value::Bool(*b),
+        Some(Json::Number(n)) => match (n.as_i64(), n.as_u64(), n.as_f64()) {
+            (Some(n), _, _) => Value::Number(Number::I64(n)),
+            (None, Some(n), _) => Value::Number(Number::U64(n)),
+            (None, None, Some(n)) => Value::Number(Number::F64(n)),
+            _ => Value::None,
+        },
+        _ => Value::None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Query, Timestamp};
+    use serde_json::json;
+
+    #[test]
+    fn reads_dot_paths() {
+        let item = json!({
+            "name": "task",
+            "meta": {"done": true, "tags": ["a", "b"], "size": 2.5, "big": u64::MAX},
+            "none": null,
+        });
+
+        assert_eq!(json_accessor(&item, "name"), Value::String("task"));
+        assert_eq!(json_accessor(&item, "meta.done"), Value::Bool(true));
+        assert_eq!(json_accessor(&item, "meta.tags.1"), Value::String("b"));
+        assert_eq!(
+            json_accessor(&item, "meta.size"),
+            Value::Number(Number::F64(2.5))
+        );
+        assert_eq!(
+            json_accessor(&item, "meta.big"),
+            Value::Number(Number::U64(u64::MAX))
+        );
+        assert!(json_accessor(&item, "none").is_none());
+        assert!(json_accessor(&item, "meta").is_none());
+        assert!(json_accessor(&item, "meta.tags.9").is_none());
+        assert!(json_accessor(&item, "name.first").is_none());
+    }
+
+    #[test]
+    fn filters_json_collections() {
+        let items = vec![
+            json!({"id": 1, "created": "2024-03-01", "score": "7"}),
+            json!({"id": 2, "created": "2023-12-31T23:00:00Z", "score": 9}),
+            json!({"id": 3}),
+        ];
+        let query = Query::new()
+            .and_after("created", Timestamp(1_704_067_200_000)) // 2024-01-01
+            .and_between("score", 5..=8)
+            .build();
+
+        let found = query.filter(&items, json_accessor);
+        assert_eq!(found, [&items[0]]);
+        assert_eq!(
+            Query::new()
+                .and_null("created")
+                .count(&items, json_accessor),
+            1
+        );
+    }
+}
@@ -69,14 +69,72 @@
 //!
 //! | Type | Operators |
 //! |------|-----------|
-//! | String | `Eq`, `Ne`, `StartsWith`, `EndsWith`, `Contains`, `Regex` |
-//! | Number | `Eq`, `Ne`, `Gt`, `Gte`, `Lt`, `Lte` |
-//! | Timestamp | `Eq`, `Ne`, `Before`, `After`, `Gt`, `Gte`, `Lt`, `Lte` |
+//! | String | `Eq`, `Ne`, `StartsWith`, `EndsWith`, `Contains`, `Regex`, `IEq`, `IContains`, `Fuzzy`, `In` |
+//! | Number | `Eq`, `Ne`, `Gt`, `Gte`, `Lt`, `Lte`, `In`, `Between` |
+//! | Timestamp | `Eq`, `Ne`, `Before`, `After`, `Gt`, `Gte`, `Lt`, `Lte`, `Between` |
 //! | Enum | `Eq`, `Ne`, `In` |
 //! | Bool | `Eq`, `Ne`, `Is` |
+//!
+//! `Exists` and `IsNull` work on every type and check whether the accessor
+//! returned [`Value::None`].
+//!
+//! Parsed timestamp values accept dates (`2024-01-15`), epoch milliseconds
+//! and relative times: `7d` or `7d ago` (seven days before now), `now` and
+//! `now-1h`. Units are `s`, `m`, `h`, `d` and `w`.
+//!
+//! # Ordering
+//!
+//! Strings sort byte-wise by default. [`Collation::Natural`] (through
+//! [`Query::order_by_natural`] or [`OrderBy::natural`]) ignores case and
+//! accents and compares digit runs by value, so `item2` sorts before
+//! `item10`.
+//!
+//! # Filter Expressions
+//!
+//! [`Query::parse`] builds a query from one expression string, for a single
+//! `--filter` flag:
+//!
+//! ```text
+//! priority >= 3 AND status != done AND name ~ 'bug'
+//! ```
+//!
+//! Fields, operators and values are checked against a [`SeekerSchema`]. See
+//! [`parse_filter`] for the syntax. A `--sort` flag maps onto
+//! [`OrderBy::parse`], which reads `-priority,name` (descending prefix,
+//! several fields).
+//!
+//! # Saved Searches
+//!
+//! With the `serde` feature, [`Query`] and its parts implement `Serialize`
+//! and `Deserialize`, so a query can be stored in a config file and loaded
+//! back (`myapp list --saved urgent`). Regex values are stored as their
+//! pattern and recompiled on load.
+//!
+//! # JSON Collections
+//!
+//! With the `json` feature, `json_accessor` reads fields from
+//! `serde_json::Value` items by dot path (`author.name`), so API responses
+//! can be filtered without defining structs.
+//!
+//! # Debugging Queries
+//!
+//! [`Query::explain`] describes a query as a tree of clause groups.
+//! [`Query::explain_with`] also runs it against a collection and counts the
+//! matches of each clause, to show why a filter returned nothing.
+//!
+//! # Parallel Filtering
+//!
+//! With the `rayon` feature, `Query::par_filter` splits matching and
+//! sorting across threads. It returns the same results as
+//! [`Query::filter`] and pays off on large in-memory collections (a million
+//! items or more).
 
 mod clause;
 mod error;
+mod explain;
+mod filter;
+#[cfg(feature = "json")]
+mod json;
 mod op;
 mod ordering;
 mod parse;
@@ -88,8 +146,12 @@ mod value;
 // Re-export public API
 pub use clause::{Clause, ClauseValue};
 pub use error::{Result, SeekerError};
+pub use explain::{ClauseExplain, Explain};
+pub use filter::parse_filter;
+#[cfg(feature = "json")]
+pub use json::json_accessor;
 pub use op::Op;
-pub use ordering::{compare_values, Dir, OrderBy};
+pub use ordering::{compare_values, natural_cmp, Collation, Dir, OrderBy};
 pub use parse::{
     parse_key, parse_operator, parse_ordering, parse_query, parse_value, ClauseGroup, ParseError,
     ParseResult,
@@ -9,12 +9,16 @@ use std::cmp::Ordering;
 ///
 /// Operators are grouped by the types they support:
 /// - Universal: `Eq`, `Ne` - work on all types
-/// - String: `StartsWith`, `EndsWith`, `Contains`, `Regex`
+/// - String: `StartsWith`, `EndsWith`, `Contains`, `Regex`, `IEq`, `IContains`, `Fuzzy`
 /// - Numeric/Timestamp: `Gt`, `Gte`, `Lt`, `Lte`
 /// - Timestamp aliases: `Before` (alias for `Lt`), `After` (alias for `Gt`)
-/// - Enum: `In` - check membership in a set
+/// - String/Number/Enum: `In` - check membership in a set
+/// - Numeric/Timestamp: `Between` - inclusive range
+/// - Any type: `Exists`, `IsNull` - check for a missing value
 /// - Bool alias: `Is` (alias for `Eq`)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum Op {
     // Universal operators
     /// Equal (exact match). Valid for all types.
@@ -31,6 +35,13 @@ pub enum Op {
     Contains,
     /// String matches regular expression.
     Regex,
+    /// String equals, ignoring case.
+    IEq,
+    /// String contains substring, ignoring case.
+    IContains,
+    /// String contains the pattern's characters in order, ignoring case
+    /// (`tsk` matches "Task"). Tolerates abbreviations and dropped letters.
+    Fuzzy,
 
     // Numeric/Timestamp comparison operators
     /// Greater than.
@@ -48,9 +59,18 @@ pub enum Op {
     /// Later than (alias for `Gt` on timestamps).
     After,
 
-    // Enum operators
-    /// Value is one of the given set.
+    // Set and range operators
+    /// Value is one of the given set. Valid for strings, numbers and enums.
     In,
+    /// Value lies within an inclusive range. Valid for numbers and timestamps.
+    Between,
+
+    // Presence operators
+    /// Field has a value (isn't [`Value::None`](crate::Value::None)).
+    /// Valid for all types.
+    Exists,
+    /// Field has no value. Valid for all types.
+    IsNull,
 
     // Bool alias
     /// Alias for `Eq` (reads naturally: `archived.is(true)`).
@@ -62,31 +82,64 @@ impl Op {
     pub fn is_string_op(self) -> bool {
         matches!(
             self,
-            Op::Eq | Op::Ne | Op::StartsWith | Op::EndsWith | Op::Contains | Op::Regex
+            Op::Eq
+                | Op::Ne
+                | Op::StartsWith
+                | Op::EndsWith
+                | Op::Contains
+                | Op::Regex
+                | Op::IEq
+                | Op::IContains
+                | Op::Fuzzy
+                | Op::In
+                | Op::Exists
+                | Op::IsNull
         )
     }
 
     /// Returns `true` if this operator is valid for numeric comparisons.
     pub fn is_number_op(self) -> bool {
-        matches!(self, Op::Eq | Op::Ne | Op::Gt | Op::Gte | Op::Lt | Op::Lte)
+        matches!(
+            self,
+            Op::Eq
+                | Op::Ne
+                | Op::Gt
+                | Op::Gte
+                | Op::Lt
+                | Op::Lte
+                | Op::In
+                | Op::Between
+                | Op::Exists
+                | Op::IsNull
+        )
     }
 
     /// Returns `true` if this operator is valid for timestamp comparisons.
     pub fn is_timestamp_op(self) -> bool {
         matches!(
             self,
-            Op::Eq | Op::Ne | Op::Gt | Op::Gte | Op::Lt | Op::Lte | Op::Before | Op::After
+            Op::Eq
+                | Op::Ne
+                | Op::Gt
+                | Op::Gte
+                | Op::Lt
+                | Op::Lte
+                | Op::Before
+                | Op::After
+                | Op::Between
+                | Op::Exists
+                | Op::IsNull
         )
     }
 
     /// Returns `true` if this operator is valid for enum comparisons.
     pub fn is_enum_op(self) -> bool {
-        matches!(self, Op::Eq | Op::Ne | Op::In)
+        matches!(self, Op::Eq | Op::Ne | Op::In | Op::Exists | Op::IsNull)
     }
 
     /// Returns `true` if this operator is valid for boolean comparisons.
     pub fn is_bool_op(self) -> bool {
-        matches!(self, Op::Eq | Op::Ne | Op::Is)
+        matches!(self, Op::Eq | Op::Ne | Op::Is | Op::Exists | Op::IsNull)
     }
 
     /// Normalizes timestamp aliases to their canonical form.
@@ -129,6 +182,9 @@ impl Op {
             Op::EndsWith => "endswith",
             Op::Contains => "contains",
             Op::Regex => "regex",
+            Op::IEq => "ieq",
+            Op::IContains => "icontains",
+            Op::Fuzzy => "fuzzy",
             Op::Gt => "gt",
             Op::Gte => "gte",
             Op::Lt => "lt",
@@ -136,6 +192,9 @@ impl Op {
             Op::Before => "before",
             Op::After => "after",
             Op::In => "in",
+            Op::Between => "between",
+            Op::Exists => "exists",
+            Op::IsNull => "isnull",
             Op::Is => "is",
         }
     }
@@ -157,7 +216,10 @@ mod tests {
         assert!(Op::Eq.is_string_op());
         assert!(Op::Contains.is_string_op());
         assert!(Op::Regex.is_string_op());
+        assert!(Op::IContains.is_string_op());
+        assert!(Op::Fuzzy.is_string_op());
         assert!(!Op::Gt.is_string_op());
+        assert!(!Op::Fuzzy.is_number_op());
 
         // Number ops
         assert!(Op::Eq.is_number_op());
@@ -176,6 +238,15 @@ mod tests {
         assert!(Op::In.is_enum_op());
         assert!(!Op::Gt.is_enum_op());
 
+        // Set, range and presence ops
+        assert!(Op::In.is_string_op());
+        assert!(Op::In.is_number_op());
+        assert!(!Op::In.is_timestamp_op());
+        assert!(Op::Between.is_timestamp_op());
+        assert!(!Op::Between.is_string_op());
+        assert!(Op::Exists.is_bool_op());
+        assert!(Op::IsNull.is_string_op());
+
         // Bool ops
         assert!(Op::Eq.is_bool_op());
         assert!(Op::Is.is_bool_op());
@@ -8,6 +8,8 @@ use crate::value::Value;
 
 /// Sort direction.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum Dir {
     /// Ascending order (smallest first).
     #[default]
@@ -53,13 +55,31 @@ impl std::fmt::Display for Dir {
     }
 }
 
+/// How strings are compared when sorting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Collation {
+    /// Byte-wise comparison (`B` < `a`, `item10` < `item2`).
+    #[default]
+    Binary,
+    /// Human ordering: case and accents are ignored (`é` sorts with `e`)
+    /// and digit runs compare by value (`item2` < `item10`). Strings that
+    /// are equal this way fall back to byte-wise order.
+    Natural,
+}
+
 /// A single ordering clause specifying a field and direction.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderBy {
     /// The field to sort by.
     pub field: String,
     /// The sort direction.
     pub dir: Dir,
+    /// How string values are compared.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub collation: Collation,
 }
 
 impl OrderBy {
@@ -68,6 +88,7 @@ impl OrderBy {
         OrderBy {
             field: field.into(),
             dir: Dir::Asc,
+            collation: Collation::Binary,
         }
     }
 
@@ -76,6 +97,7 @@ impl OrderBy {
         OrderBy {
             field: field.into(),
             dir: Dir::Desc,
+            collation: Collation::Binary,
         }
     }
 
@@ -84,14 +106,24 @@ impl OrderBy {
         OrderBy {
             field: field.into(),
             dir,
+            collation: Collation::Binary,
         }
     }
 
+    /// Compares strings with [`Collation::Natural`].
+    pub fn natural(mut self) -> Self {
+        self.collation = Collation::Natural;
+        self
+    }
+
     /// Compares two values according to this ordering.
     ///
     /// Returns `None` if the values cannot be compared (type mismatch or NaN).
     pub fn compare<'a>(&self, a: &Value<'a>, b: &Value<'a>) -> Option<Ordering> {
-        let base_ordering = compare_values(a, b)?;
+        let base_ordering = match (self.collation, a, b) {
+            (Collation::Natural, Value::String(a), Value::String(b)) => natural_cmp(a, b),
+            _ => compare_values(a, b)?,
+        };
         Some(self.dir.apply(base_ordering))
     }
 }
@@ -117,6 +149,44 @@ pub fn compare_values<'a>(a: &Value<'a>, b: &Value<'a>) -> Option<Ordering> {
     }
 }
 
+/// Compares strings with [`Collation::Natural`].
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let fold = |s: &str| deunicode::deunicode(s).to_lowercase();
+    let (folded_a, folded_b) = (fold(a), fold(b));
+    let (mut a_chars, mut b_chars) = (folded_a.chars().peekable(), folded_b.chars().peekable());
+
+    loop {
+        let ordering = match (a_chars.peek(), b_chars.peek()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let x = digit_run(&mut a_chars);
+                let y = digit_run(&mut b_chars);
+                let (x, y) = (x.trim_start_matches('0'), y.trim_start_matches('0'));
+                x.len().cmp(&y.len()).then_with(|| x.cmp(y))
+            }
+            (Some(x), Some(y)) => {
+                let ordering = x.cmp(y);
+                a_chars.next();
+                b_chars.next();
+                ordering
+            }
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+fn digit_run(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    let mut run = String::new();
+    while let Some(c) = chars.next_if(char::is_ascii_digit) {
+        run.push(c);
+    }
+    run
+}
+
 /// Compares two items using a list of ordering clauses.
 ///
 /// Uses the first clause as the primary sort key, the second to break ties, etc.
@@ -248,6 +318,29 @@ mod tests {
         assert_eq!(desc.compare(&a, &b), Some(Ordering::Greater));
     }
 
+    #[test]
+    fn natural_collation() {
+        let mut names = vec![
+            "item10", "Zebra", "item2", "éclair", "Item1", "eclair", "apple",
+        ];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(
+            names,
+            ["apple", "eclair", "éclair", "Item1", "item2", "item10", "Zebra"]
+        );
+        assert_eq!(natural_cmp("v007", "v7"), Ordering::Less); // byte-wise tie-break
+
+        let order = OrderBy::desc("name").natural();
+        assert_eq!(
+            order.compare(&Value::String("a2"), &Value::String("a10")),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(
+            OrderBy::asc("name").compare(&Value::String("a2"), &Value::String("a10")),
+            Some(Ordering::Greater)
+        );
+    }
+
     #[test]
     fn compare_by_multiple_orderings() {
         #[derive(Debug)]
@@ -80,6 +80,8 @@ pub enum ParseError {
     InvalidLimit { key: String, value: String },
     /// Unknown operator name.
     UnknownOperator { operator: String },
+    /// Filter expression syntax error.
+    InvalidExpression { expression: String, reason: String },
 }
 
 impl std::fmt::Display for ParseError {
@@ -140,6 +142,9 @@ impl std::fmt::Display for ParseError {
             ParseError::UnknownOperator { operator } => {
                 write!(f, "unknown operator '{}'", operator)
             }
+            ParseError::InvalidExpression { expression, reason } => {
+                write!(f, "invalid filter '{}': {}", expression, reason)
+            }
         }
     }
 }
@@ -174,9 +179,15 @@ pub fn parse_operator(s: &str) -> Option<Op> {
         "endswith" | "suffix" => Some(Op::EndsWith),
         "contains" => Some(Op::Contains),
         "regex" | "re" | "match" => Some(Op::Regex),
+        "ieq" => Some(Op::IEq),
+        "icontains" => Some(Op::IContains),
+        "fuzzy" => Some(Op::Fuzzy),
         "before" => Some(Op::Before),
         "after" => Some(Op::After),
         "in" => Some(Op::In),
+        "between" => Some(Op::Between),
+        "exists" => Some(Op::Exists),
+        "isnull" | "null" => Some(Op::IsNull),
         "is" => Some(Op::Is),
         _ => None,
     }
@@ -200,9 +211,16 @@ fn operator_names() -> HashSet<&'static str> {
         "regex",
         "re",
         "match",
+        "ieq",
+        "icontains",
+        "fuzzy",
         "before",
         "after",
         "in",
+        "between",
+        "exists",
+        "isnull",
+        "null",
         "is",
     ]
     .into_iter()
@@ -243,7 +261,11 @@ pub fn parse_key(key: &str) -> (String, Option<Op>) {
 /// * `value` - The raw value string
 /// * `field` - The field name (for error messages and enum resolution)
 /// * `field_type` - The expected field type
-/// * `op` - The operator (affects parsing for `In` operator)
+/// * `op` - The operator (affects parsing for `In`, `Between`, `Exists`
+///   and `IsNull`)
+///
+/// `In` takes comma-separated values and `Between` takes two (`1,5`).
+/// `Exists` and `IsNull` take a boolean, whatever the field type.
 ///
 /// # Returns
 ///
@@ -254,7 +276,38 @@ pub fn parse_value<S: SeekerSchema>(
     field_type: SeekType,
     op: Op,
 ) -> ParseResult<ClauseValue> {
+    if matches!(op, Op::Exists | Op::IsNull) {
+        return parse_bool(value, field);
+    }
+
     match field_type {
+        SeekType::String if op == Op::In => Ok(ClauseValue::StringSet(
+            value
+                .split(',')
+                .map(|part| part.trim().to_string())
+                .collect(),
+        )),
+        SeekType::Number if op == Op::In => value
+            .split(',')
+            .map(|part| number_of(parse_number(part.trim(), field)?))
+            .collect::<ParseResult<_>>()
+            .map(ClauseValue::NumberSet),
+        SeekType::Number if op == Op::Between => {
+            let (lo, hi) = parse_range(value, field, field_type)?;
+            Ok(ClauseValue::NumberRange(
+                number_of(parse_number(lo, field)?)?,
+                number_of(parse_number(hi, field)?)?,
+            ))
+        }
+        SeekType::Timestamp if op == Op::Between => {
+            let (lo, hi) = parse_range(value, field, field_type)?;
+            match (parse_timestamp(lo, field)?, parse_timestamp(hi, field)?) {
+                (ClauseValue::Timestamp(lo), ClauseValue::Timestamp(hi)) => {
+                    Ok(ClauseValue::TimestampRange(lo, hi))
+                }
+                _ => unreachable!("parse_timestamp returns timestamps"),
+            }
+        }
         SeekType::String => {
             if op == Op::Regex {
                 match regex::Regex::new(value) {
@@ -300,6 +353,32 @@ fn parse_number(value: &str, field: &str) -> ParseResult<ClauseValue> {
     })
 }
 
+fn number_of(value: ClauseValue) -> ParseResult<Number> {
+    match value {
+        ClauseValue::Number(n) => Ok(n),
+        _ => unreachable!("parse_number returns numbers"),
+    }
+}
+
+/// Splits a `Between` value into its two bounds.
+fn parse_range<'a>(
+    value: &'a str,
+    field: &str,
+    field_type: SeekType,
+) -> ParseResult<(&'a str, &'a str)> {
+    match value.split_once(',') {
+        Some((lo, hi)) if !lo.trim().is_empty() && !hi.trim().is_empty() => {
+            Ok((lo.trim(), hi.trim()))
+        }
+        _ => Err(ParseError::InvalidValue {
+            field: field.to_string(),
+            value: value.to_string(),
+            expected: field_type,
+            reason: "expected two comma-separated bounds".to_string(),
+        }),
+    }
+}
+
 fn parse_timestamp(value: &str, field: &str) -> ParseResult<ClauseValue> {
     // Try Unix timestamp in milliseconds
     if let Ok(ms) = value.parse::<i64>() {
@@ -322,6 +401,11 @@ fn parse_timestamp(value: &str, field: &str) -> ParseResult<ClauseValue> {
         return Ok(ClauseValue::Timestamp(ts));
     }
 
+    // Relative: 7d, 7d ago, now, now-1h
+    if let Some(ts) = parse_relative(value, now_millis()) {
+        return Ok(ClauseValue::Timestamp(ts));
+    }
+
     // Year only: YYYY
     if value.len() == 4 {
         if let Ok(year) = value.parse::<i32>() {
@@ -336,11 +420,13 @@ fn parse_timestamp(value: &str, field: &str) -> ParseResult<ClauseValue> {
         field: field.to_string(),
         value: value.to_string(),
         expected: SeekType::Timestamp,
-        reason: "expected Unix timestamp (ms), ISO date (YYYY-MM-DD), or datetime".to_string(),
+        reason: "expected Unix timestamp (ms), ISO date (YYYY-MM-DD), datetime, \
+                 or relative time (7d, 7d ago, now-1h)"
+            .to_string(),
     })
 }
 
-fn parse_date_only(value: &str) -> Option<Timestamp> {
+pub(crate) fn parse_date_only(value: &str) -> Option<Timestamp> {
     // YYYY-MM-DD format
     let parts: Vec<&str> = value.split('-').collect();
     if parts.len() != 3 {
@@ -356,11 +442,11 @@ fn parse_date_only(value: &str) -> Option<Timestamp> {
     }
 
     let days = days_from_ymd(year, month, day)?;
-    let ms = days as i64 * 24 * 60 * 60 * 1000;
+    let ms = days * 24 * 60 * 60 * 1000;
     Some(Timestamp(ms))
 }
 
-fn parse_datetime(value: &str) -> Option<Timestamp> {
+pub(crate) fn parse_datetime(value: &str) -> Option<Timestamp> {
     // YYYY-MM-DDTHH:MM:SS or YYYY-MM-DDTHH:MM:SSZ
     let value = value.trim_end_matches('Z');
     let parts: Vec<&str> = value.split('T').collect();
@@ -395,10 +481,60 @@ fn parse_datetime(value: &str) -> Option<Timestamp> {
 
     let days = days_from_ymd(year, month, day)?;
     let seconds = hour * 3600 + minute * 60 + second;
-    let ms = days as i64 * 24 * 60 * 60 * 1000 + seconds as i64 * 1000;
+    let ms = days * 24 * 60 * 60 * 1000 + seconds as i64 * 1000;
     Some(Timestamp(ms))
 }
 
+/// Parses a time relative to `now` (milliseconds since the epoch).
+///
+/// Accepts `now`, `now-1h`, `now+2d`, and a bare duration meaning that long
+/// ago: `7d` or `7d ago`. Units are `s`, `m` (minutes), `h`, `d` and `w`.
+/// Returns `None` for times outside the range of a [`Timestamp`].
+fn parse_relative(value: &str, now: i64) -> Option<Timestamp> {
+    let value = value.trim().to_lowercase();
+    if value == "now" {
+        return Some(Timestamp(now));
+    }
+
+    let (ahead, duration) = if let Some(rest) = value.strip_prefix("now") {
+        let rest = rest.trim_start();
+        match rest.chars().next()? {
+            '-' => (false, &rest[1..]),
+            '+' => (true, &rest[1..]),
+            _ => return None,
+        }
+    } else {
+        let duration = value.strip_suffix("ago").unwrap_or(&value);
+        (false, duration)
+    };
+
+    let duration = duration.trim();
+    let unit_start = duration.find(|c: char| !c.is_ascii_digit())?;
+    let amount: i64 = duration[..unit_start].parse().ok()?;
+    let unit_ms: i64 = match duration[unit_start..].trim() {
+        "s" => 1000,
+        "m" => 60 * 1000,
+        "h" => 60 * 60 * 1000,
+        "d" => 24 * 60 * 60 * 1000,
+        "w" => 7 * 24 * 60 * 60 * 1000,
+        _ => return None,
+    };
+    let offset = amount.checked_mul(unit_ms)?;
+    let millis = if ahead {
+        now.checked_add(offset)?
+    } else {
+        now.checked_sub(offset)?
+    };
+    Some(Timestamp(millis))
+}
+
+fn now_millis() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
 /// Calculate days since Unix epoch for a given year (Jan 1).
 fn days_from_year(year: i32) -> i64 {
     // Days from 1970 to the given year
@@ -532,7 +668,106 @@ pub fn parse_ordering(value: &str) -> ParseResult<OrderBy> {
         });
     }
 
-    Ok(OrderBy { field, dir })
+    Ok(OrderBy::new(field, dir))
+}
+
+impl OrderBy {
+    /// Parses a sort specification such as `-priority,name`.
+    ///
+    /// Fields are separated by commas and sorted in the order given. A `-`
+    /// prefix sorts a field descending; `+` or no prefix sorts it ascending.
+    /// Each field must be one of `S`'s fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use standout_seeker::{Dir, OrderBy, SeekerSchema, SeekType};
+    ///
+    /// struct Task;
+    /// impl SeekerSchema for Task {
+    ///     fn field_type(field: &str) -> Option<SeekType> {
+    ///         match field {
+    ///             "name" => Some(SeekType::String),
+    ///             "priority" => Some(SeekType::Number),
+    ///             _ => None,
+    ///         }
+    ///     }
+    ///     fn field_names() -> &'static [&'static str] {
+    ///         &["name", "priority"]
+    ///     }
+    /// }
+    ///
+    /// let orderings = OrderBy::parse::<Task>("-priority,name").unwrap();
+    /// assert_eq!(orderings, [OrderBy::desc("priority"), OrderBy::asc("name")]);
+    /// ```
+    pub fn parse<S: SeekerSchema>(spec: &str) -> ParseResult<Vec<OrderBy>> {
+        let invalid = |reason: String| ParseError::InvalidOrdering {
+            value: spec.to_string(),
+            reason,
+        };
+
+        let mut orderings: Vec<OrderBy> = Vec::new();
+        for part in spec.split(',') {
+            let part = part.trim();
+            let (field, dir) = match part.strip_prefix('-') {
+                Some(field) => (field, Dir::Desc),
+                None => (part.strip_prefix('+').unwrap_or(part), Dir::Asc),
+            };
+            if field.is_empty() {
+                return Err(invalid("missing field name".to_string()));
+            }
+            if S::field_type(field).is_none() {
+                return Err(ParseError::UnknownField {
+                    field: field.to_string(),
+                    available: S::field_names().iter().map(|s| s.to_string()).collect(),
+                });
+            }
+            if orderings.iter().any(|o| o.field == field) {
+                return Err(invalid(format!("'{}' is listed more than once", field)));
+            }
+            orderings.push(OrderBy::new(field, dir));
+        }
+        Ok(orderings)
+    }
+}
+
+/// Checks `field` and `op` against the schema and parses `value` for the
+/// field's type. Without an operator, the type's default is used.
+pub(crate) fn parse_clause<S: SeekerSchema>(
+    field: &str,
+    op: Option<Op>,
+    value: String,
+) -> ParseResult<(Op, ClauseValue)> {
+    // Look up field type
+    let field_type = S::field_type(field).ok_or_else(|| ParseError::UnknownField {
+        field: field.to_string(),
+        available: S::field_names().iter().map(|s| s.to_string()).collect(),
+    })?;
+
+    // Determine operator (use default if not specified)
+    let op = op.unwrap_or_else(|| field_type.default_operator());
+
+    // Validate operator for field type
+    if !field_type.is_valid_operator(op) {
+        return Err(ParseError::InvalidOperator {
+            field: field.to_string(),
+            operator: op.to_string(),
+            field_type,
+        });
+    }
+
+    // Handle boolean fields and presence checks with empty value (bare flag)
+    let value = if value.is_empty()
+        && (field_type == SeekType::Bool || matches!(op, Op::Exists | Op::IsNull))
+    {
+        "true".to_string()
+    } else {
+        value
+    };
+
+    // Parse the value
+    let clause_value = parse_value::<S>(&value, field, field_type, op)?;
+    Ok((op, clause_value))
 }
 
 /// Parse key-value pairs into a [`Query`].
@@ -631,36 +866,9 @@ pub fn parse_query<S: SeekerSchema>(
             _ => {}
         }
 
-        // Parse field and operator
+        // Parse field and operator, then the value for the field's type
         let (field, parsed_op) = parse_key(&key);
-
-        // Look up field type
-        let field_type = S::field_type(&field).ok_or_else(|| ParseError::UnknownField {
-            field: field.clone(),
-            available: S::field_names().iter().map(|s| s.to_string()).collect(),
-        })?;
-
-        // Determine operator (use default if not specified)
-        let op = parsed_op.unwrap_or_else(|| field_type.default_operator());
-
-        // Validate operator for field type
-        if !field_type.is_valid_operator(op) {
-            return Err(ParseError::InvalidOperator {
-                field: field.clone(),
-                operator: op.to_string(),
-                field_type,
-            });
-        }
-
-        // Handle boolean fields with empty value (bare flag)
-        let value = if value.is_empty() && field_type == SeekType::Bool {
-            "true".to_string()
-        } else {
-            value
-        };
-
-        // Parse the value
-        let clause_value = parse_value::<S>(&value, &field, field_type, op)?;
+        let (op, clause_value) = parse_clause::<S>(&field, parsed_op, value)?;
 
         // Add clause to appropriate group
         query = match current_group {
@@ -730,6 +938,9 @@ mod tests {
         assert_eq!(parse_operator("endswith"), Some(Op::EndsWith));
         assert_eq!(parse_operator("contains"), Some(Op::Contains));
         assert_eq!(parse_operator("regex"), Some(Op::Regex));
+        assert_eq!(parse_operator("ieq"), Some(Op::IEq));
+        assert_eq!(parse_operator("icontains"), Some(Op::IContains));
+        assert_eq!(parse_operator("fuzzy"), Some(Op::Fuzzy));
     }
 
     #[test]
@@ -877,6 +1088,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_timestamp_relative() {
+        const HOUR: i64 = 60 * 60 * 1000;
+        const DAY: i64 = 24 * HOUR;
+        let now = 1_700_000_000_000;
+        let relative = |value| parse_relative(value, now).map(|ts| ts.0);
+
+        assert_eq!(relative("now"), Some(now));
+        assert_eq!(relative("now-1h"), Some(now - HOUR));
+        assert_eq!(relative("NOW + 2d"), Some(now + 2 * DAY));
+        assert_eq!(relative("7d"), Some(now - 7 * DAY));
+        assert_eq!(relative("7d ago"), Some(now - 7 * DAY));
+        assert_eq!(relative("2w ago"), Some(now - 14 * DAY));
+        assert_eq!(relative("30m"), Some(now - 30 * 60 * 1000));
+        for invalid in ["d", "7", "7y", "now*1h", "ago", "-7d"] {
+            assert_eq!(relative(invalid), None, "{invalid}");
+        }
+        // Out of range rather than wrapping around: the offset fits, the sum doesn't
+        assert_eq!(relative("now+9223372036854775s"), None);
+        assert_eq!(relative("99999999999999999w"), None);
+        assert_eq!(
+            parse_relative("now-9223372036854775s", -HOUR).map(|ts| ts.0),
+            None
+        );
+
+        let val =
+            parse_value::<TestTask>("now-1h", "created-at", SeekType::Timestamp, Op::Gt).unwrap();
+        let ClauseValue::Timestamp(ts) = val else {
+            panic!("Expected Timestamp");
+        };
+        assert!((now_millis() - HOUR - ts.0).abs() < 60_000);
+    }
+
     #[test]
     fn test_parse_timestamp_invalid() {
         let result =
@@ -1025,6 +1269,43 @@ mod tests {
         assert!(matches!(result, Err(ParseError::InvalidOrdering { .. })));
     }
 
+    // =========================================================================
+    // OrderBy::parse tests
+    // =========================================================================
+
+    #[test]
+    fn test_order_by_parse() {
+        let orderings = OrderBy::parse::<TestTask>("-priority, +name,created-at").unwrap();
+        assert_eq!(
+            orderings,
+            [
+                OrderBy::desc("priority"),
+                OrderBy::asc("name"),
+                OrderBy::asc("created-at")
+            ]
+        );
+
+        let query = Query::new().order_by_all(orderings);
+        assert_eq!(query.orderings().len(), 3);
+    }
+
+    #[test]
+    fn test_order_by_parse_errors() {
+        assert!(matches!(
+            OrderBy::parse::<TestTask>("-owner"),
+            Err(ParseError::UnknownField { field, .. }) if field == "owner"
+        ));
+        for spec in ["", "name,", "-", "name,-name"] {
+            assert!(
+                matches!(
+                    OrderBy::parse::<TestTask>(spec),
+                    Err(ParseError::InvalidOrdering { .. })
+                ),
+                "{spec}"
+            );
+        }
+    }
+
     // =========================================================================
     // parse_query tests
     // =========================================================================
@@ -1097,6 +1378,37 @@ mod tests {
         assert!(query.count(&Vec::<()>::new(), |_, _| crate::Value::None) == 0);
     }
 
+    #[test]
+    fn test_parse_query_sets_ranges_and_presence() {
+        let pairs = vec![
+            ("name-in".to_string(), "a, b".to_string()),
+            ("priority-between".to_string(), "1,5".to_string()),
+            ("created-at-exists".to_string(), "".to_string()),
+            ("status-null".to_string(), "false".to_string()),
+        ];
+        let query = parse_query::<TestTask>(pairs).unwrap();
+        let clauses = query.and_clauses();
+        assert!(matches!(&clauses[0].value, ClauseValue::StringSet(set) if set == &["a", "b"]));
+        assert!(matches!(
+            clauses[1].value,
+            ClauseValue::NumberRange(Number::I64(1), Number::I64(5))
+        ));
+        assert!(matches!(clauses[2].value, ClauseValue::Bool(true)));
+        assert_eq!(clauses[3].op, Op::IsNull);
+        assert!(matches!(clauses[3].value, ClauseValue::Bool(false)));
+
+        let pairs = vec![("priority-between".to_string(), "1".to_string())];
+        assert!(matches!(
+            parse_query::<TestTask>(pairs),
+            Err(ParseError::InvalidValue { .. })
+        ));
+        let pairs = vec![("done-between".to_string(), "1,2".to_string())];
+        assert!(matches!(
+            parse_query::<TestTask>(pairs),
+            Err(ParseError::InvalidOperator { .. })
+        ));
+    }
+
     #[test]
     fn test_parse_query_unknown_field() {
         let pairs = vec![("unknown-field".to_string(), "test".to_string())];
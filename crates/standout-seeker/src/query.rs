@@ -41,6 +41,8 @@ use crate::value::{Timestamp, Value};
 ///     .build();
 /// ```
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct Query {
     and_clauses: Vec<Clause>,
     or_clauses: Vec<Clause>,
@@ -152,6 +154,21 @@ impl Query {
         self.and(field, Op::In, ClauseValue::EnumSet(set))
     }
 
+    /// Adds an AND between clause for a number or timestamp range (`1..=5`).
+    pub fn and_between(self, field: &str, range: impl Into<ClauseValue>) -> Self {
+        self.and(field, Op::Between, range)
+    }
+
+    /// Adds an AND clause matching items where the field has a value.
+    pub fn and_exists(self, field: &str) -> Self {
+        self.and(field, Op::Exists, true)
+    }
+
+    /// Adds an AND clause matching items where the field has no value.
+    pub fn and_null(self, field: &str) -> Self {
+        self.and(field, Op::IsNull, true)
+    }
+
     /// Adds an AND before clause (timestamp less than).
     pub fn and_before(self, field: &str, ts: Timestamp) -> Self {
         self.and(field, Op::Before, ts)
@@ -226,6 +243,21 @@ impl Query {
         self.or(field, Op::In, ClauseValue::EnumSet(set))
     }
 
+    /// Adds an OR between clause for a number or timestamp range (`1..=5`).
+    pub fn or_between(self, field: &str, range: impl Into<ClauseValue>) -> Self {
+        self.or(field, Op::Between, range)
+    }
+
+    /// Adds an OR clause matching items where the field has a value.
+    pub fn or_exists(self, field: &str) -> Self {
+        self.or(field, Op::Exists, true)
+    }
+
+    /// Adds an OR clause matching items where the field has no value.
+    pub fn or_null(self, field: &str) -> Self {
+        self.or(field, Op::IsNull, true)
+    }
+
     /// Adds an OR before clause.
     pub fn or_before(self, field: &str, ts: Timestamp) -> Self {
         self.or(field, Op::Before, ts)
@@ -300,6 +332,11 @@ impl Query {
         self.not(field, Op::In, ClauseValue::EnumSet(set))
     }
 
+    /// Adds a NOT between clause for a number or timestamp range (`1..=5`).
+    pub fn not_between(self, field: &str, range: impl Into<ClauseValue>) -> Self {
+        self.not(field, Op::Between, range)
+    }
+
     /// Adds a NOT before clause.
     pub fn not_before(self, field: &str, ts: Timestamp) -> Self {
         self.not(field, Op::Before, ts)
@@ -320,6 +357,13 @@ impl Query {
         self
     }
 
+    /// Adds an ordering clause that compares strings naturally: ignoring
+    /// case and accents, with numbers by value (`item2` before `item10`).
+    pub fn order_by_natural(mut self, field: &str, dir: Dir) -> Self {
+        self.orderings.push(OrderBy::new(field, dir).natural());
+        self
+    }
+
     /// Adds an ascending ordering clause.
     pub fn order_asc(self, field: &str) -> Self {
         self.order_by(field, Dir::Asc)
@@ -330,6 +374,12 @@ impl Query {
         self.order_by(field, Dir::Desc)
     }
 
+    /// Adds ordering clauses, such as those from [`OrderBy::parse`].
+    pub fn order_by_all(mut self, orderings: impl IntoIterator<Item = OrderBy>) -> Self {
+        self.orderings.extend(orderings);
+        self
+    }
+
     // ========================================================================
     // Limits
     // ========================================================================
@@ -459,7 +509,37 @@ impl Query {
             results.sort_by(|a, b| compare_by_orderings(*a, *b, &self.orderings, &accessor));
         }
 
-        // Apply offset
+        self.paginate(results)
+    }
+
+    /// Parallel version of [`filter`](Self::filter), for large collections.
+    ///
+    /// Matching and sorting are split across rayon's thread pool. Results
+    /// are the same as `filter`, in the same order. For small collections
+    /// the thread overhead outweighs the gain; prefer `filter` below a few
+    /// hundred thousand items.
+    #[cfg(feature = "rayon")]
+    pub fn par_filter<'a, T, F>(&self, items: &'a [T], accessor: F) -> Vec<&'a T>
+    where
+        T: Sync,
+        for<'b> F: Fn(&'b T, &str) -> Value<'b> + Sync,
+    {
+        use rayon::prelude::*;
+
+        let mut results: Vec<&'a T> = items
+            .par_iter()
+            .filter(|item| self.matches(*item, &accessor))
+            .collect();
+
+        if !self.orderings.is_empty() {
+            results.par_sort_by(|a, b| compare_by_orderings(*a, *b, &self.orderings, &accessor));
+        }
+
+        self.paginate(results)
+    }
+
+    /// Applies offset, then limit.
+    fn paginate<'a, T>(&self, mut results: Vec<&'a T>) -> Vec<&'a T> {
         let offset = self.offset.unwrap_or(0);
         if offset > 0 {
             if offset >= results.len() {
@@ -468,7 +548,6 @@ impl Query {
             results = results.into_iter().skip(offset).collect();
         }
 
-        // Apply limit
         if let Some(limit) = self.limit {
             results.truncate(limit);
         }
@@ -630,6 +709,39 @@ mod tests {
         assert_eq!(results[0].name, "Urgent Task");
     }
 
+    #[test]
+    fn between_and_presence() {
+        let tasks = sample_tasks();
+        let query = Query::new()
+            .and_between("priority", 2..=3)
+            .and_exists("name")
+            .and_null("due")
+            .build();
+        assert_eq!(query.count(&tasks, accessor), 2);
+
+        let query = Query::new().not_between("priority", 1..=4).build();
+        assert_eq!(query.count(&tasks, accessor), 2);
+        assert_eq!(Query::new().and_exists("due").count(&tasks, accessor), 0);
+    }
+
+    #[test]
+    fn natural_ordering() {
+        let mut tasks = sample_tasks();
+        tasks[0].name = "Task 10".to_string();
+        tasks[1].name = "task 9".to_string();
+        let query = Query::new()
+            .and_lte("priority", 2i64)
+            .order_by_natural("name", Dir::Asc)
+            .build();
+
+        let names: Vec<_> = query
+            .filter(&tasks, accessor)
+            .into_iter()
+            .map(|t| t.name.as_str())
+            .collect();
+        assert_eq!(names, ["task 9", "Task 10"]);
+    }
+
     #[test]
     fn or_clauses() {
         let tasks = sample_tasks();
@@ -126,6 +126,8 @@ impl<'a> Value<'a> {
 /// Comparisons between different numeric types are handled by converting
 /// to the appropriate common type.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
 pub enum Number {
     /// Signed 64-bit integer.
     I64(i64),
@@ -256,6 +258,7 @@ impl From<isize> for Number {
 /// assert!(Timestamp(1000) < Timestamp(2000));
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Timestamp(pub i64);
 
 impl Timestamp {
@@ -100,7 +100,7 @@ fn op_type_checks_comprehensive() {
     assert!(Op::Contains.is_string_op());
     assert!(Op::Regex.is_string_op());
     assert!(!Op::Gt.is_string_op());
-    assert!(!Op::In.is_string_op());
+    assert!(!Op::Between.is_string_op());
 
     // Number ops
     assert!(Op::Eq.is_number_op());
@@ -110,7 +110,7 @@ fn op_type_checks_comprehensive() {
     assert!(Op::Lt.is_number_op());
     assert!(Op::Lte.is_number_op());
     assert!(!Op::Contains.is_number_op());
-    assert!(!Op::In.is_number_op());
+    assert!(!Op::Before.is_number_op());
 
     // Timestamp ops
     assert!(Op::Eq.is_timestamp_op());
@@ -0,0 +1,30 @@
+#![cfg(feature = "rayon")]
+
+use standout_seeker::{Number, Query, Value};
+
+fn accessor<'a>(n: &'a u64, field: &str) -> Value<'a> {
+    match field {
+        "n" => Value::Number(Number::U64(*n)),
+        "bucket" => Value::Number(Number::U64(n % 7)),
+        _ => Value::None,
+    }
+}
+
+#[test]
+fn par_filter_matches_filter() {
+    let items: Vec<u64> = (0..50_000).rev().collect();
+    let query = Query::new()
+        .and_gte("n", 1_000u64)
+        .not_eq("bucket", 3u64)
+        .order_asc("bucket")
+        .order_desc("n")
+        .offset(10)
+        .limit(2_000)
+        .build();
+
+    let sequential = query.filter(&items, accessor);
+    let parallel = query.par_filter(&items, accessor);
+
+    assert_eq!(parallel.len(), 2_000);
+    assert_eq!(parallel, sequential);
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "serde")]
+
+use standout_seeker::{ClauseValue, Dir, Number, Op, OrderBy, Query, Timestamp, Value};
+
+fn accessor<'a>(name: &'a &str, field: &str) -> Value<'a> {
+    match field {
+        "name" => Value::String(name),
+        "size" => Value::Number(Number::U64(name.len() as u64)),
+        _ => Value::None,
+    }
+}
+
+#[test]
+fn query_round_trips_through_json() {
+    let query = Query::new()
+        .and_between("size", 3..=5)
+        .and_regex("name", "^b")
+        .unwrap()
+        .or_contains("name", "a")
+        .or_eq("name", "bolt")
+        .not_eq("name", "bar")
+        .and("when", Op::After, Timestamp(1000))
+        .order_desc("name")
+        .limit(10)
+        .offset(1)
+        .build();
+
+    let json = serde_json::to_string(&query).unwrap();
+    let loaded: Query = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(serde_json::to_string(&loaded).unwrap(), json);
+    assert_eq!(loaded.orderings(), &[OrderBy::desc("name")]);
+    assert_eq!(loaded.get_limit(), Some(10));
+    assert_eq!(loaded.get_offset(), Some(1));
+    assert!(matches!(
+        &loaded.and_clauses()[1].value,
+        ClauseValue::Regex(regex) if regex.as_str() == "^b"
+    ));
+}
+
+#[test]
+fn query_loads_from_hand_written_config() {
+    let query: Query = serde_json::from_str(
+        r#"{
+            "and_clauses": [{ "field": "name", "op": "startswith", "value": { "string": "b" } }],
+            "orderings": [{ "field": "name", "dir": "asc" }]
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(query.orderings()[0].dir, Dir::Asc);
+    let names = ["bar", "foo", "baz"];
+    let matched: Vec<_> = query.filter(&names, accessor).into_iter().collect();
+    assert_eq!(matched, [&"bar", &"baz"]);
+
+    let invalid =
+        r#"{ "and_clauses": [{ "field": "name", "op": "regex", "value": { "regex": "(" } }] }"#;
+    assert!(serde_json::from_str::<Query>(invalid).is_err());
+}
+
+#[test]
+fn explain_serializes_to_json() {
+    let names = ["bar", "foo"];
+    let explain = Query::new()
+        .and_startswith("name", "b")
+        .build()
+        .explain_with(&names, accessor);
+
+    let json = serde_json::to_value(&explain).unwrap();
+    assert_eq!(json["and"][0]["clause"], "name startswith \"b\"");
+    assert_eq!(json["and"][0]["matches"], 1);
+    assert_eq!(json["total"], 2);
+}
@@ -496,6 +496,7 @@ fn output_mode_flag(mode: OutputMode) -> &'static str {
         OutputMode::Json => "json",
         OutputMode::Yaml => "yaml",
         OutputMode::Xml => "xml",
+        OutputMode::Toml => "toml",
         OutputMode::Csv => "csv",
     }
 }
@@ -568,6 +569,15 @@ impl TestResult {
         }
     }
 
+    /// Returns the text output the handler sent to stderr with
+    /// `Output::RenderTo(Destination::Stderr, _)`, or `""` otherwise.
+    pub fn stderr(&self) -> &str {
+        match &self.outcome {
+            RunResult::Stderr(s) => s.as_str(),
+            _ => "",
+        }
+    }
+
     /// Returns `true` if the run produced text output.
     pub fn is_handled(&self) -> bool {
         matches!(self.outcome, RunResult::Handled(_))
@@ -590,16 +600,20 @@ impl TestResult {
     // --- assertions ----------------------------------------------------------
 
     /// Panics unless the run ended in a successful dispatch
-    /// (`RunResult::Handled`, `RunResult::Silent`, or `RunResult::Binary`).
+    /// (`RunResult::Handled`, `RunResult::Stderr`, `RunResult::Silent`, or
+    /// `RunResult::Binary`).
     /// `RunResult::NoMatch` and `RunResult::Error` trigger a panic.
     #[track_caller]
     pub fn assert_success(&self) {
         match &self.outcome {
-            RunResult::Handled(_) | RunResult::Silent | RunResult::Binary(_, _) => {}
+            RunResult::Handled(_)
+            | RunResult::Stderr(_)
+            | RunResult::Silent
+            | RunResult::Binary(_, _) => {}
             RunResult::NoMatch(_) => {
                 panic!("expected successful dispatch but no handler matched; stdout was empty")
             }
-            RunResult::Error(msg) => {
+            RunResult::Error(msg) | RunResult::Exit(msg, _) => {
                 panic!("expected successful dispatch, got error: {}", msg)
             }
             _ => panic!(
@@ -611,18 +625,34 @@ impl TestResult {
 
     /// Returns `true` if the run produced an error.
     pub fn is_error(&self) -> bool {
-        matches!(self.outcome, RunResult::Error(_))
+        self.outcome.is_error()
     }
 
     /// Returns the error message if the run produced one, or `None`.
     pub fn error(&self) -> Option<&str> {
-        match &self.outcome {
-            RunResult::Error(s) => Some(s.as_str()),
-            _ => None,
+        self.outcome.error()
+    }
+
+    /// Returns the process exit code for the run (see `RunResult::exit_code`).
+    pub fn exit_code(&self) -> u8 {
+        self.outcome.exit_code()
+    }
+
+    /// Panics unless the run's exit code (see [`exit_code`](Self::exit_code)) is `expected`.
+    #[track_caller]
+    pub fn assert_exit_code(&self, expected: u8) {
+        let actual = self.exit_code();
+        if actual != expected {
+            panic!(
+                "expected exit code {}, got {} ({:?})",
+                expected,
+                actual,
+                describe_outcome(&self.outcome)
+            );
         }
     }
 
-    /// Panics unless the run ended in `RunResult::Error`.
+    /// Panics unless the run ended in `RunResult::Error` or `RunResult::Exit`.
     #[track_caller]
     pub fn assert_error(&self) {
         if !self.is_error() {
@@ -633,7 +663,7 @@ impl TestResult {
         }
     }
 
-    /// Panics unless the run ended in `RunResult::Error` and the message
+    /// Panics unless the run ended in `RunResult::Error` or `RunResult::Exit` and the message
     /// contains `needle`.
     #[track_caller]
     pub fn assert_error_contains(&self, needle: &str) {
@@ -689,9 +719,11 @@ impl TestResult {
 fn describe_outcome(o: &RunResult) -> String {
     match o {
         RunResult::Handled(s) => format!("Handled({:?})", s),
+        RunResult::Stderr(s) => format!("Stderr({:?})", s),
         RunResult::Silent => "Silent".into(),
         RunResult::Binary(b, f) => format!("Binary(len={}, {:?})", b.len(), f),
         RunResult::Error(s) => format!("Error({:?})", s),
+        RunResult::Exit(s, code) => format!("Exit({:?}, {})", s, code),
         RunResult::NoMatch(_) => "NoMatch".into(),
         _ => "Unknown".into(),
     }
@@ -480,3 +480,59 @@ fn no_match_reports_cleanly() {
         result.outcome()
     );
 }
+
+#[test]
+#[serial]
+fn usage_error_reports_exit_code_2() {
+    let app = build_echo_app("{{ msg }}");
+    let result = TestHarness::new().run(&app, echo_command(), vec!["app", "echo", "--bogus"]);
+    result.assert_error();
+    result.assert_exit_code(2);
+}
+
+#[test]
+#[serial]
+fn exit_error_reports_its_code() {
+    let app = App::builder()
+        .command(
+            "show",
+            |_m, _ctx| -> standout::cli::HandlerResult<()> {
+                let missing = std::io::Error::new(std::io::ErrorKind::NotFound, "no such item");
+                Err(standout::cli::ExitError::not_found(missing).into())
+            },
+            "",
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+    let cmd = Command::new("app").subcommand(Command::new("show"));
+
+    let result = TestHarness::new().run(&app, cmd, vec!["app", "show"]);
+    result.assert_error_contains("no such item");
+    result.assert_exit_code(3);
+}
+
+#[test]
+#[serial]
+fn render_to_stderr_keeps_stdout_clean() {
+    let app = App::builder()
+        .command(
+            "sync",
+            |_m, _ctx| {
+                Ok(Output::RenderTo(
+                    standout::cli::Destination::Stderr,
+                    json!({"synced": 3}),
+                ))
+            },
+            "synced {{ synced }}",
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+    let cmd = Command::new("app").subcommand(Command::new("sync"));
+
+    let result = TestHarness::new().run(&app, cmd, vec!["app", "sync"]);
+    result.assert_success();
+    assert_eq!(result.stderr(), "synced 3");
+    assert_eq!(result.stdout(), "");
+}
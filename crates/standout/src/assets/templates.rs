@@ -9,6 +9,7 @@
 /// The registry will make them available both with and without extension.
 pub const FRAMEWORK_TEMPLATES: &[(&str, &str)] = &[
     ("standout/list-view.jinja", LIST_VIEW_TEMPLATE),
+    ("standout/detail-view.jinja", DETAIL_VIEW_TEMPLATE),
     ("standout/empty-list.jinja", EMPTY_LIST_TEMPLATE),
     ("standout/filter-summary.jinja", FILTER_SUMMARY_TEMPLATE),
 ];
@@ -16,6 +17,7 @@ pub const FRAMEWORK_TEMPLATES: &[(&str, &str)] = &[
 /// Default list view template.
 ///
 /// This template renders `ListViewResult<T>` with support for:
+/// - Title
 /// - Introduction text (header)
 /// - Items (tabular or custom rendering)
 /// - Ending text (footer)
@@ -24,13 +26,18 @@ pub const FRAMEWORK_TEMPLATES: &[(&str, &str)] = &[
 ///
 /// Template variables:
 /// - `items`: The items to display
+/// - `title`: Optional title
 /// - `intro`: Optional header text
 /// - `ending`: Optional footer text
 /// - `messages`: Status messages (level, text)
 /// - `total_count`: Total before filtering (for "showing X of Y")
 /// - `filter_summary`: Description of applied filters
 /// - `empty_message`: Custom message when list is empty
-const LIST_VIEW_TEMPLATE: &str = r#"{% if intro %}
+const LIST_VIEW_TEMPLATE: &str = r#"{% if title %}
+[standout-header]{{ title }}[/standout-header]
+
+{% endif %}
+{% if intro %}
 {{ intro }}
 
 {% endif %}
@@ -62,6 +69,44 @@ const LIST_VIEW_TEMPLATE: &str = r#"{% if intro %}
 {% endfor %}
 "#;
 
+/// Default detail view template.
+///
+/// This template renders `DetailViewResult<T>` as one `label: value` line
+/// per field, with labels padded to the same width.
+///
+/// Template variables:
+/// - `item`: The item to display, or none
+/// - `title`: Optional title
+/// - `fields`: Fields to show (key, label); every field of the item if empty
+/// - `messages`: Status messages (level, text)
+/// - `empty_message`: Custom message when there is no item
+/// - `field_styles`: Style names by field key, applied to values
+const DETAIL_VIEW_TEMPLATE: &str = r#"{%- set styles = field_styles | default({}) -%}
+{%- if title -%}
+[standout-header]{{ title }}[/standout-header]
+
+{% endif -%}
+{%- if item is none -%}
+{{ empty_message | default("Not found.") }}
+{% else -%}
+{%- if fields -%}
+{%- set keys = fields | map(attribute="key") | list -%}
+{%- set labels = fields | map(attribute="label") | list -%}
+{%- else -%}
+{%- set keys = item | list -%}
+{%- set labels = keys -%}
+{%- endif -%}
+{%- set width = labels | map("display_width") | max -%}
+{%- for key in keys -%}
+{%- set value = item[key] -%}
+[standout-muted]{{ (labels[loop.index0] ~ ":") | pad_right(width + 1) }}[/standout-muted] {{ (value if value is defined and value is not none else "-") | style_as(styles[key] | default("")) }}
+{% endfor -%}
+{%- endif -%}
+{%- for msg in messages -%}
+[standout-{{ msg.level }}]{{ msg.text }}[/standout-{{ msg.level }}]
+{% endfor -%}
+"#;
+
 /// Template for empty list message.
 const EMPTY_LIST_TEMPLATE: &str = r#"{{ message | default("No items found.") }}
 "#;
@@ -0,0 +1,253 @@
+//! Batch invocations over stdin.
+//!
+//! `batch(cmd)` lets other programs drive the app without spawning a
+//! process per call. Each input line is a JSON request naming the command
+//! and its arguments; each output line is the JSON result of that request,
+//! in the same order:
+//!
+//! ```text
+//! → {"id": 1, "command": "list", "args": ["--all"]}
+//! ← {"id":1,"ok":true,"exit_code":0,"output":{"items":[...]}}
+//! → {"id": 2, "args": ["add", "buy milk"]}
+//! ← {"id":2,"ok":true,"exit_code":0,"output":{"added":"buy milk"}}
+//! ```
+
+use std::io::{self, BufRead, Write};
+
+use clap::Command;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::execution::RunState;
+use super::AppBuilder;
+use crate::cli::handler::{ExitError, RunResult};
+use crate::OutputMode;
+
+/// One invocation read from a batch input line.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BatchRequest {
+    /// Echoed back on the result, so callers can match them up.
+    #[serde(default)]
+    id: Value,
+    /// Command path, with `.` or spaces between levels (`db.migrate`).
+    #[serde(default)]
+    command: Option<String>,
+    /// Arguments after the command path.
+    #[serde(default)]
+    args: Vec<String>,
+}
+
+impl AppBuilder {
+    /// Reads JSON requests from stdin, one per line, and writes one JSON
+    /// result per line to stdout.
+    ///
+    /// A request is an object with an optional `id`, an optional `command`
+    /// path (`"db.migrate"` or `"db migrate"`) and the remaining `args`, all
+    /// strings. Each is dispatched like a command line, with the output mode
+    /// forced to JSON and the same handlers, hooks and
+    /// [`app_state`](AppBuilder::app_state) for every request.
+    ///
+    /// Each result has the request's `id`, `ok`, the `exit_code` the command
+    /// line would have exited with, and either `output` (the command's JSON
+    /// output, or a string for output that isn't JSON) or `error`.
+    /// Deprecation warnings are listed under `warnings`. Lines that aren't
+    /// valid requests get an error result with exit code 2, and the batch
+    /// keeps going. Blank lines are skipped.
+    ///
+    /// Returns when the input ends.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// if std::env::args().nth(1).as_deref() == Some("--batch") {
+    ///     app.batch(cmd)?;
+    /// } else {
+    ///     app.run(cmd, std::env::args());
+    /// }
+    /// ```
+    pub fn batch(&self, cmd: Command) -> io::Result<()> {
+        self.run_batch(cmd, &mut io::stdin().lock(), &mut io::stdout().lock())
+    }
+
+    /// The batch loop, reading from `input` and writing to `out`.
+    fn run_batch(
+        &self,
+        cmd: Command,
+        input: &mut dyn BufRead,
+        out: &mut dyn Write,
+    ) -> io::Result<()> {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if input.read_line(&mut line)? == 0 {
+                return Ok(());
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let response = match serde_json::from_str::<BatchRequest>(&line) {
+                Ok(request) => self.run_batch_request(&cmd, request),
+                Err(e) => json!({
+                    "id": Value::Null,
+                    "ok": false,
+                    "exit_code": ExitError::USAGE,
+                    "error": format!("invalid request: {}", e),
+                }),
+            };
+            writeln!(out, "{}", response)?;
+            out.flush()?;
+        }
+    }
+
+    /// Dispatches one request and describes its result.
+    fn run_batch_request(&self, cmd: &Command, request: BatchRequest) -> Value {
+        let path = request
+            .command
+            .iter()
+            .flat_map(|command| command.split(['.', ' ']))
+            .filter(|part| !part.is_empty())
+            .map(String::from);
+        let args: Vec<String> = std::iter::once(cmd.get_name().to_string())
+            .chain(path)
+            .chain(request.args)
+            .collect();
+
+        let mut run = RunState::default();
+        let result =
+            self.dispatch_from_with_mode(cmd.clone(), &args, Some(OutputMode::Json), &mut run);
+        let warnings: Vec<String> = self
+            .deprecation_warnings(&run)
+            .iter()
+            .map(|warning| console::strip_ansi_codes(warning).into_owned())
+            .collect();
+
+        let (exit_code, outcome) = match result {
+            RunResult::Handled(output) | RunResult::Stderr(output) => {
+                (0, Ok(parse_output(&output)))
+            }
+            RunResult::Silent => (0, Ok(Value::Null)),
+            RunResult::Binary(_, filename) => (
+                1,
+                Err(Value::String(format!(
+                    "binary output ({}) can't be returned in batch mode",
+                    filename
+                ))),
+            ),
+            RunResult::Error(message) => (1, Err(parse_output(&message))),
+            RunResult::Exit(message, code) => (code, Err(parse_output(&message))),
+            // No handler matched
+            _ => (
+                ExitError::USAGE,
+                Err(Value::String(format!(
+                    "no command handles `{}`",
+                    args[1..].join(" ")
+                ))),
+            ),
+        };
+
+        let mut response = json!({
+            "id": request.id,
+            "ok": outcome.is_ok(),
+            "exit_code": exit_code,
+        });
+        match outcome {
+            Ok(output) => response["output"] = output,
+            Err(error) => response["error"] = error,
+        }
+        if !warnings.is_empty() {
+            response["warnings"] = json!(warnings);
+        }
+        response
+    }
+}
+
+/// Parses rendered output as JSON, keeping it as a string if it isn't.
+fn parse_output(output: &str) -> Value {
+    let output = output.trim_end();
+    serde_json::from_str(output).unwrap_or_else(|_| Value::String(output.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::handler::Output as HandlerOutput;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn run(builder: &AppBuilder, cmd: Command, input: &str) -> Vec<Value> {
+        let mut out = Vec::new();
+        builder
+            .run_batch(cmd, &mut io::Cursor::new(input), &mut out)
+            .unwrap();
+        String::from_utf8(out)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_batch_dispatches_requests_with_shared_state() {
+        struct Counter(AtomicU32);
+
+        let builder = AppBuilder::new()
+            .app_state(Counter(AtomicU32::new(0)))
+            .command(
+                "counter.bump",
+                |m, ctx| {
+                    let counter = ctx.app_state.get_required::<Counter>()?;
+                    let by: u32 = m.get_one::<String>("by").unwrap().parse()?;
+                    let count = counter.0.fetch_add(by, Ordering::SeqCst) + by;
+                    Ok(HandlerOutput::Render(json!({ "count": count })))
+                },
+                "count={{ count }}",
+            )
+            .unwrap();
+        let cmd = Command::new("app").subcommand(
+            Command::new("counter")
+                .subcommand(Command::new("bump").arg(clap::Arg::new("by").required(true))),
+        );
+
+        let results = run(
+            &builder,
+            cmd,
+            concat!(
+                r#"{"id": 1, "command": "counter.bump", "args": ["2"]}"#,
+                "\n\n",
+                r#"{"id": "b", "args": ["counter", "bump", "3"]}"#,
+                "\n",
+                r#"{"id": 3, "command": "counter bump", "args": ["x"]}"#,
+                "\n",
+            ),
+        );
+
+        assert_eq!(
+            results[0],
+            json!({ "id": 1, "ok": true, "exit_code": 0, "output": { "count": 2 } })
+        );
+        assert_eq!(results[1]["id"], "b");
+        assert_eq!(results[1]["output"], json!({ "count": 5 }));
+        assert_eq!(results[2]["ok"], false);
+        assert_eq!(results[2]["exit_code"], 1);
+        assert!(results[2]["error"].to_string().contains("invalid digit"));
+        assert_eq!(results.len(), 3);
+    }
+
+    #[test]
+    fn test_batch_reports_bad_requests_and_usage_errors() {
+        let builder = AppBuilder::new();
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+
+        let results = run(&builder, cmd, "not json\n{\"args\": [\"nope\"]}\n");
+
+        assert_eq!(results[0]["id"], Value::Null);
+        assert_eq!(results[0]["exit_code"], 2);
+        assert!(results[0]["error"]
+            .as_str()
+            .unwrap()
+            .starts_with("invalid request"));
+        assert_eq!(results[1]["ok"], false);
+        assert_eq!(results[1]["exit_code"], 2);
+    }
+}
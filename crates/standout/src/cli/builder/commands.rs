@@ -17,6 +17,7 @@ use crate::cli::group::{
 use crate::cli::handler::{CommandContext, FnHandler, Handler, HandlerResult};
 use crate::cli::hooks::Hooks;
 use crate::setup::SetupError;
+use crate::OutputMode;
 
 impl AppBuilder {
     /// Creates a command group for organizing related commands.
@@ -68,7 +69,7 @@ impl AppBuilder {
         configure: C,
     ) -> Result<Self, SetupError>
     where
-        F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + 'static,
+        F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + Send + 'static,
         T: Serialize + 'static,
         C: FnOnce(CommandConfig<FnHandler<F, T>>) -> CommandConfig<FnHandler<F, T>>,
     {
@@ -86,15 +87,31 @@ impl AppBuilder {
             self.command_hooks.insert(path.to_string(), hooks);
         }
 
+        for alias in std::mem::take(&mut config.aliases) {
+            self.add_command_alias(path, &alias);
+        }
+
+        if let Some(mode) = config.output_mode {
+            self.command_output_modes.insert(path.to_string(), mode);
+        }
+
+        if let Some(timeout) = config.timeout {
+            self.command_timeouts.insert(path.to_string(), timeout);
+        }
+
+        if config.hidden {
+            self.hidden_commands.insert(path.to_string());
+        }
+
         // Create a recipe for deferred closure creation using the handler
         let recipe = ClosureRecipe::new(config.handler);
 
         // Store pending command - check for duplicates
-        if self.pending_commands.borrow().contains_key(path) {
+        if self.pending_commands.lock().unwrap().contains_key(path) {
             return Err(SetupError::DuplicateCommand(path.to_string()));
         }
 
-        self.pending_commands.borrow_mut().insert(
+        self.pending_commands.lock().unwrap().insert(
             path.to_string(),
             PendingCommand {
                 recipe: Box::new(recipe),
@@ -115,13 +132,35 @@ impl AppBuilder {
             let path = format!("{}.{}", prefix, name);
 
             match entry {
-                GroupEntry::Command { mut handler } => {
+                GroupEntry::Command {
+                    mut handler,
+                    aliases,
+                    output_mode,
+                    timeout,
+                    hidden,
+                } => {
                     // Resolve template
                     let template = handler
                         .template()
                         .map(String::from)
                         .unwrap_or_else(|| self.resolve_template(&path));
 
+                    for alias in aliases {
+                        self.add_command_alias(&path, &alias);
+                    }
+
+                    if let Some(mode) = output_mode {
+                        self.command_output_modes.insert(path.clone(), mode);
+                    }
+
+                    if let Some(timeout) = timeout {
+                        self.command_timeouts.insert(path.clone(), timeout);
+                    }
+
+                    if hidden {
+                        self.hidden_commands.insert(path.clone());
+                    }
+
                     // Extract and register hooks
                     if let Some(hooks) = handler.take_hooks() {
                         self.command_hooks.insert(path.clone(), hooks);
@@ -131,12 +170,12 @@ impl AppBuilder {
                     let recipe = ErasedConfigRecipe::from_handler(handler);
 
                     // Check for duplicates
-                    if self.pending_commands.borrow().contains_key(&path) {
+                    if self.pending_commands.lock().unwrap().contains_key(&path) {
                         return Err(SetupError::DuplicateCommand(path.clone()));
                     }
 
                     // Store pending command
-                    self.pending_commands.borrow_mut().insert(
+                    self.pending_commands.lock().unwrap().insert(
                         path,
                         PendingCommand {
                             recipe: Box::new(recipe),
@@ -206,7 +245,7 @@ impl AppBuilder {
     /// ```
     pub fn command<F, T>(self, path: &str, handler: F, template: &str) -> Result<Self, SetupError>
     where
-        F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + 'static,
+        F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + Send + 'static,
         T: Serialize + 'static,
     {
         self.command_handler(path, FnHandler::new(handler), template)
@@ -249,7 +288,7 @@ impl AppBuilder {
         template: &str,
     ) -> Result<Self, SetupError>
     where
-        H: Handler<Output = T> + 'static,
+        H: Handler<Output = T> + Send + 'static,
         T: Serialize + 'static,
     {
         let template = template.to_string();
@@ -258,12 +297,12 @@ impl AppBuilder {
         let recipe = StructRecipe::new(handler);
 
         // Check for duplicates
-        if self.pending_commands.borrow().contains_key(path) {
+        if self.pending_commands.lock().unwrap().contains_key(path) {
             return Err(SetupError::DuplicateCommand(path.to_string()));
         }
 
         // Store pending command - closure will be created at dispatch time
-        self.pending_commands.borrow_mut().insert(
+        self.pending_commands.lock().unwrap().insert(
             path.to_string(),
             PendingCommand {
                 recipe: Box::new(recipe),
@@ -299,15 +338,15 @@ impl AppBuilder {
     /// ```
     pub fn command_passthrough<F>(self, path: &str, handler: F) -> Result<Self, SetupError>
     where
-        F: FnMut(&ArgMatches, &CommandContext) -> Result<(), anyhow::Error> + 'static,
+        F: FnMut(&ArgMatches, &CommandContext) -> Result<(), anyhow::Error> + Send + 'static,
     {
         let recipe = PassthroughRecipe::new(handler);
 
-        if self.pending_commands.borrow().contains_key(path) {
+        if self.pending_commands.lock().unwrap().contains_key(path) {
             return Err(SetupError::DuplicateCommand(path.to_string()));
         }
 
-        self.pending_commands.borrow_mut().insert(
+        self.pending_commands.lock().unwrap().insert(
             path.to_string(),
             PendingCommand {
                 recipe: Box::new(recipe),
@@ -366,6 +405,137 @@ impl AppBuilder {
         self.command_hooks.insert(path.to_string(), hooks);
         self
     }
+
+    /// Registers hooks that wrap every command (auth checks, timing, telemetry).
+    ///
+    /// Middleware runs around each command's own [`hooks`](Self::hooks) in a
+    /// fixed order: pre-dispatch hooks of middleware run first, in
+    /// registration order, then the command's; post-dispatch and post-output
+    /// hooks run the command's first, then middleware in reverse registration
+    /// order. The first middleware registered is therefore the outermost layer.
+    ///
+    /// A pre-dispatch hook that returns an error short-circuits the command:
+    /// the handler doesn't run and the error is reported like any hook error.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use std::time::Instant;
+    ///
+    /// struct Started(Instant);
+    ///
+    /// App::builder()
+    ///     .middleware(Hooks::new().pre_dispatch(|_m, _ctx| {
+    ///         if std::env::var("API_TOKEN").is_err() {
+    ///             return Err(HookError::pre_dispatch("not logged in"));
+    ///         }
+    ///         Ok(())
+    ///     }))
+    ///     .middleware(Hooks::new()
+    ///         .pre_dispatch(|_m, ctx| {
+    ///             ctx.extensions.insert(Started(Instant::now()));
+    ///             Ok(())
+    ///         })
+    ///         .post_output(|_m, ctx, output| {
+    ///             if let Some(Started(start)) = ctx.extensions.get::<Started>() {
+    ///                 eprintln!("{} took {:?}", ctx.command_path.join(" "), start.elapsed());
+    ///             }
+    ///             Ok(output)
+    ///         }))
+    ///     .build()?
+    ///     .run(cmd, args);
+    /// ```
+    pub fn middleware(mut self, hooks: Hooks) -> Self {
+        self.middleware.push(hooks);
+        self
+    }
+
+    /// Registers an alias for the command at `path` (e.g. `ls` for `list`).
+    ///
+    /// Equivalent to [`CommandConfig::alias`](crate::cli::CommandConfig::alias)
+    /// for commands registered without a config closure. The alias is added to
+    /// the clap command as a visible alias, dispatches to the same handler, and
+    /// is listed in help.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .command("config.list", list_handler, "{{ items }}")?
+    ///     .command_alias("config.list", "ls")
+    ///     .build()?;
+    /// // myapp config ls
+    /// ```
+    pub fn command_alias(mut self, path: &str, alias: &str) -> Self {
+        self.add_command_alias(path, alias);
+        self
+    }
+
+    /// Hides the command at `path` from help, completions and generated docs.
+    ///
+    /// Equivalent to [`CommandConfig::hidden`](crate::cli::CommandConfig::hidden)
+    /// for commands registered without a config closure. The command still
+    /// runs when invoked by name, which suits internal or experimental
+    /// commands.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .command("debug.dump-state", dump_state, "{{ state }}")?
+    ///     .hide_command("debug.dump-state")
+    ///     .build()?;
+    /// ```
+    pub fn hide_command(mut self, path: &str) -> Self {
+        self.hidden_commands.insert(path.to_string());
+        self
+    }
+
+    /// Sets the output mode the command at `path` uses when the user doesn't
+    /// choose one.
+    ///
+    /// Equivalent to [`CommandConfig::output`](crate::cli::CommandConfig::output)
+    /// for commands registered without a config closure. `--output` and the
+    /// output environment variable still take precedence.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .command("export", export_handler, "")?
+    ///     .command_output("export", OutputMode::Json)
+    ///     .build()?;
+    /// // myapp export               -> JSON
+    /// // myapp export --output=yaml -> YAML
+    /// ```
+    pub fn command_output(mut self, path: &str, mode: OutputMode) -> Self {
+        self.command_output_modes.insert(path.to_string(), mode);
+        self
+    }
+
+    pub(crate) fn add_command_alias(&mut self, path: &str, alias: &str) {
+        let aliases = self.command_aliases.entry(path.to_string()).or_default();
+        if !aliases.iter().any(|existing| existing == alias) {
+            aliases.push(alias.to_string());
+        }
+    }
+
+    /// Maps a command path whose last segment is an alias to the registered
+    /// path (`config.ls` → `config.list`).
+    ///
+    /// Clap reports the canonical name for aliases it knows about, so this
+    /// only matters when the clap command itself is named after the alias.
+    pub(crate) fn resolve_command_alias(&self, path: &str) -> Option<String> {
+        let (parent, name) = match path.rsplit_once('.') {
+            Some((parent, name)) => (Some(parent), name),
+            None => (None, path),
+        };
+        self.command_aliases.iter().find_map(|(target, aliases)| {
+            let target_parent = target.rsplit_once('.').map(|(parent, _)| parent);
+            (target_parent == parent && aliases.iter().any(|alias| alias == name))
+                .then(|| target.clone())
+        })
+    }
 }
 
 #[cfg(test)]
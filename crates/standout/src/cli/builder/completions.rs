@@ -0,0 +1,156 @@
+//! Shell completion generation.
+//!
+//! Apps opt in with `completions_command()`, which adds a
+//! `completions <SHELL>` subcommand. Its output is a completion script for
+//! bash, zsh, fish, PowerShell or elvish, generated by `clap_complete` from
+//! the command as standout augments it, so the injected `--output` flags and
+//! `help` subcommand complete like the app's own arguments.
+//!
+//! ```text
+//! myapp completions bash > ~/.local/share/bash-completion/completions/myapp
+//! myapp completions zsh > "${fpath[1]}/_myapp"
+//! myapp completions fish > ~/.config/fish/completions/myapp.fish
+//! ```
+
+use clap::{value_parser, Arg, ArgMatches, Command};
+use clap_complete::Shell;
+
+use super::AppBuilder;
+use crate::cli::handler::RunResult;
+
+impl AppBuilder {
+    /// Adds a `<name> <SHELL>` subcommand that prints a shell completion script.
+    ///
+    /// The script covers the app's commands plus everything standout injects
+    /// (`--output`, `--output-file-path`, the `help` subcommand, ...). Supported
+    /// shells are `bash`, `zsh`, `fish`, `powershell` and `elvish`.
+    ///
+    /// The command is off by default. Passing `None` uses the name "completions".
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .completions_command(None)
+    ///     .build()?;
+    /// // myapp completions zsh > "${fpath[1]}/_myapp"
+    /// ```
+    pub fn completions_command(mut self, name: Option<&str>) -> Self {
+        // clap needs a 'static name; leak it once here rather than on every
+        // dispatch, since the subcommand is rebuilt for each one.
+        let name: &'static str = Box::leak(name.unwrap_or("completions").into());
+        self.completions_command = Some(name);
+        self
+    }
+
+    /// Builds the completions subcommand, if enabled.
+    pub(crate) fn completions_subcommand(&self) -> Option<Command> {
+        Some(
+            Command::new(self.completions_command?)
+                .about("Generate a shell completion script")
+                .arg(
+                    Arg::new("shell")
+                        .required(true)
+                        .value_name("SHELL")
+                        .value_parser(value_parser!(Shell))
+                        .help("Shell to generate completions for"),
+                ),
+        )
+    }
+
+    /// Returns the completions subcommand's matches if `matches` invoked it.
+    pub(crate) fn completions_matches<'a>(
+        &self,
+        matches: &'a ArgMatches,
+    ) -> Option<&'a ArgMatches> {
+        let name = self.completions_command?;
+        match matches.subcommand() {
+            Some((sub_name, sub_matches)) if sub_name == name => Some(sub_matches),
+            _ => None,
+        }
+    }
+
+    /// Generates the completion script for an invocation of the completions
+    /// subcommand.
+    ///
+    /// `cmd` must already be augmented, so the script includes standout's
+    /// injected flags and subcommands.
+    pub(crate) fn completion_script(cmd: &mut Command, matches: &ArgMatches) -> String {
+        let shell = *matches
+            .get_one::<Shell>("shell")
+            .expect("shell is a required argument");
+
+        let bin_name = cmd
+            .get_bin_name()
+            .unwrap_or_else(|| cmd.get_name())
+            .to_string();
+
+        let mut script = Vec::new();
+        clap_complete::generate(shell, cmd, bin_name, &mut script);
+        String::from_utf8_lossy(&script).into_owned()
+    }
+
+    /// Handles an invocation of the completions subcommand.
+    ///
+    /// `cmd` is the app's command before augmentation.
+    pub(crate) fn run_completions_command(&self, cmd: Command, matches: &ArgMatches) -> RunResult {
+        let mut cmd = self.augment_command_with_help(cmd);
+        RunResult::Handled(Self::completion_script(&mut cmd, matches))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app_cmd() -> Command {
+        Command::new("app")
+            .subcommand(Command::new("list").about("List items"))
+            .subcommand(Command::new("db").subcommand(Command::new("migrate")))
+    }
+
+    #[test]
+    fn test_completions_disabled_by_default() {
+        let app = AppBuilder::new();
+
+        // Unknown subcommand: clap usage error
+        let result = app.dispatch_from(app_cmd(), ["app", "completions", "bash"]);
+        assert_eq!(result.exit_code(), 2);
+    }
+
+    #[test]
+    fn test_completions_bash_includes_injected_arguments() {
+        let app = AppBuilder::new()
+            .help_handling(true)
+            .completions_command(None);
+
+        let result = app.dispatch_from(app_cmd(), ["app", "completions", "bash"]);
+        let script = result.output().expect("completion script");
+
+        assert!(script.contains("_app()"));
+        assert!(script.contains("list"));
+        assert!(script.contains("migrate"));
+        assert!(script.contains("--output"));
+        assert!(script.contains("help"));
+        assert!(script.contains("completions"));
+    }
+
+    #[test]
+    fn test_completions_custom_name_and_shells() {
+        let app = AppBuilder::new().completions_command(Some("complete"));
+
+        for shell in ["zsh", "fish", "powershell", "elvish"] {
+            let result = app.dispatch_from(app_cmd(), ["app", "complete", shell]);
+            let script = result.output().expect("completion script");
+            assert!(script.contains("list"), "{} script lists commands", shell);
+        }
+    }
+
+    #[test]
+    fn test_completions_unknown_shell_is_usage_error() {
+        let app = AppBuilder::new().completions_command(None);
+
+        let result = app.dispatch_from(app_cmd(), ["app", "completions", "tcsh"]);
+        assert_eq!(result.exit_code(), 2);
+    }
+}
@@ -9,13 +9,14 @@
 //! - Default command
 
 use crate::context::ContextProvider;
+use crate::i18n::Catalogs;
 use crate::setup::SetupError;
-use crate::topics::Topic;
+use crate::topics::{Topic, TopicRegistry};
 use crate::TemplateRegistry;
-use crate::{EmbeddedStyles, EmbeddedTemplates, Theme};
+use crate::{CsvOptions, EmbeddedStyles, EmbeddedTemplates, EmbeddedTopics, Theme};
 use minijinja::Value;
 use std::path::PathBuf;
-use std::rc::Rc;
+use std::sync::Arc;
 
 use super::AppBuilder;
 
@@ -110,6 +111,31 @@ impl AppBuilder {
         Ok(self)
     }
 
+    /// Adds topics embedded with the `embed_topics!` macro.
+    ///
+    /// In debug mode, if the source path exists, topics are loaded from disk
+    /// for hot-reload. In release mode, embedded content is used, so the
+    /// binary never depends on the docs directory being present.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use standout::{embed_topics, cli::App};
+    ///
+    /// App::builder()
+    ///     .topics(embed_topics!("docs/topics"))
+    ///     .build()?
+    ///     .run(cmd, args);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if a topic name or alias collides with one already registered.
+    pub fn topics(mut self, topics: EmbeddedTopics) -> Self {
+        self.registry.extend(TopicRegistry::from(topics));
+        self
+    }
+
     /// Sets a custom theme for help rendering.
     pub fn theme(mut self, theme: Theme) -> Self {
         self.theme = Some(theme);
@@ -140,7 +166,7 @@ impl AppBuilder {
     ///     .run(cmd, args);
     /// ```
     pub fn templates(mut self, templates: EmbeddedTemplates) -> Self {
-        self.template_registry = Some(Rc::new(TemplateRegistry::from(templates)));
+        self.template_registry = Some(Arc::new(TemplateRegistry::from(templates)));
         self
     }
 
@@ -243,11 +269,11 @@ impl AppBuilder {
     /// ```
     pub fn templates_dir<P: AsRef<std::path::Path>>(mut self, path: P) -> Result<Self, SetupError> {
         if self.template_registry.is_none() {
-            self.template_registry = Some(Rc::new(TemplateRegistry::new()));
+            self.template_registry = Some(Arc::new(TemplateRegistry::new()));
         }
 
         let arc = self.template_registry.as_mut().unwrap();
-        match Rc::get_mut(arc) {
+        match Arc::get_mut(arc) {
             Some(registry) => {
                 registry.add_template_dir(path)?;
             }
@@ -289,6 +315,28 @@ impl AppBuilder {
         self
     }
 
+    /// Reads the default output mode from an environment variable.
+    ///
+    /// With `output_env("MYAPP_OUTPUT")`, running with `MYAPP_OUTPUT=json`
+    /// behaves as if `--output=json` were passed, which suits CI jobs that
+    /// always want machine-readable output. An explicit `--output` flag still
+    /// wins, so the precedence is flag > environment variable > `auto`.
+    ///
+    /// Values are the same as for `--output` (case-insensitive). An
+    /// unrecognized value is reported as a warning and ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .output_env("MYAPP_OUTPUT")
+    ///     .build()?;
+    /// ```
+    pub fn output_env(mut self, name: &str) -> Self {
+        self.output_env = Some(name.to_string());
+        self
+    }
+
     /// Disables the output flag entirely.
     ///
     /// By default, `--output` is added to all commands. Call this to disable it.
@@ -315,6 +363,109 @@ impl AppBuilder {
         self
     }
 
+    /// Makes the output file flag tee output instead of redirecting it.
+    ///
+    /// With tee enabled, `--output-file-path=PATH` writes the plain-text
+    /// version (ANSI codes stripped) to `PATH` and still shows the styled
+    /// version on the terminal. Binary output is only written to the file.
+    ///
+    /// Disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .output_file_tee(true)
+    ///     .build()?;
+    /// ```
+    pub fn output_file_tee(mut self, enabled: bool) -> Self {
+        self.output_file_tee = enabled;
+        self
+    }
+
+    /// Sets how the output file flag writes its file.
+    ///
+    /// By default the file is overwritten in place and a missing parent
+    /// directory is an error. Atomic writes go through a temporary file that
+    /// is renamed into place, so an interrupted long-running command never
+    /// leaves a truncated report behind.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use standout::FileWriteOptions;
+    ///
+    /// App::builder()
+    ///     .output_file_options(FileWriteOptions::new().atomic(true).create_dirs(true))
+    ///     .build()?;
+    /// ```
+    pub fn output_file_options(mut self, options: crate::FileWriteOptions) -> Self {
+        self.output_file_options = options;
+        self
+    }
+
+    /// Adds a `--<name> <EXPR>` flag that projects a command's structured output.
+    ///
+    /// The expression is evaluated against the handler's serialized data
+    /// after post-dispatch hooks run, using the dot-path syntax described in
+    /// [`crate::cli::query`] (e.g. `--query items[0].name`). Because the result
+    /// no longer matches the command's template, a query switches templated
+    /// modes to `--output=json`; other structured modes are kept.
+    ///
+    /// The flag is off by default. Passing `None` uses the name "query".
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .query_flag(None)
+    ///     .build()?;
+    /// // myapp list --query 'items[].name'
+    /// ```
+    pub fn query_flag(mut self, name: Option<&str>) -> Self {
+        self.query_flag = Some(name.unwrap_or("query").to_string());
+        self
+    }
+
+    /// Replaces the evaluator used by the [`query_flag`](Self::query_flag).
+    ///
+    /// The hook receives the serialized output and the user's expression and
+    /// returns the projected value, or an error message that fails the
+    /// command. Use it to plug in a full JSONPath or jq implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .query_flag(None)
+    ///     .query_hook(|data, expr| {
+    ///         jsonpath_lib::select(data, expr)
+    ///             .map(|found| Value::Array(found.into_iter().cloned().collect()))
+    ///             .map_err(|e| e.to_string())
+    ///     })
+    ///     .build()?;
+    /// ```
+    pub fn query_hook<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&serde_json::Value, &str) -> Result<serde_json::Value, String>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.query_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Adds a `--<name>` flag that appends to the output file instead of overwriting it.
+    ///
+    /// The flag is off by default so it can't clash with existing arguments.
+    /// Passing `None` uses the name "append-output". Appending can also be
+    /// enabled for every run with [`output_file_options`](Self::output_file_options).
+    pub fn output_file_append_flag(mut self, name: Option<&str>) -> Self {
+        self.output_file_append_flag = Some(name.unwrap_or("append-output").to_string());
+        self
+    }
+
     /// Sets a default command to use when no subcommand is specified.
     ///
     /// When the CLI is invoked without a subcommand (a "naked" invocation),
@@ -422,18 +573,87 @@ impl AppBuilder {
         self
     }
 
+    /// Sets the help template for one subcommand.
+    ///
+    /// `path` uses the same dot-separated form as [`hooks`](Self::hooks)
+    /// (e.g. `"config.get"`). The template replaces the default help layout
+    /// for `help config get` and `config get --help`, receiving the same data
+    /// (`name`, `about`, `usage`, `subcommands`, `options`, `examples`).
+    /// Other commands keep the default template. Registering a path twice
+    /// replaces the template.
+    ///
+    /// Requires [`help_handling`](Self::help_handling).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .help_handling(true)
+    ///     .help_template("config.get", "[header]{{ name }}[/header]\n{{ about }}\n\nKeys: ...")
+    ///     .build()?;
+    /// ```
+    pub fn help_template(mut self, path: &str, template: impl Into<String>) -> Self {
+        self.help_templates
+            .insert(path.to_string(), template.into());
+        self
+    }
+
+    /// Sets the message catalogs used to localize help and command output.
+    ///
+    /// The catalog for the selected locale (see [`locale_flag`](Self::locale_flag))
+    /// is resolved with fallbacks and exposed to help templates and command
+    /// templates as `t`, keyed by dotted message names. Handlers can read the
+    /// resolved [`Catalog`](crate::i18n::Catalog) and
+    /// [`Locale`](crate::i18n::Locale) from `ctx.extensions`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use standout::i18n::Catalogs;
+    ///
+    /// App::builder()
+    ///     .catalogs(Catalogs::from_directory("en", "locales")?)
+    ///     .build()?;
+    /// // {{ t["help.usage"] | default("USAGE") }}
+    /// ```
+    pub fn catalogs(mut self, catalogs: Catalogs) -> Self {
+        self.catalogs = Some(catalogs);
+        self
+    }
+
+    /// Adds a global `--<name> <LOCALE>` flag that overrides the detected locale.
+    ///
+    /// Without the flag, the locale comes from `LC_ALL`, `LC_MESSAGES` or
+    /// `LANG`. It selects both the message catalog and topic translations.
+    ///
+    /// The flag is off by default. Passing `None` uses the name "lang".
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .catalogs(catalogs)
+    ///     .locale_flag(None)
+    ///     .build()?;
+    /// // myapp --lang pt-BR help
+    /// ```
+    pub fn locale_flag(mut self, name: Option<&str>) -> Self {
+        self.locale_flag = Some(name.unwrap_or("lang").to_string());
+        self
+    }
+
     /// Enables standout help handling.
     ///
     /// When enabled, standout intercepts all help invocations (`help`, `--help`,
     /// `-h`) and renders its own themed help instead of clap's default. This is
-    /// required for `command_groups` and topics to work.
+    /// required for `command_groups`, topics and `help_template` to work.
     ///
     /// Disabled by default — clap's built-in help is used unless you opt in.
     ///
     /// # Errors
     ///
-    /// `build()` returns `SetupError::Config` if `command_groups` or topics are
-    /// configured without enabling `help_handling`.
+    /// `build()` returns `SetupError::Config` if `command_groups`, topics or
+    /// `help_template`s are configured without enabling `help_handling`.
     ///
     /// # Example
     ///
@@ -447,6 +667,63 @@ impl AppBuilder {
         self.help_handling = enabled;
         self
     }
+
+    /// Adds a global `--<name>` flag that keeps help from being paged.
+    ///
+    /// Help taller than the terminal is paged automatically; the flag turns
+    /// that off for one run. It's off by default so it can't clash with
+    /// existing arguments, and only takes effect with
+    /// [`help_handling`](Self::help_handling). Passing `None` uses the name
+    /// "no-pager".
+    pub fn no_pager_flag(mut self, name: Option<&str>) -> Self {
+        self.no_pager_flag = Some(Box::leak(name.unwrap_or("no-pager").into()));
+        self
+    }
+
+    /// Enables syntax highlighting for `--output=json` on color terminals.
+    ///
+    /// When enabled and stdout supports color, JSON output is colorized like
+    /// `jq`, using the theme's `json_key`, `json_string`, `json_number`,
+    /// `json_bool` and `json_null` styles (with jq-like defaults for any the
+    /// theme doesn't define). Piped output and `--output-file-path` always
+    /// receive plain JSON.
+    ///
+    /// Disabled by default.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .colored_json(true)
+    ///     .build()?;
+    /// ```
+    pub fn colored_json(mut self, enabled: bool) -> Self {
+        self.colored_json = enabled;
+        self
+    }
+
+    /// Sets the delimiter, quoting style and header row used by `--output=csv`.
+    ///
+    /// The options are also inserted into each command's
+    /// [`CommandContext::extensions`](crate::cli::CommandContext) before
+    /// pre-dispatch hooks run, so a hook can replace them per invocation
+    /// (for example, from a `--delimiter` flag).
+    ///
+    /// Defaults to comma-separated output with minimal quoting and a header row.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use standout::{CsvOptions, CsvQuoting};
+    ///
+    /// App::builder()
+    ///     .csv_options(CsvOptions::new().delimiter(b';').quoting(CsvQuoting::Always))
+    ///     .build()?;
+    /// ```
+    pub fn csv_options(mut self, options: CsvOptions) -> Self {
+        self.csv_options = options;
+        self
+    }
 }
 
 #[cfg(test)]
@@ -525,6 +802,25 @@ mod tests {
         assert!(output.starts_with("Width: "));
     }
 
+    #[test]
+    #[serial_test::serial]
+    fn test_terminal_capabilities_in_templates() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command(
+                "info",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({}))),
+                "{{ terminal.width }}x{{ terminal.height }} tty={{ terminal.tty }}",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("info"));
+        let out = builder.test_run(cmd, ["app", "info"], OutputMode::Text);
+
+        assert_eq!(out.stdout, "80x24 tty=false");
+    }
+
     #[test]
     fn test_context_fn_output_mode() {
         use serde_json::json;
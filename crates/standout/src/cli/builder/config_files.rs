@@ -0,0 +1,217 @@
+//! Configuration files merged into `ctx.config()`.
+//!
+//! Apps opt in with `config(ConfigLoader::new("myapp"))`. Before each
+//! dispatch the loader merges the config file, environment variables and
+//! `--set` flags (see [`crate::cli::config`] for the precedence), and the
+//! result is inserted into `ctx.extensions` as [`ConfigValues`].
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use super::AppBuilder;
+use crate::cli::config::{ConfigError, ConfigLoader};
+use crate::cli::handler::ConfigValues;
+
+impl AppBuilder {
+    /// Loads configuration with `loader` before each command.
+    ///
+    /// Handlers read it with `ctx.config::<T>()`. A config file that can't
+    /// be read or parsed fails the command with a `config_error`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .config(ConfigLoader::new("myapp").file_flag("config").set_flag("set"))
+    ///     .command("sync", |_m, ctx| {
+    ///         let settings: Settings = ctx.config()?;
+    ///         Ok(Output::Render(sync(&settings.remote)?))
+    ///     }, "{{ count }} synced")?
+    ///     .build()?;
+    /// ```
+    pub fn config(mut self, loader: ConfigLoader) -> Self {
+        self.config_loader = Some(loader);
+        self
+    }
+
+    /// Adds the loader's `--config` and `--set` flags, if enabled.
+    pub(crate) fn augment_command_with_config(&self, mut cmd: Command) -> Command {
+        let Some(ref loader) = self.config_loader else {
+            return cmd;
+        };
+        if let Some(ref flag) = loader.file_flag {
+            let flag: &'static str = Box::leak(flag.clone().into_boxed_str());
+            cmd = cmd.arg(
+                Arg::new("_config_file")
+                    .long(flag)
+                    .value_name("FILE")
+                    .global(true)
+                    .action(ArgAction::Set)
+                    .help("Read configuration from FILE"),
+            );
+        }
+        if let Some(ref flag) = loader.set_flag {
+            let flag: &'static str = Box::leak(flag.clone().into_boxed_str());
+            cmd = cmd.arg(
+                Arg::new("_config_set")
+                    .long(flag)
+                    .value_name("KEY=VALUE")
+                    .global(true)
+                    .action(ArgAction::Append)
+                    .help("Override a configuration value"),
+            );
+        }
+        cmd
+    }
+
+    /// Loads the configuration for a dispatch, or `None` without a loader.
+    pub(crate) fn load_config(
+        &self,
+        matches: &ArgMatches,
+    ) -> Option<Result<ConfigValues, ConfigError>> {
+        let loader = self.config_loader.as_ref()?;
+        let file = matches
+            .try_get_one::<String>("_config_file")
+            .ok()
+            .flatten()
+            .map(std::path::Path::new);
+        let overrides: Vec<String> = matches
+            .try_get_many::<String>("_config_set")
+            .ok()
+            .flatten()
+            .map(|values| values.cloned().collect())
+            .unwrap_or_default();
+        Some(loader.load(file, &overrides).map(ConfigValues))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::handler::Output as HandlerOutput;
+    use crate::cli::handler::RunResult;
+    use crate::cli::CommandContextInput;
+    use serde_json::json;
+    use serial_test::serial;
+    use standout_input::{ArgSource, ConfigSource, InputChain};
+
+    #[derive(serde::Deserialize)]
+    struct Settings {
+        #[serde(default)]
+        editor: String,
+        #[serde(default)]
+        limit: u32,
+    }
+
+    fn builder(loader: ConfigLoader) -> AppBuilder {
+        AppBuilder::new()
+            .config(loader)
+            .command(
+                "show",
+                |_m, ctx| {
+                    let settings: Settings = ctx.config()?;
+                    Ok(HandlerOutput::Render(
+                        json!({ "editor": settings.editor, "limit": settings.limit }),
+                    ))
+                },
+                "{{ editor }} {{ limit }}",
+            )
+            .unwrap()
+    }
+
+    #[test]
+    #[serial(config)]
+    fn test_dispatch_reads_config_file_and_set_flags() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("config.toml"),
+            "editor = \"nano\"\nlimit = 3",
+        )
+        .unwrap();
+        let loader = ConfigLoader::new("standout-config-test")
+            .dir(dir.path())
+            .set_flag("set");
+
+        let cmd = Command::new("app").subcommand(Command::new("show"));
+        let app = builder(loader);
+        let result = app.dispatch_from(cmd.clone(), ["app", "show"]);
+        assert_eq!(result.output(), Some("nano 3"));
+
+        let result = app.dispatch_from(cmd, ["app", "show", "--set", "limit=7"]);
+        assert_eq!(result.output(), Some("nano 7"));
+    }
+
+    #[test]
+    #[serial(config)]
+    fn test_dispatch_config_file_flag_and_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("other.yaml");
+        std::fs::write(&file, "editor: vim\n").unwrap();
+        let loader = ConfigLoader::new("standout-config-test")
+            .dir(dir.path())
+            .file_flag("config");
+
+        let cmd = Command::new("app").subcommand(Command::new("show"));
+        let app = builder(loader);
+        let result = app.dispatch_from(
+            cmd.clone(),
+            ["app", "show", "--config", file.to_str().unwrap()],
+        );
+        assert_eq!(result.output(), Some("vim 0"));
+
+        std::fs::write(&file, "editor: [").unwrap();
+        let result = app.dispatch_from(
+            cmd,
+            [
+                "app",
+                "show",
+                "--config",
+                file.to_str().unwrap(),
+                "--output=json",
+            ],
+        );
+        let RunResult::Error(message) = result else {
+            panic!("expected an error");
+        };
+        assert!(message.contains("\"code\":\"config_error\""), "{message}");
+    }
+
+    #[test]
+    #[serial(config)]
+    fn test_config_source_reads_loaded_config() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("config.toml"), "[user]\nname = \"ada\"\n").unwrap();
+        let loader = ConfigLoader::new("standout-config-test").dir(dir.path());
+
+        let app = AppBuilder::new()
+            .config(loader)
+            .command_with(
+                "show",
+                |_m, ctx| {
+                    let author: &String = ctx.input("author")?;
+                    Ok(HandlerOutput::Render(json!({ "author": author })))
+                },
+                |cfg| {
+                    cfg.template("{{ author }}").input(
+                        "author",
+                        InputChain::<String>::new()
+                            .try_source(ArgSource::new("author"))
+                            .try_source(ConfigSource::new("user.name"))
+                            .default("anonymous".to_string()),
+                    )
+                },
+            )
+            .unwrap();
+
+        let cmd = Command::new("app")
+            .subcommand(Command::new("show").arg(Arg::new("author").long("author")));
+        let result = app.dispatch_from(cmd.clone(), ["app", "show"]);
+        assert_eq!(result.output(), Some("ada"));
+
+        let result = app.dispatch_from(cmd, ["app", "show", "--author", "bob"]);
+        assert_eq!(result.output(), Some("bob"));
+
+        // The configuration is only readable while a command runs
+        use standout_input::env::ConfigReader;
+        assert_eq!(standout_input::DefaultConfig.get("user.name"), None);
+    }
+}
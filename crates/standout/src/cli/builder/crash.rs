@@ -0,0 +1,163 @@
+//! Panic hook rendering crash reports.
+//!
+//! [`install_panic_hook`](AppBuilder::install_panic_hook) replaces Rust's
+//! default panic output with a themed [`CrashReport`] (see
+//! [`crate::cli::crash`]) and exits with [`CRASH_EXIT_CODE`].
+
+use super::AppBuilder;
+use crate::cli::crash::{
+    default_crash_theme, render_crash_report, CrashReport, CRASH_EXIT_CODE, DEFAULT_CRASH_TEMPLATE,
+};
+use crate::OutputMode;
+
+impl AppBuilder {
+    /// Sets the URL crash reports ask users to file bugs at.
+    pub fn bug_report_url(mut self, url: impl Into<String>) -> Self {
+        self.bug_report_url = Some(url.into());
+        self
+    }
+
+    /// Replaces the template used to render crash reports.
+    ///
+    /// The template receives the [`CrashReport`] fields (`name`, `version`,
+    /// `message`, `location`, `backtrace`, `bug_report_url`) and is styled
+    /// with the app theme on top of the help styles, plus `crash` for the
+    /// headline.
+    pub fn crash_template(mut self, template: impl Into<String>) -> Self {
+        self.crash_template = Some(template.into());
+        self
+    }
+
+    /// Installs a panic hook that prints a themed crash report and exits.
+    ///
+    /// On panic, the report (message, location, a backtrace or a hint on how
+    /// to get one, and the [`bug_report_url`](Self::bug_report_url)) is
+    /// written to stderr, styled when stderr is a color terminal. The process
+    /// then exits with code 101 without unwinding, so this also applies to
+    /// panics on other threads.
+    ///
+    /// The program name and version come from
+    /// [`build_info`](Self::build_info) when set; otherwise the name is the
+    /// executable's file name.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let app = App::builder()
+    ///     .build_info(standout::build_info!())
+    ///     .bug_report_url("https://github.com/me/myapp/issues")
+    ///     .build()?;
+    /// app.install_panic_hook();
+    /// app.run(cmd, std::env::args());
+    /// ```
+    pub fn install_panic_hook(&self) {
+        let program = std::env::args_os()
+            .next()
+            .and_then(|arg0| {
+                std::path::Path::new(&arg0)
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+            })
+            .unwrap_or_else(|| "program".to_string());
+        let hook = self.crash_reporter(&program);
+        std::panic::set_hook(Box::new(move |info| {
+            let report = hook.report(CrashReport::from_panic(hook.name.clone(), info));
+            eprintln!("{}", report);
+            std::process::exit(CRASH_EXIT_CODE);
+        }));
+    }
+
+    /// Captures what the panic hook needs, since it outlives the builder.
+    ///
+    /// `program` names the app when no build info is set.
+    fn crash_reporter(&self, program: &str) -> CrashReporter {
+        let (name, version) = match &self.build_info {
+            Some(info) => (info.name.clone(), Some(info.version.clone())),
+            None => (program.to_string(), None),
+        };
+        let theme = match &self.theme {
+            Some(theme) => default_crash_theme().merge(theme.clone()),
+            None => default_crash_theme(),
+        };
+        let use_color = console::Term::stderr().features().colors_supported();
+        CrashReporter {
+            name,
+            version,
+            bug_report_url: self.bug_report_url.clone(),
+            template: self
+                .crash_template
+                .clone()
+                .unwrap_or_else(|| DEFAULT_CRASH_TEMPLATE.to_string()),
+            theme,
+            mode: if use_color {
+                OutputMode::Term
+            } else {
+                OutputMode::Text
+            },
+        }
+    }
+}
+
+/// The app settings a crash report is rendered with.
+struct CrashReporter {
+    name: String,
+    version: Option<String>,
+    bug_report_url: Option<String>,
+    template: String,
+    theme: crate::Theme,
+    mode: OutputMode,
+}
+
+impl CrashReporter {
+    /// Fills in the app details and renders `report`, falling back to plain
+    /// text if the template fails.
+    fn report(&self, report: CrashReport) -> String {
+        let report = CrashReport {
+            version: self.version.clone(),
+            bug_report_url: self.bug_report_url.clone(),
+            ..report
+        };
+        render_crash_report(&report, &self.template, &self.theme, self.mode)
+            .unwrap_or_else(|_| format!("{} crashed: {}", report.name, report.message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::BuildInfo;
+
+    #[test]
+    fn test_crash_reporter_uses_build_info_and_url() {
+        let builder = AppBuilder::new()
+            .build_info(BuildInfo::new("myapp", "1.2.3"))
+            .bug_report_url("https://example.com/issues");
+        let reporter = CrashReporter {
+            mode: OutputMode::Text,
+            ..builder.crash_reporter("fallback")
+        };
+
+        let output = reporter.report(CrashReport {
+            name: reporter.name.clone(),
+            message: "boom".into(),
+            ..Default::default()
+        });
+
+        assert!(output.starts_with("myapp crashed 1.2.3"), "{output}");
+        assert!(output.contains("https://example.com/issues"), "{output}");
+    }
+
+    #[test]
+    fn test_crash_reporter_falls_back_on_template_error() {
+        let builder = AppBuilder::new().crash_template("{{ unclosed");
+        let reporter = builder.crash_reporter("myapp");
+
+        let output = reporter.report(CrashReport {
+            name: reporter.name.clone(),
+            message: "boom".into(),
+            ..Default::default()
+        });
+
+        assert_eq!(output, "myapp crashed: boom");
+    }
+}
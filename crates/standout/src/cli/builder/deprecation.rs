@@ -0,0 +1,354 @@
+//! Deprecated commands, aliases and flags.
+//!
+//! See [`crate::cli::deprecation`] for how the warnings look. Marked items
+//! get a notice in help when the command is augmented, and dispatch records a
+//! [`Deprecation`] for each one the invocation used in its run state.
+//! [`deprecation_warnings`](AppBuilder::deprecation_warnings) renders them for
+//! `run`, `--watch` and the interactive shell to print before the output.
+
+use clap::parser::ValueSource;
+use clap::{ArgMatches, Command};
+
+use super::execution::RunState;
+use super::AppBuilder;
+use crate::cli::deprecation::{
+    default_deprecation_theme, help_notice, render_deprecation, Deprecation,
+    DEFAULT_DEPRECATION_TEMPLATE,
+};
+use crate::OutputMode;
+
+impl AppBuilder {
+    /// Marks the command or alias at `path` as deprecated.
+    ///
+    /// `path` is dot-separated like command registration (`db.migrate`).
+    /// When its last segment is an alias (`config.ls`), only that alias is
+    /// deprecated and the command itself stays current. `note` is the
+    /// replacement hint shown in the warning and in help.
+    ///
+    /// Marking a group deprecates every command in it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .command_with("list", list_handler, |cfg| cfg.alias("ls"))?
+    ///     .deprecated("ls", "use `list` instead")
+    ///     .deprecated("db.migrate", "use `db upgrade` instead")
+    ///     .build()?;
+    /// // $ myapp ls
+    /// // warning: alias `ls` is deprecated, use `list` instead
+    /// ```
+    pub fn deprecated(mut self, path: &str, note: &str) -> Self {
+        self.deprecated_commands
+            .insert(path.to_string(), note.to_string());
+        self
+    }
+
+    /// Marks the argument `id` of the command at `path` as deprecated.
+    ///
+    /// `id` is the clap argument id and `path` the command defining it (`""`
+    /// for the top-level command). The warning is printed when the argument
+    /// is given on the command line, not when it takes a default value.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .deprecated_arg("sync", "force", "use `--overwrite` instead")
+    ///     .build()?;
+    /// // $ myapp sync --force
+    /// // warning: flag `--force` is deprecated, use `--overwrite` instead
+    /// ```
+    pub fn deprecated_arg(mut self, path: &str, id: &str, note: &str) -> Self {
+        self.deprecated_args
+            .insert((path.to_string(), id.to_string()), note.to_string());
+        self
+    }
+
+    /// Adds the deprecation notices to help.
+    pub(crate) fn augment_command_with_deprecations(&self, mut cmd: Command) -> Command {
+        for (path, note) in &self.deprecated_commands {
+            let segments: Vec<&str> = path.split('.').collect();
+            let (last, parent) = segments.split_last().expect("split yields a segment");
+            let Some(sub) = crate::cli::app::find_subcommand_recursive(&cmd, parent)
+                .and_then(|parent| parent.find_subcommand(last))
+            else {
+                continue;
+            };
+            let notice = if sub.get_name() == *last {
+                help_notice(note)
+            } else {
+                format!("[alias `{}` is deprecated]", last)
+            };
+            cmd = update_subcommand(cmd, &segments, &mut |sub| {
+                let about = match sub.get_about() {
+                    Some(about) => format!("{} {}", about, notice),
+                    None => notice.clone(),
+                };
+                sub.about(about)
+            });
+        }
+
+        for ((path, id), note) in &self.deprecated_args {
+            let segments: Vec<&str> = path.split('.').filter(|s| !s.is_empty()).collect();
+            cmd = update_subcommand(cmd, &segments, &mut |sub| {
+                let Some(arg) = sub.get_arguments().find(|arg| arg.get_id() == id.as_str()) else {
+                    return sub;
+                };
+                let help = match arg.get_help() {
+                    Some(help) => format!("{} {}", help, help_notice(note)),
+                    None => help_notice(note),
+                };
+                sub.mut_arg(id, |arg| arg.help(help))
+            });
+        }
+        cmd
+    }
+
+    /// Returns a warning for each deprecated item this invocation used.
+    ///
+    /// `cmd` is the augmented command `args` were parsed with, used to tell
+    /// which name or alias was typed.
+    pub(crate) fn collect_deprecations(
+        &self,
+        cmd: &Command,
+        args: &[String],
+        matches: &ArgMatches,
+    ) -> Vec<Deprecation> {
+        if self.deprecated_commands.is_empty() && self.deprecated_args.is_empty() {
+            return Vec::new();
+        }
+        let mut found = Vec::new();
+
+        // Walk the typed subcommand names alongside the matched path
+        let mut current = cmd;
+        let mut current_matches = matches;
+        let mut path: Vec<&str> = Vec::new();
+        let mut remaining = args.iter().skip(1);
+        loop {
+            self.collect_deprecated_args(current, current_matches, &path, &mut found);
+
+            let Some((name, sub_matches)) = current_matches.subcommand() else {
+                break;
+            };
+            let Some(sub) = current.find_subcommand(name) else {
+                break;
+            };
+            let typed = remaining
+                .find(|arg| {
+                    arg.as_str() == sub.get_name()
+                        || sub.get_all_aliases().any(|a| a == arg.as_str())
+                })
+                .map(String::as_str)
+                .unwrap_or(name);
+
+            let parent = path.join(".");
+            let join = |segment: &str| {
+                if parent.is_empty() {
+                    segment.to_string()
+                } else {
+                    format!("{}.{}", parent, segment)
+                }
+            };
+            if typed != name {
+                if let Some(note) = self.deprecated_commands.get(&join(typed)) {
+                    found.push(Deprecation {
+                        subject: format!("alias `{}`", typed),
+                        note: note.clone(),
+                    });
+                }
+            }
+            path.push(sub.get_name());
+            if let Some(note) = self.deprecated_commands.get(&join(name)) {
+                found.push(Deprecation {
+                    subject: format!("command `{}`", path.join(" ")),
+                    note: note.clone(),
+                });
+            }
+
+            current = sub;
+            current_matches = sub_matches;
+        }
+
+        let mut warned = std::collections::HashSet::new();
+        found.retain(|deprecation| warned.insert(deprecation.subject.clone()));
+        found
+    }
+
+    fn collect_deprecated_args(
+        &self,
+        cmd: &Command,
+        matches: &ArgMatches,
+        path: &[&str],
+        found: &mut Vec<Deprecation>,
+    ) {
+        let path = path.join(".");
+        for ((arg_path, id), note) in &self.deprecated_args {
+            if *arg_path != path
+                || matches.try_contains_id(id).ok() != Some(true)
+                || matches.value_source(id) != Some(ValueSource::CommandLine)
+            {
+                continue;
+            }
+            let arg = cmd.get_arguments().find(|arg| arg.get_id() == id.as_str());
+            let subject = match arg {
+                Some(arg) if arg.get_long().is_some() => {
+                    format!("flag `--{}`", arg.get_long().unwrap_or_default())
+                }
+                Some(arg) if arg.get_short().is_some() => {
+                    format!("flag `-{}`", arg.get_short().unwrap_or_default())
+                }
+                _ => format!("argument `{}`", id),
+            };
+            found.push(Deprecation {
+                subject,
+                note: note.clone(),
+            });
+        }
+    }
+
+    /// Renders the deprecation warnings a dispatch recorded.
+    ///
+    /// Warnings are styled when stderr is a color terminal.
+    pub(crate) fn deprecation_warnings(&self, run: &RunState) -> Vec<String> {
+        let pending = &run.deprecations;
+        if pending.is_empty() {
+            return Vec::new();
+        }
+        let theme = match &self.theme {
+            Some(theme) => default_deprecation_theme().merge(theme.clone()),
+            None => default_deprecation_theme(),
+        };
+        let mode = if console::Term::stderr().features().colors_supported() {
+            OutputMode::Term
+        } else {
+            OutputMode::Text
+        };
+        pending
+            .iter()
+            .map(|deprecation| {
+                render_deprecation(deprecation, DEFAULT_DEPRECATION_TEMPLATE, &theme, mode)
+                    .unwrap_or_else(|_| format!("warning: {} is deprecated", deprecation.subject))
+            })
+            .collect()
+    }
+}
+
+/// Applies `f` to the subcommand at `path` (the command itself when empty).
+fn update_subcommand(
+    cmd: Command,
+    path: &[&str],
+    f: &mut dyn FnMut(Command) -> Command,
+) -> Command {
+    let Some((first, rest)) = path.split_first() else {
+        return f(cmd);
+    };
+    let Some(name) = cmd
+        .find_subcommand(first)
+        .map(|sub| sub.get_name().to_string())
+    else {
+        return cmd;
+    };
+    cmd.mut_subcommand(name, |sub| update_subcommand(sub, rest, f))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::handler::Output as HandlerOutput;
+    use clap::{Arg, ArgAction};
+
+    fn builder() -> AppBuilder {
+        AppBuilder::new()
+            .command_with(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(serde_json::json!({}))),
+                |cfg| cfg.template("listed").alias("ls"),
+            )
+            .unwrap()
+            .command(
+                "db.migrate",
+                |_m, _ctx| Ok(HandlerOutput::Render(serde_json::json!({}))),
+                "migrated",
+            )
+            .unwrap()
+            .deprecated("ls", "use `list` instead")
+            .deprecated("db.migrate", "use `db upgrade` instead")
+            .deprecated_arg("db.migrate", "force", "use `--overwrite` instead")
+    }
+
+    fn command() -> Command {
+        Command::new("app")
+            .subcommand(Command::new("list").about("List items"))
+            .subcommand(
+                Command::new("db").subcommand(
+                    Command::new("migrate").arg(
+                        Arg::new("force")
+                            .long("force")
+                            .action(ArgAction::SetTrue)
+                            .help("Overwrite"),
+                    ),
+                ),
+            )
+    }
+
+    fn warnings(builder: &AppBuilder, args: &[&str]) -> Vec<String> {
+        let mut run = RunState::default();
+        let result =
+            builder.dispatch_from_with_mode(command(), args.iter().copied(), None, &mut run);
+        assert!(result.is_handled(), "{result:?}");
+        builder
+            .deprecation_warnings(&run)
+            .into_iter()
+            .map(|w| console::strip_ansi_codes(&w).into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_deprecated_alias_warns_only_when_typed() {
+        let builder = builder();
+        assert!(warnings(&builder, &["app", "list"]).is_empty());
+        assert_eq!(
+            warnings(&builder, &["app", "ls"]),
+            ["warning: alias `ls` is deprecated, use `list` instead"]
+        );
+        // Each dispatch warns again, as `--watch` and the shell run many
+        assert_eq!(warnings(&builder, &["app", "ls"]).len(), 1);
+    }
+
+    #[test]
+    fn test_deprecated_command_and_flag() {
+        let builder = builder();
+        assert_eq!(
+            warnings(&builder, &["app", "db", "migrate", "--force"]),
+            [
+                "warning: command `db migrate` is deprecated, use `db upgrade` instead",
+                "warning: flag `--force` is deprecated, use `--overwrite` instead",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deprecations_shown_in_help() {
+        let cmd = builder().augment_command_for_dispatch(command());
+        let help = crate::cli::help::render_help(&cmd, None).unwrap();
+        assert!(
+            help.contains("List items [alias `ls` is deprecated]"),
+            "{help}"
+        );
+
+        let migrate = crate::cli::app::find_subcommand_recursive(&cmd, &["db", "migrate"]).unwrap();
+        assert_eq!(
+            migrate.get_about().map(|a| a.to_string()).as_deref(),
+            Some("[deprecated: use `db upgrade` instead]")
+        );
+        let force = migrate
+            .get_arguments()
+            .find(|arg| arg.get_id() == "force")
+            .unwrap();
+        assert_eq!(
+            force.get_help().map(|h| h.to_string()).as_deref(),
+            Some("Overwrite [deprecated: use `--overwrite` instead]")
+        );
+    }
+}
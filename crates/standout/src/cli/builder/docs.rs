@@ -0,0 +1,153 @@
+//! Markdown docs export for the configured app.
+//!
+//! Wraps [`crate::cli::help::generate_docs`] with the builder's topics and
+//! command groups, and with the flags and subcommands standout injects, so
+//! the generated pages match what `help` shows.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use clap::Command;
+
+use super::fallback::FALLBACK_PATH;
+use super::AppBuilder;
+use crate::cli::help::{self, DocPage};
+
+impl AppBuilder {
+    /// Generates Markdown pages for every visible command and every topic.
+    ///
+    /// See [`help::generate_docs`] for the page layout.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use clap::Command;
+    /// use standout::cli::App;
+    ///
+    /// let cmd = Command::new("myapp").subcommand(Command::new("list").about("List items"));
+    /// let pages = App::builder().generate_docs(cmd);
+    ///
+    /// assert!(pages.iter().any(|page| page.path.ends_with("commands/list.md")));
+    /// ```
+    pub fn generate_docs(&self, cmd: Command) -> Vec<DocPage> {
+        let cmd = self.augment_command_with_help(cmd);
+        help::generate_docs(&cmd, &self.registry, self.help_command_groups.as_deref())
+    }
+
+    /// Writes the pages from [`generate_docs`](Self::generate_docs) under `dir`.
+    ///
+    /// Returns the paths of the written files. Run it from a build script or a
+    /// release task to keep website docs in step with the CLI:
+    ///
+    /// ```rust,ignore
+    /// app.write_docs(Cli::command(), "site/docs/cli")?;
+    /// ```
+    pub fn write_docs(&self, cmd: Command, dir: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+        help::write_docs(&self.generate_docs(cmd), dir)
+    }
+
+    /// Generates a single Markdown reference for every visible command and
+    /// every topic.
+    ///
+    /// Each command's section has its usage, subcommands and options, plus
+    /// the source of the template it renders with. Commands link to their
+    /// subcommands' sections, so the document works as a README section or
+    /// a one-page manual. See [`help::generate_reference`] for the layout.
+    ///
+    /// ```rust,ignore
+    /// std::fs::write("docs/cli.md", app.generate_reference(Cli::command()))?;
+    /// ```
+    pub fn generate_reference(&self, cmd: Command) -> String {
+        let cmd = self.augment_command_with_help(cmd);
+        let templates: HashMap<String, String> = self
+            .pending_commands
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(path, _)| path.as_str() != FALLBACK_PATH)
+            .filter_map(|(path, pending)| {
+                self.template_source(&pending.template)
+                    .map(|source| (path.clone(), source))
+            })
+            .collect();
+        help::generate_reference(
+            &cmd,
+            &self.registry,
+            self.help_command_groups.as_deref(),
+            &templates,
+        )
+    }
+
+    /// Returns the source of a command's template: the registry's content for
+    /// a template name, a template file's content, or the inline template.
+    fn template_source(&self, template: &str) -> Option<String> {
+        if template.is_empty() {
+            return None;
+        }
+        if let Some(content) = self
+            .template_registry
+            .as_ref()
+            .and_then(|registry| registry.get_content(template).ok())
+        {
+            return Some(content);
+        }
+        let path = Path::new(template);
+        if path.is_file() {
+            return std::fs::read_to_string(path).ok();
+        }
+        Some(template.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topics::{Topic, TopicType};
+
+    #[test]
+    fn test_generate_docs_includes_injected_flags_and_topics() {
+        let app = AppBuilder::new().help_handling(true).add_topic(Topic::new(
+            "Storage",
+            "Where data lives.",
+            TopicType::Text,
+            Some("storage".to_string()),
+        ));
+        let cmd = Command::new("myapp").subcommand(Command::new("list").about("List items"));
+
+        let pages = app.generate_docs(cmd);
+
+        let index = &pages[0];
+        assert_eq!(index.path, PathBuf::from("index.md"));
+        assert!(index.content.contains("`--output`"), "{}", index.content);
+        assert!(index.content.contains("[Storage](topics/storage.md)"));
+        assert!(pages
+            .iter()
+            .any(|page| page.path == Path::new("topics/storage.md")));
+    }
+
+    #[test]
+    fn test_generate_reference_includes_command_templates() {
+        let app = AppBuilder::new()
+            .help_handling(true)
+            .command(
+                "list",
+                |_m, _ctx| Ok(crate::cli::handler::Output::<()>::Silent),
+                "{{ items | length }} items",
+            )
+            .unwrap();
+        let cmd = Command::new("myapp").subcommand(Command::new("list").about("List items"));
+
+        let reference = app.generate_reference(cmd);
+
+        assert!(
+            reference.contains("## myapp list\n\nList items\n"),
+            "{reference}"
+        );
+        assert!(
+            reference.contains("```jinja\n{{ items | length }} items\n```"),
+            "{reference}"
+        );
+        assert!(reference.contains("`--output`"), "{reference}");
+    }
+}
@@ -7,20 +7,43 @@
 //! - `run()` - dispatch and print
 //! - `run_to_string()` - dispatch and return
 
-use crate::{write_binary_output, write_output, OutputDestination, OutputMode};
+use crate::{
+    write_binary_output, write_binary_output_with, write_output_with, OutputDestination, OutputMode,
+};
+use clap::parser::ValueSource;
 use clap::{Arg, ArgAction, ArgMatches, Command};
 use std::path::PathBuf;
+use std::time::Instant;
 
-use super::{AppBuilder, PendingCommand};
+use super::fallback::FALLBACK_PATH;
+use super::{interrupt, schema, AppBuilder, PendingCommand};
+use crate::cli::deprecation::Deprecation;
 use crate::cli::dispatch::{
     dispatch, extract_command_path, get_deepest_matches, has_subcommand, insert_default_command,
-    DispatchOutput,
+    DispatchOutput, LiveStream,
 };
 use crate::cli::group::{ErasedConfigRecipe, GroupBuilder, GroupEntry};
-use crate::cli::handler::{CommandContext, RunResult};
+use crate::cli::handler::{CommandContext, Destination, ExitError, RunResult};
 use crate::cli::hooks::{RenderedOutput, TextOutput};
+use crate::cli::query::{Query, QueryHook};
+use crate::cli::timings::Timings;
+use crate::i18n::Locale;
 use crate::SetupError;
 
+/// State of one dispatch, left for its caller to print after the output.
+///
+/// It lives with the dispatch rather than on the app, so dispatches running
+/// on several threads never see each other's warnings or timings.
+#[derive(Default)]
+pub(crate) struct RunState {
+    /// Whether streamed items are printed as they arrive (set by `run()`).
+    pub(crate) live_output: bool,
+    /// Deprecated commands, aliases and flags the arguments used.
+    pub(crate) deprecations: Vec<Deprecation>,
+    /// `--timings` results, unless they were embedded in JSON output.
+    pub(crate) timings: Option<Timings>,
+}
+
 impl AppBuilder {
     /// Registers commands from a dispatch closure (used by the `dispatch!` macro).
     ///
@@ -58,12 +81,34 @@ impl AppBuilder {
         // Register all entries from the group builder with deferred closure creation
         for (name, entry) in builder.entries {
             match entry {
-                GroupEntry::Command { mut handler } => {
+                GroupEntry::Command {
+                    mut handler,
+                    aliases,
+                    output_mode,
+                    timeout,
+                    hidden,
+                } => {
                     let template = handler
                         .template()
                         .map(String::from)
                         .unwrap_or_else(|| self.resolve_template(&name));
 
+                    for alias in aliases {
+                        self.add_command_alias(&name, &alias);
+                    }
+
+                    if let Some(mode) = output_mode {
+                        self.command_output_modes.insert(name.clone(), mode);
+                    }
+
+                    if let Some(timeout) = timeout {
+                        self.command_timeouts.insert(name.clone(), timeout);
+                    }
+
+                    if hidden {
+                        self.hidden_commands.insert(name.clone());
+                    }
+
                     if let Some(hooks) = handler.take_hooks() {
                         self.command_hooks.insert(name.clone(), hooks);
                     }
@@ -72,12 +117,12 @@ impl AppBuilder {
                     let recipe = ErasedConfigRecipe::from_handler(handler);
 
                     // Check for duplicates
-                    if self.pending_commands.borrow().contains_key(&name) {
+                    if self.pending_commands.lock().unwrap().contains_key(&name) {
                         return Err(SetupError::DuplicateCommand(name));
                     }
 
                     // Store pending command
-                    self.pending_commands.borrow_mut().insert(
+                    self.pending_commands.lock().unwrap().insert(
                         name,
                         PendingCommand {
                             recipe: Box::new(recipe),
@@ -98,11 +143,15 @@ impl AppBuilder {
     ///
     /// Returns:
     /// - `RunResult::Handled(output)` if a handler was found and executed successfully,
+    /// - `RunResult::Stderr(output)` if the handler rendered to
+    ///   [`Destination::Stderr`](crate::cli::Destination),
     /// - `RunResult::Binary(bytes, filename)` for binary output,
     /// - `RunResult::Handled(String::new())` if the handler completed silently
     ///   (silent completion is currently mapped onto an empty `Handled`; the
     ///   8.0 overhaul will return a distinct `RunResult::Silent`),
     /// - `RunResult::Error(msg)` if a handler, hook, or output step failed,
+    /// - `RunResult::Exit(msg, code)` if the handler failed with an
+    ///   [`ExitError`](crate::cli::ExitError) whose code isn't 1,
     /// - `RunResult::NoMatch(matches)` if no handler matched.
     ///
     /// If hooks are registered for the command, they are executed:
@@ -113,46 +162,265 @@ impl AppBuilder {
     /// Handler errors and hook errors both abort execution and return
     /// `RunResult::Error(msg)`. Callers using `dispatch()` directly are
     /// responsible for writing the error to stderr and choosing an exit code.
+    ///
+    /// With `OutputMode::Json`, `msg` is a single-line JSON object instead of
+    /// human-readable text, so scripts can parse failures from stderr:
+    ///
+    /// ```json
+    /// {"error":{"code":"handler_error","message":"database is locked"}}
+    /// ```
+    ///
+    /// `code` is one of `handler_error` (the handler, post-dispatch hooks or
+    /// rendering failed), `hook_error` (a pre-dispatch or post-output hook
+    /// failed), `config_error` (the config file couldn't be loaded) or
+    /// `output_error` (writing `--output-file-path` failed).
     pub fn dispatch(&self, matches: ArgMatches, output_mode: OutputMode) -> RunResult {
+        self.dispatch_started_at(
+            matches,
+            output_mode,
+            Instant::now(),
+            &mut RunState::default(),
+        )
+    }
+
+    /// Like [`dispatch`](Self::dispatch), with `--timings` counting from
+    /// `started` (before argument parsing), recording what's left to print
+    /// in `run`.
+    fn dispatch_started_at(
+        &self,
+        matches: ArgMatches,
+        output_mode: OutputMode,
+        started: Instant,
+        run: &mut RunState,
+    ) -> RunResult {
         // Ensure commands are finalized (creates dispatch closures with current theme)
         self.ensure_commands_finalized();
 
         // Build command path from matches
-        let path = extract_command_path(&matches);
-        let path_str = path.join(".");
+        let mut path = extract_command_path(&matches);
+        let mut path_str = path.join(".");
 
         // Look up handler
         let commands = self.get_commands();
-        if let Some(dispatch_fn) = commands.get(&path_str) {
+        if !commands.contains_key(&path_str) {
+            if let Some(resolved) = self.resolve_command_alias(&path_str) {
+                path = resolved.split('.').map(String::from).collect();
+                path_str = resolved;
+            }
+        }
+        // Unmatched invocations go to the fallback, if one is set
+        let (handler_path, dispatch_fn) = match commands.get(&path_str) {
+            Some(dispatch_fn) => (path_str.as_str(), Some(dispatch_fn)),
+            None => (FALLBACK_PATH, commands.get(FALLBACK_PATH)),
+        };
+        if let Some(dispatch_fn) = dispatch_fn {
             let mut ctx = CommandContext::new(path, self.app_state.clone());
+            ctx.extensions.insert(self.csv_options.clone());
+            ctx.extensions.insert(self.paths());
+            ctx.verbosity = self.verbosity_for(&matches);
+            if let Some(policy) = self.retry_policies.get(handler_path) {
+                ctx.extensions.insert(policy.clone());
+            }
+            if run.live_output
+                && matches
+                    .try_get_one::<String>("_output_file_path")
+                    .ok()
+                    .flatten()
+                    .is_none()
+            {
+                ctx.extensions.insert(LiveStream);
+            }
+
+            // With --timings, each stage's duration is charged as it ends
+            let timings = self.timings_for(&matches, started);
+            run.timings = timings.clone();
+            if let Some(ref timings) = timings {
+                ctx.extensions.insert(timings.clone());
+            }
+            let lap = |stage| {
+                if let Some(ref timings) = timings {
+                    timings.lap(stage);
+                }
+            };
+
+            // Ctrl-C cancels ctx.cancellation until this command's output is ready
+            let _interrupts = self.watch_interrupts(&ctx.cancellation);
+
+            // Expose the selected locale and its messages to handlers and templates
+            if self.catalogs.is_some() || self.locale_flag.is_some() {
+                let locale = self.select_locale(&matches);
+                if let Some(ref catalogs) = self.catalogs {
+                    ctx.extensions.insert(catalogs.resolve(locale.as_deref()));
+                }
+                if let Some(locale) = locale {
+                    ctx.extensions.insert(Locale(locale));
+                }
+            }
+
+            // A --query projection replaces the template's data, so it's always
+            // emitted in a structured format
+            let query = self
+                .query_flag
+                .as_ref()
+                .and_then(|_| matches.try_get_one::<String>("_query").ok().flatten());
+            let output_mode = match query {
+                Some(expr) => {
+                    ctx.extensions.insert(Query(expr.clone()));
+                    if let Some(ref hook) = self.query_hook {
+                        ctx.extensions.insert(QueryHook(hook.clone()));
+                    }
+                    if output_mode.is_structured() {
+                        output_mode
+                    } else {
+                        OutputMode::Json
+                    }
+                }
+                None => output_mode,
+            };
+
+            // Merge config files, environment and --set into ctx.config(),
+            // also read by ConfigSource on this thread until the command is done
+            let _input_config = match self.load_config(&matches) {
+                Some(Ok(config)) => {
+                    let scope = crate::cli::config::scope_input_config(&config.0);
+                    ctx.extensions.insert(config);
+                    Some(scope)
+                }
+                Some(Err(e)) => {
+                    return dispatch_error(
+                        output_mode,
+                        "config_error",
+                        &e,
+                        format!("Error: {}", e),
+                        1,
+                    );
+                }
+                None => None,
+            };
+
+            // --yes / --no-input hold for the input chains and the handler
+            let _prompt_mode = self.prompt_mode_for(&matches).map(|mode| {
+                ctx.extensions.insert(mode);
+                standout_input::scope_prompt_mode(mode)
+            });
+
+            #[cfg(feature = "tracing")]
+            self.init_logging(ctx.verbosity, output_mode);
+
+            // Progress from ctx.progress() shows on a stderr terminal
+            let default_theme = crate::Theme::default();
+            let theme = self.theme.as_ref().unwrap_or(&default_theme);
+            if let Some(reporter) =
+                crate::cli::progress::stderr_reporter(theme, ctx.verbosity, output_mode)
+            {
+                ctx.extensions.insert(reporter);
+            }
+
+            // Themed indicatif bars, which framework output is printed around
+            #[cfg(feature = "indicatif")]
+            let _active_bars = {
+                use crate::cli::progress_bars::{dispatch_bars, ActiveBarsGuard};
+                let bars = dispatch_bars(theme, ctx.verbosity, output_mode);
+                let guard = ActiveBarsGuard::install(&bars);
+                ctx.extensions.insert(bars);
+                guard
+            };
+
+            // Validators report every problem at once, before any hook runs
+            if let Some(failure) = self.run_validators(
+                handler_path,
+                get_deepest_matches(&matches),
+                &ctx,
+                output_mode,
+            ) {
+                return failure;
+            }
 
-            // Get hooks for this command (used for pre-dispatch, post-dispatch, and post-output)
-            let hooks = self.command_hooks.get(&path_str);
+            // Get hooks for this command wrapped by middleware (used for
+            // pre-dispatch, post-dispatch, and post-output)
+            let hooks = self.hooks_for(handler_path);
+            let hooks = hooks.as_ref();
+            lap("parse");
 
             // Run pre-dispatch hooks if registered (hooks can inject state via ctx.extensions)
             if let Some(hooks) = hooks {
                 if let Err(e) = hooks.run_pre_dispatch(&matches, &mut ctx) {
-                    return RunResult::Error(format!("Hook error: {}", e));
+                    return dispatch_error(
+                        output_mode,
+                        "hook_error",
+                        &e,
+                        format!("Hook error: {}", e),
+                        1,
+                    );
                 }
+                lap("hooks");
             }
 
             // Get the subcommand matches for the deepest command
-            let sub_matches = get_deepest_matches(&matches);
+            // The fallback gets the top-level matches, since no command matched
+            let sub_matches = if handler_path == FALLBACK_PATH {
+                &matches
+            } else {
+                get_deepest_matches(&matches)
+            };
 
             // Run the handler (post-dispatch hooks are run inside dispatch function)
             // output_mode is passed separately because CommandContext is render-agnostic
             // Late binding: theme is resolved here at dispatch time, not when commands were registered
-            let default_theme = crate::Theme::default();
-            let theme = self.theme.as_ref().unwrap_or(&default_theme);
-            let dispatch_output =
-                match dispatch(dispatch_fn, sub_matches, &ctx, hooks, output_mode, theme) {
-                    Ok(output) => output,
-                    Err(e) => return RunResult::Error(e),
-                };
+            // A handler over its time limit is cancelled, then reported as timed out
+            let watchdog = self.start_watchdog(handler_path, &ctx, output_mode, run.live_output);
+            let result = dispatch(dispatch_fn, sub_matches, &ctx, hooks, output_mode, theme);
+            if let Some((watchdog, failure)) = watchdog {
+                if watchdog.timed_out() {
+                    return failure;
+                }
+            }
+            let dispatch_output = match result {
+                Ok(output) => output,
+                Err(e) => {
+                    let message = e.message.strip_prefix("Error: ").unwrap_or(&e.message);
+                    return dispatch_error(
+                        output_mode,
+                        "handler_error",
+                        message,
+                        e.message.clone(),
+                        e.exit_code,
+                    );
+                }
+            };
+            // Rendering laps its own stages; this covers handlers that don't render
+            lap("handler");
+
+            // Highlight JSON for color terminals; `raw` stays plain for files
+            let dispatch_output = match dispatch_output {
+                DispatchOutput::Text {
+                    formatted,
+                    raw,
+                    destination,
+                } if self.colored_json
+                    && output_mode == OutputMode::Json
+                    && standout_render::detect_color_capability() =>
+                {
+                    let formatted = highlight_json(&raw, theme).unwrap_or(formatted);
+                    DispatchOutput::Text {
+                        formatted,
+                        raw,
+                        destination,
+                    }
+                }
+                other => other,
+            };
+            lap("render");
 
-            // Convert to Output enum for post-output hooks
+            // Convert to Output enum for post-output hooks, remembering where text goes
+            let mut destination = Destination::Stdout;
             let output = match dispatch_output {
-                DispatchOutput::Text { formatted, raw } => {
+                DispatchOutput::Text {
+                    formatted,
+                    raw,
+                    destination: requested,
+                } => {
+                    destination = requested;
                     RenderedOutput::Text(TextOutput::new(formatted, raw))
                 }
                 DispatchOutput::Binary(b, f) => RenderedOutput::Binary(b, f),
@@ -163,33 +431,85 @@ impl AppBuilder {
             let mut final_output = if let Some(hooks) = hooks {
                 match hooks.run_post_output(&matches, &ctx, output) {
                     Ok(o) => o,
-                    Err(e) => return RunResult::Error(format!("Hook error: {}", e)),
+                    Err(e) => {
+                        return dispatch_error(
+                            output_mode,
+                            "hook_error",
+                            &e,
+                            format!("Hook error: {}", e),
+                            1,
+                        )
+                    }
                 }
             } else {
                 output
             };
+            if hooks.is_some() {
+                lap("hooks");
+            }
+
+            // JSON output carries the timings instead of a stderr summary
+            if let (Some(timings), RenderedOutput::Text(t), OutputMode::Json) =
+                (&timings, &mut final_output, output_mode)
+            {
+                if let Some(raw) = self.embed_timings(timings, &t.raw) {
+                    run.timings = None;
+                    let highlighted =
+                        if self.colored_json && standout_render::detect_color_capability() {
+                            highlight_json(&raw, theme)
+                        } else {
+                            None
+                        };
+                    t.formatted = highlighted.unwrap_or_else(|| raw.clone());
+                    t.raw = raw;
+                }
+            }
 
-            // Handle file output if configured
-            if self.output_file_flag.is_some() {
+            // Handle file output if configured; stderr output is never redirected
+            if self.output_file_flag.is_some() && destination == Destination::Stdout {
                 if let Some(path_str) = matches
                     .try_get_one::<String>("_output_file_path")
                     .unwrap_or(None)
                 {
                     let path = PathBuf::from(path_str);
                     let dest = OutputDestination::File(path);
+                    let mut options = self.output_file_options;
+                    if matches
+                        .try_get_one::<bool>("_output_file_append")
+                        .ok()
+                        .flatten()
+                        .copied()
+                        .unwrap_or(false)
+                    {
+                        options.append = true;
+                    }
 
                     match &final_output {
                         RenderedOutput::Text(t) => {
                             // Write raw output (without ANSI codes) to file
-                            if let Err(e) = write_output(&t.raw, &dest) {
-                                return RunResult::Error(format!("Error writing output: {}", e));
+                            if let Err(e) = write_output_with(&t.raw, &dest, &options) {
+                                return dispatch_error(
+                                    output_mode,
+                                    "output_error",
+                                    &e,
+                                    format!("Error writing output: {}", e),
+                                    1,
+                                );
+                            }
+                            // Suppress further output unless teeing to the terminal
+                            if !self.output_file_tee {
+                                final_output = RenderedOutput::Silent;
                             }
-                            // Suppress further output
-                            final_output = RenderedOutput::Silent;
                         }
                         RenderedOutput::Binary(b, _) => {
-                            if let Err(e) = write_binary_output(b, &dest) {
-                                return RunResult::Error(format!("Error writing output: {}", e));
+                            if let Err(e) = write_binary_output_with(b, &dest, &options) {
+                                return dispatch_error(
+                                    output_mode,
+                                    "output_error",
+                                    &e,
+                                    format!("Error writing output: {}", e),
+                                    1,
+                                );
                             }
                             final_output = RenderedOutput::Silent;
                         }
@@ -200,6 +520,9 @@ impl AppBuilder {
 
             // Convert back to RunResult (using formatted for terminal display)
             match final_output {
+                RenderedOutput::Text(t) if destination == Destination::Stderr => {
+                    RunResult::Stderr(t.formatted)
+                }
                 RenderedOutput::Text(t) => RunResult::Handled(t.formatted),
                 RenderedOutput::Binary(b, f) => RunResult::Binary(b, f),
                 RenderedOutput::Silent => RunResult::Handled(String::new()),
@@ -241,6 +564,25 @@ impl AppBuilder {
         I: IntoIterator<Item = T>,
         T: Into<std::ffi::OsString> + Clone,
     {
+        self.dispatch_from_with_mode(cmd, args, None, &mut RunState::default())
+    }
+
+    /// Like [`dispatch_from`](Self::dispatch_from), with `forced_mode`
+    /// overriding the `--output` flag and environment variable, recording
+    /// what's left to print in `run`.
+    pub(super) fn dispatch_from_with_mode<I, T>(
+        &self,
+        cmd: Command,
+        args: I,
+        forced_mode: Option<OutputMode>,
+        run: &mut RunState,
+    ) -> RunResult
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone,
+    {
+        let started = Instant::now();
+
         // Collect args to Vec<String> so we can potentially reparse with default command
         let args: Vec<String> = args
             .into_iter()
@@ -248,29 +590,40 @@ impl AppBuilder {
             .collect();
 
         // Augment command with --output flag
-        let augmented_cmd = self.augment_command_for_dispatch(cmd.clone());
+        let mut augmented_cmd = self.augment_command_for_dispatch(cmd.clone());
 
         // Parse arguments. Clap's "errors" include `--help` and `--version`,
         // which are successful display paths (stdout, exit 0). Real parse
         // errors (unknown flag, missing required arg, etc.) get `use_stderr()
-        // == true` and should surface as `RunResult::Error` so they exit
-        // non-zero on stderr.
-        let matches = match augmented_cmd.try_get_matches_from(&args) {
+        // == true` and should surface as failures so they exit non-zero on
+        // stderr, with clap's usage exit code (2).
+        let matches = match augmented_cmd.try_get_matches_from_mut(&args) {
             Ok(m) => m,
             Err(e) => {
                 if e.use_stderr() {
-                    return RunResult::Error(e.to_string());
+                    return RunResult::failure(e.to_string(), ExitError::USAGE);
+                }
+                if e.kind() == clap::error::ErrorKind::DisplayVersion {
+                    let args: Vec<std::ffi::OsString> = args.iter().map(Into::into).collect();
+                    if let Some(version) = self.render_version_for_args(&args) {
+                        return RunResult::Handled(version);
+                    }
                 }
                 return RunResult::Handled(e.to_string());
             }
         };
 
+        // The completions command bypasses handler dispatch
+        if let Some(sub_matches) = self.completions_matches(&matches) {
+            return self.run_completions_command(cmd, sub_matches);
+        }
+
         // Check if we need to insert default command
         let matches = if let Some(default_cmd) = &self.default_command {
             if has_subcommand(&matches) {
                 matches
             } else {
-                let new_args = insert_default_command(args, default_cmd);
+                let new_args = insert_default_command(&args, default_cmd);
 
                 // Reparse with default command inserted
                 let augmented_cmd = self.augment_command_for_dispatch(cmd);
@@ -278,7 +631,7 @@ impl AppBuilder {
                     Ok(m) => m,
                     Err(e) => {
                         if e.use_stderr() {
-                            return RunResult::Error(e.to_string());
+                            return RunResult::failure(e.to_string(), ExitError::USAGE);
                         }
                         return RunResult::Handled(e.to_string());
                     }
@@ -288,27 +641,31 @@ impl AppBuilder {
             matches
         };
 
-        // Extract output mode
-        let output_mode = if self.output_flag.is_some() {
-            match matches
+        run.deprecations = self.collect_deprecations(&augmented_cmd, &args, &matches);
+
+        // The hidden schema command bypasses handler dispatch
+        if let Some((schema::SCHEMA_COMMAND, sub_matches)) = matches.subcommand() {
+            return self.run_schema_command(sub_matches);
+        }
+
+        // Extract output mode: flag > environment variable > command default > Auto
+        let flag_mode = if self.output_flag.is_some()
+            && matches.value_source("_output_mode") == Some(ValueSource::CommandLine)
+        {
+            matches
                 .get_one::<String>("_output_mode")
-                .map(|s| s.as_str())
-            {
-                Some("term") => OutputMode::Term,
-                Some("text") => OutputMode::Text,
-                Some("term-debug") => OutputMode::TermDebug,
-                Some("json") => OutputMode::Json,
-                Some("yaml") => OutputMode::Yaml,
-                Some("xml") => OutputMode::Xml,
-                Some("csv") => OutputMode::Csv,
-                _ => OutputMode::Auto,
-            }
+                .and_then(|s| parse_output_mode(s))
         } else {
-            OutputMode::Auto
+            None
         };
+        let output_mode = forced_mode
+            .or(flag_mode)
+            .or_else(|| self.output_mode_from_env())
+            .or_else(|| self.command_output_mode(&matches))
+            .unwrap_or(OutputMode::Auto);
 
         // Dispatch to handler
-        self.dispatch(matches, output_mode)
+        self.dispatch_started_at(matches, output_mode, started, run)
     }
 
     /// Runs the CLI: parses arguments, dispatches to handlers, and prints output.
@@ -321,11 +678,19 @@ impl AppBuilder {
     /// - `true` if a handler processed and printed output
     /// - `false` if no handler matched (caller should handle manually)
     ///
+    /// Binary output is streamed to stdout byte-for-byte when stdout is piped
+    /// or redirected, and saved to its suggested filename on a terminal.
+    ///
     /// # Errors and exit codes
     ///
     /// On `RunResult::Error`, this function writes the error message to
     /// stderr and calls `std::process::exit(1)` — it does not return.
-    /// Likewise, a binary write failure writes to stderr and exits 1.
+    /// `RunResult::Exit` does the same with its carried code: command-line
+    /// usage errors exit 2, and handlers choose a code by returning an
+    /// [`ExitError`](crate::cli::ExitError) (e.g. `ExitError::not_found` exits 3).
+    /// Likewise, a binary write failure writes to stderr and exits 1. With
+    /// [`Self::on_interrupt`], a command stopped by Ctrl-C prints its partial
+    /// output and exits 130.
     /// Callers needing fine-grained control over exit codes should use
     /// [`Self::run_to_string`] or [`Self::dispatch_from`] and match on
     /// `RunResult` themselves.
@@ -349,22 +714,79 @@ impl AppBuilder {
         I: IntoIterator<Item = T>,
         T: Into<std::ffi::OsString> + Clone,
     {
-        let result = self.dispatch_from(cmd, args);
+        let args: Vec<std::ffi::OsString> = args.into_iter().map(Into::into).collect();
+        if self.watch_requested(&cmd, &args) {
+            return self.run_watch(cmd, args);
+        }
+
+        // Streamed items are printed as they arrive rather than collected
+        let mut run = RunState {
+            live_output: true,
+            ..RunState::default()
+        };
+        let result = self.dispatch_from_with_mode(cmd, args, None, &mut run);
+        for warning in self.deprecation_warnings(&run) {
+            eprintln!("{}", warning);
+        }
         // Track whether we need to terminate the process with a non-zero
         // exit code. We can't return `ExitCode` from `run()` without a
         // breaking signature change, so we exit explicitly after flushing
         // warnings (see issue #141).
+        let (handled, mut exit_code) = self.print_result(&result);
+
+        // With --timings, the summary follows the output
+        self.print_timings(&run);
+
+        // After the primary output has been flushed to stdout, render any
+        // framework warnings collected during setup/dispatch to stderr so
+        // they appear last on the user's terminal. `OutputMode::Auto` is a
+        // safe default here: the renderer's final decision on styling is
+        // driven by whether stderr itself is a color-capable TTY.
+        let default_theme = crate::Theme::default();
+        let theme = self.theme.as_ref().unwrap_or(&default_theme);
+        standout_render::warnings::flush_to_stderr(theme, OutputMode::Auto);
+
+        // A command stopped by Ctrl-C printed its partial output; still report
+        // the interrupt to the shell
+        if interrupt::take_interrupted() {
+            exit_code.get_or_insert(interrupt::INTERRUPTED_EXIT_CODE);
+        }
+
+        if let Some(code) = exit_code {
+            std::process::exit(code);
+        }
+
+        handled
+    }
+
+    /// Prints a dispatch result, returning whether it was handled and the
+    /// exit code its failure calls for.
+    pub(super) fn print_result(&self, result: &RunResult) -> (bool, Option<i32>) {
         let mut exit_code: Option<i32> = None;
         let handled = match result {
-            RunResult::Handled(ref output) => {
+            RunResult::Handled(output) => {
                 if !output.is_empty() {
                     println!("{}", output);
                 }
                 true
             }
-            RunResult::Binary(ref bytes, ref filename) => {
-                // For binary output, write to stdout or the suggested file
-                // By default, we write to the suggested filename
+            RunResult::Stderr(output) => {
+                if !output.is_empty() {
+                    eprintln!("{}", output);
+                }
+                true
+            }
+            RunResult::Binary(bytes, _) if !standout_render::detect_is_tty() => {
+                // Piped stdout gets the raw bytes, e.g. `myapp export > chart.png`
+                if let Err(e) = write_binary_output(bytes, &OutputDestination::Stdout) {
+                    eprintln!("Error writing output: {}", e);
+                    exit_code = Some(1);
+                }
+                true
+            }
+            RunResult::Binary(bytes, filename) => {
+                // On a terminal, save to the suggested filename instead of
+                // dumping bytes to the screen
                 if let Err(e) = std::fs::write(filename, bytes) {
                     eprintln!("Error writing {}: {}", filename, e);
                     exit_code = Some(1);
@@ -374,32 +796,23 @@ impl AppBuilder {
                 true
             }
             RunResult::Silent => true, // Handler ran successfully, no output
-            RunResult::Error(ref msg) => {
+            RunResult::Error(msg) => {
                 eprintln!("{}", msg);
                 exit_code = Some(1);
                 true
             }
+            RunResult::Exit(msg, code) => {
+                eprintln!("{}", msg);
+                exit_code = Some(i32::from(*code));
+                true
+            }
             RunResult::NoMatch(_) => false,
             // Required by `#[non_exhaustive]`. Conservative default: treat
             // any future variant as "not handled" so the caller's fallback
             // path runs.
             _ => false,
         };
-
-        // After the primary output has been flushed to stdout, render any
-        // framework warnings collected during setup/dispatch to stderr so
-        // they appear last on the user's terminal. `OutputMode::Auto` is a
-        // safe default here: the renderer's final decision on styling is
-        // driven by whether stderr itself is a color-capable TTY.
-        let default_theme = crate::Theme::default();
-        let theme = self.theme.as_ref().unwrap_or(&default_theme);
-        standout_render::warnings::flush_to_stderr(theme, OutputMode::Auto);
-
-        if let Some(code) = exit_code {
-            std::process::exit(code);
-        }
-
-        handled
+        (handled, exit_code)
     }
 
     /// Runs the CLI and returns the rendered output as a string.
@@ -412,8 +825,12 @@ impl AppBuilder {
     /// - `RunResult::Handled(output)` - Handler executed successfully, output is the rendered string.
     ///   Note: silent completion currently surfaces as `Handled(String::new())` rather than a
     ///   distinct `Silent` variant; that distinction returns in the 8.0 error-handling overhaul.
+    /// - `RunResult::Stderr(output)` - Handler returned `Output::RenderTo(Destination::Stderr, _)`;
+    ///   the output belongs on stderr
     /// - `RunResult::Binary(bytes, filename)` - Handler produced binary output
-    /// - `RunResult::Error(msg)` - A handler, hook, output step, or clap parse failed
+    /// - `RunResult::Error(msg)` - A handler, hook, or output step failed (exit code 1)
+    /// - `RunResult::Exit(msg, code)` - Clap parsing failed (code 2) or the handler
+    ///   returned an [`ExitError`](crate::cli::ExitError) with a code other than 1
     /// - `RunResult::NoMatch(matches)` - No handler matched
     ///
     /// # Example
@@ -433,6 +850,10 @@ impl AppBuilder {
     ///         eprintln!("{}", msg);
     ///         std::process::exit(1);
     ///     },
+    ///     RunResult::Exit(msg, code) => {
+    ///         eprintln!("{}", msg);
+    ///         std::process::exit(code);
+    ///     },
     ///     RunResult::NoMatch(matches) => { /* handle manually */ },
     ///     // RunResult is #[non_exhaustive]; cover Silent and any future variants.
     ///     _ => {},
@@ -455,18 +876,12 @@ impl AppBuilder {
                     .long(flag)
                     .value_name("MODE")
                     .global(true)
-                    .value_parser([
-                        "auto",
-                        "term",
-                        "text",
-                        "term-debug",
-                        "json",
-                        "yaml",
-                        "xml",
-                        "csv",
-                    ])
+                    .value_parser(OUTPUT_MODES)
                     .default_value("auto")
-                    .help("Output format"),
+                    .help(match self.output_env {
+                        Some(ref name) => format!("Output format [env: {}]", name),
+                        None => "Output format".to_string(),
+                    }),
             );
         }
 
@@ -479,12 +894,240 @@ impl AppBuilder {
                     .value_name("PATH")
                     .global(true)
                     .action(ArgAction::Set)
-                    .help("Write output to file instead of stdout"),
+                    .help(if self.output_file_tee {
+                        "Also write output to file"
+                    } else {
+                        "Write output to file instead of stdout"
+                    }),
+            );
+
+            if let Some(ref append_name) = self.output_file_append_flag {
+                let append: &'static str = Box::leak(append_name.clone().into_boxed_str());
+                cmd = cmd.arg(
+                    Arg::new("_output_file_append")
+                        .long(append)
+                        .global(true)
+                        .action(ArgAction::SetTrue)
+                        .help(format!(
+                            "Append to the --{} file instead of overwriting it",
+                            flag
+                        )),
+                );
+            }
+        }
+
+        // Add the query flag if enabled
+        if let Some(ref flag_name) = self.query_flag {
+            let flag: &'static str = Box::leak(flag_name.clone().into_boxed_str());
+            cmd = cmd.arg(
+                Arg::new("_query")
+                    .long(flag)
+                    .value_name("EXPR")
+                    .global(true)
+                    .action(ArgAction::Set)
+                    .help("Select part of the output (e.g. items[0].name); implies --output=json"),
+            );
+        }
+
+        // Add the locale flag if enabled
+        if let Some(ref flag_name) = self.locale_flag {
+            let flag: &'static str = Box::leak(flag_name.clone().into_boxed_str());
+            cmd = cmd.arg(
+                Arg::new("_locale")
+                    .long(flag)
+                    .value_name("LOCALE")
+                    .global(true)
+                    .action(ArgAction::Set)
+                    .help("Language for help and messages (e.g. pt-BR)"),
             );
         }
 
+        // Add -v / -q if enabled
+        cmd = self.augment_command_with_verbosity(cmd);
+
+        // Add the config loader's --config / --set if enabled
+        cmd = self.augment_command_with_config(cmd);
+
+        // Add --timings if enabled
+        cmd = self.augment_command_with_timings(cmd);
+
+        // Add --yes / --no-input if enabled
+        cmd = self.augment_command_with_prompt_flags(cmd);
+
+        // Add --watch if any paths are watched
+        cmd = self.augment_command_with_watch(cmd);
+
+        // Add the hidden schema command once any output schema is registered
+        if !self.output_schemas.is_empty() {
+            cmd = cmd.subcommand(Self::schema_subcommand());
+        }
+
+        // Enable --version from the build info if needed
+        cmd = self.augment_command_with_version(cmd);
+
+        // Add commands contributed by plugins
+        cmd = self.augment_command_with_plugins(cmd);
+
+        // Make registered aliases parse and show up in help
+        for (path, aliases) in &self.command_aliases {
+            let path: Vec<&str> = path.split('.').collect();
+            cmd = add_visible_aliases(cmd, &path, aliases);
+        }
+
+        // Hide commands registered as hidden
+        for path in &self.hidden_commands {
+            let path: Vec<&str> = path.split('.').collect();
+            cmd = hide_subcommand(cmd, &path);
+        }
+
+        // Note deprecated commands, aliases and flags in help
+        cmd = self.augment_command_with_deprecations(cmd);
+
+        // Add the completions command if enabled
+        if let Some(completions) = self.completions_subcommand() {
+            cmd = cmd.subcommand(completions);
+        }
+
         cmd
     }
+
+    /// Returns the default output mode of the matched command, if it has one.
+    fn command_output_mode(&self, matches: &ArgMatches) -> Option<OutputMode> {
+        let path = extract_command_path(matches).join(".");
+        self.command_output_modes
+            .get(&path)
+            .or_else(|| {
+                let resolved = self.resolve_command_alias(&path)?;
+                self.command_output_modes.get(&resolved)
+            })
+            .copied()
+    }
+
+    /// Reads the default output mode from the configured environment variable.
+    ///
+    /// Unset or empty variables yield `None`; unrecognized values are reported
+    /// as a framework warning and ignored.
+    pub(super) fn output_mode_from_env(&self) -> Option<OutputMode> {
+        let name = self.output_env.as_deref()?;
+        let value = std::env::var(name).ok()?;
+        let value = value.trim();
+        if value.is_empty() {
+            return None;
+        }
+        let mode = parse_output_mode(&value.to_ascii_lowercase());
+        if mode.is_none() {
+            standout_render::warnings::push_warning(format!(
+                "Ignoring {}={}: expected one of {}",
+                name,
+                value,
+                OUTPUT_MODES.join(", ")
+            ));
+        }
+        mode
+    }
+}
+
+/// Values accepted by the `--output` flag and the output environment variable.
+const OUTPUT_MODES: [&str; 9] = [
+    "auto",
+    "term",
+    "text",
+    "term-debug",
+    "json",
+    "yaml",
+    "xml",
+    "toml",
+    "csv",
+];
+
+/// Maps an `--output` value to its [`OutputMode`].
+pub(super) fn parse_output_mode(value: &str) -> Option<OutputMode> {
+    match value {
+        "auto" => Some(OutputMode::Auto),
+        "term" => Some(OutputMode::Term),
+        "text" => Some(OutputMode::Text),
+        "term-debug" => Some(OutputMode::TermDebug),
+        "json" => Some(OutputMode::Json),
+        "yaml" => Some(OutputMode::Yaml),
+        "xml" => Some(OutputMode::Xml),
+        "toml" => Some(OutputMode::Toml),
+        "csv" => Some(OutputMode::Csv),
+        _ => None,
+    }
+}
+
+/// Syntax-highlights JSON output with the theme's JSON styles.
+fn highlight_json(raw: &str, theme: &crate::Theme) -> Option<String> {
+    let styles = theme.resolve_styles(Some(crate::detect_color_mode()));
+    serde_json::from_str(raw)
+        .ok()
+        .map(|value| standout_render::colorize_json(&value, &styles))
+}
+
+/// Builds the failed `RunResult` for a dispatch error.
+///
+/// Under `--output=json` the failure becomes [`json_error`]; every other
+/// mode keeps the human-readable `text`.
+fn dispatch_error(
+    output_mode: OutputMode,
+    code: &str,
+    message: impl std::fmt::Display,
+    text: String,
+    exit_code: u8,
+) -> RunResult {
+    if output_mode == OutputMode::Json {
+        RunResult::failure(json_error(code, message), exit_code)
+    } else {
+        RunResult::failure(text, exit_code)
+    }
+}
+
+/// Formats a failure as the `{"error": {"code", "message"}}` object printed
+/// under `--output=json`.
+pub(super) fn json_error(code: &str, message: impl std::fmt::Display) -> String {
+    serde_json::json!({
+        "error": {
+            "code": code,
+            "message": message.to_string(),
+        }
+    })
+    .to_string()
+}
+
+/// Adds `aliases` to the subcommand at `path`, skipping ones it already has.
+/// Paths that don't exist in `cmd` are left alone.
+fn add_visible_aliases(cmd: Command, path: &[&str], aliases: &[String]) -> Command {
+    let Some((first, rest)) = path.split_first() else {
+        let new: Vec<&'static str> = aliases
+            .iter()
+            .filter(|alias| {
+                *alias != cmd.get_name() && !cmd.get_all_aliases().any(|a| a == alias.as_str())
+            })
+            .map(|alias| &*Box::leak(alias.clone().into_boxed_str()))
+            .collect();
+        return cmd.visible_aliases(new);
+    };
+    let Some(name) = cmd
+        .find_subcommand(first)
+        .map(|sub| sub.get_name().to_string())
+    else {
+        return cmd;
+    };
+    cmd.mut_subcommand(name, |sub| add_visible_aliases(sub, rest, aliases))
+}
+
+/// Hides the subcommand at `path`.
+fn hide_subcommand(cmd: Command, path: &[&str]) -> Command {
+    let Some((first, rest)) = path.split_first() else {
+        return cmd.hide(true);
+    };
+    let Some(name) = cmd
+        .find_subcommand(first)
+        .map(|sub| sub.get_name().to_string())
+    else {
+        return cmd;
+    };
+    cmd.mut_subcommand(name, |sub| hide_subcommand(sub, rest))
 }
 
 #[cfg(test)]
@@ -758,57 +1401,129 @@ mod tests {
     }
 
     #[test]
-    fn test_dispatch_from_basic() {
-        use serde_json::json;
-
+    fn test_dispatch_exit_error_code() {
         let builder = AppBuilder::new()
             .command(
-                "list",
-                |_m, _ctx| Ok(HandlerOutput::Render(json!({"items": ["a", "b"]}))),
-                "Items: {{ items }}",
+                "show",
+                |_m, _ctx| -> HandlerResult<()> {
+                    Err(ExitError::not_found(anyhow::anyhow!("no item with id 7")).into())
+                },
+                "",
             )
             .unwrap();
 
-        let cmd = Command::new("app").subcommand(Command::new("list"));
+        let cmd = Command::new("app").subcommand(Command::new("show"));
 
-        let result = builder.dispatch_from(cmd, ["app", "list"]);
+        let matches = cmd.clone().try_get_matches_from(["app", "show"]).unwrap();
+        let result = builder.dispatch(matches, OutputMode::Text);
+        match result {
+            RunResult::Exit(ref msg, code) => {
+                assert_eq!(msg, "Error: no item with id 7");
+                assert_eq!(code, 3);
+            }
+            ref other => panic!("Expected Exit, got {:?}", other),
+        }
 
-        assert!(result.is_handled());
-        assert_eq!(result.output(), Some("Items: [\"a\", \"b\"]"));
+        let matches = cmd.try_get_matches_from(["app", "show"]).unwrap();
+        let result = builder.dispatch(matches, OutputMode::Json);
+        assert_eq!(result.exit_code(), 3);
+        let parsed: serde_json::Value = serde_json::from_str(result.error().unwrap()).unwrap();
+        assert_eq!(parsed["error"]["code"], "handler_error");
+        assert_eq!(parsed["error"]["message"], "no item with id 7");
     }
 
     #[test]
-    fn test_dispatch_from_with_json_flag() {
-        use serde_json::json;
-
+    fn test_dispatch_from_usage_error_exit_code() {
         let builder = AppBuilder::new()
-            .command(
-                "list",
-                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 5}))),
-                "Count: {{ count }}",
-            )
+            .command("list", |_m, _ctx| Ok(HandlerOutput::Render(())), "")
             .unwrap();
 
         let cmd = Command::new("app").subcommand(Command::new("list"));
 
-        let result = builder.dispatch_from(cmd, ["app", "--output=json", "list"]);
+        let result = builder.dispatch_from(cmd, ["app", "list", "--bogus"]);
 
-        assert!(result.is_handled());
-        let output = result.output().unwrap();
-        assert!(output.contains("\"count\": 5"));
+        assert!(result.is_error());
+        assert_eq!(result.exit_code(), 2);
     }
 
     #[test]
-    fn test_dispatch_from_unhandled() {
-        use serde_json::json;
-
+    fn test_dispatch_error_result_json() {
         let builder = AppBuilder::new()
-            .command("list", |_m, _ctx| Ok(HandlerOutput::Render(json!({}))), "")
+            .command(
+                "fail",
+                |_m, _ctx| Err::<HandlerOutput<()>, _>(anyhow::anyhow!("something went wrong")),
+                "",
+            )
             .unwrap();
 
-        let cmd = Command::new("app")
-            .subcommand(Command::new("list"))
-            .subcommand(Command::new("other"));
+        let cmd = Command::new("app").subcommand(Command::new("fail"));
+
+        let matches = cmd.try_get_matches_from(["app", "fail"]).unwrap();
+        let result = builder.dispatch(matches, OutputMode::Json);
+
+        let msg = result.error().expect("expected Error");
+        assert!(!msg.contains('\n'));
+        let parsed: serde_json::Value = serde_json::from_str(msg).unwrap();
+        assert_eq!(
+            parsed,
+            serde_json::json!({
+                "error": {"code": "handler_error", "message": "something went wrong"}
+            })
+        );
+    }
+
+    #[test]
+    fn test_dispatch_from_basic() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"items": ["a", "b"]}))),
+                "Items: {{ items }}",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+
+        let result = builder.dispatch_from(cmd, ["app", "list"]);
+
+        assert!(result.is_handled());
+        assert_eq!(result.output(), Some("Items: [\"a\", \"b\"]"));
+    }
+
+    #[test]
+    fn test_dispatch_from_with_json_flag() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 5}))),
+                "Count: {{ count }}",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+
+        let result = builder.dispatch_from(cmd, ["app", "--output=json", "list"]);
+
+        assert!(result.is_handled());
+        let output = result.output().unwrap();
+        assert!(output.contains("\"count\": 5"));
+    }
+
+    #[test]
+    fn test_dispatch_from_unhandled() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command("list", |_m, _ctx| Ok(HandlerOutput::Render(json!({}))), "")
+            .unwrap();
+
+        let cmd = Command::new("app")
+            .subcommand(Command::new("list"))
+            .subcommand(Command::new("other"));
 
         let result = builder.dispatch_from(cmd, ["app", "other"]);
 
@@ -881,6 +1596,31 @@ mod tests {
         assert!(msg.contains("blocked by hook"));
     }
 
+    #[test]
+    fn test_dispatch_pre_dispatch_hook_abort_json() {
+        let builder = AppBuilder::new()
+            .command("list", |_m, _ctx| Ok(HandlerOutput::Render(())), "")
+            .unwrap()
+            .hooks(
+                "list",
+                Hooks::new()
+                    .pre_dispatch(|_, _ctx| Err(HookError::pre_dispatch("blocked by hook"))),
+            );
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+
+        let matches = cmd.try_get_matches_from(["app", "list"]).unwrap();
+        let result = builder.dispatch(matches, OutputMode::Json);
+
+        let msg = result.error().expect("expected Error");
+        let parsed: serde_json::Value = serde_json::from_str(msg).unwrap();
+        assert_eq!(parsed["error"]["code"], "hook_error");
+        assert!(parsed["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("blocked by hook"));
+    }
+
     #[test]
     fn test_dispatch_with_post_output_hook() {
         use serde_json::json;
@@ -1057,6 +1797,420 @@ mod tests {
         assert_eq!(result.output(), Some("other"));
     }
 
+    #[test]
+    fn test_dispatch_middleware_wraps_command_hooks() {
+        use serde_json::json;
+
+        // Each layer appends its name to `trail`, on the way in (pre-dispatch
+        // via extensions) and on the way out (post-dispatch on the data)
+        struct Trail(Vec<&'static str>);
+
+        let layer = |name: &'static str| {
+            Hooks::new()
+                .pre_dispatch(move |_, ctx| {
+                    let mut trail = ctx.extensions.remove::<Trail>().unwrap_or(Trail(vec![]));
+                    trail.0.push(name);
+                    ctx.extensions.insert(trail);
+                    Ok(())
+                })
+                .post_dispatch(move |_, _ctx, mut data| {
+                    let trail = data["trail"].as_str().unwrap_or_default().to_string();
+                    data["trail"] = json!(format!("{}{}", trail, name));
+                    Ok(data)
+                })
+        };
+
+        let builder = AppBuilder::new()
+            .command(
+                "list",
+                |_m, ctx| {
+                    let trail = ctx.extensions.get::<Trail>().unwrap().0.join(",");
+                    Ok(HandlerOutput::Render(json!({"seen": trail, "trail": "|"})))
+                },
+                "{{ seen }} {{ trail }}",
+            )
+            .unwrap()
+            .hooks("list", layer("cmd"))
+            .middleware(layer("outer"))
+            .middleware(layer("inner"));
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+        let matches = cmd.try_get_matches_from(["app", "list"]).unwrap();
+        let result = builder.dispatch(matches, OutputMode::Text);
+
+        assert_eq!(result.output(), Some("outer,inner,cmd |cmdinnerouter"));
+    }
+
+    #[test]
+    fn test_dispatch_timings_embedded_in_json() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .timings_flag()
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 2}))),
+                "{{ count }}",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+        let mut run = RunState::default();
+        let result = builder.dispatch_from_with_mode(
+            cmd,
+            ["app", "list", "--timings", "--output=json"],
+            None,
+            &mut run,
+        );
+
+        let output: serde_json::Value = serde_json::from_str(result.output().unwrap()).unwrap();
+        assert_eq!(output["count"], 2);
+        let stages: Vec<_> = output["_timings"]["stages"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|stage| stage["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(stages, ["parse", "handler", "render"]);
+        assert!(output["_timings"]["total_ms"].is_f64());
+        // Embedded, so there's nothing left to print on stderr
+        assert!(run.timings.is_none());
+    }
+
+    #[test]
+    fn test_dispatch_timings_kept_for_text_output() {
+        use crate::cli::timings::Timings;
+        use serde_json::json;
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let timed = Arc::new(AtomicBool::new(false));
+        let seen = timed.clone();
+        let builder = AppBuilder::new()
+            .timings_flag()
+            .command(
+                "list",
+                move |_m, ctx| {
+                    seen.store(ctx.extensions.get::<Timings>().is_some(), Ordering::SeqCst);
+                    Ok(HandlerOutput::Render(json!({"count": 2})))
+                },
+                "{{ count }} items",
+            )
+            .unwrap()
+            .hooks("list", Hooks::new().pre_dispatch(|_, _| Ok(())));
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+        let mut run = RunState::default();
+        let result = builder.dispatch_from_with_mode(
+            cmd.clone(),
+            ["app", "list", "--timings"],
+            None,
+            &mut run,
+        );
+
+        assert_eq!(result.output(), Some("2 items"));
+        assert!(timed.load(Ordering::SeqCst));
+        let timings = run.timings.unwrap();
+        let stages: Vec<_> = timings.stages().iter().map(|(name, _)| *name).collect();
+        assert_eq!(stages, ["parse", "hooks", "handler", "render"]);
+
+        // Without the flag nothing is recorded
+        let mut run = RunState::default();
+        builder.dispatch_from_with_mode(cmd, ["app", "list"], None, &mut run);
+        assert!(!timed.load(Ordering::SeqCst));
+        assert!(run.timings.is_none());
+    }
+
+    #[test]
+    fn test_dispatch_interrupt_renders_partial_output_and_runs_cleanup() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let cleaned_up = Arc::new(AtomicBool::new(false));
+        let cleanup_flag = cleaned_up.clone();
+        let post_output_ran = Arc::new(AtomicBool::new(false));
+        let post_output_flag = post_output_ran.clone();
+
+        let builder = AppBuilder::new()
+            .command(
+                "sync",
+                |_m, ctx| {
+                    let mut done = Vec::new();
+                    for item in ["a", "b", "c"] {
+                        if ctx.is_cancelled() {
+                            break;
+                        }
+                        done.push(item);
+                        // Simulate Ctrl-C arriving after the first item
+                        ctx.cancellation.cancel();
+                    }
+                    Ok(HandlerOutput::Render(serde_json::json!({ "done": done })))
+                },
+                "{{ done | join(',') }}",
+            )
+            .unwrap()
+            .hooks(
+                "sync",
+                Hooks::new().post_output(move |_, ctx, output| {
+                    assert!(ctx.is_cancelled());
+                    post_output_flag.store(true, Ordering::SeqCst);
+                    Ok(output)
+                }),
+            )
+            .on_interrupt(move || cleanup_flag.store(true, Ordering::SeqCst));
+
+        let cmd = Command::new("app").subcommand(Command::new("sync"));
+        let matches = cmd.try_get_matches_from(["app", "sync"]).unwrap();
+        let result = builder.dispatch(matches, OutputMode::Text);
+
+        assert_eq!(result.output(), Some("a"));
+        assert!(post_output_ran.load(Ordering::SeqCst));
+        assert!(cleaned_up.load(Ordering::SeqCst));
+        assert!(interrupt::take_interrupted());
+    }
+
+    #[test]
+    fn test_dispatch_middleware_short_circuits_every_command() {
+        let builder = AppBuilder::new()
+            .command(
+                "list",
+                |_m, _ctx| -> HandlerResult<()> {
+                    panic!("Handler should not be called");
+                },
+                "",
+            )
+            .unwrap()
+            .command(
+                "other",
+                |_m, _ctx| -> HandlerResult<()> {
+                    panic!("Handler should not be called");
+                },
+                "",
+            )
+            .unwrap()
+            .middleware(
+                Hooks::new().pre_dispatch(|_, _ctx| Err(HookError::pre_dispatch("not logged in"))),
+            );
+
+        let cmd = Command::new("app")
+            .subcommand(Command::new("list"))
+            .subcommand(Command::new("other"));
+
+        for name in ["list", "other"] {
+            let matches = cmd.clone().try_get_matches_from(["app", name]).unwrap();
+            let result = builder.dispatch(matches, OutputMode::Text);
+            let msg = result.error().expect("expected Error");
+            assert!(msg.contains("not logged in"), "{msg}");
+        }
+    }
+
+    #[test]
+    fn test_dispatch_command_alias() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command_with(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"items": ["a"]}))),
+                |cfg| cfg.template("{{ items | length }} items").alias("ls"),
+            )
+            .unwrap()
+            .group("db", |g| {
+                g.command_with(
+                    "migrate",
+                    |_m, _ctx| Ok(HandlerOutput::Render(json!({}))),
+                    |cfg| cfg.template("migrated"),
+                )
+            })
+            .unwrap()
+            .command_alias("db.migrate", "mig");
+
+        let cmd = Command::new("app")
+            .subcommand(Command::new("list"))
+            .subcommand(Command::new("db").subcommand(Command::new("migrate")));
+
+        let result = builder.dispatch_from(cmd.clone(), ["app", "ls"]);
+        assert_eq!(result.output(), Some("1 items"));
+
+        let result = builder.dispatch_from(cmd, ["app", "db", "mig"]);
+        assert_eq!(result.output(), Some("migrated"));
+    }
+
+    #[test]
+    fn test_dispatch_resolves_clap_command_named_after_alias() {
+        let builder = AppBuilder::new()
+            .command_with(
+                "list",
+                |_m, _ctx| -> HandlerResult<()> { Ok(HandlerOutput::Silent) },
+                |cfg| cfg.alias("ls"),
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("ls"));
+        let matches = cmd.try_get_matches_from(["app", "ls"]).unwrap();
+        let result = builder.dispatch(matches, OutputMode::Text);
+
+        assert!(result.is_handled());
+    }
+
+    #[test]
+    fn test_command_aliases_shown_in_help() {
+        let builder = AppBuilder::new().command_alias("list", "ls");
+
+        let cmd = Command::new("app").subcommand(Command::new("list").about("List items"));
+        let cmd = builder.augment_command_for_dispatch(cmd);
+        let help = crate::cli::help::render_help(&cmd, None).unwrap();
+
+        assert!(help.contains("List items (aliases: ls)"), "{help}");
+    }
+
+    #[test]
+    fn test_hidden_commands_run_but_stay_out_of_help() {
+        let builder = AppBuilder::new()
+            .commands(|g| {
+                g.command_with(
+                    "debug",
+                    |_m, _ctx| Ok(HandlerOutput::Render(serde_json::json!({}))),
+                    |cfg| cfg.template("state").hidden(),
+                )
+                .command("list", |_m, _ctx| {
+                    Ok(HandlerOutput::Render(serde_json::json!({})))
+                })
+            })
+            .unwrap();
+
+        let cmd = Command::new("app")
+            .subcommand(Command::new("list").about("List items"))
+            .subcommand(Command::new("debug").about("Dump state"));
+        let augmented = builder.augment_command_for_dispatch(cmd.clone());
+        let help = crate::cli::help::render_help(&augmented, None).unwrap();
+
+        assert!(help.contains("List items"), "{help}");
+        assert!(!help.contains("Dump state"), "{help}");
+        let result = builder.dispatch_from(cmd, ["app", "debug"]);
+        assert_eq!(result.output(), Some("state"));
+    }
+
+    #[test]
+    fn test_command_output_mode_applies_without_flag() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command_with(
+                "export",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 2}))),
+                |cfg| cfg.template("{{ count }} items").output(OutputMode::Json),
+            )
+            .unwrap()
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 3}))),
+                "{{ count }} items",
+            )
+            .unwrap()
+            .command_output("list", OutputMode::Yaml);
+
+        let cmd = Command::new("app")
+            .subcommand(Command::new("export"))
+            .subcommand(Command::new("list"));
+
+        let result = builder.dispatch_from(cmd.clone(), ["app", "export"]);
+        assert!(result.output().unwrap().contains("\"count\": 2"));
+
+        let result = builder.dispatch_from(cmd.clone(), ["app", "list"]);
+        assert_eq!(result.output(), Some("count: 3\n"));
+
+        let result = builder.dispatch_from(cmd, ["app", "export", "--output", "text"]);
+        assert_eq!(result.output(), Some("2 items"));
+    }
+
+    #[test]
+    fn test_pre_render_hook_swaps_template_and_wraps_data() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!(["a", "b"]))),
+                "{{ items }}",
+            )
+            .unwrap()
+            .hooks(
+                "list",
+                Hooks::new().pre_render(|_m, _ctx, request| {
+                    Ok(crate::cli::RenderRequest {
+                        template: "page {{ page }}: {{ items | join(', ') }}".into(),
+                        data: json!({ "items": request.data, "page": 2 }),
+                    })
+                }),
+            );
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+
+        let result = builder.dispatch_from(cmd.clone(), ["app", "list", "--output", "text"]);
+        assert_eq!(result.output(), Some("page 2: a, b"));
+
+        let result = builder.dispatch_from(cmd, ["app", "list", "--output", "json"]);
+        let json: serde_json::Value = serde_json::from_str(result.output().unwrap()).unwrap();
+        assert_eq!(json, json!({ "items": ["a", "b"], "page": 2 }));
+    }
+
+    #[test]
+    fn test_dispatch_stream_renders_each_item() {
+        use serde_json::json;
+
+        let builder = AppBuilder::new()
+            .command(
+                "tail",
+                |_m, _ctx| {
+                    Ok(HandlerOutput::stream(
+                        (1..=3).map(|n| json!({"line": format!("entry {}", n)})),
+                    ))
+                },
+                "> {{ line }}\n",
+            )
+            .unwrap()
+            .hooks(
+                "tail",
+                Hooks::new().post_dispatch(|_m, _ctx, mut data| {
+                    data["line"] = json!(data["line"].as_str().unwrap().to_uppercase());
+                    Ok(data)
+                }),
+            );
+        let cmd = Command::new("app").subcommand(Command::new("tail"));
+
+        let result = builder.dispatch_from(cmd.clone(), ["app", "tail", "--output", "text"]);
+        assert_eq!(result.output(), Some("> ENTRY 1\n> ENTRY 2\n> ENTRY 3"));
+
+        let result = builder.dispatch_from(cmd, ["app", "tail", "--output", "json"]);
+        assert_eq!(
+            result.output(),
+            Some("{\"line\":\"ENTRY 1\"}\n{\"line\":\"ENTRY 2\"}\n{\"line\":\"ENTRY 3\"}")
+        );
+    }
+
+    #[test]
+    fn test_dispatch_stream_stops_when_cancelled() {
+        let builder = AppBuilder::new()
+            .command(
+                "tail",
+                |_m, ctx| {
+                    let cancellation = ctx.cancellation.clone();
+                    Ok(HandlerOutput::stream((1..).map(move |n| {
+                        if n == 2 {
+                            cancellation.cancel();
+                        }
+                        serde_json::json!({ "n": n })
+                    })))
+                },
+                "{{ n }}",
+            )
+            .unwrap();
+        let cmd = Command::new("app").subcommand(Command::new("tail"));
+
+        let result = builder.dispatch_from(cmd, ["app", "tail", "--output", "text"]);
+        assert_eq!(result.output(), Some("1\n2"));
+    }
+
     #[test]
     fn test_dispatch_binary_output_with_hook() {
         let builder = AppBuilder::new()
@@ -1640,6 +2794,275 @@ mod tests {
         assert_eq!(content, "Count: 42");
     }
 
+    #[test]
+    fn test_dispatch_render_to_stderr() {
+        use serde_json::json;
+        let builder = AppBuilder::new()
+            .command(
+                "sync",
+                |_m, _ctx| {
+                    Ok(HandlerOutput::RenderTo(
+                        Destination::Stderr,
+                        json!({"synced": 3}),
+                    ))
+                },
+                "Synced {{ synced }} items",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("sync"));
+
+        let result = builder.dispatch_from(cmd, ["app", "--output", "text", "sync"]);
+
+        assert_eq!(result.stderr(), Some("Synced 3 items"));
+        assert_eq!(result.output(), None);
+        assert_eq!(result.exit_code(), 0);
+    }
+
+    #[test]
+    fn test_dispatch_render_to_stderr_ignores_output_file() {
+        use serde_json::json;
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("output.txt");
+        let path_str = file_path.to_str().unwrap();
+
+        let builder = AppBuilder::new()
+            .command(
+                "sync",
+                |_m, _ctx| {
+                    Ok(HandlerOutput::RenderTo(
+                        Destination::Stderr,
+                        json!({"synced": 3}),
+                    ))
+                },
+                "Synced {{ synced }} items",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("sync"));
+
+        let result = builder.dispatch_from(
+            cmd,
+            [
+                "app",
+                "--output",
+                "text",
+                "--output-file-path",
+                path_str,
+                "sync",
+            ],
+        );
+
+        assert_eq!(result.stderr(), Some("Synced 3 items"));
+        assert!(!file_path.exists());
+    }
+
+    #[test]
+    fn test_dispatch_binary_output_file_is_byte_exact() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("chart.png");
+        let path_str = file_path.to_str().unwrap();
+        // PNG signature: not valid UTF-8
+        let png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0xFF];
+
+        let data = png.clone();
+        let builder = AppBuilder::new()
+            .command(
+                "export",
+                move |_m, _ctx| -> HandlerResult<()> {
+                    Ok(HandlerOutput::Binary {
+                        data: data.clone(),
+                        filename: "chart.png".into(),
+                    })
+                },
+                "",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("export"));
+
+        // Without a file, the bytes come back untouched
+        let result = builder.dispatch_from(cmd.clone(), ["app", "export"]);
+        assert_eq!(result.binary(), Some((png.as_slice(), "chart.png")));
+        assert_eq!(result.mime_type(), Some("image/png"));
+
+        let result = builder.dispatch_from(cmd, ["app", "--output-file-path", path_str, "export"]);
+        assert!(result.is_handled());
+        assert_eq!(std::fs::read(&file_path).unwrap(), png);
+    }
+
+    #[test]
+    fn test_dispatch_with_output_file_tee() {
+        use serde_json::json;
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("output.txt");
+        let path_str = file_path.to_str().unwrap();
+
+        let builder = AppBuilder::new()
+            .output_file_tee(true)
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 42}))),
+                "Count: {{ count }}",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+
+        let result = builder.dispatch_from(
+            cmd,
+            [
+                "app",
+                "--output",
+                "text",
+                "--output-file-path",
+                path_str,
+                "list",
+            ],
+        );
+
+        // Output still reaches the terminal...
+        assert_eq!(result.output(), Some("Count: 42"));
+        // ...and is written to the file
+        let content = std::fs::read_to_string(file_path).unwrap();
+        assert_eq!(content, "Count: 42");
+    }
+
+    #[test]
+    fn test_dispatch_with_output_file_append_flag() {
+        use serde_json::json;
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("report.log");
+        let path_str = file_path.to_str().unwrap();
+
+        let builder = AppBuilder::new()
+            .output_file_append_flag(None)
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 42}))),
+                "Count: {{ count }}",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+        let args = |append: bool| {
+            let mut args = vec!["app", "--output", "text", "--output-file-path", path_str];
+            if append {
+                args.push("--append-output");
+            }
+            args.push("list");
+            args
+        };
+
+        builder.dispatch_from(cmd.clone(), args(true));
+        builder.dispatch_from(cmd.clone(), args(true));
+        assert_eq!(
+            std::fs::read_to_string(&file_path).unwrap(),
+            "Count: 42\nCount: 42\n"
+        );
+
+        // Without the flag the file is overwritten
+        builder.dispatch_from(cmd, args(false));
+        assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "Count: 42");
+    }
+
+    #[test]
+    fn test_dispatch_with_output_file_options() {
+        use serde_json::json;
+        let temp_dir = tempfile::tempdir().unwrap();
+        let file_path = temp_dir.path().join("reports/daily/output.txt");
+        let path_str = file_path.to_str().unwrap();
+
+        let builder = AppBuilder::new()
+            .output_file_options(
+                crate::FileWriteOptions::new()
+                    .atomic(true)
+                    .create_dirs(true),
+            )
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 42}))),
+                "Count: {{ count }}",
+            )
+            .unwrap();
+
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+
+        let result = builder.dispatch_from(
+            cmd,
+            [
+                "app",
+                "--output",
+                "text",
+                "--output-file-path",
+                path_str,
+                "list",
+            ],
+        );
+
+        assert!(result.is_handled());
+        assert_eq!(std::fs::read_to_string(&file_path).unwrap(), "Count: 42");
+    }
+
+    fn query_app() -> AppBuilder {
+        use serde_json::json;
+        AppBuilder::new()
+            .query_flag(None)
+            .command(
+                "list",
+                |_m, _ctx| {
+                    Ok(HandlerOutput::Render(
+                        json!({"items": [{"name": "a"}, {"name": "b"}]}),
+                    ))
+                },
+                "{{ items | length }} items",
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_dispatch_query_projects_output() {
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+
+        // Templated modes switch to JSON
+        let result =
+            query_app().dispatch_from(cmd.clone(), ["app", "--query", "items[0].name", "list"]);
+        assert_eq!(result.output(), Some("\"a\""));
+
+        // Other structured modes are kept
+        let result = query_app().dispatch_from(
+            cmd,
+            ["app", "--output", "yaml", "--query", "items[].name", "list"],
+        );
+        assert_eq!(result.output().unwrap().trim(), "- a\n- b");
+    }
+
+    #[test]
+    fn test_dispatch_query_error() {
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+
+        let result = query_app().dispatch_from(cmd, ["app", "--query", "items.name", "list"]);
+
+        let parsed: serde_json::Value = serde_json::from_str(result.error().unwrap()).unwrap();
+        assert_eq!(
+            parsed["error"]["message"],
+            "Query error: cannot read field 'name' of an array"
+        );
+    }
+
+    #[test]
+    fn test_dispatch_query_hook() {
+        let cmd = Command::new("app").subcommand(Command::new("list"));
+        let builder = query_app().query_hook(|data, expr| {
+            Ok(serde_json::json!({"expr": expr, "count": data["items"].as_array().unwrap().len()}))
+        });
+
+        let result = builder.dispatch_from(cmd, ["app", "--query", "$.items", "list"]);
+
+        let parsed: serde_json::Value = serde_json::from_str(result.output().unwrap()).unwrap();
+        assert_eq!(parsed, serde_json::json!({"expr": "$.items", "count": 2}));
+    }
+
     #[test]
     fn test_dispatch_with_custom_output_file_flag() {
         use serde_json::json;
@@ -0,0 +1,128 @@
+//! The handler for invocations no registered command matches.
+
+use clap::ArgMatches;
+
+use super::{AppBuilder, PendingCommand};
+use crate::cli::group::PassthroughRecipe;
+use crate::cli::handler::CommandContext;
+
+/// Key the fallback is stored under among the registered commands. It can't
+/// collide with a command path, since `*` isn't a valid command name.
+pub(super) const FALLBACK_PATH: &str = "*";
+
+impl AppBuilder {
+    /// Sets the handler run when no registered command matches.
+    ///
+    /// Without a fallback, dispatch returns `RunResult::NoMatch` for such
+    /// invocations. The fallback instead receives the top-level matches, so
+    /// it can inspect whatever subcommand was given, and manages its own
+    /// output like a [passthrough command](Self::command_passthrough). It
+    /// runs with the app's middleware, but not with hooks, validators or
+    /// settings registered for specific command paths.
+    ///
+    /// For names the clap command doesn't define to reach the fallback, the
+    /// command must allow them with
+    /// [`allow_external_subcommands`](clap::Command::allow_external_subcommands);
+    /// otherwise clap rejects them before dispatch.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Forward commands not yet ported to the old binary
+    /// App::builder()
+    ///     .command("list", list, "{{ items }}")?
+    ///     .fallback(|matches, _ctx| {
+    ///         let Some((name, sub)) = matches.subcommand() else {
+    ///             return Ok(());
+    ///         };
+    ///         let args = sub.get_many::<OsString>("").into_iter().flatten();
+    ///         let status = Process::new("myapp-legacy").arg(name).args(args).status()?;
+    ///         match status.code() {
+    ///             Some(0) => Ok(()),
+    ///             code => Err(ExitError::new(code.unwrap_or(1) as u8, anyhow!("legacy command failed")).into()),
+    ///         }
+    ///     })
+    ///     .run(cmd.allow_external_subcommands(true), std::env::args());
+    /// ```
+    pub fn fallback<F>(self, handler: F) -> Self
+    where
+        F: FnMut(&ArgMatches, &CommandContext) -> Result<(), anyhow::Error> + Send + 'static,
+    {
+        self.pending_commands.lock().unwrap().insert(
+            FALLBACK_PATH.to_string(),
+            PendingCommand {
+                recipe: Box::new(PassthroughRecipe::new(handler)),
+                template: String::new(),
+            },
+        );
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::handler::{Output, RunResult};
+    use clap::Command;
+    use std::ffi::OsString;
+    use std::sync::{Arc, Mutex};
+
+    fn app(seen: Arc<Mutex<Vec<String>>>) -> AppBuilder {
+        AppBuilder::new()
+            .command(
+                "list",
+                |_m, _ctx| Ok(Output::Render(serde_json::json!({ "n": 1 }))),
+                "{{ n }} item",
+            )
+            .unwrap()
+            .fallback(move |matches, ctx| {
+                let mut seen = seen.lock().unwrap();
+                seen.push(ctx.command_path.join("."));
+                if let Some((name, sub)) = matches.subcommand() {
+                    seen.push(name.to_string());
+                    seen.extend(
+                        sub.get_many::<OsString>("")
+                            .into_iter()
+                            .flatten()
+                            .map(|arg| arg.to_string_lossy().into_owned()),
+                    );
+                }
+                Ok(())
+            })
+    }
+
+    fn command() -> Command {
+        Command::new("app")
+            .allow_external_subcommands(true)
+            .subcommand(Command::new("list"))
+    }
+
+    #[test]
+    fn test_fallback_receives_unmatched_commands() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let builder = app(seen.clone());
+
+        let result = builder.dispatch_from(command(), ["app", "sync", "--all", "notes"]);
+
+        assert!(result.is_handled(), "{result:?}");
+        assert_eq!(*seen.lock().unwrap(), ["sync", "sync", "--all", "notes"]);
+    }
+
+    #[test]
+    fn test_registered_commands_bypass_fallback() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let builder = app(seen.clone());
+
+        let result = builder.dispatch_from(command(), ["app", "list", "--output", "text"]);
+
+        assert_eq!(result.output(), Some("1 item"));
+        assert!(seen.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_no_match_without_fallback() {
+        let builder = AppBuilder::new();
+        let result = builder.dispatch_from(command(), ["app", "sync"]);
+        assert!(matches!(result, RunResult::NoMatch(_)));
+    }
+}
@@ -0,0 +1,184 @@
+//! Graceful Ctrl-C handling.
+//!
+//! Apps opt in with `on_interrupt()`. The first Ctrl-C then doesn't kill the
+//! process: it cancels the running command's [`CancellationToken`]
+//! (`ctx.cancellation`), so a long-running handler can stop early and return
+//! what it has. That partial output is rendered and post-output hooks run as
+//! usual, then the cleanup callback restores terminal state and `run()` exits
+//! with 130. A second Ctrl-C runs the cleanup and exits right away, for
+//! handlers that never check the token.
+//!
+//! ```rust,ignore
+//! fn sync(_m: &ArgMatches, ctx: &CommandContext) -> HandlerResult<Vec<Item>> {
+//!     let mut done = Vec::new();
+//!     for item in pending()? {
+//!         if ctx.is_cancelled() {
+//!             break;
+//!         }
+//!         done.push(upload(item)?);
+//!     }
+//!     Ok(Output::Render(done))
+//! }
+//! ```
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+
+use super::AppBuilder;
+use crate::cli::handler::CancellationToken;
+
+/// Exit code for a command stopped by Ctrl-C (128 + SIGINT).
+pub(crate) const INTERRUPTED_EXIT_CODE: i32 = 130;
+
+/// Cleanup callback run after an interrupted command.
+pub(crate) type InterruptFn = Arc<dyn Fn() + Send + Sync>;
+
+/// The command currently running under interrupt handling.
+struct Active {
+    token: CancellationToken,
+    cleanup: InterruptFn,
+}
+
+static ACTIVE: Mutex<Option<Active>> = Mutex::new(None);
+
+/// Whether the process-wide signal handler was installed successfully.
+static INSTALLED: OnceLock<bool> = OnceLock::new();
+
+/// Set when a command finished after being interrupted; read by `run()`.
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+impl AppBuilder {
+    /// Cancels the running command on Ctrl-C instead of killing the process.
+    ///
+    /// Handlers see the interrupt through `ctx.cancellation` (or
+    /// `ctx.is_cancelled()`) and should return early with their partial
+    /// output, which is rendered and passed through post-output hooks like
+    /// any other. `cleanup` runs once the command has stopped, before its
+    /// output is printed: use it to restore terminal state (cursor, raw mode,
+    /// alternate screen) or remove temporary files. `run()` then exits with
+    /// code 130.
+    ///
+    /// A second Ctrl-C runs `cleanup` and exits immediately. Ctrl-C outside a
+    /// command (while parsing or printing) also exits with 130.
+    ///
+    /// The signal handler is installed for the whole process on first
+    /// dispatch. If another handler is already installed, interrupts are left
+    /// to it and the token is never cancelled.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .on_interrupt(|| {
+    ///         // Show the cursor again if a spinner hid it
+    ///         let _ = console::Term::stderr().show_cursor();
+    ///     })
+    ///     .build()?
+    ///     .run(cmd, std::env::args());
+    /// ```
+    pub fn on_interrupt<F>(mut self, cleanup: F) -> Self
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.interrupt_cleanup = Some(Arc::new(cleanup));
+        self
+    }
+
+    /// Routes Ctrl-C to `token` until the returned guard is dropped, or
+    /// returns `None` if interrupt handling isn't enabled.
+    ///
+    /// If the signal handler couldn't be installed, Ctrl-C isn't routed, but
+    /// the guard still runs the cleanup if the token gets cancelled.
+    pub(crate) fn watch_interrupts(&self, token: &CancellationToken) -> Option<InterruptGuard> {
+        let cleanup = self.interrupt_cleanup.clone()?;
+        if *INSTALLED.get_or_init(|| ctrlc::set_handler(interrupt).is_ok()) {
+            *ACTIVE.lock().unwrap_or_else(PoisonError::into_inner) = Some(Active {
+                token: token.clone(),
+                cleanup: cleanup.clone(),
+            });
+        }
+        Some(InterruptGuard {
+            token: token.clone(),
+            cleanup,
+        })
+    }
+}
+
+/// Stops routing Ctrl-C to a command when it finishes, running the cleanup
+/// callback if it was interrupted.
+pub(crate) struct InterruptGuard {
+    token: CancellationToken,
+    cleanup: InterruptFn,
+}
+
+impl Drop for InterruptGuard {
+    fn drop(&mut self) {
+        ACTIVE.lock().unwrap_or_else(PoisonError::into_inner).take();
+        if self.token.is_cancelled() {
+            (self.cleanup)();
+            INTERRUPTED.store(true, Ordering::SeqCst);
+        }
+    }
+}
+
+/// Responds to Ctrl-C, exiting if [`respond`] says so.
+pub(crate) fn interrupt() {
+    let active = ACTIVE.lock().unwrap_or_else(PoisonError::into_inner);
+    if respond(&active) {
+        std::process::exit(INTERRUPTED_EXIT_CODE);
+    }
+}
+
+/// Handles a Ctrl-C for the running command, if any, and returns whether the
+/// process should exit.
+///
+/// The first Ctrl-C cancels the command; the second runs its cleanup and
+/// exits, as does a Ctrl-C when no command is running.
+fn respond(active: &Option<Active>) -> bool {
+    match active {
+        Some(active) if !active.token.is_cancelled() => {
+            active.token.cancel();
+            false
+        }
+        Some(active) => {
+            (active.cleanup)();
+            true
+        }
+        None => true,
+    }
+}
+
+/// Returns true, once, if a command finished after being interrupted.
+pub(crate) fn take_interrupted() -> bool {
+    INTERRUPTED.swap(false, Ordering::SeqCst)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn test_respond_cancels_first_then_exits() {
+        let cleanups = Arc::new(AtomicUsize::new(0));
+        let counter = cleanups.clone();
+        let active = Some(Active {
+            token: CancellationToken::new(),
+            cleanup: Arc::new(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }),
+        });
+
+        assert!(!respond(&active));
+        assert!(active.as_ref().unwrap().token.is_cancelled());
+        assert_eq!(cleanups.load(Ordering::SeqCst), 0);
+
+        assert!(respond(&active));
+        assert_eq!(cleanups.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_respond_exits_without_running_command() {
+        assert!(respond(&None));
+    }
+}
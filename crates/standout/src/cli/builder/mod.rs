@@ -25,31 +25,56 @@
 //! - [`commands`]: Command and handler registration
 //! - [`execution`]: Dispatch macro integration and command execution
 //! - [`rendering`]: Template rendering and data serialization
+//! - [`schema`]: JSON Schema registration for command outputs
+//! - [`completions`]: Shell completion script generation
 
+mod batch;
 mod commands;
+mod completions;
 mod config;
+mod config_files;
+mod crash;
+mod deprecation;
+mod docs;
 mod execution;
+mod fallback;
+mod interrupt;
+mod mount;
+mod paths;
+mod plugins;
+mod prompts;
 mod rendering;
+mod repl;
+mod retry;
+mod schema;
+mod testing;
+mod timeout;
+mod timings;
+mod validation;
+mod verbosity;
+mod version;
+mod watch;
 
 use crate::context::ContextRegistry;
+use crate::i18n::{self, Catalog, Catalogs};
 use crate::setup::SetupError;
 use crate::topics::{
-    display_with_pager, render_topic, render_topics_list, TopicRegistry, TopicRenderConfig,
+    display_with_pager, render_topic, render_topics_list, should_auto_page, TopicRegistry,
+    TopicRenderConfig,
 };
 use crate::TemplateRegistry;
-use crate::{render_auto, OutputMode, Theme};
+use crate::{render_auto, CsvOptions, OutputMode, Theme};
 use clap::{Arg, ArgAction, ArgMatches, Command};
 use serde::Serialize;
-use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::path::PathBuf;
-use std::rc::Rc;
+use std::sync::{Arc, Mutex, OnceLock};
 
 use super::dispatch::DispatchFn;
 use super::group::CommandRecipe;
 use super::handler::{CommandContext, Extensions, HandlerResult, Output as HandlerOutput};
 use super::help::{render_help, render_help_with_topics, CommandGroup, HelpConfig};
-use super::hooks::{HookError, Hooks, RenderedOutput, TextOutput};
+use super::hooks::{HookError, Hooks, RenderRequest, RenderedOutput, TextOutput};
 use super::result::HelpResult;
 use standout_dispatch::verify::ExpectedArg;
 
@@ -92,31 +117,55 @@ struct PendingCommand {
 ///     .context("app_version", Value::from("1.0.0"))
 ///
 ///     // Dynamic context (computed at render time)
-///     .context_fn("terminal", |ctx: &RenderContext| {
+///     .context_fn("layout", |ctx: &RenderContext| {
 ///         Value::from_iter([
-///             ("width", Value::from(ctx.terminal_width.unwrap_or(80))),
+///             ("columns", Value::from(ctx.terminal_width.unwrap_or(80) / 40)),
 ///             ("is_tty", Value::from(ctx.output_mode == standout::OutputMode::Term)),
 ///         ])
 ///     })
-///     .command("list", handler, "Width: {{ terminal.width }}")
+///     .command("list", handler, "Columns: {{ layout.columns }}")
 ///     .build()?
 ///     .run(cmd, args);
 /// ```
+///
+/// Templates also get the terminal's [`Capabilities`](crate::Capabilities)
+/// as `terminal` (`{{ terminal.width }}`, `{% if terminal.unicode %}`), unless
+/// a context or data field of that name replaces it.
 pub struct AppBuilder {
     pub(crate) registry: TopicRegistry,
     pub(crate) output_flag: Option<String>,
+    /// Environment variable supplying the default output mode
+    pub(crate) output_env: Option<String>,
     pub(crate) output_file_flag: Option<String>,
+    /// Whether the output file flag tees to the terminal instead of redirecting
+    pub(crate) output_file_tee: bool,
+    /// Append, atomic-write and directory-creation settings for the output file
+    pub(crate) output_file_options: crate::FileWriteOptions,
+    /// Name of the opt-in flag that switches the output file to append mode
+    pub(crate) output_file_append_flag: Option<String>,
     pub(crate) theme: Option<Theme>,
     /// Stylesheet registry (built from embedded styles)
     pub(crate) stylesheet_registry: Option<crate::StylesheetRegistry>,
     /// Template registry (built from embedded templates)
-    pub(crate) template_registry: Option<Rc<TemplateRegistry>>,
+    pub(crate) template_registry: Option<Arc<TemplateRegistry>>,
     pub(crate) default_theme_name: Option<String>,
     /// Pending commands - closures are created lazily at dispatch time
-    pending_commands: RefCell<HashMap<String, PendingCommand>>,
+    pending_commands: Mutex<HashMap<String, PendingCommand>>,
     /// Finalized dispatch functions (lazily created from pending_commands)
-    finalized_commands: RefCell<Option<HashMap<String, DispatchFn>>>,
+    finalized_commands: OnceLock<HashMap<String, DispatchFn>>,
     pub(crate) command_hooks: HashMap<String, Hooks>,
+    /// App-wide hooks wrapped around every command, outermost first.
+    pub(crate) middleware: Vec<Hooks>,
+    /// Command aliases, keyed by the registered command path.
+    pub(crate) command_aliases: BTreeMap<String, Vec<String>>,
+    /// Commands hidden from help, completions and docs, by path.
+    pub(crate) hidden_commands: BTreeSet<String>,
+    /// Per-command default output modes, keyed by the registered command path.
+    pub(crate) command_output_modes: HashMap<String, OutputMode>,
+    /// Deprecated commands and aliases, keyed by path, with their notes.
+    pub(crate) deprecated_commands: BTreeMap<String, String>,
+    /// Deprecated arguments, keyed by command path and argument id.
+    pub(crate) deprecated_args: BTreeMap<(String, String), String>,
     pub(crate) context_registry: ContextRegistry,
     pub(crate) template_dir: Option<PathBuf>,
     pub(crate) template_ext: String,
@@ -128,24 +177,135 @@ pub struct AppBuilder {
     pub(crate) include_framework_styles: bool,
     /// App-level state shared across all dispatches.
     ///
-    /// Stored as `Rc<Extensions>` so it can be cloned cheaply into CommandContext.
-    /// During builder phase, `Rc::get_mut` is used since only the builder holds the Rc.
-    pub(crate) app_state: Rc<Extensions>,
+    /// Stored as `Arc<Extensions>` so it can be cloned cheaply into CommandContext.
+    /// During builder phase, `Arc::get_mut` is used since only the builder holds the Arc.
+    pub(crate) app_state: Arc<Extensions>,
 
     /// Optional template engine.
     ///
     /// If not provided, a default MiniJinja engine will be created.
-    pub(crate) template_engine: Rc<Box<dyn standout_render::template::TemplateEngine>>,
+    pub(crate) template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
 
     /// Command groups for organized help display.
     pub(crate) help_command_groups: Option<Vec<CommandGroup>>,
 
+    /// Help templates for individual subcommands, keyed by dot-separated path.
+    pub(crate) help_templates: HashMap<String, String>,
+
     /// Whether standout intercepts and renders help (default: false).
     ///
     /// When true, standout disables clap's built-in help and renders its own
     /// themed, grouped help for all invocation forms (`help`, `--help`, `-h`).
     /// Required when using `command_groups` or topics.
     pub(crate) help_handling: bool,
+
+    /// Name of the opt-in flag that keeps help from being paged.
+    pub(crate) no_pager_flag: Option<&'static str>,
+
+    /// Whether `--output=json` is syntax-highlighted on color terminals (default: false).
+    pub(crate) colored_json: bool,
+
+    /// Delimiter, quoting and header settings for `--output=csv`.
+    pub(crate) csv_options: CsvOptions,
+
+    /// JSON Schemas of command outputs, keyed by dot-separated command path.
+    pub(crate) output_schemas: BTreeMap<String, serde_json::Value>,
+
+    /// Name of the opt-in `--query` flag that projects structured output.
+    pub(crate) query_flag: Option<String>,
+
+    /// Custom evaluator for `--query` (default: [`crate::cli::query::select`]).
+    pub(crate) query_hook: Option<crate::cli::query::QueryFn>,
+
+    /// Name of the opt-in subcommand that prints shell completion scripts.
+    pub(crate) completions_command: Option<&'static str>,
+
+    /// Message catalogs for localized help and templates.
+    pub(crate) catalogs: Option<Catalogs>,
+
+    /// Name of the opt-in `--lang` flag that overrides the detected locale.
+    pub(crate) locale_flag: Option<String>,
+
+    /// Build metadata rendered by `--version`.
+    pub(crate) build_info: Option<crate::cli::BuildInfo>,
+
+    /// Custom template for `--version` output.
+    pub(crate) version_template: Option<String>,
+
+    /// Cleanup run after a command is interrupted; enables Ctrl-C handling.
+    pub(crate) interrupt_cleanup: Option<interrupt::InterruptFn>,
+
+    /// Where crash reports ask users to file bugs.
+    pub(crate) bug_report_url: Option<String>,
+
+    /// Custom template for crash reports.
+    pub(crate) crash_template: Option<String>,
+
+    /// Whether the `-v` / `-q` verbosity flags are added.
+    pub(crate) verbosity_flags: bool,
+
+    /// Whether `tracing` events are logged to stderr.
+    #[cfg(feature = "tracing")]
+    pub(crate) log_to_stderr: bool,
+
+    /// Whether the `--timings` flag is added.
+    pub(crate) timings_flag: bool,
+
+    /// Whether the `--yes` / `--no-input` flags are added.
+    pub(crate) prompt_flags: bool,
+
+    /// Paths `--watch` re-runs the command on; enables the flag.
+    pub(crate) watch_paths: Vec<PathBuf>,
+
+    /// How often watched paths are polled for changes.
+    pub(crate) watch_interval: std::time::Duration,
+
+    /// Subcommands contributed by plugins, added at dispatch time.
+    pub(crate) plugin_commands: Vec<clap::Command>,
+
+    /// Loads the configuration handlers read with `ctx.config()`.
+    pub(crate) config_loader: Option<crate::cli::config::ConfigLoader>,
+
+    /// Themes of mounted apps, layered under the app's theme at build.
+    pub(crate) mounted_themes: Vec<Theme>,
+
+    /// Validators run before each command's handler, keyed by command path.
+    pub(crate) validators: HashMap<String, Vec<validation::ValidateFn>>,
+
+    /// Custom template for validation errors.
+    pub(crate) validation_template: Option<String>,
+
+    /// Retry policies for flaky handlers, keyed by command path.
+    pub(crate) retry_policies: HashMap<String, crate::cli::RetryPolicy>,
+
+    /// Time limits for handlers, keyed by command path.
+    pub(crate) command_timeouts: HashMap<String, std::time::Duration>,
+}
+
+/// How rendered help is displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HelpPaging {
+    /// Page when the help doesn't fit the terminal (see [`should_auto_page`]).
+    Auto,
+    /// Always page (`help --page`).
+    Always,
+    /// Never page (`--no-pager`).
+    Never,
+}
+
+impl HelpPaging {
+    fn result(self, help: String) -> HelpResult {
+        let page = match self {
+            HelpPaging::Auto => should_auto_page(&help),
+            HelpPaging::Always => true,
+            HelpPaging::Never => false,
+        };
+        if page {
+            HelpResult::PagedHelp(help)
+        } else {
+            HelpResult::Help(help)
+        }
+    }
 }
 
 impl Default for AppBuilder {
@@ -154,6 +314,15 @@ impl Default for AppBuilder {
     }
 }
 
+/// Context every app starts with: the terminal's capabilities as `terminal`.
+fn default_context_registry() -> ContextRegistry {
+    let mut registry = ContextRegistry::new();
+    registry.add_provider("terminal", |_: &crate::context::RenderContext| {
+        minijinja::Value::from_serialize(crate::Capabilities::detect())
+    });
+    registry
+}
+
 impl AppBuilder {
     /// Creates a new App with default settings.
     ///
@@ -163,24 +332,63 @@ impl AppBuilder {
         Self {
             registry: TopicRegistry::new(),
             output_flag: Some("output".to_string()), // Enabled by default
+            output_env: None,
             output_file_flag: Some("output-file-path".to_string()),
+            output_file_tee: false,
+            output_file_options: crate::FileWriteOptions::default(),
+            output_file_append_flag: None,
             theme: None,
             stylesheet_registry: None,
             template_registry: None,
             default_theme_name: None,
-            pending_commands: RefCell::new(HashMap::new()),
-            finalized_commands: RefCell::new(None),
+            pending_commands: Mutex::new(HashMap::new()),
+            finalized_commands: OnceLock::new(),
             command_hooks: HashMap::new(),
-            context_registry: ContextRegistry::new(),
+            middleware: Vec::new(),
+            command_aliases: BTreeMap::new(),
+            hidden_commands: BTreeSet::new(),
+            command_output_modes: HashMap::new(),
+            deprecated_commands: BTreeMap::new(),
+            deprecated_args: BTreeMap::new(),
+            context_registry: default_context_registry(),
             template_dir: None,
             template_ext: ".j2".to_string(),
             default_command: None,
             include_framework_templates: true,
             include_framework_styles: true,
-            app_state: Rc::new(Extensions::new()),
-            template_engine: Rc::new(Box::new(standout_render::template::MiniJinjaEngine::new())),
+            app_state: Arc::new(Extensions::new()),
+            template_engine: Arc::new(Box::new(standout_render::template::MiniJinjaEngine::new())),
             help_command_groups: None,
+            help_templates: HashMap::new(),
             help_handling: false,
+            no_pager_flag: None,
+            colored_json: false,
+            csv_options: CsvOptions::default(),
+            output_schemas: BTreeMap::new(),
+            query_flag: None,
+            query_hook: None,
+            completions_command: None,
+            catalogs: None,
+            locale_flag: None,
+            build_info: None,
+            version_template: None,
+            interrupt_cleanup: None,
+            bug_report_url: None,
+            crash_template: None,
+            verbosity_flags: false,
+            #[cfg(feature = "tracing")]
+            log_to_stderr: false,
+            timings_flag: false,
+            prompt_flags: false,
+            watch_paths: Vec::new(),
+            watch_interval: watch::DEFAULT_WATCH_INTERVAL,
+            plugin_commands: Vec::new(),
+            config_loader: None,
+            mounted_themes: Vec::new(),
+            validators: HashMap::new(),
+            validation_template: None,
+            retry_policies: HashMap::new(),
+            command_timeouts: HashMap::new(),
         }
     }
 
@@ -191,7 +399,7 @@ impl AppBuilder {
 
     /// Adds app-level state that will be available to all handlers.
     ///
-    /// App state is immutable and shared across all dispatches via `Rc<Extensions>`.
+    /// App state is immutable and shared across all dispatches via `Arc<Extensions>`.
     /// Use for long-lived resources like database connections, configuration, and
     /// API clients.
     ///
@@ -248,10 +456,10 @@ impl AppBuilder {
     ///     .app_state(Config { debug: false })
     ///     .app_state(Config { debug: true })  // Replaces previous Config
     /// ```
-    pub fn app_state<T: 'static>(mut self, value: T) -> Self {
-        // During builder phase, only the builder holds the Rc, so get_mut succeeds.
-        Rc::get_mut(&mut self.app_state)
-            .expect("app_state Rc should be exclusively owned during builder phase")
+    pub fn app_state<T: Send + Sync + 'static>(mut self, value: T) -> Self {
+        // During builder phase, only the builder holds the Arc, so get_mut succeeds.
+        Arc::get_mut(&mut self.app_state)
+            .expect("app_state Arc should be exclusively owned during builder phase")
             .insert(value);
         self
     }
@@ -263,10 +471,36 @@ impl AppBuilder {
         mut self,
         engine: Box<dyn standout_render::template::TemplateEngine>,
     ) -> Self {
-        self.template_engine = Rc::new(engine);
+        self.template_engine = Arc::new(engine);
         self
     }
 
+    /// Resolves the theme: an explicit `.theme()` takes precedence, then
+    /// `.default_theme()` from the stylesheet registry, then its `default`,
+    /// `theme` or `base` stylesheet.
+    fn resolve_theme(&mut self) -> Result<(), SetupError> {
+        if self.theme.is_none() {
+            if let Some(ref mut registry) = self.stylesheet_registry {
+                let resolved = if let Some(name) = &self.default_theme_name {
+                    Some(
+                        registry
+                            .get(name)
+                            .map_err(|_| SetupError::ThemeNotFound(name.to_string()))?,
+                    )
+                } else {
+                    // Try defaults in order: default, theme, base
+                    registry
+                        .get("default")
+                        .or_else(|_| registry.get("theme"))
+                        .or_else(|_| registry.get("base"))
+                        .ok()
+                };
+                self.theme = resolved;
+            }
+        }
+        Ok(())
+    }
+
     /// Ensures all pending commands have been finalized into dispatch functions.
     ///
     /// This method is called lazily on first dispatch. It creates the actual
@@ -274,40 +508,32 @@ impl AppBuilder {
     /// it is passed at runtime via late binding, which allows `.theme()` to be
     /// called in any order relative to `.command()`.
     fn ensure_commands_finalized(&self) {
-        // Already finalized?
-        if self.finalized_commands.borrow().is_some() {
-            return;
-        }
-
-        let context_registry = &self.context_registry;
-
-        // Build dispatch functions from recipes
-        let mut commands = HashMap::new();
-        for (path, pending) in self.pending_commands.borrow().iter() {
-            let dispatch = pending.recipe.create_dispatch(
-                &pending.template,
-                context_registry,
-                self.template_engine.clone(),
-            );
-            commands.insert(path.clone(), dispatch);
-        }
-
-        *self.finalized_commands.borrow_mut() = Some(commands);
+        self.get_commands();
     }
 
     /// Returns the finalized commands map, creating it if necessary.
-    fn get_commands(&self) -> std::cell::Ref<'_, HashMap<String, DispatchFn>> {
-        self.ensure_commands_finalized();
-        std::cell::Ref::map(self.finalized_commands.borrow(), |opt| {
-            opt.as_ref()
-                .expect("finalized_commands should be Some after ensure_commands_finalized")
+    fn get_commands(&self) -> &HashMap<String, DispatchFn> {
+        self.finalized_commands.get_or_init(|| {
+            let context_registry = &self.context_registry;
+
+            // Build dispatch functions from recipes
+            let mut commands = HashMap::new();
+            for (path, pending) in self.pending_commands.lock().unwrap().iter() {
+                let dispatch = pending.recipe.create_dispatch(
+                    &pending.template,
+                    context_registry,
+                    self.template_engine.clone(),
+                );
+                commands.insert(path.clone(), dispatch);
+            }
+            commands
         })
     }
 
     /// Test helper: Check if a command path is registered.
     #[cfg(test)]
     pub(crate) fn has_command(&self, path: &str) -> bool {
-        self.pending_commands.borrow().contains_key(path)
+        self.pending_commands.lock().unwrap().contains_key(path)
     }
 
     /// Finalizes the App, resolving themes, loading templates, and preparing
@@ -317,7 +543,7 @@ impl AppBuilder {
     ///
     /// Returns an error if:
     /// - A `default_theme()` was specified but the theme wasn't found in the stylesheet registry
-    /// - `command_groups` or topics are configured without `.help_handling(true)`
+    /// - `command_groups`, topics or `help_template`s are configured without `.help_handling(true)`
     ///
     /// # Example
     ///
@@ -335,7 +561,7 @@ impl AppBuilder {
             match self.template_registry.as_mut() {
                 Some(arc) => {
                     // Get mutable access to the registry
-                    if let Some(registry) = Rc::get_mut(arc) {
+                    if let Some(registry) = Arc::get_mut(arc) {
                         registry.add_framework_entries(FRAMEWORK_TEMPLATES);
                     } else {
                         // Shouldn't happen during build before finalization
@@ -346,15 +572,15 @@ impl AppBuilder {
                     // Create new registry with just framework templates
                     let mut registry = TemplateRegistry::new();
                     registry.add_framework_entries(FRAMEWORK_TEMPLATES);
-                    self.template_registry = Some(Rc::new(registry));
+                    self.template_registry = Some(Arc::new(registry));
                 }
             };
         }
 
         // Populate engine with templates from registry
-        // We use Rc::get_mut to mutate the engine in-place before sharing it
+        // We use Arc::get_mut to mutate the engine in-place before sharing it
         if let Some(registry) = &self.template_registry {
-            if let Some(engine_box) = Rc::get_mut(&mut self.template_engine) {
+            if let Some(engine_box) = Arc::get_mut(&mut self.template_engine) {
                 for name in registry.names() {
                     if let Ok(content) = registry.get_content(name) {
                         let _ = engine_box.add_template(name, &content);
@@ -364,25 +590,12 @@ impl AppBuilder {
         }
 
         // Resolve theme BEFORE finalization
-        // Theme resolution: explicit .theme() takes precedence, then .default_theme() from stylesheet registry
-        if self.theme.is_none() {
-            if let Some(ref mut registry) = self.stylesheet_registry {
-                let resolved = if let Some(name) = &self.default_theme_name {
-                    Some(
-                        registry
-                            .get(name)
-                            .map_err(|_| SetupError::ThemeNotFound(name.to_string()))?,
-                    )
-                } else {
-                    // Try defaults in order: default, theme, base
-                    registry
-                        .get("default")
-                        .or_else(|_| registry.get("theme"))
-                        .or_else(|_| registry.get("base"))
-                        .ok()
-                };
-                self.theme = resolved;
-            }
+        self.resolve_theme()?;
+        if let Some(mounted) = self.mounted_themes.drain(..).reduce(Theme::merge) {
+            self.theme = Some(match self.theme.take() {
+                Some(theme) => mounted.merge(theme),
+                None => mounted,
+            });
         }
 
         // Validate help configuration: features that require help interception
@@ -390,11 +603,14 @@ impl AppBuilder {
         if !self.help_handling {
             let has_groups = self.help_command_groups.is_some();
             let has_topics = !self.registry.list_topics().is_empty();
-            if has_groups || has_topics {
+            let has_templates = !self.help_templates.is_empty();
+            if has_groups || has_topics || has_templates {
                 let feature = if has_groups {
                     "command_groups"
-                } else {
+                } else if has_topics {
                     "topics"
+                } else {
+                    "help_template"
                 };
                 return Err(SetupError::Config(format!(
                     "{feature} requires .help_handling(true) — \
@@ -444,6 +660,22 @@ impl AppBuilder {
         self.command_hooks.get(path)
     }
 
+    /// Returns the hooks that run for a command: its own hooks wrapped by
+    /// every middleware, or `None` if there are neither.
+    pub(crate) fn hooks_for(&self, path: &str) -> Option<Hooks> {
+        let hooks = self.command_hooks.get(path);
+        if self.middleware.is_empty() {
+            return hooks.cloned();
+        }
+        let inner = hooks.cloned().unwrap_or_default();
+        Some(
+            self.middleware
+                .iter()
+                .rev()
+                .fold(inner, |hooks, middleware| hooks.wrapped_by(middleware)),
+        )
+    }
+
     /// Returns the default theme, if configured.
     pub fn get_default_theme(&self) -> Option<&Theme> {
         self.theme.as_ref()
@@ -542,6 +774,11 @@ impl AppBuilder {
         let matches = match cmd.clone().try_get_matches_from(&args) {
             Ok(m) => m,
             Err(e) => {
+                if e.kind() == clap::error::ErrorKind::DisplayVersion {
+                    if let Some(version) = self.render_version_for_args(&args) {
+                        return HelpResult::Help(version);
+                    }
+                }
                 if self.help_handling && e.kind() == clap::error::ErrorKind::DisplayHelp {
                     // Clap's native --help/-h short-circuited parsing.
                     // Render standout help for the appropriate command.
@@ -551,38 +788,53 @@ impl AppBuilder {
             }
         };
 
+        // The completions command prints its script like help output
+        if let Some(sub_matches) = self.completions_matches(&matches) {
+            return HelpResult::Help(Self::completion_script(&mut cmd, sub_matches));
+        }
+
         if !self.help_handling {
             return HelpResult::Matches(matches);
         }
 
         // Extract output mode
         let output_mode = self.extract_output_mode(&matches);
+        let locale = self.select_locale(&matches);
+        let registry = self.localized_topics(locale.as_deref());
 
         let config = HelpConfig {
             output_mode: Some(output_mode),
             theme: self.theme.clone(),
             command_groups: self.help_command_groups.clone(),
+            messages: self.help_messages(locale.as_deref()),
             ..Default::default()
         };
 
         // Check for the `help` subcommand (e.g. `myapp help`, `myapp help build`)
         if let Some((name, sub_matches)) = matches.subcommand() {
             if name == "help" {
-                let use_pager = sub_matches.get_flag("page");
+                let paging = if sub_matches.get_flag("page") {
+                    HelpPaging::Always
+                } else if self.no_pager_flag.is_some() && sub_matches.get_flag("_no_pager") {
+                    HelpPaging::Never
+                } else {
+                    HelpPaging::Auto
+                };
 
                 if let Some(topic_args) = sub_matches.get_many::<String>("topic") {
                     let keywords: Vec<_> = topic_args.map(|s| s.as_str()).collect();
                     if !keywords.is_empty() {
                         return self.handle_help_request(
                             &mut cmd,
+                            &registry,
                             &keywords,
-                            use_pager,
+                            paging,
                             Some(config),
                         );
                     }
                 }
                 // If "help" is called without args, return the root help with topics
-                return self.render_root_help(&cmd, Some(config), use_pager);
+                return self.render_root_help(&cmd, &registry, Some(config), paging);
             }
         }
 
@@ -593,17 +845,12 @@ impl AppBuilder {
     fn render_root_help(
         &self,
         cmd: &Command,
+        registry: &TopicRegistry,
         config: Option<HelpConfig>,
-        use_pager: bool,
+        paging: HelpPaging,
     ) -> HelpResult {
-        match render_help_with_topics(cmd, &self.registry, config) {
-            Ok(h) => {
-                if use_pager {
-                    HelpResult::PagedHelp(h)
-                } else {
-                    HelpResult::Help(h)
-                }
-            }
+        match render_help_with_topics(cmd, registry, config) {
+            Ok(h) => paging.result(h),
             Err(e) => {
                 let err = cmd.clone().error(
                     clap::error::ErrorKind::Io,
@@ -627,18 +874,69 @@ impl AppBuilder {
         // Stop at the first arg that isn't a known subcommand or is a flag.
         let subcommand_path = Self::extract_subcommand_path(cmd, args);
 
+        // Clap stops parsing at `--help`, so flags are read from the raw args
+        let output_mode = self
+            .output_flag
+            .as_deref()
+            .and_then(|flag| Self::flag_value_from_args(args, flag))
+            .and_then(|value| execution::parse_output_mode(&value));
+        let locale = self
+            .locale_flag
+            .as_deref()
+            .and_then(|flag| Self::flag_value_from_args(args, flag))
+            .and_then(|value| i18n::normalize_locale(&value))
+            .or_else(i18n::detect_locale);
+        let registry = self.localized_topics(locale.as_deref());
+
         let config = HelpConfig {
+            output_mode,
             theme: self.theme.clone(),
             command_groups: self.help_command_groups.clone(),
+            messages: self.help_messages(locale.as_deref()),
             ..Default::default()
         };
 
+        let no_pager = self.no_pager_flag.as_ref().is_some_and(|name| {
+            let flag = format!("--{}", name);
+            args.iter()
+                .skip(1)
+                .take_while(|arg| *arg != "--")
+                .any(|arg| *arg == *flag)
+        });
+        let paging = if no_pager {
+            HelpPaging::Never
+        } else {
+            HelpPaging::Auto
+        };
+
         if subcommand_path.is_empty() {
-            return self.render_root_help(cmd, Some(config), false);
+            return self.render_root_help(cmd, &registry, Some(config), paging);
         }
 
         let keywords: Vec<&str> = subcommand_path.iter().map(|s| s.as_str()).collect();
-        self.handle_help_request(cmd, &keywords, false, Some(config))
+        self.handle_help_request(cmd, &registry, &keywords, paging, Some(config))
+    }
+
+    /// Reads the value of `--<flag> VALUE` or `--<flag>=VALUE` from raw args.
+    fn flag_value_from_args(args: &[std::ffi::OsString], flag: &str) -> Option<String> {
+        let flag = format!("--{}", flag);
+        let mut args = args.iter().skip(1).map(|arg| arg.to_string_lossy());
+        while let Some(arg) = args.next() {
+            if arg == "--" {
+                break;
+            }
+            let value = if arg == flag {
+                args.next().map(|value| value.into_owned())
+            } else {
+                arg.strip_prefix(flag.as_str())
+                    .and_then(|rest| rest.strip_prefix('='))
+                    .map(str::to_string)
+            };
+            if value.is_some() {
+                return value;
+            }
+        }
+        None
     }
 
     /// Extracts the subcommand chain from raw args by matching against known subcommands.
@@ -675,8 +973,9 @@ impl AppBuilder {
     fn handle_help_request(
         &self,
         cmd: &mut Command,
+        registry: &TopicRegistry,
         keywords: &[&str],
-        use_pager: bool,
+        paging: HelpPaging,
         config: Option<HelpConfig>,
     ) -> HelpResult {
         let sub_name = keywords[0];
@@ -689,44 +988,37 @@ impl AppBuilder {
                 ..Default::default()
             };
             if let Ok(h) = render_topics_list(
-                &self.registry,
+                registry,
                 &format!("{} help", cmd.get_name()),
                 Some(topic_config),
             ) {
-                return if use_pager {
-                    HelpResult::PagedHelp(h)
-                } else {
-                    HelpResult::Help(h)
-                };
+                return paging.result(h);
             }
         }
 
         // 1. Check if it's a real command
         if super::app::find_subcommand(cmd, sub_name).is_some() {
             if let Some(target) = super::app::find_subcommand_recursive(cmd, keywords) {
-                if let Ok(h) = render_help(target, config.clone()) {
-                    return if use_pager {
-                        HelpResult::PagedHelp(h)
-                    } else {
-                        HelpResult::Help(h)
-                    };
+                let mut config = config.clone();
+                if let Some(template) = self.help_template_for(cmd, keywords) {
+                    config.get_or_insert_with(HelpConfig::default).template =
+                        Some(template.to_string());
+                }
+                if let Ok(h) = render_help(target, config) {
+                    return paging.result(h);
                 }
             }
         }
 
         // 2. Check if it is a topic
-        if let Some(topic) = self.registry.get_topic(sub_name) {
+        if let Some(topic) = registry.get_topic(sub_name) {
             let topic_config = TopicRenderConfig {
                 output_mode: config.as_ref().and_then(|c| c.output_mode),
                 theme: config.as_ref().and_then(|c| c.theme.clone()),
                 ..Default::default()
             };
             if let Ok(h) = render_topic(topic, Some(topic_config)) {
-                return if use_pager {
-                    HelpResult::PagedHelp(h)
-                } else {
-                    HelpResult::Help(h)
-                };
+                return paging.result(h);
             }
         }
 
@@ -738,6 +1030,20 @@ impl AppBuilder {
         HelpResult::Error(err)
     }
 
+    /// Returns the help template registered for the subcommand at `keywords`.
+    ///
+    /// Aliases are resolved to command names, so `help cfg get` finds the
+    /// template registered for `config.get`.
+    fn help_template_for(&self, cmd: &Command, keywords: &[&str]) -> Option<&str> {
+        let mut current = cmd;
+        let mut path = Vec::with_capacity(keywords.len());
+        for keyword in keywords {
+            current = super::app::find_subcommand(current, keyword)?;
+            path.push(current.get_name());
+        }
+        self.help_templates.get(&path.join(".")).map(String::as_str)
+    }
+
     /// Augments a command with help subcommand and output flags.
     ///
     /// When `help_handling` is enabled, this disables clap's built-in help
@@ -753,6 +1059,16 @@ impl AppBuilder {
             // Keep clap's native --help/-h flag — it short-circuits validation
             // so `myapp subcmd --help` works even with required args.
             // The resulting DisplayHelp error is intercepted in get_matches_from.
+            let cmd = match self.no_pager_flag {
+                Some(name) => cmd.arg(
+                    Arg::new("_no_pager")
+                        .long(name)
+                        .global(true)
+                        .action(ArgAction::SetTrue)
+                        .help("Never display help through a pager"),
+                ),
+                None => cmd,
+            };
             cmd.disable_help_subcommand(true).subcommand(
                 Command::new("help")
                     .about("Print this message or the help of the given subcommand(s)")
@@ -777,6 +1093,37 @@ impl AppBuilder {
         self.augment_command_for_dispatch(cmd)
     }
 
+    /// Selects the locale for a dispatch: the `--lang` flag if given,
+    /// otherwise the one detected from the environment.
+    pub(crate) fn select_locale(&self, matches: &ArgMatches) -> Option<String> {
+        self.locale_flag
+            .as_ref()
+            .and_then(|_| matches.try_get_one::<String>("_locale").ok().flatten())
+            .and_then(|value| i18n::normalize_locale(value))
+            .or_else(i18n::detect_locale)
+    }
+
+    /// Resolves the help messages for `locale`, if the app has catalogs.
+    fn help_messages(&self, locale: Option<&str>) -> Option<Catalog> {
+        self.catalogs
+            .as_ref()
+            .map(|catalogs| catalogs.resolve(locale))
+    }
+
+    /// Returns the topic registry translated for `locale`.
+    fn localized_topics(&self, locale: Option<&str>) -> TopicRegistry {
+        let Some(ref catalogs) = self.catalogs else {
+            let chain = locale.map(i18n::locale_chain).unwrap_or_default();
+            return self.registry.localized(&chain);
+        };
+        // Topic files named after a catalog locale translate their topic
+        let mut locales = catalogs.locales();
+        locales.push(catalogs.default_locale());
+        let mut registry = self.registry.clone();
+        registry.adopt_translations(&locales);
+        registry.localized(&catalogs.fallback_chain(locale))
+    }
+
     /// Extracts the output mode from parsed ArgMatches.
     pub fn extract_output_mode(&self, matches: &ArgMatches) -> OutputMode {
         if self.output_flag.is_some() {
@@ -790,6 +1137,7 @@ impl AppBuilder {
                 Some("json") => OutputMode::Json,
                 Some("yaml") => OutputMode::Yaml,
                 Some("xml") => OutputMode::Xml,
+                Some("toml") => OutputMode::Toml,
                 Some("csv") => OutputMode::Csv,
                 _ => OutputMode::Auto,
             }
@@ -810,9 +1158,13 @@ impl AppBuilder {
     /// The method:
     /// 1. Runs pre-dispatch hooks (if any)
     /// 2. Calls your handler closure
-    /// 3. Renders the result using the template
+    /// 3. Runs post-dispatch and pre-render hooks, then renders the result
     /// 4. Runs post-output hooks (if any)
     /// 5. Returns the final output
+    ///
+    /// `Output::RenderTo` is rendered like `Output::Render`; printing the
+    /// result to the right stream is up to the caller. The items of an
+    /// `Output::Stream` are rendered one per line into a single output.
     pub fn run_command<F, T>(
         &self,
         path: &str,
@@ -828,8 +1180,11 @@ impl AppBuilder {
             path.split('.').map(String::from).collect(),
             self.app_state.clone(),
         );
+        ctx.verbosity = self.verbosity_for(matches);
+        let _interrupts = self.watch_interrupts(&ctx.cancellation);
 
-        let hooks = self.command_hooks.get(path);
+        let hooks = self.hooks_for(path);
+        let hooks = hooks.as_ref();
 
         // Run pre-dispatch hooks
         if let Some(hooks) = hooks {
@@ -839,21 +1194,38 @@ impl AppBuilder {
         // Run handler
         let result = handler(matches, &ctx);
 
-        // Convert result to RenderedOutput
-        let output = match result {
-            Ok(HandlerOutput::Render(data)) => {
-                let mut json_data = serde_json::to_value(&data)
-                    .map_err(|e| HookError::post_dispatch("Serialization error").with_source(e))?;
+        let theme = self.theme.clone().unwrap_or_default();
+        let render = |data: &T| -> Result<String, HookError> {
+            let json_data = serde_json::to_value(data)
+                .map_err(|e| HookError::post_dispatch("Serialization error").with_source(e))?;
 
-                if let Some(hooks) = hooks {
-                    json_data = hooks.run_post_dispatch(matches, &ctx, json_data)?;
-                }
+            let mut request = RenderRequest {
+                template: template.to_string(),
+                data: json_data,
+            };
+            if let Some(hooks) = hooks {
+                request.data = hooks.run_post_dispatch(matches, &ctx, request.data)?;
+                request = hooks.run_pre_render(matches, &ctx, request)?;
+            }
+
+            render_auto(&request.template, &request.data, &theme, OutputMode::Auto)
+                .map_err(|e| HookError::post_output("Render error").with_source(e))
+        };
 
-                let theme = self.theme.clone().unwrap_or_default();
-                match render_auto(template, &json_data, &theme, OutputMode::Auto) {
-                    Ok(rendered) => RenderedOutput::Text(TextOutput::plain(rendered)),
-                    Err(e) => return Err(HookError::post_output("Render error").with_source(e)),
+        // Convert result to RenderedOutput
+        let output = match result {
+            Ok(HandlerOutput::Render(data) | HandlerOutput::RenderTo(_, data)) => {
+                RenderedOutput::Text(TextOutput::plain(render(&data)?))
+            }
+            Ok(HandlerOutput::Stream(items)) => {
+                let mut lines = Vec::new();
+                for item in items {
+                    lines.push(render(&item)?.trim_end_matches('\n').to_string());
+                    if ctx.is_cancelled() {
+                        break;
+                    }
                 }
+                RenderedOutput::Text(TextOutput::plain(lines.join("\n")))
             }
             Err(e) => {
                 return Err(HookError::post_output("Handler error").with_source(e));
@@ -881,7 +1253,8 @@ impl AppBuilder {
     pub fn verify_command(&self, cmd: &Command) -> Result<(), SetupError> {
         let expected_args: HashMap<String, Vec<ExpectedArg>> = self
             .pending_commands
-            .borrow()
+            .lock()
+            .unwrap()
             .iter()
             .map(|(path, cmd)| (path.clone(), cmd.recipe.expected_args()))
             .collect();
@@ -893,6 +1266,12 @@ impl AppBuilder {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_app_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<AppBuilder>();
+    }
+
     #[test]
     fn test_builder_output_flag_enabled_by_default() {
         let standout = AppBuilder::new().build().unwrap();
@@ -0,0 +1,234 @@
+//! Mounting one app's commands under another.
+//!
+//! Large CLIs are often assembled from commands developed in separate
+//! crates. Each crate exposes a builder with its own commands, templates,
+//! topics and styles, and the host app mounts it under a subcommand:
+//!
+//! ```text
+//! myapp db migrate    # `migrate` registered on the db crate's builder
+//! ```
+
+use std::sync::Arc;
+
+use clap::Command;
+
+use super::fallback::FALLBACK_PATH;
+use super::AppBuilder;
+use crate::setup::SetupError;
+use crate::TemplateRegistry;
+
+impl AppBuilder {
+    /// Merges `other`'s commands, templates, topics and styles into this app
+    /// under `prefix`.
+    ///
+    /// Command paths are prefixed (`migrate` becomes `db.migrate`), along
+    /// with their hooks, aliases, hidden flags, output modes, deprecations,
+    /// help templates, schemas, validators, retry policies and timeouts. Templates the app
+    /// doesn't define itself are added to its registry so mounted templates
+    /// can include their partials. `other`'s theme is layered under the
+    /// app's, so the app's styles win on conflicts. Plugin commands of
+    /// `other` are nested under `prefix`.
+    ///
+    /// App-wide settings of `other` (flags, middleware, default command,
+    /// fallback and the like) are not carried over. The app's clap command must define
+    /// the `prefix` subcommand with `other`'s commands, typically as a
+    /// variant wrapping the mounted crate's subcommand enum.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SetupError::DuplicateCommand`] if a prefixed path is already
+    /// registered, and [`SetupError::ThemeNotFound`] if `other` names a
+    /// default theme its stylesheets don't define.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a topic name or alias of `other` collides with one already
+    /// registered.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[derive(Subcommand)]
+    /// enum Commands {
+    ///     List,
+    ///     #[command(subcommand)]
+    ///     Db(db_cli::Commands),
+    /// }
+    ///
+    /// App::builder()
+    ///     .command("list", list, "{{ items }}")?
+    ///     .mount("db", db_cli::app())?
+    ///     .build()?;
+    /// ```
+    pub fn mount(mut self, prefix: &str, mut other: AppBuilder) -> Result<Self, SetupError> {
+        other.resolve_theme()?;
+        let prefixed = |path: &str| format!("{}.{}", prefix, path);
+
+        for (path, pending) in other.pending_commands.into_inner().unwrap() {
+            if path == FALLBACK_PATH {
+                continue;
+            }
+            let path = prefixed(&path);
+            if self.pending_commands.lock().unwrap().contains_key(&path) {
+                return Err(SetupError::DuplicateCommand(path));
+            }
+            self.pending_commands.lock().unwrap().insert(path, pending);
+        }
+
+        self.command_hooks
+            .extend(prefix_keys(other.command_hooks, prefixed));
+        self.command_aliases
+            .extend(prefix_keys(other.command_aliases, prefixed));
+        self.hidden_commands
+            .extend(other.hidden_commands.iter().map(|path| prefixed(path)));
+        self.command_output_modes
+            .extend(prefix_keys(other.command_output_modes, prefixed));
+        self.deprecated_commands
+            .extend(prefix_keys(other.deprecated_commands, prefixed));
+        self.deprecated_args.extend(
+            other
+                .deprecated_args
+                .into_iter()
+                .map(|((path, id), note)| ((prefixed(&path), id), note)),
+        );
+        self.help_templates
+            .extend(prefix_keys(other.help_templates, prefixed));
+        self.output_schemas
+            .extend(prefix_keys(other.output_schemas, prefixed));
+        self.validators
+            .extend(prefix_keys(other.validators, prefixed));
+        self.retry_policies
+            .extend(prefix_keys(other.retry_policies, prefixed));
+        self.command_timeouts
+            .extend(prefix_keys(other.command_timeouts, prefixed));
+
+        if let Some(mut templates) = other.template_registry.take() {
+            // Directory templates are only scanned on first use
+            if let Some(registry) = Arc::get_mut(&mut templates) {
+                registry
+                    .refresh()
+                    .map_err(|e| SetupError::Template(e.to_string()))?;
+            }
+            self.merge_templates(&templates);
+        }
+
+        self.registry.extend(other.registry);
+
+        self.mounted_themes.append(&mut other.mounted_themes);
+        self.mounted_themes.extend(other.theme);
+
+        if !other.plugin_commands.is_empty() {
+            let name: &'static str = Box::leak(prefix.to_string().into_boxed_str());
+            self.plugin_commands
+                .push(Command::new(name).subcommands(other.plugin_commands));
+        }
+
+        Ok(self)
+    }
+
+    /// Adds the templates of `other` that the app's registry doesn't have.
+    fn merge_templates(&mut self, other: &TemplateRegistry) {
+        let registry = self
+            .template_registry
+            .get_or_insert_with(|| Arc::new(TemplateRegistry::new()));
+        // The registry is only shared once the app is built
+        let Some(registry) = Arc::get_mut(registry) else {
+            return;
+        };
+        let missing: Vec<String> = other
+            .names()
+            .filter(|name| registry.get_content(name).is_err())
+            .map(String::from)
+            .collect();
+        for name in missing {
+            if let Ok(content) = other.get_content(&name) {
+                registry.add_inline(name, content);
+            }
+        }
+    }
+}
+
+/// Re-keys a per-command map by prefixing each path.
+fn prefix_keys<V, M>(map: M, prefixed: impl Fn(&str) -> String) -> Vec<(String, V)>
+where
+    M: IntoIterator<Item = (String, V)>,
+{
+    map.into_iter()
+        .map(|(path, value)| (prefixed(&path), value))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::handler::Output;
+    use crate::Theme;
+    use console::Style;
+
+    fn db_app() -> AppBuilder {
+        let mut templates = TemplateRegistry::new();
+        templates.add_inline("_count.j2", "{{ count }} migrations");
+        let mut app = AppBuilder::new();
+        app.template_registry = Some(Arc::new(templates));
+        app.command(
+            "migrate",
+            |_m, _ctx| Ok(Output::Render(serde_json::json!({ "count": 3 }))),
+            r#"ran {% include "_count.j2" %}"#,
+        )
+        .unwrap()
+        .command_output("status", crate::OutputMode::Json)
+        .theme(
+            Theme::new()
+                .add("db", Style::new().red())
+                .add("title", Style::new().blue()),
+        )
+    }
+
+    fn host_command() -> Command {
+        Command::new("app").subcommand(
+            Command::new("db")
+                .subcommand(Command::new("migrate"))
+                .subcommand(Command::new("status")),
+        )
+    }
+
+    #[test]
+    fn test_mount_registers_commands_under_prefix() {
+        let builder = AppBuilder::new()
+            .mount("db", db_app())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert!(builder.has_command("db.migrate"));
+        assert!(!builder.has_command("migrate"));
+        assert!(builder.command_output_modes.contains_key("db.status"));
+
+        let result = builder.dispatch_from(host_command(), ["app", "db", "migrate"]);
+        assert_eq!(result.output(), Some("ran 3 migrations"));
+    }
+
+    #[test]
+    fn test_mount_rejects_duplicate_paths() {
+        let builder = AppBuilder::new()
+            .command("db.migrate", |_m, _ctx| Ok(Output::<()>::Silent), "")
+            .unwrap();
+
+        let result = builder.mount("db", db_app());
+        assert!(matches!(result, Err(SetupError::DuplicateCommand(path)) if path == "db.migrate"));
+    }
+
+    #[test]
+    fn test_mount_layers_theme_under_host() {
+        let builder = AppBuilder::new()
+            .theme(Theme::new().add("title", Style::new().green()))
+            .mount("db", db_app())
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let theme = builder.get_default_theme().unwrap();
+        assert_eq!(theme.get_style("db", None), Some(Style::new().red()));
+        assert_eq!(theme.get_style("title", None), Some(Style::new().green()));
+    }
+}
@@ -0,0 +1,75 @@
+//! The app directories behind `ctx.paths()`.
+
+use super::AppBuilder;
+use crate::cli::handler::AppPaths;
+use crate::cli::paths::app_paths;
+
+impl AppBuilder {
+    /// Returns the name the app's directories are derived from.
+    ///
+    /// That's the [`build_info`](Self::build_info) name, else the
+    /// [`config`](Self::config) loader's, else the executable's file name.
+    pub(crate) fn app_name(&self) -> String {
+        if let Some(ref info) = self.build_info {
+            return info.name.clone();
+        }
+        if let Some(ref loader) = self.config_loader {
+            return loader.name().to_string();
+        }
+        std::env::args_os()
+            .next()
+            .and_then(|arg0| {
+                std::path::Path::new(&arg0)
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+            })
+            .unwrap_or_else(|| "app".to_string())
+    }
+
+    /// Returns the app's config, data, cache and state directories.
+    ///
+    /// These are what handlers get from `ctx.paths()`; see
+    /// [`crate::cli::paths`] for the locations on each platform.
+    pub fn paths(&self) -> AppPaths {
+        app_paths(&self.app_name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::config::ConfigLoader;
+    use crate::cli::handler::Output as HandlerOutput;
+    use crate::cli::BuildInfo;
+    use clap::Command;
+
+    #[test]
+    fn test_app_name_prefers_build_info() {
+        let builder = AppBuilder::new().config(ConfigLoader::new("from-config"));
+        assert_eq!(builder.app_name(), "from-config");
+        let builder = builder.build_info(BuildInfo::new("from-build", "1.0.0"));
+        assert_eq!(builder.app_name(), "from-build");
+    }
+
+    #[test]
+    fn test_handlers_get_paths() {
+        let builder = AppBuilder::new()
+            .build_info(BuildInfo::new("standout-paths-test", "1.0.0"))
+            .command(
+                "where",
+                |_m, ctx| {
+                    let paths = ctx.paths().expect("paths are set on dispatch");
+                    Ok(HandlerOutput::Render(serde_json::json!({
+                        "cache": paths.cache.ends_with("standout-paths-test"),
+                    })))
+                },
+                "{{ cache }}",
+            )
+            .unwrap();
+        let result = builder.dispatch_from(
+            Command::new("app").subcommand(Command::new("where")),
+            ["app", "where"],
+        );
+        assert_eq!(result.output(), Some("true"));
+    }
+}
@@ -0,0 +1,254 @@
+//! Plugin registration.
+//!
+//! See [`crate::cli::plugin`] for the [`CommandPlugin`] trait and how
+//! external plugin executables are found and run.
+
+use std::path::Path;
+use std::process::{Command as Process, Stdio};
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use clap::{Arg, ArgMatches, Command};
+
+use super::AppBuilder;
+use crate::cli::handler::{ExitError, HandlerResult, Output};
+use crate::cli::plugin::{discover_external_plugins, CommandPlugin, ExternalPlugin};
+use crate::setup::SetupError;
+
+impl AppBuilder {
+    /// Adds a plugin's commands and registers their handlers.
+    ///
+    /// The plugin's clap commands become subcommands of the app's command at
+    /// dispatch time, so they show up in help and completions.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .plugin(SyncPlugin::new(remote))?
+    ///     .build()?;
+    /// ```
+    pub fn plugin<P: CommandPlugin>(mut self, plugin: P) -> Result<Self, SetupError> {
+        self.plugin_commands.extend(plugin.commands());
+        plugin.register(self)
+    }
+
+    /// Adds a subcommand for every `<prefix><name>` executable on `PATH`.
+    ///
+    /// `myapp <name> args...` runs the executable with `args`, with stdin and
+    /// stderr inherited. If the template registry has a `<name>` template
+    /// (from [`templates`](Self::templates) or
+    /// [`templates_dir`](Self::templates_dir), so configure those first), the
+    /// executable's stdout is parsed as JSON and rendered through it,
+    /// honoring `--output`. Otherwise its output is passed through as-is. A
+    /// non-zero exit is reported with the executable's exit code.
+    ///
+    /// Commands registered on the app take precedence over plugins of the
+    /// same name.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // `myapp-report` on PATH becomes `myapp report`
+    /// App::builder()
+    ///     .templates(embed_templates!("src/templates"))
+    ///     .external_plugins("myapp-")?
+    ///     .build()?;
+    /// ```
+    pub fn external_plugins(self, prefix: &str) -> Result<Self, SetupError> {
+        let search_path = std::env::var_os("PATH").unwrap_or_default();
+        self.register_external_plugins(discover_external_plugins(prefix, &search_path))
+    }
+
+    fn register_external_plugins(
+        mut self,
+        plugins: Vec<ExternalPlugin>,
+    ) -> Result<Self, SetupError> {
+        // Directory templates are only scanned on first use
+        if let Some(registry) = self.template_registry.as_mut().and_then(Arc::get_mut) {
+            registry
+                .refresh()
+                .map_err(|e| SetupError::Template(e.to_string()))?;
+        }
+
+        for plugin in plugins {
+            if self
+                .pending_commands
+                .lock()
+                .unwrap()
+                .contains_key(&plugin.name)
+            {
+                continue;
+            }
+
+            let name: &'static str = Box::leak(plugin.name.clone().into_boxed_str());
+            self.plugin_commands.push(
+                Command::new(name)
+                    .about(format!("Run the {} plugin", plugin.path.display()))
+                    .disable_help_flag(true)
+                    .arg(
+                        Arg::new("args")
+                            .num_args(0..)
+                            .trailing_var_arg(true)
+                            .allow_hyphen_values(true),
+                    ),
+            );
+
+            let template = self.template_registry.as_ref().and_then(|registry| {
+                registry
+                    .get_content(&format!("{}{}", plugin.name, self.template_ext))
+                    .ok()
+            });
+            let path = plugin.path;
+            self = match template {
+                Some(template) => {
+                    self.command(name, move |m, _ctx| run_json(&path, m), &template)?
+                }
+                None => self.command_passthrough(name, move |m, _ctx| run_passthrough(&path, m))?,
+            };
+        }
+        Ok(self)
+    }
+
+    /// Adds the plugins' subcommands the command doesn't define itself.
+    pub(crate) fn augment_command_with_plugins(&self, cmd: Command) -> Command {
+        add_missing_subcommands(cmd, &self.plugin_commands)
+    }
+}
+
+/// Adds the subcommands `cmd` doesn't define, recursing into the ones it
+/// does (plugins of a [mounted](AppBuilder::mount) app sit under its prefix).
+fn add_missing_subcommands(mut cmd: Command, subcommands: &[Command]) -> Command {
+    for sub in subcommands {
+        let name = sub.get_name().to_string();
+        if cmd.find_subcommand(&name).is_none() {
+            cmd = cmd.subcommand(sub.clone());
+        } else if sub.has_subcommands() {
+            let nested: Vec<Command> = sub.get_subcommands().cloned().collect();
+            cmd = cmd.mut_subcommand(name, |existing| add_missing_subcommands(existing, &nested));
+        }
+    }
+    cmd
+}
+
+/// The arguments after the plugin's name.
+fn plugin_args(matches: &ArgMatches) -> Vec<String> {
+    matches
+        .get_many::<String>("args")
+        .map(|args| args.cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Turns a failed plugin run into an error carrying its exit code.
+fn plugin_failed(path: &Path, status: std::process::ExitStatus) -> anyhow::Error {
+    let code = status
+        .code()
+        .and_then(|code| u8::try_from(code).ok())
+        .filter(|code| *code != 0)
+        .unwrap_or(1);
+    ExitError::new(code, anyhow!("{} failed ({})", path.display(), status)).into()
+}
+
+fn run_passthrough(path: &Path, matches: &ArgMatches) -> anyhow::Result<()> {
+    let status = Process::new(path)
+        .args(plugin_args(matches))
+        .status()
+        .map_err(|e| anyhow!("failed to run {}: {}", path.display(), e))?;
+    if !status.success() {
+        return Err(plugin_failed(path, status));
+    }
+    Ok(())
+}
+
+fn run_json(path: &Path, matches: &ArgMatches) -> HandlerResult<serde_json::Value> {
+    let output = Process::new(path)
+        .args(plugin_args(matches))
+        .stdin(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| anyhow!("failed to run {}: {}", path.display(), e))?;
+    if !output.status.success() {
+        return Err(plugin_failed(path, output.status));
+    }
+    let data = serde_json::from_slice(&output.stdout)
+        .map_err(|e| anyhow!("{} printed invalid JSON: {}", path.display(), e))?;
+    Ok(Output::Render(data))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::cli::handler::RunResult;
+    use std::os::unix::fs::PermissionsExt;
+
+    struct Greet;
+
+    impl CommandPlugin for Greet {
+        fn commands(&self) -> Vec<Command> {
+            vec![Command::new("greet").arg(Arg::new("who").required(true))]
+        }
+
+        fn register(self, app: AppBuilder) -> Result<AppBuilder, SetupError> {
+            app.command(
+                "greet",
+                |m, _ctx| {
+                    let who = m.get_one::<String>("who").unwrap().clone();
+                    Ok(Output::Render(serde_json::json!({ "who": who })))
+                },
+                "hello {{ who }}",
+            )
+        }
+    }
+
+    fn plugin_script(dir: &Path, body: &str) -> ExternalPlugin {
+        let path = dir.join("app-report");
+        std::fs::write(&path, format!("#!/bin/sh\n{}\n", body)).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        ExternalPlugin {
+            name: "report".into(),
+            path,
+        }
+    }
+
+    #[test]
+    fn test_plugin_adds_commands_and_handlers() {
+        let builder = AppBuilder::new().plugin(Greet).unwrap();
+        let result = builder.dispatch_from(Command::new("app"), ["app", "greet", "world"]);
+        assert_eq!(result.output(), Some("hello world"));
+    }
+
+    #[test]
+    fn test_external_plugin_output_rendered_through_template() {
+        let dir = tempfile::tempdir().unwrap();
+        let plugin = plugin_script(dir.path(), r#"echo "{\"count\": $#, \"first\": \"$1\"}""#);
+        let templates = tempfile::tempdir().unwrap();
+        std::fs::write(
+            templates.path().join("report.j2"),
+            "{{ count }} args, first {{ first }}",
+        )
+        .unwrap();
+
+        let builder = AppBuilder::new()
+            .templates_dir(templates.path())
+            .unwrap()
+            .register_external_plugins(vec![plugin])
+            .unwrap();
+        let result =
+            builder.dispatch_from(Command::new("app"), ["app", "report", "--since", "monday"]);
+
+        assert_eq!(result.output(), Some("2 args, first --since"));
+    }
+
+    #[test]
+    fn test_external_plugin_failure_keeps_exit_code() {
+        let dir = tempfile::tempdir().unwrap();
+        let plugin = plugin_script(dir.path(), "exit 4");
+
+        let builder = AppBuilder::new()
+            .register_external_plugins(vec![plugin])
+            .unwrap();
+        let result = builder.dispatch_from(Command::new("app"), ["app", "report"]);
+
+        assert!(matches!(result, RunResult::Exit(_, 4)), "{result:?}");
+    }
+}
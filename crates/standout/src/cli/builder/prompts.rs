@@ -0,0 +1,175 @@
+//! `--yes` / `--no-input` prompt flags.
+//!
+//! Apps opt in with `prompt_flags()`. During dispatch the flags scope the
+//! [`PromptMode`] that standout-input's prompt and editor sources consult to
+//! the dispatching thread, so scripts can bypass every prompt the same way.
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use standout_input::PromptMode;
+
+use super::AppBuilder;
+
+impl AppBuilder {
+    /// Adds global `--yes` and `--no-input` flags for scripted runs.
+    ///
+    /// With `--yes`, confirm prompts answer their default (or yes when they
+    /// have none) without asking, and other interactive sources are skipped.
+    /// With `--no-input`, every interactive source is skipped, so input
+    /// chains fall through to their defaults. Handlers can read the mode
+    /// with `ctx.prompt_mode()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .prompt_flags()
+    ///     .command("prune", prune, "{{ removed }} removed")?
+    ///     .build()?;
+    /// // myapp prune --yes
+    /// ```
+    pub fn prompt_flags(mut self) -> Self {
+        self.prompt_flags = true;
+        self
+    }
+
+    /// Adds the `--yes` / `--no-input` flags to the command, if enabled.
+    pub(crate) fn augment_command_with_prompt_flags(&self, cmd: Command) -> Command {
+        if !self.prompt_flags {
+            return cmd;
+        }
+        cmd.arg(
+            Arg::new("_yes")
+                .long("yes")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .conflicts_with("_no_input")
+                .help("Answer yes to confirmations and skip other prompts"),
+        )
+        .arg(
+            Arg::new("_no_input")
+                .long("no-input")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help("Never prompt; use defaults instead"),
+        )
+    }
+
+    /// Returns the prompt mode selected by the flags, if enabled.
+    pub(crate) fn prompt_mode_for(&self, matches: &ArgMatches) -> Option<PromptMode> {
+        if !self.prompt_flags {
+            return None;
+        }
+        let set = |id: &str| {
+            matches
+                .try_get_one::<bool>(id)
+                .ok()
+                .flatten()
+                .copied()
+                .unwrap_or(false)
+        };
+        Some(if set("_yes") {
+            PromptMode::AssumeYes
+        } else if set("_no_input") {
+            PromptMode::NoInput
+        } else {
+            PromptMode::Ask
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{CommandContextInput, Output};
+    use serde_json::json;
+    use serial_test::serial;
+    use std::sync::{Arc, Mutex};
+
+    fn builder_seeing_mode(seen: Arc<Mutex<Option<PromptMode>>>) -> AppBuilder {
+        AppBuilder::new()
+            .prompt_flags()
+            .command(
+                "prune",
+                move |_m, ctx| {
+                    *seen.lock().unwrap() = Some(ctx.prompt_mode());
+                    assert_eq!(standout_input::prompt_mode(), ctx.prompt_mode());
+                    Ok(Output::Render(json!({})))
+                },
+                "",
+            )
+            .unwrap()
+    }
+
+    #[test]
+    #[serial]
+    fn test_prompt_flags_set_mode_during_dispatch() {
+        for (flag, mode) in [
+            (None, PromptMode::Ask),
+            (Some("--yes"), PromptMode::AssumeYes),
+            (Some("--no-input"), PromptMode::NoInput),
+        ] {
+            let seen = Arc::new(Mutex::new(None));
+            let builder = builder_seeing_mode(seen.clone());
+            let cmd = Command::new("app").subcommand(Command::new("prune"));
+            let args: Vec<&str> = ["app", "prune"].into_iter().chain(flag).collect();
+
+            builder.dispatch_from(cmd, args);
+
+            assert_eq!(*seen.lock().unwrap(), Some(mode));
+            assert_eq!(standout_input::prompt_mode(), PromptMode::Ask);
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn test_prompt_flags_hold_per_dispatch_across_threads() {
+        use std::sync::Barrier;
+
+        // Both handlers run at once, each reading the mode its flag chose
+        let barrier = Arc::new(Barrier::new(2));
+        let handler = |barrier: Arc<Barrier>| {
+            move |_m: &ArgMatches, _ctx: &crate::cli::CommandContext| {
+                barrier.wait();
+                let mode = standout_input::prompt_mode();
+                barrier.wait();
+                Ok(Output::Render(json!({ "mode": format!("{:?}", mode) })))
+            }
+        };
+        let builder = Arc::new(
+            AppBuilder::new()
+                .prompt_flags()
+                .command("prune", handler(barrier.clone()), "{{ mode }}")
+                .unwrap()
+                .command("clean", handler(barrier), "{{ mode }}")
+                .unwrap(),
+        );
+        let cmd = Command::new("app")
+            .subcommand(Command::new("prune"))
+            .subcommand(Command::new("clean"));
+
+        let threads: Vec<_> = [("prune", "--yes"), ("clean", "--no-input")]
+            .into_iter()
+            .map(|(name, flag)| {
+                let builder = builder.clone();
+                let cmd = cmd.clone();
+                std::thread::spawn(move || builder.dispatch_from(cmd, ["app", name, flag]))
+            })
+            .collect();
+        let outputs: Vec<_> = threads
+            .into_iter()
+            .map(|thread| thread.join().unwrap().output().unwrap().to_string())
+            .collect();
+
+        assert_eq!(outputs, ["AssumeYes", "NoInput"]);
+    }
+
+    #[test]
+    fn test_prompt_flags_conflict() {
+        let builder = AppBuilder::new().prompt_flags();
+        let cmd = builder.augment_command_with_prompt_flags(Command::new("app"));
+
+        assert!(cmd
+            .try_get_matches_from(["app", "--yes", "--no-input"])
+            .is_err());
+    }
+}
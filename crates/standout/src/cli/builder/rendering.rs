@@ -1,7 +1,7 @@
 //! Rendering methods for App.
 //!
 //! This module contains methods for rendering templates and serializing data
-//! across all output modes (Term, Text, JSON, YAML, XML, CSV).
+//! across all output modes (Term, Text, JSON, YAML, XML, TOML, CSV).
 
 use serde::Serialize;
 use std::collections::HashMap;
@@ -33,7 +33,7 @@ impl AppBuilder {
         data: &T,
         mode: OutputMode,
     ) -> Result<String, SetupError> {
-        // For JSON/YAML/XML/CSV modes, serialize directly
+        // For JSON/YAML/XML/TOML/CSV modes, serialize directly
         if mode.is_structured() {
             return self.serialize_data(data, mode);
         }
@@ -63,7 +63,7 @@ impl AppBuilder {
         data: &T,
         mode: OutputMode,
     ) -> Result<String, SetupError> {
-        // For JSON/YAML/XML/CSV modes, serialize directly
+        // For JSON/YAML/XML/TOML/CSV modes, serialize directly
         if mode.is_structured() {
             return self.serialize_data(data, mode);
         }
@@ -196,22 +196,15 @@ impl AppBuilder {
             OutputMode::Xml => {
                 crate::serialize_to_xml(data).map_err(|e| SetupError::Config(e.to_string()))
             }
+            OutputMode::Toml => {
+                crate::serialize_to_toml(data).map_err(|e| SetupError::Config(e.to_string()))
+            }
             OutputMode::Csv => {
                 let value =
                     serde_json::to_value(data).map_err(|e| SetupError::Config(e.to_string()))?;
                 let (headers, rows) = crate::flatten_json_for_csv(&value);
-
-                let mut wtr = csv::Writer::from_writer(Vec::new());
-                wtr.write_record(&headers)
-                    .map_err(|e| SetupError::Config(e.to_string()))?;
-                for row in rows {
-                    wtr.write_record(&row)
-                        .map_err(|e| SetupError::Config(e.to_string()))?;
-                }
-                let bytes = wtr
-                    .into_inner()
-                    .map_err(|e| SetupError::Config(e.to_string()))?;
-                String::from_utf8(bytes).map_err(|e| SetupError::Config(e.to_string()))
+                crate::format_csv(&headers, &rows, &self.csv_options)
+                    .map_err(|e| SetupError::Config(e.to_string()))
             }
             _ => Err(SetupError::Config(format!(
                 "Unexpected output mode: {:?}",
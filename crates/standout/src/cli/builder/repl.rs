@@ -0,0 +1,250 @@
+//! Interactive shell over the registered commands.
+//!
+//! `repl(cmd)` turns the app into a shell: each line read at the
+//! `myapp>` prompt is split into arguments and dispatched like a command
+//! line, with the same handlers, hooks and [`app_state`](AppBuilder::app_state)
+//! for every command:
+//!
+//! ```text
+//! myapp> add "buy milk"
+//! Added: buy milk
+//! myapp> list --output=json
+//! ```
+//!
+//! `exit`, `quit` or end of input (Ctrl-D) leave the shell.
+
+use std::ffi::OsString;
+use std::io::{self, BufRead, Write};
+
+use clap::Command;
+
+use super::execution::RunState;
+use super::AppBuilder;
+use crate::cli::handler::RunResult;
+use crate::OutputMode;
+
+impl AppBuilder {
+    /// Runs an interactive shell dispatching each input line as a command.
+    ///
+    /// The prompt is the command's name followed by `> `. Lines are split
+    /// like a shell would: whitespace separates arguments, quotes group them
+    /// and `\` escapes the next character. Failures are printed on stderr and
+    /// the shell keeps going. `help` and `--help` work as on the command
+    /// line.
+    ///
+    /// Returns when the input ends or on `exit` / `quit`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let app = App::builder()
+    ///     .app_state(Database::connect()?)
+    ///     .command("list", list, "{{ items | length }} items")?
+    ///     .build()?;
+    ///
+    /// if std::env::args().len() > 1 {
+    ///     app.run(cmd, std::env::args());
+    /// } else {
+    ///     app.repl(cmd)?;
+    /// }
+    /// ```
+    pub fn repl(&self, cmd: Command) -> io::Result<()> {
+        self.run_repl(
+            cmd,
+            &mut io::stdin().lock(),
+            &mut io::stdout(),
+            &mut io::stderr(),
+        )
+    }
+
+    /// The shell loop, reading from `input` and writing to `out` and `err`.
+    fn run_repl(
+        &self,
+        cmd: Command,
+        input: &mut dyn BufRead,
+        out: &mut dyn Write,
+        err: &mut dyn Write,
+    ) -> io::Result<()> {
+        let name = cmd.get_name().to_string();
+        let mut line = String::new();
+        loop {
+            write!(out, "{}> ", name)?;
+            out.flush()?;
+
+            line.clear();
+            if input.read_line(&mut line)? == 0 {
+                writeln!(out)?;
+                return Ok(());
+            }
+            let words = match split_line(&line) {
+                Ok(words) => words,
+                Err(e) => {
+                    writeln!(err, "Error: {}", e)?;
+                    continue;
+                }
+            };
+            match words.first().map(String::as_str) {
+                None => continue,
+                Some("exit" | "quit") => return Ok(()),
+                Some(_) => {}
+            }
+
+            let args =
+                std::iter::once(OsString::from(&name)).chain(words.into_iter().map(Into::into));
+            let mut run = RunState::default();
+            let result = self.dispatch_from_with_mode(cmd.clone(), args, None, &mut run);
+            for warning in self.deprecation_warnings(&run) {
+                writeln!(err, "{}", warning)?;
+            }
+            match result {
+                RunResult::Handled(output) => {
+                    if !output.is_empty() {
+                        writeln!(out, "{}", output.trim_end())?;
+                    }
+                }
+                RunResult::Stderr(output) | RunResult::Error(output) => {
+                    writeln!(err, "{}", output.trim_end())?;
+                }
+                RunResult::Exit(output, _) => writeln!(err, "{}", output.trim_end())?,
+                RunResult::NoMatch(_) => {
+                    writeln!(err, "Error: no command handles `{}`", line.trim())?;
+                }
+                result => {
+                    self.print_result(&result);
+                }
+            }
+
+            self.print_timings(&run);
+            let default_theme = crate::Theme::default();
+            let theme = self.theme.as_ref().unwrap_or(&default_theme);
+            standout_render::warnings::flush_to_stderr(theme, OutputMode::Auto);
+        }
+    }
+}
+
+/// Splits a line into arguments the way a shell would.
+///
+/// Whitespace separates arguments. Single quotes keep their contents as-is;
+/// inside double quotes and unquoted, `\` escapes the next character.
+pub(crate) fn split_line(line: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            '\'' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => word.push(c),
+                        None => return Err("unclosed single quote".to_string()),
+                    }
+                }
+            }
+            '"' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(c) => word.push(c),
+                            None => return Err("unclosed double quote".to_string()),
+                        },
+                        Some(c) => word.push(c),
+                        None => return Err("unclosed double quote".to_string()),
+                    }
+                }
+            }
+            '\\' => {
+                in_word = true;
+                if let Some(c) = chars.next() {
+                    word.push(c);
+                }
+            }
+            c => {
+                in_word = true;
+                word.push(c);
+            }
+        }
+    }
+    if in_word {
+        words.push(word);
+    }
+    Ok(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::handler::Output as HandlerOutput;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    fn test_split_line() {
+        assert_eq!(
+            split_line("add  \"buy milk\" --tag 'a b' x\\ y\n").unwrap(),
+            ["add", "buy milk", "--tag", "a b", "x y"]
+        );
+        assert_eq!(
+            split_line("say \"a \\\"b\\\"\" ''").unwrap(),
+            ["say", "a \"b\"", ""]
+        );
+        assert!(split_line("   ").unwrap().is_empty());
+        assert!(split_line("add \"oops").is_err());
+        assert!(split_line("add 'oops").is_err());
+    }
+
+    #[test]
+    fn test_repl_dispatches_lines_with_shared_state() {
+        struct Counter(AtomicU32);
+
+        let builder = AppBuilder::new()
+            .app_state(Counter(AtomicU32::new(0)))
+            .command(
+                "bump",
+                |m, ctx| {
+                    let counter = ctx.app_state.get_required::<Counter>()?;
+                    let by: u32 = m.get_one::<String>("by").unwrap().parse()?;
+                    let count = counter.0.fetch_add(by, Ordering::SeqCst) + by;
+                    Ok(HandlerOutput::Render(json!({ "count": count })))
+                },
+                "count={{ count }}",
+            )
+            .unwrap();
+        let cmd = Command::new("app").subcommand(Command::new("bump").arg(clap::Arg::new("by")));
+
+        let mut input = io::Cursor::new("bump 2\n\nbump 'x'\nnope\nbump 3\nquit\nbump 9\n");
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        builder
+            .run_repl(cmd, &mut input, &mut out, &mut err)
+            .unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        let err = String::from_utf8(err).unwrap();
+        assert_eq!(out, "app> count=2\napp> app> app> app> count=5\napp> ");
+        assert!(err.contains("invalid digit"), "{err}");
+        assert!(err.contains("unrecognized subcommand 'nope'"), "{err}");
+    }
+
+    #[test]
+    fn test_repl_ends_at_end_of_input() {
+        let builder = AppBuilder::new();
+        let mut input = io::Cursor::new("");
+        let mut out = Vec::new();
+        builder
+            .run_repl(Command::new("app"), &mut input, &mut out, &mut Vec::new())
+            .unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "app> \n");
+    }
+}
@@ -0,0 +1,93 @@
+//! Per-command retry policies.
+//!
+//! See [`crate::cli::retry`] for how failing handlers are retried and the
+//! notices printed between attempts.
+
+use super::AppBuilder;
+use crate::cli::RetryPolicy;
+
+impl AppBuilder {
+    /// Retries the handler of the command at `path` as `policy` allows.
+    ///
+    /// When the handler fails and the policy allows another attempt, a
+    /// themed `retrying` notice is printed on stderr (styled with the app
+    /// theme plus `retry` for the label; `-q` hides it) and the handler runs
+    /// again after the policy's backoff. Hooks, validators and rendering run
+    /// once; the last error is reported if every attempt fails. Interrupting
+    /// the command stops retrying.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .command("sync", sync, "{{ pulled }} pulled")?
+    ///     .retry(
+    ///         "sync",
+    ///         RetryPolicy::new(3)
+    ///             .backoff(Duration::from_millis(500))
+    ///             .retry_on(|err| err.is::<reqwest::Error>()),
+    ///     )
+    ///     .build()?;
+    /// ```
+    pub fn retry(mut self, path: &str, policy: RetryPolicy) -> Self {
+        self.retry_policies.insert(path.to_string(), policy);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::handler::{Output, RunResult};
+    use clap::Command;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    fn flaky_app(failures: u32, policy: RetryPolicy) -> (AppBuilder, Arc<AtomicU32>) {
+        let calls = Arc::new(AtomicU32::new(0));
+        let counter = calls.clone();
+        let builder = AppBuilder::new()
+            .command(
+                "sync",
+                move |_m, _ctx| {
+                    let calls = counter.fetch_add(1, Ordering::SeqCst) + 1;
+                    if calls <= failures {
+                        anyhow::bail!("connection refused");
+                    }
+                    Ok(Output::Render(serde_json::json!({ "calls": calls })))
+                },
+                "synced after {{ calls }}",
+            )
+            .unwrap()
+            .retry("sync", policy);
+        (builder, calls)
+    }
+
+    fn cmd() -> Command {
+        Command::new("app").subcommand(Command::new("sync"))
+    }
+
+    #[test]
+    fn test_retry_runs_handler_again() {
+        let (builder, calls) = flaky_app(2, RetryPolicy::new(3).backoff(Duration::ZERO));
+
+        let result = builder.dispatch_from(cmd(), ["app", "sync"]);
+
+        assert_eq!(result.output(), Some("synced after 3"));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_retry_reports_last_error() {
+        let (builder, calls) = flaky_app(5, RetryPolicy::new(2).backoff(Duration::ZERO));
+
+        let result = builder.dispatch_from(cmd(), ["app", "sync"]);
+
+        let RunResult::Error(message) = result else {
+            panic!("expected an error, got {result:?}");
+        };
+        assert!(message.contains("connection refused"), "{message}");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
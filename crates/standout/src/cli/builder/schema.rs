@@ -0,0 +1,240 @@
+//! JSON Schema registration for command outputs.
+//!
+//! Commands render arbitrary `Serialize` data, so the framework can't infer
+//! what `--output=json` will look like. Apps opt in by registering a schema
+//! per command path:
+//! - `output_schema_value()` - register a pre-built schema
+//! - `output_schema::<T>()` - generate one from a `JsonSchema` type (`schema` feature)
+//!
+//! Once any schema is registered, `dispatch_from()` accepts a hidden
+//! `__schema [command...]` subcommand that prints the schema for one command,
+//! or every registered schema keyed by command path when no command is given.
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use serde_json::Value;
+
+use super::AppBuilder;
+use crate::cli::handler::RunResult;
+
+/// Name of the hidden subcommand that prints registered output schemas.
+pub(crate) const SCHEMA_COMMAND: &str = "__schema";
+
+impl AppBuilder {
+    /// Registers the JSON Schema describing a command's structured output.
+    ///
+    /// `path` uses the same dot-separated form as [`hooks`](Self::hooks)
+    /// (e.g. `"db.migrate"`). Registering a path twice replaces the schema.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use standout::cli::App;
+    /// use serde_json::json;
+    ///
+    /// let app = App::builder().output_schema_value(
+    ///     "list",
+    ///     json!({"type": "array", "items": {"type": "string"}}),
+    /// );
+    /// assert!(app.command_schema("list").is_some());
+    /// ```
+    pub fn output_schema_value(mut self, path: &str, schema: Value) -> Self {
+        self.output_schemas.insert(path.to_string(), schema);
+        self
+    }
+
+    /// Registers the JSON Schema generated from `T` as a command's output schema.
+    ///
+    /// `T` is typically the type the handler wraps in `Output::Render`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use schemars::JsonSchema;
+    ///
+    /// #[derive(Serialize, JsonSchema)]
+    /// struct ListOutput { items: Vec<String> }
+    ///
+    /// App::builder()
+    ///     .command("list", list_handler, "{{ items }}")?
+    ///     .output_schema::<ListOutput>("list")
+    ///     .build()?;
+    /// ```
+    #[cfg(feature = "schema")]
+    pub fn output_schema<T: schemars::JsonSchema>(self, path: &str) -> Self {
+        let schema = serde_json::to_value(schemars::schema_for!(T))
+            .expect("JSON Schema always serializes to JSON");
+        self.output_schema_value(path, schema)
+    }
+
+    /// Returns the output schema registered for a command path, if any.
+    pub fn command_schema(&self, path: &str) -> Option<&Value> {
+        self.output_schemas.get(path)
+    }
+
+    /// Returns all registered output schemas as an object keyed by command path.
+    pub fn output_schemas(&self) -> Value {
+        Value::Object(
+            self.output_schemas
+                .iter()
+                .map(|(path, schema)| (path.clone(), schema.clone()))
+                .collect(),
+        )
+    }
+
+    /// Builds the hidden `__schema` subcommand.
+    pub(crate) fn schema_subcommand() -> Command {
+        Command::new(SCHEMA_COMMAND)
+            .hide(true)
+            .about("Print the JSON Schema of a command's output")
+            .arg(
+                Arg::new("command")
+                    .action(ArgAction::Append)
+                    .num_args(0..)
+                    .value_name("COMMAND"),
+            )
+    }
+
+    /// Handles an invocation of the hidden `__schema` subcommand.
+    ///
+    /// The command may be given as separate words (`__schema db migrate`) or
+    /// in dotted form (`__schema db.migrate`).
+    pub(crate) fn run_schema_command(&self, matches: &ArgMatches) -> RunResult {
+        let words: Vec<&str> = matches
+            .get_many::<String>("command")
+            .map(|values| values.map(String::as_str).collect())
+            .unwrap_or_default();
+
+        let schema = if words.is_empty() {
+            self.output_schemas()
+        } else {
+            let path = words.join(".");
+            match self.command_schema(&path) {
+                Some(schema) => schema.clone(),
+                None => {
+                    return RunResult::Error(format!(
+                        "No output schema registered for command '{}'",
+                        words.join(" ")
+                    ))
+                }
+            }
+        };
+
+        match serde_json::to_string_pretty(&schema) {
+            Ok(json) => RunResult::Handled(json),
+            Err(e) => RunResult::Error(format!("Failed to serialize schema: {}", e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn list_schema() -> Value {
+        json!({"type": "array", "items": {"type": "string"}})
+    }
+
+    fn app_cmd() -> Command {
+        Command::new("app")
+            .subcommand(Command::new("list"))
+            .subcommand(Command::new("db").subcommand(Command::new("migrate")))
+    }
+
+    #[test]
+    fn test_command_schema_lookup() {
+        let app = AppBuilder::new().output_schema_value("list", list_schema());
+
+        assert_eq!(app.command_schema("list"), Some(&list_schema()));
+        assert_eq!(app.command_schema("other"), None);
+    }
+
+    #[test]
+    fn test_schema_command_single() {
+        let app = AppBuilder::new().output_schema_value("list", list_schema());
+
+        let result = app.dispatch_from(app_cmd(), ["app", "__schema", "list"]);
+
+        let output = result.output().expect("expected output");
+        let parsed: Value = serde_json::from_str(output).unwrap();
+        assert_eq!(parsed, list_schema());
+    }
+
+    #[test]
+    fn test_schema_command_nested_path() {
+        let migrate = json!({"type": "object"});
+        let app = AppBuilder::new().output_schema_value("db.migrate", migrate.clone());
+
+        for args in [
+            vec!["app", "__schema", "db", "migrate"],
+            vec!["app", "__schema", "db.migrate"],
+        ] {
+            let result = app.dispatch_from(app_cmd(), args);
+            let parsed: Value = serde_json::from_str(result.output().unwrap()).unwrap();
+            assert_eq!(parsed, migrate);
+        }
+    }
+
+    #[test]
+    fn test_schema_command_lists_all() {
+        let app = AppBuilder::new()
+            .output_schema_value("list", list_schema())
+            .output_schema_value("db.migrate", json!({"type": "object"}));
+
+        let result = app.dispatch_from(app_cmd(), ["app", "__schema"]);
+
+        let parsed: Value = serde_json::from_str(result.output().unwrap()).unwrap();
+        assert_eq!(
+            parsed,
+            json!({"list": list_schema(), "db.migrate": {"type": "object"}})
+        );
+    }
+
+    #[test]
+    fn test_schema_command_unknown() {
+        let app = AppBuilder::new().output_schema_value("list", list_schema());
+
+        let result = app.dispatch_from(app_cmd(), ["app", "__schema", "db", "migrate"]);
+
+        match result {
+            RunResult::Error(msg) => assert!(msg.contains("'db migrate'")),
+            _ => panic!("Expected error"),
+        }
+    }
+
+    #[test]
+    fn test_schema_command_absent_without_schemas() {
+        let app = AppBuilder::new();
+
+        let result = app.dispatch_from(app_cmd(), ["app", "__schema"]);
+
+        assert!(result.is_error());
+    }
+
+    #[cfg(feature = "schema")]
+    #[test]
+    fn test_output_schema_from_type() {
+        #[derive(serde::Serialize, schemars::JsonSchema)]
+        #[allow(dead_code)]
+        struct ListOutput {
+            items: Vec<String>,
+        }
+
+        let app = AppBuilder::new().output_schema::<ListOutput>("list");
+
+        let schema = app.command_schema("list").unwrap();
+        assert_eq!(schema["type"], "object");
+        assert_eq!(schema["properties"]["items"]["type"], "array");
+    }
+
+    #[test]
+    fn test_schema_command_hidden_from_help() {
+        let app = AppBuilder::new().output_schema_value("list", list_schema());
+
+        let mut cmd = app.augment_command_for_dispatch(app_cmd());
+        let help = cmd.render_help().to_string();
+
+        assert!(!help.contains(SCHEMA_COMMAND));
+        assert!(cmd.find_subcommand(SCHEMA_COMMAND).is_some());
+    }
+}
@@ -0,0 +1,134 @@
+//! In-process test runs.
+//!
+//! [`test_run`](AppBuilder::test_run) dispatches like `run` but returns what
+//! would have been printed as a [`TestOutput`], so handler and template tests
+//! don't need to spawn the binary.
+
+use clap::Command;
+
+use super::execution::RunState;
+use super::AppBuilder;
+use crate::cli::testing::TestOutput;
+use crate::OutputMode;
+
+/// Terminal width reported to commands during a test run.
+const TEST_TERMINAL_WIDTH: usize = 80;
+
+/// Terminal height reported to commands during a test run.
+const TEST_TERMINAL_HEIGHT: usize = 24;
+
+impl AppBuilder {
+    /// Runs the app with `args` and returns what it would have printed.
+    ///
+    /// Output is rendered in `mode` regardless of `--output` or the output
+    /// environment variable. For repeatable results, environment detection
+    /// is fixed for the duration of the run: stdout is not a TTY, the
+    /// terminal is 80 columns wide and the OS theme is light. Errors,
+    /// deprecation notices and framework warnings are collected in
+    /// `stderr`, and `exit_code` is the code `run` would exit with. No
+    /// matching handler is reported as a usage error (exit code 2).
+    ///
+    /// The detectors are process-wide and are reset to the library defaults
+    /// afterwards, so run tests that also override them with `#[serial]`.
+    /// Anything a handler prints directly (`println!`) is not captured.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[test]
+    /// fn list_renders_items() {
+    ///     let out = app().test_run(cli(), ["myapp", "list"], OutputMode::Text);
+    ///     assert_eq!(out.exit_code, 0);
+    ///     assert_eq!(out.stdout, "2 items");
+    /// }
+    /// ```
+    pub fn test_run<I, T>(&self, cmd: Command, args: I, mode: OutputMode) -> TestOutput
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<std::ffi::OsString> + Clone,
+    {
+        let _detectors = DetectorReset;
+        standout_render::set_tty_detector(|| false);
+        standout_render::set_terminal_width_detector(|| Some(TEST_TERMINAL_WIDTH));
+        standout_render::set_terminal_height_detector(|| Some(TEST_TERMINAL_HEIGHT));
+        standout_render::set_theme_detector(|| crate::ColorMode::Light);
+
+        let mut run = RunState::default();
+        let result = self.dispatch_from_with_mode(cmd, args, Some(mode), &mut run);
+        let mut output = TestOutput::from_result(result);
+        for warning in self.deprecation_warnings(&run) {
+            output.push_stderr(&console::strip_ansi_codes(&warning));
+        }
+        for warning in standout_render::warnings::drain_warnings() {
+            output.push_stderr(&warning);
+        }
+        output
+    }
+}
+
+/// Restores the default detectors when dropped, including on panic.
+struct DetectorReset;
+
+impl Drop for DetectorReset {
+    fn drop(&mut self) {
+        standout_render::reset_environment_detectors();
+        standout_render::reset_theme_detector();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::handler::{ExitError, Output as HandlerOutput};
+    use serde_json::json;
+    use serial_test::serial;
+
+    fn builder() -> AppBuilder {
+        AppBuilder::new()
+            .command(
+                "list",
+                |_m, _ctx| Ok(HandlerOutput::Render(json!({"count": 2}))),
+                "{{ count }} items",
+            )
+            .unwrap()
+            .command(
+                "fail",
+                |_m, _ctx| -> crate::cli::handler::HandlerResult<()> {
+                    Err(ExitError::new(3, anyhow::anyhow!("nothing to do")).into())
+                },
+                "",
+            )
+            .unwrap()
+    }
+
+    fn cmd() -> Command {
+        Command::new("app")
+            .subcommand(Command::new("list"))
+            .subcommand(Command::new("fail"))
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_forces_output_mode() {
+        let out = builder().test_run(cmd(), ["app", "list"], OutputMode::Text);
+        assert_eq!(out.stdout, "2 items");
+        assert_eq!(out.stderr, "");
+        assert!(out.success());
+
+        let out = builder().test_run(cmd(), ["app", "list", "--output=yaml"], OutputMode::Json);
+        assert!(out.stdout.contains("\"count\": 2"), "{}", out.stdout);
+    }
+
+    #[test]
+    #[serial]
+    fn test_run_captures_errors_and_exit_codes() {
+        let out = builder().test_run(cmd(), ["app", "fail"], OutputMode::Text);
+        assert_eq!(out.exit_code, 3);
+        assert!(out.stderr.contains("nothing to do"), "{}", out.stderr);
+        assert_eq!(out.stdout, "");
+
+        let out = builder().test_run(cmd(), ["app", "--bogus"], OutputMode::Text);
+        assert_eq!(out.exit_code, 2);
+        assert!(out.stderr.contains("--bogus"), "{}", out.stderr);
+    }
+}
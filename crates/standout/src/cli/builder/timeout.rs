@@ -0,0 +1,159 @@
+//! Per-command time limits.
+//!
+//! See [`crate::cli::timeout`] for how handlers over their limit are stopped
+//! and reported.
+
+use std::time::Duration;
+
+use super::execution::json_error;
+use super::AppBuilder;
+use crate::cli::handler::{CommandContext, ExitError, RunResult};
+use crate::cli::timeout::{
+    default_timeout_theme, render_timeout, TimedOut, Watchdog, DEFAULT_TIMEOUT_TEMPLATE,
+};
+use crate::OutputMode;
+
+impl AppBuilder {
+    /// Fails the command at `path` if its handler runs longer than `timeout`.
+    ///
+    /// Equivalent to [`CommandConfig::timeout`](crate::cli::CommandConfig::timeout)
+    /// for commands registered without a config closure. When the time is
+    /// up, `ctx.cancellation` is cancelled so the handler can stop early;
+    /// whatever it returns, the command then fails with a themed timeout
+    /// error (styled with the app theme plus `timeout` for the label, or a
+    /// JSON error with `--output=json`) and exit code 124.
+    ///
+    /// A handler that doesn't check for cancellation can't be stopped: under
+    /// `run()`, the error is printed and the process exits two seconds after
+    /// the deadline if the handler is still running. With
+    /// [`dispatch_from`](Self::dispatch_from), the error is only reported
+    /// once the handler returns.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .command("sync", sync, "{{ pulled }} pulled")?
+    ///     .command_timeout("sync", Duration::from_secs(30))
+    ///     .build()?;
+    /// ```
+    pub fn command_timeout(mut self, path: &str, timeout: Duration) -> Self {
+        self.command_timeouts.insert(path.to_string(), timeout);
+        self
+    }
+
+    /// Starts the watchdog for the command at `path`, if it has a timeout,
+    /// with the failure to report when it fires.
+    ///
+    /// Only `run()`, which sets `live_output`, may end the process for a
+    /// handler that won't return.
+    pub(crate) fn start_watchdog(
+        &self,
+        path: &str,
+        ctx: &CommandContext,
+        output_mode: OutputMode,
+        live_output: bool,
+    ) -> Option<(Watchdog, RunResult)> {
+        let timeout = *self.command_timeouts.get(path)?;
+        let timed_out = TimedOut::new(ctx.command_path.join(" "), timeout);
+        let message = self.render_timeout_failure(&timed_out, output_mode);
+        let exit_message = live_output.then(|| message.clone());
+        let watchdog = Watchdog::start(timeout, ctx.cancellation.clone(), exit_message);
+        Some((watchdog, RunResult::failure(message, ExitError::TIMEOUT)))
+    }
+
+    fn render_timeout_failure(&self, timed_out: &TimedOut, output_mode: OutputMode) -> String {
+        let plain = format!(
+            "`{}` timed out after {}",
+            timed_out.command, timed_out.timeout
+        );
+        if output_mode == OutputMode::Json {
+            return json_error("timeout", plain);
+        }
+        let theme = match &self.theme {
+            Some(theme) => default_timeout_theme().merge(theme.clone()),
+            None => default_timeout_theme(),
+        };
+        let mode = if output_mode != OutputMode::Text
+            && console::Term::stderr().features().colors_supported()
+        {
+            OutputMode::Term
+        } else {
+            OutputMode::Text
+        };
+        render_timeout(timed_out, DEFAULT_TIMEOUT_TEMPLATE, &theme, mode)
+            .unwrap_or_else(|_| format!("error: {}", plain))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::handler::Output;
+    use clap::Command;
+
+    fn slow_app(timeout: Duration) -> AppBuilder {
+        AppBuilder::new()
+            .command_with(
+                "sync",
+                |_m, ctx| {
+                    // Runs until cancelled, giving up after a second
+                    let started = std::time::Instant::now();
+                    while !ctx.is_cancelled() && started.elapsed() < Duration::from_secs(1) {
+                        std::thread::sleep(Duration::from_millis(1));
+                    }
+                    Ok(Output::Render(
+                        serde_json::json!({ "cancelled": ctx.is_cancelled() }),
+                    ))
+                },
+                |cfg| cfg.template("cancelled={{ cancelled }}").timeout(timeout),
+            )
+            .unwrap()
+    }
+
+    fn cmd() -> Command {
+        Command::new("app").subcommand(Command::new("sync"))
+    }
+
+    #[test]
+    fn test_timeout_cancels_and_fails() {
+        let builder = slow_app(Duration::from_millis(20));
+
+        let result = builder.dispatch_from(cmd(), ["app", "sync", "--output=text"]);
+
+        let RunResult::Exit(message, code) = result else {
+            panic!("expected a timeout, got {result:?}");
+        };
+        assert_eq!(code, ExitError::TIMEOUT);
+        assert_eq!(message, "error: `sync` timed out after 20ms");
+    }
+
+    #[test]
+    fn test_timeout_reported_as_json() {
+        let builder = slow_app(Duration::from_millis(20));
+
+        let result = builder.dispatch_from(cmd(), ["app", "sync", "--output=json"]);
+
+        let RunResult::Exit(message, _) = result else {
+            panic!("expected a timeout, got {result:?}");
+        };
+        let error: serde_json::Value = serde_json::from_str(&message).unwrap();
+        assert_eq!(error["error"]["code"], "timeout");
+    }
+
+    #[test]
+    fn test_fast_handler_unaffected() {
+        let builder = AppBuilder::new()
+            .command(
+                "sync",
+                |_m, _ctx| Ok(Output::Render(serde_json::json!({ "ok": true }))),
+                "ok={{ ok }}",
+            )
+            .unwrap()
+            .command_timeout("sync", Duration::from_secs(5));
+
+        let result = builder.dispatch_from(cmd(), ["app", "sync"]);
+
+        assert_eq!(result.output(), Some("ok=true"));
+    }
+}
@@ -0,0 +1,104 @@
+//! `--timings` diagnostics.
+//!
+//! Apps opt in with `timings_flag()`. With `--timings`, dispatch records the
+//! time spent parsing, in hooks, in the handler, rendering and writing the
+//! output (see [`crate::cli::timings`]). `run()` prints the summary on stderr
+//! after the output; JSON output embeds it under `_timings` instead.
+
+use std::time::Instant;
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use super::execution::RunState;
+use super::AppBuilder;
+use crate::cli::help::default_help_theme;
+use crate::cli::timings::{render_timings, Timings, DEFAULT_TIMINGS_TEMPLATE};
+use crate::OutputMode;
+
+/// Key the timings report is embedded under in JSON output.
+pub(crate) const TIMINGS_KEY: &str = "_timings";
+
+impl AppBuilder {
+    /// Adds a global `--timings` flag that reports per-stage durations.
+    ///
+    /// The stages are `parse`, `hooks`, `handler`, `render` and `output`.
+    /// The summary is printed on stderr, styled with the help theme; with
+    /// `--output=json` it's embedded in the output object under `_timings`.
+    /// Handlers can read the [`Timings`] from `ctx.extensions`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .timings_flag()
+    ///     .command("sync", sync, "{{ count }} synced")?
+    ///     .build()?;
+    /// // myapp sync --timings
+    /// ```
+    pub fn timings_flag(mut self) -> Self {
+        self.timings_flag = true;
+        self
+    }
+
+    /// Adds the `--timings` flag to the command, if enabled.
+    pub(crate) fn augment_command_with_timings(&self, cmd: Command) -> Command {
+        if !self.timings_flag {
+            return cmd;
+        }
+        cmd.arg(
+            Arg::new("_timings")
+                .long("timings")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help("Report how long each stage of the command took"),
+        )
+    }
+
+    /// Starts timing if `--timings` was passed, counting from `started`.
+    pub(crate) fn timings_for(&self, matches: &ArgMatches, started: Instant) -> Option<Timings> {
+        if !self.timings_flag
+            || !matches
+                .try_get_one::<bool>("_timings")
+                .ok()
+                .flatten()
+                .copied()
+                .unwrap_or(false)
+        {
+            return None;
+        }
+        Some(Timings::starting_at(started))
+    }
+
+    /// Embeds the timings in a JSON object output, returning the new output.
+    ///
+    /// Returns `None` (leaving the summary for stderr) if the output isn't a
+    /// JSON object.
+    pub(crate) fn embed_timings(&self, timings: &Timings, raw: &str) -> Option<String> {
+        let serde_json::Value::Object(mut object) = serde_json::from_str(raw).ok()? else {
+            return None;
+        };
+        let report = serde_json::to_value(timings.report()).ok()?;
+        object.insert(TIMINGS_KEY.to_string(), report);
+        serde_json::to_string_pretty(&object).ok()
+    }
+
+    /// Prints the timings summary on stderr if `--timings` was passed and the
+    /// report wasn't embedded in the output.
+    pub(crate) fn print_timings(&self, run: &RunState) {
+        let Some(ref timings) = run.timings else {
+            return;
+        };
+        timings.lap("output");
+        let theme = self.theme.clone().unwrap_or_else(default_help_theme);
+        let mode = if console::Term::stderr().features().colors_supported() {
+            OutputMode::Term
+        } else {
+            OutputMode::Text
+        };
+        if let Ok(summary) =
+            render_timings(&timings.report(), DEFAULT_TIMINGS_TEMPLATE, &theme, mode)
+        {
+            eprintln!("{}", summary);
+        }
+    }
+}
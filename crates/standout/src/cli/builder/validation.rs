@@ -0,0 +1,203 @@
+//! The validation stage between parsing and the handler.
+//!
+//! See [`crate::cli::validation`] for how aggregated errors are reported.
+
+use std::sync::Arc;
+
+use clap::ArgMatches;
+
+use super::AppBuilder;
+use crate::cli::handler::{CommandContext, ExitError, RunResult};
+use crate::cli::validation::{
+    default_validation_theme, render_validation_errors, ValidationErrors,
+    DEFAULT_VALIDATION_TEMPLATE,
+};
+use crate::OutputMode;
+
+/// A validator: checks the command's arguments and adds what it finds.
+pub(crate) type ValidateFn =
+    Arc<dyn Fn(&ArgMatches, &CommandContext, &mut ValidationErrors) + Send + Sync>;
+
+impl AppBuilder {
+    /// Adds a validator for the command at `path`.
+    ///
+    /// Validators run after parsing and before pre-dispatch hooks and the
+    /// handler, with the command's own matches (as handlers get them) and
+    /// the context. Every validator of the command runs, adding problems to
+    /// the shared [`ValidationErrors`]; if any were added, they are reported
+    /// together and the handler doesn't run.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .command("serve", serve, "listening on {{ port }}")?
+    ///     .validate("serve", |m, _ctx, errors| {
+    ///         let port = *m.get_one::<u32>("port").unwrap();
+    ///         if !(1..=65535).contains(&port) {
+    ///             errors.arg("--port", "must be between 1 and 65535");
+    ///         }
+    ///         if m.get_flag("tls") && m.get_one::<String>("cert").is_none() {
+    ///             errors.arg("--cert", "is required with --tls");
+    ///         }
+    ///     })
+    ///     .build()?;
+    /// ```
+    pub fn validate<F>(mut self, path: &str, validator: F) -> Self
+    where
+        F: Fn(&ArgMatches, &CommandContext, &mut ValidationErrors) + Send + Sync + 'static,
+    {
+        self.validators
+            .entry(path.to_string())
+            .or_default()
+            .push(Arc::new(validator));
+        self
+    }
+
+    /// Replaces the template used to render validation errors.
+    ///
+    /// The template receives `errors`, a list with the `arg` (if any) and
+    /// `message` of each problem, and is styled with the app theme on top of
+    /// the help styles, plus `invalid` for the label.
+    pub fn validation_template(mut self, template: impl Into<String>) -> Self {
+        self.validation_template = Some(template.into());
+        self
+    }
+
+    /// Runs the validators of the command at `path`, returning the failure
+    /// to report if any found a problem.
+    pub(crate) fn run_validators(
+        &self,
+        path: &str,
+        matches: &ArgMatches,
+        ctx: &CommandContext,
+        output_mode: OutputMode,
+    ) -> Option<RunResult> {
+        let validators = self.validators.get(path)?;
+        let mut errors = ValidationErrors::new();
+        for validator in validators {
+            validator(matches, ctx, &mut errors);
+        }
+        if errors.is_empty() {
+            return None;
+        }
+        Some(RunResult::failure(
+            self.render_validation_failure(&errors, output_mode),
+            ExitError::USAGE,
+        ))
+    }
+
+    fn render_validation_failure(
+        &self,
+        errors: &ValidationErrors,
+        output_mode: OutputMode,
+    ) -> String {
+        if output_mode == OutputMode::Json {
+            return errors.to_json().to_string();
+        }
+        let theme = match &self.theme {
+            Some(theme) => default_validation_theme().merge(theme.clone()),
+            None => default_validation_theme(),
+        };
+        let mode = if output_mode != OutputMode::Text
+            && console::Term::stderr().features().colors_supported()
+        {
+            OutputMode::Term
+        } else {
+            OutputMode::Text
+        };
+        let template = self
+            .validation_template
+            .as_deref()
+            .unwrap_or(DEFAULT_VALIDATION_TEMPLATE);
+        render_validation_errors(errors, template, &theme, mode).unwrap_or_else(|_| {
+            errors
+                .errors
+                .iter()
+                .map(|error| match &error.arg {
+                    Some(arg) => format!("error: {}: {}", arg, error.message),
+                    None => format!("error: {}", error.message),
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::handler::Output;
+    use clap::{Arg, Command};
+
+    fn serve_app() -> AppBuilder {
+        AppBuilder::new()
+            .command(
+                "serve",
+                |m, _ctx| {
+                    let port = m.get_one::<String>("port").unwrap().clone();
+                    Ok(Output::Render(serde_json::json!({ "port": port })))
+                },
+                "listening on {{ port }}",
+            )
+            .unwrap()
+            .validate("serve", |m, _ctx, errors| {
+                if m.get_one::<String>("port").unwrap().len() > 5 {
+                    errors.arg("--port", "is too long");
+                }
+            })
+            .validate("serve", |m, _ctx, errors| {
+                if m.get_one::<String>("host").is_none() {
+                    errors.error("a host is required");
+                }
+            })
+    }
+
+    fn serve_command() -> Command {
+        Command::new("app").subcommand(
+            Command::new("serve")
+                .arg(Arg::new("port").long("port").default_value("80"))
+                .arg(Arg::new("host").long("host")),
+        )
+    }
+
+    #[test]
+    fn test_validators_pass() {
+        let result =
+            serve_app().dispatch_from(serve_command(), ["app", "serve", "--host", "localhost"]);
+        assert_eq!(result.output(), Some("listening on 80"));
+    }
+
+    #[test]
+    fn test_validation_errors_are_aggregated() {
+        let result = serve_app().dispatch_from(
+            serve_command(),
+            ["app", "--output", "text", "serve", "--port", "123456"],
+        );
+
+        let RunResult::Exit(output, code) = result else {
+            panic!("expected a failure, got {result:?}");
+        };
+        assert_eq!(code, ExitError::USAGE);
+        assert_eq!(
+            output,
+            "error: 2 invalid arguments\n  --port: is too long\n  a host is required"
+        );
+    }
+
+    #[test]
+    fn test_validation_errors_as_json() {
+        let result = serve_app().dispatch_from(
+            serve_command(),
+            ["app", "--output", "json", "serve", "--port", "123456"],
+        );
+
+        let RunResult::Exit(output, code) = result else {
+            panic!("expected a failure, got {result:?}");
+        };
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(code, ExitError::USAGE);
+        assert_eq!(json["error"]["code"], "validation_error");
+        assert_eq!(json["error"]["errors"].as_array().unwrap().len(), 2);
+    }
+}
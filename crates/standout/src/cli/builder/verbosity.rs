@@ -0,0 +1,164 @@
+//! `-q` / `-v` verbosity flags and the stderr logging bridge.
+//!
+//! Apps opt in with `verbosity_flags()`, which adds global `-v, --verbose`
+//! (repeatable) and `-q, --quiet` flags. The level reaches handlers as
+//! `ctx.verbosity`. With the `tracing` feature, `log_to_stderr()` also
+//! installs a subscriber whose level follows the flags (see
+//! [`crate::cli::logging`]).
+//!
+//! ```text
+//! myapp sync -q      # errors only
+//! myapp sync -vv     # debug logging
+//! ```
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+
+use super::AppBuilder;
+use crate::cli::handler::Verbosity;
+
+impl AppBuilder {
+    /// Adds global `-v, --verbose` and `-q, --quiet` flags.
+    ///
+    /// `-v` can be repeated (`-vv`, `-vvv`) and `-q` conflicts with it. The
+    /// resulting [`Verbosity`] is set on `ctx.verbosity` for every command.
+    ///
+    /// The flags are off by default so they can't clash with existing `-v`
+    /// or `-q` arguments.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .verbosity_flags()
+    ///     .command("sync", |_m, ctx| {
+    ///         if ctx.verbosity.is_verbose() {
+    ///             eprintln!("syncing...");
+    ///         }
+    ///         Ok(Output::Render(sync()?))
+    ///     }, "{{ count }} synced")?
+    ///     .build()?;
+    /// ```
+    pub fn verbosity_flags(mut self) -> Self {
+        self.verbosity_flags = true;
+        self
+    }
+
+    /// Logs `tracing` events to stderr, level-styled by the app's theme.
+    ///
+    /// A global subscriber is installed on dispatch. It logs warnings and
+    /// errors by default; with [`verbosity_flags`](Self::verbosity_flags),
+    /// `-q` keeps only errors and each `-v` adds a level (info, debug,
+    /// trace). Lines are colored when stderr is a color terminal and the
+    /// output mode isn't `text`. See [`crate::cli::logging`] for the format
+    /// and the `log-*` styles it uses.
+    ///
+    /// If the app already installed a global subscriber, it's kept.
+    #[cfg(feature = "tracing")]
+    pub fn log_to_stderr(mut self) -> Self {
+        self.log_to_stderr = true;
+        self
+    }
+
+    /// Adds the verbosity flags to the command, if enabled.
+    pub(crate) fn augment_command_with_verbosity(&self, cmd: Command) -> Command {
+        if !self.verbosity_flags {
+            return cmd;
+        }
+        cmd.arg(
+            Arg::new("_verbose")
+                .short('v')
+                .long("verbose")
+                .global(true)
+                .action(ArgAction::Count)
+                .help("Show more output (repeat for more: -vv, -vvv)"),
+        )
+        .arg(
+            Arg::new("_quiet")
+                .short('q')
+                .long("quiet")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .conflicts_with("_verbose")
+                .help("Only show errors"),
+        )
+    }
+
+    /// Reads the verbosity from the flags, or `Normal` if they're disabled.
+    pub(crate) fn verbosity_for(&self, matches: &ArgMatches) -> Verbosity {
+        if !self.verbosity_flags {
+            return Verbosity::Normal;
+        }
+        let quiet = matches
+            .try_get_one::<bool>("_quiet")
+            .ok()
+            .flatten()
+            .copied()
+            .unwrap_or(false);
+        let verbose = matches
+            .try_get_one::<u8>("_verbose")
+            .ok()
+            .flatten()
+            .copied()
+            .unwrap_or(0);
+        Verbosity::from_flags(quiet, verbose)
+    }
+
+    /// Installs the stderr logging subscriber, if enabled.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn init_logging(&self, verbosity: Verbosity, output_mode: crate::OutputMode) {
+        if !self.log_to_stderr {
+            return;
+        }
+        let use_color = output_mode != crate::OutputMode::Text
+            && console::Term::stderr().features().colors_supported();
+        let default_theme = crate::Theme::default();
+        let theme = self.theme.as_ref().unwrap_or(&default_theme);
+        crate::cli::logging::init(verbosity, theme, use_color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verbosity(builder: &AppBuilder, args: &[&str]) -> Verbosity {
+        let cmd = builder
+            .augment_command_with_verbosity(Command::new("app").subcommand(Command::new("sync")));
+        let matches = cmd.try_get_matches_from(args).unwrap();
+        builder.verbosity_for(&matches)
+    }
+
+    #[test]
+    fn test_verbosity_flags() {
+        let builder = AppBuilder::new().verbosity_flags();
+        assert_eq!(verbosity(&builder, &["app", "sync"]), Verbosity::Normal);
+        assert_eq!(
+            verbosity(&builder, &["app", "sync", "-v"]),
+            Verbosity::Verbose
+        );
+        assert_eq!(
+            verbosity(&builder, &["app", "-vv", "sync"]),
+            Verbosity::Debug
+        );
+        assert_eq!(
+            verbosity(&builder, &["app", "sync", "--quiet"]),
+            Verbosity::Quiet
+        );
+    }
+
+    #[test]
+    fn test_quiet_conflicts_with_verbose() {
+        let builder = AppBuilder::new().verbosity_flags();
+        let cmd = builder.augment_command_with_verbosity(Command::new("app"));
+        assert!(cmd.try_get_matches_from(["app", "-q", "-v"]).is_err());
+    }
+
+    #[test]
+    fn test_verbosity_flags_disabled_by_default() {
+        let builder = AppBuilder::new();
+        let cmd = builder.augment_command_with_verbosity(Command::new("app"));
+        assert!(cmd.clone().try_get_matches_from(["app", "-v"]).is_err());
+        let matches = cmd.try_get_matches_from(["app"]).unwrap();
+        assert_eq!(builder.verbosity_for(&matches), Verbosity::Normal);
+    }
+}
@@ -0,0 +1,137 @@
+//! Rich `--version` output.
+//!
+//! With [`build_info`](AppBuilder::build_info) set, `--version` / `-V` is
+//! rendered from a [`BuildInfo`] through a themed template (see
+//! [`crate::cli::version`]) instead of clap's bare `name version` line.
+
+use std::ffi::OsString;
+
+use clap::Command;
+
+use super::{execution, AppBuilder};
+use crate::cli::help::default_help_theme;
+use crate::cli::version::{render_version, BuildInfo, DEFAULT_VERSION_TEMPLATE};
+use crate::OutputMode;
+
+impl AppBuilder {
+    /// Renders `--version` from `info`: version, git SHA, build date, target
+    /// and enabled features.
+    ///
+    /// The command's `--version` flag is enabled with `info.version` if it
+    /// doesn't have one. `--version --output=json` serializes `info`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .build_info(standout::build_info!().with_features(["sync"]))
+    ///     .build()?;
+    /// // myapp --version
+    /// ```
+    pub fn build_info(mut self, info: BuildInfo) -> Self {
+        self.build_info = Some(info);
+        self
+    }
+
+    /// Replaces the template used to render `--version`.
+    ///
+    /// The template receives the [`BuildInfo`] fields (`name`, `version`,
+    /// `git_sha`, `build_date`, `target`, `features`) and is styled with the
+    /// help theme.
+    pub fn version_template(mut self, template: impl Into<String>) -> Self {
+        self.version_template = Some(template.into());
+        self
+    }
+
+    /// Sets the command's version from the build info if it has none.
+    pub(crate) fn augment_command_with_version(&self, cmd: Command) -> Command {
+        match &self.build_info {
+            Some(info) if cmd.get_version().is_none() => {
+                let version: &'static str = Box::leak(info.version.clone().into_boxed_str());
+                cmd.version(version)
+            }
+            _ => cmd,
+        }
+    }
+
+    /// Renders the version output for a `--version` request, or `None` if no
+    /// build info is registered or rendering fails.
+    ///
+    /// Clap stops parsing at `--version`, so the output mode is read from the
+    /// raw args, falling back to the output environment variable.
+    pub(crate) fn render_version_for_args(&self, args: &[OsString]) -> Option<String> {
+        let info = self.build_info.as_ref()?;
+        let mode = self
+            .output_flag
+            .as_deref()
+            .and_then(|flag| Self::flag_value_from_args(args, flag))
+            .and_then(|value| execution::parse_output_mode(&value))
+            .or_else(|| self.output_mode_from_env())
+            .unwrap_or(OutputMode::Auto);
+        let theme = self.theme.clone().unwrap_or_else(default_help_theme);
+        let template = self
+            .version_template
+            .as_deref()
+            .unwrap_or(DEFAULT_VERSION_TEMPLATE);
+        render_version(info, template, &theme, mode).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app() -> AppBuilder {
+        AppBuilder::new().build_info(
+            BuildInfo::new("app", "1.2.3")
+                .with_git_sha("3f2a9c1")
+                .with_features(["sync"]),
+        )
+    }
+
+    #[test]
+    fn test_version_flag_renders_build_info() {
+        let result =
+            app().dispatch_from(Command::new("app"), ["app", "--version", "--output=text"]);
+        let output = result.output().expect("version output");
+
+        assert!(output.starts_with("app 1.2.3\n"), "{output}");
+        assert!(output.contains("commit:   3f2a9c1"), "{output}");
+        assert!(output.contains("features: sync"), "{output}");
+    }
+
+    #[test]
+    fn test_version_flag_json() {
+        let result = app().dispatch_from(Command::new("app"), ["app", "--output", "json", "-V"]);
+        let value: serde_json::Value =
+            serde_json::from_str(result.output().expect("version output")).unwrap();
+
+        assert_eq!(value["version"], "1.2.3");
+        assert_eq!(value["features"][0], "sync");
+    }
+
+    #[test]
+    fn test_version_template_override() {
+        let app = app().version_template("{{ name }} v{{ version }} ({{ git_sha }})");
+        let result = app.dispatch_from(Command::new("app"), ["app", "--version", "--output=text"]);
+
+        assert_eq!(result.output(), Some("app v1.2.3 (3f2a9c1)"));
+    }
+
+    #[test]
+    fn test_version_flag_through_get_matches() {
+        let result = app().get_matches_from(Command::new("app"), ["app", "--version"]);
+        match result {
+            crate::cli::HelpResult::Help(output) => assert!(output.contains("3f2a9c1")),
+            other => panic!("expected version output, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_without_build_info_clap_version_is_kept() {
+        let cmd = Command::new("app").version("0.1.0");
+        let result = AppBuilder::new().dispatch_from(cmd, ["app", "--version"]);
+
+        assert_eq!(result.output().map(str::trim), Some("app 0.1.0"));
+    }
+}
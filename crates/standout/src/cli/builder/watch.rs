@@ -0,0 +1,255 @@
+//! `--watch`: re-run a command when files change.
+//!
+//! Apps opt in with `watch(paths)`, which adds a global `--watch` flag. With
+//! it, `run()` clears the screen, runs the command, and waits for a watched
+//! file to change before running it again, until Ctrl-C:
+//!
+//! ```text
+//! myapp status --watch
+//! ```
+//!
+//! Changes are found by polling modification times and sizes, so no
+//! platform file-notification support is needed. Directories are watched
+//! recursively.
+
+use std::collections::{BTreeMap, HashSet};
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use clap::{Arg, ArgAction, Command};
+
+use super::execution::RunState;
+use super::{interrupt, AppBuilder};
+use crate::cli::handler::RunResult;
+use crate::OutputMode;
+
+/// How often watched paths are checked for changes by default.
+pub(crate) const DEFAULT_WATCH_INTERVAL: Duration = Duration::from_millis(300);
+
+impl AppBuilder {
+    /// Adds a global `--watch` flag that re-runs the command when any of
+    /// `paths` changes.
+    ///
+    /// Directories are watched recursively. With `--watch`,
+    /// [`run`](Self::run) clears the screen and re-renders the command after
+    /// every change; failures are printed and watching continues. Ctrl-C
+    /// stops it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// App::builder()
+    ///     .watch(["todos.json", "templates"])
+    ///     .command("status", status, "{{ open }} open")?
+    ///     .build()?;
+    /// // myapp status --watch
+    /// ```
+    pub fn watch<I, P>(mut self, paths: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<PathBuf>,
+    {
+        self.watch_paths.extend(paths.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets how often watched paths are checked for changes (default 300ms).
+    pub fn watch_interval(mut self, interval: Duration) -> Self {
+        self.watch_interval = interval;
+        self
+    }
+
+    /// Adds the `--watch` flag to the command, if any paths are watched.
+    pub(crate) fn augment_command_with_watch(&self, cmd: Command) -> Command {
+        if self.watch_paths.is_empty() {
+            return cmd;
+        }
+        cmd.arg(
+            Arg::new("_watch")
+                .long("watch")
+                .global(true)
+                .action(ArgAction::SetTrue)
+                .help("Re-run the command when watched files change"),
+        )
+    }
+
+    /// Whether `args` ask for `--watch`.
+    pub(crate) fn watch_requested(&self, cmd: &Command, args: &[OsString]) -> bool {
+        if self.watch_paths.is_empty() {
+            return false;
+        }
+        self.augment_command_for_dispatch(cmd.clone())
+            .try_get_matches_from(args)
+            .ok()
+            .and_then(|matches| {
+                matches
+                    .try_get_one::<bool>("_watch")
+                    .ok()
+                    .flatten()
+                    .copied()
+            })
+            .unwrap_or(false)
+    }
+
+    /// Runs the command, then again after every change to the watched paths.
+    ///
+    /// Returns `false` if no handler matched; otherwise only returns through
+    /// Ctrl-C.
+    pub(crate) fn run_watch(&self, cmd: Command, args: Vec<OsString>) -> bool {
+        let mut snapshot = Snapshot::take(&self.watch_paths);
+        loop {
+            if standout_render::detect_is_tty() {
+                let _ = console::Term::stdout().clear_screen();
+            }
+
+            let mut run = RunState::default();
+            let result = self.dispatch_from_with_mode(cmd.clone(), args.clone(), None, &mut run);
+            if let RunResult::NoMatch(_) = result {
+                return false;
+            }
+            for warning in self.deprecation_warnings(&run) {
+                eprintln!("{}", warning);
+            }
+            self.print_result(&result);
+            self.print_timings(&run);
+            let default_theme = crate::Theme::default();
+            let theme = self.theme.as_ref().unwrap_or(&default_theme);
+            standout_render::warnings::flush_to_stderr(theme, OutputMode::Auto);
+
+            if interrupt::take_interrupted() {
+                std::process::exit(interrupt::INTERRUPTED_EXIT_CODE);
+            }
+
+            snapshot = snapshot.wait_for_change(&self.watch_paths, self.watch_interval);
+        }
+    }
+}
+
+/// Modification times and sizes of every file under the watched paths.
+#[derive(Debug, PartialEq, Eq)]
+struct Snapshot(BTreeMap<PathBuf, (Option<SystemTime>, u64)>);
+
+impl Snapshot {
+    fn take(paths: &[PathBuf]) -> Self {
+        let mut files = BTreeMap::new();
+        let mut visited = HashSet::new();
+        for path in paths {
+            collect(path, &mut files, &mut visited);
+        }
+        Self(files)
+    }
+
+    /// Polls until the watched files differ from this snapshot, returning
+    /// the new one.
+    fn wait_for_change(self, paths: &[PathBuf], interval: Duration) -> Self {
+        loop {
+            std::thread::sleep(interval);
+            let current = Self::take(paths);
+            if current != self {
+                return current;
+            }
+        }
+    }
+}
+
+/// Records the files under `path`, following symlinks.
+///
+/// `visited` holds the canonical paths of the directories already read, so a
+/// directory reached twice (through a symlink loop, say) is only read once.
+fn collect(
+    path: &Path,
+    files: &mut BTreeMap<PathBuf, (Option<SystemTime>, u64)>,
+    visited: &mut HashSet<PathBuf>,
+) {
+    let Ok(metadata) = std::fs::metadata(path) else {
+        return;
+    };
+    if metadata.is_dir() {
+        let Ok(canonical) = std::fs::canonicalize(path) else {
+            return;
+        };
+        if !visited.insert(canonical) {
+            return;
+        }
+        if let Ok(entries) = std::fs::read_dir(path) {
+            for entry in entries.flatten() {
+                collect(&entry.path(), files, visited);
+            }
+        }
+    } else {
+        files.insert(
+            path.to_path_buf(),
+            (metadata.modified().ok(), metadata.len()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_sees_changes_in_watched_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("nested");
+        std::fs::create_dir(&nested).unwrap();
+        std::fs::write(nested.join("a.txt"), "one").unwrap();
+        let paths = vec![dir.path().to_path_buf()];
+
+        let before = Snapshot::take(&paths);
+        assert_eq!(before.0.len(), 1);
+        assert_eq!(Snapshot::take(&paths), before);
+
+        std::fs::write(nested.join("a.txt"), "three").unwrap();
+        assert_ne!(Snapshot::take(&paths), before);
+
+        std::fs::write(dir.path().join("b.txt"), "new").unwrap();
+        assert_eq!(Snapshot::take(&paths).0.len(), 2);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_snapshot_survives_symlink_loops() {
+        let dir = tempfile::tempdir().unwrap();
+        let nested = dir.path().join("nested");
+        std::fs::create_dir(&nested).unwrap();
+        std::fs::write(nested.join("a.txt"), "one").unwrap();
+        std::os::unix::fs::symlink(dir.path(), nested.join("up")).unwrap();
+        std::os::unix::fs::symlink(&nested, nested.join("self")).unwrap();
+        let paths = vec![dir.path().to_path_buf()];
+
+        let snapshot = Snapshot::take(&paths);
+        assert_eq!(
+            snapshot.0.keys().collect::<Vec<_>>(),
+            [&nested.join("a.txt")]
+        );
+    }
+
+    #[test]
+    fn test_missing_paths_are_ignored_until_created() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("later.json");
+        let paths = vec![file.clone()];
+
+        let before = Snapshot::take(&paths);
+        assert!(before.0.is_empty());
+
+        std::fs::write(&file, "{}").unwrap();
+        assert_ne!(Snapshot::take(&paths), before);
+    }
+
+    #[test]
+    fn test_watch_flag() {
+        let cmd = Command::new("app").subcommand(Command::new("status"));
+        let args = |list: &[&str]| list.iter().map(OsString::from).collect::<Vec<_>>();
+
+        let builder = AppBuilder::new().watch(["todos.json"]);
+        assert!(builder.watch_requested(&cmd, &args(&["app", "status", "--watch"])));
+        assert!(!builder.watch_requested(&cmd, &args(&["app", "status"])));
+
+        // Without watched paths there's no flag
+        let builder = AppBuilder::new();
+        assert!(!builder.watch_requested(&cmd, &args(&["app", "status", "--watch"])));
+    }
+}
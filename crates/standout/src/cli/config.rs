@@ -0,0 +1,406 @@
+//! Layered configuration files.
+//!
+//! A [`ConfigLoader`] (registered with [`App::config`](crate::cli::App::config))
+//! merges configuration from several sources before each dispatch and makes
+//! the result available to handlers as `ctx.config::<T>()`. Later sources
+//! override earlier ones, key by key:
+//!
+//! 1. Defaults: the `#[serde(default)]` values of `T`
+//! 2. The config file: the file given with the `--config` flag if enabled,
+//!    else the first of `config.toml`, `config.yaml`, `config.yml` and
+//!    `config.json` in the app's config directory (see [`crate::cli::paths`])
+//! 3. Environment variables: `MYAPP_EDITOR=vim` sets `editor`, and `__`
+//!    separates nested keys (`MYAPP_SYNC__REMOTE` sets `sync.remote`). The
+//!    directory overrides (`MYAPP_CONFIG_DIR` and friends) are skipped.
+//! 4. `--set key=value` flags, if enabled (`--set sync.remote=origin`)
+//!
+//! Values from environment variables and flags are kept as strings.
+//! `ctx.config::<T>()` reads them as numbers or booleans where `T` has such
+//! a field, so a `String` field can still hold `1234` or `true`.
+//!
+//! While a command runs, the merged configuration is also what
+//! [`ConfigSource`](crate::input::ConfigSource) reads, so input chains can
+//! fall back to it.
+//!
+//! ```rust,ignore
+//! #[derive(Deserialize)]
+//! struct Settings {
+//!     #[serde(default = "default_editor")]
+//!     editor: String,
+//! }
+//!
+//! App::builder()
+//!     .config(ConfigLoader::new("myapp").file_flag("config").set_flag("set"))
+//!     .command("edit", |_m, ctx| {
+//!         let settings: Settings = ctx.config()?;
+//!         ...
+//!     }, "...")?
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde_json::{Map, Value};
+
+use crate::cli::paths::DIR_OVERRIDES;
+
+/// Error loading configuration.
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    /// A config file couldn't be read.
+    #[error("cannot read {path}: {source}")]
+    Read {
+        /// The file.
+        path: PathBuf,
+        /// The I/O error.
+        source: std::io::Error,
+    },
+    /// A config file isn't valid TOML, YAML or JSON.
+    #[error("invalid config file {path}: {message}")]
+    Parse {
+        /// The file.
+        path: PathBuf,
+        /// What's wrong with it.
+        message: String,
+    },
+    /// A `--set` override isn't `key=value`.
+    #[error("invalid config override `{0}`: expected key=value")]
+    InvalidOverride(String),
+}
+
+/// Loads and merges an app's configuration.
+#[derive(Debug, Clone)]
+pub struct ConfigLoader {
+    name: String,
+    env_prefix: String,
+    dir: Option<PathBuf>,
+    pub(crate) file_flag: Option<String>,
+    pub(crate) set_flag: Option<String>,
+}
+
+impl ConfigLoader {
+    /// Creates a loader for the app `name`.
+    ///
+    /// The environment prefix defaults to the name in upper case, with `-`
+    /// replaced by `_` (`my-app` reads `MY_APP_*`).
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+        let env_prefix = crate::cli::paths::env_prefix(&name);
+        Self {
+            name,
+            env_prefix,
+            dir: None,
+            file_flag: None,
+            set_flag: None,
+        }
+    }
+
+    /// Returns the app name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Sets the prefix of environment variable overrides (without the
+    /// trailing `_`).
+    pub fn env_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.env_prefix = prefix.into();
+        self
+    }
+
+    /// Looks for the config file in `dir` instead of the app's config
+    /// directory.
+    pub fn dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dir = Some(dir.into());
+        self
+    }
+
+    /// Adds a `--<flag> <FILE>` option that replaces the default config file.
+    pub fn file_flag(mut self, flag: impl Into<String>) -> Self {
+        self.file_flag = Some(flag.into());
+        self
+    }
+
+    /// Adds a repeatable `--<flag> <KEY=VALUE>` option overriding single keys.
+    pub fn set_flag(mut self, flag: impl Into<String>) -> Self {
+        self.set_flag = Some(flag.into());
+        self
+    }
+
+    /// Returns the directory the config file is looked for in.
+    pub fn config_dir(&self) -> PathBuf {
+        match self.dir {
+            Some(ref dir) => dir.clone(),
+            None => crate::cli::paths::app_paths(&self.name).config,
+        }
+    }
+
+    /// Returns the config file that exists in the config directory, if any.
+    pub fn default_file(&self) -> Option<PathBuf> {
+        let dir = self.config_dir();
+        ["config.toml", "config.yaml", "config.yml", "config.json"]
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|path| path.is_file())
+    }
+
+    /// Merges the configuration from the file, the process environment and
+    /// `overrides` (`key=value` strings).
+    ///
+    /// `file` replaces the [`default_file`](Self::default_file) when given.
+    pub fn load(&self, file: Option<&Path>, overrides: &[String]) -> Result<Value, ConfigError> {
+        self.load_from(file, std::env::vars(), overrides)
+    }
+
+    /// Like [`load`](Self::load), with the environment given explicitly.
+    pub fn load_from(
+        &self,
+        file: Option<&Path>,
+        env: impl IntoIterator<Item = (String, String)>,
+        overrides: &[String],
+    ) -> Result<Value, ConfigError> {
+        let mut config = Value::Object(Map::new());
+
+        let file = file.map(Path::to_path_buf).or_else(|| self.default_file());
+        if let Some(path) = file {
+            merge(&mut config, read_file(&path)?);
+        }
+
+        let prefix = format!("{}_", self.env_prefix);
+        let mut env: Vec<_> = env
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let key = key.strip_prefix(&prefix)?;
+                if DIR_OVERRIDES.contains(&key) {
+                    return None;
+                }
+                Some((key.to_lowercase().replace("__", "."), value))
+            })
+            .collect();
+        env.sort();
+        for (key, value) in env {
+            set_path(&mut config, &key, Value::String(value));
+        }
+
+        for item in overrides {
+            let (key, value) = item
+                .split_once('=')
+                .filter(|(key, _)| !key.trim().is_empty())
+                .ok_or_else(|| ConfigError::InvalidOverride(item.clone()))?;
+            set_path(&mut config, key.trim(), Value::String(value.to_string()));
+        }
+
+        Ok(config)
+    }
+}
+
+/// Makes a loaded configuration readable by `ConfigSource` on this thread
+/// until the returned scope is dropped.
+pub(crate) fn scope_input_config(config: &Value) -> standout_input::ConfigReaderScope {
+    standout_input::scope_config_reader(Arc::new(InputConfig(config.clone())))
+}
+
+/// A loaded configuration, read by dotted path.
+struct InputConfig(Value);
+
+impl standout_input::env::ConfigReader for InputConfig {
+    fn get(&self, path: &str) -> Option<String> {
+        let value = path
+            .split('.')
+            .try_fold(&self.0, |value, key| value.get(key))?;
+        match value {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            Value::Bool(b) => Some(b.to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// Reads a TOML, YAML or JSON file, by extension.
+fn read_file(path: &Path) -> Result<Value, ConfigError> {
+    let content = std::fs::read_to_string(path).map_err(|source| ConfigError::Read {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let parse_error = |message: String| ConfigError::Parse {
+        path: path.to_path_buf(),
+        message,
+    };
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("yaml" | "yml") => {
+            serde_yaml::from_str(&content).map_err(|e| parse_error(e.to_string()))
+        }
+        Some("json") => serde_json::from_str(&content).map_err(|e| parse_error(e.to_string())),
+        _ => toml::from_str(&content).map_err(|e| parse_error(e.to_string())),
+    }
+}
+
+/// Merges `other` into `base`, recursing into tables.
+fn merge(base: &mut Value, other: Value) {
+    match (base, other) {
+        (Value::Object(base), Value::Object(other)) => {
+            for (key, value) in other {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, other) => *base = other,
+    }
+}
+
+/// Sets the dotted `path` in `config`, creating tables as needed.
+fn set_path(config: &mut Value, path: &str, value: Value) {
+    let mut current = config;
+    for key in path.split('.') {
+        if !current.is_object() {
+            *current = Value::Object(Map::new());
+        }
+        current = current
+            .as_object_mut()
+            .expect("just made an object")
+            .entry(key)
+            .or_insert(Value::Null);
+    }
+    *current = value;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn env(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_layers_override_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("config.toml"),
+            "editor = \"nano\"\nlimit = 10\n[sync]\nremote = \"origin\"\nbranch = \"main\"\n",
+        )
+        .unwrap();
+        let loader = ConfigLoader::new("my-app").dir(dir.path());
+
+        let config = loader
+            .load_from(
+                None,
+                env(&[
+                    ("MY_APP_LIMIT", "20"),
+                    ("MY_APP_SYNC__REMOTE", "upstream"),
+                    ("OTHER_LIMIT", "99"),
+                    ("MY_APP_CACHE_DIR", "/tmp/my-app"),
+                ]),
+                &["sync.branch=dev".to_string(), "verbose=true".to_string()],
+            )
+            .unwrap();
+
+        assert_eq!(
+            config,
+            json!({
+                "editor": "nano",
+                "limit": "20",
+                "sync": { "remote": "upstream", "branch": "dev" },
+                "verbose": "true",
+            })
+        );
+    }
+
+    #[test]
+    fn test_overrides_take_the_settings_field_type() {
+        use crate::cli::handler::{CommandContext, ConfigValues};
+
+        #[derive(serde::Deserialize)]
+        struct Settings {
+            limit: u32,
+            verbose: bool,
+            token: String,
+            version: String,
+        }
+
+        let loader = ConfigLoader::new("my-app").dir(tempfile::tempdir().unwrap().path());
+        let config = loader
+            .load_from(
+                None,
+                env(&[
+                    ("MY_APP_LIMIT", "20"),
+                    ("MY_APP_TOKEN", "12345678901234567890"),
+                ]),
+                &["verbose=true".to_string(), "version=1.0".to_string()],
+            )
+            .unwrap();
+        let mut ctx = CommandContext::default();
+        ctx.extensions.insert(ConfigValues(config));
+
+        let settings: Settings = ctx.config().unwrap();
+        assert_eq!(settings.limit, 20);
+        assert!(settings.verbose);
+        assert_eq!(settings.token, "12345678901234567890");
+        assert_eq!(settings.version, "1.0");
+    }
+
+    #[test]
+    fn test_explicit_yaml_file_replaces_default() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("config.toml"), "editor = \"nano\"").unwrap();
+        let other = dir.path().join("work.yaml");
+        std::fs::write(&other, "limit: 5\n").unwrap();
+        let loader = ConfigLoader::new("myapp").dir(dir.path());
+
+        let config = loader.load_from(Some(&other), env(&[]), &[]).unwrap();
+
+        assert_eq!(config, json!({ "limit": 5 }));
+    }
+
+    #[test]
+    fn test_missing_config_is_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let loader = ConfigLoader::new("myapp").dir(dir.path().join("nope"));
+        assert_eq!(loader.load_from(None, env(&[]), &[]).unwrap(), json!({}));
+    }
+
+    #[test]
+    fn test_input_config_reads_scalars_by_path() {
+        use standout_input::env::ConfigReader;
+
+        let config = InputConfig(json!({
+            "user": { "name": "ada", "admin": true },
+            "limit": 5,
+            "tags": ["a"],
+        }));
+        assert_eq!(config.get("user.name").as_deref(), Some("ada"));
+        assert_eq!(config.get("user.admin").as_deref(), Some("true"));
+        assert_eq!(config.get("limit").as_deref(), Some("5"));
+        assert_eq!(config.get("tags"), None);
+        assert_eq!(config.get("user"), None);
+        assert_eq!(config.get("user.email"), None);
+    }
+
+    #[test]
+    fn test_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("config.toml"), "editor = ").unwrap();
+        let loader = ConfigLoader::new("myapp").dir(dir.path());
+        assert!(matches!(
+            loader.load_from(None, env(&[]), &[]),
+            Err(ConfigError::Parse { .. })
+        ));
+
+        let loader = ConfigLoader::new("myapp").dir(dir.path().join("nope"));
+        assert!(matches!(
+            loader.load_from(None, env(&[]), &["oops".to_string()]),
+            Err(ConfigError::InvalidOverride(_))
+        ));
+        assert!(matches!(
+            loader.load_from(Some(&dir.path().join("missing.toml")), env(&[]), &[]),
+            Err(ConfigError::Read { .. })
+        ));
+    }
+}
@@ -0,0 +1,145 @@
+//! Styled crash reports for panics.
+//!
+//! A panic normally prints Rust's `thread 'main' panicked at ...` line, which
+//! reads like an internal error dump to end users. After
+//! [`App::install_panic_hook`](crate::cli::App::install_panic_hook), panics
+//! are rendered through a themed template on stderr instead:
+//!
+//! ```text
+//! myapp crashed 1.2.3
+//!   message:  index out of bounds: the len is 0 but the index is 0
+//!   location: src/store.rs:42:17
+//!
+//! Run with RUST_BACKTRACE=1 to include a backtrace.
+//! This is a bug. Please report it at https://github.com/me/myapp/issues
+//! ```
+//!
+//! The process then exits with [`CRASH_EXIT_CODE`], so scripts can tell a
+//! crash apart from an ordinary failure.
+
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::panic::PanicHookInfo;
+
+use console::Style;
+use serde::Serialize;
+
+use crate::{render_auto, OutputMode, RenderError, Theme};
+
+/// Default template for crash reports.
+pub const DEFAULT_CRASH_TEMPLATE: &str = include_str!("crash.txt");
+
+/// Exit code after a crash report (the same code Rust uses for panics).
+pub const CRASH_EXIT_CODE: i32 = 101;
+
+/// What a crash report shows.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct CrashReport {
+    /// Program name.
+    pub name: String,
+    /// Program version, if known.
+    pub version: Option<String>,
+    /// The panic message.
+    pub message: String,
+    /// Where the panic happened (`file:line:column`).
+    pub location: Option<String>,
+    /// Captured backtrace, when enabled with `RUST_BACKTRACE`.
+    pub backtrace: Option<String>,
+    /// Where users should report the crash.
+    pub bug_report_url: Option<String>,
+}
+
+impl CrashReport {
+    /// Builds a report from a panic, capturing a backtrace if `RUST_BACKTRACE`
+    /// enables one.
+    pub fn from_panic(name: impl Into<String>, info: &PanicHookInfo<'_>) -> Self {
+        let payload = info.payload();
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Box<dyn Any>".to_string());
+        let backtrace = Backtrace::capture();
+
+        Self {
+            name: name.into(),
+            message,
+            location: info
+                .location()
+                .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column())),
+            backtrace: (backtrace.status() == BacktraceStatus::Captured)
+                .then(|| backtrace.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+/// Returns the theme crash reports are rendered with: the help styles plus
+/// `crash` for the headline.
+pub fn default_crash_theme() -> Theme {
+    crate::cli::help::default_help_theme().add("crash", Style::new().red().bold())
+}
+
+/// Renders a crash report through `template`.
+pub fn render_crash_report(
+    report: &CrashReport,
+    template: &str,
+    theme: &Theme,
+    mode: OutputMode,
+) -> Result<String, RenderError> {
+    render_auto(template, report, theme, mode).map(|output| output.trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> CrashReport {
+        CrashReport {
+            name: "myapp".into(),
+            version: Some("1.2.3".into()),
+            message: "disk on fire".into(),
+            location: Some("src/store.rs:42:17".into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_render_crash_report_text() {
+        let output = render_crash_report(
+            &report(),
+            DEFAULT_CRASH_TEMPLATE,
+            &default_crash_theme(),
+            OutputMode::Text,
+        )
+        .unwrap();
+
+        assert!(output.starts_with("myapp crashed 1.2.3\n"), "{output}");
+        assert!(output.contains("message:  disk on fire"), "{output}");
+        assert!(output.contains("location: src/store.rs:42:17"), "{output}");
+        assert!(output.contains("RUST_BACKTRACE=1"), "{output}");
+        assert!(!output.contains("report it"), "{output}");
+    }
+
+    #[test]
+    fn test_render_crash_report_with_backtrace_and_url() {
+        let report = CrashReport {
+            backtrace: Some("   0: myapp::main".into()),
+            bug_report_url: Some("https://example.com/issues".into()),
+            ..report()
+        };
+        let output = render_crash_report(
+            &report,
+            DEFAULT_CRASH_TEMPLATE,
+            &default_crash_theme(),
+            OutputMode::Text,
+        )
+        .unwrap();
+
+        assert!(output.contains("0: myapp::main"), "{output}");
+        assert!(!output.contains("RUST_BACKTRACE"), "{output}");
+        assert!(
+            output.ends_with("Please report it at https://example.com/issues"),
+            "{output}"
+        );
+    }
+}
@@ -0,0 +1,101 @@
+//! Deprecation notices for commands, aliases and flags.
+//!
+//! Commands, aliases and flags marked with
+//! [`App::deprecated`](crate::cli::App::deprecated) or
+//! [`App::deprecated_arg`](crate::cli::App::deprecated_arg) keep working, but
+//! using one prints a themed warning on stderr before the output:
+//!
+//! ```text
+//! warning: alias `ls` is deprecated, use `list` instead
+//! ```
+//!
+//! Each notice is printed once per invocation that uses the item, including
+//! every run under `--watch` and every line of the interactive shell. Help
+//! shows the notice next to the command or flag.
+
+use console::Style;
+use serde::Serialize;
+
+use crate::{render_auto, OutputMode, RenderError, Theme};
+
+/// Default template for deprecation warnings.
+pub const DEFAULT_DEPRECATION_TEMPLATE: &str = include_str!("deprecation.txt");
+
+/// A deprecated command, alias or flag that was used.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Deprecation {
+    /// What was used, e.g. ``command `db migrate` `` or ``flag `--force` ``.
+    pub subject: String,
+    /// The replacement hint, e.g. ``use `db upgrade` instead``.
+    pub note: String,
+}
+
+/// Returns the notice help shows for a deprecation with `note`.
+pub fn help_notice(note: &str) -> String {
+    if note.is_empty() {
+        "[deprecated]".to_string()
+    } else {
+        format!("[deprecated: {}]", note)
+    }
+}
+
+/// Returns the theme deprecation warnings are rendered with: the help styles
+/// plus `deprecated` for the `warning:` label.
+pub fn default_deprecation_theme() -> Theme {
+    crate::cli::help::default_help_theme().add("deprecated", Style::new().yellow().bold())
+}
+
+/// Renders a deprecation warning through `template`.
+pub fn render_deprecation(
+    deprecation: &Deprecation,
+    template: &str,
+    theme: &Theme,
+    mode: OutputMode,
+) -> Result<String, RenderError> {
+    render_auto(template, deprecation, theme, mode).map(|output| output.trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_deprecation() {
+        let deprecation = Deprecation {
+            subject: "alias `ls`".into(),
+            note: "use `list` instead".into(),
+        };
+        let output = render_deprecation(
+            &deprecation,
+            DEFAULT_DEPRECATION_TEMPLATE,
+            &default_deprecation_theme(),
+            OutputMode::Text,
+        )
+        .unwrap();
+        assert_eq!(
+            output,
+            "warning: alias `ls` is deprecated, use `list` instead"
+        );
+
+        let output = render_deprecation(
+            &Deprecation {
+                note: String::new(),
+                ..deprecation
+            },
+            DEFAULT_DEPRECATION_TEMPLATE,
+            &default_deprecation_theme(),
+            OutputMode::Text,
+        )
+        .unwrap();
+        assert_eq!(output, "warning: alias `ls` is deprecated");
+    }
+
+    #[test]
+    fn test_help_notice() {
+        assert_eq!(
+            help_notice("use `list` instead"),
+            "[deprecated: use `list` instead]"
+        );
+        assert_eq!(help_notice(""), "[deprecated]");
+    }
+}
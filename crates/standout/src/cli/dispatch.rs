@@ -2,19 +2,22 @@
 //!
 //! Internal types and functions for dispatching commands to handlers.
 //!
-//! This module provides the dispatch function type for single-threaded CLI apps:
+//! This module provides the dispatch function type for CLI apps:
 //!
-//! - [`DispatchFn`]: Dispatch using `Rc<RefCell<dyn FnMut>>` (single-threaded)
+//! - [`DispatchFn`]: Dispatch using `Arc<dyn Fn + Send + Sync>`, so apps are `Send + Sync`
 
 use clap::ArgMatches;
-use std::cell::RefCell;
-use std::rc::Rc;
+use std::io::Write;
+use std::sync::Arc;
 
-use crate::cli::handler::CommandContext;
 use crate::cli::handler::Output as HandlerOutput;
-use crate::cli::hooks::Hooks;
+use crate::cli::handler::{CommandContext, Destination, ExitError};
+use crate::cli::hooks::{Hooks, RenderRequest};
+use crate::cli::query::{self, Query, QueryHook};
+use crate::cli::timings::Timings;
 use crate::context::{ContextRegistry, RenderContext};
-use crate::Theme;
+use crate::i18n::Catalog;
+use crate::{CsvOptions, Theme};
 use serde::Serialize;
 
 // Re-export pure dispatch utilities from standout-dispatch
@@ -30,6 +33,8 @@ pub enum DispatchOutput {
         formatted: String,
         /// The raw output without ANSI codes (for piping)
         raw: String,
+        /// The stream the handler asked the output to be written to
+        destination: Destination,
     },
     /// Binary output (bytes, filename)
     Binary(Vec<u8>, String),
@@ -37,6 +42,39 @@ pub enum DispatchOutput {
     Silent,
 }
 
+/// Internal error type for dispatch functions.
+pub struct DispatchError {
+    /// The formatted error message
+    pub message: String,
+    /// Process exit code for this failure
+    pub exit_code: u8,
+}
+
+impl DispatchError {
+    /// Wraps a handler error, keeping the code of a contained [`ExitError`].
+    pub(crate) fn from_handler(err: &anyhow::Error) -> Self {
+        Self {
+            message: format!("Error: {}", err),
+            exit_code: ExitError::code_of(err),
+        }
+    }
+}
+
+impl From<String> for DispatchError {
+    fn from(message: String) -> Self {
+        Self {
+            message,
+            exit_code: 1,
+        }
+    }
+}
+
+/// Marks a dispatch whose streamed items are printed as they arrive.
+///
+/// `run()` inserts it in `ctx.extensions`; without it (e.g. `dispatch_from`)
+/// streamed items are collected into one output.
+pub(crate) struct LiveStream;
+
 /// Helper to render output from a handler.
 ///
 /// This shared logic ensures consistent hook execution, context injection, and rendering.
@@ -46,7 +84,7 @@ pub enum DispatchOutput {
 /// managed by standout.
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn render_handler_output<T: Serialize>(
-    result: Result<HandlerOutput<T>, String>,
+    result: Result<HandlerOutput<T>, anyhow::Error>,
     matches: &ArgMatches,
     ctx: &CommandContext,
     hooks: Option<&Hooks>,
@@ -55,51 +93,196 @@ pub(crate) fn render_handler_output<T: Serialize>(
     context_registry: &ContextRegistry,
     template_engine: &dyn standout_render::template::TemplateEngine,
     output_mode: crate::OutputMode,
-) -> Result<DispatchOutput, String> {
-    match result {
-        Ok(output) => match output {
-            HandlerOutput::Render(data) => {
-                let mut json_data = serde_json::to_value(&data)
-                    .map_err(|e| format!("Failed to serialize handler result: {}", e))?;
-
-                if let Some(hooks) = hooks {
-                    json_data = hooks
-                        .run_post_dispatch(matches, ctx, json_data)
-                        .map_err(|e| format!("Hook error: {}", e))?;
-                }
+) -> Result<DispatchOutput, DispatchError> {
+    // With --timings, the handler just finished and rendering starts
+    let timings = ctx.extensions.get::<Timings>();
+    let lap = |stage| {
+        if let Some(timings) = timings {
+            timings.lap(stage);
+        }
+    };
+    lap("handler");
+
+    let renderer = Renderer {
+        matches,
+        ctx,
+        hooks,
+        template,
+        theme,
+        context_registry,
+        template_engine,
+        output_mode,
+    };
+
+    let (destination, data) = match result {
+        Ok(HandlerOutput::Render(data)) => (Destination::Stdout, data),
+        Ok(HandlerOutput::RenderTo(destination, data)) => (destination, data),
+        Ok(HandlerOutput::Silent) => return Ok(DispatchOutput::Silent),
+        Ok(HandlerOutput::Binary { data, filename }) => {
+            return Ok(DispatchOutput::Binary(data, filename))
+        }
+        Ok(HandlerOutput::Stream(items)) => return renderer.render_stream(items, lap),
+        Err(e) => return Err(DispatchError::from_handler(&e)),
+    };
+
+    let request = renderer.prepare(&data)?;
+    if hooks.is_some() {
+        lap("hooks");
+    }
+
+    let (formatted, raw) = renderer.render(&request)?;
+    lap("render");
+
+    Ok(DispatchOutput::Text {
+        formatted,
+        raw,
+        destination,
+    })
+}
+
+/// What rendering one handler result needs.
+struct Renderer<'a> {
+    matches: &'a ArgMatches,
+    ctx: &'a CommandContext,
+    hooks: Option<&'a Hooks>,
+    template: &'a str,
+    theme: &'a Theme,
+    context_registry: &'a ContextRegistry,
+    template_engine: &'a dyn standout_render::template::TemplateEngine,
+    output_mode: crate::OutputMode,
+}
+
+impl Renderer<'_> {
+    /// Serializes `data`, runs post-dispatch hooks and `--query` on it, then
+    /// lets pre-render hooks pick the template and final data.
+    fn prepare<T: Serialize>(&self, data: &T) -> Result<RenderRequest, DispatchError> {
+        let mut json_data = serde_json::to_value(data)
+            .map_err(|e| format!("Failed to serialize handler result: {}", e))?;
 
-                let render_ctx = RenderContext::new(
-                    output_mode,
-                    standout_render::detect_terminal_width(),
-                    theme,
-                    &json_data,
-                );
-
-                // Use the split render function to get both formatted and raw output
-                let render_result = standout_render::template::render_auto_with_engine_split(
-                    template_engine,
-                    template,
-                    &json_data,
-                    theme,
-                    output_mode,
-                    context_registry,
-                    &render_ctx,
-                )
-                .map_err(|e| e.to_string())?;
-
-                Ok(DispatchOutput::Text {
-                    formatted: render_result.formatted,
-                    raw: render_result.raw,
-                })
+        if let Some(hooks) = self.hooks {
+            json_data = hooks
+                .run_post_dispatch(self.matches, self.ctx, json_data)
+                .map_err(|e| format!("Hook error: {}", e))?;
+        }
+
+        if let Some(Query(expr)) = self.ctx.extensions.get::<Query>() {
+            json_data = match self.ctx.extensions.get::<QueryHook>() {
+                Some(QueryHook(hook)) => hook(&json_data, expr),
+                None => query::select(&json_data, expr),
             }
-            HandlerOutput::Silent => Ok(DispatchOutput::Silent),
-            HandlerOutput::Binary { data, filename } => Ok(DispatchOutput::Binary(data, filename)),
-        },
-        Err(e) => Err(format!("Error: {}", e)),
+            .map_err(|e| format!("Query error: {}", e))?;
+        }
+
+        let request = RenderRequest {
+            template: self.template.to_string(),
+            data: json_data,
+        };
+        match self.hooks {
+            Some(hooks) => Ok(hooks
+                .run_pre_render(self.matches, self.ctx, request)
+                .map_err(|e| format!("Hook error: {}", e))?),
+            None => Ok(request),
+        }
+    }
+
+    /// Renders a prepared request, returning the formatted and raw output.
+    fn render(&self, request: &RenderRequest) -> Result<(String, String), DispatchError> {
+        let ctx = self.ctx;
+        let json_data = &request.data;
+        let render_ctx = RenderContext::new(
+            self.output_mode,
+            standout_render::detect_terminal_width(),
+            self.theme,
+            json_data,
+        )
+        .with_csv_options(
+            ctx.extensions
+                .get::<CsvOptions>()
+                .cloned()
+                .unwrap_or_default(),
+        );
+
+        // Localized apps expose the resolved messages to templates as `t`
+        let localized_registry = ctx.extensions.get::<Catalog>().map(|catalog| {
+            let mut registry = self.context_registry.clone();
+            registry.add_static("t", minijinja::Value::from_serialize(catalog.messages()));
+            registry
+        });
+
+        // Use the split render function to get both formatted and raw output
+        let render_result = standout_render::template::render_auto_with_engine_split(
+            self.template_engine,
+            &request.template,
+            json_data,
+            self.theme,
+            self.output_mode,
+            localized_registry.as_ref().unwrap_or(self.context_registry),
+            &render_ctx,
+        )
+        .map_err(|e| e.to_string())?;
+
+        Ok((render_result.formatted, render_result.raw))
+    }
+
+    /// Renders each streamed item as it arrives.
+    ///
+    /// With `--output json` each item is one line of JSON (JSON Lines). In a
+    /// live dispatch items are printed right away and the result is silent;
+    /// otherwise they're collected, one per line.
+    fn render_stream<T: Serialize>(
+        &self,
+        items: Box<dyn Iterator<Item = T>>,
+        lap: impl Fn(&'static str),
+    ) -> Result<DispatchOutput, DispatchError> {
+        let live = self.ctx.extensions.contains::<LiveStream>();
+        let mut formatted = Vec::new();
+        let mut raw = Vec::new();
+
+        for item in items {
+            lap("handler");
+            let request = self.prepare(&item)?;
+            if self.hooks.is_some() {
+                lap("hooks");
+            }
+            let (item_formatted, item_raw) = if self.output_mode == crate::OutputMode::Json {
+                let line = request.data.to_string();
+                (line.clone(), line)
+            } else {
+                self.render(&request)?
+            };
+            lap("render");
+
+            if live {
+                // A closed pipe (e.g. `| head`) ends the stream
+                let written = crate::cli::progress::suspended(|| {
+                    let mut stdout = std::io::stdout().lock();
+                    writeln!(stdout, "{}", item_formatted.trim_end_matches('\n')).is_ok()
+                        && stdout.flush().is_ok()
+                });
+                if !written {
+                    break;
+                }
+            } else {
+                formatted.push(item_formatted.trim_end_matches('\n').to_string());
+                raw.push(item_raw.trim_end_matches('\n').to_string());
+            }
+            if self.ctx.is_cancelled() {
+                break;
+            }
+        }
+
+        if live {
+            return Ok(DispatchOutput::Silent);
+        }
+        Ok(DispatchOutput::Text {
+            formatted: formatted.join("\n"),
+            raw: raw.join("\n"),
+            destination: Destination::Stdout,
+        })
     }
 }
 
-/// Type-erased dispatch function for single-threaded handlers.
+/// Type-erased dispatch function for handlers.
 ///
 /// Takes ArgMatches, CommandContext, optional Hooks, OutputMode, and Theme.
 /// The hooks parameter allows post-dispatch hooks to run between handler
@@ -107,17 +290,19 @@ pub(crate) fn render_handler_output<T: Serialize>(
 /// is render-agnostic, while output_mode is a rendering concern.
 /// Theme is passed at runtime (late binding) to ensure the correct theme is used.
 ///
-/// Uses `Rc<RefCell<_>>` and `FnMut` for single-threaded CLI apps.
-pub type DispatchFn = Rc<
-    RefCell<
-        dyn FnMut(
+/// Each handler sits behind its own lock, held only while the handler runs,
+/// so `FnMut` handlers keep their state while dispatches of the same command
+/// from several threads still render concurrently.
+pub type DispatchFn = Arc<
+    dyn Fn(
             &ArgMatches,
             &CommandContext,
             Option<&Hooks>,
             crate::OutputMode,
             &crate::Theme,
-        ) -> Result<DispatchOutput, String>,
-    >,
+        ) -> Result<DispatchOutput, DispatchError>
+        + Send
+        + Sync,
 >;
 
 /// Dispatches the command with the given context.
@@ -128,8 +313,8 @@ pub fn dispatch(
     hooks: Option<&Hooks>,
     output_mode: crate::OutputMode,
     theme: &crate::Theme,
-) -> Result<DispatchOutput, String> {
-    (dispatch_fn.borrow_mut())(matches, ctx, hooks, output_mode, theme)
+) -> Result<DispatchOutput, DispatchError> {
+    (dispatch_fn)(matches, ctx, hooks, output_mode, theme)
 }
 
 // Note: extract_command_path, get_deepest_matches, has_subcommand, insert_default_command,
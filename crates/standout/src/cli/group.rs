@@ -7,13 +7,13 @@ use crate::context::ContextRegistry;
 
 use clap::ArgMatches;
 use serde::Serialize;
-use std::cell::RefCell;
 use std::collections::HashMap;
-use std::rc::Rc;
+use std::sync::{Arc, Mutex, PoisonError};
 
-use super::dispatch::{render_handler_output, DispatchFn};
+use super::dispatch::{render_handler_output, DispatchError, DispatchFn};
 use crate::cli::handler::{CommandContext, FnHandler, Handler, HandlerResult};
 use crate::cli::hooks::{Hooks, RenderedOutput, TextOutput};
+use crate::cli::retry::with_retries;
 use standout_dispatch::verify::ExpectedArg;
 use standout_pipe::PipeTarget;
 
@@ -30,11 +30,11 @@ use standout_pipe::PipeTarget;
 /// # Implementation Notes
 ///
 /// Most implementations (`ClosureRecipe`, `StructRecipe`) can be called multiple
-/// times since they clone their Rc-wrapped handlers. However, `ErasedConfigRecipe`
+/// times since they clone their Arc-wrapped handlers. However, `ErasedConfigRecipe`
 /// is single-use due to type erasure constraints - it will panic if called twice.
 /// This is acceptable because `ensure_commands_finalized()` is guarded to run
 /// only once per builder.
-pub(crate) trait CommandRecipe {
+pub(crate) trait CommandRecipe: Send {
     /// Returns the template for this command, if explicitly set.
     #[allow(dead_code)]
     fn template(&self) -> Option<&str>;
@@ -56,7 +56,7 @@ pub(crate) trait CommandRecipe {
         &self,
         template: &str,
         context_registry: &ContextRegistry,
-        template_engine: Rc<Box<dyn standout_render::template::TemplateEngine>>,
+        template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
     ) -> DispatchFn;
 
     /// Returns the arguments expected by this command handler.
@@ -66,22 +66,22 @@ pub(crate) trait CommandRecipe {
 /// Recipe for closure-based command handlers.
 pub(crate) struct ClosureRecipe<F, T>
 where
-    F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + 'static,
+    F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + Send + 'static,
     T: Serialize + 'static,
 {
-    handler: Rc<RefCell<FnHandler<F, T>>>,
+    handler: Arc<Mutex<FnHandler<F, T>>>,
     template: Option<String>,
     hooks: Option<Hooks>,
 }
 
 impl<F, T> ClosureRecipe<F, T>
 where
-    F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + 'static,
+    F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + Send + 'static,
     T: Serialize + 'static,
 {
     pub fn new(handler: FnHandler<F, T>) -> Self {
         Self {
-            handler: Rc::new(RefCell::new(handler)),
+            handler: Arc::new(Mutex::new(handler)),
             template: None,
             hooks: None,
         }
@@ -102,7 +102,7 @@ where
 
 impl<F, T> CommandRecipe for ClosureRecipe<F, T>
 where
-    F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + 'static,
+    F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + Send + 'static,
     T: Serialize + 'static,
 {
     fn template(&self) -> Option<&str> {
@@ -121,22 +121,24 @@ where
         &self,
         template: &str,
         context_registry: &ContextRegistry,
-        template_engine: Rc<Box<dyn standout_render::template::TemplateEngine>>,
+        template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
     ) -> DispatchFn {
         let handler = self.handler.clone();
         let template = template.to_string();
         let context_registry = context_registry.clone();
 
-        Rc::new(RefCell::new(
+        Arc::new(
             move |matches: &ArgMatches,
                   ctx: &CommandContext,
                   hooks: Option<&Hooks>,
                   output_mode: crate::OutputMode,
                   theme: &crate::Theme| {
-                let result = handler
-                    .borrow_mut()
-                    .handle(matches, ctx)
-                    .map_err(|e| e.to_string());
+                let result = with_retries(ctx, theme, output_mode, || {
+                    handler
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .handle(matches, ctx)
+                });
                 render_handler_output(
                     result,
                     matches,
@@ -149,35 +151,38 @@ where
                     output_mode,
                 )
             },
-        ))
+        )
     }
 
     fn expected_args(&self) -> Vec<ExpectedArg> {
-        self.handler.borrow().expected_args()
+        self.handler
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .expected_args()
     }
 }
 
 /// Recipe for struct-based command handlers.
 pub(crate) struct StructRecipe<H, T>
 where
-    H: Handler<Output = T> + 'static,
+    H: Handler<Output = T> + Send + 'static,
     T: Serialize + 'static,
 {
-    handler: Rc<RefCell<H>>,
+    handler: Arc<Mutex<H>>,
     #[allow(dead_code)]
     template: Option<String>,
     hooks: Option<Hooks>,
-    _phantom: std::marker::PhantomData<T>,
+    _phantom: std::marker::PhantomData<fn() -> T>,
 }
 
 impl<H, T> StructRecipe<H, T>
 where
-    H: Handler<Output = T> + 'static,
+    H: Handler<Output = T> + Send + 'static,
     T: Serialize + 'static,
 {
     pub fn new(handler: H) -> Self {
         Self {
-            handler: Rc::new(RefCell::new(handler)),
+            handler: Arc::new(Mutex::new(handler)),
             template: None,
             hooks: None,
             _phantom: std::marker::PhantomData,
@@ -199,7 +204,7 @@ where
 
 impl<H, T> CommandRecipe for StructRecipe<H, T>
 where
-    H: Handler<Output = T> + 'static,
+    H: Handler<Output = T> + Send + 'static,
     T: Serialize + 'static,
 {
     fn template(&self) -> Option<&str> {
@@ -218,22 +223,24 @@ where
         &self,
         template: &str,
         context_registry: &ContextRegistry,
-        template_engine: Rc<Box<dyn standout_render::template::TemplateEngine>>,
+        template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
     ) -> DispatchFn {
         let handler = self.handler.clone();
         let template = template.to_string();
         let context_registry = context_registry.clone();
 
-        Rc::new(RefCell::new(
+        Arc::new(
             move |matches: &ArgMatches,
                   ctx: &CommandContext,
                   hooks: Option<&Hooks>,
                   output_mode: crate::OutputMode,
                   theme: &crate::Theme| {
-                let result = handler
-                    .borrow_mut()
-                    .handle(matches, ctx)
-                    .map_err(|e| e.to_string());
+                let result = with_retries(ctx, theme, output_mode, || {
+                    handler
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .handle(matches, ctx)
+                });
                 render_handler_output(
                     result,
                     matches,
@@ -246,34 +253,37 @@ where
                     output_mode,
                 )
             },
-        ))
+        )
     }
 
     fn expected_args(&self) -> Vec<ExpectedArg> {
-        self.handler.borrow().expected_args()
+        self.handler
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .expected_args()
     }
 }
 
 /// Wrapper around ErasedCommandConfig that implements CommandRecipe.
 ///
 /// This allows group-registered commands to use the deferred closure pattern.
-/// The inner config is wrapped in RefCell to allow interior mutability.
+/// The inner config is wrapped in a Mutex to allow interior mutability.
 ///
 /// # Single-Use Constraint
 ///
 /// Unlike `ClosureRecipe` and `StructRecipe`, this implementation can only
 /// have `create_dispatch` called once. This is because `ErasedCommandConfig::register`
-/// consumes `Box<Self>`, so we must use `.take()` to extract it from the RefCell.
+/// consumes `Box<Self>`, so we must use `.take()` to extract it from the Mutex.
 ///
 /// This constraint is safe because `ensure_commands_finalized()` in `AppBuilder`
 /// is guarded to run only once, so each recipe's `create_dispatch` is called
 /// exactly once during the builder's lifecycle.
 pub(crate) struct ErasedConfigRecipe {
-    config: RefCell<Option<Box<dyn ErasedCommandConfig>>>,
+    config: Mutex<Option<Box<dyn ErasedCommandConfig>>>,
     #[allow(dead_code)]
     template: Option<String>,
     #[allow(dead_code)]
-    hooks: RefCell<Option<Hooks>>,
+    hooks: Mutex<Option<Hooks>>,
 }
 
 impl ErasedConfigRecipe {
@@ -282,9 +292,9 @@ impl ErasedConfigRecipe {
         let template = handler.template().map(String::from);
         let hooks = handler.take_hooks();
         Self {
-            config: RefCell::new(Some(handler)),
+            config: Mutex::new(Some(handler)),
             template,
-            hooks: RefCell::new(hooks),
+            hooks: Mutex::new(hooks),
         }
     }
 }
@@ -295,23 +305,24 @@ impl CommandRecipe for ErasedConfigRecipe {
     }
 
     fn hooks(&self) -> Option<&Hooks> {
-        // Can't return reference through RefCell, but hooks are extracted during construction
+        // Can't return reference through Mutex, but hooks are extracted during construction
         None
     }
 
     fn take_hooks(&mut self) -> Option<Hooks> {
-        self.hooks.borrow_mut().take()
+        self.hooks.get_mut().unwrap().take()
     }
 
     fn create_dispatch(
         &self,
         template: &str,
         context_registry: &ContextRegistry,
-        template_engine: Rc<Box<dyn standout_render::template::TemplateEngine>>,
+        template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
     ) -> DispatchFn {
         let config = self
             .config
-            .borrow_mut()
+            .lock()
+            .unwrap()
             .take()
             .expect("ErasedConfigRecipe::create_dispatch called more than once");
         config.register(
@@ -324,7 +335,7 @@ impl CommandRecipe for ErasedConfigRecipe {
 
     fn expected_args(&self) -> Vec<ExpectedArg> {
         // See implementation note in prev step thought: we check if config is present
-        if let Some(config) = self.config.borrow().as_ref() {
+        if let Some(config) = self.config.lock().unwrap().as_ref() {
             config.expected_args()
         } else {
             Vec::new()
@@ -339,25 +350,25 @@ impl CommandRecipe for ErasedConfigRecipe {
 /// handled with no output.
 pub(crate) struct PassthroughRecipe<F>
 where
-    F: FnMut(&ArgMatches, &CommandContext) -> Result<(), anyhow::Error> + 'static,
+    F: FnMut(&ArgMatches, &CommandContext) -> Result<(), anyhow::Error> + Send + 'static,
 {
-    handler: Rc<RefCell<F>>,
+    handler: Arc<Mutex<F>>,
 }
 
 impl<F> PassthroughRecipe<F>
 where
-    F: FnMut(&ArgMatches, &CommandContext) -> Result<(), anyhow::Error> + 'static,
+    F: FnMut(&ArgMatches, &CommandContext) -> Result<(), anyhow::Error> + Send + 'static,
 {
     pub fn new(handler: F) -> Self {
         Self {
-            handler: Rc::new(RefCell::new(handler)),
+            handler: Arc::new(Mutex::new(handler)),
         }
     }
 }
 
 impl<F> CommandRecipe for PassthroughRecipe<F>
 where
-    F: FnMut(&ArgMatches, &CommandContext) -> Result<(), anyhow::Error> + 'static,
+    F: FnMut(&ArgMatches, &CommandContext) -> Result<(), anyhow::Error> + Send + 'static,
 {
     fn template(&self) -> Option<&str> {
         None
@@ -375,23 +386,25 @@ where
         &self,
         _template: &str,
         _context_registry: &ContextRegistry,
-        _template_engine: Rc<Box<dyn standout_render::template::TemplateEngine>>,
+        _template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
     ) -> DispatchFn {
         let handler = self.handler.clone();
 
-        Rc::new(RefCell::new(
+        Arc::new(
             move |matches: &ArgMatches,
                   ctx: &CommandContext,
                   _hooks: Option<&Hooks>,
-                  _output_mode: crate::OutputMode,
-                  _theme: &crate::Theme| {
-                let result = (handler.borrow_mut())(matches, ctx);
+                  output_mode: crate::OutputMode,
+                  theme: &crate::Theme| {
+                let result = with_retries(ctx, theme, output_mode, || {
+                    (handler.lock().unwrap_or_else(PoisonError::into_inner))(matches, ctx)
+                });
                 match result {
                     Ok(()) => Ok(super::dispatch::DispatchOutput::Silent),
-                    Err(e) => Err(format!("Error: {}", e)),
+                    Err(e) => Err(DispatchError::from_handler(&e)),
                 }
             },
-        ))
+        )
     }
 
     fn expected_args(&self) -> Vec<ExpectedArg> {
@@ -407,6 +420,10 @@ pub struct CommandConfig<H> {
     pub(crate) handler: H,
     pub(crate) template: Option<String>,
     pub(crate) hooks: Option<Hooks>,
+    pub(crate) aliases: Vec<String>,
+    pub(crate) output_mode: Option<crate::OutputMode>,
+    pub(crate) timeout: Option<std::time::Duration>,
+    pub(crate) hidden: bool,
 }
 
 impl<H> CommandConfig<H> {
@@ -416,6 +433,10 @@ impl<H> CommandConfig<H> {
             handler,
             template: None,
             hooks: None,
+            aliases: Vec::new(),
+            output_mode: None,
+            timeout: None,
+            hidden: false,
         }
     }
 
@@ -428,6 +449,43 @@ impl<H> CommandConfig<H> {
         self
     }
 
+    /// Adds an alternative name for this command (e.g. `ls` for `list`).
+    ///
+    /// The alias is added to the clap command as a visible alias, dispatches
+    /// to the same handler, and is listed in help.
+    pub fn alias(mut self, alias: impl Into<String>) -> Self {
+        self.aliases.push(alias.into());
+        self
+    }
+
+    /// Hides this command from help, completions and generated docs.
+    ///
+    /// The command still runs when invoked by name. See
+    /// [`App::hide_command`](crate::cli::App::hide_command).
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+
+    /// Sets the output mode used when the user doesn't choose one.
+    ///
+    /// `--output` (and the output environment variable, if configured)
+    /// still take precedence, so `export` can default to JSON while
+    /// `export --output=yaml` works as usual.
+    pub fn output(mut self, mode: crate::OutputMode) -> Self {
+        self.output_mode = Some(mode);
+        self
+    }
+
+    /// Fails the command with a timeout error if its handler runs longer
+    /// than `timeout`.
+    ///
+    /// See [`App::command_timeout`](crate::cli::App::command_timeout).
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
     /// Sets hooks for this command.
     pub fn hooks(mut self, hooks: Hooks) -> Self {
         self.hooks = Some(hooks);
@@ -441,6 +499,8 @@ impl<H> CommandConfig<H> {
     pub fn pre_dispatch<F>(mut self, f: F) -> Self
     where
         F: Fn(&ArgMatches, &mut CommandContext) -> Result<(), crate::cli::hooks::HookError>
+            + Send
+            + Sync
             + 'static,
     {
         let hooks = self.hooks.take().unwrap_or_default();
@@ -456,6 +516,8 @@ impl<H> CommandConfig<H> {
                 &CommandContext,
                 serde_json::Value,
             ) -> Result<serde_json::Value, crate::cli::hooks::HookError>
+            + Send
+            + Sync
             + 'static,
     {
         let hooks = self.hooks.take().unwrap_or_default();
@@ -472,6 +534,8 @@ impl<H> CommandConfig<H> {
                 crate::cli::hooks::RenderedOutput,
             )
                 -> Result<crate::cli::hooks::RenderedOutput, crate::cli::hooks::HookError>
+            + Send
+            + Sync
             + 'static,
     {
         let hooks = self.hooks.take().unwrap_or_default();
@@ -685,7 +749,7 @@ impl<H> CommandConfig<H> {
     where
         P: standout_pipe::PipeTarget + 'static,
     {
-        let target = Rc::new(target);
+        let target = Arc::new(target);
         self.post_output(move |_matches, _ctx, output| {
             if let RenderedOutput::Text(ref text_output) = output {
                 // Pipe the raw output (no ANSI codes)
@@ -698,20 +762,50 @@ impl<H> CommandConfig<H> {
             }
         })
     }
+
+    /// Sends the output to every target of a [`Tee`](standout_pipe::Tee).
+    ///
+    /// Targets with [`Transform::Ansi`](standout_pipe::Transform::Ansi) get
+    /// the formatted output, the others the raw output. The output itself
+    /// passes through unchanged and is still printed, so the tee shouldn't
+    /// include [`terminal`](standout_pipe::Tee::terminal).
+    ///
+    /// ```rust,ignore
+    /// cfg.tee(Tee::new().file("report.txt").clipboard())
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// Only [`RenderedOutput::Text`] is piped. Binary and silent outputs pass through unchanged.
+    pub fn tee(self, tee: standout_pipe::Tee) -> Self {
+        let tee = Arc::new(tee);
+        self.post_output(move |_matches, _ctx, output| {
+            if let RenderedOutput::Text(ref text_output) = output {
+                tee.tee(&text_output.formatted, &text_output.raw)
+                    .map_err(|e| crate::cli::hooks::HookError::post_output(e.to_string()))?;
+            }
+            Ok(output)
+        })
+    }
 }
 
 /// Entry in the group builder - either a command or a nested group.
 pub(crate) enum GroupEntry {
-    /// A leaf command with handler, optional template, and optional hooks
+    /// A leaf command with handler, optional template, optional hooks,
+    /// aliases, default output mode, timeout and whether it's hidden
     Command {
         handler: Box<dyn ErasedCommandConfig>,
+        aliases: Vec<String>,
+        output_mode: Option<crate::OutputMode>,
+        timeout: Option<std::time::Duration>,
+        hidden: bool,
     },
     /// A nested group
     Group { builder: GroupBuilder },
 }
 
 /// Type-erased command configuration for storage.
-pub(crate) trait ErasedCommandConfig {
+pub(crate) trait ErasedCommandConfig: Send {
     fn template(&self) -> Option<&str>;
     #[allow(dead_code)]
     fn hooks(&self) -> Option<&Hooks>;
@@ -721,7 +815,7 @@ pub(crate) trait ErasedCommandConfig {
         path: &str,
         template: String,
         context_registry: ContextRegistry,
-        template_engine: Rc<Box<dyn standout_render::template::TemplateEngine>>,
+        template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
     ) -> DispatchFn;
 
     fn expected_args(&self) -> Vec<ExpectedArg>;
@@ -790,7 +884,7 @@ impl GroupBuilder {
     /// ```
     pub fn command<F, T>(self, name: &str, handler: F) -> Self
     where
-        F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + 'static,
+        F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + Send + 'static,
         T: Serialize + 'static,
     {
         self.command_with(name, handler, |cfg| cfg)
@@ -808,7 +902,7 @@ impl GroupBuilder {
     /// ```
     pub fn command_with<F, T, C>(mut self, name: &str, handler: F, configure: C) -> Self
     where
-        F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + 'static,
+        F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + Send + 'static,
         T: Serialize + 'static,
         C: FnOnce(CommandConfig<FnHandler<F, T>>) -> CommandConfig<FnHandler<F, T>>,
     {
@@ -818,10 +912,14 @@ impl GroupBuilder {
             name.to_string(),
             GroupEntry::Command {
                 handler: Box::new(ClosureCommandConfig {
-                    handler: Rc::new(RefCell::new(config.handler)),
+                    handler: Arc::new(Mutex::new(config.handler)),
                     template: config.template,
                     hooks: config.hooks,
                 }),
+                aliases: config.aliases,
+                output_mode: config.output_mode,
+                timeout: config.timeout,
+                hidden: config.hidden,
             },
         );
         self
@@ -830,7 +928,7 @@ impl GroupBuilder {
     /// Registers a struct handler in this group.
     pub fn handler<H, T>(self, name: &str, handler: H) -> Self
     where
-        H: Handler<Output = T> + 'static,
+        H: Handler<Output = T> + Send + 'static,
         T: Serialize + 'static,
     {
         self.handler_with(name, handler, |cfg| cfg)
@@ -839,7 +937,7 @@ impl GroupBuilder {
     /// Registers a struct handler with configuration.
     pub fn handler_with<H, T, C>(mut self, name: &str, handler: H, configure: C) -> Self
     where
-        H: Handler<Output = T> + 'static,
+        H: Handler<Output = T> + Send + 'static,
         T: Serialize + 'static,
         C: FnOnce(CommandConfig<H>) -> CommandConfig<H>,
     {
@@ -849,10 +947,14 @@ impl GroupBuilder {
             name.to_string(),
             GroupEntry::Command {
                 handler: Box::new(StructCommandConfig {
-                    handler: Rc::new(RefCell::new(config.handler)),
+                    handler: Arc::new(Mutex::new(config.handler)),
                     template: config.template,
                     hooks: config.hooks,
                 }),
+                aliases: config.aliases,
+                output_mode: config.output_mode,
+                timeout: config.timeout,
+                hidden: config.hidden,
             },
         );
         self
@@ -879,14 +981,18 @@ impl GroupBuilder {
     /// ```
     pub fn passthrough<F>(mut self, name: &str, handler: F) -> Self
     where
-        F: FnMut(&ArgMatches, &CommandContext) -> Result<(), anyhow::Error> + 'static,
+        F: FnMut(&ArgMatches, &CommandContext) -> Result<(), anyhow::Error> + Send + 'static,
     {
         self.entries.insert(
             name.to_string(),
             GroupEntry::Command {
                 handler: Box::new(PassthroughCommandConfig {
-                    handler: Rc::new(RefCell::new(handler)),
+                    handler: Arc::new(Mutex::new(handler)),
                 }),
+                aliases: Vec::new(),
+                output_mode: None,
+                timeout: None,
+                hidden: false,
             },
         );
         self
@@ -945,17 +1051,17 @@ impl GroupBuilder {
 /// Internal: closure-based command config that implements ErasedCommandConfig
 struct ClosureCommandConfig<F, T>
 where
-    F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + 'static,
+    F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + Send + 'static,
     T: Serialize + 'static,
 {
-    handler: Rc<RefCell<FnHandler<F, T>>>,
+    handler: Arc<Mutex<FnHandler<F, T>>>,
     template: Option<String>,
     hooks: Option<Hooks>,
 }
 
 impl<F, T> ErasedCommandConfig for ClosureCommandConfig<F, T>
 where
-    F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + 'static,
+    F: FnMut(&ArgMatches, &CommandContext) -> HandlerResult<T> + Send + 'static,
     T: Serialize + 'static,
 {
     fn template(&self) -> Option<&str> {
@@ -975,20 +1081,22 @@ where
         _path: &str,
         template: String,
         context_registry: ContextRegistry,
-        template_engine: Rc<Box<dyn standout_render::template::TemplateEngine>>,
+        template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
     ) -> DispatchFn {
         let handler = self.handler;
 
-        Rc::new(RefCell::new(
+        Arc::new(
             move |matches: &ArgMatches,
                   ctx: &CommandContext,
                   hooks: Option<&Hooks>,
                   output_mode: crate::OutputMode,
                   theme: &crate::Theme| {
-                let result = handler
-                    .borrow_mut()
-                    .handle(matches, ctx)
-                    .map_err(|e| e.to_string());
+                let result = with_retries(ctx, theme, output_mode, || {
+                    handler
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .handle(matches, ctx)
+                });
                 render_handler_output(
                     result,
                     matches,
@@ -1001,28 +1109,31 @@ where
                     output_mode,
                 )
             },
-        ))
+        )
     }
 
     fn expected_args(&self) -> Vec<ExpectedArg> {
-        self.handler.borrow().expected_args()
+        self.handler
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .expected_args()
     }
 }
 
 /// Internal: struct-based command config that implements ErasedCommandConfig
 struct StructCommandConfig<H, T>
 where
-    H: Handler<Output = T> + 'static,
+    H: Handler<Output = T> + Send + 'static,
     T: Serialize + 'static,
 {
-    handler: Rc<RefCell<H>>,
+    handler: Arc<Mutex<H>>,
     template: Option<String>,
     hooks: Option<Hooks>,
 }
 
 impl<H, T> ErasedCommandConfig for StructCommandConfig<H, T>
 where
-    H: Handler<Output = T> + 'static,
+    H: Handler<Output = T> + Send + 'static,
     T: Serialize + 'static,
 {
     fn template(&self) -> Option<&str> {
@@ -1042,20 +1153,22 @@ where
         _path: &str,
         template: String,
         context_registry: ContextRegistry,
-        template_engine: Rc<Box<dyn standout_render::template::TemplateEngine>>,
+        template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
     ) -> DispatchFn {
         let handler = self.handler;
 
-        Rc::new(RefCell::new(
+        Arc::new(
             move |matches: &ArgMatches,
                   ctx: &CommandContext,
                   hooks: Option<&Hooks>,
                   output_mode: crate::OutputMode,
                   theme: &crate::Theme| {
-                let result = handler
-                    .borrow_mut()
-                    .handle(matches, ctx)
-                    .map_err(|e| e.to_string());
+                let result = with_retries(ctx, theme, output_mode, || {
+                    handler
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .handle(matches, ctx)
+                });
                 render_handler_output(
                     result,
                     matches,
@@ -1068,25 +1181,28 @@ where
                     output_mode,
                 )
             },
-        ))
+        )
     }
 
     fn expected_args(&self) -> Vec<ExpectedArg> {
-        self.handler.borrow().expected_args()
+        self.handler
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .expected_args()
     }
 }
 
 /// Internal: passthrough command config that bypasses rendering.
 struct PassthroughCommandConfig<F>
 where
-    F: FnMut(&ArgMatches, &CommandContext) -> Result<(), anyhow::Error> + 'static,
+    F: FnMut(&ArgMatches, &CommandContext) -> Result<(), anyhow::Error> + Send + 'static,
 {
-    handler: Rc<RefCell<F>>,
+    handler: Arc<Mutex<F>>,
 }
 
 impl<F> ErasedCommandConfig for PassthroughCommandConfig<F>
 where
-    F: FnMut(&ArgMatches, &CommandContext) -> Result<(), anyhow::Error> + 'static,
+    F: FnMut(&ArgMatches, &CommandContext) -> Result<(), anyhow::Error> + Send + 'static,
 {
     fn template(&self) -> Option<&str> {
         None
@@ -1105,23 +1221,25 @@ where
         _path: &str,
         _template: String,
         _context_registry: ContextRegistry,
-        _template_engine: Rc<Box<dyn standout_render::template::TemplateEngine>>,
+        _template_engine: Arc<Box<dyn standout_render::template::TemplateEngine>>,
     ) -> DispatchFn {
         let handler = self.handler;
 
-        Rc::new(RefCell::new(
+        Arc::new(
             move |matches: &ArgMatches,
                   ctx: &CommandContext,
                   _hooks: Option<&Hooks>,
-                  _output_mode: crate::OutputMode,
-                  _theme: &crate::Theme| {
-                let result = (handler.borrow_mut())(matches, ctx);
+                  output_mode: crate::OutputMode,
+                  theme: &crate::Theme| {
+                let result = with_retries(ctx, theme, output_mode, || {
+                    (handler.lock().unwrap_or_else(PoisonError::into_inner))(matches, ctx)
+                });
                 match result {
                     Ok(()) => Ok(super::dispatch::DispatchOutput::Silent),
-                    Err(e) => Err(format!("Error: {}", e)),
+                    Err(e) => Err(DispatchError::from_handler(&e)),
                 }
             },
-        ))
+        )
     }
 
     fn expected_args(&self) -> Vec<ExpectedArg> {
@@ -55,10 +55,12 @@
 // Re-export all handler types from standout-dispatch.
 // These types are render-agnostic and focus on handler execution.
 pub use standout_dispatch::{
-    CommandContext, Extensions, FnHandler, Handler, HandlerResult, Output, RunResult,
+    mime_type_for, AppPaths, CancellationToken, CommandContext, ConfigValues, Destination,
+    ExitError, Extensions, FnHandler, Handler, HandlerResult, Output, Progress, ProgressKind,
+    ProgressReporter, ProgressSink, ProgressState, RunResult, Verbosity,
 };
 
-use standout_input::{InputSourceKind, Inputs, MissingInput};
+use standout_input::{InputSourceKind, Inputs, MissingInput, PromptMode};
 
 /// Extension trait for [`CommandContext`] that exposes inputs registered with
 /// [`CommandConfig::input`](crate::cli::CommandConfig::input).
@@ -92,6 +94,13 @@ pub trait CommandContextInput {
     /// Most handlers should prefer [`input`](Self::input); this is for cases
     /// where the handler needs to iterate over all resolved inputs.
     fn inputs(&self) -> Option<&Inputs>;
+
+    /// Returns the prompt mode set by `--yes` / `--no-input`.
+    ///
+    /// [`PromptMode::Ask`] unless the app enabled `prompt_flags()` and one of
+    /// the flags was passed. Handlers that confirm on their own should skip
+    /// the question when it isn't `Ask`.
+    fn prompt_mode(&self) -> PromptMode;
 }
 
 impl CommandContextInput for CommandContext {
@@ -111,6 +120,13 @@ impl CommandContextInput for CommandContext {
     fn inputs(&self) -> Option<&Inputs> {
         self.extensions.get::<Inputs>()
     }
+
+    fn prompt_mode(&self) -> PromptMode {
+        self.extensions
+            .get::<PromptMode>()
+            .copied()
+            .unwrap_or_default()
+    }
 }
 
 // Tests for these types are in the standout-dispatch crate.
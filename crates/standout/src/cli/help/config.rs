@@ -1,5 +1,6 @@
 //! Help rendering configuration.
 
+use crate::i18n::Catalog;
 use crate::setup::SetupError;
 use crate::{OutputMode, Theme};
 use clap::Command;
@@ -36,6 +37,9 @@ pub struct HelpConfig {
     /// Subcommand grouping for help display. If None, all subcommands
     /// appear in a single "Commands" group (default behavior).
     pub command_groups: Option<Vec<CommandGroup>>,
+    /// Localized messages, available to the template as `t`. If None, the
+    /// default template's English headers are used.
+    pub messages: Option<Catalog>,
 }
 
 /// Returns the default theme for help rendering.
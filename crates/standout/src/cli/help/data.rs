@@ -33,6 +33,7 @@ pub(crate) struct Group<T> {
 pub(crate) struct Subcommand {
     pub name: String,
     pub about: String,
+    pub aliases: Vec<String>,
     pub padding: String,
     pub separator: bool,
 }
@@ -140,6 +141,7 @@ fn extract_default_subcommands(subs: &[&Command]) -> Vec<Group<Subcommand>> {
             Subcommand {
                 name,
                 about: sub.get_about().map(|s| s.to_string()).unwrap_or_default(),
+                aliases: sub.get_visible_aliases().map(String::from).collect(),
                 padding: " ".repeat(pad),
                 separator: false,
             }
@@ -175,6 +177,7 @@ fn extract_grouped_subcommands(
                     group_cmds.push(Subcommand {
                         name: String::new(),
                         about: String::new(),
+                        aliases: Vec::new(),
                         padding: String::new(),
                         separator: true,
                     });
@@ -186,6 +189,7 @@ fn extract_grouped_subcommands(
                         group_cmds.push(Subcommand {
                             name,
                             about: sub.get_about().map(|s| s.to_string()).unwrap_or_default(),
+                            aliases: sub.get_visible_aliases().map(String::from).collect(),
                             padding: " ".repeat(pad),
                             separator: false,
                         });
@@ -218,6 +222,7 @@ fn extract_grouped_subcommands(
                 Subcommand {
                     name,
                     about: sub.get_about().map(|s| s.to_string()).unwrap_or_default(),
+                    aliases: sub.get_visible_aliases().map(String::from).collect(),
                     padding: " ".repeat(pad),
                     separator: false,
                 }
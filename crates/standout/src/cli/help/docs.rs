@@ -0,0 +1,429 @@
+//! Markdown documentation generated from help data and topics.
+//!
+//! [`generate_docs`] walks a command tree and a topic registry and produces
+//! one Markdown page per visible command and per topic, laid out for a static
+//! site generator:
+//!
+//! ```text
+//! index.md                 root command, with links to commands and topics
+//! commands/config.md       `myapp config`
+//! commands/config/get.md   `myapp config get`
+//! topics/storage.md        the `storage` topic
+//! ```
+//!
+//! Pages are built from the same data as `help` output, so the website and
+//! the CLI describe the same commands, options and topics.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use clap::Command;
+
+use super::config::CommandGroup;
+use super::data::{extract_help_data, extract_help_data_with_topics, HelpData};
+use crate::topics::{Topic, TopicRegistry};
+
+/// A generated documentation page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocPage {
+    /// Path of the page, relative to the docs root (e.g. `commands/config.md`).
+    pub path: PathBuf,
+    /// Markdown content of the page.
+    pub content: String,
+}
+
+/// Generates Markdown pages for `cmd`, its visible subcommands and all topics.
+///
+/// `command_groups` groups the root command's subcommands, as in help output.
+/// Hidden commands are skipped.
+pub fn generate_docs(
+    cmd: &Command,
+    registry: &TopicRegistry,
+    command_groups: Option<&[CommandGroup]>,
+) -> Vec<DocPage> {
+    // Building sets bin names, so subcommand usage lines include their
+    // parents. It would also add clap's `help` subcommands, which get no pages.
+    let mut cmd = cmd.clone().disable_help_subcommand(true);
+    cmd.build();
+
+    let mut pages = Vec::new();
+
+    let data = extract_help_data_with_topics(&cmd, registry, command_groups);
+    pages.push(DocPage {
+        path: PathBuf::from("index.md"),
+        content: command_page(&data, cmd.get_name(), "commands"),
+    });
+    add_subcommand_pages(&cmd, cmd.get_name(), &mut Vec::new(), &mut pages);
+
+    for topic in registry.list_topics() {
+        pages.push(DocPage {
+            path: Path::new("topics").join(format!("{}.md", topic.name)),
+            content: topic_page(topic),
+        });
+    }
+
+    pages
+}
+
+/// Generates a single Markdown document for `cmd`, its visible subcommands
+/// and all topics.
+///
+/// Each subcommand gets a `## myapp config get` section, linked from its
+/// parent's command table. `templates` maps command paths (`config.get`) to
+/// the source of the template the command renders with, shown under the
+/// command's section. Topics follow the commands under `## Topics`.
+pub fn generate_reference(
+    cmd: &Command,
+    registry: &TopicRegistry,
+    command_groups: Option<&[CommandGroup]>,
+    templates: &HashMap<String, String>,
+) -> String {
+    let mut cmd = cmd.clone().disable_help_subcommand(true);
+    cmd.build();
+    let root_name = cmd.get_name();
+
+    let mut out = format!("# {}\n", root_name);
+    let data = extract_help_data_with_topics(&cmd, registry, command_groups);
+    command_sections(&mut out, &data, "##", &|name| {
+        format!("#{}", anchor(&format!("{} {}", root_name, name)))
+    });
+    add_reference_sections(&cmd, root_name, &mut Vec::new(), templates, &mut out);
+
+    let topics = registry.list_topics();
+    if !topics.is_empty() {
+        out.push_str("\n## Topics\n");
+        for topic in topics {
+            out.push_str(&format!(
+                "\n### {}\n\n{}\n",
+                topic.title,
+                topic.content.trim_end()
+            ));
+        }
+    }
+
+    out
+}
+
+/// Writes the pages from [`generate_docs`] under `dir`, creating directories
+/// as needed.
+///
+/// Returns the paths of the written files.
+pub fn write_docs(pages: &[DocPage], dir: impl AsRef<Path>) -> io::Result<Vec<PathBuf>> {
+    let dir = dir.as_ref();
+    let mut written = Vec::with_capacity(pages.len());
+    for page in pages {
+        let path = dir.join(&page.path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, &page.content)?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn add_subcommand_pages<'a>(
+    cmd: &'a Command,
+    root_name: &str,
+    path: &mut Vec<&'a str>,
+    pages: &mut Vec<DocPage>,
+) {
+    for sub in cmd.get_subcommands().filter(|s| !s.is_hide_set()) {
+        path.push(sub.get_name());
+
+        // The page for `a b` is commands/a/b.md, so its children's pages
+        // are in the directory named after it
+        let data = extract_help_data(sub, None);
+        pages.push(DocPage {
+            path: Path::new("commands").join(format!("{}.md", path.join("/"))),
+            content: command_page(
+                &data,
+                &format!("{} {}", root_name, path.join(" ")),
+                sub.get_name(),
+            ),
+        });
+
+        add_subcommand_pages(sub, root_name, path, pages);
+        path.pop();
+    }
+}
+
+fn add_reference_sections<'a>(
+    cmd: &'a Command,
+    root_name: &str,
+    path: &mut Vec<&'a str>,
+    templates: &HashMap<String, String>,
+    out: &mut String,
+) {
+    for sub in cmd.get_subcommands().filter(|s| !s.is_hide_set()) {
+        path.push(sub.get_name());
+
+        let title = format!("{} {}", root_name, path.join(" "));
+        out.push_str(&format!("\n## {}\n", title));
+        let data = extract_help_data(sub, None);
+        command_sections(out, &data, "###", &|name| {
+            format!("#{}", anchor(&format!("{} {}", title, name)))
+        });
+        if let Some(template) = templates.get(&path.join(".")) {
+            out.push_str(&format!(
+                "\n### Template\n\n```jinja\n{}\n```\n",
+                template.trim_end()
+            ));
+        }
+
+        add_reference_sections(sub, root_name, path, templates, out);
+        path.pop();
+    }
+}
+
+/// Renders one command's page.
+///
+/// `children_dir` is the directory of its subcommands' pages, relative to
+/// this page. Topic links in "Learn More" (root page only) point to `topics/`.
+fn command_page(data: &HelpData, title: &str, children_dir: &str) -> String {
+    let mut out = format!("# {}\n", title);
+    command_sections(&mut out, data, "##", &|name| {
+        format!("{}/{}.md", children_dir, name)
+    });
+
+    if !data.learn_more.is_empty() {
+        out.push_str("\n## Learn More\n\n");
+        for topic in &data.learn_more {
+            out.push_str(&format!("- [{}](topics/{}.md)\n", topic.title, topic.name));
+        }
+    }
+
+    out
+}
+
+/// Renders a command's description, usage, subcommand and option tables and
+/// examples, with `heading` marking the section headings.
+///
+/// `link` gives the link target of a subcommand, by name.
+fn command_sections(
+    out: &mut String,
+    data: &HelpData,
+    heading: &str,
+    link: &dyn Fn(&str) -> String,
+) {
+    if !data.about.is_empty() {
+        out.push_str(&format!("\n{}\n", data.about));
+    }
+
+    out.push_str(&format!(
+        "\n{} Usage\n\n```text\n{}\n```\n",
+        heading, data.usage
+    ));
+
+    for group in &data.subcommands {
+        let commands: Vec<_> = group.commands.iter().filter(|c| !c.separator).collect();
+        if commands.is_empty() {
+            continue;
+        }
+        out.push_str(&format!(
+            "\n{} {}\n\n",
+            heading,
+            group.title.as_deref().unwrap_or("Commands")
+        ));
+        if let Some(help) = &group.help {
+            out.push_str(&format!("{}\n\n", help));
+        }
+        out.push_str("| Command | Description |\n|---------|-------------|\n");
+        for command in commands {
+            out.push_str(&format!(
+                "| [`{}`]({}) | {} |\n",
+                command.name,
+                link(&command.name),
+                table_cell(&command.about)
+            ));
+        }
+    }
+
+    for group in &data.options {
+        if group.options.is_empty() {
+            continue;
+        }
+        out.push_str(&format!(
+            "\n{} {}\n\n",
+            heading,
+            group.title.as_deref().unwrap_or("Options")
+        ));
+        out.push_str("| Option | Description |\n|--------|-------------|\n");
+        for option in &group.options {
+            out.push_str(&format!(
+                "| `{}` | {} |\n",
+                option.name,
+                table_cell(&option.help)
+            ));
+        }
+    }
+
+    if !data.examples.is_empty() {
+        out.push_str(&format!(
+            "\n{} Examples\n\n```text\n{}\n```\n",
+            heading, data.examples
+        ));
+    }
+}
+
+fn topic_page(topic: &Topic) -> String {
+    format!("# {}\n\n{}\n", topic.title, topic.content.trim_end())
+}
+
+/// Returns the fragment GitHub-style renderers give a heading's anchor.
+fn anchor(heading: &str) -> String {
+    heading
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c.to_ascii_lowercase()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Escapes text for a single Markdown table cell.
+fn table_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::topics::TopicType;
+    use clap::Arg;
+
+    fn app_cmd() -> Command {
+        Command::new("myapp")
+            .about("My app")
+            .arg(Arg::new("verbose").long("verbose").help("Say more"))
+            .subcommand(
+                Command::new("config")
+                    .about("Manage config")
+                    .subcommand(Command::new("get").about("Read a | value")),
+            )
+            .subcommand(Command::new("secret").hide(true))
+    }
+
+    fn page<'a>(pages: &'a [DocPage], path: &str) -> &'a str {
+        pages
+            .iter()
+            .find(|page| page.path == Path::new(path))
+            .map(|page| page.content.as_str())
+            .unwrap_or_else(|| panic!("no page {path} in {pages:?}"))
+    }
+
+    #[test]
+    fn test_generate_docs_tree() {
+        let mut registry = TopicRegistry::new();
+        registry.add_topic(Topic::new(
+            "Storage",
+            "Where data lives.",
+            TopicType::Text,
+            Some("storage".to_string()),
+        ));
+
+        let pages = generate_docs(&app_cmd(), &registry, None);
+        let paths: Vec<_> = pages.iter().map(|p| p.path.clone()).collect();
+        assert_eq!(
+            paths,
+            [
+                "index.md",
+                "commands/config.md",
+                "commands/config/get.md",
+                "topics/storage.md"
+            ]
+            .map(PathBuf::from)
+        );
+
+        let index = page(&pages, "index.md");
+        assert!(index.starts_with("# myapp\n\nMy app\n"), "{index}");
+        assert!(
+            index.contains("| [`config`](commands/config.md) | Manage config |"),
+            "{index}"
+        );
+        assert!(index.contains("| `--verbose` | Say more |"), "{index}");
+        assert!(index.contains("- [Storage](topics/storage.md)"), "{index}");
+
+        let config = page(&pages, "commands/config.md");
+        assert!(config.starts_with("# myapp config\n"), "{config}");
+        assert!(config.contains("myapp config [COMMAND]"), "{config}");
+        assert!(
+            config.contains("| [`get`](config/get.md) | Read a \\| value |"),
+            "{config}"
+        );
+
+        let get = page(&pages, "commands/config/get.md");
+        assert!(get.starts_with("# myapp config get\n"), "{get}");
+
+        assert_eq!(
+            page(&pages, "topics/storage.md"),
+            "# Storage\n\nWhere data lives.\n"
+        );
+    }
+
+    #[test]
+    fn test_generate_docs_uses_command_groups() {
+        let groups = [CommandGroup {
+            title: "Setup".into(),
+            help: Some("Get started.".into()),
+            commands: vec![Some("config".into())],
+        }];
+        let pages = generate_docs(&app_cmd(), &TopicRegistry::new(), Some(&groups));
+
+        let index = page(&pages, "index.md");
+        assert!(index.contains("## Setup\n\nGet started.\n"), "{index}");
+    }
+
+    #[test]
+    fn test_generate_reference() {
+        let mut registry = TopicRegistry::new();
+        registry.add_topic(Topic::new(
+            "Storage",
+            "Where data lives.",
+            TopicType::Text,
+            Some("storage".to_string()),
+        ));
+        let templates = HashMap::from([("config.get".to_string(), "{{ value }}\n".to_string())]);
+
+        let reference = generate_reference(&app_cmd(), &registry, None, &templates);
+
+        assert!(reference.starts_with("# myapp\n\nMy app\n"), "{reference}");
+        assert!(
+            reference.contains("| [`config`](#myapp-config) | Manage config |"),
+            "{reference}"
+        );
+        assert!(
+            reference.contains("## myapp config\n\nManage config\n\n### Usage\n"),
+            "{reference}"
+        );
+        assert!(
+            reference.contains("| [`get`](#myapp-config-get) | Read a \\| value |"),
+            "{reference}"
+        );
+        assert!(reference.contains("## myapp config get\n"), "{reference}");
+        assert!(
+            reference.contains("### Template\n\n```jinja\n{{ value }}\n```\n"),
+            "{reference}"
+        );
+        assert!(!reference.contains("secret"), "{reference}");
+        assert!(
+            reference.ends_with("## Topics\n\n### Storage\n\nWhere data lives.\n"),
+            "{reference}"
+        );
+    }
+
+    #[test]
+    fn test_write_docs() {
+        let dir = tempfile::tempdir().unwrap();
+        let pages = generate_docs(&app_cmd(), &TopicRegistry::new(), None);
+
+        let written = write_docs(&pages, dir.path()).unwrap();
+
+        assert_eq!(written.len(), 3);
+        let get = fs::read_to_string(dir.path().join("commands/config/get.md")).unwrap();
+        assert!(get.starts_with("# myapp config get"));
+    }
+}
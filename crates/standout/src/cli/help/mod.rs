@@ -8,10 +8,14 @@
 //! - [`CommandGroup`]: Define subcommand groups for organized help display
 //! - [`validate_command_groups`]: Validate group config against a clap Command tree
 //! - [`default_help_theme`]: Returns the default theme for help
+//! - [`generate_docs`] / [`write_docs`]: Export help and topics as Markdown pages
+//! - [`generate_reference`]: Export help and topics as a single Markdown document
 
 mod config;
 pub(crate) mod data;
+mod docs;
 mod render;
 
 pub use config::{default_help_theme, validate_command_groups, CommandGroup, HelpConfig};
+pub use docs::{generate_docs, generate_reference, write_docs, DocPage};
 pub use render::{render_help, render_help_with_topics};
@@ -1,13 +1,19 @@
 //! Help rendering functions.
 
+use crate::i18n::Catalog;
 use crate::topics::TopicRegistry;
-use crate::{render_with_output, OutputMode, RenderError};
+use crate::{render_auto, render_with_output, OutputMode, RenderError, Theme};
 use clap::Command;
+use serde_json::Value;
 
 use super::config::{default_help_theme, HelpConfig};
-use super::data::{extract_help_data, extract_help_data_with_topics};
+use super::data::{extract_help_data, extract_help_data_with_topics, HelpData};
 
 /// Renders the help for a clap command using standout.
+///
+/// In structured output modes (`json`, `yaml`, ...) the extracted help data
+/// is serialized instead: name, about, usage, grouped subcommands, options
+/// and topics, without the column padding used for terminal layout.
 pub fn render_help(cmd: &Command, config: Option<HelpConfig>) -> Result<String, RenderError> {
     let config = config.unwrap_or_default();
     let template = config
@@ -20,10 +26,12 @@ pub fn render_help(cmd: &Command, config: Option<HelpConfig>) -> Result<String,
 
     let data = extract_help_data(cmd, config.command_groups.as_deref());
 
-    render_with_output(template, &data, &theme, mode)
+    render_help_data(template, &data, config.messages.as_ref(), &theme, mode)
 }
 
 /// Renders the help for a clap command with topics in a "Learn More" section.
+///
+/// Structured output modes serialize the help data, as in [`render_help`].
 pub fn render_help_with_topics(
     cmd: &Command,
     registry: &TopicRegistry,
@@ -40,5 +48,42 @@ pub fn render_help_with_topics(
 
     let data = extract_help_data_with_topics(cmd, registry, config.command_groups.as_deref());
 
-    render_with_output(template, &data, &theme, mode)
+    render_help_data(template, &data, config.messages.as_ref(), &theme, mode)
+}
+
+/// Renders extracted help data through the template, or serializes it in
+/// structured modes.
+///
+/// Templates also receive the localized `messages` as `t` (empty when there
+/// are none), so `{{ t["help.usage"] | default("USAGE") }}` always works.
+fn render_help_data(
+    template: &str,
+    data: &HelpData,
+    messages: Option<&Catalog>,
+    theme: &Theme,
+    mode: OutputMode,
+) -> Result<String, RenderError> {
+    let mut value = serde_json::to_value(data)?;
+    if mode.is_structured() {
+        strip_padding(&mut value);
+        return render_auto(template, &value, theme, mode);
+    }
+
+    let messages = messages.map(Catalog::messages).cloned().unwrap_or_default();
+    if let Value::Object(ref mut map) = value {
+        map.insert("t".to_string(), serde_json::to_value(messages)?);
+    }
+    render_with_output(template, &value, theme, mode)
+}
+
+/// Removes the `padding` alignment fields, which only matter to templates.
+fn strip_padding(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.remove("padding");
+            map.values_mut().for_each(strip_padding);
+        }
+        Value::Array(items) => items.iter_mut().for_each(strip_padding),
+        _ => {}
+    }
 }
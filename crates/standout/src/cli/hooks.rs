@@ -8,6 +8,8 @@
 //! - Pre-dispatch: Runs before the command handler. Can abort execution.
 //! - Post-dispatch: Runs after the handler but before rendering. Receives the raw
 //!   handler data as `serde_json::Value`. Can inspect, modify, or replace the data.
+//! - Pre-render: Runs right before rendering. Receives a [`RenderRequest`] with
+//!   the template and data, and can swap the template or wrap the data.
 //! - Post-output: Runs after output is generated. Can transform output or abort.
 //!
 //! # Example
@@ -48,8 +50,8 @@
 // Re-export all hook types from standout-dispatch.
 // These types are render-agnostic and focus on hook execution.
 pub use standout_dispatch::{
-    HookError, HookPhase, Hooks, PostDispatchFn, PostOutputFn, PreDispatchFn, RenderedOutput,
-    TextOutput,
+    HookError, HookPhase, Hooks, PostDispatchFn, PostOutputFn, PreDispatchFn, PreRenderFn,
+    RenderRequest, RenderedOutput, TextOutput,
 };
 
 // Tests for these types are in the standout-dispatch crate.
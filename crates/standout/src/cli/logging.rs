@@ -0,0 +1,339 @@
+//! Themed `tracing` output on stderr (`tracing` feature).
+//!
+//! [`ThemedLogLayer`] is a `tracing-subscriber` layer that writes each event
+//! as one line on stderr, with the level styled from the theme:
+//!
+//! ```text
+//!  WARN cache is stale age=3600
+//! ERROR upload failed file="notes.md"
+//! ```
+//!
+//! Levels use the `log-error`, `log-warn`, `log-info`, `log-debug` and
+//! `log-trace` styles, falling back to red, yellow, green, blue and dim when
+//! the theme doesn't define them.
+//!
+//! Apps usually don't build the layer themselves: with
+//! [`App::log_to_stderr`](crate::cli::App::log_to_stderr) and
+//! [`verbosity_flags`](crate::cli::App::verbosity_flags), the subscriber is
+//! installed on dispatch and `-q` / `-v` / `-vv` / `-vvv` pick the level.
+//!
+//! Programs managing their own subscriber add [`layer()`] to it. Each line
+//! is rendered from a template, which can be replaced; it sees `level`,
+//! `level_style` (the level's `log-*` style name), `target`, `message` and
+//! `fields`, and its style tags use the theme, with `log-target` dim by
+//! default:
+//!
+//! ```rust,ignore
+//! use tracing_subscriber::prelude::*;
+//!
+//! let layer = standout::logging::layer()
+//!     .with_theme(&theme)
+//!     .with_template("[{{ level_style }}]{{ level }}[/{{ level_style }}] [log-target]{{ target }}[/log-target] {{ message }}")?;
+//! tracing_subscriber::registry().with(layer).init();
+//! ```
+
+use std::fmt::{self, Write as _};
+use std::io::Write as _;
+
+use console::{Style, Term};
+use minijinja::Environment;
+use serde::Serialize;
+use standout_bbparser::{BBParser, TagTransform, UnknownTagBehavior};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+
+use crate::cli::handler::Verbosity;
+use crate::{RenderError, Theme};
+
+/// The template lines are rendered from unless replaced:
+/// ` WARN cache is stale age=3600`.
+pub const DEFAULT_LOG_TEMPLATE: &str = "[{{ level_style }}]{{ level | pad_left(5) }}[/{{ level_style }}] {{ message }}{% if fields %} {{ fields }}{% endif %}";
+
+/// Name the line template is registered under.
+const TEMPLATE_NAME: &str = "log-line";
+
+/// Returns the most detailed level logged at `verbosity`.
+///
+/// Warnings and errors show by default; `-q` keeps only errors and each `-v`
+/// adds a level.
+pub fn level_filter(verbosity: Verbosity) -> LevelFilter {
+    match verbosity {
+        Verbosity::Quiet => LevelFilter::ERROR,
+        Verbosity::Normal => LevelFilter::WARN,
+        Verbosity::Verbose => LevelFilter::INFO,
+        Verbosity::Debug => LevelFilter::DEBUG,
+        Verbosity::Trace => LevelFilter::TRACE,
+    }
+}
+
+/// Fallback level styles for themes without `log-*` styles.
+fn default_log_theme() -> Theme {
+    Theme::new()
+        .add("log-error", Style::new().red().bold())
+        .add("log-warn", Style::new().yellow())
+        .add("log-info", Style::new().green())
+        .add("log-debug", Style::new().blue())
+        .add("log-trace", Style::new().dim())
+        .add("log-target", Style::new().dim())
+}
+
+fn style_name(level: &Level) -> &'static str {
+    match *level {
+        Level::ERROR => "log-error",
+        Level::WARN => "log-warn",
+        Level::INFO => "log-info",
+        Level::DEBUG => "log-debug",
+        Level::TRACE => "log-trace",
+    }
+}
+
+/// A `tracing-subscriber` layer writing events to stderr, level-styled by a theme.
+pub struct ThemedLogLayer {
+    env: Environment<'static>,
+    parser: BBParser,
+    use_color: bool,
+}
+
+/// What the line template sees.
+#[derive(Serialize)]
+struct LogLine {
+    level: String,
+    level_style: &'static str,
+    target: String,
+    message: String,
+    fields: String,
+}
+
+impl ThemedLogLayer {
+    /// Creates a layer styling levels from `theme`.
+    ///
+    /// Styling is applied when `use_color` is true; pass the result of a
+    /// stderr TTY check.
+    pub fn new(theme: &Theme, use_color: bool) -> Self {
+        let mut env = Environment::new();
+        standout_render::template::register_filters(&mut env);
+        env.add_template_owned(TEMPLATE_NAME, DEFAULT_LOG_TEMPLATE)
+            .expect("default log template is valid");
+        Self {
+            env,
+            parser: line_parser(theme, use_color),
+            use_color,
+        }
+    }
+
+    /// Replaces the theme the line's style tags use.
+    pub fn with_theme(mut self, theme: &Theme) -> Self {
+        self.parser = line_parser(theme, self.use_color);
+        self
+    }
+
+    /// Replaces the line template.
+    ///
+    /// Fails if the template doesn't compile.
+    pub fn with_template(mut self, template: impl Into<String>) -> Result<Self, RenderError> {
+        self.env
+            .add_template_owned(TEMPLATE_NAME, template.into())?;
+        Ok(self)
+    }
+
+    /// Formats an event as one line (without the trailing newline).
+    fn event_line(&self, event: &Event<'_>) -> String {
+        let mut visitor = EventVisitor::default();
+        event.record(&mut visitor);
+        let metadata = event.metadata();
+        self.format_line(
+            metadata.level(),
+            metadata.target(),
+            &visitor.message,
+            &visitor.fields,
+        )
+    }
+
+    fn format_line(&self, level: &Level, target: &str, message: &str, fields: &str) -> String {
+        let line = LogLine {
+            level: level.to_string(),
+            level_style: style_name(level),
+            target: escape_tags(target),
+            message: escape_tags(message),
+            fields: escape_tags(fields),
+        };
+        let rendered = self
+            .env
+            .get_template(TEMPLATE_NAME)
+            .and_then(|template| template.render(&line));
+        match rendered {
+            Ok(text) => self.parser.parse(&text),
+            // A template failing on an event still shouldn't lose it
+            Err(e) => format!("{} {} {} ({})", line.level, message, fields, e),
+        }
+    }
+}
+
+/// Builds the parser applying the theme's styles to a rendered line.
+fn line_parser(theme: &Theme, use_color: bool) -> BBParser {
+    let styles = default_log_theme()
+        .merge(theme.clone())
+        .resolve_styles(Some(crate::detect_color_mode()))
+        .to_resolved_map()
+        .into_iter()
+        .map(|(name, style)| (name, style.for_stderr().force_styling(true)))
+        .collect();
+    let transform = if use_color {
+        TagTransform::Apply
+    } else {
+        TagTransform::Remove
+    };
+    BBParser::new(styles, transform).unknown_behavior(UnknownTagBehavior::Passthrough)
+}
+
+/// Escapes brackets so event text isn't read as style tags.
+fn escape_tags(text: &str) -> String {
+    text.replace('[', "\\[").replace(']', "\\]")
+}
+
+/// Returns a layer logging to stderr with the default theme and template.
+///
+/// Lines are colored when stderr is a color terminal. Set the theme and
+/// template with [`ThemedLogLayer::with_theme`] and
+/// [`ThemedLogLayer::with_template`].
+pub fn layer() -> ThemedLogLayer {
+    let stderr = Term::stderr();
+    ThemedLogLayer::new(
+        &Theme::new(),
+        stderr.is_term() && stderr.features().colors_supported(),
+    )
+}
+
+impl<S: Subscriber> Layer<S> for ThemedLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let line = self.event_line(event);
+        crate::cli::progress::suspended(|| {
+            let _ = writeln!(std::io::stderr().lock(), "{}", line);
+        });
+    }
+}
+
+/// Collects an event's message and its other fields as `key=value` pairs.
+#[derive(Default)]
+struct EventVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for EventVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.record_debug(field, &value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+            return;
+        }
+        if !self.fields.is_empty() {
+            self.fields.push(' ');
+        }
+        let _ = write!(self.fields, "{}={:?}", field.name(), value);
+    }
+}
+
+/// Installs a global subscriber logging to stderr at `verbosity`.
+///
+/// Does nothing if a global subscriber is already set.
+pub fn init(verbosity: Verbosity, theme: &Theme, use_color: bool) {
+    let subscriber = tracing_subscriber::registry()
+        .with(level_filter(verbosity))
+        .with(ThemedLogLayer::new(theme, use_color));
+    let _ = tracing::subscriber::set_global_default(subscriber);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_filter_follows_verbosity() {
+        assert_eq!(level_filter(Verbosity::Quiet), LevelFilter::ERROR);
+        assert_eq!(level_filter(Verbosity::Normal), LevelFilter::WARN);
+        assert_eq!(level_filter(Verbosity::Verbose), LevelFilter::INFO);
+        assert_eq!(level_filter(Verbosity::Trace), LevelFilter::TRACE);
+    }
+
+    #[test]
+    fn test_format_line_plain() {
+        let layer = ThemedLogLayer::new(&Theme::new(), false);
+        assert_eq!(
+            layer.format_line(&Level::WARN, "app", "cache is stale", "age=3600"),
+            " WARN cache is stale age=3600"
+        );
+        assert_eq!(
+            layer.format_line(&Level::ERROR, "app", "boom", ""),
+            "ERROR boom"
+        );
+    }
+
+    #[test]
+    fn test_format_line_uses_theme_style() {
+        let theme = Theme::new().add("log-info", Style::new().magenta());
+        let layer = ThemedLogLayer::new(&theme, true);
+        let line = layer.format_line(&Level::INFO, "app", "synced", "");
+        assert!(line.contains("\x1b[35m"), "{line:?}");
+        assert!(line.ends_with(" synced"));
+    }
+
+    #[test]
+    fn test_custom_template_sees_target() {
+        let layer = ThemedLogLayer::new(&Theme::new(), true)
+            .with_template("{{ level }} [log-target]{{ target }}[/log-target]: {{ message }}")
+            .unwrap();
+        let line = layer.format_line(&Level::INFO, "app::sync", "synced", "");
+        assert_eq!(line, "INFO \x1b[2mapp::sync\x1b[0m: synced");
+    }
+
+    #[test]
+    fn test_event_text_is_not_read_as_tags() {
+        let theme = Theme::new().add("bold", Style::new().bold());
+        let layer = ThemedLogLayer::new(&theme, true)
+            .with_template("{{ message }}")
+            .unwrap();
+        assert_eq!(
+            layer.format_line(&Level::INFO, "app", "[bold]x[/bold] [1]", ""),
+            "[bold]x[/bold] [1]"
+        );
+    }
+
+    #[test]
+    fn test_invalid_template_is_rejected() {
+        assert!(layer().with_template("{{ message").is_err());
+    }
+
+    #[test]
+    fn test_event_line_splits_message_and_fields() {
+        use std::sync::{Arc, Mutex};
+
+        struct Capture(ThemedLogLayer, Arc<Mutex<Vec<String>>>);
+        impl<S: Subscriber> Layer<S> for Capture {
+            fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+                self.1.lock().unwrap().push(self.0.event_line(event));
+            }
+        }
+
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let layer = ThemedLogLayer::new(&Theme::new(), false);
+        let subscriber = tracing_subscriber::registry().with(Capture(layer, lines.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(file = "notes.md", count = 2, "uploaded");
+        });
+
+        assert_eq!(
+            *lines.lock().unwrap(),
+            [" INFO uploaded file=\"notes.md\" count=2"]
+        );
+    }
+}
@@ -14,11 +14,24 @@
 //! If you only need template rendering without CLI integration, use the
 //! [`render`](crate::render) functions directly.
 //!
-//! ## Single-Threaded Design
+//! ## Threading
 //!
-//! CLI applications are single-threaded: parse args → run one handler → output → exit.
+//! A CLI run is sequential: parse args → run one handler → output → exit.
 //! Handlers use `&mut self` and `FnMut`, allowing natural Rust patterns without
-//! forcing interior mutability wrappers (`Arc<Mutex<_>>`).
+//! forcing interior mutability wrappers (`Arc<Mutex<_>>`); the framework keeps
+//! each handler behind a `Mutex` itself.
+//!
+//! An `App` is `Send + Sync`, so it can be built once and shared, for example
+//! by a server rendering CLI-style reports. This requires handlers to be
+//! `Send`, and hooks, validators, `app_state` values and context providers to
+//! be `Send + Sync`: capture `Arc` and `Mutex` rather than `Rc` and `RefCell`.
+//!
+//! Dispatches on a shared `App` run concurrently. Each keeps its own
+//! deprecation warnings and `--timings`, and the `--yes` / `--no-input` mode
+//! and loaded configuration that input chains read are scoped to the
+//! dispatching thread. Calls to the same handler take turns, as its lock is
+//! held while it runs; rendering isn't serialized. Ctrl-C handling
+//! (`on_interrupt`), indicatif bars and terminal detection stay process-wide.
 //!
 //! ```rust,ignore
 //! use standout::cli::{App, Output};
@@ -35,7 +48,7 @@
 //!
 //! // FnMut handlers can capture mutable state
 //! App::new()
-//!     .command("add", |m, ctx| {
+//!     .command("add", move |m, ctx| {
 //!         let item = Item::from(m);
 //!         api.add(item);  // &mut self works!
 //!         Ok(Output::Silent)
@@ -90,12 +103,17 @@
 //! ```rust,ignore
 //! match app.run_to_string(cmd, args) {
 //!     RunResult::Handled(output) => println!("{}", output),
+//!     RunResult::Stderr(output) => eprintln!("{}", output),
 //!     RunResult::NoMatch(matches) => legacy_dispatch(matches),
 //!     RunResult::Binary(bytes, filename) => std::fs::write(filename, bytes)?,
 //!     RunResult::Error(msg) => {
 //!         eprintln!("{}", msg);
 //!         std::process::exit(1);
 //!     },
+//!     RunResult::Exit(msg, code) => {
+//!         eprintln!("{}", msg);
+//!         std::process::exit(code);
+//!     },
 //!     // RunResult is #[non_exhaustive]; covers Silent (currently mapped to
 //!     // Handled(String::new()) in 7.x) and any future variants.
 //!     _ => {},
@@ -117,7 +135,17 @@
 //!
 //! - [`crate::render`]: Direct rendering without CLI integration
 //! - [`handler`]: Handler types and the Handler trait
+//! - [`config`]: Layered configuration files read with `ctx.config()`
+//! - [`crash`]: Themed crash reports for panics
+//! - [`deprecation`]: Warnings for deprecated commands, aliases and flags
 //! - [`hooks`]: Hook system for intercepting execution
+//! - `logging`: Themed `tracing` output on stderr (`tracing` feature)
+//! - [`paths`]: Per-app config, data, cache and state directories
+//! - [`plugin`]: Commands contributed by plugins and external executables
+//! - [`query`]: Dot-path evaluator behind the `--query` flag
+//! - [`testing`]: Captured output of in-process test runs
+//! - [`timings`]: Per-stage durations behind the `--timings` flag
+//! - [`version`]: Rich `--version` output from [`BuildInfo`]
 //! - [`help`]: Help rendering and topic system
 
 // Internal modules
@@ -131,12 +159,29 @@ pub(crate) mod app;
 mod builder;
 
 // Public modules
+pub mod config;
+pub mod crash;
+pub mod deprecation;
 pub mod group;
 pub mod handler;
 pub mod help;
 pub mod hooks;
+#[cfg(feature = "tracing")]
+pub mod logging;
 #[macro_use]
 pub mod macros;
+pub mod paths;
+pub mod plugin;
+pub mod progress;
+#[cfg(feature = "indicatif")]
+pub mod progress_bars;
+pub mod query;
+pub mod retry;
+pub mod testing;
+pub mod timeout;
+pub mod timings;
+pub mod validation;
+pub mod version;
 
 // Re-export AppBuilder as App — the single unified type
 pub use builder::AppBuilder as App;
@@ -155,11 +200,28 @@ pub use help::{
 
 // Re-export handler types
 pub use handler::{
-    CommandContext, CommandContextInput, FnHandler, Handler, HandlerResult, Output, RunResult,
+    AppPaths, CancellationToken, CommandContext, CommandContextInput, ConfigValues, Destination,
+    ExitError, FnHandler, Handler, HandlerResult, Output, Progress, ProgressKind, RunResult,
+    Verbosity,
 };
 
+// Re-export plugin types
+pub use plugin::CommandPlugin;
+
+// Re-export retry types
+pub use retry::RetryPolicy;
+
+// Re-export test run output
+pub use testing::TestOutput;
+
+// Re-export validation types
+pub use validation::{ValidationError, ValidationErrors};
+
+// Re-export version types
+pub use version::BuildInfo;
+
 // Re-export hook types
-pub use hooks::{HookError, HookPhase, Hooks, RenderedOutput};
+pub use hooks::{HookError, HookPhase, Hooks, RenderRequest, RenderedOutput};
 
 // Re-export derive macros from standout-macros
 pub use standout_macros::Dispatch;
@@ -0,0 +1,82 @@
+//! Per-app config, data, cache and state directories.
+//!
+//! Apps get their [`AppPaths`] from `ctx.paths()`. Each directory is the
+//! platform's standard location joined with the app name:
+//!
+//! | | Linux | macOS | Windows |
+//! |-|-------|-------|---------|
+//! | `config` | `$XDG_CONFIG_HOME/<name>` | `~/Library/Application Support/<name>` | `%APPDATA%\<name>` |
+//! | `data` | `$XDG_DATA_HOME/<name>` | `~/Library/Application Support/<name>` | `%APPDATA%\<name>` |
+//! | `cache` | `$XDG_CACHE_HOME/<name>` | `~/Library/Caches/<name>` | `%LOCALAPPDATA%\<name>` |
+//! | `state` | `$XDG_STATE_HOME/<name>` | `~/Library/Application Support/<name>` | `%LOCALAPPDATA%\<name>` |
+//!
+//! The XDG variables fall back to `~/.config`, `~/.local/share`, `~/.cache`
+//! and `~/.local/state`. Users can move any directory with an environment
+//! variable named after the app: `MYAPP_CONFIG_DIR`, `MYAPP_DATA_DIR`,
+//! `MYAPP_CACHE_DIR` and `MYAPP_STATE_DIR` (for `my-app`, `MY_APP_*`).
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+use crate::cli::handler::AppPaths;
+
+/// The environment variable suffixes that override each directory.
+pub(crate) const DIR_OVERRIDES: [&str; 4] = ["CONFIG_DIR", "DATA_DIR", "CACHE_DIR", "STATE_DIR"];
+
+/// Returns the environment variable prefix for the app `name` (`my-app`
+/// becomes `MY_APP`).
+pub(crate) fn env_prefix(name: &str) -> String {
+    name.to_uppercase().replace('-', "_")
+}
+
+/// Returns the directories for the app `name`, honoring overrides from the
+/// process environment.
+pub fn app_paths(name: &str) -> AppPaths {
+    app_paths_from(name, |key| std::env::var_os(key))
+}
+
+/// Like [`app_paths`], with the override lookup given explicitly.
+pub fn app_paths_from(name: &str, env: impl Fn(&str) -> Option<OsString>) -> AppPaths {
+    let prefix = env_prefix(name);
+    let dir = |suffix: &str, base: Option<PathBuf>| {
+        env(&format!("{}_{}", prefix, suffix))
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| base.unwrap_or_else(std::env::temp_dir).join(name))
+    };
+    AppPaths {
+        config: dir(DIR_OVERRIDES[0], dirs::config_dir()),
+        data: dir(DIR_OVERRIDES[1], dirs::data_dir()),
+        cache: dir(DIR_OVERRIDES[2], dirs::cache_dir()),
+        // Only Linux has a state directory; elsewhere it's machine-local data
+        state: dir(
+            DIR_OVERRIDES[3],
+            dirs::state_dir().or_else(dirs::data_local_dir),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_app_paths_end_with_app_name() {
+        let paths = app_paths_from("myapp", |_| None);
+        for dir in [&paths.config, &paths.data, &paths.cache, &paths.state] {
+            assert!(dir.ends_with("myapp"), "{}", dir.display());
+        }
+        assert_ne!(paths.config, paths.cache);
+    }
+
+    #[test]
+    fn test_app_paths_env_overrides() {
+        let paths = app_paths_from("my-app", |key| match key {
+            "MY_APP_CACHE_DIR" => Some("/tmp/my-cache".into()),
+            "MY_APP_DATA_DIR" => Some("".into()),
+            _ => None,
+        });
+        assert_eq!(paths.cache, PathBuf::from("/tmp/my-cache"));
+        assert!(paths.data.ends_with("my-app"));
+    }
+}
@@ -0,0 +1,154 @@
+//! Commands contributed by plugins.
+//!
+//! Two kinds of plugins add commands to an app:
+//!
+//! - **In-process plugins** implement [`CommandPlugin`]: they provide the
+//!   clap definitions of their commands and register the handlers and
+//!   templates for them. [`App::plugin`](crate::cli::App::plugin) adds both.
+//!
+//! - **External plugins** are executables named `<prefix><plugin>` on
+//!   `PATH`, the way `git` finds `git-foo` for `git foo`. With
+//!   [`App::external_plugins`](crate::cli::App::external_plugins), each one
+//!   becomes a subcommand that runs the executable with the remaining
+//!   arguments. When a template exists for the plugin (by the usual
+//!   command-path convention), its stdout is parsed as JSON and rendered
+//!   through it; otherwise stdout is passed through unchanged.
+//!
+//! ```rust,ignore
+//! struct Sync;
+//!
+//! impl CommandPlugin for Sync {
+//!     fn commands(&self) -> Vec<clap::Command> {
+//!         vec![clap::Command::new("sync").about("Sync with the server")]
+//!     }
+//!
+//!     fn register(self, app: App) -> Result<App, SetupError> {
+//!         app.command("sync", sync_handler, "{{ count }} synced")
+//!     }
+//! }
+//!
+//! App::builder()
+//!     .plugin(Sync)?
+//!     .external_plugins("myapp-")?
+//!     .build()?;
+//! ```
+
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use crate::cli::App;
+use crate::SetupError;
+
+/// A bundle of commands that can be added to an app.
+pub trait CommandPlugin {
+    /// The clap definitions of the plugin's commands.
+    ///
+    /// They are added as subcommands of the app's command, unless it already
+    /// has a subcommand with the same name.
+    fn commands(&self) -> Vec<clap::Command>;
+
+    /// Registers the handlers and templates for the plugin's commands.
+    fn register(self, app: App) -> Result<App, SetupError>
+    where
+        Self: Sized;
+}
+
+/// An executable found on `PATH` that provides a subcommand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalPlugin {
+    /// Subcommand name: the file name without the prefix.
+    pub name: String,
+    /// Path of the executable.
+    pub path: PathBuf,
+}
+
+/// Finds the executables named `<prefix><name>` in the directories of
+/// `search_path` (formatted like `PATH`).
+///
+/// Earlier directories win when a name appears more than once. Results are
+/// sorted by name.
+pub fn discover_external_plugins(prefix: &str, search_path: &OsStr) -> Vec<ExternalPlugin> {
+    let mut plugins: Vec<ExternalPlugin> = Vec::new();
+    for dir in std::env::split_paths(search_path) {
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Some(name) = plugin_name(&path, prefix) else {
+                continue;
+            };
+            if is_executable(&path) && !plugins.iter().any(|p| p.name == name) {
+                plugins.push(ExternalPlugin { name, path });
+            }
+        }
+    }
+    plugins.sort_by(|a, b| a.name.cmp(&b.name));
+    plugins
+}
+
+/// The plugin name of `path`, if its file name starts with `prefix`.
+fn plugin_name(path: &Path, prefix: &str) -> Option<String> {
+    let file_name = path.file_name()?.to_str()?;
+    let name = file_name.strip_prefix(prefix)?;
+    let name = if cfg!(windows) {
+        name.strip_suffix(".exe").unwrap_or(name)
+    } else {
+        name
+    };
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path)
+        .map(|m| m.is_file() && m.permissions().mode() & 0o111 != 0)
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+
+    fn script(dir: &Path, name: &str, mode: u32) {
+        let path = dir.join(name);
+        std::fs::write(&path, "#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(mode)).unwrap();
+    }
+
+    #[test]
+    fn test_discover_external_plugins() {
+        let first = tempfile::tempdir().unwrap();
+        let second = tempfile::tempdir().unwrap();
+        script(first.path(), "myapp-sync", 0o755);
+        script(first.path(), "myapp-notes", 0o644); // not executable
+        script(first.path(), "other-tool", 0o755);
+        script(second.path(), "myapp-sync", 0o755);
+        script(second.path(), "myapp-export", 0o755);
+
+        let search_path =
+            std::env::join_paths([first.path(), Path::new("/nonexistent"), second.path()]).unwrap();
+        let plugins = discover_external_plugins("myapp-", &search_path);
+
+        assert_eq!(
+            plugins,
+            [
+                ExternalPlugin {
+                    name: "export".into(),
+                    path: second.path().join("myapp-export"),
+                },
+                ExternalPlugin {
+                    name: "sync".into(),
+                    path: first.path().join("myapp-sync"),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,217 @@
+//! Themed progress lines on stderr.
+//!
+//! Handlers report long operations through
+//! [`ctx.progress()`](crate::cli::CommandContext::progress). When stderr is a
+//! terminal and `-q` wasn't given, the app draws the progress on one stderr
+//! line, redrawn in place and cleared when the handle finishes:
+//!
+//! ```text
+//! ⠹ scanning
+//! [████████░░░░░░░░░░░░]  42% uploading
+//! ```
+//!
+//! A spinner shows until the total is known, then a bar (or only the
+//! percentage, with [`Progress::percent`](crate::cli::Progress::percent)).
+//! Piped stderr gets nothing, so machine output stays clean. The line uses
+//! the `progress-spinner`, `progress-bar`, `progress-track` and
+//! `progress-percent` styles, falling back to cyan, cyan, dim and bold when
+//! the theme doesn't define them.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use console::{Style, Term};
+
+use crate::cli::handler::Verbosity;
+use crate::{OutputMode, Theme};
+use standout_dispatch::{ProgressKind, ProgressReporter, ProgressSink, ProgressState};
+
+/// Spinner animation frames.
+pub(crate) const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Width of the bar in cells.
+pub(crate) const BAR_WIDTH: usize = 20;
+
+/// Minimum time between redraws, so tight loops don't flood the terminal.
+const REDRAW_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Fallback styles for themes without `progress-*` styles.
+pub(crate) fn default_progress_theme() -> Theme {
+    Theme::new()
+        .add("progress-spinner", Style::new().cyan())
+        .add("progress-bar", Style::new().cyan())
+        .add("progress-track", Style::new().dim())
+        .add("progress-percent", Style::new().bold())
+}
+
+/// Draws progress on stderr, styled by a theme.
+pub struct StderrProgress {
+    styles: HashMap<String, Style>,
+    use_color: bool,
+    frame: AtomicUsize,
+    last_draw: Mutex<Option<Instant>>,
+}
+
+impl StderrProgress {
+    /// Creates a sink styling the line from `theme`.
+    ///
+    /// Styling is applied when `use_color` is true; pass the result of a
+    /// stderr color check.
+    pub fn new(theme: &Theme, use_color: bool) -> Self {
+        let styles = default_progress_theme()
+            .merge(theme.clone())
+            .resolve_styles(Some(crate::detect_color_mode()))
+            .to_resolved_map();
+        Self {
+            styles,
+            use_color,
+            frame: AtomicUsize::new(0),
+            last_draw: Mutex::new(None),
+        }
+    }
+
+    fn styled(&self, name: &str, text: &str) -> String {
+        match self.styles.get(name) {
+            Some(style) if self.use_color => style
+                .clone()
+                .for_stderr()
+                .force_styling(true)
+                .apply_to(text)
+                .to_string(),
+            _ => text.to_string(),
+        }
+    }
+
+    /// Formats `state` as one line, showing spinner frame `frame`.
+    fn format_line(&self, state: &ProgressState, frame: usize) -> String {
+        let fraction = state.fraction();
+        let indicator = match (state.kind, fraction) {
+            (ProgressKind::Spinner, _) | (_, None) => self.styled(
+                "progress-spinner",
+                SPINNER_FRAMES[frame % SPINNER_FRAMES.len()],
+            ),
+            (ProgressKind::Bar, Some(fraction)) => {
+                let filled = (fraction * BAR_WIDTH as f64).round() as usize;
+                format!(
+                    "[{}{}] {}",
+                    self.styled("progress-bar", &"█".repeat(filled)),
+                    self.styled("progress-track", &"░".repeat(BAR_WIDTH - filled)),
+                    self.styled("progress-percent", &percent(fraction)),
+                )
+            }
+            (ProgressKind::Percent, Some(fraction)) => {
+                self.styled("progress-percent", &percent(fraction))
+            }
+        };
+        if state.message.is_empty() {
+            indicator
+        } else {
+            format!("{} {}", indicator, state.message)
+        }
+    }
+}
+
+pub(crate) fn percent(fraction: f64) -> String {
+    format!("{:>3}%", (fraction * 100.0).floor() as u64)
+}
+
+impl ProgressSink for StderrProgress {
+    fn draw(&self, state: &ProgressState) {
+        let now = Instant::now();
+        let done = state.fraction() == Some(1.0);
+        {
+            let mut last_draw = self.last_draw.lock().unwrap();
+            if let Some(last) = *last_draw {
+                if !done && now.duration_since(last) < REDRAW_INTERVAL {
+                    return;
+                }
+            }
+            *last_draw = Some(now);
+        }
+        let frame = self.frame.fetch_add(1, Ordering::Relaxed);
+
+        let term = Term::stderr();
+        let _ = term.clear_line();
+        let _ = term.write_str(&self.format_line(state, frame));
+    }
+
+    fn clear(&self) {
+        if self.last_draw.lock().unwrap().is_some() {
+            let _ = Term::stderr().clear_line();
+        }
+    }
+}
+
+/// Runs `f` with any themed indicatif bars cleared, redrawing them after.
+///
+/// Framework output printed while a command runs (log lines, retry notices,
+/// streamed items) goes through this so it doesn't tear the bars of the
+/// active `ProgressBars` (with the `indicatif` feature).
+pub(crate) fn suspended<R>(f: impl FnOnce() -> R) -> R {
+    #[cfg(feature = "indicatif")]
+    if let Some(multi) = crate::cli::progress_bars::active() {
+        return multi.suspend(f);
+    }
+    f()
+}
+
+/// Returns the reporter handlers' progress is drawn with, or `None` when
+/// progress shouldn't show (stderr isn't a terminal, or `-q` was given).
+pub(crate) fn stderr_reporter(
+    theme: &Theme,
+    verbosity: Verbosity,
+    output_mode: OutputMode,
+) -> Option<ProgressReporter> {
+    let stderr = Term::stderr();
+    if verbosity == Verbosity::Quiet || !stderr.is_term() {
+        return None;
+    }
+    let use_color = output_mode != OutputMode::Text && stderr.features().colors_supported();
+    Some(ProgressReporter(Arc::new(StderrProgress::new(
+        theme, use_color,
+    ))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(kind: ProgressKind, position: u64, total: Option<u64>) -> ProgressState {
+        ProgressState {
+            kind,
+            message: "copying".into(),
+            position,
+            total,
+        }
+    }
+
+    #[test]
+    fn test_format_spinner() {
+        let sink = StderrProgress::new(&Theme::new(), false);
+        let line = sink.format_line(&state(ProgressKind::Spinner, 3, None), 2);
+        assert_eq!(line, "⠹ copying");
+    }
+
+    #[test]
+    fn test_format_bar_and_percent() {
+        let sink = StderrProgress::new(&Theme::new(), false);
+        assert_eq!(
+            sink.format_line(&state(ProgressKind::Bar, 1, Some(4)), 0),
+            "[█████░░░░░░░░░░░░░░░]  25% copying"
+        );
+        assert_eq!(
+            sink.format_line(&state(ProgressKind::Percent, 4, Some(4)), 0),
+            "100% copying"
+        );
+    }
+
+    #[test]
+    fn test_format_uses_theme_style() {
+        let theme = Theme::new().add("progress-percent", Style::new().magenta());
+        let sink = StderrProgress::new(&theme, true);
+        let line = sink.format_line(&state(ProgressKind::Percent, 1, Some(2)), 0);
+        assert!(line.contains("\x1b[35m"), "{line:?}");
+    }
+}
@@ -0,0 +1,362 @@
+//! Theme-styled indicatif progress bars (`indicatif` feature).
+//!
+//! [`ProgressBars`] builds [`indicatif`] bars and spinners that look like
+//! the line drawn by [`ctx.progress()`](crate::cli::CommandContext::progress):
+//! the same `progress-spinner`, `progress-bar`, `progress-track` and
+//! `progress-percent` styles, with the same fallbacks. Use it when a handler
+//! needs what indicatif offers beyond a single line, such as several bars at
+//! once, ETAs or work on other threads.
+//!
+//! During dispatch the app puts a `ProgressBars` styled by its theme in
+//! `ctx.extensions`. While the command runs, standout's own stderr output
+//! (log lines, retry notices) and streamed items are printed with the bars
+//! suspended, so they don't tear. With `-q`, or when stderr isn't a terminal,
+//! its bars are hidden.
+//!
+//! ```rust,ignore
+//! use standout::cli::progress_bars::ProgressBars;
+//!
+//! fn sync(_m: &ArgMatches, ctx: &CommandContext) -> HandlerResult<Summary> {
+//!     let bars = ctx.extensions.get::<ProgressBars>().cloned().unwrap_or_default();
+//!     let files = bars.bar(paths.len() as u64).with_message("uploading");
+//!     for path in &paths {
+//!         upload(path)?;
+//!         files.inc(1);
+//!     }
+//!     files.finish_and_clear();
+//!     Ok(Output::Render(summary))
+//! }
+//! ```
+//!
+//! Custom layouts keep the themed keys: `bars.bar_style().template("{spinner}
+//! {bar} {pos}/{len}")` styles `{spinner}`, `{bar}` and `{percent}` from the
+//! theme.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use console::{Style, Term};
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressState, ProgressStyle};
+
+use crate::cli::handler::Verbosity;
+use crate::cli::progress::{default_progress_theme, percent, BAR_WIDTH, SPINNER_FRAMES};
+use crate::{OutputMode, Theme};
+
+/// How long each spinner frame shows.
+const SPINNER_FRAME_MS: u128 = 80;
+
+/// The bars standout's stderr output is printed around, if any.
+static ACTIVE: Mutex<Option<MultiProgress>> = Mutex::new(None);
+
+/// Builds indicatif bars and spinners styled by a theme.
+///
+/// All bars made from one `ProgressBars` share a [`MultiProgress`], so they
+/// stack on stderr. Cloning is cheap and clones share the bars; the type is
+/// `Send + Sync` for use from worker threads.
+#[derive(Clone)]
+pub struct ProgressBars {
+    multi: MultiProgress,
+    styles: Arc<HashMap<String, Style>>,
+    use_color: bool,
+}
+
+impl ProgressBars {
+    /// Creates bars drawn on stderr, styled by `theme`.
+    ///
+    /// Styling is applied when stderr supports color.
+    pub fn new(theme: &Theme) -> Self {
+        Self::with_target(
+            theme,
+            ProgressDrawTarget::stderr(),
+            Term::stderr().features().colors_supported(),
+        )
+    }
+
+    /// Creates bars that are never drawn.
+    pub fn hidden() -> Self {
+        Self::with_target(&Theme::new(), ProgressDrawTarget::hidden(), false)
+    }
+
+    fn with_target(theme: &Theme, target: ProgressDrawTarget, use_color: bool) -> Self {
+        let styles = default_progress_theme()
+            .merge(theme.clone())
+            .resolve_styles(Some(crate::detect_color_mode()))
+            .to_resolved_map();
+        Self {
+            multi: MultiProgress::with_draw_target(target),
+            styles: Arc::new(styles),
+            use_color,
+        }
+    }
+
+    /// Adds a bar counting to `len`, showing the bar, percentage and message.
+    pub fn bar(&self, len: u64) -> ProgressBar {
+        self.multi
+            .add(ProgressBar::new(len).with_style(self.bar_style()))
+    }
+
+    /// Adds a spinner showing its message, ticking on its own.
+    pub fn spinner(&self) -> ProgressBar {
+        let spinner = self
+            .multi
+            .add(ProgressBar::new_spinner().with_style(self.spinner_style()));
+        spinner.enable_steady_tick(std::time::Duration::from_millis(SPINNER_FRAME_MS as u64));
+        spinner
+    }
+
+    /// Returns the style of [`bar`](Self::bar): `{bar} {percent} {msg}`.
+    pub fn bar_style(&self) -> ProgressStyle {
+        self.themed("{bar} {percent} {msg}")
+    }
+
+    /// Returns the style of [`spinner`](Self::spinner): `{spinner} {msg}`.
+    pub fn spinner_style(&self) -> ProgressStyle {
+        self.themed("{spinner} {msg}")
+    }
+
+    /// Returns the [`MultiProgress`] the bars are drawn by, for adding bars
+    /// built elsewhere.
+    pub fn multi(&self) -> &MultiProgress {
+        &self.multi
+    }
+
+    /// Runs `f` with the bars cleared, redrawing them after.
+    ///
+    /// Use it around the handler's own stderr or stdout writes.
+    pub fn suspend<R>(&self, f: impl FnOnce() -> R) -> R {
+        self.multi.suspend(f)
+    }
+
+    fn themed(&self, template: &str) -> ProgressStyle {
+        let style = ProgressStyle::with_template(template).expect("valid progress template");
+        let paint = Painter {
+            styles: self.styles.clone(),
+            use_color: self.use_color,
+        };
+        let (bar, pct) = (paint.clone(), paint.clone());
+        style
+            .with_key(
+                "spinner",
+                move |state: &ProgressState, w: &mut dyn std::fmt::Write| {
+                    let frame = state.elapsed().as_millis() / SPINNER_FRAME_MS;
+                    let frame = SPINNER_FRAMES[frame as usize % SPINNER_FRAMES.len()];
+                    let _ = w.write_str(&paint.styled("progress-spinner", frame));
+                },
+            )
+            .with_key(
+                "bar",
+                move |state: &ProgressState, w: &mut dyn std::fmt::Write| {
+                    let filled = (state.fraction() as f64 * BAR_WIDTH as f64).round() as usize;
+                    let filled = filled.min(BAR_WIDTH);
+                    let _ = write!(
+                        w,
+                        "[{}{}]",
+                        bar.styled("progress-bar", &"█".repeat(filled)),
+                        bar.styled("progress-track", &"░".repeat(BAR_WIDTH - filled)),
+                    );
+                },
+            )
+            .with_key(
+                "percent",
+                move |state: &ProgressState, w: &mut dyn std::fmt::Write| {
+                    let _ = w.write_str(
+                        &pct.styled("progress-percent", &percent(state.fraction() as f64)),
+                    );
+                },
+            )
+    }
+}
+
+impl Default for ProgressBars {
+    /// Hidden bars, for handlers run without an app (e.g. in unit tests).
+    fn default() -> Self {
+        Self::hidden()
+    }
+}
+
+/// Applies the resolved `progress-*` styles inside template keys.
+#[derive(Clone)]
+struct Painter {
+    styles: Arc<HashMap<String, Style>>,
+    use_color: bool,
+}
+
+impl Painter {
+    fn styled(&self, name: &str, text: &str) -> String {
+        match self.styles.get(name) {
+            Some(style) if self.use_color => style
+                .clone()
+                .for_stderr()
+                .force_styling(true)
+                .apply_to(text)
+                .to_string(),
+            _ => text.to_string(),
+        }
+    }
+}
+
+/// Returns the bars handlers of this dispatch draw with, hidden with `-q` or
+/// when stderr isn't a terminal.
+pub(crate) fn dispatch_bars(
+    theme: &Theme,
+    verbosity: Verbosity,
+    output_mode: OutputMode,
+) -> ProgressBars {
+    let stderr = Term::stderr();
+    if verbosity == Verbosity::Quiet || !stderr.is_term() {
+        return ProgressBars::hidden();
+    }
+    let use_color = output_mode != OutputMode::Text && stderr.features().colors_supported();
+    ProgressBars::with_target(theme, ProgressDrawTarget::stderr(), use_color)
+}
+
+/// Returns the active bars' [`MultiProgress`], if a command is running.
+pub(crate) fn active() -> Option<MultiProgress> {
+    ACTIVE.lock().unwrap().clone()
+}
+
+/// Makes `bars` the active bars until dropped.
+pub(crate) struct ActiveBarsGuard;
+
+impl ActiveBarsGuard {
+    pub(crate) fn install(bars: &ProgressBars) -> Self {
+        *ACTIVE.lock().unwrap() = Some(bars.multi.clone());
+        Self
+    }
+}
+
+impl Drop for ActiveBarsGuard {
+    fn drop(&mut self) {
+        *ACTIVE.lock().unwrap() = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::Output;
+    use serde_json::json;
+    use serial_test::serial;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// A terminal recording what indicatif writes.
+    #[derive(Debug, Clone, Default)]
+    struct Recorder(Arc<Mutex<String>>);
+
+    impl indicatif::TermLike for Recorder {
+        fn width(&self) -> u16 {
+            80
+        }
+        fn move_cursor_up(&self, _n: usize) -> std::io::Result<()> {
+            Ok(())
+        }
+        fn move_cursor_down(&self, _n: usize) -> std::io::Result<()> {
+            Ok(())
+        }
+        fn move_cursor_right(&self, _n: usize) -> std::io::Result<()> {
+            Ok(())
+        }
+        fn move_cursor_left(&self, _n: usize) -> std::io::Result<()> {
+            Ok(())
+        }
+        fn write_line(&self, s: &str) -> std::io::Result<()> {
+            self.write_str(s)
+        }
+        fn write_str(&self, s: &str) -> std::io::Result<()> {
+            self.0.lock().unwrap().push_str(s);
+            Ok(())
+        }
+        fn clear_line(&self) -> std::io::Result<()> {
+            self.0.lock().unwrap().clear();
+            Ok(())
+        }
+        fn flush(&self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    /// Draws one bar at `pos`/`len` with `style` and returns the line.
+    fn draw(style: ProgressStyle, pos: u64, len: u64) -> String {
+        let term = Recorder::default();
+        let bar = ProgressBar::with_draw_target(
+            Some(len),
+            ProgressDrawTarget::term_like(Box::new(term.clone())),
+        )
+        .with_style(style)
+        .with_message("copying");
+        bar.set_position(pos);
+        bar.abandon();
+        let line = term.0.lock().unwrap();
+        line.trim_end().to_string()
+    }
+
+    fn bars(theme: &Theme, use_color: bool) -> ProgressBars {
+        ProgressBars::with_target(theme, ProgressDrawTarget::hidden(), use_color)
+    }
+
+    #[test]
+    fn test_bars_are_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<ProgressBars>();
+    }
+
+    #[test]
+    #[serial]
+    fn test_dispatch_installs_bars_and_activates_them() {
+        let seen = Arc::new(AtomicBool::new(false));
+        let seen_in_handler = seen.clone();
+        let builder = crate::cli::App::builder()
+            .command(
+                "sync",
+                move |_m, ctx| {
+                    let bars = ctx
+                        .extensions
+                        .get::<ProgressBars>()
+                        .expect("bars installed");
+                    seen_in_handler.store(active().is_some(), Ordering::SeqCst);
+                    bars.bar(3).finish_and_clear();
+                    Ok(Output::Render(json!({})))
+                },
+                "",
+            )
+            .unwrap();
+
+        builder.dispatch_from(
+            clap::Command::new("app").subcommand(clap::Command::new("sync")),
+            ["app", "sync"],
+        );
+
+        assert!(seen.load(Ordering::SeqCst));
+        assert!(active().is_none());
+    }
+
+    #[test]
+    fn test_bar_matches_progress_line() {
+        let bars = bars(&Theme::new(), false);
+        assert_eq!(
+            draw(bars.bar_style(), 1, 4),
+            "[█████░░░░░░░░░░░░░░░]  25% copying"
+        );
+    }
+
+    #[test]
+    fn test_themed_keys_use_theme_styles() {
+        let theme = Theme::new().add("progress-percent", Style::new().magenta());
+        let bars = bars(&theme, true);
+        let line = draw(bars.bar_style(), 1, 2);
+        assert!(line.contains("\x1b[35m 50%"), "{line:?}");
+    }
+
+    #[test]
+    fn test_custom_template_keeps_themed_keys() {
+        let bars = bars(&Theme::new(), false);
+        let style = bars.bar_style().template("{percent} {pos}/{len}").unwrap();
+        assert_eq!(draw(style, 3, 4), " 75% 3/4");
+    }
+
+    #[test]
+    fn test_hidden_bars_draw_nothing() {
+        let bars = ProgressBars::hidden();
+        assert!(bars.bar(2).is_hidden());
+    }
+}
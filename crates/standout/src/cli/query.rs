@@ -0,0 +1,240 @@
+//! Dot-path queries over structured output.
+//!
+//! Backs the opt-in `--query` flag (see [`App::query_flag`](crate::cli::App::query_flag)),
+//! which projects a
+//! command's serialized output before it's written, so users can pull out a
+//! field without piping to `jq`.
+//!
+//! # Syntax
+//!
+//! | Expression | Selects |
+//! |------------|---------|
+//! | `.` or empty | The whole value |
+//! | `name`, `.name` | Object field `name` |
+//! | `a.b.c` | Nested field |
+//! | `items[0]`, `items[-1]` | Array element (negative counts from the end) |
+//! | `items[].name`, `items[*].name` | `name` of every element, as an array |
+//! | `["odd key"]` | Field whose name contains `.` or `[` |
+//!
+//! Missing fields and out-of-range indices select `null`, like `jq`. Indexing
+//! into a value of the wrong type (e.g. `count.x` where `count` is a number)
+//! is an error.
+//!
+//! # Example
+//!
+//! ```rust
+//! use standout::cli::query::select;
+//! use serde_json::json;
+//!
+//! let data = json!({"items": [{"name": "a"}, {"name": "b"}]});
+//! assert_eq!(select(&data, "items[0].name").unwrap(), json!("a"));
+//! assert_eq!(select(&data, "items[].name").unwrap(), json!(["a", "b"]));
+//! ```
+
+use serde_json::Value;
+use std::sync::Arc;
+
+/// The expression given to the `--query` flag for the current dispatch.
+///
+/// Inserted into [`CommandContext::extensions`](crate::cli::CommandContext)
+/// when the flag is present, so handlers can see what was asked for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Query(pub String);
+
+/// Evaluator installed with [`App::query_hook`](crate::cli::App::query_hook).
+pub(crate) type QueryFn = Arc<dyn Fn(&Value, &str) -> Result<Value, String> + Send + Sync>;
+
+/// Carries a custom [`QueryFn`] to the renderer through the context extensions.
+#[derive(Clone)]
+pub(crate) struct QueryHook(pub(crate) QueryFn);
+
+/// One step of a parsed query.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Step {
+    Key(String),
+    Index(i64),
+    Each,
+}
+
+/// Evaluates a dot-path `expr` against `value`.
+///
+/// Returns an error message if the expression is malformed or indexes into a
+/// value of the wrong type.
+pub fn select(value: &Value, expr: &str) -> Result<Value, String> {
+    let steps = parse(expr)?;
+    apply(value, &steps)
+}
+
+fn parse(expr: &str) -> Result<Vec<Step>, String> {
+    let mut steps = Vec::new();
+    let mut chars = expr.trim().chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            '.' => {
+                chars.next();
+            }
+            '[' => {
+                chars.next();
+                let mut inner = String::new();
+                loop {
+                    match chars.next() {
+                        Some(']') => break,
+                        Some(c) => inner.push(c),
+                        None => return Err(format!("unclosed '[' in query '{}'", expr)),
+                    }
+                }
+                let inner = inner.trim();
+                let step = if inner.is_empty() || inner == "*" {
+                    Step::Each
+                } else if let Some(key) = inner
+                    .strip_prefix('"')
+                    .and_then(|rest| rest.strip_suffix('"'))
+                {
+                    Step::Key(key.to_string())
+                } else {
+                    inner
+                        .parse()
+                        .map(Step::Index)
+                        .map_err(|_| format!("invalid index '[{}]' in query '{}'", inner, expr))?
+                };
+                steps.push(step);
+            }
+            _ => {
+                let mut key = String::new();
+                while let Some(&c) = chars.peek() {
+                    if c == '.' || c == '[' {
+                        break;
+                    }
+                    key.push(c);
+                    chars.next();
+                }
+                steps.push(Step::Key(key));
+            }
+        }
+    }
+
+    Ok(steps)
+}
+
+fn apply(value: &Value, steps: &[Step]) -> Result<Value, String> {
+    let Some((step, rest)) = steps.split_first() else {
+        return Ok(value.clone());
+    };
+
+    match (step, value) {
+        (_, Value::Null) => Ok(Value::Null),
+        (Step::Key(key), Value::Object(map)) => apply(map.get(key).unwrap_or(&Value::Null), rest),
+        (Step::Index(index), Value::Array(items)) => {
+            let position = if *index < 0 {
+                items.len() as i64 + index
+            } else {
+                *index
+            };
+            let item = usize::try_from(position)
+                .ok()
+                .and_then(|position| items.get(position));
+            apply(item.unwrap_or(&Value::Null), rest)
+        }
+        (Step::Each, Value::Array(items)) => items
+            .iter()
+            .map(|item| apply(item, rest))
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        (Step::Each, Value::Object(map)) => map
+            .values()
+            .map(|item| apply(item, rest))
+            .collect::<Result<_, _>>()
+            .map(Value::Array),
+        (Step::Key(key), other) => Err(format!(
+            "cannot read field '{}' of {}",
+            key,
+            type_name(other)
+        )),
+        (Step::Index(index), other) => Err(format!(
+            "cannot read index [{}] of {}",
+            index,
+            type_name(other)
+        )),
+        (Step::Each, other) => Err(format!("cannot iterate over {}", type_name(other))),
+    }
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn data() -> Value {
+        json!({
+            "count": 2,
+            "items": [
+                {"name": "alpha", "tags": ["x"]},
+                {"name": "beta", "tags": []}
+            ],
+            "meta": {"a.b": true}
+        })
+    }
+
+    #[test]
+    fn test_select_whole_value() {
+        assert_eq!(select(&data(), "").unwrap(), data());
+        assert_eq!(select(&data(), ".").unwrap(), data());
+    }
+
+    #[test]
+    fn test_select_fields_and_indices() {
+        assert_eq!(select(&data(), "count").unwrap(), json!(2));
+        assert_eq!(select(&data(), ".items[0].name").unwrap(), json!("alpha"));
+        assert_eq!(select(&data(), "items[-1].name").unwrap(), json!("beta"));
+    }
+
+    #[test]
+    fn test_select_each() {
+        assert_eq!(
+            select(&data(), "items[].name").unwrap(),
+            json!(["alpha", "beta"])
+        );
+        assert_eq!(
+            select(&data(), "items[*].tags[0]").unwrap(),
+            json!(["x", null])
+        );
+    }
+
+    #[test]
+    fn test_select_quoted_key() {
+        assert_eq!(select(&data(), r#"meta["a.b"]"#).unwrap(), json!(true));
+    }
+
+    #[test]
+    fn test_select_missing_is_null() {
+        assert_eq!(select(&data(), "missing.deeper").unwrap(), json!(null));
+        assert_eq!(select(&data(), "items[5]").unwrap(), json!(null));
+    }
+
+    #[test]
+    fn test_select_type_errors() {
+        let err = select(&data(), "count.value").unwrap_err();
+        assert_eq!(err, "cannot read field 'value' of a number");
+
+        let err = select(&data(), "meta[0]").unwrap_err();
+        assert_eq!(err, "cannot read index [0] of an object");
+    }
+
+    #[test]
+    fn test_select_malformed() {
+        assert!(select(&data(), "items[0").is_err());
+        assert!(select(&data(), "items[abc]").is_err());
+    }
+}
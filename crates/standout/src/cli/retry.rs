@@ -0,0 +1,338 @@
+//! Retries for flaky handlers.
+//!
+//! Commands registered with a [`RetryPolicy`] through
+//! [`App::retry`](crate::cli::App::retry) run their handler again when it
+//! fails, waiting longer before each attempt. Each retry prints a themed
+//! notice on stderr (unless `-q` was given):
+//!
+//! ```text
+//! retrying sync: connection refused (attempt 2 of 3 in 200ms)
+//! ```
+//!
+//! Only the handler is retried: hooks and rendering run once, and an
+//! interrupted command isn't retried.
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use console::{Style, Term};
+use serde::Serialize;
+
+use crate::cli::handler::{CommandContext, Verbosity};
+use crate::{render_auto, OutputMode, RenderError, Theme};
+
+/// Default template for retry notices.
+pub const DEFAULT_RETRY_TEMPLATE: &str = include_str!("retry.txt");
+
+/// How often an interrupted wait between attempts is noticed.
+const CANCEL_POLL: Duration = Duration::from_millis(20);
+
+/// Decides whether an error is retried.
+type RetryPredicate = Arc<dyn Fn(&anyhow::Error) -> bool + Send + Sync>;
+
+/// When and how often a failing handler is run again.
+///
+/// The delay before the first retry is the [`backoff`](Self::backoff) and
+/// doubles on each retry after that, up to [`max_backoff`](Self::max_backoff).
+///
+/// ```rust
+/// use std::time::Duration;
+/// use standout::cli::RetryPolicy;
+///
+/// let policy = RetryPolicy::new(4)
+///     .backoff(Duration::from_millis(100))
+///     .max_backoff(Duration::from_millis(300))
+///     .retry_on(|err| err.to_string().contains("timed out"));
+///
+/// assert_eq!(policy.delay(1), Duration::from_millis(100));
+/// assert_eq!(policy.delay(2), Duration::from_millis(200));
+/// assert_eq!(policy.delay(3), Duration::from_millis(300));
+/// ```
+#[derive(Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    backoff: Duration,
+    max_backoff: Duration,
+    retry_on: Option<RetryPredicate>,
+}
+
+impl RetryPolicy {
+    /// Runs the handler up to `max_attempts` times in total (at least once).
+    ///
+    /// Every error is retried, after 100ms, then 200ms, and so on, up to 10s.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+            retry_on: None,
+        }
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn backoff(mut self, delay: Duration) -> Self {
+        self.backoff = delay;
+        self
+    }
+
+    /// Caps the delay between attempts.
+    pub fn max_backoff(mut self, delay: Duration) -> Self {
+        self.max_backoff = delay;
+        self
+    }
+
+    /// Only retries errors `predicate` returns true for; others fail at once.
+    pub fn retry_on<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&anyhow::Error) -> bool + Send + Sync + 'static,
+    {
+        self.retry_on = Some(Arc::new(predicate));
+        self
+    }
+
+    /// Returns how many times the handler runs at most.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns the delay before retry number `retry`, counting from 1.
+    pub fn delay(&self, retry: u32) -> Duration {
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        self.backoff
+            .checked_mul(factor)
+            .unwrap_or(self.max_backoff)
+            .min(self.max_backoff)
+    }
+
+    /// Returns true if `error` should be retried.
+    pub fn should_retry(&self, error: &anyhow::Error) -> bool {
+        self.retry_on
+            .as_ref()
+            .is_none_or(|retry_on| retry_on(error))
+    }
+}
+
+impl fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("max_attempts", &self.max_attempts)
+            .field("backoff", &self.backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("retry_on", &self.retry_on.is_some())
+            .finish()
+    }
+}
+
+/// A retry about to happen, as passed to the notice template.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RetryNotice {
+    /// The command, e.g. `remote sync`.
+    pub command: String,
+    /// The error of the failed attempt.
+    pub error: String,
+    /// The number of the next attempt.
+    pub attempt: u32,
+    /// How many attempts are made at most.
+    pub max_attempts: u32,
+    /// The wait before the next attempt, e.g. `200ms` or `1.5s`.
+    pub delay: String,
+}
+
+/// Returns the theme retry notices are rendered with: the help styles plus
+/// `retry` for the `retrying` label.
+pub fn default_retry_theme() -> Theme {
+    crate::cli::help::default_help_theme().add("retry", Style::new().yellow().bold())
+}
+
+/// Renders a retry notice through `template`.
+pub fn render_retry_notice(
+    notice: &RetryNotice,
+    template: &str,
+    theme: &Theme,
+    mode: OutputMode,
+) -> Result<String, RenderError> {
+    render_auto(template, notice, theme, mode).map(|output| output.trim_end().to_string())
+}
+
+/// Runs `run`, running it again as the command's [`RetryPolicy`] (in
+/// `ctx.extensions`) allows while it fails.
+pub(crate) fn with_retries<T>(
+    ctx: &CommandContext,
+    theme: &Theme,
+    output_mode: OutputMode,
+    mut run: impl FnMut() -> anyhow::Result<T>,
+) -> anyhow::Result<T> {
+    let Some(policy) = ctx.extensions.get::<RetryPolicy>() else {
+        return run();
+    };
+    let mut attempt = 1;
+    loop {
+        match run() {
+            Err(e)
+                if attempt < policy.max_attempts
+                    && !ctx.is_cancelled()
+                    && policy.should_retry(&e) =>
+            {
+                let delay = policy.delay(attempt);
+                attempt += 1;
+                if ctx.verbosity != Verbosity::Quiet {
+                    let notice = RetryNotice {
+                        command: ctx.command_path.join(" "),
+                        error: e.to_string(),
+                        attempt,
+                        max_attempts: policy.max_attempts,
+                        delay: format_delay(delay),
+                    };
+                    let line = notice_line(&notice, theme, output_mode);
+                    crate::cli::progress::suspended(|| eprintln!("{}", line));
+                }
+                if !wait_unless_cancelled(ctx, delay) {
+                    return Err(e);
+                }
+            }
+            result => return result,
+        }
+    }
+}
+
+fn notice_line(notice: &RetryNotice, theme: &Theme, output_mode: OutputMode) -> String {
+    let theme = default_retry_theme().merge(theme.clone());
+    let mode = if output_mode != OutputMode::Text && Term::stderr().features().colors_supported() {
+        OutputMode::Term
+    } else {
+        OutputMode::Text
+    };
+    render_retry_notice(notice, DEFAULT_RETRY_TEMPLATE, &theme, mode).unwrap_or_else(|_| {
+        format!(
+            "retrying {}: {} (attempt {} of {})",
+            notice.command, notice.error, notice.attempt, notice.max_attempts
+        )
+    })
+}
+
+/// Sleeps for `delay`, returning false early if the command is interrupted.
+fn wait_unless_cancelled(ctx: &CommandContext, delay: Duration) -> bool {
+    let until = Instant::now() + delay;
+    loop {
+        if ctx.is_cancelled() {
+            return false;
+        }
+        let now = Instant::now();
+        if now >= until {
+            return true;
+        }
+        std::thread::sleep((until - now).min(CANCEL_POLL));
+    }
+}
+
+fn format_delay(delay: Duration) -> String {
+    if delay < Duration::from_secs(1) {
+        format!("{}ms", delay.as_millis())
+    } else {
+        format!("{:.1}s", delay.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    fn ctx_with(policy: RetryPolicy) -> CommandContext {
+        let mut ctx = CommandContext {
+            command_path: vec!["sync".into()],
+            verbosity: Verbosity::Quiet,
+            ..Default::default()
+        };
+        ctx.extensions.insert(policy);
+        ctx
+    }
+
+    #[test]
+    fn test_delay_doubles_up_to_max() {
+        let policy = RetryPolicy::new(10)
+            .backoff(Duration::from_millis(50))
+            .max_backoff(Duration::from_secs(1));
+        assert_eq!(policy.delay(1), Duration::from_millis(50));
+        assert_eq!(policy.delay(3), Duration::from_millis(200));
+        assert_eq!(policy.delay(8), Duration::from_secs(1));
+        assert_eq!(policy.delay(40), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_with_retries_until_success() {
+        let ctx = ctx_with(RetryPolicy::new(3).backoff(Duration::ZERO));
+        let calls = Cell::new(0);
+
+        let result = with_retries(&ctx, &Theme::new(), OutputMode::Text, || {
+            calls.set(calls.get() + 1);
+            if calls.get() < 3 {
+                anyhow::bail!("flaky")
+            }
+            Ok(calls.get())
+        });
+
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn test_with_retries_gives_up() {
+        let ctx = ctx_with(RetryPolicy::new(2).backoff(Duration::ZERO));
+        let calls = Cell::new(0);
+
+        let result: anyhow::Result<()> =
+            with_retries(&ctx, &Theme::new(), OutputMode::Text, || {
+                calls.set(calls.get() + 1);
+                anyhow::bail!("down")
+            });
+
+        assert_eq!(result.unwrap_err().to_string(), "down");
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_with_retries_respects_predicate() {
+        let policy = RetryPolicy::new(5)
+            .backoff(Duration::ZERO)
+            .retry_on(|err| err.to_string() == "timeout");
+        let ctx = ctx_with(policy);
+        let calls = Cell::new(0);
+
+        let result: anyhow::Result<()> =
+            with_retries(&ctx, &Theme::new(), OutputMode::Text, || {
+                calls.set(calls.get() + 1);
+                anyhow::bail!(if calls.get() == 1 {
+                    "timeout"
+                } else {
+                    "denied"
+                })
+            });
+
+        assert!(result.is_err());
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_render_retry_notice() {
+        let notice = RetryNotice {
+            command: "remote sync".into(),
+            error: "connection refused".into(),
+            attempt: 2,
+            max_attempts: 3,
+            delay: format_delay(Duration::from_millis(200)),
+        };
+        let output = render_retry_notice(
+            &notice,
+            DEFAULT_RETRY_TEMPLATE,
+            &default_retry_theme(),
+            OutputMode::Text,
+        )
+        .unwrap();
+        assert_eq!(
+            output,
+            "retrying remote sync: connection refused (attempt 2 of 3 in 200ms)"
+        );
+        assert_eq!(format_delay(Duration::from_millis(1500)), "1.5s");
+    }
+}
@@ -0,0 +1,66 @@
+//! Captured output of [`App::test_run`](crate::cli::App::test_run).
+//!
+//! `test_run` dispatches in-process with a forced output mode and fixed
+//! environment detection, and returns what `run` would have printed:
+//!
+//! ```rust,ignore
+//! let out = app.test_run(cmd, ["myapp", "list"], OutputMode::Text);
+//! assert_eq!(out.exit_code, 0);
+//! assert_eq!(out.stdout, "2 items");
+//! ```
+
+use crate::cli::handler::RunResult;
+
+/// What a command printed and how it exited.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TestOutput {
+    /// Rendered output, without the trailing newline `run` adds. Binary
+    /// output is converted lossily to UTF-8.
+    pub stdout: String,
+    /// Errors, output sent to stderr, and warnings, one per line.
+    pub stderr: String,
+    /// The exit code `run` would exit with (0 on success).
+    pub exit_code: i32,
+}
+
+impl TestOutput {
+    /// Splits a dispatch result into stdout, stderr and exit code, the way
+    /// `run` prints it.
+    pub(crate) fn from_result(result: RunResult) -> Self {
+        let mut output = Self::default();
+        match result {
+            RunResult::Handled(text) => output.stdout = text,
+            RunResult::Binary(bytes, _) => {
+                output.stdout = String::from_utf8_lossy(&bytes).into_owned()
+            }
+            RunResult::Stderr(text) => output.stderr = text,
+            RunResult::Error(message) => {
+                output.stderr = message;
+                output.exit_code = 1;
+            }
+            RunResult::Exit(message, code) => {
+                output.stderr = message;
+                output.exit_code = i32::from(code);
+            }
+            RunResult::NoMatch(_) => {
+                output.stderr = "no command matched".to_string();
+                output.exit_code = 2;
+            }
+            _ => {}
+        }
+        output
+    }
+
+    /// Appends a line to stderr.
+    pub(crate) fn push_stderr(&mut self, line: &str) {
+        if !self.stderr.is_empty() && !self.stderr.ends_with('\n') {
+            self.stderr.push('\n');
+        }
+        self.stderr.push_str(line);
+    }
+
+    /// Returns true if the command exited with code 0.
+    pub fn success(&self) -> bool {
+        self.exit_code == 0
+    }
+}
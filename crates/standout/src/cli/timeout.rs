@@ -0,0 +1,168 @@
+//! Time limits for handlers.
+//!
+//! Commands given a timeout (see
+//! [`App::command_timeout`](crate::cli::App::command_timeout)) run under a
+//! watchdog. When the time is up, the command's
+//! [`CancellationToken`](crate::cli::CancellationToken) is cancelled, so a
+//! handler checking `ctx.is_cancelled()` can stop, and the command fails
+//! with a themed error and exit code 124:
+//!
+//! ```text
+//! error: `sync` timed out after 30s
+//! ```
+//!
+//! Handlers can't be stopped from the outside, so when the app is run with
+//! `run()`, a handler still going shortly after the deadline gets the error
+//! printed and the process exits, rather than hanging a CI job.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::time::Duration;
+
+use console::Style;
+use serde::Serialize;
+
+use crate::cli::handler::{CancellationToken, ExitError};
+use crate::{render_auto, OutputMode, RenderError, Theme};
+
+/// Default template for timeout errors.
+pub const DEFAULT_TIMEOUT_TEMPLATE: &str = include_str!("timeout.txt");
+
+/// How long a cancelled handler gets to return before the process exits.
+pub(crate) const TIMEOUT_GRACE: Duration = Duration::from_secs(2);
+
+/// A command that ran out of time, as passed to the timeout template.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct TimedOut {
+    /// The command, e.g. `remote sync`.
+    pub command: String,
+    /// Its time limit, e.g. `500ms` or `30s`.
+    pub timeout: String,
+}
+
+impl TimedOut {
+    /// Describes `command` running out of its `timeout`.
+    pub fn new(command: impl Into<String>, timeout: Duration) -> Self {
+        Self {
+            command: command.into(),
+            timeout: format_duration(timeout),
+        }
+    }
+}
+
+/// Returns the theme timeout errors are rendered with: the help styles plus
+/// `timeout` for the `error:` label.
+pub fn default_timeout_theme() -> Theme {
+    crate::cli::help::default_help_theme().add("timeout", Style::new().red().bold())
+}
+
+/// Renders a timeout error through `template`.
+pub fn render_timeout(
+    timed_out: &TimedOut,
+    template: &str,
+    theme: &Theme,
+    mode: OutputMode,
+) -> Result<String, RenderError> {
+    render_auto(template, timed_out, theme, mode).map(|output| output.trim_end().to_string())
+}
+
+/// Watches a running handler, cancelling `token` when its time is up.
+///
+/// Dropping the watchdog means the handler returned.
+pub(crate) struct Watchdog {
+    timed_out: Arc<AtomicBool>,
+    _done: Sender<()>,
+}
+
+impl Watchdog {
+    /// Starts watching. If `exit_message` is given and the handler is still
+    /// running [`TIMEOUT_GRACE`] after the deadline, the message is printed
+    /// on stderr and the process exits with [`ExitError::TIMEOUT`].
+    pub(crate) fn start(
+        timeout: Duration,
+        token: CancellationToken,
+        exit_message: Option<String>,
+    ) -> Self {
+        let (done, finished) = mpsc::channel::<()>();
+        let timed_out = Arc::new(AtomicBool::new(false));
+        let flag = timed_out.clone();
+        std::thread::spawn(move || {
+            if finished.recv_timeout(timeout) != Err(RecvTimeoutError::Timeout) {
+                return;
+            }
+            flag.store(true, Ordering::SeqCst);
+            token.cancel();
+            if let Some(message) = exit_message {
+                if finished.recv_timeout(TIMEOUT_GRACE) == Err(RecvTimeoutError::Timeout) {
+                    crate::cli::progress::suspended(|| eprintln!("{}", message));
+                    std::process::exit(i32::from(ExitError::TIMEOUT));
+                }
+            }
+        });
+        Self {
+            timed_out,
+            _done: done,
+        }
+    }
+
+    /// Returns true if the deadline passed before the handler returned.
+    pub(crate) fn timed_out(&self) -> bool {
+        self.timed_out.load(Ordering::SeqCst)
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    if duration < Duration::from_secs(1) {
+        format!("{}ms", duration.as_millis())
+    } else if duration.subsec_millis() == 0 {
+        format!("{}s", duration.as_secs())
+    } else {
+        format!("{:.1}s", duration.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_timeout() {
+        let output = render_timeout(
+            &TimedOut::new("remote sync", Duration::from_secs(30)),
+            DEFAULT_TIMEOUT_TEMPLATE,
+            &default_timeout_theme(),
+            OutputMode::Text,
+        )
+        .unwrap();
+        assert_eq!(output, "error: `remote sync` timed out after 30s");
+        assert_eq!(
+            TimedOut::new("x", Duration::from_millis(250)).timeout,
+            "250ms"
+        );
+        assert_eq!(
+            TimedOut::new("x", Duration::from_millis(1500)).timeout,
+            "1.5s"
+        );
+    }
+
+    #[test]
+    fn test_watchdog_cancels_after_timeout() {
+        let token = CancellationToken::new();
+        let watchdog = Watchdog::start(Duration::from_millis(10), token.clone(), None);
+        while !token.is_cancelled() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert!(watchdog.timed_out());
+    }
+
+    #[test]
+    fn test_watchdog_stops_when_dropped() {
+        let token = CancellationToken::new();
+        let watchdog = Watchdog::start(Duration::from_millis(30), token.clone(), None);
+        assert!(!watchdog.timed_out());
+        drop(watchdog);
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(!token.is_cancelled());
+    }
+}
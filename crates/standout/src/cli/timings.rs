@@ -0,0 +1,242 @@
+//! Per-stage timing diagnostics behind `--timings`.
+//!
+//! With [`App::timings_flag`](crate::cli::App::timings_flag), `--timings`
+//! records how long each stage of a command took and prints a themed summary
+//! on stderr after the output:
+//!
+//! ```text
+//! timings
+//!   parse:        0.412 ms
+//!   hooks:        0.031 ms
+//!   handler:    182.904 ms
+//!   render:       1.337 ms
+//!   output:       0.058 ms
+//!   total:      184.742 ms
+//! ```
+//!
+//! With `--output=json`, the report is embedded in the JSON output under
+//! `_timings` instead, so it travels with the data it describes. Writing the
+//! output happens after embedding, so the `output` stage is left out there.
+//!
+//! Handlers can add their own stages through the [`Timings`] in
+//! `ctx.extensions`:
+//!
+//! ```rust,ignore
+//! if let Some(timings) = ctx.extensions.get::<Timings>() {
+//!     timings.lap("handler");
+//! }
+//! ```
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::{render_auto, OutputMode, RenderError, Theme};
+
+/// Default template for the timings summary.
+pub const DEFAULT_TIMINGS_TEMPLATE: &str = include_str!("timings.txt");
+
+/// A stopwatch splitting a command's run into named stages.
+///
+/// Clones share the same measurements.
+#[derive(Debug, Clone)]
+pub struct Timings {
+    inner: Arc<TimingsInner>,
+}
+
+#[derive(Debug)]
+struct TimingsInner {
+    started: Instant,
+    last: Mutex<Instant>,
+    stages: Mutex<Vec<(&'static str, Duration)>>,
+}
+
+impl Timings {
+    /// Starts timing now.
+    pub fn new() -> Self {
+        Self::starting_at(Instant::now())
+    }
+
+    /// Starts timing from an earlier instant, e.g. before argument parsing.
+    pub fn starting_at(started: Instant) -> Self {
+        Self {
+            inner: Arc::new(TimingsInner {
+                started,
+                last: Mutex::new(started),
+                stages: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Ends the current stage, charging the time since the previous lap to
+    /// `stage`.
+    ///
+    /// Laps with the same name add up, keeping the position of the first.
+    pub fn lap(&self, stage: &'static str) {
+        let now = Instant::now();
+        let elapsed = now - std::mem::replace(&mut *self.inner.last.lock().unwrap(), now);
+        let mut stages = self.inner.stages.lock().unwrap();
+        match stages.iter_mut().find(|(name, _)| *name == stage) {
+            Some((_, duration)) => *duration += elapsed,
+            None => stages.push((stage, elapsed)),
+        }
+    }
+
+    /// Returns the recorded stages in the order they first ran.
+    pub fn stages(&self) -> Vec<(&'static str, Duration)> {
+        self.inner.stages.lock().unwrap().clone()
+    }
+
+    /// Returns the sum of the recorded stages.
+    pub fn total(&self) -> Duration {
+        self.inner
+            .stages
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, d)| *d)
+            .sum()
+    }
+
+    /// Returns the time since timing started, including any unfinished stage.
+    pub fn elapsed(&self) -> Duration {
+        self.inner.started.elapsed()
+    }
+
+    /// Builds the serializable report of the recorded stages.
+    pub fn report(&self) -> TimingsReport {
+        TimingsReport {
+            stages: self
+                .stages()
+                .into_iter()
+                .map(|(name, duration)| StageTiming {
+                    name: name.to_string(),
+                    ms: millis(duration),
+                })
+                .collect(),
+            total_ms: millis(self.total()),
+        }
+    }
+}
+
+impl Default for Timings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// The duration of one stage.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StageTiming {
+    /// Stage name (`parse`, `hooks`, `handler`, `render`, `output`, ...).
+    pub name: String,
+    /// Duration in milliseconds.
+    pub ms: f64,
+}
+
+/// Stage durations and their total, as embedded in JSON output.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct TimingsReport {
+    /// Stages in the order they first ran.
+    pub stages: Vec<StageTiming>,
+    /// Sum of all stages in milliseconds.
+    pub total_ms: f64,
+}
+
+/// Renders a timings summary through `template`.
+///
+/// The template receives `stages` and `total`, each with a `label` (the
+/// padded `name:`) and a `time` (right-aligned milliseconds) for alignment.
+pub fn render_timings(
+    report: &TimingsReport,
+    template: &str,
+    theme: &Theme,
+    mode: OutputMode,
+) -> Result<String, RenderError> {
+    #[derive(Serialize)]
+    struct Row {
+        label: String,
+        time: String,
+    }
+    fn row(name: &str, ms: f64) -> Row {
+        Row {
+            label: format!("{:<8}", format!("{}:", name)),
+            time: format!("{:>10.3} ms", ms),
+        }
+    }
+
+    let data = serde_json::json!({
+        "stages": report
+            .stages
+            .iter()
+            .map(|stage| row(&stage.name, stage.ms))
+            .collect::<Vec<_>>(),
+        "total": row("total", report.total_ms),
+    });
+    render_auto(template, &data, theme, mode).map(|output| output.trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::help::default_help_theme;
+
+    #[test]
+    fn test_laps_accumulate_by_stage() {
+        let timings = Timings::new();
+        timings.lap("parse");
+        timings.lap("hooks");
+        std::thread::sleep(Duration::from_millis(2));
+        timings.lap("handler");
+        timings.lap("hooks");
+
+        let names: Vec<_> = timings.stages().iter().map(|(name, _)| *name).collect();
+        assert_eq!(names, ["parse", "hooks", "handler"]);
+        assert!(timings.stages()[2].1 >= Duration::from_millis(2));
+        assert!(timings.total() <= timings.elapsed());
+    }
+
+    #[test]
+    fn test_render_timings_summary() {
+        let report = TimingsReport {
+            stages: vec![
+                StageTiming {
+                    name: "parse".into(),
+                    ms: 0.412,
+                },
+                StageTiming {
+                    name: "handler".into(),
+                    ms: 182.904,
+                },
+            ],
+            total_ms: 183.316,
+        };
+        let output = render_timings(
+            &report,
+            DEFAULT_TIMINGS_TEMPLATE,
+            &default_help_theme(),
+            OutputMode::Text,
+        )
+        .unwrap();
+
+        assert_eq!(
+            output,
+            "timings\n  parse:        0.412 ms\n  handler:    182.904 ms\n  total:      183.316 ms"
+        );
+    }
+
+    #[test]
+    fn test_report_serializes_milliseconds() {
+        let timings = Timings::new();
+        timings.lap("render");
+        let json = serde_json::to_value(timings.report()).unwrap();
+        assert_eq!(json["stages"][0]["name"], "render");
+        assert!(json["stages"][0]["ms"].is_f64());
+        assert!(json["total_ms"].is_f64());
+    }
+}
@@ -0,0 +1,147 @@
+//! Argument validation with aggregated errors.
+//!
+//! Clap stops at the first argument it can't parse. Checks that depend on
+//! several arguments, or that users should see all at once, go in validators
+//! registered with [`App::validate`](crate::cli::App::validate). They run
+//! after parsing and before the handler, each adding what it finds to a
+//! shared [`ValidationErrors`]. If any were added, the handler doesn't run
+//! and the errors are rendered together on stderr:
+//!
+//! ```text
+//! error: 2 invalid arguments
+//!   --port: must be between 1 and 65535
+//!   --since: must be before --until
+//! ```
+//!
+//! The command then exits with the usage exit code (2), like a clap error.
+//! With `--output json`, the errors are reported as a `validation_error`
+//! with an `errors` list.
+
+use console::Style;
+use serde::Serialize;
+
+use crate::{render_auto, OutputMode, RenderError, Theme};
+
+/// Default template for validation errors.
+pub const DEFAULT_VALIDATION_TEMPLATE: &str = include_str!("validation.txt");
+
+/// One problem found by a validator.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ValidationError {
+    /// The argument at fault as users type it (e.g. `--port`), if any.
+    pub arg: Option<String>,
+    /// What's wrong with it.
+    pub message: String,
+}
+
+/// The problems found by a command's validators.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ValidationErrors {
+    /// The problems, in the order they were found.
+    pub errors: Vec<ValidationError>,
+}
+
+impl ValidationErrors {
+    /// Creates an empty collection.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a problem with the argument `arg`.
+    pub fn arg(&mut self, arg: impl Into<String>, message: impl Into<String>) -> &mut Self {
+        self.errors.push(ValidationError {
+            arg: Some(arg.into()),
+            message: message.into(),
+        });
+        self
+    }
+
+    /// Adds a problem that isn't tied to one argument.
+    pub fn error(&mut self, message: impl Into<String>) -> &mut Self {
+        self.errors.push(ValidationError {
+            arg: None,
+            message: message.into(),
+        });
+        self
+    }
+
+    /// Returns true if no problems were found.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns the number of problems found.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Returns the errors as the JSON error object of `--output json`.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "error": {
+                "code": "validation_error",
+                "message": format!("{} invalid argument(s)", self.len()),
+                "errors": self.errors,
+            }
+        })
+    }
+}
+
+/// Returns the theme validation errors are rendered with: the help styles
+/// plus `invalid` for the `error:` label.
+pub fn default_validation_theme() -> Theme {
+    crate::cli::help::default_help_theme().add("invalid", Style::new().red().bold())
+}
+
+/// Renders validation errors through `template`.
+pub fn render_validation_errors(
+    errors: &ValidationErrors,
+    template: &str,
+    theme: &Theme,
+    mode: OutputMode,
+) -> Result<String, RenderError> {
+    render_auto(template, errors, theme, mode).map(|output| output.trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_validation_errors() {
+        let mut errors = ValidationErrors::new();
+        errors
+            .arg("--port", "must be between 1 and 65535")
+            .error("nothing to sync");
+
+        let output = render_validation_errors(
+            &errors,
+            DEFAULT_VALIDATION_TEMPLATE,
+            &default_validation_theme(),
+            OutputMode::Text,
+        )
+        .unwrap();
+
+        assert_eq!(
+            output,
+            "error: 2 invalid arguments\n  --port: must be between 1 and 65535\n  nothing to sync"
+        );
+    }
+
+    #[test]
+    fn test_validation_errors_to_json() {
+        let mut errors = ValidationErrors::new();
+        errors.arg("--port", "too large");
+
+        assert_eq!(
+            errors.to_json(),
+            serde_json::json!({
+                "error": {
+                    "code": "validation_error",
+                    "message": "1 invalid argument(s)",
+                    "errors": [{ "arg": "--port", "message": "too large" }],
+                }
+            })
+        );
+    }
+}
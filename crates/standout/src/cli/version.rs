@@ -0,0 +1,183 @@
+//! Rich `--version` output.
+//!
+//! Clap's `--version` prints a bare `myapp 1.2.3`. When an app registers a
+//! [`BuildInfo`] with [`App::build_info`](crate::cli::App::build_info),
+//! standout renders `--version` / `-V` through a themed template instead:
+//!
+//! ```text
+//! myapp 1.2.3
+//!   commit:   3f2a9c1
+//!   built:    2026-10-16
+//!   target:   x86_64-unknown-linux-gnu
+//!   features: schema, input-editor
+//! ```
+//!
+//! With `--output=json` (or any structured mode) the `BuildInfo` is
+//! serialized instead, for bug-report tooling and update checkers.
+//!
+//! [`build_info!`](crate::build_info) fills in the name and version from
+//! Cargo, plus the git SHA, build date and target from environment variables
+//! a build script can set.
+
+use serde::Serialize;
+
+use crate::{render_auto, OutputMode, RenderError, Theme};
+
+/// Default template for version output.
+pub const DEFAULT_VERSION_TEMPLATE: &str = include_str!("version.txt");
+
+/// Build metadata shown by `--version`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct BuildInfo {
+    /// Program name.
+    pub name: String,
+    /// Version string.
+    pub version: String,
+    /// Git commit the binary was built from.
+    pub git_sha: Option<String>,
+    /// Build date or timestamp.
+    pub build_date: Option<String>,
+    /// Target triple (e.g. `x86_64-unknown-linux-gnu`).
+    pub target: Option<String>,
+    /// Enabled Cargo features.
+    pub features: Vec<String>,
+}
+
+impl BuildInfo {
+    /// Creates build info with just a name and version.
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the git commit.
+    pub fn with_git_sha(mut self, sha: impl Into<String>) -> Self {
+        self.git_sha = Some(sha.into());
+        self
+    }
+
+    /// Sets the build date.
+    pub fn with_build_date(mut self, date: impl Into<String>) -> Self {
+        self.build_date = Some(date.into());
+        self
+    }
+
+    /// Sets the target triple.
+    pub fn with_target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    /// Sets the enabled features.
+    pub fn with_features<I, S>(mut self, features: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.features = features.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+/// Creates a [`BuildInfo`] for the calling crate.
+///
+/// The name and version come from `CARGO_PKG_NAME` and `CARGO_PKG_VERSION`.
+/// The git SHA, build date and target are read at compile time from
+/// `STANDOUT_GIT_SHA`, `STANDOUT_BUILD_DATE` and `STANDOUT_TARGET`, which a
+/// build script can set:
+///
+/// ```rust,ignore
+/// // build.rs
+/// println!("cargo:rustc-env=STANDOUT_TARGET={}", std::env::var("TARGET").unwrap());
+/// ```
+///
+/// Unset variables leave the field empty. Features can be added with
+/// [`BuildInfo::with_features`].
+///
+/// # Example
+///
+/// ```rust
+/// let info = standout::build_info!();
+/// assert_eq!(info.name, "standout");
+/// ```
+#[macro_export]
+macro_rules! build_info {
+    () => {{
+        let mut info =
+            $crate::cli::BuildInfo::new(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+        info.git_sha = option_env!("STANDOUT_GIT_SHA").map(str::to_string);
+        info.build_date = option_env!("STANDOUT_BUILD_DATE").map(str::to_string);
+        info.target = option_env!("STANDOUT_TARGET").map(str::to_string);
+        info
+    }};
+}
+
+/// Renders build info through `template`, or serializes it in structured modes.
+pub fn render_version(
+    info: &BuildInfo,
+    template: &str,
+    theme: &Theme,
+    mode: OutputMode,
+) -> Result<String, RenderError> {
+    render_auto(template, info, theme, mode).map(|output| output.trim_end().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::help::default_help_theme;
+
+    fn info() -> BuildInfo {
+        BuildInfo::new("myapp", "1.2.3")
+            .with_git_sha("3f2a9c1")
+            .with_target("x86_64-unknown-linux-gnu")
+            .with_features(["schema", "input-editor"])
+    }
+
+    #[test]
+    fn test_render_version_text() {
+        let output = render_version(
+            &info(),
+            DEFAULT_VERSION_TEMPLATE,
+            &default_help_theme(),
+            OutputMode::Text,
+        )
+        .unwrap();
+
+        assert!(output.starts_with("myapp 1.2.3\n"), "{output}");
+        assert!(output.contains("commit:   3f2a9c1"), "{output}");
+        assert!(
+            output.contains("features: schema, input-editor"),
+            "{output}"
+        );
+        // Unset fields are omitted
+        assert!(!output.contains("built:"), "{output}");
+    }
+
+    #[test]
+    fn test_render_version_json() {
+        let output = render_version(
+            &info(),
+            DEFAULT_VERSION_TEMPLATE,
+            &default_help_theme(),
+            OutputMode::Json,
+        )
+        .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(value["version"], "1.2.3");
+        assert_eq!(value["git_sha"], "3f2a9c1");
+        assert!(value["build_date"].is_null());
+        assert_eq!(value["features"][1], "input-editor");
+    }
+
+    #[test]
+    fn test_build_info_macro() {
+        let info = crate::build_info!();
+        assert_eq!(info.name, "standout");
+        assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+    }
+}
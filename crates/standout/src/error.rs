@@ -0,0 +1,98 @@
+//! One error type for application code.
+//!
+//! Each subsystem has its own error: rendering, setup, stylesheets, hooks,
+//! configuration, input, queries and piping. [`Error`] converts from all of them, so code
+//! that touches several can use `?` throughout and return a single type.
+//!
+//! ```rust,ignore
+//! fn report(theme: &str, data: &Report) -> Result<String, standout::Error> {
+//!     let theme = Theme::from_css(theme)?;          // StylesheetError
+//!     let output = render(TEMPLATE, data, &theme)?; // RenderError
+//!     Ok(SimplePipe::to_command("sort", ["-u"]).pipe(&output)?) // PipeError
+//! }
+//! ```
+
+use crate::cli::config::ConfigError;
+use crate::cli::HookError;
+use crate::input::InputError;
+use crate::pipe::PipeError;
+use crate::seeker::{ParseError, SeekerError};
+use crate::{RenderError, SetupError, StylesheetError};
+
+/// Any error produced by standout's subsystems.
+///
+/// Displays as the wrapped error; [`source`](std::error::Error::source) is
+/// the wrapped error's source. New subsystems add variants, so matches need
+/// a wildcard arm.
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {
+    /// Template rendering failed.
+    #[error(transparent)]
+    Render(#[from] RenderError),
+    /// App setup failed.
+    #[error(transparent)]
+    Setup(#[from] SetupError),
+    /// A stylesheet couldn't be loaded or parsed.
+    #[error(transparent)]
+    Stylesheet(#[from] StylesheetError),
+    /// A hook failed.
+    #[error(transparent)]
+    Hook(#[from] HookError),
+    /// Configuration couldn't be loaded.
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    /// Input collection failed.
+    #[error(transparent)]
+    Input(#[from] InputError),
+    /// A query couldn't be built or run.
+    #[error(transparent)]
+    Seeker(#[from] SeekerError),
+    /// A query or filter expression couldn't be parsed.
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    /// Piping output to a command failed.
+    #[error(transparent)]
+    Pipe(#[from] PipeError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fails_with<E>(e: E) -> Result<(), Error>
+    where
+        Error: From<E>,
+    {
+        Err(e)?
+    }
+
+    #[test]
+    fn test_converts_and_displays_transparently() {
+        let err = fails_with(SetupError::ThemeNotFound("dark".into())).unwrap_err();
+        assert!(matches!(err, Error::Setup(_)));
+        assert_eq!(err.to_string(), "theme not found: dark");
+
+        let err = fails_with(InputError::NoInput).unwrap_err();
+        assert!(matches!(err, Error::Input(_)));
+
+        let err = fails_with(ConfigError::InvalidOverride("oops".into())).unwrap_err();
+        assert!(matches!(err, Error::Config(_)));
+        assert_eq!(
+            err.to_string(),
+            "invalid config override `oops`: expected key=value"
+        );
+
+        let err = fails_with(ParseError::UnknownOperator {
+            operator: "equals".into(),
+        })
+        .unwrap_err();
+        assert!(matches!(err, Error::Parse(_)));
+
+        let err = fails_with(PipeError::Unsupported("clipboard")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "clipboard is not supported on this platform"
+        );
+    }
+}
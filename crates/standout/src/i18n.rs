@@ -0,0 +1,433 @@
+//! Message catalogs for localized help and topics.
+//!
+//! A [`Catalog`] holds one locale's messages, written as YAML. Nested keys are
+//! flattened with dots, so this file:
+//!
+//! ```yaml
+//! help:
+//!   usage: USO
+//!   options: OPÇÕES
+//! ```
+//!
+//! defines `help.usage` and `help.options`. [`Catalogs`] collects the
+//! catalogs of every supported locale and resolves the messages for a
+//! requested locale, falling back from `pt-BR` to `pt` to the default locale.
+//!
+//! ## Locale Selection
+//!
+//! The locale comes from the app's `--lang` flag when enabled (see
+//! [`App::locale_flag`](crate::cli::App::locale_flag)), otherwise from the
+//! `LC_ALL`, `LC_MESSAGES` and `LANG` environment variables, in that order
+//! (see [`detect_locale`]). Locale names are normalized, so `pt_BR.UTF-8`
+//! selects `pt-BR`.
+//!
+//! ## Where Messages Are Used
+//!
+//! - Help templates receive the resolved messages as `t`, keyed by the dotted
+//!   names: `{{ t["help.usage"] | default("USAGE") }}`. The default help
+//!   template translates its headers this way (`help.usage`, `help.options`,
+//!   `help.examples`, `help.learn_more`, and `help.groups.<title>` for
+//!   command group titles).
+//! - Topic files can have translations next to them: `setup.pt-BR.md` is the
+//!   `pt-BR` version of `setup.md` (see [`crate::topics`]).
+//!
+//! ## Example
+//!
+//! ```rust
+//! use standout::i18n::{Catalog, Catalogs};
+//!
+//! let mut catalogs = Catalogs::new("en");
+//! catalogs.add("pt", Catalog::from_yaml("help:\n  usage: USO\n").unwrap());
+//!
+//! let messages = catalogs.resolve(Some("pt_BR.UTF-8"));
+//! assert_eq!(messages.get("help.usage"), Some("USO"));
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+use serde_yaml::Value as YamlValue;
+
+use crate::setup::SetupError;
+
+/// Environment variables consulted by [`detect_locale`], in priority order.
+pub const LOCALE_ENV_VARS: [&str; 3] = ["LC_ALL", "LC_MESSAGES", "LANG"];
+
+/// The locale selected for the current dispatch.
+///
+/// Inserted into [`CommandContext::extensions`](crate::cli::CommandContext)
+/// alongside the resolved [`Catalog`] when the app has catalogs or a locale
+/// flag, so handlers can localize their own messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Locale(pub String);
+
+/// One locale's messages, keyed by dotted name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Catalog {
+    messages: BTreeMap<String, String>,
+}
+
+impl Catalog {
+    /// Creates an empty catalog.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses a catalog from YAML, flattening nested keys with dots.
+    ///
+    /// Scalar values (strings, numbers, booleans) become messages; nulls are
+    /// skipped and lists are rejected.
+    pub fn from_yaml(content: &str) -> Result<Self, SetupError> {
+        let value: YamlValue = serde_yaml::from_str(content)
+            .map_err(|e| SetupError::Config(format!("invalid message catalog: {}", e)))?;
+
+        let mut catalog = Self::new();
+        if !value.is_null() {
+            flatten("", &value, &mut catalog.messages)?;
+        }
+        Ok(catalog)
+    }
+
+    /// Adds or replaces a message.
+    pub fn insert(&mut self, key: impl Into<String>, message: impl Into<String>) {
+        self.messages.insert(key.into(), message.into());
+    }
+
+    /// Returns the message for `key`, if defined.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+
+    /// Returns the message for `key`, or `default` if it isn't defined.
+    pub fn get_or<'a>(&'a self, key: &str, default: &'a str) -> &'a str {
+        self.get(key).unwrap_or(default)
+    }
+
+    /// Returns true if the catalog has no messages.
+    pub fn is_empty(&self) -> bool {
+        self.messages.is_empty()
+    }
+
+    /// Returns all messages, keyed by dotted name.
+    pub fn messages(&self) -> &BTreeMap<String, String> {
+        &self.messages
+    }
+
+    /// Adds the messages of `other` that this catalog doesn't define.
+    fn fill_from(&mut self, other: &Catalog) {
+        for (key, message) in &other.messages {
+            self.messages
+                .entry(key.clone())
+                .or_insert_with(|| message.clone());
+        }
+    }
+}
+
+/// Flattens a YAML mapping into dotted keys.
+fn flatten(
+    prefix: &str,
+    value: &YamlValue,
+    out: &mut BTreeMap<String, String>,
+) -> Result<(), SetupError> {
+    let key = |name: &str| {
+        if prefix.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", prefix, name)
+        }
+    };
+
+    match value {
+        YamlValue::Mapping(map) => {
+            for (name, value) in map {
+                let name = match name {
+                    YamlValue::String(s) => s.clone(),
+                    YamlValue::Number(n) => n.to_string(),
+                    YamlValue::Bool(b) => b.to_string(),
+                    other => {
+                        return Err(SetupError::Config(format!(
+                            "invalid message catalog: unsupported key {:?}",
+                            other
+                        )))
+                    }
+                };
+                flatten(&key(&name), value, out)?;
+            }
+        }
+        YamlValue::String(s) => {
+            out.insert(prefix.to_string(), s.clone());
+        }
+        YamlValue::Number(n) => {
+            out.insert(prefix.to_string(), n.to_string());
+        }
+        YamlValue::Bool(b) => {
+            out.insert(prefix.to_string(), b.to_string());
+        }
+        YamlValue::Null => {}
+        YamlValue::Sequence(_) | YamlValue::Tagged(_) => {
+            return Err(SetupError::Config(format!(
+                "invalid message catalog: '{}' must be a string or a mapping",
+                prefix
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Message catalogs for every supported locale.
+#[derive(Debug, Clone, Default)]
+pub struct Catalogs {
+    default_locale: String,
+    catalogs: HashMap<String, Catalog>,
+}
+
+impl Catalogs {
+    /// Creates an empty set of catalogs.
+    ///
+    /// `default_locale` is the last fallback when resolving messages.
+    pub fn new(default_locale: &str) -> Self {
+        Self {
+            default_locale: normalize_locale(default_locale).unwrap_or_default(),
+            catalogs: HashMap::new(),
+        }
+    }
+
+    /// Loads `<locale>.yaml` / `<locale>.yml` files from a directory.
+    ///
+    /// Files whose name isn't a locale (e.g. `README.yaml`) are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory can't be read or a catalog fails to
+    /// parse.
+    pub fn from_directory(
+        default_locale: &str,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, SetupError> {
+        let mut catalogs = Self::new(default_locale);
+        for entry in fs::read_dir(path)? {
+            let path = entry?.path();
+            let is_yaml = matches!(
+                path.extension().and_then(|e| e.to_str()),
+                Some("yaml" | "yml")
+            );
+            let Some(locale) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            if !is_yaml || !is_locale_tag(locale) || !path.is_file() {
+                continue;
+            }
+            let catalog = Catalog::from_yaml(&fs::read_to_string(&path)?)
+                .map_err(|e| SetupError::Config(format!("{} ({})", e, path.display())))?;
+            catalogs.add(locale, catalog);
+        }
+        Ok(catalogs)
+    }
+
+    /// Adds the catalog for a locale, merging into any existing one.
+    pub fn add(&mut self, locale: &str, catalog: Catalog) {
+        let Some(locale) = normalize_locale(locale) else {
+            return;
+        };
+        let existing = self.catalogs.entry(locale).or_default();
+        let mut merged = catalog;
+        merged.fill_from(existing);
+        *existing = merged;
+    }
+
+    /// Returns the default locale.
+    pub fn default_locale(&self) -> &str {
+        &self.default_locale
+    }
+
+    /// Returns the catalog registered for exactly `locale`.
+    pub fn get(&self, locale: &str) -> Option<&Catalog> {
+        self.catalogs.get(&normalize_locale(locale)?)
+    }
+
+    /// Returns the supported locales, sorted.
+    pub fn locales(&self) -> Vec<&str> {
+        let mut locales: Vec<&str> = self.catalogs.keys().map(String::as_str).collect();
+        locales.sort();
+        locales
+    }
+
+    /// Resolves the messages for `locale`.
+    ///
+    /// Each message comes from the most specific catalog that defines it:
+    /// `pt-BR`, then `pt`, then the default locale. `None` resolves to the
+    /// default locale alone.
+    pub fn resolve(&self, locale: Option<&str>) -> Catalog {
+        let mut resolved = Catalog::new();
+        for locale in self.fallback_chain(locale) {
+            if let Some(catalog) = self.catalogs.get(&locale) {
+                resolved.fill_from(catalog);
+            }
+        }
+        resolved
+    }
+
+    /// Returns the locales tried for `locale`, most specific first.
+    pub fn fallback_chain(&self, locale: Option<&str>) -> Vec<String> {
+        let mut chain = locale.map(locale_chain).unwrap_or_default();
+        for fallback in locale_chain(&self.default_locale) {
+            if !chain.contains(&fallback) {
+                chain.push(fallback);
+            }
+        }
+        chain
+    }
+}
+
+/// Normalizes a locale name: `pt_BR.UTF-8@euro` becomes `pt-BR`.
+///
+/// Returns `None` for empty names and the `C` / `POSIX` locales.
+pub fn normalize_locale(locale: &str) -> Option<String> {
+    let locale = locale.trim();
+    let locale = locale.split(['.', '@']).next().unwrap_or_default();
+    if locale.is_empty() || locale == "C" || locale == "POSIX" {
+        return None;
+    }
+
+    let mut parts = locale.split(['_', '-']);
+    let language = parts.next()?.to_ascii_lowercase();
+    let mut normalized = language;
+    for part in parts {
+        normalized.push('-');
+        if part.len() == 2 {
+            normalized.push_str(&part.to_ascii_uppercase());
+        } else {
+            normalized.push_str(part);
+        }
+    }
+    Some(normalized)
+}
+
+/// Returns `locale` and its less specific parents: `pt-BR` gives `["pt-BR", "pt"]`.
+pub fn locale_chain(locale: &str) -> Vec<String> {
+    let Some(locale) = normalize_locale(locale) else {
+        return Vec::new();
+    };
+    let mut chain = vec![locale.clone()];
+    let mut current = locale.as_str();
+    while let Some((parent, _)) = current.rsplit_once('-') {
+        chain.push(parent.to_string());
+        current = parent;
+    }
+    chain
+}
+
+/// Returns true if `tag` looks like a locale name (`pt`, `pt-BR`, `zh_Hant`).
+pub fn is_locale_tag(tag: &str) -> bool {
+    let mut parts = tag.split(['-', '_']);
+    let language = parts.next().unwrap_or_default();
+    (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_lowercase())
+        && parts.all(|part| {
+            (2..=8).contains(&part.len()) && part.chars().all(|c| c.is_ascii_alphanumeric())
+        })
+}
+
+/// Detects the user's locale from `LC_ALL`, `LC_MESSAGES` and `LANG`.
+///
+/// The first variable that is set to something other than empty, `C` or
+/// `POSIX` wins.
+pub fn detect_locale() -> Option<String> {
+    LOCALE_ENV_VARS
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find_map(|value| normalize_locale(&value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalog_from_yaml_flattens_keys() {
+        let catalog = Catalog::from_yaml(
+            "help:\n  usage: USO\n  groups:\n    Commands: Comandos\ncount: 3\n",
+        )
+        .unwrap();
+
+        assert_eq!(catalog.get("help.usage"), Some("USO"));
+        assert_eq!(catalog.get("help.groups.Commands"), Some("Comandos"));
+        assert_eq!(catalog.get("count"), Some("3"));
+        assert_eq!(catalog.get("help"), None);
+    }
+
+    #[test]
+    fn test_catalog_from_yaml_rejects_lists() {
+        let err = Catalog::from_yaml("help:\n  usage: [a, b]\n").unwrap_err();
+        assert!(err.to_string().contains("help.usage"));
+    }
+
+    #[test]
+    fn test_normalize_locale() {
+        assert_eq!(normalize_locale("pt_BR.UTF-8"), Some("pt-BR".into()));
+        assert_eq!(normalize_locale("de_DE@euro"), Some("de-DE".into()));
+        assert_eq!(normalize_locale("EN"), Some("en".into()));
+        assert_eq!(normalize_locale("C"), None);
+        assert_eq!(normalize_locale("POSIX"), None);
+        assert_eq!(normalize_locale(""), None);
+    }
+
+    #[test]
+    fn test_locale_chain() {
+        assert_eq!(locale_chain("pt_BR"), vec!["pt-BR", "pt"]);
+        assert_eq!(locale_chain("en"), vec!["en"]);
+        assert!(locale_chain("C").is_empty());
+    }
+
+    #[test]
+    fn test_is_locale_tag() {
+        assert!(is_locale_tag("pt"));
+        assert!(is_locale_tag("pt-BR"));
+        assert!(is_locale_tag("pt_BR"));
+        assert!(!is_locale_tag("setup"));
+        assert!(!is_locale_tag("v2"));
+        assert!(!is_locale_tag("PT"));
+    }
+
+    #[test]
+    fn test_resolve_falls_back() {
+        let mut catalogs = Catalogs::new("en");
+        catalogs.add(
+            "en",
+            Catalog::from_yaml("help:\n  usage: USAGE\n  options: OPTIONS\n  examples: EXAMPLES\n")
+                .unwrap(),
+        );
+        catalogs.add(
+            "pt",
+            Catalog::from_yaml("help:\n  usage: USO\n  options: OPÇÕES\n").unwrap(),
+        );
+        catalogs.add(
+            "pt_BR",
+            Catalog::from_yaml("help:\n  usage: MODO DE USO\n").unwrap(),
+        );
+
+        let messages = catalogs.resolve(Some("pt_BR.UTF-8"));
+        assert_eq!(messages.get("help.usage"), Some("MODO DE USO"));
+        assert_eq!(messages.get("help.options"), Some("OPÇÕES"));
+        assert_eq!(messages.get("help.examples"), Some("EXAMPLES"));
+
+        let messages = catalogs.resolve(Some("fr"));
+        assert_eq!(messages.get("help.usage"), Some("USAGE"));
+
+        let messages = catalogs.resolve(None);
+        assert_eq!(messages.get("help.usage"), Some("USAGE"));
+    }
+
+    #[test]
+    fn test_from_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("en.yaml"), "greeting: Hello\n").unwrap();
+        std::fs::write(dir.path().join("pt-BR.yml"), "greeting: Olá\n").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let catalogs = Catalogs::from_directory("en", dir.path()).unwrap();
+        assert_eq!(catalogs.locales(), vec!["en", "pt-BR"]);
+        assert_eq!(catalogs.resolve(Some("pt_BR")).get("greeting"), Some("Olá"));
+    }
+}
@@ -19,9 +19,11 @@
 //! - [`ColorMode`]: Light or dark color mode enum
 //! - [`OutputMode`]: Control output formatting (Auto/Term/Text/TermDebug)
 //! - [`topics`]: Help topics system for extended documentation
+//! - [`i18n`]: Message catalogs for localized help and topics
 //! - Style syntax: Tag-based styling `[name]content[/name]`
 //! - [`Renderer`]: Pre-compile templates for repeated rendering
 //! - [`validate_template`]: Check templates for unknown style tags
+//! - [`testing`]: Snapshot helpers rendering in `TermDebug` mode
 //!
 //! ## Quick Start
 //!
@@ -221,10 +223,14 @@
 //! ```
 
 // Internal modules (standout-specific)
+mod error;
 mod setup;
 
 // Public submodules
 pub mod assets;
+pub mod i18n;
+pub mod scaffold;
+pub mod testing;
 pub mod topics;
 pub mod views;
 
@@ -241,30 +247,43 @@ pub use standout_render::RenderError;
 
 // Style module exports (from standout-render)
 pub use standout_render::{
-    parse_css, parse_stylesheet, ColorDef, StyleAttributes, StyleDefinition, StyleValidationError,
-    StyleValue, Styles, StylesheetError, StylesheetRegistry, ThemeVariants,
-    DEFAULT_MISSING_STYLE_INDICATOR, STYLESHEET_EXTENSIONS,
+    parse_css, parse_stylesheet, ColorDef, ConditionalStyle, StyleAttributes, StyleCondition,
+    StyleDefinition, StyleValidationError, StyleValue, Styles, StylesheetError, StylesheetRegistry,
+    ThemeVariants, DEFAULT_MISSING_STYLE_INDICATOR, STYLESHEET_EXTENSIONS,
 };
 
 // Theme module exports (from standout-render)
 pub use standout_render::{
-    detect_color_mode, detect_icon_mode, set_icon_detector, set_theme_detector, ColorMode,
-    IconDefinition, IconMode, IconSet, Theme,
+    detect_color_mode, detect_icon_mode, reset_theme_detector, set_icon_detector,
+    set_theme_detector, ColorMode, IconDefinition, IconMode, IconSet, Theme, ThemeDef,
 };
 
 // Output module exports (from standout-render)
-pub use standout_render::{write_binary_output, write_output, OutputDestination, OutputMode};
+pub use standout_render::{
+    write_binary_output, write_binary_output_with, write_output, write_output_with, CsvOptions,
+    CsvQuoting, FileWriteOptions, OutputDestination, OutputMode,
+};
+
+// Terminal capability report (from standout-render)
+pub use standout_render::{Capabilities, ColorDepth};
+
+// Piped color policy (from standout-render)
+pub use standout_render::{set_piped_color, PipedColor, COLOR_PAGERS};
 
 // Render module exports (from standout-render)
 pub use standout_render::{
+    // Compiled-template cache
+    clear_template_cache,
     render,
     render_auto,
     render_auto_with_context,
     render_auto_with_spec,
+    render_styled,
     render_with_context,
     render_with_mode,
     render_with_output,
     render_with_vars,
+    set_template_cache_capacity,
     validate_template,
     // Template registry
     walk_template_dir,
@@ -273,9 +292,11 @@ pub use standout_render::{
     RegistryError,
     Renderer,
     ResolvedTemplate,
+    StyledArg,
     TemplateEngine,
     TemplateFile,
     TemplateRegistry,
+    DEFAULT_TEMPLATE_CACHE_CAPACITY,
     TEMPLATE_EXTENSIONS,
 };
 
@@ -284,7 +305,8 @@ pub use standout_bbparser::{UnknownTagError, UnknownTagErrors, UnknownTagKind};
 
 // Utility exports (from standout-render)
 pub use standout_render::{
-    flatten_json_for_csv, rgb_to_ansi256, rgb_to_truecolor, serialize_to_xml, truncate_to_width,
+    colorize_json, flatten_json_for_csv, format_csv, rgb_to_ansi256, rgb_to_truecolor,
+    serialize_to_toml, serialize_to_xml, truncate_to_width,
 };
 
 // File loader exports (from standout-render)
@@ -298,23 +320,52 @@ pub use standout_render::{
     EmbeddedSource, EmbeddedStyles, EmbeddedTemplates, StylesheetResource, TemplateResource,
 };
 
+// Embedded help topics (for `embed_topics!`)
+pub use topics::{EmbeddedTopics, TopicResource};
+
 // Setup error type (standout-specific)
 pub use setup::SetupError;
 
+// Unified error type over every subsystem's errors
+pub use error::Error;
+
 // Macro re-exports
-pub use standout_macros::{command, embed_styles, embed_templates, handler};
+pub use standout_macros::{
+    command, embed_styles, embed_templates, embed_topics, handler, styled, validate_assets,
+};
 
 // Tabular derive macros
 pub use standout_macros::{Tabular, TabularRow};
 
+// Theme derive macro (shares its name with the `ThemeDef` trait)
+pub use standout_macros::ThemeDef;
+
 // Seeker query engine (re-export from standout-seeker)
 pub use standout_seeker as seeker;
 
+// Output piping to external commands (re-export from standout-pipe)
+pub use standout_pipe as pipe;
+
 // Declarative input collection (re-export from standout-input)
 pub use standout_input as input;
 
+// Input collection derive macro
+pub use standout_macros::Collect;
+
 // Seeker derive macro (requires `features = ["macros"]`)
 pub use standout_macros::Seekable;
 
+// JSON Schema derive support for `App::output_schema` (requires `features = ["schema"]`)
+#[cfg(feature = "schema")]
+pub use schemars;
+
+// Themed `tracing` layer (requires `features = ["tracing"]`)
+#[cfg(feature = "tracing")]
+pub use cli::logging;
+
+// Progress bars for `cli::progress_bars` (requires `features = ["indicatif"]`)
+#[cfg(feature = "indicatif")]
+pub use indicatif;
+
 // CLI integration
 pub mod cli;
@@ -0,0 +1,185 @@
+//! Starter files for new standout apps.
+//!
+//! [`init`] writes a working theme, list and detail templates and a help
+//! topic into a project directory:
+//!
+//! ```text
+//! templates/list.jinja          renders a ListViewResult
+//! templates/detail.jinja        renders a DetailViewResult
+//! styles/default.css            the "default" theme, with light/dark variants
+//! topics/getting-started.md     a help topic
+//! ```
+//!
+//! Run it once, from an example binary or a build script, then edit the files:
+//!
+//! ```rust,no_run
+//! fn main() -> std::io::Result<()> {
+//!     let scaffold = standout::scaffold::init(".")?;
+//!     for path in &scaffold.created {
+//!         println!("created {}", path.display());
+//!     }
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Existing files are never overwritten, so running it again only restores
+//! deleted files. The app then picks the files up with:
+//!
+//! ```rust,ignore
+//! App::builder()
+//!     .templates(embed_templates!("templates"))
+//!     .styles(embed_styles!("styles"))
+//!     .topics(embed_topics!("topics"))
+//!     .command_with("list", list, |cfg| cfg.template("list.jinja"))?
+//!     .command_with("show", show, |cfg| cfg.template("detail.jinja"))?
+//! ```
+//!
+//! where `list` returns a [`list_view`](crate::views::list_view) and `show` a
+//! [`detail_view`](crate::views::detail_view).
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The files [`init`] writes, as `(path relative to the project, content)`.
+pub const SCAFFOLD_FILES: &[(&str, &str)] = &[
+    ("templates/list.jinja", include_str!("list.jinja")),
+    ("templates/detail.jinja", include_str!("detail.jinja")),
+    ("styles/default.css", include_str!("default.css")),
+    (
+        "topics/getting-started.md",
+        include_str!("getting-started.md"),
+    ),
+];
+
+/// What [`init`] did.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Scaffold {
+    /// Files written.
+    pub created: Vec<PathBuf>,
+    /// Files left alone because they already existed.
+    pub skipped: Vec<PathBuf>,
+}
+
+/// Writes the starter `templates/`, `styles/` and `topics/` into `dir`.
+///
+/// Creates `dir` and the subdirectories as needed. Files that already exist
+/// are skipped and listed in [`Scaffold::skipped`].
+pub fn init(dir: impl AsRef<Path>) -> io::Result<Scaffold> {
+    let dir = dir.as_ref();
+    let mut scaffold = Scaffold::default();
+
+    for (relative, content) in SCAFFOLD_FILES {
+        let path = dir.join(relative);
+        if path.exists() {
+            scaffold.skipped.push(path);
+            continue;
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, content)?;
+        scaffold.created.push(path);
+    }
+
+    Ok(scaffold)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::{App, Output, RunResult};
+    use crate::views::{detail_view, list_view};
+    use serde_json::json;
+
+    fn run(app: &App, name: &str) -> String {
+        let cmd = clap::Command::new("app")
+            .subcommand(clap::Command::new("list"))
+            .subcommand(clap::Command::new("show"));
+        match app.run_to_string(cmd, ["app", name]) {
+            RunResult::Handled(output) => output,
+            other => panic!("expected output, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_init_writes_files_once() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let first = init(dir.path()).unwrap();
+        assert_eq!(first.created.len(), SCAFFOLD_FILES.len());
+        assert!(first.skipped.is_empty());
+
+        fs::write(dir.path().join("styles/default.css"), ".title {}").unwrap();
+        fs::remove_file(dir.path().join("templates/list.jinja")).unwrap();
+        let second = init(dir.path()).unwrap();
+        assert_eq!(second.created, [dir.path().join("templates/list.jinja")]);
+        assert_eq!(second.skipped.len(), SCAFFOLD_FILES.len() - 1);
+        assert_eq!(
+            fs::read_to_string(dir.path().join("styles/default.css")).unwrap(),
+            ".title {}"
+        );
+    }
+
+    #[test]
+    fn test_scaffolded_files_render_views_and_topics() {
+        let dir = tempfile::tempdir().unwrap();
+        init(dir.path()).unwrap();
+
+        let template = |name: &str| fs::read_to_string(dir.path().join(name)).unwrap();
+        let app = App::builder()
+            .styles_dir(dir.path().join("styles"))
+            .unwrap()
+            .command(
+                "list",
+                |_m, _ctx| {
+                    Ok(Output::Render(
+                        list_view(vec![json!({"name": "alpha"}), json!({"name": "beta"})])
+                            .title("Things")
+                            .build(),
+                    ))
+                },
+                &template("templates/list.jinja"),
+            )
+            .unwrap()
+            .command(
+                "show",
+                |_m, _ctx| {
+                    Ok(Output::Render(
+                        detail_view(json!({"name": "alpha", "size": 3}))
+                            .labeled_field("name", "Name")
+                            .labeled_field("size", "Size")
+                            .build(),
+                    ))
+                },
+                &template("templates/detail.jinja"),
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            run(&app, "list").trim_end(),
+            "Things\n\n  1  alpha\n  2  beta"
+        );
+        assert_eq!(run(&app, "show").trim_end(), "Name: alpha\nSize: 3");
+
+        let mut topics = crate::topics::TopicRegistry::new();
+        topics
+            .add_from_directory(dir.path().join("topics"))
+            .unwrap();
+        let topic = topics.get_topic("getting-started").unwrap();
+        assert_eq!(topic.title, "Getting Started");
+    }
+
+    #[test]
+    fn test_scaffolded_theme_parses() {
+        let css = SCAFFOLD_FILES
+            .iter()
+            .find(|(path, _)| path.ends_with(".css"))
+            .unwrap()
+            .1;
+        let theme = crate::Theme::from_css(css).unwrap();
+        assert!(theme.validate().is_ok());
+    }
+}
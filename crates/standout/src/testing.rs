@@ -0,0 +1,279 @@
+//! Helpers for snapshot tests of rendered output.
+//!
+//! Snapshots of styled output are hard to read with raw ANSI codes and
+//! useless in plain text, where the styling is gone. [`OutputMode::TermDebug`]
+//! keeps the style names as bracket tags (`[title]Report[/title]`), so a
+//! snapshot records both the text and how it's styled. These helpers render
+//! in that mode with a fixed light color mode and [`normalize`] the result,
+//! so snapshots don't change with the terminal they were recorded in.
+//!
+//! ```rust
+//! use standout::testing::render_term_debug;
+//! use standout::Theme;
+//! use console::Style;
+//!
+//! let theme = Theme::new().add("title", Style::new().bold());
+//! let output = render_term_debug(
+//!     "[title]{{ name }}[/title]   \n\n",
+//!     &serde_json::json!({ "name": "Report" }),
+//!     &theme,
+//! )
+//! .unwrap();
+//! assert_eq!(output, "[title]Report[/title]");
+//! ```
+//!
+//! With [insta](https://insta.rs) as a dev-dependency,
+//! [`assert_term_debug_snapshot!`](crate::assert_term_debug_snapshot) renders
+//! and snapshots in one step:
+//!
+//! ```rust,ignore
+//! standout::assert_term_debug_snapshot!("report", "[title]{{ name }}[/title]", &data, &theme);
+//! ```
+//!
+//! Without insta, [`assert_renders!`](crate::assert_renders) compares against
+//! an expected string, normalized the same way, so a raw string may start and
+//! end with blank lines and a golden file read with `include_str!` may end
+//! with a newline. On mismatch it panics with a line diff:
+//!
+//! ```rust
+//! # use standout::Theme;
+//! # use console::Style;
+//! # let theme = Theme::new().add("title", Style::new().bold());
+//! # let data = serde_json::json!({ "name": "Report" });
+//! standout::assert_renders!("[title]{{ name }}[/title]", &data, &theme, "[title]Report[/title]");
+//! // standout::assert_renders!(TEMPLATE, &data, &theme, include_str!("golden/report.txt"));
+//! ```
+//!
+//! For whole commands, run the app with
+//! [`App::test_run`](crate::cli::App::test_run) in `OutputMode::TermDebug`
+//! and snapshot `normalize(&out.stdout)`.
+
+use serde::Serialize;
+
+use crate::{render_with_mode, ColorMode, OutputMode, RenderError, Theme};
+
+/// Renders `template` in [`OutputMode::TermDebug`] and normalizes it.
+///
+/// The theme is resolved in light mode regardless of the OS setting.
+pub fn render_term_debug<T: Serialize>(
+    template: &str,
+    data: &T,
+    theme: &Theme,
+) -> Result<String, RenderError> {
+    render_with_mode(
+        template,
+        data,
+        theme,
+        OutputMode::TermDebug,
+        ColorMode::Light,
+    )
+    .map(|output| normalize(&output))
+}
+
+/// Normalizes whitespace so snapshots are stable.
+///
+/// Line endings become `\n`, trailing whitespace is removed from every line,
+/// and leading and trailing blank lines are dropped. Indentation and blank
+/// lines between content are kept, since they're part of the layout.
+pub fn normalize(output: &str) -> String {
+    let lines: Vec<&str> = output
+        .split('\n')
+        .map(|line| line.trim_end_matches('\r').trim_end())
+        .collect();
+    let start = lines.iter().position(|line| !line.is_empty());
+    let end = lines.iter().rposition(|line| !line.is_empty());
+    match (start, end) {
+        (Some(start), Some(end)) => lines[start..=end].join("\n"),
+        _ => String::new(),
+    }
+}
+
+/// Renders `template` with [`render_term_debug`] and compares it with
+/// `expected`, normalized the same way.
+///
+/// Returns a message with a [`line_diff`] when they differ, or the render
+/// error when the template fails.
+pub fn check_renders<T: Serialize>(
+    template: &str,
+    data: &T,
+    theme: &Theme,
+    expected: &str,
+) -> Result<(), String> {
+    let actual = render_term_debug(template, data, theme)
+        .map_err(|e| format!("template failed to render: {}", e))?;
+    let expected = normalize(expected);
+    if actual == expected {
+        return Ok(());
+    }
+    Err(format!(
+        "rendered output doesn't match (- expected, + actual):\n{}",
+        line_diff(&expected, &actual)
+    ))
+}
+
+/// Returns a line diff of `expected` and `actual`.
+///
+/// Every line is prefixed with `- ` (only in `expected`), `+ ` (only in
+/// `actual`) or two spaces (in both).
+pub fn line_diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // Longest common subsequence lengths of every pair of suffixes
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            out.push(format!("  {}", old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            out.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            out.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+    out.join("\n")
+}
+
+/// Renders a template with [`render_term_debug`] and asserts it equals the
+/// expected output.
+///
+/// Takes the template, data, theme and expected output; the expected output
+/// is [`normalize`]d before comparing. Panics with a line diff on mismatch,
+/// or if the template fails to render. See [`check_renders`].
+///
+/// ```rust,ignore
+/// assert_renders!("[title]{{ name }}[/title]", &data, &theme, "[title]Report[/title]");
+/// assert_renders!(TEMPLATE, &data, &theme, include_str!("golden/report.txt"));
+/// ```
+#[macro_export]
+macro_rules! assert_renders {
+    ($template:expr, $data:expr, $theme:expr, $expected:expr $(,)?) => {{
+        if let Err(message) = $crate::testing::check_renders($template, $data, $theme, $expected) {
+            panic!("{}", message);
+        }
+    }};
+}
+
+/// Renders a template with [`render_term_debug`] and asserts it matches an
+/// insta snapshot.
+///
+/// Takes an optional snapshot name, then the template, data and theme. The
+/// calling crate needs `insta` as a dev-dependency. Panics if the template
+/// fails to render.
+///
+/// ```rust,ignore
+/// assert_term_debug_snapshot!("report", "[title]{{ name }}[/title]", &data, &theme);
+/// assert_term_debug_snapshot!("[title]{{ name }}[/title]", &data, &theme);
+/// ```
+#[macro_export]
+macro_rules! assert_term_debug_snapshot {
+    ($name:expr, $template:expr, $data:expr, $theme:expr $(,)?) => {{
+        let output = $crate::testing::render_term_debug($template, $data, $theme)
+            .expect("template should render");
+        ::insta::assert_snapshot!($name, output);
+    }};
+    ($template:expr, $data:expr, $theme:expr $(,)?) => {{
+        let output = $crate::testing::render_term_debug($template, $data, $theme)
+            .expect("template should render");
+        ::insta::assert_snapshot!(output);
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use console::Style;
+    use serde_json::json;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("\n\n  a  \r\n\n\tb\t\n\n"), "  a\n\n\tb");
+        assert_eq!(normalize(" \n \n"), "");
+    }
+
+    #[test]
+    fn test_render_term_debug_keeps_style_names() {
+        let theme = Theme::new()
+            .add("title", Style::new().bold())
+            .add("count", Style::new().cyan());
+        let output = render_term_debug(
+            "[title]{{ title }}[/title]  \n[count]{{ n }}[/count] items\n",
+            &json!({ "title": "Report", "n": 3 }),
+            &theme,
+        )
+        .unwrap();
+        assert_eq!(output, "[title]Report[/title]\n[count]3[/count] items");
+    }
+
+    #[test]
+    fn test_line_diff() {
+        assert_eq!(
+            line_diff("a\nb\nc", "a\nx\nc\nd"),
+            "  a\n- b\n+ x\n  c\n+ d"
+        );
+        assert_eq!(line_diff("same", "same"), "  same");
+    }
+
+    #[test]
+    fn test_assert_renders_normalizes_expected() {
+        let theme = Theme::new().add("title", Style::new().bold());
+        crate::assert_renders!(
+            "[title]{{ name }}[/title]\n{{ n }} items",
+            &json!({ "name": "Report", "n": 3 }),
+            &theme,
+            r#"
+[title]Report[/title]
+3 items
+"#,
+        );
+    }
+
+    #[test]
+    fn test_check_renders_reports_diff_and_errors() {
+        let theme = Theme::new().add("title", Style::new().bold());
+        let data = json!({ "name": "Report" });
+
+        let err = check_renders(
+            "[title]{{ name }}[/title]",
+            &data,
+            &theme,
+            "[title]Summary[/title]",
+        )
+        .unwrap_err();
+        assert!(
+            err.ends_with("- [title]Summary[/title]\n+ [title]Report[/title]"),
+            "{}",
+            err
+        );
+
+        let err = check_renders("{{ name", &data, &theme, "").unwrap_err();
+        assert!(err.starts_with("template failed to render"), "{}", err);
+    }
+
+    #[test]
+    #[should_panic(expected = "+ [title]Report[/title]")]
+    fn test_assert_renders_panics_with_diff() {
+        let theme = Theme::new().add("title", Style::new().bold());
+        crate::assert_renders!(
+            "[title]{{ name }}[/title]",
+            &json!({ "name": "Report" }),
+            &theme,
+            "Report"
+        );
+    }
+}
@@ -42,13 +42,65 @@
 //! File format: first non-blank line is the title, rest is content.
 //! Filename (minus extension) becomes the topic name.
 //!
+//! ## Front Matter
+//!
+//! A topic file may start with a YAML front matter block:
+//!
+//! ```text
+//! ---
+//! title: Getting Started
+//! category: Guides
+//! weight: 1
+//! aliases: [start, quickstart]
+//! tags: [intro]
+//! ---
+//! Install the tool, then...
+//! ```
+//!
+//! `title` replaces the first-line title (the whole body is then content),
+//! `weight` (or `order`) positions the topic within its category, `aliases`
+//! are extra names [`TopicRegistry::get_topic`] answers to, and `tags` can be
+//! queried with [`TopicRegistry::topics_with_tag`]. Unknown keys are ignored;
+//! malformed YAML makes [`TopicRegistry::add_from_directory`] fail.
+//!
+//! ## Translations
+//!
+//! A file whose name ends in a locale, like `setup.pt-BR.md`, is a
+//! translation of `setup.md` rather than a topic of its own. The app's help
+//! shows it to users in that locale (see [`crate::i18n`]);
+//! [`TopicRegistry::localized`] does the substitution.
+//!
+//! ## Categories
+//!
+//! Once an app has many topics, `help topics` can group them. A topic's
+//! category comes from [`Topic::with_category`], from the name of the
+//! subdirectory its file was loaded from (`docs/topics/guides/setup.md` is in
+//! `guides`), or from front matter at the top of the file, which takes
+//! precedence (see [Front Matter](#front-matter)).
+//!
+//! Within a category, topics are listed by `order` (default 0), then name.
+//! Categories are listed alphabetically after uncategorized topics, unless
+//! [`TopicRegistry::set_category_order`] says otherwise.
+//!
 //! ## Key Types
 //!
 //! - [`Topic`]: A single help topic with title, content, and name
 //! - [`TopicRegistry`]: Collection of topics with lookup by name
 //! - [`TopicType`]: Text or Markdown (affects rendering)
+//!
+//! ## Markdown Topics
+//!
+//! [`TopicType::Markdown`] content (e.g. loaded from `.md` files) is rendered
+//! rather than printed verbatim: headings, `**bold**`, `*italic*`, inline
+//! code, lists and fenced code blocks are styled with the `md-heading`,
+//! `md-bold`, `md-italic`, `md-code` and `md-bullet` theme styles, and links
+//! become `text (url)`. In Text mode the styles are dropped, leaving readable
+//! plain text without Markdown punctuation.
 //! - [`render_topic`] / [`render_topics_list`]: Rendering functions
 //! - [`display_with_pager`]: Show long content through less/more
+//! - [`should_auto_page`]: Whether content is too tall for the terminal
+//! - [`display_table_with_pager`]: Page a table that doesn't fit the terminal
+//!   (called by your code; App output is never paged automatically)
 
 use deunicode::deunicode;
 use std::collections::HashMap;
@@ -57,10 +109,13 @@ use std::io::Write;
 use std::path::Path;
 use std::process::{Command as ProcessCommand, Stdio};
 
-use console::Style;
-use serde::Serialize;
+use console::{Style, Term};
+use serde::{Deserialize, Serialize};
 
-use crate::{render_with_output, OutputMode, RenderError, Theme};
+use crate::i18n;
+use crate::tabular::Table;
+use crate::warnings::push_warning;
+use crate::{render_with_output, EmbeddedSource, OutputMode, RenderError, Theme};
 
 /// Fixed width for the name column in topic listings.
 const NAME_COLUMN_WIDTH: usize = 14;
@@ -79,6 +134,14 @@ pub struct Topic {
     pub content: String,
     pub topic_type: TopicType,
     pub name: String,
+    /// Category the topic is listed under in `help topics` (None: uncategorized)
+    pub category: Option<String>,
+    /// Position within its category; lower comes first, ties sort by name
+    pub order: i32,
+    /// Alternative names the topic can be looked up by
+    pub aliases: Vec<String>,
+    /// Free-form labels for finding related topics
+    pub tags: Vec<String>,
 }
 
 impl Topic {
@@ -98,9 +161,45 @@ impl Topic {
             content: content.into(),
             topic_type,
             name,
+            category: None,
+            order: 0,
+            aliases: Vec::new(),
+            tags: Vec::new(),
         }
     }
 
+    /// Sets the category the topic is listed under.
+    pub fn with_category(mut self, category: impl Into<String>) -> Self {
+        self.category = Some(category.into());
+        self
+    }
+
+    /// Sets the topic's position within its category.
+    pub fn with_order(mut self, order: i32) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Sets alternative names the topic can be looked up by.
+    pub fn with_aliases<I, S>(mut self, aliases: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.aliases = aliases.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the topic's tags.
+    pub fn with_tags<I, S>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.tags = tags.into_iter().map(Into::into).collect();
+        self
+    }
+
     fn generate_slug(title: &str) -> String {
         let transliterated = deunicode(title);
         let mut slug: String = transliterated
@@ -120,29 +219,98 @@ impl Topic {
 #[derive(Default, Clone)]
 pub struct TopicRegistry {
     topics: HashMap<String, Topic>,
+    /// Alias to topic name
+    aliases: HashMap<String, String>,
+    /// Locale to translated topics, keyed by the name of the topic they translate
+    translations: HashMap<String, HashMap<String, Topic>>,
+    category_order: Vec<String>,
 }
 
 impl TopicRegistry {
     pub fn new() -> Self {
         Self {
             topics: HashMap::new(),
+            aliases: HashMap::new(),
+            translations: HashMap::new(),
+            category_order: Vec::new(),
         }
     }
 
+    /// Sets the order categories are listed in.
+    ///
+    /// Categories not named here follow, alphabetically.
+    pub fn set_category_order<I, S>(&mut self, categories: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.category_order = categories.into_iter().map(Into::into).collect();
+    }
+
+    /// Returns topics grouped by category, in listing order.
+    ///
+    /// Uncategorized topics come first under `None`; topics within each group
+    /// are sorted by [`Topic::order`], then name.
+    pub fn topics_by_category(&self) -> Vec<(Option<&str>, Vec<&Topic>)> {
+        let mut groups: Vec<(Option<&str>, Vec<&Topic>)> = Vec::new();
+        for topic in self.topics.values() {
+            let category = topic.category.as_deref();
+            match groups.iter_mut().find(|(name, _)| *name == category) {
+                Some((_, topics)) => topics.push(topic),
+                None => groups.push((category, vec![topic])),
+            }
+        }
+
+        let rank = |category: Option<&str>| match category {
+            None => (0, 0),
+            Some(name) => match self.category_order.iter().position(|c| c == name) {
+                Some(index) => (1, index),
+                None => (2, 0),
+            },
+        };
+        groups.sort_by(|(a, _), (b, _)| rank(*a).cmp(&rank(*b)).then_with(|| a.cmp(b)));
+        for (_, topics) in &mut groups {
+            topics.sort_by(|a, b| a.order.cmp(&b.order).then_with(|| a.name.cmp(&b.name)));
+        }
+        groups
+    }
+
     /// Adds a topic to the registry.
-    /// Panics if a topic with the same name already exists.
+    /// Panics if the topic's name or one of its aliases is already taken by
+    /// another topic's name or alias.
     pub fn add_topic(&mut self, topic: Topic) {
-        if self.topics.contains_key(&topic.name) {
-            panic!(
-                "Topic collision: A topic with the name '{}' already exists.",
-                topic.name
-            );
+        for name in std::iter::once(&topic.name).chain(&topic.aliases) {
+            if self.topics.contains_key(name) || self.aliases.contains_key(name) {
+                panic!(
+                    "Topic collision: A topic with the name '{}' already exists.",
+                    name
+                );
+            }
+        }
+        for alias in &topic.aliases {
+            self.aliases.insert(alias.clone(), topic.name.clone());
         }
         self.topics.insert(topic.name.clone(), topic);
     }
 
+    /// Looks up a topic by name, falling back to its aliases.
     pub fn get_topic(&self, name: &str) -> Option<&Topic> {
-        self.topics.get(name)
+        self.topics.get(name).or_else(|| {
+            self.aliases
+                .get(name)
+                .and_then(|name| self.topics.get(name))
+        })
+    }
+
+    /// Returns the topics carrying `tag`, sorted by name.
+    pub fn topics_with_tag(&self, tag: &str) -> Vec<&Topic> {
+        let mut topics: Vec<&Topic> = self
+            .topics
+            .values()
+            .filter(|topic| topic.tags.iter().any(|t| t == tag))
+            .collect();
+        topics.sort_by(|a, b| a.name.cmp(&b.name));
+        topics
     }
 
     pub fn list_topics(&self) -> Vec<&Topic> {
@@ -154,6 +322,8 @@ impl TopicRegistry {
     /// Adds topics from files in the specified directory.
     /// Only .txt and .md files are processed.
     /// Empty files or files with only one line are ignored.
+    /// Files in immediate subdirectories are added with the subdirectory name
+    /// as their category.
     /// Returns an error if the path does not exist or is not a directory.
     pub fn add_from_directory(&mut self, path: impl AsRef<Path>) -> std::io::Result<()> {
         let path = path.as_ref();
@@ -169,7 +339,7 @@ impl TopicRegistry {
                 format!("Path is not a directory: {}", path.display()),
             ));
         }
-        self.load_from_directory(path)
+        self.load_from_directory(path, None)
     }
 
     /// Adds topics from files in the specified directory if it exists.
@@ -181,67 +351,337 @@ impl TopicRegistry {
         if !path.exists() || !path.is_dir() {
             return Ok(());
         }
-        self.load_from_directory(path)
+        self.load_from_directory(path, None)
     }
 
-    fn load_from_directory(&mut self, path: &Path) -> std::io::Result<()> {
+    fn load_from_directory(&mut self, path: &Path, category: Option<&str>) -> std::io::Result<()> {
         for entry in fs::read_dir(path)? {
             let entry = entry?;
             let path = entry.path();
 
+            if path.is_dir() {
+                // One level of subdirectories maps to categories
+                if category.is_none() {
+                    if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+                        self.load_from_directory(&path, Some(name))?;
+                    }
+                }
+                continue;
+            }
+
             if !path.is_file() {
                 continue;
             }
 
-            let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-            let topic_type = match extension {
-                "txt" => TopicType::Text,
-                "md" => TopicType::Markdown,
-                _ => continue,
+            let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
             };
+            if topic_type_for(file_name).is_none() {
+                continue;
+            }
 
             let content = fs::read_to_string(&path)?;
-            let lines: Vec<&str> = content.lines().collect();
+            let topic = parse_topic_file(file_name, &content, category).map_err(|e| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("Invalid front matter in {}: {}", path.display(), e),
+                )
+            })?;
+            if let Some(topic) = topic {
+                self.add_topic(topic);
+            }
+        }
+        Ok(())
+    }
 
-            // Skip empty or single-line files
-            if lines.len() < 2 {
+    /// Builds a registry from `(name_with_extension, content)` pairs, as
+    /// produced by the `embed_topics!` macro.
+    ///
+    /// Names follow the directory layout: `setup.md` is uncategorized and
+    /// `guides/setup.md` is in the `guides` category. Deeper paths and
+    /// non-topic extensions are skipped, matching [`add_from_directory`].
+    ///
+    /// [`add_from_directory`]: TopicRegistry::add_from_directory
+    pub fn from_embedded_entries(entries: &[(&str, &str)]) -> std::io::Result<Self> {
+        let mut registry = Self::new();
+        for (name_with_ext, content) in entries {
+            let (category, file_name) = match name_with_ext.split_once('/') {
+                None => (None, *name_with_ext),
+                Some((_, rest)) if rest.contains('/') => continue,
+                Some((category, file_name)) => (Some(category), file_name),
+            };
+            if topic_type_for(file_name).is_none() {
                 continue;
             }
 
-            // Title is first non-blank line
-            let title_idx = lines.iter().position(|l| !l.trim().is_empty());
-            if let Some(idx) = title_idx {
-                let title = lines[idx].trim().to_string();
-
-                // Content starts after title, skipping any leading blank lines
-                let content_lines = &lines[idx + 1..];
-                let content_start = content_lines
-                    .iter()
-                    .position(|l| !l.trim().is_empty())
-                    .unwrap_or(content_lines.len());
-
-                let body = content_lines[content_start..]
-                    .join("\n")
-                    .trim_end()
-                    .to_string();
-                if body.is_empty() {
-                    continue;
-                }
+            let topic = parse_topic_file(file_name, content, category).map_err(|e| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("Invalid front matter in {}: {}", name_with_ext, e),
+                )
+            })?;
+            if let Some(topic) = topic {
+                registry.add_topic(topic);
+            }
+        }
+        Ok(registry)
+    }
+
+    /// Turns topics named `<name>.<locale>` into translations of `<name>`.
+    ///
+    /// Only suffixes naming one of `locales` (or a variant of one, like
+    /// `pt-BR` for `pt`) count, and only when a topic named `<name>` exists,
+    /// so files like `notes.old.md` stay topics of their own. Apps call this
+    /// when built, with the locales of their message catalogs.
+    pub fn adopt_translations(&mut self, locales: &[&str]) {
+        let locales: Vec<String> = locales
+            .iter()
+            .filter_map(|locale| i18n::normalize_locale(locale))
+            .collect();
+        let mut adopted: Vec<String> = self
+            .topics
+            .keys()
+            .filter(|name| {
+                name.rsplit_once('.').is_some_and(|(base, suffix)| {
+                    self.topics.contains_key(base)
+                        && i18n::normalize_locale(suffix).is_some_and(|suffix| {
+                            i18n::locale_chain(&suffix)
+                                .iter()
+                                .any(|locale| locales.contains(locale))
+                        })
+                })
+            })
+            .cloned()
+            .collect();
+        adopted.sort();
+        for name in adopted {
+            let Some(mut topic) = self.topics.remove(&name) else {
+                continue;
+            };
+            self.aliases.retain(|_, target| *target != name);
+            let (base, suffix) = name.rsplit_once('.').unwrap_or_default();
+            topic.name = base.to_string();
+            self.add_translation(suffix, topic);
+        }
+    }
 
-                // Name is filename sans extension
-                let name = path
-                    .file_stem()
-                    .and_then(|s| s.to_str())
-                    .map(|s| s.to_string());
+    /// Adds a translation of the topic named `topic.name`.
+    ///
+    /// Translations don't appear in listings on their own; [`localized`]
+    /// swaps them in for the topic they translate. A later translation for
+    /// the same locale and name replaces the earlier one.
+    ///
+    /// [`localized`]: TopicRegistry::localized
+    pub fn add_translation(&mut self, locale: &str, topic: Topic) {
+        let Some(locale) = i18n::normalize_locale(locale) else {
+            return;
+        };
+        self.translations
+            .entry(locale)
+            .or_default()
+            .insert(topic.name.clone(), topic);
+    }
 
-                let topic = Topic::new(title, body, topic_type, name);
-                self.add_topic(topic);
+    /// Adds the topics and translations of `other` to this registry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a topic name or alias collides with one already registered.
+    pub fn extend(&mut self, other: TopicRegistry) {
+        for topic in other.list_topics() {
+            self.add_topic(topic.clone());
+        }
+        for (locale, topics) in other.translations {
+            self.translations.entry(locale).or_default().extend(topics);
+        }
+    }
+
+    /// Returns a copy of the registry with topics translated for `locales`.
+    ///
+    /// `locales` is a fallback chain, most specific first (see
+    /// [`Catalogs::fallback_chain`](crate::i18n::Catalogs::fallback_chain)).
+    /// Each topic takes the title, content and type of its first available
+    /// translation, and its category when the translation sets one; name,
+    /// order, aliases and tags stay those of the original. Topics without a
+    /// translation are kept as they are.
+    pub fn localized(&self, locales: &[String]) -> TopicRegistry {
+        let mut registry = self.clone();
+        for topic in registry.topics.values_mut() {
+            let translation = locales.iter().find_map(|locale| {
+                self.translations
+                    .get(locale)
+                    .and_then(|topics| topics.get(&topic.name))
+            });
+            if let Some(translation) = translation {
+                topic.title = translation.title.clone();
+                topic.content = translation.content.clone();
+                topic.topic_type = translation.topic_type.clone();
+                if translation.category.is_some() {
+                    topic.category = translation.category.clone();
+                }
             }
         }
-        Ok(())
+        registry
     }
 }
 
+/// Marker type for help topic resources.
+#[derive(Debug, Clone, Copy)]
+pub struct TopicResource;
+
+/// Help topics embedded with the `embed_topics!` macro.
+pub type EmbeddedTopics = EmbeddedSource<TopicResource>;
+
+impl From<EmbeddedTopics> for TopicRegistry {
+    /// Converts embedded topics into a TopicRegistry.
+    ///
+    /// In debug mode, if the source path exists, topics are loaded from disk
+    /// (enabling hot-reload). Otherwise, embedded content is used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if embedded front matter fails to parse (should be caught in dev).
+    fn from(source: EmbeddedTopics) -> Self {
+        if source.should_hot_reload() {
+            let mut registry = TopicRegistry::new();
+            match registry.add_from_directory(source.source_path) {
+                Ok(()) => return registry,
+                Err(e) => push_warning(format!(
+                    "Failed to load topics from '{}', using embedded: {}",
+                    source.source_path, e
+                )),
+            }
+        }
+        TopicRegistry::from_embedded_entries(source.entries).expect("embedded topics should parse")
+    }
+}
+
+/// Returns the topic type for a file name, or `None` if it isn't a topic file.
+fn topic_type_for(file_name: &str) -> Option<TopicType> {
+    match Path::new(file_name).extension().and_then(|e| e.to_str()) {
+        Some("txt") => Some(TopicType::Text),
+        Some("md") => Some(TopicType::Markdown),
+        _ => None,
+    }
+}
+
+/// Parses one topic file. The name is the file name without extension.
+///
+/// Returns `Ok(None)` for files with no usable title or body, and the YAML
+/// error message for malformed front matter.
+fn parse_topic_file(
+    file_name: &str,
+    content: &str,
+    category: Option<&str>,
+) -> Result<Option<Topic>, String> {
+    let Some(topic_type) = topic_type_for(file_name) else {
+        return Ok(None);
+    };
+    let (front_matter, content) = parse_front_matter(content).map_err(|e| e.to_string())?;
+
+    let (title, body) = match front_matter.title {
+        // Front matter supplies the title, so the whole file is content
+        Some(ref title) => (title.clone(), content.trim().to_string()),
+        None => match split_title(content) {
+            Some(parts) => parts,
+            None => return Ok(None),
+        },
+    };
+    if body.is_empty() {
+        return Ok(None);
+    }
+
+    // Name is filename sans extension
+    let name = Path::new(file_name)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .map(|s| s.to_string());
+
+    let mut topic = Topic::new(title, body, topic_type, name);
+    topic.category = front_matter
+        .category
+        .or_else(|| category.map(str::to_string));
+    topic.order = front_matter.order.unwrap_or(0);
+    topic.aliases = front_matter.aliases;
+    topic.tags = front_matter.tags;
+    Ok(Some(topic))
+}
+
+/// Splits a topic file without a front matter title into title and body.
+///
+/// The title is the first non-blank line; the body is everything after it,
+/// minus leading blank lines. Returns `None` for empty or single-line files.
+fn split_title(content: &str) -> Option<(String, String)> {
+    let lines: Vec<&str> = content.lines().collect();
+
+    // Skip empty or single-line files
+    if lines.len() < 2 {
+        return None;
+    }
+
+    // Title is first non-blank line
+    let idx = lines.iter().position(|l| !l.trim().is_empty())?;
+    let title = lines[idx].trim().to_string();
+
+    // Content starts after title, skipping any leading blank lines
+    let content_lines = &lines[idx + 1..];
+    let content_start = content_lines
+        .iter()
+        .position(|l| !l.trim().is_empty())
+        .unwrap_or(content_lines.len());
+
+    let body = content_lines[content_start..]
+        .join("\n")
+        .trim_end()
+        .to_string();
+    Some((title, body))
+}
+
+/// Metadata read from a topic file's YAML front matter.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct FrontMatter {
+    /// Display title; when set, the file's first line is content, not the title
+    title: Option<String>,
+    category: Option<String>,
+    /// Position within the category (`weight` is accepted as a synonym)
+    #[serde(alias = "weight")]
+    order: Option<i32>,
+    aliases: Vec<String>,
+    tags: Vec<String>,
+}
+
+/// Parses `---`-fenced YAML front matter off the top of a topic file.
+///
+/// Recognized keys are `title`, `category`, `order` (or `weight`), `aliases`
+/// and `tags`; others are ignored. Files without front matter, or whose
+/// opening `---` is never closed, are returned unchanged.
+fn parse_front_matter(content: &str) -> Result<(FrontMatter, &str), serde_yaml::Error> {
+    let Some(rest) = content
+        .strip_prefix("---\n")
+        .or_else(|| content.strip_prefix("---\r\n"))
+    else {
+        return Ok((FrontMatter::default(), content));
+    };
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim() == "---" {
+            let yaml = &rest[..offset];
+            let front_matter = if yaml.trim().is_empty() {
+                FrontMatter::default()
+            } else {
+                serde_yaml::from_str(yaml)?
+            };
+            return Ok((front_matter, &rest[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+
+    // Unterminated front matter: treat the file as plain content
+    Ok((FrontMatter::default(), content))
+}
+
 // ============================================================================
 // TOPIC RENDERING
 // ============================================================================
@@ -261,7 +701,7 @@ pub struct TopicRenderConfig {
 
 /// Returns the default theme for topic rendering.
 pub fn default_topic_theme() -> Theme {
-    Theme::new()
+    markdown_topic_theme()
         .add("header", Style::new().bold())
         .add("item", Style::new().bold())
         .add("desc", Style::new())
@@ -269,6 +709,144 @@ pub fn default_topic_theme() -> Theme {
         .add("about", Style::new())
 }
 
+/// Styles used by rendered Markdown topics.
+fn markdown_topic_theme() -> Theme {
+    Theme::new()
+        .add("md-heading", Style::new().bold().underlined())
+        .add("md-bold", Style::new().bold())
+        .add("md-italic", Style::new().italic())
+        .add("md-code", Style::new().cyan())
+        .add("md-bullet", Style::new().dim())
+}
+
+/// Converts Markdown into style-tagged text for the topic template.
+///
+/// Handles ATX headings, emphasis, inline code, links, bullet and numbered
+/// lists, and fenced code blocks (indented, without further processing).
+/// Anything else passes through as-is.
+fn markdown_to_styled(markdown: &str) -> String {
+    let mut lines = Vec::new();
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        let indent = &line[..line.len() - trimmed.len()];
+
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            if line.trim().is_empty() {
+                lines.push(String::new());
+            } else {
+                lines.push(format!("    [md-code]{}[/md-code]", line));
+            }
+        } else if let Some(heading) = markdown_heading(trimmed) {
+            lines.push(format!(
+                "[md-heading]{}[/md-heading]",
+                markdown_inline(heading)
+            ));
+        } else if let Some((marker, item)) = markdown_list_item(trimmed) {
+            lines.push(format!(
+                "{}[md-bullet]{}[/md-bullet] {}",
+                indent,
+                marker,
+                markdown_inline(item)
+            ));
+        } else {
+            lines.push(markdown_inline(line));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Returns the text of an ATX heading (`# Title`), if `line` is one.
+fn markdown_heading(line: &str) -> Option<&str> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &line[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    Some(rest.trim().trim_end_matches('#').trim_end())
+}
+
+/// Splits a list item into its display marker and text, if `line` is one.
+fn markdown_list_item(line: &str) -> Option<(String, &str)> {
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(item) = line.strip_prefix(bullet) {
+            return Some(("•".to_string(), item));
+        }
+    }
+
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits > 0 {
+        let rest = &line[digits..];
+        if let Some(item) = rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") ")) {
+            return Some((format!("{}.", &line[..digits]), item));
+        }
+    }
+    None
+}
+
+/// Converts inline Markdown (code, emphasis, links) within a single line.
+fn markdown_inline(text: &str) -> String {
+    let mut out = String::new();
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        let after_word = out.chars().last().is_some_and(char::is_alphanumeric);
+
+        if c == '`' {
+            if let Some(end) = rest[1..].find('`') {
+                out.push_str(&format!("[md-code]{}[/md-code]", &rest[1..1 + end]));
+                rest = &rest[end + 2..];
+                continue;
+            }
+        } else if rest.starts_with("**") || rest.starts_with("__") {
+            let delimiter = &rest[..2];
+            if let Some(end) = rest[2..].find(delimiter).filter(|&end| end > 0) {
+                let inner = markdown_inline(&rest[2..2 + end]);
+                out.push_str(&format!("[md-bold]{}[/md-bold]", inner));
+                rest = &rest[end + 4..];
+                continue;
+            }
+        } else if (c == '*' || c == '_') && !after_word {
+            // Emphasis hugs its text: `2 * 3 * 4` is arithmetic, not italics
+            let hugs = |end: &usize| {
+                !rest[1..].starts_with(char::is_whitespace)
+                    && !rest[..1 + end].ends_with(char::is_whitespace)
+            };
+            if let Some(end) = rest[1..].find(c).filter(|&end| end > 0).filter(hugs) {
+                let inner = markdown_inline(&rest[1..1 + end]);
+                out.push_str(&format!("[md-italic]{}[/md-italic]", inner));
+                rest = &rest[end + 2..];
+                continue;
+            }
+        } else if c == '[' {
+            if let Some(close) = rest.find("](") {
+                if let Some(end) = rest[close + 2..].find(')') {
+                    let label = markdown_inline(&rest[1..close]);
+                    let url = &rest[close + 2..close + 2 + end];
+                    out.push_str(&format!("{} ({})", label, url));
+                    rest = &rest[close + 3 + end..];
+                    continue;
+                }
+            }
+        }
+
+        out.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    out
+}
+
 #[derive(Serialize)]
 struct TopicData {
     title: String,
@@ -278,6 +856,15 @@ struct TopicData {
 #[derive(Serialize)]
 struct TopicsListData {
     usage: String,
+    /// All topics, flat and sorted by name (kept for custom list templates)
+    topics: Vec<TopicListItem>,
+    /// Topics grouped by category, in listing order
+    categories: Vec<TopicCategoryData>,
+}
+
+#[derive(Serialize)]
+struct TopicCategoryData {
+    title: String,
     topics: Vec<TopicListItem>,
 }
 
@@ -315,12 +902,20 @@ pub fn render_topic(
         .as_deref()
         .unwrap_or(include_str!("topic_template.txt"));
 
-    let theme = config.theme.unwrap_or_else(default_topic_theme);
+    let mut theme = config.theme.unwrap_or_else(default_topic_theme);
     let mode = config.output_mode.unwrap_or(OutputMode::Auto);
 
+    let content = if topic.topic_type == TopicType::Markdown {
+        // Custom themes only need to override the Markdown styles they care about
+        theme = markdown_topic_theme().merge(theme);
+        markdown_to_styled(&topic.content)
+    } else {
+        topic.content.clone()
+    };
+
     let data = TopicData {
         title: topic.title.clone(),
-        content: topic.content.clone(),
+        content,
     };
 
     render_with_output(template, &data, &theme, mode)
@@ -360,24 +955,29 @@ pub fn render_topics_list(
     let theme = config.theme.unwrap_or_else(default_topic_theme);
     let mode = config.output_mode.unwrap_or(OutputMode::Auto);
 
-    let topics = registry.list_topics();
+    let list_item = |t: &&Topic| {
+        // +1 accounts for the colon added in the template
+        let pad = NAME_COLUMN_WIDTH.saturating_sub(t.name.len() + 1);
+        TopicListItem {
+            name: t.name.clone(),
+            title: t.title.clone(),
+            padding: " ".repeat(pad),
+        }
+    };
 
-    let topic_items: Vec<TopicListItem> = topics
-        .iter()
-        .map(|t| {
-            // +1 accounts for the colon added in the template
-            let pad = NAME_COLUMN_WIDTH.saturating_sub(t.name.len() + 1);
-            TopicListItem {
-                name: t.name.clone(),
-                title: t.title.clone(),
-                padding: " ".repeat(pad),
-            }
+    let categories = registry
+        .topics_by_category()
+        .into_iter()
+        .map(|(category, topics)| TopicCategoryData {
+            title: category.unwrap_or("Topics").to_string(),
+            topics: topics.iter().map(list_item).collect(),
         })
         .collect();
 
     let data = TopicsListData {
         usage: format!("{} <topic>", usage_prefix),
-        topics: topic_items,
+        topics: registry.list_topics().iter().map(list_item).collect(),
+        categories,
     };
 
     render_with_output(template, &data, &theme, mode)
@@ -418,6 +1018,110 @@ pub fn display_with_pager(content: &str) -> std::io::Result<()> {
     std::io::stdout().flush()
 }
 
+/// Returns true if `content` should be paged without the user asking.
+///
+/// That's the case when stdout is a TTY, the content is taller than the
+/// terminal, and paging isn't disabled with an empty `$PAGER`.
+pub fn should_auto_page(content: &str) -> bool {
+    let term = Term::stdout();
+    let terminal_height = term.is_term().then(|| term.size().0 as usize);
+    auto_page(
+        terminal_height,
+        content.lines().count(),
+        std::env::var("PAGER").ok().as_deref(),
+    )
+}
+
+/// Decides auto-paging from the terminal height, content height and `$PAGER`.
+fn auto_page(terminal_height: Option<usize>, line_count: usize, pager: Option<&str>) -> bool {
+    if pager.is_some_and(|pager| pager.trim().is_empty()) {
+        return false;
+    }
+    matches!(terminal_height, Some(height) if height > 0 && line_count > height)
+}
+
+/// Prints a table, handing it to a pager when it doesn't fit the terminal.
+///
+/// When stdout is a TTY and the table is taller than the terminal, it is
+/// rendered with [`Table::render_paged`] (the header repeated at the top of
+/// every screenful) and shown through [`display_with_pager`]. Otherwise,
+/// including when output is piped or redirected, the table is printed
+/// directly. Either way, style tags in the table (such as the header style)
+/// are resolved against `theme` for `mode`, as in rendered templates. As with
+/// [`should_auto_page`], an empty `$PAGER` disables paging.
+///
+/// This is a manual API: output rendered by the [`App`](crate::cli::App) is
+/// printed as-is and never paged. Call it where your own code prints a table,
+/// for example after building rows outside a command handler.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use standout::tabular::{Col, Table, TabularSpec};
+/// use standout::topics::display_table_with_pager;
+/// use standout::{OutputMode, Theme};
+///
+/// let spec = TabularSpec::builder()
+///     .column(Col::fixed(6).header("ID"))
+///     .column(Col::fill().header("Name"))
+///     .build();
+/// let table = Table::new(spec, 60)
+///     .header_from_columns()
+///     .header_style("header");
+/// let theme = Theme::new().add("header", console::Style::new().bold());
+///
+/// let rows: Vec<Vec<String>> = (0..500)
+///     .map(|i| vec![i.to_string(), format!("item-{}", i)])
+///     .collect();
+/// display_table_with_pager(&table, &rows, &theme, OutputMode::Auto).unwrap();
+/// ```
+pub fn display_table_with_pager<S: AsRef<str>>(
+    table: &Table,
+    rows: &[Vec<S>],
+    theme: &Theme,
+    mode: OutputMode,
+) -> std::io::Result<()> {
+    let term = Term::stdout();
+    let paging_disabled = std::env::var("PAGER").is_ok_and(|pager| pager.trim().is_empty());
+    let terminal_height = (term.is_term() && !paging_disabled).then(|| term.size().0 as usize);
+    let (output, paged) = render_table_for_terminal(table, rows, terminal_height, theme, mode);
+    if paged {
+        display_with_pager(&format!("{}\n", output))
+    } else {
+        println!("{}", output);
+        std::io::stdout().flush()
+    }
+}
+
+/// Renders a table for a terminal `terminal_height` lines tall, paged if it
+/// doesn't fit, with style tags resolved. Returns the output and whether it
+/// was paged.
+fn render_table_for_terminal<S: AsRef<str>>(
+    table: &Table,
+    rows: &[Vec<S>],
+    terminal_height: Option<usize>,
+    theme: &Theme,
+    mode: OutputMode,
+) -> (String, bool) {
+    let (rendered, paged) = match table_page_limits(terminal_height) {
+        Some((max_height, page_height)) => table.render_to_fit(rows, max_height, page_height),
+        None => (table.render(rows), false),
+    };
+    let styles = theme.resolve_styles(Some(crate::detect_color_mode()));
+    let output = standout_render::template::apply_style_tags(&rendered, &styles, mode);
+    (output, paged)
+}
+
+/// The most lines a table may take before it is paged, and the page height to
+/// use then, or `None` if tables are never paged.
+///
+/// `terminal_height` is `None` when stdout is not a terminal. One line of each
+/// page is reserved for the pager's prompt.
+fn table_page_limits(terminal_height: Option<usize>) -> Option<(usize, usize)> {
+    let height = terminal_height.filter(|&height| height > 0)?;
+    Some((height, height.saturating_sub(1).max(1)))
+}
+
 /// Returns the list of pager candidates to try.
 fn get_pager_candidates() -> Vec<String> {
     let mut pagers = Vec::new();
@@ -539,6 +1243,210 @@ mod tests {
         assert!(registry.get_topic("empty_body").is_none());
     }
 
+    #[test]
+    fn test_load_from_dir_categories() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("guides")).unwrap();
+
+        let mut f = File::create(dir.path().join("intro.txt")).unwrap();
+        writeln!(f, "Introduction\nContent").unwrap();
+
+        // Category from the subdirectory
+        let mut f = File::create(dir.path().join("guides/setup.md")).unwrap();
+        writeln!(f, "Setup\nContent").unwrap();
+
+        // Front matter overrides the subdirectory
+        let mut f = File::create(dir.path().join("guides/formats.md")).unwrap();
+        writeln!(
+            f,
+            "---\ncategory: Reference\norder: 2\n---\nFormats\nContent"
+        )
+        .unwrap();
+
+        let mut registry = TopicRegistry::new();
+        registry.add_from_directory(dir.path()).unwrap();
+
+        assert_eq!(registry.get_topic("intro").unwrap().category, None);
+        let setup = registry.get_topic("setup").unwrap();
+        assert_eq!(setup.category.as_deref(), Some("guides"));
+        let formats = registry.get_topic("formats").unwrap();
+        assert_eq!(formats.category.as_deref(), Some("Reference"));
+        assert_eq!(formats.order, 2);
+        assert_eq!(formats.title, "Formats");
+        assert_eq!(formats.content, "Content");
+    }
+
+    #[test]
+    fn test_from_embedded_entries() {
+        let registry = TopicRegistry::from_embedded_entries(&[
+            ("intro.txt", "Introduction\nContent"),
+            ("guides/setup.md", "---\norder: 1\n---\nSetup\nContent"),
+            ("guides/deep/skipped.md", "Skipped\nContent"),
+            ("notes.rst", "Notes\nContent"),
+        ])
+        .unwrap();
+
+        assert_eq!(registry.list_topics().len(), 2);
+        assert_eq!(registry.get_topic("intro").unwrap().category, None);
+        let setup = registry.get_topic("setup").unwrap();
+        assert_eq!(setup.category.as_deref(), Some("guides"));
+        assert_eq!(setup.topic_type, TopicType::Markdown);
+        assert_eq!(setup.order, 1);
+    }
+
+    #[test]
+    fn test_from_embedded_entries_invalid_front_matter() {
+        let Err(err) = TopicRegistry::from_embedded_entries(&[("bad.md", "---\n[oops\n---\nT\nB")])
+        else {
+            panic!("expected invalid front matter to fail");
+        };
+        assert!(err.to_string().contains("bad.md"));
+    }
+
+    #[test]
+    fn test_load_from_dir_translations() {
+        let dir = tempdir().unwrap();
+        let mut f = File::create(dir.path().join("setup.md")).unwrap();
+        writeln!(f, "Setup\nInstall it.").unwrap();
+        let mut f = File::create(dir.path().join("setup.pt-BR.md")).unwrap();
+        writeln!(f, "Instalação\nInstale.").unwrap();
+        let mut f = File::create(dir.path().join("notes.txt")).unwrap();
+        writeln!(f, "Notes\nSome notes.").unwrap();
+
+        let mut registry = TopicRegistry::new();
+        registry.add_from_directory(dir.path()).unwrap();
+        assert!(registry.get_topic("setup.pt-BR").is_some());
+
+        // Once adopted, translations aren't topics of their own
+        registry.adopt_translations(&["en", "pt"]);
+        assert_eq!(registry.list_topics().len(), 2);
+        assert!(registry.get_topic("setup.pt-BR").is_none());
+
+        let localized = registry.localized(&["pt-BR".to_string(), "pt".to_string()]);
+        let setup = localized.get_topic("setup").unwrap();
+        assert_eq!(setup.title, "Instalação");
+        assert_eq!(setup.content, "Instale.");
+        assert_eq!(localized.get_topic("notes").unwrap().title, "Notes");
+
+        // Other locales fall back to the original
+        let localized = registry.localized(&["fr".to_string()]);
+        assert_eq!(localized.get_topic("setup").unwrap().title, "Setup");
+    }
+
+    #[test]
+    fn test_adopt_translations_keeps_other_dotted_names() {
+        let dir = tempdir().unwrap();
+        for (file, content) in [
+            ("foo.md", "Foo\nCurrent."),
+            ("foo.old.md", "Old Foo\nBefore the rewrite."),
+            ("api.get.md", "Get\nFetching."),
+            ("setup.fr.md", "Installation\nInstallez."),
+        ] {
+            let mut f = File::create(dir.path().join(file)).unwrap();
+            writeln!(f, "{}", content).unwrap();
+        }
+
+        let mut registry = TopicRegistry::new();
+        registry.add_from_directory(dir.path()).unwrap();
+        registry.adopt_translations(&["en", "pt"]);
+
+        // Not a catalog locale, or no topic to translate
+        let names: Vec<_> = registry.list_topics().iter().map(|t| &t.name).collect();
+        assert_eq!(names, ["api.get", "foo", "foo.old", "setup.fr"]);
+    }
+
+    #[test]
+    fn test_parse_front_matter_unterminated() {
+        let (front_matter, content) = parse_front_matter("---\ncategory: X\nTitle\nBody").unwrap();
+        assert_eq!(front_matter.category, None);
+        assert_eq!(content, "---\ncategory: X\nTitle\nBody");
+    }
+
+    #[test]
+    fn test_load_from_dir_front_matter_metadata() {
+        let dir = tempdir().unwrap();
+        let mut f = File::create(dir.path().join("getting-started.md")).unwrap();
+        writeln!(
+            f,
+            "---\ntitle: Getting Started\nweight: 3\naliases: [start, quickstart]\ntags: [intro]\nauthor: ignored\n---\nInstall the tool.\n\nThen run it."
+        )
+        .unwrap();
+
+        let mut registry = TopicRegistry::new();
+        registry.add_from_directory(dir.path()).unwrap();
+
+        let topic = registry.get_topic("getting-started").unwrap();
+        assert_eq!(topic.title, "Getting Started");
+        assert_eq!(topic.content, "Install the tool.\n\nThen run it.");
+        assert_eq!(topic.order, 3);
+        assert_eq!(topic.aliases, vec!["start", "quickstart"]);
+
+        assert_eq!(
+            registry.get_topic("quickstart").unwrap().name,
+            "getting-started"
+        );
+        let tagged = registry.topics_with_tag("intro");
+        assert_eq!(tagged.len(), 1);
+        assert_eq!(tagged[0].name, "getting-started");
+    }
+
+    #[test]
+    fn test_load_from_dir_invalid_front_matter() {
+        let dir = tempdir().unwrap();
+        let mut f = File::create(dir.path().join("broken.md")).unwrap();
+        writeln!(f, "---\naliases: [unclosed\n---\nTitle\nBody").unwrap();
+
+        let mut registry = TopicRegistry::new();
+        let err = registry.add_from_directory(dir.path()).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("broken.md"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Topic collision")]
+    fn test_alias_collision_panics() {
+        let mut registry = TopicRegistry::new();
+        registry.add_topic(Topic::new("Setup", "x", TopicType::Text, None));
+        registry
+            .add_topic(Topic::new("Install", "y", TopicType::Text, None).with_aliases(["setup"]));
+    }
+
+    #[test]
+    fn test_topics_by_category_ordering() {
+        let mut registry = TopicRegistry::new();
+        registry.add_topic(Topic::new("Zeta", "c", TopicType::Text, None));
+        registry.add_topic(Topic::new("Alpha", "c", TopicType::Text, None).with_category("Misc"));
+        registry.add_topic(
+            Topic::new("Later", "c", TopicType::Text, None)
+                .with_category("Guides")
+                .with_order(5),
+        );
+        registry.add_topic(
+            Topic::new("Basics", "c", TopicType::Text, None)
+                .with_category("Guides")
+                .with_order(1),
+        );
+        registry
+            .add_topic(Topic::new("Api", "c", TopicType::Text, None).with_category("Reference"));
+        registry.set_category_order(["Reference"]);
+
+        let groups: Vec<(Option<&str>, Vec<&str>)> = registry
+            .topics_by_category()
+            .into_iter()
+            .map(|(category, topics)| (category, topics.iter().map(|t| t.name.as_str()).collect()))
+            .collect();
+
+        assert_eq!(
+            groups,
+            vec![
+                (None, vec!["zeta"]),
+                (Some("Reference"), vec!["api"]),
+                (Some("Guides"), vec!["basics", "later"]),
+                (Some("Misc"), vec!["alpha"]),
+            ]
+        );
+    }
+
     #[test]
     fn test_add_from_nonexistent_directory() {
         let mut registry = TopicRegistry::new();
@@ -595,6 +1503,66 @@ mod tests {
         assert!(output.contains("This is the content."));
     }
 
+    #[test]
+    fn test_markdown_to_styled() {
+        let markdown = "# Storage\n\nNotes live in **one** *folder*, see [docs](https://x.io).\n\n- first `item`\n2. second\n\n```\nls -la\n```";
+
+        assert_eq!(
+            markdown_to_styled(markdown),
+            "[md-heading]Storage[/md-heading]\n\
+             \n\
+             Notes live in [md-bold]one[/md-bold] [md-italic]folder[/md-italic], see docs (https://x.io).\n\
+             \n\
+             [md-bullet]•[/md-bullet] first [md-code]item[/md-code]\n\
+             [md-bullet]2.[/md-bullet] second\n\
+             \n    [md-code]ls -la[/md-code]"
+        );
+    }
+
+    #[test]
+    fn test_markdown_inline_leaves_snake_case_alone() {
+        assert_eq!(markdown_inline("use my_topic_name"), "use my_topic_name");
+        assert_eq!(markdown_inline("2 * 3 * 4"), "2 * 3 * 4");
+    }
+
+    #[test]
+    fn test_render_topic_markdown_text_mode() {
+        let topic = Topic::new(
+            "Guide",
+            "## Setup\n\nRun **init** first:\n\n* install\n* configure",
+            TopicType::Markdown,
+            None,
+        );
+
+        let config = TopicRenderConfig {
+            output_mode: Some(crate::OutputMode::Text),
+            ..Default::default()
+        };
+
+        let output = render_topic(&topic, Some(config)).unwrap();
+        assert!(output.contains("Setup\n\nRun init first:\n\n• install\n• configure"));
+        assert!(!output.contains("**"));
+        assert!(!output.contains("md-"));
+    }
+
+    #[test]
+    fn test_render_topic_markdown_custom_theme() {
+        let topic = Topic::new("Guide", "**bold**", TopicType::Markdown, None);
+
+        // A theme without md-* styles still renders (defaults fill in)
+        let config = TopicRenderConfig {
+            theme: Some(Theme::new().add("header", Style::new())),
+            output_mode: Some(crate::OutputMode::Term),
+            ..Default::default()
+        };
+
+        // Unknown styles would show up as `[md-bold?]` markers
+        let output = render_topic(&topic, Some(config)).unwrap();
+        assert!(output.contains("bold"));
+        assert!(!output.contains("md-bold"));
+        assert!(!output.contains("**"));
+    }
+
     #[test]
     fn test_render_topics_list_basic() {
         let mut registry = TopicRegistry::new();
@@ -623,6 +1591,25 @@ mod tests {
         assert!(output.contains("myapp help <topic>"));
     }
 
+    #[test]
+    fn test_render_topics_list_by_category() {
+        let mut registry = TopicRegistry::new();
+        registry.add_topic(Topic::new("Storage", "c", TopicType::Text, None));
+        registry.add_topic(Topic::new("Setup", "c", TopicType::Text, None).with_category("Guides"));
+
+        let config = TopicRenderConfig {
+            output_mode: Some(crate::OutputMode::Text),
+            ..Default::default()
+        };
+
+        let output = render_topics_list(&registry, "myapp help", Some(config)).unwrap();
+        let topics = output.find("TOPICS").unwrap();
+        let guides = output.find("GUIDES").unwrap();
+        assert!(topics < output.find("storage").unwrap());
+        assert!(guides > output.find("storage").unwrap());
+        assert!(guides < output.find("setup").unwrap());
+    }
+
     #[test]
     fn test_get_pager_candidates_includes_defaults() {
         // Don't modify env vars (not thread-safe in tests)
@@ -631,4 +1618,48 @@ mod tests {
         assert!(candidates.contains(&"less".to_string()));
         assert!(candidates.contains(&"more".to_string()));
     }
+
+    #[test]
+    fn test_table_page_limits() {
+        assert_eq!(table_page_limits(None), None);
+        assert_eq!(table_page_limits(Some(0)), None);
+        assert_eq!(table_page_limits(Some(40)), Some((40, 39)));
+        assert_eq!(table_page_limits(Some(1)), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_render_table_for_terminal_resolves_header_style() {
+        use crate::tabular::{Col, TabularSpec};
+
+        let spec = TabularSpec::builder()
+            .column(Col::fixed(4).header("ID"))
+            .build();
+        let table = Table::new(spec, 4)
+            .header_from_columns()
+            .header_style("header");
+        let theme = Theme::new().add("header", Style::new().bold());
+        let rows: Vec<Vec<String>> = (0..3).map(|i| vec![i.to_string()]).collect();
+
+        let (output, paged) =
+            render_table_for_terminal(&table, &rows, None, &theme, OutputMode::Text);
+        assert!(!paged);
+        assert_eq!(output, "ID  \n0   \n1   \n2   ");
+
+        let (output, paged) =
+            render_table_for_terminal(&table, &rows, Some(3), &theme, OutputMode::Text);
+        assert!(paged);
+        assert!(!output.contains("[header]"), "{output:?}");
+        assert_eq!(output.matches("ID").count(), 3);
+    }
+
+    #[test]
+    fn test_auto_page() {
+        assert!(auto_page(Some(40), 41, None));
+        assert!(auto_page(Some(40), 41, Some("less -R")));
+        assert!(!auto_page(Some(40), 40, None));
+        assert!(!auto_page(None, 1000, None));
+        assert!(!auto_page(Some(0), 5, None));
+        // An empty $PAGER disables paging
+        assert!(!auto_page(Some(40), 41, Some("")));
+    }
 }
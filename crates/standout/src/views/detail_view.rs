@@ -0,0 +1,266 @@
+//! DetailView result type and builder.
+//!
+//! DetailView is the single-item counterpart of ListView:
+//! - Title (optional, styled as a header)
+//! - The item, shown as one `label: value` line per field
+//! - Status messages (info, warnings, errors)
+//! - Empty-state message (shown when there is no item)
+//! - Per-field styles (applied to the values)
+//!
+//! Without an explicit field list, every field of the item is shown under its
+//! own key. The framework-supplied `standout/detail-view` template renders it.
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use super::{Message, MessageLevel};
+
+/// A field shown by a detail view.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DetailField {
+    /// Key of the field in the serialized item.
+    pub key: String,
+    /// Label shown before the value.
+    pub label: String,
+}
+
+/// Result type for detail view handlers.
+///
+/// This struct is serialized and passed to the detail view template.
+/// The framework-supplied `standout/detail-view` template handles
+/// rendering, or you can provide your own.
+#[derive(Debug, Clone, Serialize)]
+pub struct DetailViewResult<T> {
+    /// The item to display, or `None` if it wasn't found.
+    pub item: Option<T>,
+
+    /// Title shown above the item, in the `standout-header` style.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// Fields to show, in order. Empty shows every field of the item.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<DetailField>,
+
+    /// Status messages (info, warning, error).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<Message>,
+
+    /// Text shown when there is no item.
+    ///
+    /// The framework template falls back to "Not found.".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub empty_message: Option<String>,
+
+    /// Style names by field key, applied to the values.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub field_styles: BTreeMap<String, String>,
+}
+
+impl<T> DetailViewResult<T> {
+    /// Create a new detail view result for `item`.
+    pub fn new(item: Option<T>) -> Self {
+        Self {
+            item,
+            title: None,
+            fields: Vec::new(),
+            messages: Vec::new(),
+            empty_message: None,
+            field_styles: BTreeMap::new(),
+        }
+    }
+
+    /// Returns true if there is no item.
+    pub fn is_empty(&self) -> bool {
+        self.item.is_none()
+    }
+}
+
+impl<T> Default for DetailViewResult<T> {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+/// Builder for constructing `DetailViewResult` instances.
+///
+/// Use [`detail_view()`] to start building:
+///
+/// ```rust
+/// use standout::views::detail_view;
+///
+/// # #[derive(serde::Serialize)]
+/// # struct Task { id: u32, name: String }
+/// let task = Task { id: 7, name: "Write docs".into() };
+/// let result = detail_view(task)
+///     .title("Task 7")
+///     .field("name")
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct DetailViewBuilder<T> {
+    item: Option<T>,
+    title: Option<String>,
+    fields: Vec<DetailField>,
+    messages: Vec<Message>,
+    empty_message: Option<String>,
+    field_styles: BTreeMap<String, String>,
+}
+
+impl<T> DetailViewBuilder<T> {
+    /// Create a new builder for `item`, which may be missing.
+    pub fn new(item: Option<T>) -> Self {
+        Self {
+            item,
+            title: None,
+            fields: Vec::new(),
+            messages: Vec::new(),
+            empty_message: None,
+            field_styles: BTreeMap::new(),
+        }
+    }
+
+    /// Set the title shown above the item.
+    pub fn title(mut self, text: impl Into<String>) -> Self {
+        self.title = Some(text.into());
+        self
+    }
+
+    /// Show `key`, labeled with the key itself.
+    pub fn field(self, key: impl Into<String>) -> Self {
+        let key = key.into();
+        self.labeled_field(key.clone(), key)
+    }
+
+    /// Show `key` under `label`.
+    pub fn labeled_field(mut self, key: impl Into<String>, label: impl Into<String>) -> Self {
+        self.fields.push(DetailField {
+            key: key.into(),
+            label: label.into(),
+        });
+        self
+    }
+
+    /// Style the value of `field` with the theme style `style`.
+    pub fn field_style(mut self, field: impl Into<String>, style: impl Into<String>) -> Self {
+        self.field_styles.insert(field.into(), style.into());
+        self
+    }
+
+    /// Set the text shown when there is no item.
+    pub fn empty_message(mut self, text: impl Into<String>) -> Self {
+        self.empty_message = Some(text.into());
+        self
+    }
+
+    /// Add a status message.
+    pub fn message(mut self, level: MessageLevel, text: impl Into<String>) -> Self {
+        self.messages.push(Message::new(level, text));
+        self
+    }
+
+    /// Add an info message.
+    pub fn info(self, text: impl Into<String>) -> Self {
+        self.message(MessageLevel::Info, text)
+    }
+
+    /// Add a success message.
+    pub fn success(self, text: impl Into<String>) -> Self {
+        self.message(MessageLevel::Success, text)
+    }
+
+    /// Add a warning message.
+    pub fn warning(self, text: impl Into<String>) -> Self {
+        self.message(MessageLevel::Warning, text)
+    }
+
+    /// Add an error message.
+    pub fn error(self, text: impl Into<String>) -> Self {
+        self.message(MessageLevel::Error, text)
+    }
+
+    /// Build the `DetailViewResult`.
+    pub fn build(self) -> DetailViewResult<T> {
+        DetailViewResult {
+            item: self.item,
+            title: self.title,
+            fields: self.fields,
+            messages: self.messages,
+            empty_message: self.empty_message,
+            field_styles: self.field_styles,
+        }
+    }
+}
+
+/// Create a new detail view builder for `item`.
+///
+/// Use [`DetailViewBuilder::new`] when the item may be missing.
+///
+/// # Examples
+///
+/// ```rust
+/// use standout::views::{detail_view, DetailViewBuilder};
+///
+/// # #[derive(serde::Serialize)]
+/// # struct Task { id: u32, status: String }
+/// let task = Task { id: 3, status: "pending".into() };
+/// let result = detail_view(task)
+///     .labeled_field("id", "ID")
+///     .labeled_field("status", "Status")
+///     .field_style("status", "warning")
+///     .build();
+/// assert_eq!(result.fields.len(), 2);
+///
+/// let missing = DetailViewBuilder::<Task>::new(None)
+///     .empty_message("No task 3")
+///     .build();
+/// assert!(missing.is_empty());
+/// ```
+pub fn detail_view<T>(item: T) -> DetailViewBuilder<T> {
+    DetailViewBuilder::new(Some(item))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detail_view_builder_basic() {
+        let result = detail_view(5).build();
+        assert_eq!(result.item, Some(5));
+        assert!(result.title.is_none());
+        assert!(result.fields.is_empty());
+        assert!(!result.is_empty());
+    }
+
+    #[test]
+    fn test_detail_view_fields_keep_order_and_labels() {
+        let result = detail_view(())
+            .field("name")
+            .labeled_field("created_at", "Created")
+            .build();
+
+        assert_eq!(
+            result.fields,
+            [
+                DetailField {
+                    key: "name".into(),
+                    label: "name".into()
+                },
+                DetailField {
+                    key: "created_at".into(),
+                    label: "Created".into()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detail_view_serialization_skips_empty() {
+        let json = serde_json::to_value(detail_view("x").build()).unwrap();
+        assert_eq!(json, serde_json::json!({ "item": "x" }));
+
+        let json = serde_json::to_value(DetailViewResult::<u8>::default()).unwrap();
+        assert_eq!(json, serde_json::json!({ "item": null }));
+    }
+}
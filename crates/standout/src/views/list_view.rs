@@ -1,10 +1,13 @@
 //! ListView result type and builder.
 //!
 //! ListView provides a standardized structure for displaying collections:
+//! - Title (optional, styled as a header)
 //! - Introduction text (optional header)
 //! - Item list (the main content)
 //! - Ending text (optional footer)
 //! - Status messages (info, warnings, errors)
+//! - Empty-state message (shown instead of the items when there are none)
+//! - Per-field styles (applied to the matching table columns)
 //!
 //! # Rendering Modes
 //!
@@ -20,6 +23,7 @@
 //!    total control.
 
 use serde::Serialize;
+use std::collections::BTreeMap;
 
 use super::{Message, MessageLevel};
 use crate::tabular::TabularSpec;
@@ -34,6 +38,10 @@ pub struct ListViewResult<T> {
     /// Items to display (post-filtering, post-ordering).
     pub items: Vec<T>,
 
+    /// Title shown above everything else, in the `standout-header` style.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
     /// Text shown before the list (optional header).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub intro: Option<String>,
@@ -46,6 +54,17 @@ pub struct ListViewResult<T> {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub messages: Vec<Message>,
 
+    /// Text shown instead of the items when there are none.
+    ///
+    /// The framework template falls back to "No items found.".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub empty_message: Option<String>,
+
+    /// Style names by field key, applied to the matching columns of
+    /// [`tabular_spec`](Self::tabular_spec).
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub field_styles: BTreeMap<String, String>,
+
     /// Total count before limit/offset (for "showing X of Y").
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_count: Option<usize>,
@@ -64,9 +83,12 @@ impl<T> ListViewResult<T> {
     pub fn new(items: Vec<T>) -> Self {
         Self {
             items,
+            title: None,
             intro: None,
             ending: None,
             messages: Vec::new(),
+            empty_message: None,
+            field_styles: BTreeMap::new(),
             total_count: None,
             filter_summary: None,
             tabular_spec: None,
@@ -82,6 +104,21 @@ impl<T> ListViewResult<T> {
     pub fn len(&self) -> usize {
         self.items.len()
     }
+
+    /// Sets the tabular specification, styling the columns named in
+    /// [`field_styles`](Self::field_styles).
+    ///
+    /// A field style replaces the column's own style. Columns are matched by
+    /// key, or by name when they have no key.
+    pub fn set_tabular_spec(&mut self, mut spec: TabularSpec) {
+        for column in &mut spec.columns {
+            let field = column.key.as_ref().or(column.name.as_ref());
+            if let Some(style) = field.and_then(|field| self.field_styles.get(field)) {
+                column.style = Some(style.clone());
+            }
+        }
+        self.tabular_spec = Some(spec);
+    }
 }
 
 impl<T> Default for ListViewResult<T> {
@@ -105,9 +142,12 @@ impl<T> Default for ListViewResult<T> {
 #[derive(Debug)]
 pub struct ListViewBuilder<T> {
     items: Vec<T>,
+    title: Option<String>,
     intro: Option<String>,
     ending: Option<String>,
     messages: Vec<Message>,
+    empty_message: Option<String>,
+    field_styles: BTreeMap<String, String>,
     total_count: Option<usize>,
     filter_summary: Option<String>,
     tabular_spec: Option<TabularSpec>,
@@ -118,15 +158,24 @@ impl<T> ListViewBuilder<T> {
     pub fn new(items: impl IntoIterator<Item = T>) -> Self {
         Self {
             items: items.into_iter().collect(),
+            title: None,
             intro: None,
             ending: None,
             messages: Vec::new(),
+            empty_message: None,
+            field_styles: BTreeMap::new(),
             total_count: None,
             filter_summary: None,
             tabular_spec: None,
         }
     }
 
+    /// Set the title shown above the list.
+    pub fn title(mut self, text: impl Into<String>) -> Self {
+        self.title = Some(text.into());
+        self
+    }
+
     /// Set the introduction text shown before the list.
     pub fn intro(mut self, text: impl Into<String>) -> Self {
         self.intro = Some(text.into());
@@ -165,6 +214,20 @@ impl<T> ListViewBuilder<T> {
         self.message(MessageLevel::Error, text)
     }
 
+    /// Set the text shown instead of the items when there are none.
+    pub fn empty_message(mut self, text: impl Into<String>) -> Self {
+        self.empty_message = Some(text.into());
+        self
+    }
+
+    /// Style the values of `field` with the theme style `style`.
+    ///
+    /// Applies to the table column whose key (or name) is `field`.
+    pub fn field_style(mut self, field: impl Into<String>, style: impl Into<String>) -> Self {
+        self.field_styles.insert(field.into(), style.into());
+        self
+    }
+
     /// Set the total count (before filtering/limiting).
     ///
     /// This enables "Showing X of Y" display when the list
@@ -191,15 +254,22 @@ impl<T> ListViewBuilder<T> {
 
     /// Build the `ListViewResult`.
     pub fn build(self) -> ListViewResult<T> {
-        ListViewResult {
+        let mut result = ListViewResult {
             items: self.items,
+            title: self.title,
             intro: self.intro,
             ending: self.ending,
             messages: self.messages,
+            empty_message: self.empty_message,
+            field_styles: self.field_styles,
             total_count: self.total_count,
             filter_summary: self.filter_summary,
-            tabular_spec: self.tabular_spec,
+            tabular_spec: None,
+        };
+        if let Some(spec) = self.tabular_spec {
+            result.set_tabular_spec(spec);
         }
+        result
     }
 }
 
@@ -324,6 +394,42 @@ mod tests {
         assert!(!json.contains("\"total_count\""));
         assert!(!json.contains("\"filter_summary\""));
         assert!(!json.contains("\"tabular_spec\""));
+        assert!(!json.contains("\"title\""));
+        assert!(!json.contains("\"empty_message\""));
+        assert!(!json.contains("\"field_styles\""));
+    }
+
+    #[test]
+    fn test_field_styles_apply_to_matching_columns() {
+        use crate::tabular::Column;
+
+        let spec = TabularSpec::builder()
+            .column(Column::default().key("status").style("muted"))
+            .column(Column::default().named("name"))
+            .column(Column::default().key("id"))
+            .build();
+        let mut result = list_view(vec![1])
+            .field_style("status", "warning")
+            .field_style("name", "bold")
+            .tabular_spec(spec.clone())
+            .build();
+
+        let styles: Vec<_> = result
+            .tabular_spec
+            .as_ref()
+            .unwrap()
+            .columns
+            .iter()
+            .map(|c| c.style.as_deref())
+            .collect();
+        assert_eq!(styles, [Some("warning"), Some("bold"), None]);
+
+        result.field_styles.insert("id".into(), "dim".into());
+        result.set_tabular_spec(spec);
+        assert_eq!(
+            result.tabular_spec.unwrap().columns[2].style.as_deref(),
+            Some("dim")
+        );
     }
 
     #[test]
@@ -21,9 +21,33 @@
 //!
 //! When combined with the `#[derive(Tabular)]` macro on your item type,
 //! the framework renders items as a formatted table with zero template code.
+//!
+//! # DetailView
+//!
+//! A single item, one `label: value` line per field:
+//!
+//! ```rust
+//! use standout::views::detail_view;
+//!
+//! # #[derive(serde::Serialize)]
+//! # struct Task { name: String, status: String }
+//! # let task = Task { name: "Write docs".into(), status: "pending".into() };
+//! let result = detail_view(task)
+//!     .title("Task 12")
+//!     .labeled_field("name", "Name")
+//!     .labeled_field("status", "Status")
+//!     .field_style("status", "warning")
+//!     .build();
+//! ```
+//!
+//! Both views take a title, an empty-state message and per-field styles, and
+//! render through the framework templates `standout/list-view` and
+//! `standout/detail-view`, so handlers share the same theme-aware layout.
 
+mod detail_view;
 mod list_view;
 mod message;
 
+pub use detail_view::{detail_view, DetailField, DetailViewBuilder, DetailViewResult};
 pub use list_view::{list_view, ListViewBuilder, ListViewResult};
 pub use message::{Message, MessageLevel};
@@ -1,8 +1,7 @@
 use clap::Command;
 use serde_json::json;
 use standout::cli::{App, HandlerResult, Output};
-use std::cell::RefCell;
-use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 
 // Test App with closure handlers
 #[test]
@@ -29,15 +28,16 @@ fn test_app_integration() {
 // Test App with mutable state (FnMut closures)
 #[test]
 fn test_app_with_mutable_state() {
-    let counter = Rc::new(RefCell::new(0));
+    let counter = Arc::new(Mutex::new(0));
     let counter_clone = counter.clone();
 
     let app = App::builder()
         .command(
             "inc",
             move |_m, _ctx| {
-                *counter_clone.borrow_mut() += 1;
-                Ok(Output::Render(json!({"count": *counter_clone.borrow()})))
+                let mut count = counter_clone.lock().unwrap();
+                *count += 1;
+                Ok(Output::Render(json!({"count": *count})))
             },
             "{{ count }}",
         )
@@ -53,7 +53,7 @@ fn test_app_with_mutable_state() {
     } else {
         panic!("Expected RunResult::Handled, got {:?}", result);
     }
-    assert_eq!(*counter.borrow(), 1);
+    assert_eq!(*counter.lock().unwrap(), 1);
 }
 
 // Test stateful struct handler
@@ -91,7 +91,7 @@ fn test_struct_handler_with_state() {
         panic!("Expected RunResult::Handled, got {:?}", result1);
     }
 
-    // State persists across calls because handlers are stored in Rc<RefCell>
+    // State persists across calls because handlers are stored in Arc<Mutex>
     let result2 = app.run_to_string(cmd, vec!["test", "add"]);
     if let standout::cli::RunResult::Handled(output) = result2 {
         assert_eq!(output, "20");
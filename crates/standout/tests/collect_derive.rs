@@ -0,0 +1,80 @@
+//! Integration tests for the Collect derive macro.
+
+#![cfg(feature = "macros")]
+
+use clap::{Arg, ArgAction, ArgMatches, Command};
+use standout::input::InputError;
+use standout::Collect;
+
+#[derive(Debug, Collect)]
+struct NewTask {
+    #[input(arg, env = "STANDOUT_COLLECT_TEST_TITLE")]
+    title: String,
+    #[input(arg = "prio", default = 3)]
+    priority: u8,
+    due: Option<String>,
+    #[input(flag)]
+    urgent: bool,
+}
+
+fn matches(args: &[&str]) -> ArgMatches {
+    Command::new("create")
+        .arg(Arg::new("title").long("title"))
+        .arg(Arg::new("prio").long("prio"))
+        .arg(Arg::new("due").long("due"))
+        .arg(Arg::new("urgent").long("urgent").action(ArgAction::SetTrue))
+        .try_get_matches_from(std::iter::once("create").chain(args.iter().copied()))
+        .unwrap()
+}
+
+#[test]
+fn test_collects_fields_from_arguments() {
+    let task = NewTask::collect(&matches(&[
+        "--title", "Ship it", "--prio", "1", "--due", "friday", "--urgent",
+    ]))
+    .unwrap();
+
+    assert_eq!(task.title, "Ship it");
+    assert_eq!(task.priority, 1);
+    assert_eq!(task.due.as_deref(), Some("friday"));
+    assert!(task.urgent);
+}
+
+#[test]
+fn test_falls_back_to_env_and_defaults() {
+    std::env::set_var("STANDOUT_COLLECT_TEST_TITLE", "From env");
+    let task = NewTask::collect(&matches(&[])).unwrap();
+    std::env::remove_var("STANDOUT_COLLECT_TEST_TITLE");
+
+    assert_eq!(task.title, "From env");
+    assert_eq!(task.priority, 3);
+    assert_eq!(task.due, None);
+    assert!(!task.urgent);
+}
+
+#[derive(Debug, Collect)]
+struct Required {
+    name: String,
+    count: Option<u32>,
+}
+
+#[test]
+fn test_missing_and_invalid_fields() {
+    let cmd = || {
+        Command::new("t")
+            .arg(Arg::new("name").long("name"))
+            .arg(Arg::new("count").long("count"))
+    };
+
+    let err = Required::collect(&cmd().get_matches_from(["t"])).unwrap_err();
+    assert!(matches!(err, InputError::MissingArgument(ref name) if name == "name"));
+
+    let err = Required::collect(&cmd().get_matches_from(["t", "--name", "x", "--count", "many"]))
+        .unwrap_err();
+    assert!(matches!(err, InputError::ParseError { ref name, .. } if name == "count"));
+
+    let required =
+        Required::collect(&cmd().get_matches_from(["t", "--name", "x", "--count", "2"])).unwrap();
+    assert_eq!(required.name, "x");
+    assert_eq!(required.count, Some(2));
+}
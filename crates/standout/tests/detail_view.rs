@@ -0,0 +1,75 @@
+//! Integration tests for DetailView functionality.
+
+use clap::Command;
+use serde::Serialize;
+use standout::cli::{App, Output, RunResult};
+use standout::views::{detail_view, DetailViewBuilder, DetailViewResult};
+
+#[derive(Clone, Serialize)]
+struct Task {
+    id: u32,
+    name: String,
+    owner: Option<String>,
+}
+
+fn test_task() -> Task {
+    Task {
+        id: 7,
+        name: "Write docs".to_string(),
+        owner: None,
+    }
+}
+
+fn run(result: DetailViewResult<Task>) -> String {
+    let app = App::builder()
+        .command(
+            "show",
+            move |_m, _ctx| Ok(Output::Render(result.clone())),
+            "standout/detail-view",
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+    let cmd = Command::new("test").subcommand(Command::new("show"));
+
+    match app.run_to_string(cmd, vec!["test", "show"]) {
+        RunResult::Handled(output) => output,
+        other => panic!("Expected RunResult::Handled, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_detail_view_renders_labeled_fields_in_order() {
+    let output = run(detail_view(test_task())
+        .title("Task 7")
+        .labeled_field("name", "Name")
+        .labeled_field("owner", "Owner")
+        .labeled_field("id", "ID")
+        .build());
+
+    assert_eq!(
+        output.trim_end(),
+        "Task 7\n\nName:  Write docs\nOwner: -\nID:    7"
+    );
+}
+
+#[test]
+fn test_detail_view_shows_every_field_without_a_field_list() {
+    let output = run(detail_view(test_task()).build());
+
+    assert!(output.contains("id:    7"), "{}", output);
+    assert!(output.contains("name:  Write docs"), "{}", output);
+    assert!(output.contains("owner: -"), "{}", output);
+}
+
+#[test]
+fn test_detail_view_missing_item_shows_empty_message() {
+    let output = run(DetailViewBuilder::new(None).build());
+    assert_eq!(output.trim_end(), "Not found.");
+
+    let output = run(DetailViewBuilder::new(None)
+        .empty_message("No task 7")
+        .warning("Archived tasks are hidden")
+        .build());
+    assert_eq!(output.trim_end(), "No task 7\nArchived tasks are hidden");
+}
@@ -25,6 +25,13 @@ mod handlers {
     pub fn show_all(_matches: &ArgMatches, _ctx: &CommandContext) -> HandlerResult<()> {
         Ok(Output::Silent)
     }
+
+    pub fn export(
+        _matches: &ArgMatches,
+        _ctx: &CommandContext,
+    ) -> HandlerResult<serde_json::Value> {
+        Ok(Output::Render(serde_json::json!({ "count": 2 })))
+    }
 }
 
 // =============================================================================
@@ -141,3 +148,111 @@ fn test_default_command_registers_commands() {
     assert!(builder.contains("list"));
     assert!(builder.contains("add"));
 }
+
+// =============================================================================
+// Output mode tests
+// =============================================================================
+
+#[derive(Subcommand, Dispatch)]
+#[dispatch(handlers = handlers)]
+enum OutputCommands {
+    #[dispatch(output = "json", template = "{{ count }} items")]
+    Export,
+}
+
+#[test]
+fn test_output_attribute_sets_default_mode() {
+    let app = standout::cli::App::builder()
+        .commands(OutputCommands::dispatch_config())
+        .unwrap()
+        .build()
+        .unwrap();
+    let cmd = clap::Command::new("app").subcommand(clap::Command::new("export"));
+
+    let result = app.run_to_string(cmd.clone(), ["app", "export"]);
+    assert!(result.output().unwrap().contains("\"count\": 2"));
+
+    let result = app.run_to_string(cmd, ["app", "export", "--output=text"]);
+    assert_eq!(result.output(), Some("2 items"));
+}
+
+// =============================================================================
+// Alias and hidden tests
+// =============================================================================
+
+#[derive(Subcommand, Dispatch)]
+#[dispatch(handlers = handlers)]
+enum AliasCommands {
+    /// List items
+    #[dispatch(handler = handlers::export, alias = "ls", alias = "l", template = "{{ count }} listed")]
+    List,
+    /// Add an item
+    #[dispatch(handler = handlers::export, hidden, template = "{{ count }} added")]
+    Add,
+}
+
+#[test]
+fn test_alias_and_hidden_attributes() {
+    let app = standout::cli::App::builder()
+        .commands(AliasCommands::dispatch_config())
+        .unwrap()
+        .build()
+        .unwrap();
+    let cmd = || {
+        clap::Command::new("app")
+            .subcommand(clap::Command::new("list").about("List items"))
+            .subcommand(clap::Command::new("add").about("Add an item"))
+    };
+
+    for alias in ["ls", "l"] {
+        let result = app.run_to_string(cmd(), ["app", alias]);
+        assert_eq!(result.output(), Some("2 listed"));
+    }
+    let result = app.run_to_string(cmd(), ["app", "add"]);
+    assert_eq!(result.output(), Some("2 added"));
+
+    let help = app
+        .augment_command_with_help(cmd())
+        .render_help()
+        .to_string();
+    assert!(help.contains("List items"), "{help}");
+    assert!(!help.contains("Add an item"), "{help}");
+}
+
+// =============================================================================
+// Template directory tests
+// =============================================================================
+
+#[derive(Subcommand, Dispatch)]
+#[dispatch(handlers = handlers, template_dir = "views/tasks")]
+enum TemplateDirCommands {
+    #[dispatch(handler = handlers::export)]
+    Export,
+    #[dispatch(handler = handlers::export, template = "{{ count }} shown")]
+    Show,
+}
+
+#[test]
+fn test_template_dir_prefixes_convention_templates() {
+    static TEMPLATES: &[(&str, &str)] = &[("views/tasks/export.jinja", "{{ count }} exported")];
+
+    let app = standout::cli::App::builder()
+        .templates(standout::EmbeddedTemplates::new(
+            TEMPLATES,
+            "/nonexistent/path",
+        ))
+        .commands(TemplateDirCommands::dispatch_config())
+        .unwrap()
+        .build()
+        .unwrap();
+    let cmd = || {
+        clap::Command::new("app")
+            .subcommand(clap::Command::new("export"))
+            .subcommand(clap::Command::new("show"))
+    };
+
+    let result = app.run_to_string(cmd(), ["app", "export"]);
+    assert_eq!(result.output(), Some("2 exported"));
+    let result = app.run_to_string(cmd(), ["app", "show"]);
+    assert_eq!(result.output(), Some("2 shown"));
+}
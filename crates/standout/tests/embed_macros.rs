@@ -1,12 +1,14 @@
 //! Integration tests for the embed macros.
 //!
-//! These tests verify that the `embed_templates!` and `embed_styles!` macros
+//! These tests verify that the `embed_templates!`, `embed_styles!` and
+//! `embed_topics!` macros
 //! correctly walk directories at compile time and embed resources, with proper
 //! handling of extension priority and name resolution.
 
 #![cfg(feature = "macros")]
 
-use standout::{embed_styles, embed_templates, StylesheetRegistry, TemplateRegistry};
+use standout::topics::{TopicRegistry, TopicType};
+use standout::{embed_styles, embed_templates, embed_topics, StylesheetRegistry, TemplateRegistry};
 
 // =============================================================================
 // Template embedding tests
@@ -205,6 +207,47 @@ fn test_embed_styles_css_beats_yaml_priority() {
     );
 }
 
+// =============================================================================
+// Topic embedding tests
+// =============================================================================
+
+#[test]
+fn test_embed_topics() {
+    let topics: TopicRegistry = embed_topics!("tests/fixtures/topics").into();
+
+    let storage = topics
+        .get_topic("storage")
+        .expect("storage topic should exist");
+    assert_eq!(storage.title, "Storage");
+    assert_eq!(storage.topic_type, TopicType::Text);
+    assert_eq!(storage.category, None);
+
+    // Subdirectory becomes the category; front matter supplies title and aliases
+    let setup = topics
+        .get_topic("quickstart")
+        .expect("alias should resolve");
+    assert_eq!(setup.name, "setup");
+    assert_eq!(setup.title, "Getting Started");
+    assert_eq!(setup.category.as_deref(), Some("guides"));
+    assert_eq!(setup.topic_type, TopicType::Markdown);
+}
+
+#[test]
+fn test_embedded_topics_entries_match_disk() {
+    let source = embed_topics!("tests/fixtures/topics");
+
+    let names: Vec<&str> = source.entries().iter().map(|(name, _)| *name).collect();
+    assert_eq!(names, vec!["guides/setup.md", "storage.txt"]);
+
+    // Embedded entries alone produce the same registry as hot-reload
+    let embedded = TopicRegistry::from_embedded_entries(source.entries()).unwrap();
+    assert_eq!(embedded.list_topics().len(), 2);
+    assert_eq!(
+        embedded.get_topic("setup").unwrap().title,
+        "Getting Started"
+    );
+}
+
 // =============================================================================
 // EmbeddedSource tests
 // =============================================================================
@@ -230,3 +273,18 @@ fn test_embedded_styles_source_has_entries() {
     // Should have source path (absolute path ending with our directory)
     assert!(source.source_path().ends_with("tests/fixtures/styles"));
 }
+
+// =============================================================================
+// Asset validation tests
+// =============================================================================
+
+// Fails to compile if report.jinja used a style the fixtures don't define
+standout::validate_assets!("tests/fixtures/styled_templates", "tests/fixtures/styles");
+
+#[test]
+fn test_validated_templates_embed() {
+    let templates: TemplateRegistry = embed_templates!("tests/fixtures/styled_templates").into();
+
+    let content = templates.get_content("report").unwrap();
+    assert!(content.contains("[panel]"));
+}
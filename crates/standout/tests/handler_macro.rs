@@ -340,6 +340,111 @@ fn test_mixed_params() {
     assert_eq!(result.unwrap(), "verbose=true, path_len=0, limit=Some(5)");
 }
 
+// =============================================================================
+// Defaults and custom parsers
+// =============================================================================
+
+fn parse_seconds(s: &str) -> Result<u64, String> {
+    s.strip_suffix('s')
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| format!("expected seconds like `30s`, got `{}`", s))
+}
+
+#[handler]
+fn with_defaults(
+    #[arg(default = 10)] limit: usize,
+    #[arg(default = ".")] dir: std::path::PathBuf,
+    #[arg(default = "30s", parser = parse_seconds)] timeout: u64,
+    #[arg(parser = parse_seconds)] delays: Vec<u64>,
+    #[arg(parser = parse_seconds)] retry: Option<u64>,
+) -> Result<String, anyhow::Error> {
+    Ok(format!(
+        "limit={}, dir={}, timeout={}, delays={:?}, retry={:?}",
+        limit,
+        dir.display(),
+        timeout,
+        delays,
+        retry
+    ))
+}
+
+fn with_defaults_command() -> clap::Command {
+    clap::Command::new("test")
+        .arg(
+            clap::Arg::new("limit")
+                .long("limit")
+                .value_parser(clap::value_parser!(usize)),
+        )
+        .arg(
+            clap::Arg::new("dir")
+                .long("dir")
+                .value_parser(clap::value_parser!(std::path::PathBuf)),
+        )
+        .arg(clap::Arg::new("timeout").long("timeout"))
+        .arg(
+            clap::Arg::new("delays")
+                .long("delay")
+                .action(clap::ArgAction::Append),
+        )
+        .arg(clap::Arg::new("retry").long("retry"))
+}
+
+#[test]
+fn test_defaults_used_when_args_absent() {
+    let matches = with_defaults_command().get_matches_from(vec!["test"]);
+
+    let result = with_defaults__handler(&matches, &CommandContext::default()).unwrap();
+    let Output::Render(text) = result else {
+        panic!("expected rendered output");
+    };
+    assert_eq!(text, "limit=10, dir=., timeout=30, delays=[], retry=None");
+}
+
+#[test]
+fn test_parsers_convert_given_values() {
+    let matches = with_defaults_command().get_matches_from(vec![
+        "test",
+        "--limit",
+        "3",
+        "--timeout",
+        "5s",
+        "--delay",
+        "1s",
+        "--delay",
+        "2s",
+        "--retry",
+        "9s",
+    ]);
+
+    let result = with_defaults__handler(&matches, &CommandContext::default()).unwrap();
+    let Output::Render(text) = result else {
+        panic!("expected rendered output");
+    };
+    assert_eq!(
+        text,
+        "limit=3, dir=., timeout=5, delays=[1, 2], retry=Some(9)"
+    );
+}
+
+#[test]
+fn test_parser_failure_is_usage_error() {
+    let matches = with_defaults_command().get_matches_from(vec!["test", "--delay", "soon"]);
+
+    let err = with_defaults__handler(&matches, &CommandContext::default()).unwrap_err();
+    assert_eq!(standout::cli::ExitError::code_of(&err), 2);
+    assert_eq!(
+        err.to_string(),
+        "invalid value 'soon' for 'delays': expected seconds like `30s`, got `soon`"
+    );
+}
+
+#[test]
+fn test_expected_args_with_default_are_optional() {
+    let args = with_defaults__expected_args();
+    assert_eq!(args[0].kind, ArgKind::OptionalArg);
+    assert_eq!(args[2].kind, ArgKind::OptionalArg);
+}
+
 // =============================================================================
 // Expected args generation for verification
 // =============================================================================
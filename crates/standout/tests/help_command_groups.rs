@@ -468,3 +468,264 @@ fn test_help_flag_works_with_required_args() {
     let output = extract_help(result);
     assert!(output.contains("greet"), "output:\n{output}");
 }
+
+// =============================================================================
+// Per-command help templates
+// =============================================================================
+
+fn nested_cmd() -> Command {
+    Command::new("myapp")
+        .subcommand(Command::new("status").about("Show status"))
+        .subcommand(
+            Command::new("config")
+                .alias("cfg")
+                .subcommand(Command::new("get").about("Read a config key")),
+        )
+}
+
+#[test]
+fn test_help_template_applies_to_its_command() {
+    let app = App::new()
+        .help_handling(true)
+        .help_template("config.get", "CUSTOM {{ name }}: {{ about }}");
+
+    let output =
+        extract_help(app.get_matches_from(nested_cmd(), ["myapp", "help", "config", "get"]));
+    assert_eq!(output.trim(), "CUSTOM get: Read a config key");
+
+    // --help and aliases resolve to the same template
+    let output =
+        extract_help(app.get_matches_from(nested_cmd(), ["myapp", "config", "get", "--help"]));
+    assert!(output.starts_with("CUSTOM get"), "output:\n{output}");
+    let output = extract_help(app.get_matches_from(nested_cmd(), ["myapp", "help", "cfg", "get"]));
+    assert!(output.starts_with("CUSTOM get"), "output:\n{output}");
+}
+
+#[test]
+fn test_help_template_leaves_other_commands_alone() {
+    let app = App::new()
+        .help_handling(true)
+        .help_template("config.get", "CUSTOM {{ name }}");
+
+    let output = extract_help(app.get_matches_from(nested_cmd(), ["myapp", "help", "status"]));
+    assert!(!output.contains("CUSTOM"), "output:\n{output}");
+    assert!(output.contains("USAGE"), "output:\n{output}");
+
+    let output = extract_help(app.get_matches_from(nested_cmd(), ["myapp", "help", "config"]));
+    assert!(!output.contains("CUSTOM"), "output:\n{output}");
+}
+
+#[test]
+fn test_build_errors_on_help_template_without_help_handling() {
+    let result = App::new().help_template("status", "{{ name }}").build();
+    match result {
+        Err(e) => {
+            let msg = e.to_string();
+            assert!(
+                msg.contains("help_template requires .help_handling(true)"),
+                "error: {msg}"
+            );
+        }
+        Ok(_) => panic!("Expected build to fail"),
+    }
+}
+
+// =============================================================================
+// Structured help output
+// =============================================================================
+
+fn parse_json_help(result: HelpResult) -> serde_json::Value {
+    let output = extract_help(result);
+    serde_json::from_str(&output).unwrap_or_else(|e| panic!("invalid JSON ({e}):\n{output}"))
+}
+
+#[test]
+fn test_help_json_output_root() {
+    use standout::topics::{Topic, TopicType};
+
+    let app = app_with_groups().add_topic(Topic::new(
+        "Storage",
+        "Where data lives.",
+        TopicType::Text,
+        Some("storage".to_string()),
+    ));
+    let help =
+        parse_json_help(app.get_matches_from(test_cmd(), ["myapp", "help", "--output", "json"]));
+
+    assert_eq!(help["name"], "myapp");
+    assert_eq!(help["about"], "Test app");
+    assert_eq!(help["subcommands"][0]["title"], "Core");
+    assert_eq!(help["subcommands"][0]["commands"][0]["name"], "status");
+    assert_eq!(help["learn_more"][0]["name"], "storage");
+    assert!(
+        help["options"][0]["options"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|opt| opt["long"] == "output"),
+        "help: {help}"
+    );
+
+    // Layout padding is template-only
+    assert!(help["subcommands"][0]["commands"][0]
+        .get("padding")
+        .is_none());
+}
+
+#[test]
+fn test_help_flag_json_output_subcommand() {
+    let app = app_with_groups();
+    let help = parse_json_help(
+        app.get_matches_from(test_cmd(), ["myapp", "--output=json", "status", "--help"]),
+    );
+
+    assert_eq!(help["name"], "status");
+    assert_eq!(help["about"], "Show status");
+}
+
+#[test]
+fn test_render_help_yaml_output() {
+    let config = HelpConfig {
+        output_mode: Some(OutputMode::Yaml),
+        ..Default::default()
+    };
+    let output = render_help(&test_cmd(), Some(config)).unwrap();
+
+    assert!(output.contains("name: myapp"), "output:\n{output}");
+    assert!(!output.contains("padding"), "output:\n{output}");
+}
+
+// =============================================================================
+// Localized help
+// =============================================================================
+
+fn localized_app() -> App {
+    use standout::i18n::{Catalog, Catalogs};
+
+    let mut catalogs = Catalogs::new("en");
+    catalogs.add(
+        "pt",
+        Catalog::from_yaml(
+            "help:\n  usage: USO\n  options: OPÇÕES\n  groups:\n    Core: Principal\n",
+        )
+        .unwrap(),
+    );
+    app_with_groups().catalogs(catalogs).locale_flag(None)
+}
+
+#[test]
+fn test_help_headers_use_catalog_for_lang_flag() {
+    let output = extract_help(localized_app().get_matches_from(
+        test_cmd(),
+        ["myapp", "--lang", "pt-BR", "help", "--output", "text"],
+    ));
+
+    assert!(output.contains("USO"), "output:\n{output}");
+    assert!(output.contains("OPÇÕES"), "output:\n{output}");
+    assert!(output.contains("PRINCIPAL"), "output:\n{output}");
+    // Untranslated group titles fall back to the original
+    assert!(output.contains("MISC"), "output:\n{output}");
+    assert!(!output.contains("USAGE"), "output:\n{output}");
+}
+
+#[test]
+fn test_help_flag_reads_lang_from_raw_args() {
+    let output = extract_help(localized_app().get_matches_from(
+        test_cmd(),
+        ["myapp", "--lang=pt", "--output=text", "--help"],
+    ));
+
+    assert!(output.contains("USO"), "output:\n{output}");
+}
+
+#[test]
+fn test_help_default_locale_keeps_english_headers() {
+    let output = extract_help(localized_app().get_matches_from(
+        test_cmd(),
+        ["myapp", "--lang", "en", "help", "--output", "text"],
+    ));
+
+    assert!(output.contains("USAGE"), "output:\n{output}");
+    assert!(output.contains("CORE"), "output:\n{output}");
+}
+
+#[test]
+fn test_help_topic_uses_translation() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("storage.txt"),
+        "Storage\n\nWhere data lives.",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("storage.pt.txt"),
+        "Armazenamento\n\nOnde os dados ficam.",
+    )
+    .unwrap();
+
+    let app = localized_app().topics_dir(dir.path()).unwrap();
+
+    let output = extract_help(app.get_matches_from(
+        test_cmd(),
+        [
+            "myapp", "--lang", "pt", "help", "storage", "--output", "text",
+        ],
+    ));
+    assert!(output.contains("Onde os dados ficam."), "output:\n{output}");
+
+    let output = extract_help(app.get_matches_from(
+        test_cmd(),
+        [
+            "myapp", "--lang", "en", "help", "storage", "--output", "text",
+        ],
+    ));
+    assert!(output.contains("Where data lives."), "output:\n{output}");
+}
+
+// =============================================================================
+// Paging
+// =============================================================================
+
+#[test]
+fn test_help_page_flag_forces_pager() {
+    let result = app_with_groups().get_matches_from(test_cmd(), ["myapp", "help", "--page"]);
+    assert!(matches!(result, HelpResult::PagedHelp(_)), "{result:?}");
+}
+
+#[test]
+fn test_help_not_paged_when_stdout_is_not_a_terminal() {
+    // Test stdout is captured, so auto-paging never kicks in
+    let result = app_with_groups().get_matches_from(test_cmd(), ["myapp", "help"]);
+    assert!(matches!(result, HelpResult::Help(_)), "{result:?}");
+}
+
+#[test]
+fn test_no_pager_flag_is_accepted() {
+    let app = app_with_groups().no_pager_flag(None);
+
+    let result = app.get_matches_from(test_cmd(), ["myapp", "--no-pager", "help", "status"]);
+    assert!(matches!(result, HelpResult::Help(_)), "{result:?}");
+
+    let result = app.get_matches_from(test_cmd(), ["myapp", "status", "--no-pager", "--help"]);
+    assert!(matches!(result, HelpResult::Help(_)), "{result:?}");
+
+    // Outside help, the flag is inert
+    let result = app.get_matches_from(test_cmd(), ["myapp", "--no-pager", "status"]);
+    assert!(matches!(result, HelpResult::Matches(_)), "{result:?}");
+}
+
+#[test]
+fn test_app_defining_no_pager_keeps_its_own_flag() {
+    let cmd = test_cmd().arg(
+        clap::Arg::new("no_pager")
+            .long("no-pager")
+            .action(clap::ArgAction::SetTrue),
+    );
+
+    let result = app_with_groups().get_matches_from(cmd, ["myapp", "--no-pager", "status"]);
+
+    match result {
+        HelpResult::Matches(matches) => assert!(matches.get_flag("no_pager")),
+        other => panic!("expected matches, got {other:?}"),
+    }
+}
@@ -146,6 +146,36 @@ fn test_list_view_empty_list() {
     }
 }
 
+#[test]
+fn test_list_view_title_and_empty_message_render() {
+    let app = App::builder()
+        .command(
+            "list",
+            |_m, _ctx| {
+                let result: ListViewResult<Task> = list_view(vec![])
+                    .title("Tasks")
+                    .empty_message("Nothing to do")
+                    .build();
+                Ok(Output::Render(result))
+            },
+            "standout/list-view",
+        )
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let cmd = Command::new("test").subcommand(Command::new("list"));
+    let result = app.run_to_string(cmd, vec!["test", "list"]);
+
+    if let RunResult::Handled(output) = result {
+        assert!(output.contains("Tasks"), "{}", output);
+        assert!(output.contains("Nothing to do"), "{}", output);
+        assert!(!output.contains("No items found"), "{}", output);
+    } else {
+        panic!("Expected RunResult::Handled, got {:?}", result);
+    }
+}
+
 #[test]
 fn test_list_view_with_filter_summary_renders() {
     let app = App::builder()
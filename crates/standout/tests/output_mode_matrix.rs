@@ -2,11 +2,13 @@
 //!
 //! These tests ensure that all output modes work correctly with App.
 
-use clap::ArgMatches;
+use clap::{ArgMatches, Command};
 use serde::Serialize;
+use serial_test::serial;
 use standout::cli::handler::{CommandContext, Output};
 use standout::cli::App;
 use standout::OutputMode;
+use standout_render::{set_color_capability_detector, DetectorGuard};
 
 #[derive(Serialize)]
 struct TestData {
@@ -136,6 +138,27 @@ fn test_app_output_mode_yaml() {
     assert!(output.contains("count: 42"));
 }
 
+#[test]
+fn test_app_output_mode_toml() {
+    let app = App::builder()
+        .command(
+            "run",
+            |_m: &ArgMatches, _ctx: &CommandContext| Ok(Output::Render(TestData::sample())),
+            simple_template(),
+        )
+        .unwrap()
+        .build()
+        .expect("Failed to build app");
+
+    let output = app
+        .render_inline(simple_template(), &TestData::sample(), OutputMode::Toml)
+        .expect("Render failed");
+
+    // Should contain TOML format
+    assert!(output.contains("name = \"test\""));
+    assert!(output.contains("count = 42"));
+}
+
 #[test]
 fn test_app_output_mode_csv() {
     let app = App::builder()
@@ -440,3 +463,231 @@ fn test_style_tags_kept_in_term_debug_mode() {
     assert!(output.contains("[/title]"));
     assert!(output.contains("test"));
 }
+
+// ============================================================================
+// Colored JSON Tests
+// ============================================================================
+
+fn colored_json_app(enabled: bool) -> App {
+    App::builder()
+        .theme(
+            standout::Theme::new().add("json_key", console::Style::new().red().force_styling(true)),
+        )
+        .colored_json(enabled)
+        .command(
+            "run",
+            |_m: &ArgMatches, _ctx: &CommandContext| Ok(Output::Render(TestData::sample())),
+            simple_template(),
+        )
+        .unwrap()
+        .build()
+        .expect("Failed to build app")
+}
+
+/// Plain pretty JSON, as dispatch produces it from the handler's data.
+fn plain_json() -> String {
+    let value = serde_json::to_value(TestData::sample()).unwrap();
+    serde_json::to_string_pretty(&value).unwrap()
+}
+
+fn dispatch_json(app: &App) -> String {
+    let cmd = Command::new("app").subcommand(Command::new("run"));
+    let matches = cmd.try_get_matches_from(["app", "run"]).unwrap();
+    app.dispatch(matches, OutputMode::Json)
+        .output()
+        .expect("expected output")
+        .to_string()
+}
+
+#[test]
+#[serial(color_detector)]
+fn test_colored_json_on_color_terminal() {
+    let _guard = DetectorGuard::new();
+    set_color_capability_detector(|| true);
+
+    let output = dispatch_json(&colored_json_app(true));
+
+    assert!(output.contains("\x1b[31m\"name\"\x1b[0m"));
+    assert_eq!(console::strip_ansi_codes(&output), plain_json());
+}
+
+#[test]
+#[serial(color_detector)]
+fn test_colored_json_plain_when_piped() {
+    let _guard = DetectorGuard::new();
+    set_color_capability_detector(|| false);
+
+    let output = dispatch_json(&colored_json_app(true));
+
+    assert_eq!(output, plain_json());
+}
+
+#[test]
+#[serial(color_detector)]
+fn test_colored_json_disabled_by_default() {
+    let _guard = DetectorGuard::new();
+    set_color_capability_detector(|| true);
+
+    let output = dispatch_json(&colored_json_app(false));
+
+    assert!(!output.contains('\x1b'));
+}
+
+// ============================================================================
+// CSV Options Tests
+// ============================================================================
+
+#[derive(Serialize)]
+struct Drink {
+    name: String,
+    price: f64,
+}
+
+fn drinks() -> Vec<Drink> {
+    vec![
+        Drink {
+            name: "tea".to_string(),
+            price: 2.5,
+        },
+        Drink {
+            name: "flat white".to_string(),
+            price: 3.25,
+        },
+    ]
+}
+
+fn csv_app(options: standout::CsvOptions, hooks: Option<standout::cli::Hooks>) -> App {
+    let mut builder = App::builder()
+        .csv_options(options)
+        .command(
+            "run",
+            |_m: &ArgMatches, _ctx: &CommandContext| Ok(Output::Render(drinks())),
+            simple_template(),
+        )
+        .unwrap();
+    if let Some(hooks) = hooks {
+        builder = builder.hooks("run", hooks);
+    }
+    builder.build().expect("Failed to build app")
+}
+
+fn dispatch_csv(app: &App) -> String {
+    let cmd = Command::new("app").subcommand(Command::new("run"));
+    let matches = cmd.try_get_matches_from(["app", "run"]).unwrap();
+    app.dispatch(matches, OutputMode::Csv)
+        .output()
+        .expect("expected output")
+        .to_string()
+}
+
+#[test]
+fn test_csv_options_default_output() {
+    let app = csv_app(standout::CsvOptions::default(), None);
+
+    assert_eq!(dispatch_csv(&app), "name,price\ntea,2.5\nflat white,3.25\n");
+}
+
+#[test]
+fn test_csv_options_delimiter_and_headers() {
+    let app = csv_app(
+        standout::CsvOptions::new().delimiter(b';').headers(false),
+        None,
+    );
+
+    assert_eq!(dispatch_csv(&app), "tea;2.5\nflat white;3.25\n");
+}
+
+#[test]
+fn test_csv_options_render_inline() {
+    let app = csv_app(
+        standout::CsvOptions::new().quoting(standout::CsvQuoting::NonNumeric),
+        None,
+    );
+
+    let output = app
+        .render_inline(simple_template(), &drinks(), OutputMode::Csv)
+        .expect("Render failed");
+
+    assert_eq!(
+        output,
+        "\"name\",\"price\"\n\"tea\",2.5\n\"flat white\",3.25\n"
+    );
+}
+
+#[test]
+fn test_csv_options_overridden_by_pre_dispatch_hook() {
+    let hooks = standout::cli::Hooks::new().pre_dispatch(|_m, ctx| {
+        ctx.extensions
+            .insert(standout::CsvOptions::new().delimiter(b'\t'));
+        Ok(())
+    });
+    let app = csv_app(standout::CsvOptions::default(), Some(hooks));
+
+    assert_eq!(
+        dispatch_csv(&app),
+        "name\tprice\ntea\t2.5\nflat white\t3.25\n"
+    );
+}
+
+// ============================================================================
+// Output Mode From Environment
+// ============================================================================
+
+const OUTPUT_ENV: &str = "STANDOUT_MATRIX_OUTPUT";
+
+fn dispatch_with_env(env_value: Option<&str>, args: &[&str]) -> String {
+    let app = App::builder()
+        .output_env(OUTPUT_ENV)
+        .command(
+            "run",
+            |_m: &ArgMatches, _ctx: &CommandContext| Ok(Output::Render(TestData::sample())),
+            simple_template(),
+        )
+        .unwrap()
+        .build()
+        .expect("Failed to build app");
+    let cmd = Command::new("app").subcommand(Command::new("run"));
+
+    match env_value {
+        Some(value) => std::env::set_var(OUTPUT_ENV, value),
+        None => std::env::remove_var(OUTPUT_ENV),
+    }
+    let result = app.dispatch_from(cmd, args.iter().copied());
+    std::env::remove_var(OUTPUT_ENV);
+
+    result.output().expect("expected output").to_string()
+}
+
+#[test]
+#[serial]
+fn test_output_env_sets_default_mode() {
+    let output = dispatch_with_env(Some("json"), &["app", "run"]);
+
+    let parsed: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(parsed["count"], 42);
+}
+
+#[test]
+#[serial]
+fn test_output_flag_overrides_env() {
+    let output = dispatch_with_env(Some("json"), &["app", "--output", "text", "run"]);
+    assert_eq!(output, "Name: test, Count: 42");
+
+    // An explicit --output=auto also wins over the environment
+    let output = dispatch_with_env(Some("JSON"), &["app", "--output", "auto", "run"]);
+    assert!(output.starts_with("Name: test"));
+}
+
+#[test]
+#[serial]
+fn test_output_env_unset_or_invalid_falls_back_to_auto() {
+    let output = dispatch_with_env(None, &["app", "run"]);
+    assert!(output.starts_with("Name: test"));
+
+    let output = dispatch_with_env(Some("xlsx"), &["app", "run"]);
+    assert!(output.starts_with("Name: test"));
+    let warnings = standout_render::warnings::drain_warnings();
+    assert!(warnings
+        .iter()
+        .any(|w| w.contains("STANDOUT_MATRIX_OUTPUT=xlsx")));
+}
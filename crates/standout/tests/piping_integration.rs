@@ -389,3 +389,49 @@ fn test_clipboard_receives_plain_text() {
         "Clipboard should receive the raw text content"
     );
 }
+
+/// Test that a tee sends formatted output to ANSI targets and raw output to the rest.
+#[test]
+fn test_tee_fans_out_with_per_target_transform() {
+    use standout::pipe::{PipeError, PipeTarget, Tee, Transform};
+
+    struct Recorder(Arc<std::sync::Mutex<Vec<String>>>);
+
+    impl PipeTarget for Recorder {
+        fn pipe(&self, input: &str) -> Result<String, PipeError> {
+            self.0.lock().unwrap().push(input.to_string());
+            Ok(String::new())
+        }
+    }
+
+    let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("report.txt");
+    let theme = Theme::new().add("bold", Style::new().bold().force_styling(true));
+
+    let tee = Tee::new()
+        .target(Recorder(seen.clone()), Transform::Ansi)
+        .file(&path);
+    let app = App::builder()
+        .theme(theme)
+        .commands(|g| {
+            g.command_with(
+                "report",
+                |_m, _ctx| Ok(Output::Render(json!({"msg": "done"}))),
+                move |cfg| cfg.template("[bold]{{ msg }}[/bold]").tee(tee),
+            )
+        })
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let cmd = Command::new("app").subcommand(Command::new("report"));
+    let result = app.run_to_string(cmd, vec!["app", "report", "--output=term"]);
+
+    let RunResult::Handled(output) = result else {
+        panic!("Expected RunResult::Handled, got {:?}", result);
+    };
+    assert!(output.contains("\x1b["), "got {:?}", output);
+    assert_eq!(*seen.lock().unwrap(), [output]);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "done");
+}
@@ -6,7 +6,7 @@ use standout::cli::{App, Output};
 use standout::{OutputMode, Theme};
 
 // Strategy for generating arbitrary OutputMode values
-// Per design guidelines: all 8 output modes must be covered
+// Per design guidelines: all 9 output modes must be covered
 fn output_mode_strategy() -> impl Strategy<Value = OutputMode> {
     prop_oneof![
         Just(OutputMode::Auto),
@@ -16,6 +16,7 @@ fn output_mode_strategy() -> impl Strategy<Value = OutputMode> {
         Just(OutputMode::Json),
         Just(OutputMode::Yaml),
         Just(OutputMode::Xml),
+        Just(OutputMode::Toml),
         Just(OutputMode::Csv),
     ]
 }
@@ -98,6 +99,15 @@ fn validate_structured_output(output: &str, mode: OutputMode) {
                 output
             );
         }
+        OutputMode::Toml => {
+            // Null data serializes to an empty document; anything else must parse
+            let parsed: Result<toml::Value, _> = toml::from_str(output);
+            assert!(
+                output.is_empty() || parsed.is_ok(),
+                "TOML output should be parseable: {}",
+                output
+            );
+        }
         OutputMode::Csv => {
             // CSV output must be non-empty
             assert!(!output.is_empty(), "CSV output should not be empty");
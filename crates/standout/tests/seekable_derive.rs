@@ -744,3 +744,104 @@ fn test_parse_query_invalid_operator_error() {
     let result = parse_query::<BasicTask>(pairs);
     assert!(result.is_err());
 }
+
+// =============================================================================
+// Option and flatten tests
+// =============================================================================
+
+#[derive(DeriveSeekable)]
+struct Author {
+    #[seek(String)]
+    name: String,
+    #[seek(Number)]
+    karma: Option<i64>,
+}
+
+#[derive(DeriveSeekable)]
+struct Post {
+    #[seek(String)]
+    title: String,
+    #[seek(String)]
+    summary: Option<String>,
+    #[seek(flatten)]
+    author: Author,
+    #[seek(flatten, rename = "editor")]
+    reviewed_by: Option<Author>,
+}
+
+fn posts() -> Vec<Post> {
+    vec![
+        Post {
+            title: "Intro".to_string(),
+            summary: Some("Getting started".to_string()),
+            author: Author {
+                name: "ada".to_string(),
+                karma: Some(10),
+            },
+            reviewed_by: None,
+        },
+        Post {
+            title: "Deep dive".to_string(),
+            summary: None,
+            author: Author {
+                name: "linus".to_string(),
+                karma: None,
+            },
+            reviewed_by: Some(Author {
+                name: "ada".to_string(),
+                karma: Some(10),
+            }),
+        },
+    ]
+}
+
+#[test]
+fn test_option_fields_are_missing_when_none() {
+    let posts = posts();
+    assert_eq!(
+        posts[0].seeker_field_value("summary"),
+        Value::String("Getting started")
+    );
+    assert_eq!(posts[1].seeker_field_value("summary"), Value::None);
+
+    let query = Query::new().and_contains("summary", "started").build();
+    let results = query.filter(&posts, Post::accessor);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].title, "Intro");
+}
+
+#[test]
+fn test_flatten_exposes_nested_fields() {
+    let posts = posts();
+    assert_eq!(
+        posts[1].seeker_field_value("author.name"),
+        Value::String("linus")
+    );
+    assert_eq!(posts[1].seeker_field_value("author.karma"), Value::None);
+    assert_eq!(posts[0].seeker_field_value("editor.name"), Value::None);
+    assert_eq!(
+        posts[1].seeker_field_value("editor.name"),
+        Value::String("ada")
+    );
+
+    assert_eq!(Post::field_type("author.karma"), Some(SeekType::Number));
+    assert_eq!(Post::field_type("editor.name"), Some(SeekType::String));
+    assert_eq!(Post::field_type("author.unknown"), None);
+    assert_eq!(
+        Post::field_names(),
+        [
+            "title",
+            "summary",
+            "author.name",
+            "author.karma",
+            "editor.name",
+            "editor.karma"
+        ]
+    );
+
+    let pairs = vec![("editor.name".to_string(), "ada".to_string())];
+    let query = parse_query::<Post>(pairs).unwrap();
+    let results = query.filter(&posts, Post::accessor);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].title, "Deep dive");
+}
@@ -88,3 +88,17 @@ fn test_snapshots_error_handling() {
     let output = result.error().unwrap();
     assert_snapshot!("error_output", output);
 }
+
+#[test]
+fn test_snapshots_term_debug_template() {
+    let theme = standout::Theme::new()
+        .add("title", console::Style::new().bold())
+        .add("count", console::Style::new().cyan());
+
+    standout::assert_term_debug_snapshot!(
+        "term_debug_report",
+        "[title]{{ title }}[/title]   \n  [count]{{ count }}[/count] items\n\n",
+        &json!({ "title": "Report", "count": 3 }),
+        &theme
+    );
+}
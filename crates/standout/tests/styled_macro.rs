@@ -0,0 +1,43 @@
+//! Integration tests for the `styled!` macro.
+
+#![cfg(feature = "macros")]
+
+use console::Style;
+use serial_test::serial;
+use standout::{styled, Theme};
+use standout_render::{set_color_capability_detector, DetectorGuard};
+
+fn theme() -> Theme {
+    Theme::new().add("title", Style::new().bold())
+}
+
+#[test]
+#[serial(color_detector)]
+fn test_styled_formats_and_applies_theme() {
+    let _guard = DetectorGuard::new();
+    set_color_capability_detector(|| true);
+    console::set_colors_enabled(true);
+    let theme = theme();
+    let name = "tasks";
+
+    let line = styled!(theme, "[title]{}[/title]: {} items", name, 3);
+    let expected = format!(
+        "{}: 3 items",
+        Style::new().bold().force_styling(true).apply_to("tasks")
+    );
+    assert_eq!(line, expected);
+}
+
+#[test]
+#[serial(color_detector)]
+fn test_styled_escapes_arguments() {
+    let _guard = DetectorGuard::new();
+    set_color_capability_detector(|| false);
+    let theme = theme();
+    let path = "[draft]";
+
+    assert_eq!(
+        styled!(theme, "[title]{path}[/title] {n:>3}", n = 7),
+        "[draft]   7"
+    );
+}
@@ -6,7 +6,9 @@
 #![cfg(feature = "macros")]
 
 use serde::Serialize;
-use standout::tabular::{Align, Anchor, Overflow, Tabular, TabularRow, TruncateAt, Width};
+use standout::tabular::{
+    Align, Anchor, Overflow, StyleCondition, StyleRule, Tabular, TabularRow, TruncateAt, Width,
+};
 use standout_macros::Tabular as DeriveTabular;
 
 // =============================================================================
@@ -234,6 +236,62 @@ fn test_style_from_value() {
     assert!(spec.columns[1].style_from_value);
 }
 
+fn load_style(value: &str) -> Option<String> {
+    value
+        .trim_end_matches('%')
+        .parse::<u32>()
+        .ok()
+        .filter(|n| *n > 80)
+        .map(|_| "hot".to_string())
+}
+
+#[derive(Serialize, DeriveTabular)]
+struct ConditionalStyleTask {
+    #[col(
+        width = 8,
+        style_if(eq = "error", style = "red"),
+        style_if(eq = "ok", style = "green")
+    )]
+    status: String,
+
+    #[col(
+        width = 6,
+        style_if(ge = 90, style = "critical"),
+        style_if(ge = 70.5, style = "warn")
+    )]
+    score: f64,
+
+    #[col(width = 6, style_fn = "load_style")]
+    load: String,
+}
+
+#[test]
+fn test_style_if() {
+    let spec = ConditionalStyleTask::tabular_spec();
+    let status = &spec.columns[0];
+    assert_eq!(status.style_rules.len(), 2);
+    assert_eq!(
+        status.style_rules[0],
+        StyleRule::new(StyleCondition::Eq("error".into()), "red")
+    );
+    assert_eq!(status.resolve_style("ok").as_deref(), Some("green"));
+    assert_eq!(status.resolve_style("pending"), None);
+
+    let score = &spec.columns[1];
+    assert_eq!(score.resolve_style("95").as_deref(), Some("critical"));
+    assert_eq!(score.resolve_style("71").as_deref(), Some("warn"));
+    assert_eq!(score.resolve_style("70"), None);
+}
+
+#[test]
+fn test_style_fn() {
+    let spec = ConditionalStyleTask::tabular_spec();
+    let load = &spec.columns[2];
+    assert!(load.style_fn.is_some());
+    assert_eq!(load.resolve_style("93%").as_deref(), Some("hot"));
+    assert_eq!(load.resolve_style("12%"), None);
+}
+
 // =============================================================================
 // Header and null_repr tests
 // =============================================================================
@@ -550,6 +608,72 @@ fn test_combined_row_matches_spec_columns() {
     assert_eq!(spec.columns.len(), values.len());
 }
 
+// Wide/narrow views from a single struct
+#[derive(Serialize, DeriveTabular, DeriveTabularRow)]
+struct ViewTask {
+    #[col(width = 8)]
+    id: String,
+
+    #[col(width = 20, only = "wide")]
+    path: String,
+
+    #[col(width = "fill")]
+    title: String,
+
+    #[col(width = 10, only = "wide")]
+    owner: String,
+}
+
+fn view_task() -> ViewTask {
+    ViewTask {
+        id: "TSK-001".to_string(),
+        path: "src/lib.rs".to_string(),
+        title: "Implement feature".to_string(),
+        owner: "alice".to_string(),
+    }
+}
+
+#[test]
+fn test_view_default_excludes_wide_columns() {
+    let spec = ViewTask::tabular_spec();
+    let names: Vec<_> = spec.columns.iter().map(|c| c.name.as_deref()).collect();
+    assert_eq!(names, vec![Some("id"), Some("title")]);
+    assert_eq!(view_task().to_row(), vec!["TSK-001", "Implement feature"]);
+}
+
+#[test]
+fn test_view_wide_includes_columns_in_order() {
+    let spec = ViewTask::tabular_spec_for("wide");
+    let names: Vec<_> = spec.columns.iter().map(|c| c.name.as_deref()).collect();
+    assert_eq!(
+        names,
+        vec![Some("id"), Some("path"), Some("title"), Some("owner")]
+    );
+    assert_eq!(
+        view_task().to_row_for(Some("wide")),
+        vec!["TSK-001", "src/lib.rs", "Implement feature", "alice"]
+    );
+}
+
+#[test]
+fn test_view_unknown_matches_default() {
+    assert_eq!(ViewTask::tabular_spec_for("other").columns.len(), 2);
+    assert_eq!(view_task().to_row_for(Some("other")).len(), 2);
+}
+
+#[test]
+fn test_table_from_type_for_view() {
+    let table = Table::from_type_for::<ViewTask>("wide", 100);
+    assert_eq!(table.num_columns(), 4);
+
+    let row = table.row_from_trait(&view_task());
+    assert!(row.contains("src/lib.rs"));
+    assert!(row.contains("alice"));
+
+    let narrow = Table::from_type::<ViewTask>(100).row_from_trait(&view_task());
+    assert!(!narrow.contains("alice"));
+}
+
 // =============================================================================
 // Integration with TabularFormatter tests
 // =============================================================================
@@ -896,3 +1020,52 @@ fn test_tabular_row_option() {
     // Default behavior for None is empty string
     assert_eq!(values[2], "");
 }
+
+// =============================================================================
+// format_with tests
+// =============================================================================
+
+/// Amount in cents, deliberately without `Display`.
+struct Cents(u64);
+
+enum Priority {
+    Low,
+    High,
+}
+
+fn fmt_cents(value: &Cents) -> String {
+    format!("${}.{:02}", value.0 / 100, value.0 % 100)
+}
+
+fn fmt_priority(value: &Priority) -> &'static str {
+    match value {
+        Priority::Low => "low",
+        Priority::High => "HIGH",
+    }
+}
+
+#[derive(DeriveTabularRow)]
+struct Invoice {
+    id: String,
+    #[col(format_with = fmt_cents)]
+    total: Cents,
+    #[col(format_with = "fmt_priority")]
+    priority: Priority,
+}
+
+#[test]
+fn test_tabular_row_format_with() {
+    let invoice = Invoice {
+        id: "INV-1".to_string(),
+        total: Cents(12345),
+        priority: Priority::High,
+    };
+    assert_eq!(invoice.to_row(), vec!["INV-1", "$123.45", "HIGH"]);
+
+    let invoice = Invoice {
+        id: "INV-2".to_string(),
+        total: Cents(5),
+        priority: Priority::Low,
+    };
+    assert_eq!(invoice.to_row(), vec!["INV-2", "$0.05", "low"]);
+}
@@ -0,0 +1,75 @@
+//! Integration tests for the ThemeDef derive macro.
+
+#![cfg(feature = "macros")]
+
+use console::{Color, Style};
+use standout::{render_with_mode, ColorMode, OutputMode, Theme, ThemeDef};
+
+#[derive(ThemeDef)]
+struct AppTheme {
+    #[style(
+        light = Style::new().fg(Color::Black),
+        dark = Style::new().fg(Color::White)
+    )]
+    title: Style,
+    section_title: Style,
+    #[style(name = "dim")]
+    muted: Style,
+}
+
+fn app_theme() -> AppTheme {
+    AppTheme {
+        title: Style::new().bold(),
+        section_title: Style::new().underlined(),
+        muted: Style::new().dim(),
+    }
+}
+
+#[test]
+fn test_style_name_constants() {
+    assert_eq!(AppTheme::TITLE, "title");
+    assert_eq!(AppTheme::SECTION_TITLE, "section-title");
+    assert_eq!(AppTheme::MUTED, "dim");
+    assert_eq!(AppTheme::STYLE_NAMES, ["title", "section-title", "dim"]);
+}
+
+#[test]
+fn test_theme_has_field_styles_and_variants() {
+    let theme: Theme = app_theme().into();
+
+    assert_eq!(
+        theme.get_style(AppTheme::MUTED, None),
+        Some(Style::new().dim())
+    );
+    assert_eq!(
+        theme.get_style(AppTheme::TITLE, Some(ColorMode::Dark)),
+        Some(Style::new().fg(Color::White))
+    );
+    assert_eq!(
+        theme.get_style(AppTheme::TITLE, Some(ColorMode::Light)),
+        Some(Style::new().fg(Color::Black))
+    );
+}
+
+#[test]
+fn test_constants_usable_in_templates() {
+    console::set_colors_enabled(true);
+    let template = format!("[{0}]Tasks[/{0}]", AppTheme::SECTION_TITLE);
+    let output = render_with_mode(
+        &template,
+        &serde_json::json!({}),
+        &app_theme().to_theme(),
+        OutputMode::Term,
+        ColorMode::Dark,
+    )
+    .unwrap();
+
+    assert_eq!(
+        output,
+        Style::new()
+            .underlined()
+            .force_styling(true)
+            .apply_to("Tasks")
+            .to_string()
+    );
+}